@@ -141,6 +141,7 @@ export function TenantDetailClient({ namespace, name, initialTab, initialYamlEdi
   const [restartingPod, setRestartingPod] = useState<string | null>(null)
   const [deletingPod, setDeletingPod] = useState<string | null>(null)
   const [deletingPool, setDeletingPool] = useState<string | null>(null)
+  const [restartingPool, setRestartingPool] = useState<string | null>(null)
   const [logsPod, setLogsPod] = useState<string | null>(null)
   const [logsContent, setLogsContent] = useState("")
   const [logsLoading, setLogsLoading] = useState(false)
@@ -381,7 +382,23 @@ export function TenantDetailClient({ namespace, name, initialTab, initialYamlEdi
     }
   }
 
+  const handleRolloutRestartPool = async (poolName: string) => {
+    if (!confirm(t('Restart all pods of pool "{{name}}"?', { name: poolName }))) return
+    setRestartingPool(poolName)
+    try {
+      await api.rolloutRestartPool(namespace, name, poolName)
+      toast.success(t("Pool restart requested"))
+      loadTenant()
+    } catch (e) {
+      const err = e as ApiError
+      toast.error(err.message || t("Restart failed"))
+    } finally {
+      setRestartingPool(null)
+    }
+  }
+
   const handleRestartPod = async (podName: string) => {
+    if (!confirm(t('Restart pod "{{name}}"?', { name: podName }))) return
     setRestartingPod(podName)
     try {
       await api.restartPod(namespace, name, podName)
@@ -916,6 +933,19 @@ export function TenantDetailClient({ namespace, name, initialTab, initialYamlEdi
                       {p.ready_replicas}/{p.replicas}
                     </TableCell>
                     <TableCell>
+                      <Button
+                        variant="ghost"
+                        size="icon-xs"
+                        title={t("Restart")}
+                        disabled={restartingPool === p.name}
+                        onClick={() => handleRolloutRestartPool(p.name)}
+                      >
+                        {restartingPool === p.name ? (
+                          <Spinner className="size-4" />
+                        ) : (
+                          <RiRestartLine className="size-4" />
+                        )}
+                      </Button>
                       <Button
                         variant="ghost"
                         size="icon-xs"
@@ -19,6 +19,7 @@ import * as api from "@/lib/api"
 import type {
   CreatePoolRequest,
   CreateTenantRequest,
+  TenantProfile,
   ProvisioningBucket,
   ProvisioningPolicy,
   ProvisioningUser,
@@ -44,6 +45,7 @@ metadata:
   namespace: default
 spec:
   image: ${DEFAULT_RUSTFS_IMAGE}
+  profile: standalone
   credsSecret:
     name: rustfs-creds
   pools:
@@ -274,6 +276,7 @@ export default function TenantCreatePage() {
       namespace: parsedNamespace,
       pools: parsedPools,
       image: asString(spec?.image),
+      profile: asString(spec?.profile) as TenantProfile | undefined,
       mount_path: asString(spec?.mountPath ?? spec?.mount_path),
       creds_secret: asString(spec?.credsSecret ?? spec?.creds_secret) ?? asString(credsSecretRef?.name),
       policies,
@@ -315,6 +318,8 @@ export default function TenantCreatePage() {
             storage_class: p.storage_class || undefined,
           })),
           image: trimmedImage,
+          // Pools below four drives only pass validation under the standalone profile.
+          profile: pools.some((p) => p.servers * p.volumes_per_server < 4) ? "standalone" : undefined,
           creds_secret: credsSecret.trim() || undefined,
           security_context: {
             runAsUser: securityContext.runAsUser ? parseInt(securityContext.runAsUser, 10) : undefined,
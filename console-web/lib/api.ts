@@ -12,6 +12,8 @@ import type {
   CancelPoolDecommissionRequest,
   DeletePoolResponse,
   PoolDecommissionRequestResponse,
+  PoolRolloutResponse,
+  RolloutRestartPoolResponse,
   PodListResponse,
   PodDetails,
   StartPoolDecommissionRequest,
@@ -19,6 +21,7 @@ import type {
   NodeListResponse,
   NamespaceListResponse,
   ClusterResourcesResponse,
+  DashboardResponse,
   TenantYamlPayload,
   TenantLifecycleState,
   TenantStateCountsResponse,
@@ -28,8 +31,15 @@ import type {
   SecurityContextInfo,
   UpdateSecurityContextRequest,
   SecurityContextUpdateResponse,
+  TenantConditionHistoryResponse,
+  StartBenchmarkRequest,
+  BenchmarkItem,
+  BenchmarkListResponse,
+  BenchmarkDetailsResponse,
+  DeleteBenchmarkResponse,
+  ConsoleAccessResponse,
 } from "@/types/api"
-import type { TopologyOverviewResponse } from "@/types/topology"
+import type { TenantTopologyResponse, TopologyOverviewResponse } from "@/types/topology"
 import { getApiBaseUrl } from "@/lib/config"
 
 const ns = (namespace: string) => `/namespaces/${encodeURIComponent(namespace)}`
@@ -105,6 +115,25 @@ export async function updateTenantYaml(
   return apiClient.put<TenantYamlPayload>(tenantYaml(namespace, name), body)
 }
 
+export async function exportTenant(
+  namespace: string,
+  name: string,
+  redactSecrets = false,
+): Promise<TenantYamlPayload> {
+  return apiClient.get<TenantYamlPayload>(
+    `${tenant(namespace, name)}/export?redactSecrets=${redactSecrets}`,
+  )
+}
+
+export async function getTenantConditionHistory(
+  namespace: string,
+  name: string,
+  type?: string,
+): Promise<TenantConditionHistoryResponse> {
+  const query = type ? `?type=${encodeURIComponent(type)}` : ""
+  return apiClient.get<TenantConditionHistoryResponse>(`${tenant(namespace, name)}/conditions/history${query}`)
+}
+
 export async function listTenantStateCounts(): Promise<TenantStateCountsResponse> {
   return apiClient.get<TenantStateCountsResponse>(tenantStateCounts)
 }
@@ -147,6 +176,24 @@ export async function cancelPoolDecommission(
   )
 }
 
+export async function getPoolRollout(
+  namespace: string,
+  tenantName: string,
+  poolName: string,
+): Promise<PoolRolloutResponse> {
+  return apiClient.get<PoolRolloutResponse>(`${pool(namespace, tenantName, poolName)}/rollout`)
+}
+
+export async function rolloutRestartPool(
+  namespace: string,
+  tenantName: string,
+  poolName: string,
+): Promise<RolloutRestartPoolResponse> {
+  return apiClient.post<RolloutRestartPoolResponse>(`${pool(namespace, tenantName, poolName)}/rollout-restart`, {
+    confirm: poolName,
+  })
+}
+
 // ----- Pods -----
 export async function listPods(namespace: string, tenantName: string): Promise<PodListResponse> {
   return apiClient.get<PodListResponse>(`${pods(namespace, tenantName)}`)
@@ -167,6 +214,7 @@ export async function restartPod(
   force = false,
 ): Promise<{ success: boolean; message: string }> {
   return apiClient.post(`${pod(namespace, tenantName, podName)}/restart`, {
+    confirm: podName,
     force,
   })
 }
@@ -185,6 +233,49 @@ export async function getPodLogs(
   return apiClient.getText(`${pod(namespace, tenantName, podName)}/logs${q ? `?${q}` : ""}`)
 }
 
+// ----- Benchmarks -----
+export async function listBenchmarks(namespace: string, tenantName: string): Promise<BenchmarkListResponse> {
+  return apiClient.get<BenchmarkListResponse>(`${tenant(namespace, tenantName)}/benchmarks`)
+}
+
+export async function startBenchmark(
+  namespace: string,
+  tenantName: string,
+  body: StartBenchmarkRequest,
+): Promise<BenchmarkItem> {
+  return apiClient.post<BenchmarkItem>(`${tenant(namespace, tenantName)}/benchmarks`, body)
+}
+
+export async function getBenchmark(
+  namespace: string,
+  tenantName: string,
+  name: string,
+): Promise<BenchmarkDetailsResponse> {
+  return apiClient.get<BenchmarkDetailsResponse>(
+    `${tenant(namespace, tenantName)}/benchmarks/${encodeURIComponent(name)}`,
+  )
+}
+
+export async function deleteBenchmark(
+  namespace: string,
+  tenantName: string,
+  name: string,
+): Promise<DeleteBenchmarkResponse> {
+  return apiClient.delete<DeleteBenchmarkResponse>(
+    `${tenant(namespace, tenantName)}/benchmarks/${encodeURIComponent(name)}`,
+  )
+}
+
+// ----- Console access -----
+export async function getConsoleAccess(
+  namespace: string,
+  tenantName: string,
+  durationSeconds?: number,
+): Promise<ConsoleAccessResponse> {
+  const q = durationSeconds != null ? `?duration_seconds=${durationSeconds}` : ""
+  return apiClient.get<ConsoleAccessResponse>(`${tenant(namespace, tenantName)}/console-access${q}`)
+}
+
 // ----- Encryption -----
 const encryption = (namespace: string, name: string) => `${tenant(namespace, name)}/encryption`
 
@@ -251,3 +342,12 @@ export async function createNamespace(name: string): Promise<unknown> {
 export async function getTopologyOverview(): Promise<TopologyOverviewResponse> {
   return apiClient.get<TopologyOverviewResponse>("/topology/overview")
 }
+
+export async function getTenantTopology(namespace: string, name: string): Promise<TenantTopologyResponse> {
+  return apiClient.get<TenantTopologyResponse>(`${tenant(namespace, name)}/topology`)
+}
+
+// ----- Dashboard -----
+export async function getDashboard(): Promise<DashboardResponse> {
+  return apiClient.get<DashboardResponse>("/dashboard")
+}
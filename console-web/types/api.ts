@@ -20,6 +20,16 @@ export interface TenantListItem {
   observed_generation?: number | null
   stale?: boolean
   created_at: string | null
+  ready_replicas?: number
+  conditions?: TenantCondition[]
+  health?: TenantHealth | null
+}
+
+export interface TenantHealth {
+  ready_pods: number
+  total_pods: number
+  admin_api: string | null
+  last_check_time: string | null
 }
 
 export interface TenantListResponse {
@@ -69,6 +79,19 @@ export interface TenantCondition {
   observed_generation: number | null
 }
 
+export interface ConditionTransitionItem {
+  type: string
+  previous_status: string | null
+  status: string
+  reason: string
+  message: string
+  time: string
+}
+
+export interface TenantConditionHistoryResponse {
+  transitions: ConditionTransitionItem[]
+}
+
 export interface TenantStatusSummary {
   current_state: string
   ready: boolean
@@ -87,6 +110,8 @@ export interface TenantDetailsResponse {
   pools: PoolInfo[]
   state: string
   status_summary: TenantStatusSummary
+  ready_replicas?: number
+  health?: TenantHealth | null
   conditions: TenantCondition[]
   next_actions: string[]
   provisioning?: ProvisioningStatus
@@ -164,11 +189,14 @@ export interface CreateSecurityContextRequest {
   runAsNonRoot?: boolean
 }
 
+export type TenantProfile = "production" | "standalone"
+
 export interface CreateTenantRequest {
   name: string
   namespace: string
   pools: CreatePoolRequest[]
   image?: string
+  profile?: TenantProfile
   mount_path?: string
   creds_secret?: string
   policies?: ProvisioningPolicy[]
@@ -223,6 +251,7 @@ export interface PoolDetails {
   current_revision: string | null
   update_revision: string | null
   state: string
+  paused: boolean
   lifecycle_state: string | null
   workload_state: string | null
   decommission_phase: string | null
@@ -283,6 +312,27 @@ export interface PoolDecommissionRequestResponse {
   action: string
 }
 
+export interface PoolRolloutResponse {
+  pool: string
+  statefulset: string
+  desired_replicas: number
+  replicas: number
+  ready_replicas: number
+  updated_replicas: number
+  current_revision: string | null
+  update_revision: string | null
+  partition: number | null
+  generation_observed: boolean
+  complete: boolean
+  progress_percent: number
+}
+
+export interface RolloutRestartPoolResponse {
+  success: boolean
+  message: string
+  restarted_at: string
+}
+
 // ----- Pod -----
 export interface PodListItem {
   name: string
@@ -374,6 +424,53 @@ export interface DeletePodResponse {
   message: string
 }
 
+// ----- Benchmark -----
+export type BenchmarkOperation = "put" | "get" | "mixed"
+
+export interface StartBenchmarkRequest {
+  operation?: BenchmarkOperation
+  object_size?: string
+  concurrency?: number
+  duration_seconds?: number
+}
+
+export interface BenchmarkParameters {
+  operation: BenchmarkOperation
+  object_size: string
+  concurrency: number
+  duration_seconds: number
+}
+
+export interface BenchmarkItem {
+  name: string
+  phase: "Pending" | "Running" | "Succeeded" | "Failed" | "Unknown"
+  parameters: BenchmarkParameters
+  started_at: string | null
+  finished_at: string | null
+}
+
+export interface BenchmarkListResponse {
+  benchmarks: BenchmarkItem[]
+}
+
+export interface BenchmarkDetailsResponse extends BenchmarkItem {
+  output: string | null
+}
+
+export interface DeleteBenchmarkResponse {
+  success: boolean
+  message: string
+}
+
+// ----- Console access -----
+export interface ConsoleAccessResponse {
+  access_key_id: string
+  secret_access_key: string
+  session_token: string
+  expiration: string
+  console_url: string
+}
+
 // ----- Event -----
 export interface EventItem {
   event_type: string
@@ -481,3 +578,19 @@ export interface ClusterResourcesResponse {
   allocatable_cpu: string
   allocatable_memory: string
 }
+
+export interface DashboardStorageSummary {
+  pools: number
+  servers: number
+  volumes: number
+  capacity: string
+  capacity_bytes: number
+}
+
+export interface DashboardResponse {
+  operator_version: string
+  tenants: TenantStateCountsResponse
+  storage: DashboardStorageSummary
+  cluster: ClusterResourcesResponse
+  recent_warnings: EventItem[]
+}
@@ -81,3 +81,34 @@ export interface TopologyOverviewResponse {
   namespaces: TopologyNamespace[]
   nodes: TopologyNode[]
 }
+
+export interface TenantTopologyPod {
+  name: string
+  phase: string
+  ready: boolean
+  node: string | null
+  zone: string | null
+}
+
+export interface TenantTopologyPool {
+  name: string
+  servers: number
+  pods: TenantTopologyPod[]
+}
+
+export interface TenantTopologyNode {
+  name: string
+  zone: string | null
+  ready: boolean
+  disk_pressure: boolean
+  unschedulable: boolean
+  pod_count: number
+}
+
+export interface TenantTopologyResponse {
+  name: string
+  namespace: string
+  pools: TenantTopologyPool[]
+  nodes: TenantTopologyNode[]
+  warnings: string[]
+}
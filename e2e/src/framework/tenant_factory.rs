@@ -117,6 +117,7 @@ impl TenantTemplate {
                 }),
                 ..PersistenceConfig::default()
             },
+            paused: None,
             scheduling: SchedulingConfig {
                 node_selector: self.node_selector.clone(),
                 affinity: self.affinity.clone(),
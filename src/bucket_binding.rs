@@ -0,0 +1,950 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bucket bindings requested by application workloads.
+//!
+//! Off by default. With `OPERATOR_BUCKET_BINDING_ENABLED=true` the leader watches the metadata
+//! of Deployments and ServiceAccounts for the annotation
+//! `rustfs.com/bind-bucket: <tenant>/<bucket>` (or `<tenant-namespace>/<tenant>/<bucket>`). For
+//! each binding it creates the bucket on the Tenant, a RustFS user whose policy only allows that
+//! bucket, and a Secret `<workload>-<kind>-rustfs-bucket` in the workload namespace holding the
+//! `AWS_*` variables and `BUCKET_NAME`. Deployments also get the Secret added to `envFrom` of
+//! every container; ServiceAccount bindings only receive the Secret. Failed bindings are retried
+//! every `OPERATOR_BUCKET_BINDING_INTERVAL_SECONDS`.
+//!
+//! A Tenant serves bindings from its own namespace. Other namespaces must be listed in the
+//! Tenant annotation `rustfs.com/bucket-binding-namespaces` (comma-separated, or `*`).
+//!
+//! A binding only gets access to a bucket it created. The creating workload is recorded in the
+//! ConfigMap `<tenant>-bucket-bindings` in the Tenant namespace; a bucket that already exists, or
+//! that another workload created, is refused. An existing Secret with the binding Secret name is
+//! only overwritten when it is a binding Secret of the same workload.
+//!
+//! The result is written to the workload annotation `rustfs.com/bucket-binding-status`. The
+//! Secret is owned by the workload and is deleted with it; the bucket and the RustFS user are
+//! kept, so removing a binding never deletes data.
+
+use crate::reconcile::maintenance::MaintenanceConfig;
+use crate::sts::rustfs_client::{CreateBucketResult, RustfsAdminClient, RustfsClientError};
+use crate::tenant_monitor;
+use crate::types::v1alpha1::tenant::Tenant;
+use futures::StreamExt;
+use k8s_openapi::ByteString;
+use k8s_openapi::api::apps::v1 as appsv1;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use kube::api::{Patch, PatchParams, PostParams};
+use kube::runtime::{WatchStreamExt, metadata_watcher, watcher};
+use kube::{Api, Client, Resource, ResourceExt};
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+use snafu::{ResultExt, Snafu};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// Workload annotation requesting a bucket: `<tenant>/<bucket>` or `<namespace>/<tenant>/<bucket>`.
+pub const BIND_BUCKET_ANNOTATION: &str = "rustfs.com/bind-bucket";
+/// Workload annotation with the outcome of the last binding attempt.
+pub const BINDING_STATUS_ANNOTATION: &str = "rustfs.com/bucket-binding-status";
+/// Tenant annotation listing other namespaces whose workloads may bind buckets.
+pub const BINDING_NAMESPACES_ANNOTATION: &str = "rustfs.com/bucket-binding-namespaces";
+/// Label on binding Secrets.
+pub const BINDING_LABEL: &str = "rustfs.com/bucket-binding";
+
+const DEFAULT_BINDING_INTERVAL: Duration = Duration::from_secs(30);
+const FIELD_MANAGER: &str = "rustfs-operator-bucket-binding";
+const DEFAULT_REGION: &str = "us-east-1";
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub(crate) enum Error {
+    #[snafu(display("{}", message))]
+    Invalid { message: String },
+
+    #[snafu(display("Kubernetes API error while {}: {}", action, source))]
+    Kube {
+        action: String,
+        source: Box<kube::Error>,
+    },
+
+    #[snafu(display("cannot reach tenant {}: {}", tenant, message))]
+    TenantClient { tenant: String, message: String },
+
+    #[snafu(display("RustFS admin API error while {}: {}", action, source))]
+    Admin {
+        action: String,
+        #[snafu(source(from(RustfsClientError, Box::new)))]
+        source: Box<RustfsClientError>,
+    },
+}
+
+/// Parsed value of [`BIND_BUCKET_ANNOTATION`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BindingTarget {
+    pub tenant_namespace: String,
+    pub tenant: String,
+    pub bucket: String,
+}
+
+impl BindingTarget {
+    pub(crate) fn parse(value: &str, workload_namespace: &str) -> Result<Self, Error> {
+        let parts: Vec<&str> = value.trim().split('/').map(str::trim).collect();
+        let (tenant_namespace, tenant, bucket) = match parts.as_slice() {
+            [tenant, bucket] => (workload_namespace, *tenant, *bucket),
+            [namespace, tenant, bucket] => (*namespace, *tenant, *bucket),
+            _ => {
+                return InvalidSnafu {
+                    message: format!(
+                        "{BIND_BUCKET_ANNOTATION} must be <tenant>/<bucket> or <namespace>/<tenant>/<bucket>, got {value:?}"
+                    ),
+                }
+                .fail();
+            }
+        };
+        if tenant_namespace.is_empty() || tenant.is_empty() {
+            return InvalidSnafu {
+                message: format!("{BIND_BUCKET_ANNOTATION} names an empty tenant or namespace"),
+            }
+            .fail();
+        }
+        crate::reconcile::provisioning::validate_bucket_name(bucket)
+            .map_err(|message| Error::Invalid { message })?;
+        Ok(Self {
+            tenant_namespace: tenant_namespace.to_string(),
+            tenant: tenant.to_string(),
+            bucket: bucket.to_string(),
+        })
+    }
+}
+
+/// Credentials of one bucket binding, as written to the binding Secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BucketCredentials {
+    pub access_key: String,
+    pub secret_key: String,
+    pub endpoint: String,
+    pub bucket: String,
+}
+
+impl BucketCredentials {
+    fn secret_data(&self) -> BTreeMap<String, ByteString> {
+        [
+            ("AWS_ACCESS_KEY_ID", self.access_key.as_str()),
+            ("AWS_SECRET_ACCESS_KEY", self.secret_key.as_str()),
+            ("AWS_ENDPOINT_URL", self.endpoint.as_str()),
+            ("AWS_REGION", DEFAULT_REGION),
+            ("BUCKET_NAME", self.bucket.as_str()),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), ByteString(value.as_bytes().to_vec())))
+        .collect()
+    }
+}
+
+pub fn is_enabled() -> bool {
+    tenant_monitor::env_bool("OPERATOR_BUCKET_BINDING_ENABLED", false)
+}
+
+pub fn interval() -> Duration {
+    match std::env::var("OPERATOR_BUCKET_BINDING_INTERVAL_SECONDS") {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(seconds) if seconds > 0 => Duration::from_secs(seconds),
+            Ok(_) | Err(_) => {
+                warn!(
+                    value,
+                    "invalid OPERATOR_BUCKET_BINDING_INTERVAL_SECONDS value, using default"
+                );
+                DEFAULT_BINDING_INTERVAL
+            }
+        },
+        Err(_) => DEFAULT_BINDING_INTERVAL,
+    }
+}
+
+/// Annotated workload seen by the watch: kind, namespace, and name.
+type WorkloadRef = (&'static str, String, String);
+
+pub async fn run(client: Client, maintenance: MaintenanceConfig, cancel: CancellationToken) {
+    let interval = interval();
+    info!(
+        retry_interval_seconds = interval.as_secs(),
+        "bucket binding started"
+    );
+    let deployments = annotated_workloads::<appsv1::Deployment>(&client);
+    let accounts = annotated_workloads::<corev1::ServiceAccount>(&client);
+    let mut workloads = std::pin::pin!(futures::stream::select(deployments, accounts));
+    let mut retry = tokio::time::interval(interval);
+    retry.tick().await;
+    let mut failed: BTreeSet<WorkloadRef> = BTreeSet::new();
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                info!("bucket binding cancellation requested");
+                break;
+            }
+            workload = workloads.next() => {
+                let Some(workload) = workload else {
+                    warn!("bucket binding watch ended");
+                    break;
+                };
+                // Held for the retry tick, which binds it once maintenance mode ends.
+                if !maintenance.allows_changes(&client, "bucket-binding").await {
+                    failed.insert(workload);
+                } else if bind_named(&client, &workload).await {
+                    failed.remove(&workload);
+                } else {
+                    failed.insert(workload);
+                }
+            }
+            _ = retry.tick() => {
+                if failed.is_empty()
+                    || !maintenance.allows_changes(&client, "bucket-binding").await
+                {
+                    continue;
+                }
+                for workload in std::mem::take(&mut failed) {
+                    if !bind_named(&client, &workload).await {
+                        failed.insert(workload);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Metadata watch of every `K`, yielding the workloads that carry [`BIND_BUCKET_ANNOTATION`].
+fn annotated_workloads<K: BindableWorkload + Send + Sync + 'static>(
+    client: &Client,
+) -> impl futures::Stream<Item = WorkloadRef> + Send {
+    metadata_watcher(Api::<K>::all(client.clone()), watcher::Config::default())
+        .default_backoff()
+        .touched_objects()
+        .filter_map(|event| async move {
+            match event {
+                Ok(workload) if requests_binding(&workload) => Some((
+                    <K as k8s_openapi::Resource>::KIND,
+                    workload.namespace()?,
+                    workload.name_any(),
+                )),
+                Ok(_) => None,
+                Err(error) => {
+                    warn!(%error, kind = <K as k8s_openapi::Resource>::KIND, "bucket binding watch failed");
+                    None
+                }
+            }
+        })
+}
+
+fn requests_binding<K: ResourceExt>(workload: &K) -> bool {
+    workload.annotations().contains_key(BIND_BUCKET_ANNOTATION)
+}
+
+/// Reads the workload and binds it. Returns `false` when the binding should be retried.
+async fn bind_named(client: &Client, (kind, namespace, name): &WorkloadRef) -> bool {
+    match *kind {
+        "Deployment" => bind_current::<appsv1::Deployment>(client, namespace, name).await,
+        "ServiceAccount" => bind_current::<corev1::ServiceAccount>(client, namespace, name).await,
+        _ => true,
+    }
+}
+
+async fn bind_current<K: BindableWorkload>(client: &Client, namespace: &str, name: &str) -> bool {
+    match Api::<K>::namespaced(client.clone(), namespace)
+        .get_opt(name)
+        .await
+    {
+        Ok(Some(workload)) if requests_binding(&workload) => bind_workload(client, &workload).await,
+        Ok(_) => true,
+        Err(error) => {
+            warn!(workload = %name, namespace, %error, "bucket binding failed reading workload");
+            false
+        }
+    }
+}
+
+/// Workloads that can request a bucket binding.
+trait BindableWorkload:
+    Resource<DynamicType = (), Scope = k8s_openapi::NamespaceResourceScope>
+    + k8s_openapi::Resource
+    + Clone
+    + serde::de::DeserializeOwned
+    + std::fmt::Debug
+{
+    /// Merge patch adding `secret` to the workload environment, or `None` when nothing changes.
+    fn env_from_patch(&self, secret: &str) -> Option<serde_json::Value>;
+}
+
+impl BindableWorkload for appsv1::Deployment {
+    fn env_from_patch(&self, secret: &str) -> Option<serde_json::Value> {
+        deployment_env_from_patch(self, secret)
+    }
+}
+
+impl BindableWorkload for corev1::ServiceAccount {
+    fn env_from_patch(&self, _secret: &str) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+/// Binds one workload and records the outcome. Returns `false` when the binding failed.
+async fn bind_workload<K: BindableWorkload>(client: &Client, workload: &K) -> bool {
+    let Some(namespace) = workload.namespace() else {
+        return true;
+    };
+    let kind = K::kind(&()).to_ascii_lowercase();
+    let name = workload.name_any();
+    let (bound, status) = match bind(client, workload, &namespace, &kind, &name).await {
+        Ok(status) => (true, status),
+        Err(error) => {
+            warn!(workload = %name, kind, namespace, %error, "bucket binding failed");
+            (false, format!("Error: {error}"))
+        }
+    };
+    if workload.annotations().get(BINDING_STATUS_ANNOTATION) == Some(&status) {
+        return bound;
+    }
+    let patch = serde_json::json!({
+        "metadata": { "annotations": { BINDING_STATUS_ANNOTATION: status } }
+    });
+    if let Err(error) = Api::<K>::namespaced(client.clone(), &namespace)
+        .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        warn!(workload = %name, kind, namespace, %error, "failed to record bucket binding status");
+    }
+    bound
+}
+
+async fn bind<K: BindableWorkload>(
+    client: &Client,
+    workload: &K,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+) -> Result<String, Error> {
+    let annotation = workload
+        .annotations()
+        .get(BIND_BUCKET_ANNOTATION)
+        .cloned()
+        .unwrap_or_default();
+    let target = BindingTarget::parse(&annotation, namespace)?;
+
+    let tenant = Api::<Tenant>::namespaced(client.clone(), &target.tenant_namespace)
+        .get(&target.tenant)
+        .await
+        .map_err(|source| Error::Kube {
+            action: format!(
+                "reading Tenant {}/{}",
+                target.tenant_namespace, target.tenant
+            ),
+            source: Box::new(source),
+        })?;
+    if !binding_allowed(&tenant, namespace) {
+        return InvalidSnafu {
+            message: format!(
+                "Tenant {}/{} does not allow bucket bindings from namespace {namespace}; list it in {BINDING_NAMESPACES_ANNOTATION}",
+                target.tenant_namespace, target.tenant
+            ),
+        }
+        .fail();
+    }
+
+    let secret_name = binding_secret_name(name, kind);
+    let secrets = Api::<corev1::Secret>::namespaced(client.clone(), namespace);
+    let existing = secrets
+        .get_opt(&secret_name)
+        .await
+        .map_err(|source| Error::Kube {
+            action: format!("reading Secret {namespace}/{secret_name}"),
+            source: Box::new(source),
+        })?;
+
+    // A Secret of that name that the binding did not write for this workload is left alone.
+    if let Some(secret) = &existing
+        && !is_binding_secret_of(secret, workload)
+    {
+        return InvalidSnafu {
+            message: format!(
+                "Secret {namespace}/{secret_name} already exists and is not a bucket binding Secret of this {kind}"
+            ),
+        }
+        .fail();
+    }
+
+    // The Secret records the binding it was issued for; the RustFS side is only touched when the
+    // binding is new or its target changed.
+    let bound = existing.as_ref().is_some_and(|secret| {
+        secret.annotations().get(BIND_BUCKET_ANNOTATION) == Some(&annotation)
+            && secret_value(secret, "AWS_SECRET_ACCESS_KEY").is_some()
+    });
+    if !bound {
+        let access_key = binding_access_key(namespace, kind, name);
+        let secret_key = existing
+            .as_ref()
+            .and_then(|secret| secret_value(secret, "AWS_SECRET_ACCESS_KEY"))
+            .map_or_else(generate_secret_key, Ok)?;
+        let admin = admin_client(client, &tenant).await?;
+        claim_bucket(
+            client,
+            &admin,
+            &tenant,
+            &target,
+            &binding_owner(namespace, kind, name),
+        )
+        .await?;
+        grant_bucket_access(&admin, &target.bucket, &access_key, &secret_key).await?;
+
+        let credentials = BucketCredentials {
+            access_key,
+            secret_key,
+            endpoint: tenant_endpoint(&tenant, &target.tenant_namespace),
+            bucket: target.bucket.clone(),
+        };
+        let secret = binding_secret(workload, &secret_name, &annotation, &credentials);
+        secrets
+            .patch(
+                &secret_name,
+                &PatchParams::apply(FIELD_MANAGER).force(),
+                &Patch::Apply(&secret),
+            )
+            .await
+            .map_err(|source| Error::Kube {
+                action: format!("applying Secret {namespace}/{secret_name}"),
+                source: Box::new(source),
+            })?;
+        info!(workload = %name, kind, namespace, bucket = %target.bucket, tenant = %target.tenant, "bucket binding provisioned");
+    }
+
+    if let Some(patch) = workload.env_from_patch(&secret_name) {
+        Api::<K>::namespaced(client.clone(), namespace)
+            .patch(name, &PatchParams::default(), &Patch::Strategic(&patch))
+            .await
+            .map_err(|source| Error::Kube {
+                action: format!("adding Secret {secret_name} to {kind} {namespace}/{name}"),
+                source: Box::new(source),
+            })?;
+        debug!(workload = %name, kind, namespace, "bucket binding Secret projected into envFrom");
+    }
+
+    Ok(format!(
+        "Bound: bucket {} on Tenant {}/{}, credentials in Secret {secret_name}",
+        target.bucket, target.tenant_namespace, target.tenant
+    ))
+}
+
+/// Ensures `target.bucket` belongs to the binding `owner`, creating it when nobody owns it.
+///
+/// Ownership is recorded in [`ownership_config_map_name`] only after RustFS reports the bucket
+/// as newly created, so a binding never gains access to a bucket that existed before it.
+async fn claim_bucket(
+    client: &Client,
+    admin: &RustfsAdminClient,
+    tenant: &Tenant,
+    target: &BindingTarget,
+    owner: &str,
+) -> Result<(), Error> {
+    let bucket = &target.bucket;
+    let config_map_name = ownership_config_map_name(&target.tenant);
+    let config_maps =
+        Api::<corev1::ConfigMap>::namespaced(client.clone(), &target.tenant_namespace);
+    let ownership = config_maps
+        .get_opt(&config_map_name)
+        .await
+        .map_err(|source| Error::Kube {
+            action: format!(
+                "reading ConfigMap {}/{config_map_name}",
+                target.tenant_namespace
+            ),
+            source: Box::new(source),
+        })?;
+    let recorded = ownership
+        .as_ref()
+        .and_then(|config_map| config_map.data.as_ref())
+        .and_then(|data| data.get(bucket));
+    if let Some(recorded) = recorded
+        && recorded != owner
+    {
+        return InvalidSnafu {
+            message: format!("bucket {bucket} belongs to the bucket binding of {recorded}"),
+        }
+        .fail();
+    }
+
+    let outcome = admin
+        .create_bucket(bucket, None, false)
+        .await
+        .context(AdminSnafu {
+            action: format!("creating bucket {bucket}"),
+        })?;
+    match (outcome, recorded) {
+        // Owned already; an owned bucket deleted out of band is simply recreated.
+        (_, Some(_)) => Ok(()),
+        (CreateBucketResult::AlreadyExists, None) => InvalidSnafu {
+            message: format!(
+                "bucket {bucket} already exists and was not created by a bucket binding"
+            ),
+        }
+        .fail(),
+        (CreateBucketResult::Created, None) => {
+            let action = format!(
+                "recording bucket {bucket} in ConfigMap {}/{config_map_name}",
+                target.tenant_namespace
+            );
+            let result = match ownership {
+                Some(_) => config_maps
+                    .patch(
+                        &config_map_name,
+                        &PatchParams::default(),
+                        &Patch::Merge(serde_json::json!({ "data": { bucket: owner } })),
+                    )
+                    .await
+                    .map(|_| ()),
+                None => config_maps
+                    .create(
+                        &PostParams::default(),
+                        &ownership_config_map(tenant, &config_map_name, bucket, owner),
+                    )
+                    .await
+                    .map(|_| ()),
+            };
+            result.map_err(|source| Error::Kube {
+                action,
+                source: Box::new(source),
+            })
+        }
+    }
+}
+
+/// Creates or replaces the user `access_key` with a policy that only allows `bucket`.
+pub(crate) async fn grant_bucket_access(
+    admin: &RustfsAdminClient,
+    bucket: &str,
+    access_key: &str,
+    secret_key: &str,
+) -> Result<(), Error> {
+    admin
+        .add_canned_policy(access_key, &bucket_policy(bucket))
+        .await
+        .context(AdminSnafu {
+            action: format!("writing policy {access_key}"),
+        })?;
+    admin
+        .add_user(access_key, secret_key)
+        .await
+        .context(AdminSnafu {
+            action: format!("creating user {access_key}"),
+        })?;
+    admin
+        .set_user_policy(access_key, &[access_key.to_string()])
+        .await
+        .context(AdminSnafu {
+            action: format!("attaching policy to user {access_key}"),
+        })?;
+    Ok(())
+}
+
+/// Admin client for `tenant`, signed with its `credsSecret`.
+pub(crate) async fn admin_client(
+    client: &Client,
+    tenant: &Tenant,
+) -> Result<RustfsAdminClient, Error> {
+    tenant_monitor::tenant_admin_client(client, tenant)
+        .await
+        .map_err(|error| Error::TenantClient {
+            tenant: tenant.name_any(),
+            message: error.to_string(),
+        })
+}
+
+/// IAM policy allowing every S3 action on `bucket` and its objects, and nothing else.
+pub(crate) fn bucket_policy(bucket: &str) -> String {
+    serde_json::json!({
+        "Version": "2012-10-17",
+        "Statement": [{
+            "Effect": "Allow",
+            "Action": ["s3:*"],
+            "Resource": [
+                format!("arn:aws:s3:::{bucket}"),
+                format!("arn:aws:s3:::{bucket}/*"),
+            ],
+        }],
+    })
+    .to_string()
+}
+
+/// Whether workloads in `namespace` may bind buckets on `tenant`.
+fn binding_allowed(tenant: &Tenant, namespace: &str) -> bool {
+    if tenant.metadata.namespace.as_deref() == Some(namespace) {
+        return true;
+    }
+    tenant
+        .annotations()
+        .get(BINDING_NAMESPACES_ANNOTATION)
+        .is_some_and(|allowed| {
+            allowed
+                .split(',')
+                .map(str::trim)
+                .any(|allowed| allowed == "*" || allowed == namespace)
+        })
+}
+
+/// Stable RustFS access key for one workload, so repeated bindings reuse the same user.
+pub(crate) fn binding_access_key(namespace: &str, kind: &str, name: &str) -> String {
+    let digest = Sha256::digest(format!("{namespace}/{kind}/{name}"));
+    format!("bind-{}", &hex::encode(digest)[..16])
+}
+
+/// Value recorded as the owner of a bucket a binding created.
+fn binding_owner(namespace: &str, kind: &str, name: &str) -> String {
+    format!("{namespace}/{kind}/{name}")
+}
+
+/// ConfigMap in the Tenant namespace mapping binding-created buckets to their workloads.
+fn ownership_config_map_name(tenant: &str) -> String {
+    format!("{tenant}-bucket-bindings")
+}
+
+fn ownership_config_map(
+    tenant: &Tenant,
+    name: &str,
+    bucket: &str,
+    owner: &str,
+) -> corev1::ConfigMap {
+    corev1::ConfigMap {
+        metadata: metav1::ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: tenant.metadata.namespace.clone(),
+            labels: Some(BTreeMap::from([(
+                BINDING_LABEL.to_string(),
+                "true".to_string(),
+            )])),
+            owner_references: tenant.controller_owner_ref(&()).map(|owner| vec![owner]),
+            ..Default::default()
+        },
+        data: Some(BTreeMap::from([(bucket.to_string(), owner.to_string())])),
+        ..Default::default()
+    }
+}
+
+/// Whether `secret` is the binding Secret this module wrote for `workload`.
+fn is_binding_secret_of<K: BindableWorkload>(secret: &corev1::Secret, workload: &K) -> bool {
+    let Some(uid) = workload.meta().uid.as_deref() else {
+        return false;
+    };
+    secret.labels().get(BINDING_LABEL).map(String::as_str) == Some("true")
+        && secret
+            .owner_references()
+            .iter()
+            .any(|owner| owner.uid == uid)
+}
+
+fn binding_secret_name(name: &str, kind: &str) -> String {
+    format!("{name}-{kind}-rustfs-bucket")
+}
+
+pub(crate) fn generate_secret_key() -> Result<String, Error> {
+    let mut bytes = [0u8; 20];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| Error::Invalid {
+            message: "failed to generate a secret key".to_string(),
+        })?;
+    Ok(hex::encode(bytes))
+}
+
+fn secret_value(secret: &corev1::Secret, key: &str) -> Option<String> {
+    secret
+        .data
+        .as_ref()?
+        .get(key)
+        .and_then(|value| String::from_utf8(value.0.clone()).ok())
+        .filter(|value| !value.is_empty())
+}
+
+/// In-cluster S3 endpoint of the Tenant.
+pub(crate) fn tenant_endpoint(tenant: &Tenant, namespace: &str) -> String {
+    let scheme = if tenant.spec.tls.as_ref().is_some_and(|tls| tls.is_enabled()) {
+        "https"
+    } else {
+        "http"
+    };
+    let service = tenant.new_io_service().metadata.name.unwrap_or_default();
+    format!(
+        "{}://{}.{}.svc:{}",
+        scheme,
+        service,
+        namespace,
+        tenant.s3_port()
+    )
+}
+
+fn binding_secret<K: BindableWorkload>(
+    workload: &K,
+    name: &str,
+    annotation: &str,
+    credentials: &BucketCredentials,
+) -> corev1::Secret {
+    corev1::Secret {
+        metadata: metav1::ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: workload.namespace(),
+            labels: Some(BTreeMap::from([
+                (BINDING_LABEL.to_string(), "true".to_string()),
+                (
+                    "app.kubernetes.io/managed-by".to_string(),
+                    "rustfs-operator".to_string(),
+                ),
+            ])),
+            annotations: Some(BTreeMap::from([(
+                BIND_BUCKET_ANNOTATION.to_string(),
+                annotation.to_string(),
+            )])),
+            owner_references: workload.controller_owner_ref(&()).map(|mut owner| {
+                owner.controller = Some(false);
+                owner.block_owner_deletion = Some(false);
+                vec![owner]
+            }),
+            ..Default::default()
+        },
+        type_: Some("Opaque".to_string()),
+        data: Some(credentials.secret_data()),
+        ..Default::default()
+    }
+}
+
+/// Patch adding `secret` to `envFrom` of every container that does not reference it yet.
+fn deployment_env_from_patch(
+    deployment: &appsv1::Deployment,
+    secret: &str,
+) -> Option<serde_json::Value> {
+    let containers = &deployment.spec.as_ref()?.template.spec.as_ref()?.containers;
+    let patched: Vec<serde_json::Value> = containers
+        .iter()
+        .filter(|container| {
+            !container.env_from.iter().flatten().any(|source| {
+                source
+                    .secret_ref
+                    .as_ref()
+                    .is_some_and(|secret_ref| secret_ref.name == secret)
+            })
+        })
+        .map(|container| {
+            let mut env_from = container.env_from.clone().unwrap_or_default();
+            env_from.push(corev1::EnvFromSource {
+                secret_ref: Some(corev1::SecretEnvSource {
+                    name: secret.to_string(),
+                    optional: Some(true),
+                }),
+                ..Default::default()
+            });
+            serde_json::json!({ "name": container.name, "envFrom": env_from })
+        })
+        .collect();
+    (!patched.is_empty()).then(|| {
+        serde_json::json!({
+            "spec": { "template": { "spec": { "containers": patched } } }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment(env_from: Option<Vec<corev1::EnvFromSource>>) -> appsv1::Deployment {
+        appsv1::Deployment {
+            metadata: metav1::ObjectMeta {
+                name: Some("web".to_string()),
+                namespace: Some("apps".to_string()),
+                uid: Some("uid-1".to_string()),
+                ..Default::default()
+            },
+            spec: Some(appsv1::DeploymentSpec {
+                template: corev1::PodTemplateSpec {
+                    spec: Some(corev1::PodSpec {
+                        containers: vec![corev1::Container {
+                            name: "app".to_string(),
+                            env_from,
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn binding_target_defaults_to_the_workload_namespace() {
+        assert_eq!(
+            BindingTarget::parse("storage/uploads", "apps").unwrap(),
+            BindingTarget {
+                tenant_namespace: "apps".to_string(),
+                tenant: "storage".to_string(),
+                bucket: "uploads".to_string(),
+            }
+        );
+        assert_eq!(
+            BindingTarget::parse("rustfs/storage/uploads", "apps")
+                .unwrap()
+                .tenant_namespace,
+            "rustfs"
+        );
+        assert!(BindingTarget::parse("uploads", "apps").is_err());
+        assert!(BindingTarget::parse("/storage/uploads", "apps").is_err());
+        assert!(BindingTarget::parse("storage/Uploads", "apps").is_err());
+    }
+
+    #[test]
+    fn other_namespaces_need_the_tenant_opt_in() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let namespace = tenant.metadata.namespace.clone().unwrap();
+        assert!(binding_allowed(&tenant, &namespace));
+        assert!(!binding_allowed(&tenant, "apps"));
+
+        tenant.metadata.annotations = Some(BTreeMap::from([(
+            BINDING_NAMESPACES_ANNOTATION.to_string(),
+            "team-a, apps".to_string(),
+        )]));
+        assert!(binding_allowed(&tenant, "apps"));
+        assert!(!binding_allowed(&tenant, "team-b"));
+
+        tenant.metadata.annotations = Some(BTreeMap::from([(
+            BINDING_NAMESPACES_ANNOTATION.to_string(),
+            "*".to_string(),
+        )]));
+        assert!(binding_allowed(&tenant, "team-b"));
+    }
+
+    #[test]
+    fn access_key_is_stable_per_workload() {
+        let key = binding_access_key("apps", "deployment", "web");
+        assert_eq!(key, binding_access_key("apps", "deployment", "web"));
+        assert_ne!(key, binding_access_key("apps", "serviceaccount", "web"));
+        assert!(key.starts_with("bind-"));
+        assert_eq!(key.len(), 21);
+    }
+
+    #[test]
+    fn bucket_policy_is_scoped_to_the_bucket() {
+        let policy: serde_json::Value = serde_json::from_str(&bucket_policy("uploads")).unwrap();
+        assert_eq!(
+            policy["Statement"][0]["Resource"],
+            serde_json::json!(["arn:aws:s3:::uploads", "arn:aws:s3:::uploads/*"])
+        );
+    }
+
+    #[test]
+    fn env_from_is_added_once() {
+        let patch =
+            deployment_env_from_patch(&deployment(None), "web-deployment-rustfs-bucket").unwrap();
+        assert_eq!(
+            patch["spec"]["template"]["spec"]["containers"][0]["envFrom"][0]["secretRef"]["name"],
+            "web-deployment-rustfs-bucket"
+        );
+
+        let bound = deployment(Some(vec![corev1::EnvFromSource {
+            secret_ref: Some(corev1::SecretEnvSource {
+                name: "web-deployment-rustfs-bucket".to_string(),
+                optional: Some(true),
+            }),
+            ..Default::default()
+        }]));
+        assert_eq!(
+            deployment_env_from_patch(&bound, "web-deployment-rustfs-bucket"),
+            None
+        );
+    }
+
+    #[test]
+    fn binding_secret_is_owned_by_the_workload() {
+        let workload = deployment(None);
+        let credentials = BucketCredentials {
+            access_key: "bind-0123456789abcdef".to_string(),
+            secret_key: generate_secret_key().unwrap(),
+            endpoint: "http://storage-io.apps.svc:9000".to_string(),
+            bucket: "uploads".to_string(),
+        };
+        let secret = binding_secret(
+            &workload,
+            "web-deployment-rustfs-bucket",
+            "storage/uploads",
+            &credentials,
+        );
+
+        let owner = &secret.metadata.owner_references.as_ref().unwrap()[0];
+        assert_eq!(owner.kind, "Deployment");
+        assert_eq!(owner.uid, "uid-1");
+        let data = secret.data.as_ref().unwrap();
+        assert_eq!(data["BUCKET_NAME"].0, b"uploads");
+        assert_eq!(data["AWS_SECRET_ACCESS_KEY"].0.len(), 40);
+        assert_eq!(
+            secret.metadata.annotations.as_ref().unwrap()[BIND_BUCKET_ANNOTATION],
+            "storage/uploads"
+        );
+        assert!(is_binding_secret_of(&secret, &workload));
+    }
+
+    #[test]
+    fn foreign_secrets_are_not_binding_secrets() {
+        let workload = deployment(None);
+        let mut secret = corev1::Secret {
+            metadata: metav1::ObjectMeta {
+                name: Some("web-deployment-rustfs-bucket".to_string()),
+                labels: Some(BTreeMap::from([(
+                    BINDING_LABEL.to_string(),
+                    "true".to_string(),
+                )])),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(!is_binding_secret_of(&secret, &workload));
+
+        secret.metadata.owner_references =
+            workload.controller_owner_ref(&()).map(|owner| vec![owner]);
+        assert!(is_binding_secret_of(&secret, &workload));
+
+        secret.metadata.labels = None;
+        assert!(!is_binding_secret_of(&secret, &workload));
+    }
+
+    #[test]
+    fn ownership_config_map_records_the_workload() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.uid = Some("tenant-uid".to_string());
+        let owner = binding_owner("apps", "deployment", "web");
+        let config_map = ownership_config_map(
+            &tenant,
+            &ownership_config_map_name(&tenant.name_any()),
+            "uploads",
+            &owner,
+        );
+
+        assert_eq!(
+            config_map.metadata.name.as_deref(),
+            Some(format!("{}-bucket-bindings", tenant.name_any()).as_str())
+        );
+        assert_eq!(config_map.data.unwrap()["uploads"], "apps/deployment/web");
+        assert_eq!(
+            config_map.metadata.owner_references.unwrap()[0].uid,
+            "tenant-uid"
+        );
+    }
+}
@@ -0,0 +1,519 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tenant export/import bundles for disaster recovery.
+//!
+//! A bundle is a `v1/List` holding the Tenant, the Secrets it references, and the Services and
+//! PersistentVolumeClaims labelled with `rustfs.tenant`. Server-populated metadata, status, owner
+//! references and volume bindings are stripped so the bundle can be re-created in another cluster.
+
+use crate::types::v1alpha1::tenant::{Tenant, tenant_selector};
+use k8s_openapi::ByteString;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use kube::api::{ListParams, PostParams};
+use kube::{Api, Client, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use snafu::{ResultExt, Snafu};
+use std::collections::BTreeMap;
+use tracing::warn;
+
+/// Annotation set on Secrets whose values were removed from the bundle.
+pub const REDACTED_ANNOTATION: &str = "operator.rustfs.com/redacted";
+
+/// Metadata annotations written by Kubernetes controllers that must not be copied to a new cluster.
+const SERVER_ANNOTATION_PREFIXES: &[&str] = &[
+    "kubectl.kubernetes.io/last-applied-configuration",
+    "pv.kubernetes.io/",
+    "volume.kubernetes.io/",
+    "volume.beta.kubernetes.io/",
+];
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Kubernetes API error while {}: {}", action, source))]
+    Kube {
+        action: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+
+    #[snafu(display("failed to serialize bundle: {}", source))]
+    Serialize { source: serde_json::Error },
+
+    #[snafu(display("failed to render bundle YAML: {}", source))]
+    RenderYaml { source: serde_yaml_ng::Error },
+
+    #[snafu(display("failed to parse bundle YAML: {}", source))]
+    ParseYaml { source: serde_yaml_ng::Error },
+
+    #[snafu(display("invalid {} '{}' in bundle: {}", kind, name, source))]
+    InvalidItem {
+        kind: String,
+        name: String,
+        source: serde_json::Error,
+    },
+
+    #[snafu(display("bundle has unsupported kind '{}'", kind))]
+    UnsupportedKind { kind: String },
+
+    #[snafu(display("bundle must contain exactly one Tenant (found {})", count))]
+    TenantCount { count: usize },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug, Default)]
+pub struct ExportOptions {
+    /// Replace Secret values with empty strings and mark the Secret as redacted.
+    pub redact_secrets: bool,
+}
+
+/// Objects that make up a restorable Tenant.
+#[derive(Clone, Debug)]
+pub struct TenantBundle {
+    pub tenant: Tenant,
+    pub secrets: Vec<corev1::Secret>,
+    pub services: Vec<corev1::Service>,
+    pub persistent_volume_claims: Vec<corev1::PersistentVolumeClaim>,
+}
+
+/// Outcome of re-creating a bundle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// `Kind/name` of objects created.
+    pub created: Vec<String>,
+    /// `Kind/name` of objects that already existed and were left untouched.
+    pub skipped: Vec<String>,
+    /// Redacted Secrets that were not created and must be supplied before the Tenant is usable.
+    pub redacted_secrets: Vec<String>,
+}
+
+impl TenantBundle {
+    /// Collect a Tenant and its dependent objects from the cluster.
+    pub async fn export(
+        client: Client,
+        namespace: &str,
+        name: &str,
+        options: &ExportOptions,
+    ) -> Result<Self> {
+        let tenant = Api::<Tenant>::namespaced(client.clone(), namespace)
+            .get(name)
+            .await
+            .context(KubeSnafu {
+                action: format!("reading Tenant '{name}'"),
+            })?;
+
+        let secret_api = Api::<corev1::Secret>::namespaced(client.clone(), namespace);
+        let mut secrets = Vec::new();
+        for secret_name in tenant.referenced_secret_names() {
+            match secret_api.get_opt(&secret_name).await.context(KubeSnafu {
+                action: format!("reading Secret '{secret_name}'"),
+            })? {
+                Some(secret) => secrets.push(portable_secret(secret, options.redact_secrets)),
+                None => warn!(
+                    tenant = %name,
+                    namespace = %namespace,
+                    secret = %secret_name,
+                    "referenced Secret not found; leaving it out of the bundle"
+                ),
+            }
+        }
+
+        let selector = ListParams::default().labels(&tenant_selector(name));
+        let services = Api::<corev1::Service>::namespaced(client.clone(), namespace)
+            .list(&selector)
+            .await
+            .context(KubeSnafu {
+                action: "listing Services".to_string(),
+            })?
+            .items
+            .into_iter()
+            .map(portable_service)
+            .collect();
+        let persistent_volume_claims =
+            Api::<corev1::PersistentVolumeClaim>::namespaced(client, namespace)
+                .list(&selector)
+                .await
+                .context(KubeSnafu {
+                    action: "listing PersistentVolumeClaims".to_string(),
+                })?
+                .items
+                .into_iter()
+                .map(portable_persistent_volume_claim)
+                .collect();
+
+        Ok(Self {
+            tenant: portable_tenant(tenant),
+            secrets,
+            services,
+            persistent_volume_claims,
+        })
+    }
+
+    /// Render the bundle as a single `v1/List` YAML document.
+    pub fn to_yaml(&self) -> Result<String> {
+        let mut items = Vec::new();
+        for secret in &self.secrets {
+            items.push(serde_json::to_value(secret).context(SerializeSnafu)?);
+        }
+        for claim in &self.persistent_volume_claims {
+            items.push(serde_json::to_value(claim).context(SerializeSnafu)?);
+        }
+        for service in &self.services {
+            items.push(serde_json::to_value(service).context(SerializeSnafu)?);
+        }
+        items.push(serde_json::to_value(&self.tenant).context(SerializeSnafu)?);
+
+        let list = json!({
+            "apiVersion": "v1",
+            "kind": "List",
+            "items": items,
+        });
+        serde_yaml_ng::to_string(&list).context(RenderYamlSnafu)
+    }
+
+    /// Parse a bundle produced by [`TenantBundle::to_yaml`].
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let list: Value = serde_yaml_ng::from_str(yaml).context(ParseYamlSnafu)?;
+        let items = list
+            .get("items")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut tenants = Vec::new();
+        let mut secrets = Vec::new();
+        let mut services = Vec::new();
+        let mut persistent_volume_claims = Vec::new();
+        for item in items {
+            let kind = item
+                .get("kind")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            match kind.as_str() {
+                "Tenant" => tenants.push(parse_item::<Tenant>(item, &kind)?),
+                "Secret" => secrets.push(parse_item(item, &kind)?),
+                "Service" => services.push(parse_item(item, &kind)?),
+                "PersistentVolumeClaim" => persistent_volume_claims.push(parse_item(item, &kind)?),
+                _ => return UnsupportedKindSnafu { kind }.fail(),
+            }
+        }
+
+        let count = tenants.len();
+        let Some(tenant) = tenants.pop().filter(|_| count == 1) else {
+            return TenantCountSnafu { count }.fail();
+        };
+        Ok(Self {
+            tenant,
+            secrets,
+            services,
+            persistent_volume_claims,
+        })
+    }
+
+    /// Create the bundle's objects, optionally in a different namespace.
+    ///
+    /// Objects are created in dependency order (Secrets, PVCs, Services, Tenant). Objects that
+    /// already exist are left untouched, so an interrupted import can be re-run.
+    pub async fn import(mut self, client: Client, namespace: Option<&str>) -> Result<ImportReport> {
+        if let Some(namespace) = namespace {
+            self.set_namespace(namespace);
+        }
+
+        let mut report = ImportReport::default();
+        for secret in self.secrets {
+            if is_redacted(&secret) {
+                report.redacted_secrets.push(secret.name_any());
+                continue;
+            }
+            create_if_missing(&client, secret, &mut report).await?;
+        }
+        for claim in self.persistent_volume_claims {
+            create_if_missing(&client, claim, &mut report).await?;
+        }
+        for service in self.services {
+            create_if_missing(&client, service, &mut report).await?;
+        }
+        create_if_missing(&client, self.tenant, &mut report).await?;
+        Ok(report)
+    }
+
+    fn set_namespace(&mut self, namespace: &str) {
+        let namespace = Some(namespace.to_string());
+        self.tenant.metadata.namespace.clone_from(&namespace);
+        for secret in &mut self.secrets {
+            secret.metadata.namespace.clone_from(&namespace);
+        }
+        for service in &mut self.services {
+            service.metadata.namespace.clone_from(&namespace);
+        }
+        for claim in &mut self.persistent_volume_claims {
+            claim.metadata.namespace.clone_from(&namespace);
+        }
+    }
+}
+
+fn portable_metadata(metadata: metav1::ObjectMeta) -> metav1::ObjectMeta {
+    let annotations = metadata.annotations.map(|annotations| {
+        annotations
+            .into_iter()
+            .filter(|(key, _)| {
+                !SERVER_ANNOTATION_PREFIXES
+                    .iter()
+                    .any(|prefix| key.starts_with(prefix))
+            })
+            .collect::<BTreeMap<_, _>>()
+    });
+    metav1::ObjectMeta {
+        name: metadata.name,
+        namespace: metadata.namespace,
+        labels: metadata.labels.filter(|labels| !labels.is_empty()),
+        annotations: annotations.filter(|annotations| !annotations.is_empty()),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn portable_tenant(tenant: Tenant) -> Tenant {
+    let mut portable = Tenant::new(&tenant.name_any(), tenant.spec);
+    portable.metadata = portable_metadata(tenant.metadata);
+    portable
+}
+
+pub(crate) fn portable_secret(secret: corev1::Secret, redact: bool) -> corev1::Secret {
+    let mut metadata = portable_metadata(secret.metadata);
+    let mut data = secret.data;
+    if let Some(string_data) = secret.string_data {
+        data.get_or_insert_with(BTreeMap::new).extend(
+            string_data
+                .into_iter()
+                .map(|(key, value)| (key, ByteString(value.into_bytes()))),
+        );
+    }
+    if redact {
+        data = data.map(|data| {
+            data.into_keys()
+                .map(|key| (key, ByteString(Vec::new())))
+                .collect()
+        });
+        metadata
+            .annotations
+            .get_or_insert_with(BTreeMap::new)
+            .insert(REDACTED_ANNOTATION.to_string(), "true".to_string());
+    }
+    corev1::Secret {
+        metadata,
+        data,
+        immutable: secret.immutable,
+        type_: secret.type_,
+        ..Default::default()
+    }
+}
+
+fn portable_service(service: corev1::Service) -> corev1::Service {
+    let spec = service.spec.map(|spec| {
+        // Headless Services keep their explicit "None"; allocated addresses are dropped.
+        let headless = spec.cluster_ip.as_deref() == Some("None");
+        corev1::ServiceSpec {
+            cluster_ip: headless.then(|| "None".to_string()),
+            cluster_ips: None,
+            ..spec
+        }
+    });
+    corev1::Service {
+        metadata: portable_metadata(service.metadata),
+        spec,
+        status: None,
+    }
+}
+
+fn portable_persistent_volume_claim(
+    claim: corev1::PersistentVolumeClaim,
+) -> corev1::PersistentVolumeClaim {
+    let spec = claim.spec.map(|spec| corev1::PersistentVolumeClaimSpec {
+        // The bound PersistentVolume does not exist in the target cluster.
+        volume_name: None,
+        ..spec
+    });
+    corev1::PersistentVolumeClaim {
+        metadata: portable_metadata(claim.metadata),
+        spec,
+        status: None,
+    }
+}
+
+fn is_redacted(secret: &corev1::Secret) -> bool {
+    secret
+        .annotations()
+        .get(REDACTED_ANNOTATION)
+        .is_some_and(|value| value == "true")
+}
+
+fn parse_item<K: DeserializeOwned>(item: Value, kind: &str) -> Result<K> {
+    let name = item
+        .pointer("/metadata/name")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    serde_json::from_value(item).context(InvalidItemSnafu {
+        kind: kind.to_string(),
+        name,
+    })
+}
+
+async fn create_if_missing<K>(client: &Client, object: K, report: &mut ImportReport) -> Result<()>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + std::fmt::Debug
+        + serde::Serialize
+        + DeserializeOwned,
+    K::DynamicType: Default,
+{
+    let kind = K::kind(&K::DynamicType::default()).to_string();
+    let name = object.name_any();
+    let namespace = object.namespace().unwrap_or_else(|| "default".to_string());
+    let label = format!("{kind}/{name}");
+    match Api::<K>::namespaced(client.clone(), &namespace)
+        .create(&PostParams::default(), &object)
+        .await
+    {
+        Ok(_) => report.created.push(label),
+        Err(kube::Error::Api(response)) if response.code == 409 => report.skipped.push(label),
+        Err(source) => {
+            return Err(Error::Kube {
+                action: format!("creating {label}"),
+                source: Box::new(source),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::v1alpha1::credentials::CredsSecretReference;
+
+    fn secret(name: &str) -> corev1::Secret {
+        corev1::Secret {
+            metadata: metav1::ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                uid: Some("uid".to_string()),
+                resource_version: Some("42".to_string()),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([(
+                "accesskey".to_string(),
+                ByteString(b"rustfsadmin".to_vec()),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn referenced_secret_names_cover_credentials_users_and_kms() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.creds_secret = Some(CredsSecretReference::new("creds"));
+        tenant.spec.users = vec![crate::types::v1alpha1::provisioning::ProvisioningUser {
+            name: "app-user".to_string(),
+            ..Default::default()
+        }];
+
+        let names = tenant.referenced_secret_names();
+
+        assert_eq!(
+            names.into_iter().collect::<Vec<_>>(),
+            vec!["app-user".to_string(), "creds".to_string()]
+        );
+    }
+
+    #[test]
+    fn redacted_secrets_keep_keys_and_drop_values() {
+        let portable = portable_secret(secret("creds"), true);
+
+        assert!(is_redacted(&portable));
+        assert_eq!(portable.metadata.uid, None);
+        assert_eq!(portable.metadata.resource_version, None);
+        assert_eq!(
+            portable
+                .data
+                .map(|data| data.into_iter().collect::<Vec<_>>()),
+            Some(vec![("accesskey".to_string(), ByteString(Vec::new()))])
+        );
+    }
+
+    #[test]
+    fn bundle_round_trips_through_yaml() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.uid = Some("uid".to_string());
+        let headless = corev1::Service {
+            metadata: metav1::ObjectMeta {
+                name: Some("test-tenant-hl".to_string()),
+                ..Default::default()
+            },
+            spec: Some(corev1::ServiceSpec {
+                cluster_ip: Some("None".to_string()),
+                cluster_ips: Some(vec!["None".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let claim = corev1::PersistentVolumeClaim {
+            metadata: metav1::ObjectMeta {
+                name: Some("vol-0-test-tenant-pool-0-0".to_string()),
+                annotations: Some(BTreeMap::from([(
+                    "pv.kubernetes.io/bind-completed".to_string(),
+                    "yes".to_string(),
+                )])),
+                ..Default::default()
+            },
+            spec: Some(corev1::PersistentVolumeClaimSpec {
+                volume_name: Some("pvc-1234".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let bundle = TenantBundle {
+            tenant: portable_tenant(tenant),
+            secrets: vec![portable_secret(secret("creds"), false)],
+            services: vec![portable_service(headless)],
+            persistent_volume_claims: vec![portable_persistent_volume_claim(claim)],
+        };
+
+        let parsed = bundle
+            .to_yaml()
+            .and_then(|yaml| TenantBundle::from_yaml(&yaml));
+
+        assert!(parsed.is_ok_and(|parsed| {
+            parsed.tenant.name_any() == "test-tenant"
+                && parsed.tenant.metadata.uid.is_none()
+                && parsed.secrets.len() == 1
+                && parsed.services[0].spec.as_ref().is_some_and(|spec| {
+                    spec.cluster_ip.as_deref() == Some("None") && spec.cluster_ips.is_none()
+                })
+                && parsed.persistent_volume_claims[0]
+                    .spec
+                    .as_ref()
+                    .is_some_and(|spec| spec.volume_name.is_none())
+                && parsed.persistent_volume_claims[0]
+                    .metadata
+                    .annotations
+                    .is_none()
+        }));
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fault injection for resilience testing in staging clusters.
+//!
+//! Chaos mode is off unless the operator runs with `server --chaos` or
+//! `OPERATOR_CHAOS_ENABLED=true`, and then only affects the Tenants listed in
+//! `OPERATOR_CHAOS_TENANTS`. Applies and deletes made while reconciling such a Tenant are
+//! randomly delayed and may fail with a synthetic `500 ChaosInjected` API error before they
+//! reach the API server.
+//!
+//! Each Tenant draws its faults from its own stream seeded by `OPERATOR_CHAOS_SEED` and the
+//! Tenant key, so the same seed replays the same sequence of faults for that Tenant.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tracing::warn;
+
+pub const CHAOS_ENABLED_ENV: &str = "OPERATOR_CHAOS_ENABLED";
+pub const CHAOS_TENANTS_ENV: &str = "OPERATOR_CHAOS_TENANTS";
+pub const CHAOS_SEED_ENV: &str = "OPERATOR_CHAOS_SEED";
+pub const CHAOS_ERROR_RATE_ENV: &str = "OPERATOR_CHAOS_ERROR_RATE";
+pub const CHAOS_MAX_DELAY_MS_ENV: &str = "OPERATOR_CHAOS_MAX_DELAY_MS";
+
+/// Reason of the synthetic API errors.
+pub const CHAOS_ERROR_REASON: &str = "ChaosInjected";
+
+const DEFAULT_ERROR_RATE: f64 = 0.1;
+const DEFAULT_MAX_DELAY_MS: u64 = 2000;
+
+tokio::task_local! {
+    static TARGET: Option<ChaosTarget>;
+}
+
+/// Chaos mode settings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChaosConfig {
+    /// `namespace/name` of the affected Tenants; `namespace/*` selects a whole namespace.
+    pub tenants: Vec<String>,
+    /// Seed of the per-Tenant fault streams.
+    pub seed: u64,
+    /// Probability, from 0 to 1, that a write fails with a synthetic API error.
+    pub error_rate: f64,
+    /// Upper bound of the random delay added before each write.
+    pub max_delay: Duration,
+}
+
+impl ChaosConfig {
+    /// Reads the chaos settings when `flag` (`--chaos`) or [`CHAOS_ENABLED_ENV`] turns chaos
+    /// mode on. An empty Tenant list or an invalid value is an error, so chaos mode never
+    /// silently targets everything or nothing.
+    pub fn from_env(flag: bool) -> Result<Option<Self>, String> {
+        if !flag && !crate::tenant_monitor::env_bool(CHAOS_ENABLED_ENV, false) {
+            return Ok(None);
+        }
+        Self::parse(|name| std::env::var(name).ok()).map(Some)
+    }
+
+    fn parse(var: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let tenants: Vec<String> = var(CHAOS_TENANTS_ENV)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(str::to_string)
+            .collect();
+        if tenants.is_empty() {
+            return Err(format!(
+                "chaos mode requires {CHAOS_TENANTS_ENV} to list at least one namespace/name"
+            ));
+        }
+        if let Some(entry) = tenants.iter().find(|entry| !valid_tenant_entry(entry)) {
+            return Err(format!(
+                "{CHAOS_TENANTS_ENV} entry '{entry}' must be namespace/name or namespace/*"
+            ));
+        }
+
+        let seed = parse_var(&var, CHAOS_SEED_ENV, 0u64)?;
+        let error_rate = parse_var(&var, CHAOS_ERROR_RATE_ENV, DEFAULT_ERROR_RATE)?;
+        if !(0.0..=1.0).contains(&error_rate) {
+            return Err(format!(
+                "{CHAOS_ERROR_RATE_ENV} must be between 0 and 1 (got {error_rate})"
+            ));
+        }
+        let max_delay_ms = parse_var(&var, CHAOS_MAX_DELAY_MS_ENV, DEFAULT_MAX_DELAY_MS)?;
+
+        Ok(Self {
+            tenants,
+            seed,
+            error_rate,
+            max_delay: Duration::from_millis(max_delay_ms),
+        })
+    }
+
+    /// Whether chaos applies to the Tenant `namespace/name`.
+    pub fn selects(&self, namespace: &str, name: &str) -> bool {
+        self.tenants
+            .iter()
+            .any(|entry| match entry.split_once('/') {
+                Some((ns, "*")) => ns == namespace,
+                Some((ns, tenant)) => ns == namespace && tenant == name,
+                None => false,
+            })
+    }
+}
+
+fn valid_tenant_entry(entry: &str) -> bool {
+    matches!(
+        entry.split_once('/'),
+        Some((namespace, name)) if !namespace.is_empty() && !name.is_empty() && !name.contains('/')
+    )
+}
+
+fn parse_var<T: std::str::FromStr>(
+    var: &impl Fn(&str) -> Option<String>,
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    match var(name).as_deref().map(str::trim) {
+        None | Some("") => Ok(default),
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("{name} has an invalid value '{value}'")),
+    }
+}
+
+/// Chaos state shared by all reconciles: one fault stream per selected Tenant, kept across
+/// reconciles so the sequence continues where the previous reconcile stopped.
+pub(crate) struct Chaos {
+    config: ChaosConfig,
+    streams: Mutex<HashMap<String, Arc<Mutex<u64>>>>,
+}
+
+impl Chaos {
+    pub(crate) fn new(config: ChaosConfig) -> Self {
+        Self {
+            config,
+            streams: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn target(&self, namespace: &str, name: &str) -> Option<ChaosTarget> {
+        if !self.config.selects(namespace, name) {
+            return None;
+        }
+        let tenant = format!("{namespace}/{name}");
+        let stream = self
+            .streams
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(tenant.clone())
+            .or_insert_with(|| Arc::new(Mutex::new(stream_seed(self.config.seed, &tenant))))
+            .clone();
+        Some(ChaosTarget {
+            tenant,
+            stream,
+            error_rate: self.config.error_rate,
+            max_delay: self.config.max_delay,
+        })
+    }
+}
+
+#[derive(Clone)]
+struct ChaosTarget {
+    tenant: String,
+    stream: Arc<Mutex<u64>>,
+    error_rate: f64,
+    max_delay: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+struct Fault {
+    delay: Duration,
+    error: bool,
+}
+
+impl ChaosTarget {
+    fn next_fault(&self) -> Fault {
+        let mut state = self
+            .stream
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        draw_fault(&mut state, self.error_rate, self.max_delay)
+    }
+}
+
+fn draw_fault(state: &mut u64, error_rate: f64, max_delay: Duration) -> Fault {
+    let max_delay_ms = max_delay.as_millis() as u64;
+    let delay = Duration::from_millis(splitmix64(state) % max_delay_ms.saturating_add(1));
+    // 53 random bits give a uniform float in [0, 1).
+    let roll = (splitmix64(state) >> 11) as f64 / (1u64 << 53) as f64;
+    Fault {
+        delay,
+        error: roll < error_rate,
+    }
+}
+
+/// Mixes the Tenant key into the seed (FNV-1a) so Tenants sharing a seed get distinct streams.
+fn stream_seed(seed: u64, tenant: &str) -> u64 {
+    tenant
+        .bytes()
+        .fold(seed ^ 0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Runs one reconcile of `namespace/name` with chaos applied when `chaos` selects the Tenant.
+pub(crate) async fn scope<F: Future>(
+    chaos: Option<&Chaos>,
+    namespace: &str,
+    name: &str,
+    reconcile: F,
+) -> F::Output {
+    let target = chaos.and_then(|chaos| chaos.target(namespace, name));
+    TARGET.scope(target, reconcile).await
+}
+
+/// Delays the write `operation` on `object` and may fail it, when the current reconcile runs
+/// under [`scope`] for a selected Tenant. A no-op everywhere else.
+pub(crate) async fn before_write(operation: &str, object: &str) -> Result<(), kube::Error> {
+    let Some((tenant, fault)) = TARGET
+        .try_with(|target| {
+            target
+                .as_ref()
+                .map(|target| (target.tenant.clone(), target.next_fault()))
+        })
+        .ok()
+        .flatten()
+    else {
+        return Ok(());
+    };
+
+    if !fault.delay.is_zero() {
+        crate::metrics::record_chaos_injection("delay");
+        tokio::time::sleep(fault.delay).await;
+    }
+    if fault.error {
+        crate::metrics::record_chaos_injection("error");
+        warn!(
+            tenant,
+            operation,
+            object,
+            delay_ms = fault.delay.as_millis() as u64,
+            "chaos: injecting synthetic API error"
+        );
+        return Err(kube::Error::Api(kube::error::ErrorResponse {
+            status: "Failure".to_string(),
+            message: format!("chaos: synthetic failure of {operation} {object}"),
+            reason: CHAOS_ERROR_REASON.to_string(),
+            code: 500,
+        }));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(vars: &[(&str, &str)]) -> Result<ChaosConfig, String> {
+        ChaosConfig::parse(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn parses_settings_and_selects_listed_tenants() {
+        let config = config(&[
+            (CHAOS_TENANTS_ENV, "staging/tenant-a, chaos/*"),
+            (CHAOS_SEED_ENV, "42"),
+            (CHAOS_ERROR_RATE_ENV, "0.5"),
+            (CHAOS_MAX_DELAY_MS_ENV, "100"),
+        ])
+        .expect("valid config");
+
+        assert_eq!(config.seed, 42);
+        assert_eq!(config.error_rate, 0.5);
+        assert_eq!(config.max_delay, Duration::from_millis(100));
+        assert!(config.selects("staging", "tenant-a"));
+        assert!(!config.selects("staging", "tenant-b"));
+        assert!(config.selects("chaos", "anything"));
+    }
+
+    #[test]
+    fn rejects_missing_tenants_and_invalid_values() {
+        assert!(config(&[]).is_err());
+        assert!(config(&[(CHAOS_TENANTS_ENV, "tenant-a")]).is_err());
+        assert!(config(&[(CHAOS_TENANTS_ENV, "a/b"), (CHAOS_ERROR_RATE_ENV, "2")]).is_err());
+        assert!(config(&[(CHAOS_TENANTS_ENV, "a/b"), (CHAOS_SEED_ENV, "x")]).is_err());
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_faults() {
+        let draw = |seed: u64, tenant: &str| {
+            let mut state = stream_seed(seed, tenant);
+            (0..32)
+                .map(|_| draw_fault(&mut state, 0.3, Duration::from_millis(50)))
+                .collect::<Vec<_>>()
+        };
+
+        let faults = draw(7, "staging/tenant-a");
+        assert_eq!(faults, draw(7, "staging/tenant-a"));
+        assert_ne!(faults, draw(8, "staging/tenant-a"));
+        assert_ne!(faults, draw(7, "staging/tenant-b"));
+        assert!(faults.iter().any(|fault| fault.error));
+        assert!(faults.iter().any(|fault| !fault.error));
+        assert!(
+            faults
+                .iter()
+                .all(|fault| fault.delay <= Duration::from_millis(50))
+        );
+    }
+
+    #[tokio::test]
+    async fn injects_only_inside_a_selected_scope() {
+        let chaos = Chaos::new(ChaosConfig {
+            tenants: vec!["staging/tenant-a".to_string()],
+            seed: 1,
+            error_rate: 1.0,
+            max_delay: Duration::ZERO,
+        });
+
+        assert!(before_write("apply", "ConfigMap/a").await.is_ok());
+        let other = scope(Some(&chaos), "staging", "tenant-b", async {
+            before_write("apply", "ConfigMap/a").await
+        })
+        .await;
+        assert!(other.is_ok());
+
+        let selected = scope(Some(&chaos), "staging", "tenant-a", async {
+            before_write("apply", "ConfigMap/a").await
+        })
+        .await;
+        assert!(
+            matches!(selected, Err(kube::Error::Api(response)) if response.reason == CHAOS_ERROR_REASON)
+        );
+    }
+}
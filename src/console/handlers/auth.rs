@@ -13,13 +13,14 @@
 // limitations under the License.
 
 use axum::{Extension, Json, extract::State, http::header, response::IntoResponse};
+use k8s_openapi::api::authentication::v1::{TokenReview, TokenReviewSpec, TokenReviewStatus};
 use kube::Client;
 use snafu::ResultExt;
 
 use crate::console::{
     error::{self, Error, Result},
     models::auth::{LoginRequest, LoginResponse, SessionResponse},
-    state::{AppState, Claims, SESSION_TTL_SECONDS},
+    state::{AppState, Claims, ConsoleAuthMode, ImpersonatedUser, SESSION_TTL_SECONDS},
 };
 use crate::types::v1alpha1::tenant::Tenant;
 
@@ -34,8 +35,17 @@ pub async fn login(
 ) -> Result<impl IntoResponse> {
     tracing::info!("Console login attempt");
 
-    // Validate the bearer token by building a client
-    let client = create_k8s_client(&req.token).await?;
+    // Impersonation mode keeps only the reviewed identity; token mode keeps the token itself
+    let impersonate = match state.auth_mode {
+        ConsoleAuthMode::Token => None,
+        ConsoleAuthMode::Impersonation => Some(review_login_token(&state, &req.token).await?),
+    };
+
+    // Validate the session identity by building a client
+    let client = match &impersonate {
+        Some(user) => create_impersonating_client(user).await?,
+        None => create_k8s_client(&req.token).await?,
+    };
 
     // Permission smoke test: list Tenant CRs (limit 1)
     let api: kube::Api<Tenant> = kube::Api::all(client);
@@ -51,9 +61,14 @@ pub async fn login(
             }
         })?;
 
-    let token = state
-        .create_session(req.token)
-        .context(error::SessionSnafu)?;
+    let token = match impersonate {
+        Some(user) => {
+            tracing::info!(username = %user.username, "Console login using impersonation");
+            state.create_impersonation_session(user)
+        }
+        None => state.create_session(req.token),
+    }
+    .context(error::SessionSnafu)?;
 
     // HttpOnly session cookie
     let cookie = session_cookie(&token);
@@ -113,6 +128,67 @@ async fn create_k8s_client(token: &str) -> Result<Client> {
     })
 }
 
+/// Authenticate a login token with a TokenReview made by the Console ServiceAccount.
+async fn review_login_token(state: &AppState, token: &str) -> Result<ImpersonatedUser> {
+    let client = state
+        .kube_client
+        .clone()
+        .ok_or_else(|| Error::InternalServer {
+            message: "Impersonation mode requires an in-cluster Kubernetes client".to_string(),
+        })?;
+
+    let review = TokenReview {
+        metadata: Default::default(),
+        spec: TokenReviewSpec {
+            audiences: None,
+            token: Some(token.to_string()),
+        },
+        status: None,
+    };
+    let api: kube::Api<TokenReview> = kube::Api::all(client);
+    let review = api
+        .create(&kube::api::PostParams::default(), &review)
+        .await
+        .map_err(|error| {
+            tracing::warn!(%error, "Console login TokenReview failed");
+            Error::InternalServer {
+                message: "Failed to review login token".to_string(),
+            }
+        })?;
+
+    impersonated_user(review.status.as_ref()).ok_or_else(|| Error::Unauthorized {
+        message: "Invalid or insufficient permissions".to_string(),
+    })
+}
+
+/// The identity of an authenticated TokenReview.
+fn impersonated_user(status: Option<&TokenReviewStatus>) -> Option<ImpersonatedUser> {
+    let status = status.filter(|status| status.authenticated == Some(true))?;
+    let user = status.user.as_ref()?;
+    let username = user.username.clone().filter(|name| !name.is_empty())?;
+    Some(ImpersonatedUser {
+        username,
+        groups: user.groups.clone().unwrap_or_default(),
+        uid: user.uid.clone().filter(|uid| !uid.is_empty()),
+        extra: user.extra.clone().unwrap_or_default(),
+    })
+}
+
+/// Build a `kube::Client` with the Console's own credentials impersonating `user`.
+async fn create_impersonating_client(user: &ImpersonatedUser) -> Result<Client> {
+    let mut config = kube::Config::infer()
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to load kubeconfig: {}", e),
+        })?;
+
+    user.apply_to(&mut config);
+
+    Client::try_from(config).map_err(|e| Error::InternalServer {
+        message: format!("Failed to create K8s client: {}", e),
+    })
+}
+
 fn session_cookie(token: &str) -> String {
     let same_site = console_cookie_same_site();
     let secure = if console_cookie_secure() || same_site == "None" {
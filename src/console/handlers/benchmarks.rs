@@ -0,0 +1,650 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! S3 benchmarks run as Jobs against a tenant's S3 endpoint.
+//!
+//! Each run is a `warp` Job plus a ConfigMap of the same name holding the parameters and,
+//! once collected, the tool's summary. Both are owned by the Tenant. The Job is removed by
+//! its TTL a day after it finishes; the ConfigMap keeps the result until the benchmark is
+//! deleted.
+
+use super::audit;
+use crate::console::{
+    error::{self, Error, Result},
+    models::benchmark::*,
+    state::Claims,
+};
+use crate::types::v1alpha1::tenant::Tenant;
+use axum::{Extension, Json, extract::Path};
+use futures::AsyncReadExt;
+use k8s_openapi::api::batch::v1 as batchv1;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::{
+    Api, Client, ResourceExt,
+    api::{DeleteParams, ListParams, LogParams, Patch, PatchParams, PostParams},
+};
+use std::collections::BTreeMap;
+
+/// Label carrying the tenant name on benchmark Jobs, their pods and result ConfigMaps. The
+/// tenant label is not used, so benchmark pods never count as tenant pods.
+pub const BENCHMARK_LABEL: &str = "rustfs.com/benchmark";
+
+pub const BENCHMARK_IMAGE_ENV: &str = "OPERATOR_BENCHMARK_IMAGE";
+const DEFAULT_BENCHMARK_IMAGE: &str = "minio/warp:latest";
+
+const PARAMETERS_KEY: &str = "parameters";
+const PHASE_KEY: &str = "phase";
+const FINISHED_AT_KEY: &str = "finishedAt";
+const OUTPUT_KEY: &str = "output";
+
+/// Finished Jobs are garbage collected after a day.
+const JOB_TTL_SECONDS: i32 = 24 * 60 * 60;
+/// Lines of tool output kept as the result.
+const OUTPUT_TAIL_LINES: i64 = 100;
+
+const OPERATIONS: [&str; 3] = ["put", "get", "mixed"];
+const SIZE_UNITS: [&str; 8] = ["", "B", "KB", "MB", "GB", "KiB", "MiB", "GiB"];
+
+pub async fn start_benchmark(
+    Path((namespace, tenant_name)): Path<(String, String)>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<StartBenchmarkRequest>,
+) -> Result<Json<BenchmarkItem>> {
+    let parameters = benchmark_parameters(&req)?;
+    let client = create_client(&claims).await?;
+    let tenant_api: Api<Tenant> = Api::namespaced(client.clone(), &namespace);
+    let tenant = tenant_api
+        .get(&tenant_name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", tenant_name)))?;
+
+    let name = benchmark_name(&tenant_name, chrono::Utc::now());
+    let image = std::env::var(BENCHMARK_IMAGE_ENV)
+        .ok()
+        .filter(|image| !image.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_BENCHMARK_IMAGE.to_string());
+    let job = benchmark_job(&tenant, &namespace, &name, &parameters, &image)?;
+
+    let cm_api: Api<corev1::ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    let config_map = cm_api
+        .create(
+            &PostParams::default(),
+            &result_config_map(&tenant, &name, &parameters)?,
+        )
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("ConfigMap '{}'", name)))?;
+    let job_api: Api<batchv1::Job> = Api::namespaced(client, &namespace);
+    if let Err(e) = job_api.create(&PostParams::default(), &job).await {
+        let _ = cm_api.delete(&name, &DeleteParams::default()).await;
+        return Err(error::map_kube_error(e, format!("Job '{}'", name)));
+    }
+    audit(&claims, "startBenchmark", &namespace, &tenant_name, &name);
+
+    Ok(Json(benchmark_item(&config_map, None)))
+}
+
+pub async fn list_benchmarks(
+    Path((namespace, tenant_name)): Path<(String, String)>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<BenchmarkListResponse>> {
+    let client = create_client(&claims).await?;
+    let cm_api: Api<corev1::ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    let job_api: Api<batchv1::Job> = Api::namespaced(client, &namespace);
+    let params = ListParams::default().labels(&format!("{BENCHMARK_LABEL}={tenant_name}"));
+
+    let (config_maps, jobs) = tokio::join!(cm_api.list(&params), job_api.list(&params));
+    let config_maps = config_maps
+        .map_err(|e| error::map_kube_error(e, format!("Benchmarks of tenant '{}'", tenant_name)))?;
+    let jobs = jobs.map_err(|e| {
+        error::map_kube_error(e, format!("Benchmark Jobs of tenant '{}'", tenant_name))
+    })?;
+
+    let mut benchmarks: Vec<BenchmarkItem> = config_maps
+        .items
+        .iter()
+        .map(|config_map| {
+            let job = jobs
+                .items
+                .iter()
+                .find(|job| job.name_any() == config_map.name_any());
+            benchmark_item(config_map, job)
+        })
+        .collect();
+    // Names end with the start time, so this lists the newest run first.
+    benchmarks.sort_by(|a, b| b.name.cmp(&a.name));
+
+    Ok(Json(BenchmarkListResponse { benchmarks }))
+}
+
+/// Returns a benchmark, collecting the tool output into its ConfigMap the first time it is
+/// read after the Job has finished.
+pub async fn get_benchmark(
+    Path((namespace, tenant_name, name)): Path<(String, String, String)>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<BenchmarkDetailsResponse>> {
+    let client = create_client(&claims).await?;
+    let cm_api: Api<corev1::ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    let job_api: Api<batchv1::Job> = Api::namespaced(client.clone(), &namespace);
+
+    let mut config_map = get_benchmark_config_map(&cm_api, &tenant_name, &name).await?;
+    let job = job_api
+        .get_opt(&name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Job '{}'", name)))?;
+
+    if !has_result(&config_map)
+        && let Some(job) = &job
+        && matches!(job_phase(job), "Succeeded" | "Failed")
+    {
+        let pod_api: Api<corev1::Pod> = Api::namespaced(client, &namespace);
+        let output = collect_output(&pod_api, &name).await?;
+        let patch = serde_json::json!({
+            "data": {
+                PHASE_KEY: job_phase(job),
+                FINISHED_AT_KEY: job_finished_at(job),
+                OUTPUT_KEY: output,
+            }
+        });
+        config_map = cm_api
+            .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .map_err(|e| error::map_kube_error(e, format!("ConfigMap '{}'", name)))?;
+    }
+
+    let output = config_map
+        .data
+        .as_ref()
+        .and_then(|data| data.get(OUTPUT_KEY))
+        .cloned();
+    Ok(Json(BenchmarkDetailsResponse {
+        benchmark: benchmark_item(&config_map, job.as_ref()),
+        output,
+    }))
+}
+
+/// Deletes the benchmark Job with its pods, and the result ConfigMap.
+pub async fn delete_benchmark(
+    Path((namespace, tenant_name, name)): Path<(String, String, String)>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<DeleteBenchmarkResponse>> {
+    let client = create_client(&claims).await?;
+    let cm_api: Api<corev1::ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    let job_api: Api<batchv1::Job> = Api::namespaced(client, &namespace);
+
+    get_benchmark_config_map(&cm_api, &tenant_name, &name).await?;
+    match job_api.delete(&name, &DeleteParams::background()).await {
+        Ok(_) => {}
+        Err(kube::Error::Api(response)) if response.code == 404 => {}
+        Err(e) => return Err(error::map_kube_error(e, format!("Job '{}'", name))),
+    }
+    cm_api
+        .delete(&name, &DeleteParams::default())
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("ConfigMap '{}'", name)))?;
+    audit(&claims, "deleteBenchmark", &namespace, &tenant_name, &name);
+
+    Ok(Json(DeleteBenchmarkResponse {
+        success: true,
+        message: format!("Benchmark '{}' deleted", name),
+    }))
+}
+
+async fn get_benchmark_config_map(
+    api: &Api<corev1::ConfigMap>,
+    tenant_name: &str,
+    name: &str,
+) -> Result<corev1::ConfigMap> {
+    let not_found = || Error::NotFound {
+        resource: format!("Benchmark '{}'", name),
+    };
+    let config_map = api
+        .get_opt(name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Benchmark '{}'", name)))?
+        .ok_or_else(not_found)?;
+    if config_map.labels().get(BENCHMARK_LABEL).map(String::as_str) != Some(tenant_name) {
+        return Err(not_found());
+    }
+    Ok(config_map)
+}
+
+async fn collect_output(api: &Api<corev1::Pod>, job_name: &str) -> Result<String> {
+    let pods = api
+        .list(&ListParams::default().labels(&format!("job-name={job_name}")))
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Pods of Job '{}'", job_name)))?;
+    let Some(pod) = pods.items.first() else {
+        return Ok(String::new());
+    };
+    let params = LogParams {
+        tail_lines: Some(OUTPUT_TAIL_LINES),
+        ..Default::default()
+    };
+    let mut output = String::new();
+    api.log_stream(&pod.name_any(), &params)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Pod '{}'", pod.name_any())))?
+        .read_to_string(&mut output)
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to read benchmark output: {}", e),
+        })?;
+    Ok(output)
+}
+
+fn benchmark_parameters(req: &StartBenchmarkRequest) -> Result<BenchmarkParameters> {
+    let bad_request = |message: String| Error::BadRequest { message };
+    let operation = req.operation.as_deref().unwrap_or("mixed").to_string();
+    if !OPERATIONS.contains(&operation.as_str()) {
+        return Err(bad_request(format!(
+            "operation must be one of {}",
+            OPERATIONS.join(", ")
+        )));
+    }
+    let object_size = req.object_size.as_deref().unwrap_or("1MiB").to_string();
+    if !valid_object_size(&object_size) {
+        return Err(bad_request(format!(
+            "object_size '{}' must be a positive number with an optional unit (B, KB, MB, GB, KiB, MiB, GiB)",
+            object_size
+        )));
+    }
+    let concurrency = req.concurrency.unwrap_or(16);
+    if !(1..=1024).contains(&concurrency) {
+        return Err(bad_request(
+            "concurrency must be between 1 and 1024".to_string(),
+        ));
+    }
+    let duration_seconds = req.duration_seconds.unwrap_or(60);
+    if !(10..=3600).contains(&duration_seconds) {
+        return Err(bad_request(
+            "duration_seconds must be between 10 and 3600".to_string(),
+        ));
+    }
+    Ok(BenchmarkParameters {
+        operation,
+        object_size,
+        concurrency,
+        duration_seconds,
+    })
+}
+
+fn valid_object_size(size: &str) -> bool {
+    let digits = size.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    SIZE_UNITS.contains(&&size[digits.len()..])
+        && digits.parse::<u64>().is_ok_and(|number| number > 0)
+}
+
+/// `<tenant>-bench-<UTC start time>`, with the tenant part shortened so the name stays a
+/// valid `job-name` label value.
+fn benchmark_name(tenant_name: &str, now: chrono::DateTime<chrono::Utc>) -> String {
+    let prefix: String = tenant_name.chars().take(42).collect();
+    format!(
+        "{}-bench-{}",
+        prefix.trim_end_matches('-'),
+        now.format("%Y%m%d%H%M%S")
+    )
+}
+
+fn benchmark_labels(tenant_name: &str) -> BTreeMap<String, String> {
+    BTreeMap::from([(BENCHMARK_LABEL.to_string(), tenant_name.to_string())])
+}
+
+fn result_config_map(
+    tenant: &Tenant,
+    name: &str,
+    parameters: &BenchmarkParameters,
+) -> Result<corev1::ConfigMap> {
+    let parameters = serde_json::to_string(parameters).map_err(|e| Error::Json { source: e })?;
+    Ok(corev1::ConfigMap {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            labels: Some(benchmark_labels(&tenant.name_any())),
+            owner_references: Some(vec![tenant.new_owner_ref()]),
+            ..Default::default()
+        },
+        data: Some(BTreeMap::from([(PARAMETERS_KEY.to_string(), parameters)])),
+        ..Default::default()
+    })
+}
+
+fn benchmark_job(
+    tenant: &Tenant,
+    namespace: &str,
+    name: &str,
+    parameters: &BenchmarkParameters,
+    image: &str,
+) -> Result<batchv1::Job> {
+    let creds = tenant
+        .spec
+        .creds_secret
+        .as_ref()
+        .ok_or_else(|| Error::BadRequest {
+            message:
+                "Benchmarks authenticate with spec.credsSecret; configure it on the tenant first"
+                    .to_string(),
+        })?;
+    let service = tenant
+        .new_io_service()
+        .metadata
+        .name
+        .unwrap_or_else(|| format!("{}-io", tenant.name_any()));
+
+    let mut args = vec![
+        parameters.operation.clone(),
+        format!("--host={service}.{namespace}.svc:{}", tenant.s3_port()),
+        format!("--obj.size={}", parameters.object_size),
+        format!("--concurrent={}", parameters.concurrency),
+        format!("--duration={}s", parameters.duration_seconds),
+    ];
+    if tenant.spec.tls.as_ref().is_some_and(|tls| tls.is_enabled()) {
+        args.push("--tls".to_string());
+        args.push("--insecure".to_string());
+    }
+    let secret_env = |env: &str, key: &str| corev1::EnvVar {
+        name: env.to_string(),
+        value_from: Some(corev1::EnvVarSource {
+            secret_key_ref: Some(corev1::SecretKeySelector {
+                name: creds.name.clone(),
+                key: key.to_string(),
+                optional: Some(false),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let labels = benchmark_labels(&tenant.name_any());
+    Ok(batchv1::Job {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            labels: Some(labels.clone()),
+            owner_references: Some(vec![tenant.new_owner_ref()]),
+            ..Default::default()
+        },
+        spec: Some(batchv1::JobSpec {
+            backoff_limit: Some(0),
+            ttl_seconds_after_finished: Some(JOB_TTL_SECONDS),
+            active_deadline_seconds: Some(i64::from(parameters.duration_seconds) + 600),
+            template: corev1::PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(labels),
+                    ..Default::default()
+                }),
+                spec: Some(corev1::PodSpec {
+                    restart_policy: Some("Never".to_string()),
+                    containers: vec![corev1::Container {
+                        name: "warp".to_string(),
+                        image: Some(image.to_string()),
+                        args: Some(args),
+                        env: Some(vec![
+                            secret_env("WARP_ACCESS_KEY", creds.access_key()),
+                            secret_env("WARP_SECRET_KEY", creds.secret_key()),
+                        ]),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+fn job_phase(job: &batchv1::Job) -> &'static str {
+    let status = job.status.as_ref();
+    let failed_condition = status
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|condition| condition.type_ == "Failed" && condition.status == "True")
+        });
+    if status.and_then(|status| status.succeeded).unwrap_or(0) > 0 {
+        "Succeeded"
+    } else if failed_condition || status.and_then(|status| status.failed).unwrap_or(0) > 0 {
+        "Failed"
+    } else if status.and_then(|status| status.active).unwrap_or(0) > 0 {
+        "Running"
+    } else {
+        "Pending"
+    }
+}
+
+fn job_finished_at(job: &batchv1::Job) -> Option<String> {
+    let status = job.status.as_ref()?;
+    status
+        .completion_time
+        .as_ref()
+        .map(|time| time.0.to_rfc3339())
+        .or_else(|| {
+            status
+                .conditions
+                .as_ref()?
+                .iter()
+                .find(|condition| condition.type_ == "Failed" && condition.status == "True")
+                .and_then(|condition| condition.last_transition_time.as_ref())
+                .map(|time| time.0.to_rfc3339())
+        })
+}
+
+fn has_result(config_map: &corev1::ConfigMap) -> bool {
+    config_map
+        .data
+        .as_ref()
+        .is_some_and(|data| data.contains_key(PHASE_KEY))
+}
+
+/// The phase recorded with the result wins; without one it comes from the Job, and a run whose
+/// Job expired before its result was collected is `Unknown`.
+fn benchmark_item(config_map: &corev1::ConfigMap, job: Option<&batchv1::Job>) -> BenchmarkItem {
+    let data = config_map.data.clone().unwrap_or_default();
+    let parameters = data
+        .get(PARAMETERS_KEY)
+        .and_then(|parameters| serde_json::from_str(parameters).ok())
+        .unwrap_or_else(|| BenchmarkParameters {
+            operation: String::new(),
+            object_size: String::new(),
+            concurrency: 0,
+            duration_seconds: 0,
+        });
+    let phase = match (data.get(PHASE_KEY), job) {
+        (Some(phase), _) => phase.clone(),
+        (None, Some(job)) => job_phase(job).to_string(),
+        (None, None) => "Unknown".to_string(),
+    };
+    BenchmarkItem {
+        name: config_map.name_any(),
+        phase,
+        parameters,
+        started_at: config_map
+            .metadata
+            .creation_timestamp
+            .as_ref()
+            .map(|time| time.0.to_rfc3339()),
+        finished_at: data
+            .get(FINISHED_AT_KEY)
+            .cloned()
+            .or_else(|| job.and_then(job_finished_at)),
+    }
+}
+
+/// Build a client acting as the session identity (bearer token or impersonation).
+async fn create_client(claims: &Claims) -> Result<Client> {
+    let mut config = kube::Config::infer()
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to load kubeconfig: {}", e),
+        })?;
+
+    claims.apply_to(&mut config);
+
+    Client::try_from(config).map_err(|e| Error::InternalServer {
+        message: format!("Failed to create K8s client: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::v1alpha1::credentials::CredsSecretReference;
+    use crate::types::v1alpha1::tenant::TenantSpec;
+
+    fn tenant(creds: bool) -> Tenant {
+        Tenant {
+            metadata: ObjectMeta {
+                name: Some("bench".to_string()),
+                namespace: Some("storage".to_string()),
+                uid: Some("uid-1".to_string()),
+                ..Default::default()
+            },
+            spec: TenantSpec {
+                creds_secret: creds.then(|| CredsSecretReference::new("bench-creds")),
+                ..Default::default()
+            },
+            status: None,
+        }
+    }
+
+    #[test]
+    fn parameters_have_defaults_and_limits() {
+        let parameters =
+            benchmark_parameters(&StartBenchmarkRequest::default()).expect("defaults are valid");
+        assert_eq!(
+            parameters,
+            BenchmarkParameters {
+                operation: "mixed".to_string(),
+                object_size: "1MiB".to_string(),
+                concurrency: 16,
+                duration_seconds: 60,
+            }
+        );
+
+        for req in [
+            StartBenchmarkRequest {
+                operation: Some("delete".to_string()),
+                ..Default::default()
+            },
+            StartBenchmarkRequest {
+                object_size: Some("0MiB".to_string()),
+                ..Default::default()
+            },
+            StartBenchmarkRequest {
+                object_size: Some("1TiB".to_string()),
+                ..Default::default()
+            },
+            StartBenchmarkRequest {
+                concurrency: Some(0),
+                ..Default::default()
+            },
+            StartBenchmarkRequest {
+                duration_seconds: Some(5),
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                benchmark_parameters(&req),
+                Err(Error::BadRequest { .. })
+            ));
+        }
+        assert!(valid_object_size("4096"));
+        assert!(valid_object_size("64KiB"));
+    }
+
+    #[test]
+    fn job_targets_the_io_service_with_tenant_credentials() {
+        let parameters =
+            benchmark_parameters(&StartBenchmarkRequest::default()).expect("defaults are valid");
+        let job = benchmark_job(
+            &tenant(true),
+            "storage",
+            "bench-bench-20250101000000",
+            &parameters,
+            DEFAULT_BENCHMARK_IMAGE,
+        )
+        .expect("job");
+
+        let spec = job.spec.expect("spec");
+        assert_eq!(spec.backoff_limit, Some(0));
+        assert_eq!(spec.ttl_seconds_after_finished, Some(JOB_TTL_SECONDS));
+        let pod = spec.template.spec.expect("pod spec");
+        let container = &pod.containers[0];
+        let args = container.args.clone().unwrap_or_default();
+        assert_eq!(args[0], "mixed");
+        assert!(args.contains(&"--host=bench-io.storage.svc:9000".to_string()));
+        assert!(!args.contains(&"--tls".to_string()));
+        let env = container.env.clone().unwrap_or_default();
+        let access = env[0]
+            .value_from
+            .as_ref()
+            .and_then(|source| source.secret_key_ref.as_ref())
+            .expect("secret ref");
+        assert_eq!(access.name, "bench-creds");
+        assert_eq!(access.key, "accesskey");
+
+        let labels = spec.template.metadata.and_then(|m| m.labels);
+        assert_eq!(
+            labels.as_ref().and_then(|l| l.get(BENCHMARK_LABEL)),
+            Some(&"bench".to_string())
+        );
+        assert!(
+            !labels
+                .unwrap_or_default()
+                .contains_key(crate::types::v1alpha1::tenant::TENANT_LABEL)
+        );
+
+        assert!(matches!(
+            benchmark_job(&tenant(false), "storage", "x", &parameters, "img"),
+            Err(Error::BadRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn names_stay_valid_label_values() {
+        let now = chrono::DateTime::parse_from_rfc3339("2025-01-02T03:04:05Z")
+            .expect("time")
+            .with_timezone(&chrono::Utc);
+        assert_eq!(benchmark_name("bench", now), "bench-bench-20250102030405");
+        let long = benchmark_name(&"a".repeat(80), now);
+        assert!(long.len() <= 63);
+    }
+
+    #[test]
+    fn recorded_result_phase_outlives_the_job() {
+        let tenant = tenant(true);
+        let parameters =
+            benchmark_parameters(&StartBenchmarkRequest::default()).expect("defaults are valid");
+        let mut config_map = result_config_map(&tenant, "run", &parameters).expect("cm");
+        assert_eq!(benchmark_item(&config_map, None).phase, "Unknown");
+
+        let job = batchv1::Job {
+            status: Some(batchv1::JobStatus {
+                active: Some(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let item = benchmark_item(&config_map, Some(&job));
+        assert_eq!(item.phase, "Running");
+        assert_eq!(item.parameters, parameters);
+
+        config_map
+            .data
+            .get_or_insert_with(BTreeMap::new)
+            .insert(PHASE_KEY.to_string(), "Succeeded".to_string());
+        assert!(has_result(&config_map));
+        assert_eq!(benchmark_item(&config_map, None).phase, "Succeeded");
+    }
+}
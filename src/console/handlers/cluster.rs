@@ -17,6 +17,7 @@ use crate::console::{
     models::cluster::*,
     state::Claims,
 };
+use crate::reconcile::namespace_policy::NamespaceFilter;
 use axum::{Extension, Json};
 use k8s_openapi::api::core::v1 as corev1;
 use kube::{Api, Client, ResourceExt, api::ListParams};
@@ -148,6 +149,10 @@ pub async fn create_namespace(
     Extension(claims): Extension<Claims>,
     Json(req): Json<CreateNamespaceRequest>,
 ) -> Result<Json<NamespaceItem>> {
+    if let Err(message) = NamespaceFilter::from_env().check(&req.name) {
+        return Err(Error::Forbidden { message });
+    }
+
     let client = create_client(&claims).await?;
     let api: Api<corev1::Namespace> = Api::all(client);
 
@@ -190,11 +195,16 @@ pub async fn get_cluster_resources(
         .await
         .map_err(|e| error::map_kube_error(e, "Nodes"))?;
 
-    let total_nodes = nodes.items.len();
+    Ok(Json(summarize_node_resources(&nodes.items)))
+}
+
+/// Sum node capacity and allocatable CPU/memory into a cluster-wide summary.
+pub(crate) fn summarize_node_resources(nodes: &[corev1::Node]) -> ClusterResourcesResponse {
+    let total_nodes = nodes.len();
 
     // Sum each node's capacity/allocatable, then format
     let (total_cpu_millicores, total_memory_bytes, alloc_cpu_millicores, alloc_memory_bytes) =
-        nodes.items.iter().fold(
+        nodes.iter().fold(
             (0i64, 0i64, 0i64, 0i64),
             |(cap_cpu, cap_mem, alloc_cpu, alloc_mem), node| {
                 let (dcap_cpu, dcap_mem, dalloc_cpu, dalloc_mem) = node
@@ -239,13 +249,13 @@ pub async fn get_cluster_resources(
     let allocatable_cpu = format_cpu_from_millicores(alloc_cpu_millicores);
     let allocatable_memory = format_memory_from_bytes(alloc_memory_bytes);
 
-    Ok(Json(ClusterResourcesResponse {
+    ClusterResourcesResponse {
         total_nodes,
         total_cpu,
         total_memory,
         allocatable_cpu,
         allocatable_memory,
-    }))
+    }
 }
 
 /// Parse a Kubernetes CPU quantity to millicores.
@@ -349,7 +359,7 @@ pub(crate) fn format_memory_from_bytes(b: i64) -> String {
     }
 }
 
-/// Build a client using the Kubernetes bearer token from session claims.
+/// Build a client acting as the session identity (bearer token or impersonation).
 async fn create_client(claims: &Claims) -> Result<Client> {
     let mut config = kube::Config::infer()
         .await
@@ -357,7 +367,7 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
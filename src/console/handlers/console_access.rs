@@ -0,0 +1,173 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Short-lived credentials for a tenant's own RustFS console.
+//!
+//! The console reads the tenant `credsSecret` with the caller's Kubernetes identity, so only
+//! users allowed to read that Secret get a credential, and exchanges it for a temporary STS
+//! credential through RustFS `AssumeRole`. The long-term keys are never returned.
+
+use super::audit;
+use crate::console::{
+    error::{self, Error, Result},
+    models::console_access::*,
+    state::Claims,
+};
+use crate::types::v1alpha1::tenant::Tenant;
+use axum::{
+    Extension, Json,
+    extract::{Path, Query},
+    http::header,
+    response::IntoResponse,
+};
+use k8s_openapi::api::core::v1 as corev1;
+use kube::{Api, Client};
+
+const DEFAULT_DURATION_SECONDS: u64 = 3600;
+const MIN_DURATION_SECONDS: u64 = 900;
+const MAX_DURATION_SECONDS: u64 = 43200;
+
+/// Issue a temporary RustFS credential for the tenant console.
+pub async fn get_console_access(
+    Path((namespace, name)): Path<(String, String)>,
+    Query(query): Query<ConsoleAccessQuery>,
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse> {
+    let duration_seconds = duration_seconds(query.duration_seconds)?;
+    let client = create_client(&claims).await?;
+
+    let tenant = Api::<Tenant>::namespaced(client.clone(), &namespace)
+        .get(&name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", name)))?;
+    let Some(creds_secret) = &tenant.spec.creds_secret else {
+        return Err(Error::BadRequest {
+            message: format!(
+                "Tenant '{}' has no spec.credsSecret to issue console credentials from",
+                name
+            ),
+        });
+    };
+    // Checked with the caller's identity first so RBAC denials surface as 403 and 404.
+    Api::<corev1::Secret>::namespaced(client.clone(), &namespace)
+        .get(&creds_secret.name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Secret '{}'", creds_secret.name)))?;
+
+    let rustfs = crate::tenant_monitor::tenant_admin_client(&client, &tenant)
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to connect to tenant '{}': {}", name, e),
+        })?;
+    let credentials = rustfs
+        .assume_role(None, duration_seconds)
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to issue console credentials: {}", e),
+        })?;
+
+    audit(&claims, "console-access", &namespace, &name, &name);
+
+    let response = ConsoleAccessResponse {
+        access_key_id: credentials.access_key_id,
+        secret_access_key: credentials.secret_access_key,
+        session_token: credentials.session_token,
+        expiration: credentials.expiration,
+        console_url: console_url(&tenant, &namespace),
+    };
+    Ok(([(header::CACHE_CONTROL, "no-store")], Json(response)))
+}
+
+fn duration_seconds(requested: Option<u64>) -> Result<u64> {
+    let duration = requested.unwrap_or(DEFAULT_DURATION_SECONDS);
+    if !(MIN_DURATION_SECONDS..=MAX_DURATION_SECONDS).contains(&duration) {
+        return Err(Error::BadRequest {
+            message: format!(
+                "duration_seconds must be between {} and {}",
+                MIN_DURATION_SECONDS, MAX_DURATION_SECONDS
+            ),
+        });
+    }
+    Ok(duration)
+}
+
+fn console_url(tenant: &Tenant, namespace: &str) -> String {
+    let scheme = if tenant.spec.tls.as_ref().is_some_and(|tls| tls.is_enabled()) {
+        "https"
+    } else {
+        "http"
+    };
+    let service = tenant
+        .new_console_service()
+        .metadata
+        .name
+        .unwrap_or_default();
+    format!(
+        "{}://{}.{}.svc:{}",
+        scheme,
+        service,
+        namespace,
+        tenant.console_port()
+    )
+}
+
+async fn create_client(claims: &Claims) -> Result<Client> {
+    let mut config = kube::Config::infer()
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to load kubeconfig: {}", e),
+        })?;
+
+    claims.apply_to(&mut config);
+
+    Client::try_from(config).map_err(|e| Error::InternalServer {
+        message: format!("Failed to create K8s client: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_defaults_and_is_bounded() {
+        assert_eq!(duration_seconds(None).ok(), Some(3600));
+        assert_eq!(duration_seconds(Some(900)).ok(), Some(900));
+        assert_eq!(duration_seconds(Some(43200)).ok(), Some(43200));
+        assert!(matches!(
+            duration_seconds(Some(60)),
+            Err(Error::BadRequest { .. })
+        ));
+        assert!(matches!(
+            duration_seconds(Some(86400)),
+            Err(Error::BadRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn console_url_points_at_the_console_service() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let namespace = tenant.namespace().unwrap_or_default();
+
+        assert_eq!(
+            console_url(&tenant, &namespace),
+            format!(
+                "http://{}-console.{}.svc:{}",
+                tenant.name(),
+                namespace,
+                tenant.console_port()
+            )
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::{Extension, Json};
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::api::events::v1 as eventsv1;
+use kube::{Api, Client, api::ListParams};
+
+use crate::console::{
+    error::{self, Error, Result},
+    handlers::cluster::summarize_node_resources,
+    handlers::tenants::summarize_tenant_states,
+    handlers::topology::{format_storage_bytes, get_per_volume_bytes},
+    models::dashboard::*,
+    state::Claims,
+    tenant_event_scope::{TENANT_CR_KIND, merge_events_v1},
+};
+use crate::types::v1alpha1::tenant::Tenant;
+
+/// Warning events returned on the dashboard.
+const MAX_RECENT_WARNINGS: usize = 20;
+
+/// Landing page summary: tenant states, storage totals, node resources, and recent
+/// Tenant warnings, fetched concurrently.
+pub async fn get_dashboard(
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<DashboardResponse>> {
+    let client = create_client(&claims).await?;
+
+    let tenant_api: Api<Tenant> = Api::all(client.clone());
+    let node_api: Api<corev1::Node> = Api::all(client.clone());
+    let event_api: Api<eventsv1::Event> = Api::all(client);
+
+    let list_params = ListParams::default();
+    let event_params = ListParams::default()
+        .fields(&format!("type=Warning,regarding.kind={TENANT_CR_KIND}"))
+        .limit(500);
+    let (tenants_result, nodes_result, events_result) = tokio::join!(
+        tenant_api.list(&list_params),
+        node_api.list(&list_params),
+        event_api.list(&event_params),
+    );
+
+    let tenants = tenants_result.map_err(|e| error::map_kube_error(e, "Tenants"))?;
+    let nodes = nodes_result.map_err(|e| error::map_kube_error(e, "Nodes"))?;
+    let events = events_result.map_err(|e| error::map_kube_error(e, "Events"))?;
+
+    let mut recent_warnings = merge_events_v1(events.items);
+    recent_warnings.truncate(MAX_RECENT_WARNINGS);
+
+    Ok(Json(DashboardResponse {
+        operator_version: crate::version::SHORT_VERSION.to_string(),
+        tenants: summarize_tenant_states(&tenants.items),
+        storage: summarize_storage(&tenants.items),
+        cluster: summarize_node_resources(&nodes.items),
+        recent_warnings,
+    }))
+}
+
+/// Pool, server, volume, and requested capacity totals from Tenant specs.
+fn summarize_storage(tenants: &[Tenant]) -> DashboardStorageSummary {
+    let mut summary = DashboardStorageSummary::default();
+    for pool in tenants.iter().flat_map(|tenant| &tenant.spec.pools) {
+        let servers = i64::from(pool.servers);
+        let volumes = servers * i64::from(pool.persistence.volumes_per_server);
+        summary.pools += 1;
+        summary.servers += servers;
+        summary.volumes += volumes;
+        summary.capacity_bytes += volumes * get_per_volume_bytes(&pool.persistence);
+    }
+    summary.capacity = format_storage_bytes(summary.capacity_bytes);
+    summary
+}
+
+/// Build a client acting as the session identity (bearer token or impersonation).
+async fn create_client(claims: &Claims) -> Result<Client> {
+    let mut config = kube::Config::infer()
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to load kubeconfig: {}", e),
+        })?;
+
+    claims.apply_to(&mut config);
+
+    Client::try_from(config).map_err(|e| Error::InternalServer {
+        message: format!("Failed to create K8s client: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::summarize_storage;
+
+    #[test]
+    fn storage_totals_sum_every_pool_of_every_tenant() {
+        let first = crate::tests::create_test_tenant(None, None);
+        let mut second = crate::tests::create_test_tenant(None, None);
+        let mut extra = second.spec.pools[0].clone();
+        extra.name = "pool-1".to_string();
+        extra.servers = 2;
+        extra.persistence.volumes_per_server = 1;
+        second.spec.pools.push(extra);
+
+        let summary = summarize_storage(&[first, second]);
+
+        assert_eq!(summary.pools, 3);
+        assert_eq!(summary.servers, 4 + 4 + 2);
+        assert_eq!(summary.volumes, 16 + 16 + 2);
+        assert_eq!(summary.capacity_bytes, 34 * 10 * 1024 * 1024 * 1024);
+        assert_eq!(summary.capacity, "340 GiB");
+    }
+}
@@ -184,7 +184,7 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
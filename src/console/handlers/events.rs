@@ -172,7 +172,7 @@ async fn build_snapshot_json(client: &Client, namespace: &str, tenant: &str) ->
     serde_json::to_string(&body).map_err(|e| Error::Json { source: e })
 }
 
-/// Build a client using the Kubernetes bearer token from session claims.
+/// Build a client acting as the session identity (bearer token or impersonation).
 async fn create_client(claims: &Claims) -> Result<Client> {
     let mut config = kube::Config::infer()
         .await
@@ -180,7 +180,7 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
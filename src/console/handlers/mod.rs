@@ -13,7 +13,10 @@
 // limitations under the License.
 
 pub mod auth;
+pub mod benchmarks;
 pub mod cluster;
+pub mod console_access;
+pub mod dashboard;
 pub mod encryption;
 pub mod events;
 pub mod pods;
@@ -21,3 +24,52 @@ pub mod pools;
 pub mod security_context;
 pub mod tenants;
 pub mod topology;
+pub mod usage;
+
+use crate::console::{
+    error::{Error, Result},
+    state::Claims,
+};
+
+/// Require a destructive request to repeat the name of its target.
+pub(crate) fn require_confirmation(confirm: &str, target: &str) -> Result<()> {
+    if confirm == target {
+        Ok(())
+    } else {
+        Err(Error::BadRequest {
+            message: format!("Set 'confirm' to '{}' to confirm this action", target),
+        })
+    }
+}
+
+/// Audit log line for a Console write action.
+pub(crate) fn audit(claims: &Claims, action: &str, namespace: &str, tenant: &str, target: &str) {
+    tracing::info!(
+        target: "rustfs_operator::console::audit",
+        actor = %claims.actor(),
+        action,
+        namespace,
+        tenant,
+        target,
+        "Console action"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::require_confirmation;
+    use crate::console::error::Error;
+
+    #[test]
+    fn confirmation_must_repeat_the_target_name() {
+        assert!(require_confirmation("tenant-a-pool-0-0", "tenant-a-pool-0-0").is_ok());
+        assert!(matches!(
+            require_confirmation("", "tenant-a-pool-0-0"),
+            Err(Error::BadRequest { .. })
+        ));
+        assert!(matches!(
+            require_confirmation("pool-0", "pool-1"),
+            Err(Error::BadRequest { .. })
+        ));
+    }
+}
@@ -12,22 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::{audit, require_confirmation};
 use crate::console::{
     error::{self, Error, Result},
-    models::pod::*,
+    models::{common::ConsoleErrorDetails, pod::*},
     state::Claims,
 };
+use crate::types::v1alpha1::status::next_actions_for_reason;
+use crate::types::v1alpha1::tenant::{POOL_LABEL, TENANT_LABEL, tenant_selector};
 use axum::{
     Extension, Json,
     body::Body,
     extract::{Path, Query},
+    http::StatusCode,
     response::{IntoResponse, Response},
 };
 use futures::TryStreamExt;
 use k8s_openapi::api::core::v1 as corev1;
 use kube::{
     Api, Client, ResourceExt,
-    api::{DeleteParams, ListParams, LogParams},
+    api::{DeleteParams, EvictParams, ListParams, LogParams},
 };
 
 fn container_state_from_k8s(state: Option<&corev1::ContainerState>) -> ContainerState {
@@ -66,7 +70,7 @@ fn last_terminated_container(
         .max_by_key(|terminated| terminated.finished_at.as_ref().map(|t| t.0))
 }
 
-/// Ensure the pod has `rustfs.tenant=<tenant>` label.
+/// Ensure the pod has the `rustfs.tenant=<tenant>` label.
 fn ensure_pod_belongs_to_tenant(
     pod: &corev1::Pod,
     tenant_name: &str,
@@ -76,7 +80,7 @@ fn ensure_pod_belongs_to_tenant(
         .metadata
         .labels
         .as_ref()
-        .and_then(|l| l.get("rustfs.tenant").map(String::as_str));
+        .and_then(|l| l.get(TENANT_LABEL).map(String::as_str));
     if pod_tenant != Some(tenant_name) {
         return Err(Error::NotFound {
             resource: format!("Pod '{}'", pod_name),
@@ -95,7 +99,7 @@ pub async fn list_pods(
 
     // List pods with tenant label
     let pods = api
-        .list(&ListParams::default().labels(&format!("rustfs.tenant={}", tenant_name)))
+        .list(&ListParams::default().labels(&tenant_selector(&tenant_name)))
         .await
         .map_err(|e| error::map_kube_error(e, format!("Pods for tenant '{}'", tenant_name)))?;
 
@@ -111,7 +115,7 @@ pub async fn list_pods(
             .metadata
             .labels
             .as_ref()
-            .and_then(|l| l.get("rustfs.pool"))
+            .and_then(|l| l.get(POOL_LABEL))
             .cloned()
             .unwrap_or_else(|| "unknown".to_string());
 
@@ -230,12 +234,39 @@ pub async fn delete_pod(
     }))
 }
 
-/// Restart by deleting the pod (StatefulSet recreates it).
+/// Map an eviction refused by a PodDisruptionBudget (HTTP 429) to an actionable error.
+fn map_eviction_error(e: kube::Error, namespace: &str, tenant_name: &str, pod_name: &str) -> Error {
+    match &e {
+        kube::Error::Api(ae) if ae.code == 429 => {
+            let reason = "DisruptionBudgetExceeded";
+            Error::ActionRequired {
+                status: StatusCode::TOO_MANY_REQUESTS,
+                code: "PodRestartBlocked".to_string(),
+                reason: reason.to_string(),
+                message: format!("Pod '{}' cannot be restarted now: {}", pod_name, ae.message),
+                next_actions: next_actions_for_reason(reason)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+                details: Some(Box::new(ConsoleErrorDetails {
+                    namespace: Some(namespace.to_string()),
+                    tenant: Some(tenant_name.to_string()),
+                    resource: Some(pod_name.to_string()),
+                })),
+            }
+        }
+        _ => error::map_kube_error(e, format!("Pod '{}'", pod_name)),
+    }
+}
+
+/// Restart by evicting the pod (StatefulSet recreates it). Eviction honours
+/// PodDisruptionBudgets, so a restart that would take too many servers down is refused.
 pub async fn restart_pod(
     Path((namespace, tenant_name, pod_name)): Path<(String, String, String)>,
     Extension(claims): Extension<Claims>,
     Json(req): Json<RestartPodRequest>,
 ) -> Result<Json<DeletePodResponse>> {
+    require_confirmation(&req.confirm, &pod_name)?;
     let client = create_client(&claims).await?;
     let api: Api<corev1::Pod> = Api::namespaced(client, &namespace);
 
@@ -245,19 +276,19 @@ pub async fn restart_pod(
         .map_err(|e| error::map_kube_error(e, format!("Pod '{}'", pod_name)))?;
     ensure_pod_belongs_to_tenant(&pod, &tenant_name, &pod_name)?;
 
-    // Delete; StatefulSet controller recreates the pod
-    let delete_params = if req.force {
-        DeleteParams {
+    // Evict; StatefulSet controller recreates the pod
+    let evict_params = EvictParams {
+        delete_options: req.force.then(|| DeleteParams {
             grace_period_seconds: Some(0),
             ..Default::default()
-        }
-    } else {
-        DeleteParams::default()
+        }),
+        ..Default::default()
     };
 
-    api.delete(&pod_name, &delete_params)
+    api.evict(&pod_name, &evict_params)
         .await
-        .map_err(|e| error::map_kube_error(e, format!("Pod '{}'", pod_name)))?;
+        .map_err(|e| map_eviction_error(e, &namespace, &tenant_name, &pod_name))?;
+    audit(&claims, "restartPod", &namespace, &tenant_name, &pod_name);
 
     Ok(Json(DeletePodResponse {
         success: true,
@@ -287,7 +318,7 @@ pub async fn get_pod_details(
         .metadata
         .labels
         .as_ref()
-        .and_then(|l| l.get("rustfs.pool"))
+        .and_then(|l| l.get(POOL_LABEL))
         .cloned()
         .unwrap_or_else(|| "unknown".to_string());
 
@@ -445,7 +476,7 @@ pub async fn get_pod_logs(
     Ok(Body::from_stream(byte_stream).into_response())
 }
 
-/// Build a client using the Kubernetes bearer token from session claims.
+/// Build a client acting as the session identity (bearer token or impersonation).
 async fn create_client(claims: &Claims) -> Result<Client> {
     let mut config = kube::Config::infer()
         .await
@@ -453,7 +484,7 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
@@ -15,13 +15,18 @@
 use axum::{Extension, Json, extract::Path, http::StatusCode};
 use k8s_openapi::api::apps::v1 as appsv1;
 use k8s_openapi::api::core::v1 as corev1;
-use kube::{Api, Client, ResourceExt, api::ListParams};
+use kube::{
+    Api, Client, ResourceExt,
+    api::{ListParams, Patch, PatchParams},
+};
 
+use super::{audit, require_confirmation};
 use crate::console::{
     error::{self, Error, Result},
     models::{common::ConsoleErrorDetails, pool::*},
     state::Claims,
 };
+use crate::context::statefulset_rollout_complete;
 use crate::types::v1alpha1::{
     persistence::PersistenceConfig,
     pool::{Pool, SchedulingConfig, validate_pool_collection, validate_pool_name},
@@ -30,7 +35,7 @@ use crate::types::v1alpha1::{
     },
     status::next_actions_for_reason,
     status::pool::PoolLifecycleState,
-    tenant::Tenant,
+    tenant::{POOL_LABEL, RESTARTED_AT_ANNOTATION, TENANT_LABEL, Tenant, tenant_selector},
 };
 
 /// Loose validation for a Kubernetes resource quantity (e.g. `10Gi`, `100M`, `1`).
@@ -120,7 +125,7 @@ fn pool_status_requires_decommission_before_spec_removal(
 ) -> bool {
     !matches!(
         pool_status.lifecycle_state,
-        Some(PoolLifecycleState::Decommissioned)
+        Some(PoolLifecycleState::Decommissioned | PoolLifecycleState::Terminating)
     )
 }
 
@@ -329,10 +334,10 @@ fn is_managed_pool_statefulset(
 
     let labels_match = statefulset.metadata.labels.as_ref().is_some_and(|labels| {
         labels
-            .get("rustfs.tenant")
+            .get(TENANT_LABEL)
             .is_some_and(|value| value == &tenant_name)
             && labels
-                .get("rustfs.pool")
+                .get(POOL_LABEL)
                 .is_some_and(|value| value == pool_name)
     });
     if !labels_match {
@@ -414,7 +419,7 @@ pub async fn list_pools(
     // List StatefulSets in namespace
     let ss_api: Api<appsv1::StatefulSet> = Api::namespaced(client, &namespace);
     let statefulsets = ss_api
-        .list(&ListParams::default().labels(&format!("rustfs.tenant={}", tenant_name)))
+        .list(&ListParams::default().labels(&tenant_selector(&tenant_name)))
         .await
         .map_err(|e| {
             error::map_kube_error(e, format!("StatefulSets for tenant '{}'", tenant_name))
@@ -513,7 +518,12 @@ pub async fn list_pools(
             updated_replicas,
             current_revision,
             update_revision,
-            state,
+            state: if pool.is_paused() {
+                "Paused".to_string()
+            } else {
+                state
+            },
+            paused: pool.is_paused(),
             lifecycle_state: recorded_pool_status
                 .and_then(|status| status.lifecycle_state.as_ref())
                 .map(ToString::to_string),
@@ -595,10 +605,13 @@ pub async fn add_pool(
                 storage_class_name: req.storage_class.clone(),
                 ..Default::default()
             }),
-            path: None,
-            labels: None,
-            annotations: None,
+            ..Default::default()
         },
+        paused: None,
+        env: Vec::new(),
+        service_account_name: None,
+        pod_metadata: None,
+        pod_deletion_policy_when_node_is_down: None,
         scheduling: SchedulingConfig {
             node_selector: req.node_selector,
             resources: req.resources.map(|r| corev1::ResourceRequirements {
@@ -685,6 +698,7 @@ pub async fn add_pool(
                         current_revision: None,
                         update_revision: None,
                         state: "Creating".to_string(),
+                        paused: false,
                         lifecycle_state: None,
                         workload_state: None,
                         decommission_phase: None,
@@ -743,6 +757,9 @@ async fn write_pool_decommission_request(
 
         if action == DecommissionAction::Start {
             ensure_pool_delete_does_not_remove_last_pool(tenant.spec.pools.len())?;
+            tenant
+                .validate_pool_decommission(&pool_name)
+                .map_err(|message| Error::BadRequest { message })?;
         }
 
         let existing_decommission_request = tenant
@@ -952,7 +969,139 @@ pub async fn delete_pool(
     }))
 }
 
-/// Build a client using the Kubernetes bearer token from session claims.
+/// Rollout progress of one pool, for upgrade progress bars.
+pub async fn get_pool_rollout(
+    Path((namespace, tenant_name, pool_name)): Path<(String, String, String)>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<PoolRolloutResponse>> {
+    let client = create_client(&claims).await?;
+    let tenant_api: Api<Tenant> = Api::namespaced(client.clone(), &namespace);
+    let ss_api: Api<appsv1::StatefulSet> = Api::namespaced(client, &namespace);
+
+    let tenant = tenant_api
+        .get(&tenant_name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", tenant_name)))?;
+    if !tenant.spec.pools.iter().any(|p| p.name == pool_name) {
+        return Err(Error::NotFound {
+            resource: format!("Pool '{}'", pool_name),
+        });
+    }
+
+    let ss_name = format!("{}-{}", tenant_name, pool_name);
+    let statefulset = ss_api
+        .get(&ss_name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("StatefulSet '{}'", ss_name)))?;
+
+    Ok(Json(pool_rollout(&pool_name, &statefulset)))
+}
+
+fn pool_rollout(pool_name: &str, statefulset: &appsv1::StatefulSet) -> PoolRolloutResponse {
+    let spec = statefulset.spec.as_ref();
+    let status = statefulset.status.as_ref();
+    let desired_replicas = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let updated_replicas = status.and_then(|s| s.updated_replicas).unwrap_or(0);
+    let progress_percent = if desired_replicas > 0 {
+        (i64::from(updated_replicas.clamp(0, desired_replicas)) * 100 / i64::from(desired_replicas))
+            as u8
+    } else {
+        100
+    };
+
+    PoolRolloutResponse {
+        pool: pool_name.to_string(),
+        statefulset: statefulset.name_any(),
+        desired_replicas,
+        replicas: status.map(|s| s.replicas).unwrap_or(0),
+        ready_replicas: status.and_then(|s| s.ready_replicas).unwrap_or(0),
+        updated_replicas,
+        current_revision: status.and_then(|s| s.current_revision.clone()),
+        update_revision: status.and_then(|s| s.update_revision.clone()),
+        partition: spec
+            .and_then(|s| s.update_strategy.as_ref())
+            .and_then(|strategy| strategy.rolling_update.as_ref())
+            .and_then(|rolling| rolling.partition)
+            .filter(|partition| *partition > 0),
+        generation_observed: statefulset.metadata.generation.is_some()
+            && status.and_then(|s| s.observed_generation) == statefulset.metadata.generation,
+        complete: statefulset_rollout_complete(statefulset),
+        progress_percent,
+    }
+}
+
+/// Restart every pod of a pool by stamping the pod template, like `kubectl rollout restart`.
+/// The StatefulSet controller then replaces the pods one at a time.
+pub async fn rollout_restart_pool(
+    Path((namespace, tenant_name, pool_name)): Path<(String, String, String)>,
+    Extension(claims): Extension<Claims>,
+    Json(req): Json<RolloutRestartPoolRequest>,
+) -> Result<Json<RolloutRestartPoolResponse>> {
+    require_confirmation(&req.confirm, &pool_name)?;
+    let client = create_client(&claims).await?;
+    let tenant_api: Api<Tenant> = Api::namespaced(client.clone(), &namespace);
+    let ss_api: Api<appsv1::StatefulSet> = Api::namespaced(client, &namespace);
+
+    let tenant = tenant_api
+        .get(&tenant_name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", tenant_name)))?;
+    if !tenant.spec.pools.iter().any(|p| p.name == pool_name) {
+        return Err(Error::NotFound {
+            resource: format!("Pool '{}'", pool_name),
+        });
+    }
+
+    let ss_name = format!("{}-{}", tenant_name, pool_name);
+    let statefulset = ss_api
+        .get(&ss_name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("StatefulSet '{}'", ss_name)))?;
+    if !is_managed_pool_statefulset(&tenant, &statefulset, &pool_name) {
+        return Err(Error::NotFound {
+            resource: format!("StatefulSet '{}'", ss_name),
+        });
+    }
+
+    let restarted_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    ss_api
+        .patch(
+            &ss_name,
+            &PatchParams::default(),
+            &Patch::Merge(rollout_restart_patch(&restarted_at)),
+        )
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("StatefulSet '{}'", ss_name)))?;
+    audit(
+        &claims,
+        "rolloutRestartPool",
+        &namespace,
+        &tenant_name,
+        &pool_name,
+    );
+
+    Ok(Json(RolloutRestartPoolResponse {
+        success: true,
+        message: format!("Pool '{}' rollout restart initiated", pool_name),
+        restarted_at,
+    }))
+}
+
+fn rollout_restart_patch(restarted_at: &str) -> serde_json::Value {
+    serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        RESTARTED_AT_ANNOTATION: restarted_at,
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Build a client acting as the session identity (bearer token or impersonation).
 async fn create_client(claims: &Claims) -> Result<Client> {
     let mut config = kube::Config::infer()
         .await
@@ -960,7 +1109,7 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
@@ -974,7 +1123,8 @@ mod tests {
         decommission_request_can_replace, has_recorded_pool_status_requiring_decommission,
         is_managed_pool_statefulset, is_pool_observation_current,
         pool_delete_observation_pending_error, pool_delete_requires_decommission_error,
-        remove_decommission_request, upsert_decommission_request, validate_lifecycle_request_id,
+        pool_rollout, remove_decommission_request, rollout_restart_patch,
+        upsert_decommission_request, validate_lifecycle_request_id,
     };
     use crate::console::error::Error;
     use crate::types::v1alpha1::{
@@ -1120,6 +1270,9 @@ mod tests {
                 current_revision: None,
                 update_revision: None,
                 last_update_time: None,
+                rollout_started_at: None,
+                pods: Vec::new(),
+                failed_volumes: Vec::new(),
             }],
             ..Default::default()
         });
@@ -1150,6 +1303,9 @@ mod tests {
                 current_revision: None,
                 update_revision: None,
                 last_update_time: None,
+                rollout_started_at: None,
+                pods: Vec::new(),
+                failed_volumes: Vec::new(),
             }],
             ..Default::default()
         });
@@ -1240,6 +1396,9 @@ mod tests {
                 current_revision: None,
                 update_revision: None,
                 last_update_time: None,
+                rollout_started_at: None,
+                pods: Vec::new(),
+                failed_volumes: Vec::new(),
             }],
             ..Default::default()
         });
@@ -1315,6 +1474,9 @@ mod tests {
                 current_revision: None,
                 update_revision: None,
                 last_update_time: None,
+                rollout_started_at: None,
+                pods: Vec::new(),
+                failed_volumes: Vec::new(),
             }],
             ..Default::default()
         });
@@ -1348,6 +1510,10 @@ mod tests {
                         state: PoolDecommissionCleanupState::PvcRetained,
                         stateful_set_deleted_at: Some("2026-05-20T00:00:00Z".to_string()),
                         pvc_retention_policy: Some("Retain".to_string()),
+                        removed_from_spec_at: None,
+                        remaining_pods: None,
+                        retained_claims: None,
+                        terminating_claims: None,
                     }),
                     request_id: Some("request-1".to_string()),
                     rustfs_pool_id: Some("1".to_string()),
@@ -1367,6 +1533,9 @@ mod tests {
             current_revision: None,
             update_revision: None,
             last_update_time: None,
+            rollout_started_at: None,
+            pods: Vec::new(),
+            failed_volumes: Vec::new(),
         };
 
         assert!(!super::pool_status_requires_decommission_before_spec_removal(&pool_status));
@@ -1447,4 +1616,50 @@ mod tests {
         unowned.metadata.owner_references = None;
         assert!(!is_managed_pool_statefulset(&tenant, &unowned, "pool-a"));
     }
+
+    #[test]
+    fn rollout_restart_patch_stamps_the_pod_template() {
+        let patch = rollout_restart_patch("2025-01-01T00:00:00Z");
+        assert_eq!(
+            patch["spec"]["template"]["metadata"]["annotations"]["kubectl.kubernetes.io/restartedAt"],
+            "2025-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn pool_rollout_reports_progress_and_completion() {
+        let mut statefulset = k8s_openapi::api::apps::v1::StatefulSet {
+            metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+                name: Some("tenant-a-pool-a".to_string()),
+                generation: Some(3),
+                ..Default::default()
+            },
+            spec: Some(k8s_openapi::api::apps::v1::StatefulSetSpec {
+                replicas: Some(4),
+                ..Default::default()
+            }),
+            status: Some(k8s_openapi::api::apps::v1::StatefulSetStatus {
+                observed_generation: Some(3),
+                replicas: 4,
+                ready_replicas: Some(4),
+                updated_replicas: Some(1),
+                current_revision: Some("rev-1".to_string()),
+                update_revision: Some("rev-2".to_string()),
+                ..Default::default()
+            }),
+        };
+
+        let rollout = pool_rollout("pool-a", &statefulset);
+        assert_eq!(rollout.progress_percent, 25);
+        assert!(rollout.generation_observed);
+        assert!(!rollout.complete);
+        assert_eq!(rollout.partition, None);
+
+        let status = statefulset.status.as_mut().expect("status");
+        status.updated_replicas = Some(4);
+        status.current_revision = Some("rev-2".to_string());
+        let rollout = pool_rollout("pool-a", &statefulset);
+        assert_eq!(rollout.progress_percent, 100);
+        assert!(rollout.complete);
+    }
 }
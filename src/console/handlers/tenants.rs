@@ -12,20 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::bundle::{self, ExportOptions, TenantBundle};
 use crate::console::{
     error::{self, Error, Result},
-    models::tenant::*,
-    state::Claims,
+    models::{common::ConsoleErrorDetails, tenant::*},
+    state::{AppState, Claims},
 };
+use crate::reconcile::namespace_policy::NamespaceFilter;
 use crate::types::v1alpha1::{
+    credentials::CredsSecretReference,
     encryption::PodSecurityContextOverride,
     persistence::PersistenceConfig,
-    pool::{Pool, validate_pool_shape_immutable},
-    tenant::{Tenant, TenantSpec},
+    pool::{Pool, detect_pool_renames, validate_pool_shape_immutable},
+    tenant::{TENANT_LABEL, Tenant, TenantSpec, tenant_selector},
 };
 use axum::{
     Extension, Json,
-    extract::{Path, Query},
+    extract::{Path, Query, State},
+    http::StatusCode,
 };
 use k8s_openapi::api::core::v1 as corev1;
 use kube::{
@@ -123,7 +127,7 @@ pub async fn get_tenant_details(
     // List tenant-scoped Services
     let svc_api: Api<corev1::Service> = Api::namespaced(client, &namespace);
     let services = svc_api
-        .list(&ListParams::default().labels(&format!("rustfs.tenant={}", name)))
+        .list(&ListParams::default().labels(&tenant_selector(&name)))
         .await
         .map_err(|e| error::map_kube_error(e, format!("Services for tenant '{}'", name)))?;
 
@@ -179,18 +183,11 @@ pub async fn get_tenant_details(
     Ok(Json(TenantDetailsResponse {
         name: tenant.name_any(),
         namespace: tenant.namespace().unwrap_or_default(),
-        pools: tenant
-            .spec
-            .pools
-            .iter()
-            .map(|p| PoolInfo {
-                name: p.name.clone(),
-                servers: p.servers,
-                volumes_per_server: p.persistence.volumes_per_server,
-            })
-            .collect(),
+        pools: tenant.spec.pools.iter().map(PoolInfo::from).collect(),
         state: status_summary.current_state.clone(),
         status_summary,
+        ready_replicas: tenant_ready_replicas(&tenant),
+        health: tenant_health(&tenant),
         conditions,
         next_actions,
         certificates,
@@ -207,6 +204,7 @@ pub async fn get_tenant_details(
 
 /// Create a Tenant CR (and namespace if missing).
 pub async fn create_tenant(
+    State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Json(req): Json<CreateTenantRequest>,
 ) -> Result<Json<TenantListItem>> {
@@ -217,25 +215,35 @@ pub async fn create_tenant(
         });
     }
 
+    if let Err(message) = NamespaceFilter::from_env().check(&req.namespace) {
+        return Err(Error::Forbidden { message });
+    }
+
     let client = create_client(&claims).await?;
 
     // Ensure namespace exists
     let ns_api: Api<corev1::Namespace> = Api::all(client.clone());
     let ns_exists = ns_api.get(&req.namespace).await.is_ok();
 
-    // Create when absent
+    // Create when absent, with the operator-level namespace template if one is configured
     if !ns_exists {
-        let ns = corev1::Namespace {
-            metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
-                name: Some(req.namespace.clone()),
+        let ns = match &state.namespace_template {
+            Some(template) => template.namespace(&req.namespace),
+            None => corev1::Namespace {
+                metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+                    name: Some(req.namespace.clone()),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
-            ..Default::default()
         };
         ns_api
             .create(&Default::default(), &ns)
             .await
             .map_err(|e| error::map_kube_error(e, format!("Namespace '{}'", req.namespace)))?;
+        if let Some(template) = &state.namespace_template {
+            template.apply(&client, &req.namespace).await?;
+        }
     }
 
     // Build Tenant object
@@ -265,10 +273,13 @@ pub async fn create_tenant(
                     storage_class_name: p.storage_class,
                     ..Default::default()
                 }),
-                path: None,
-                labels: None,
-                annotations: None,
+                ..Default::default()
             },
+            paused: None,
+            env: Vec::new(),
+            service_account_name: None,
+            pod_metadata: None,
+            pod_deletion_policy_when_node_is_down: None,
             scheduling: Default::default(),
         })
         .collect();
@@ -292,10 +303,9 @@ pub async fn create_tenant(
         spec: crate::types::v1alpha1::tenant::TenantSpec {
             pools,
             image: req.image,
+            profile: req.profile,
             mount_path: req.mount_path,
-            creds_secret: req
-                .creds_secret
-                .map(|name| corev1::LocalObjectReference { name }),
+            creds_secret: req.creds_secret.map(CredsSecretReference::new),
             policies: req.policies.unwrap_or_default(),
             users: req.users.unwrap_or_default(),
             buckets: req.buckets.unwrap_or_default(),
@@ -311,6 +321,13 @@ pub async fn create_tenant(
     }
 
     let api: Api<Tenant> = Api::namespaced(client.clone(), &req.namespace);
+    if let Some(live) = api
+        .get_opt(&req.name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", req.name)))?
+    {
+        ensure_no_pool_renames(&req.namespace, &req.name, &live, &tenant.spec.pools)?;
+    }
     let created = api
         .create(&Default::default(), &tenant)
         .await
@@ -318,7 +335,7 @@ pub async fn create_tenant(
 
     label_provisioning_references(&client, &req.namespace, &req.name, &created.spec).await;
 
-    let item = tenant_to_list_item(created);
+    let item = TenantListItem::from(created);
 
     Ok(Json(item))
 }
@@ -386,9 +403,10 @@ pub async fn update_tenant(
             tenant.spec.creds_secret = None;
             updated_fields.push("creds_secret=<removed>".to_string());
         } else {
-            tenant.spec.creds_secret = Some(corev1::LocalObjectReference {
-                name: creds_secret.clone(),
-            });
+            // Keep the provider of an existing reference; only the Secret name is editable here.
+            let mut reference = tenant.spec.creds_secret.take().unwrap_or_default();
+            reference.name = creds_secret.clone();
+            tenant.spec.creds_secret = Some(reference);
             updated_fields.push(format!("creds_secret={}", creds_secret));
         }
     }
@@ -491,7 +509,7 @@ pub async fn update_tenant(
     Ok(Json(UpdateTenantResponse {
         success: true,
         message: format!("Tenant updated: {}", updated_fields.join(", ")),
-        tenant: tenant_to_list_item(updated_tenant),
+        tenant: TenantListItem::from(updated_tenant),
     }))
 }
 
@@ -518,6 +536,51 @@ pub async fn get_tenant_yaml(
     Ok(Json(TenantYAML { yaml: yaml_str }))
 }
 
+/// Condition transitions recorded in the Tenant status, newest first.
+pub async fn get_tenant_condition_history(
+    Path((namespace, name)): Path<(String, String)>,
+    Query(query): Query<ConditionHistoryQuery>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<TenantConditionHistoryResponse>> {
+    let client = create_client(&claims).await?;
+    let api: Api<Tenant> = Api::namespaced(client, &namespace);
+    let tenant = api
+        .get(&name)
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", name)))?;
+
+    Ok(Json(TenantConditionHistoryResponse {
+        transitions: tenant_condition_history(&tenant, query.type_.as_deref()),
+    }))
+}
+
+/// Return a restorable bundle of the Tenant, its Secrets, Services and PVC specs.
+pub async fn export_tenant(
+    Path((namespace, name)): Path<(String, String)>,
+    Query(query): Query<TenantExportQuery>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<TenantYAML>> {
+    let client = create_client(&claims).await?;
+    let options = ExportOptions {
+        redact_secrets: query.redact_secrets,
+    };
+    let bundle = TenantBundle::export(client, &namespace, &name, &options)
+        .await
+        .map_err(|e| match e {
+            bundle::Error::Kube { source, .. } => {
+                error::map_kube_error(*source, format!("Tenant '{}'", name))
+            }
+            other => Error::InternalServer {
+                message: format!("Failed to export Tenant: {}", other),
+            },
+        })?;
+    let yaml = bundle.to_yaml().map_err(|e| Error::InternalServer {
+        message: format!("Failed to export Tenant: {}", e),
+    })?;
+
+    Ok(Json(TenantYAML { yaml }))
+}
+
 /// Apply raw YAML for a Tenant (server-side apply or replace).
 pub async fn put_tenant_yaml(
     Path((namespace, name)): Path<(String, String)>,
@@ -564,6 +627,7 @@ pub async fn put_tenant_yaml(
         .await
         .map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", name)))?;
 
+    ensure_no_pool_renames(&namespace, &name, &current, &in_tenant.spec.pools)?;
     if let Err(message) = validate_pool_shape_immutable(&current.spec.pools, &in_tenant.spec.pools)
     {
         return Err(Error::BadRequest { message });
@@ -602,7 +666,7 @@ pub async fn put_tenant_yaml(
     Ok(Json(TenantYAML { yaml: yaml_str }))
 }
 
-/// Build a client using the Kubernetes bearer token from session claims.
+/// Build a client acting as the session identity (bearer token or impersonation).
 async fn create_client(claims: &Claims) -> Result<Client> {
     let mut config = kube::Config::infer()
         .await
@@ -610,7 +674,7 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
@@ -624,7 +688,7 @@ fn build_tenant_list_items(
     tenants
         .into_iter()
         .filter_map(|t| {
-            let item = tenant_to_list_item(t);
+            let item = TenantListItem::from(t);
             if state_matches_filter(&item.state, state_filter) {
                 Some(item)
             } else {
@@ -647,7 +711,7 @@ fn state_matches_filter(state: &str, state_filter: Option<&str>) -> bool {
     }
 }
 
-fn summarize_tenant_states(tenants: &[Tenant]) -> TenantStateCountsResponse {
+pub(crate) fn summarize_tenant_states(tenants: &[Tenant]) -> TenantStateCountsResponse {
     let mut counts = std::collections::BTreeMap::new();
     for tenant in tenants {
         let state = tenant_state(tenant);
@@ -669,7 +733,7 @@ async fn label_provisioning_references(
     let patch = json!({
         "metadata": {
             "labels": {
-                "rustfs.tenant": tenant_name,
+                TENANT_LABEL: tenant_name,
             },
         },
     });
@@ -712,9 +776,46 @@ async fn label_provisioning_references(
     }
 }
 
+/// Rejects a pool rename with 409 before it reaches the API server. The reconcile would otherwise
+/// fail later with `ImmutableFieldModified` or provision an empty pool under the new name.
+fn ensure_no_pool_renames(
+    namespace: &str,
+    tenant_name: &str,
+    live: &Tenant,
+    desired: &[Pool],
+) -> Result<()> {
+    let Some((old, new)) = detect_pool_renames(&live.spec.pools, desired)
+        .into_iter()
+        .next()
+    else {
+        return Ok(());
+    };
+    Err(Error::ActionRequired {
+        status: StatusCode::CONFLICT,
+        code: "PoolRenameNotAllowed".to_string(),
+        reason: "PoolRenameNotAllowed".to_string(),
+        message: format!(
+            "Pool '{}' cannot be renamed to '{}': the pool name is part of its StatefulSet, volume claims, and RustFS peer addresses. Restore the name '{}', or add '{}' as a new pool and decommission '{}'.",
+            old, new, old, new, old
+        ),
+        next_actions: crate::types::v1alpha1::status::next_actions_for_reason(
+            "PoolRenameNotAllowed",
+        )
+        .into_iter()
+        .map(str::to_string)
+        .collect(),
+        details: Some(Box::new(ConsoleErrorDetails {
+            namespace: Some(namespace.to_string()),
+            tenant: Some(tenant_name.to_string()),
+            resource: Some(old),
+        })),
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::state_matches_filter;
+    use super::{ensure_no_pool_renames, state_matches_filter};
+    use crate::console::error::Error;
 
     #[test]
     fn state_filter_is_case_insensitive_for_known_states() {
@@ -727,4 +828,29 @@ mod tests {
     fn unknown_filter_value_does_not_match_unknown_state() {
         assert!(!state_matches_filter("Unknown", Some("foo")));
     }
+
+    #[test]
+    fn pool_rename_is_rejected_with_conflict() {
+        let live = crate::tests::create_test_tenant(None, None);
+        let mut desired = live.spec.pools.clone();
+        assert!(ensure_no_pool_renames("default", "test-tenant", &live, &desired).is_ok());
+
+        desired[0].name = "pool-a".to_string();
+        match ensure_no_pool_renames("default", "test-tenant", &live, &desired) {
+            Err(Error::ActionRequired {
+                status,
+                code,
+                next_actions,
+                ..
+            }) => {
+                assert_eq!(status, axum::http::StatusCode::CONFLICT);
+                assert_eq!(code, "PoolRenameNotAllowed");
+                assert_eq!(
+                    next_actions,
+                    vec!["restorePoolName", "addPoolThenDecommission"]
+                );
+            }
+            other => panic!("expected PoolRenameNotAllowed, got {other:?}"),
+        }
+    }
 }
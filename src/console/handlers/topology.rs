@@ -22,11 +22,17 @@ use crate::console::{
     models::topology::*,
     state::Claims,
 };
-use crate::types::v1alpha1::{status::pool::PoolState, tenant::Tenant};
-use axum::{Extension, Json};
+use crate::types::v1alpha1::{
+    status::pool::PoolState,
+    tenant::{POOL_LABEL, TENANT_LABEL, Tenant, tenant_selector},
+};
+
+/// Node label carrying the failure zone.
+const ZONE_LABEL: &str = "topology.kubernetes.io/zone";
+use axum::{Extension, Json, extract::Path};
 use k8s_openapi::api::core::v1 as corev1;
 use kube::{Api, Client, ResourceExt, api::ListParams};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Aggregated topology for the dashboard (nodes, namespaces, tenants, pods).
 pub async fn get_topology_overview(
@@ -41,7 +47,7 @@ pub async fn get_topology_overview(
 
     let node_params = ListParams::default();
     let tenant_params = ListParams::default();
-    let pod_params = ListParams::default().labels("rustfs.tenant");
+    let pod_params = ListParams::default().labels(TENANT_LABEL);
 
     let (nodes_result, tenants_result, pods_result) = tokio::join!(
         node_api.list(&node_params),
@@ -147,11 +153,11 @@ pub async fn get_topology_overview(
     for pod in &k8s_pods.items {
         let labels = pod.metadata.labels.as_ref();
         let tenant_name = labels
-            .and_then(|l| l.get("rustfs.tenant"))
+            .and_then(|l| l.get(TENANT_LABEL))
             .cloned()
             .unwrap_or_default();
         let pool = labels
-            .and_then(|l| l.get("rustfs.pool"))
+            .and_then(|l| l.get(POOL_LABEL))
             .cloned()
             .unwrap_or_else(|| "unknown".to_string());
         let ns = pod.namespace().unwrap_or_default();
@@ -266,9 +272,18 @@ pub async fn get_topology_overview(
                         })
                         .sum();
 
-                    let endpoint = Some(format!("http://{}-io.{}.svc:9000", name, namespace));
-                    let console_endpoint =
-                        Some(format!("http://{}-console.{}.svc:9001", name, namespace));
+                    let endpoint = Some(format!(
+                        "http://{}-io.{}.svc:{}",
+                        name,
+                        namespace,
+                        t.s3_port()
+                    ));
+                    let console_endpoint = Some(format!(
+                        "http://{}-console.{}.svc:{}",
+                        name,
+                        namespace,
+                        t.console_port()
+                    ));
 
                     // Attach pods collected earlier
                     let key = (namespace.clone(), name.clone());
@@ -333,6 +348,169 @@ pub async fn get_topology_overview(
     }))
 }
 
+/// Pool → pod → node → zone placement of one tenant, with node readiness and disk pressure.
+pub async fn get_tenant_topology(
+    Path((namespace, name)): Path<(String, String)>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<TenantTopologyResponse>> {
+    let client = create_client(&claims).await?;
+
+    let tenant_api: Api<Tenant> = Api::namespaced(client.clone(), &namespace);
+    let pod_api: Api<corev1::Pod> = Api::namespaced(client.clone(), &namespace);
+    let node_api: Api<corev1::Node> = Api::all(client);
+
+    let pod_params = ListParams::default().labels(&tenant_selector(&name));
+    let node_params = ListParams::default();
+    let (tenant_result, pods_result, nodes_result) = tokio::join!(
+        tenant_api.get(&name),
+        pod_api.list(&pod_params),
+        node_api.list(&node_params),
+    );
+
+    let tenant =
+        tenant_result.map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", name)))?;
+    let pods =
+        pods_result.map_err(|e| error::map_kube_error(e, format!("Pods for tenant '{}'", name)))?;
+    let nodes = nodes_result.map_err(|e| error::map_kube_error(e, "Nodes"))?;
+
+    Ok(Json(build_tenant_topology(
+        &tenant,
+        &pods.items,
+        &nodes.items,
+    )))
+}
+
+fn build_tenant_topology(
+    tenant: &Tenant,
+    pods: &[corev1::Pod],
+    nodes: &[corev1::Node],
+) -> TenantTopologyResponse {
+    let node_index: BTreeMap<String, &corev1::Node> =
+        nodes.iter().map(|node| (node.name_any(), node)).collect();
+    let node_zone = |node_name: &str| {
+        node_index
+            .get(node_name)
+            .and_then(|node| node.labels().get(ZONE_LABEL).cloned())
+    };
+
+    let mut warnings = Vec::new();
+    let mut pods_per_node: BTreeMap<String, usize> = BTreeMap::new();
+    let pools = tenant
+        .spec
+        .pools
+        .iter()
+        .map(|spec_pool| {
+            let mut pool_pods: Vec<TenantTopologyPod> = pods
+                .iter()
+                .filter(|pod| {
+                    pod.labels().get(POOL_LABEL).map(String::as_str)
+                        == Some(spec_pool.name.as_str())
+                })
+                .map(|pod| {
+                    let node = pod.spec.as_ref().and_then(|spec| spec.node_name.clone());
+                    TenantTopologyPod {
+                        name: pod.name_any(),
+                        phase: pod
+                            .status
+                            .as_ref()
+                            .and_then(|status| status.phase.clone())
+                            .unwrap_or_else(|| "Unknown".to_string()),
+                        ready: pod_is_ready(pod),
+                        zone: node.as_deref().and_then(node_zone),
+                        node,
+                    }
+                })
+                .collect();
+            pool_pods.sort_by(|left, right| left.name.cmp(&right.name));
+
+            let mut pool_nodes: BTreeMap<&str, usize> = BTreeMap::new();
+            for pod in &pool_pods {
+                match pod.node.as_deref() {
+                    Some(node) => {
+                        *pool_nodes.entry(node).or_default() += 1;
+                        *pods_per_node.entry(node.to_string()).or_default() += 1;
+                    }
+                    None => warnings.push(format!("Pod '{}' is not scheduled", pod.name)),
+                }
+            }
+            for (node, count) in pool_nodes.iter().filter(|(_, count)| **count > 1) {
+                warnings.push(format!(
+                    "Pool '{}' runs {} pods on node '{}'; losing the node takes down {} servers",
+                    spec_pool.name, count, node, count
+                ));
+            }
+            let zones: BTreeSet<&str> = pool_pods
+                .iter()
+                .filter_map(|pod| pod.zone.as_deref())
+                .collect();
+            if zones.len() == 1 && pool_nodes.len() > 1 {
+                warnings.push(format!(
+                    "Pool '{}' runs all pods in zone '{}'",
+                    spec_pool.name,
+                    zones.iter().next().copied().unwrap_or_default()
+                ));
+            }
+
+            TenantTopologyPool {
+                name: spec_pool.name.clone(),
+                servers: spec_pool.servers,
+                pods: pool_pods,
+            }
+        })
+        .collect();
+
+    let nodes = pods_per_node
+        .into_iter()
+        .map(|(name, pod_count)| {
+            let node = node_index.get(&name).copied();
+            let condition_true = |type_: &str| {
+                node.and_then(|node| node.status.as_ref())
+                    .and_then(|status| status.conditions.as_ref())
+                    .and_then(|conditions| conditions.iter().find(|c| c.type_ == type_))
+                    .is_some_and(|condition| condition.status == "True")
+            };
+            let ready = condition_true("Ready");
+            let disk_pressure = condition_true("DiskPressure");
+            if !ready {
+                warnings.push(format!("Node '{}' hosting tenant pods is not Ready", name));
+            }
+            if disk_pressure {
+                warnings.push(format!(
+                    "Node '{}' hosting tenant pods has DiskPressure",
+                    name
+                ));
+            }
+            TenantTopologyNode {
+                zone: node_zone(&name),
+                ready,
+                disk_pressure,
+                unschedulable: node
+                    .and_then(|node| node.spec.as_ref())
+                    .and_then(|spec| spec.unschedulable)
+                    .unwrap_or(false),
+                pod_count,
+                name,
+            }
+        })
+        .collect();
+
+    TenantTopologyResponse {
+        name: tenant.name_any(),
+        namespace: tenant.namespace().unwrap_or_default(),
+        pools,
+        nodes,
+        warnings,
+    }
+}
+
+fn pod_is_ready(pod: &corev1::Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .and_then(|conditions| conditions.iter().find(|c| c.type_ == "Ready"))
+        .is_some_and(|condition| condition.status == "True")
+}
+
 /// Map operator `PoolState` to a short UI label.
 fn map_pool_state(state: &PoolState) -> String {
     match state {
@@ -342,11 +520,12 @@ fn map_pool_state(state: &PoolState) -> String {
         PoolState::Updating => "Updating".to_string(),
         PoolState::Degraded | PoolState::RolloutFailed => "Degraded".to_string(),
         PoolState::NotCreated => "NotReady".to_string(),
+        PoolState::Paused => "Paused".to_string(),
     }
 }
 
 /// Bytes per PVC volume from `PersistenceConfig` (default 10Gi).
-fn get_per_volume_bytes(
+pub(crate) fn get_per_volume_bytes(
     persistence: &crate::types::v1alpha1::persistence::PersistenceConfig,
 ) -> i64 {
     const DEFAULT_BYTES: i64 = 10 * 1024 * 1024 * 1024; // 10Gi
@@ -362,7 +541,7 @@ fn get_per_volume_bytes(
 }
 
 /// Human-readable storage size (prefer TiB/GiB).
-fn format_storage_bytes(b: i64) -> String {
+pub(crate) fn format_storage_bytes(b: i64) -> String {
     const TIB: i64 = 1024 * 1024 * 1024 * 1024;
     const GIB: i64 = 1024 * 1024 * 1024;
     const MIB: i64 = 1024 * 1024;
@@ -393,7 +572,7 @@ async fn get_cluster_version(client: &Client) -> String {
     }
 }
 
-/// Build a client using the Kubernetes bearer token from session claims.
+/// Build a client acting as the session identity (bearer token or impersonation).
 async fn create_client(claims: &Claims) -> Result<Client> {
     let mut config = kube::Config::infer()
         .await
@@ -401,9 +580,92 @@ async fn create_client(claims: &Claims) -> Result<Client> {
             message: format!("Failed to load kubeconfig: {}", e),
         })?;
 
-    config.auth_info.token = Some(claims.k8s_token.clone().into());
+    claims.apply_to(&mut config);
 
     Client::try_from(config).map_err(|e| Error::InternalServer {
         message: format!("Failed to create K8s client: {}", e),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{POOL_LABEL, ZONE_LABEL, build_tenant_topology};
+    use k8s_openapi::api::core::v1 as corev1;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn pod(name: &str, node: Option<&str>) -> corev1::Pod {
+        corev1::Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some(BTreeMap::from([(
+                    POOL_LABEL.to_string(),
+                    "pool-0".to_string(),
+                )])),
+                ..Default::default()
+            },
+            spec: Some(corev1::PodSpec {
+                node_name: node.map(str::to_string),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn node(name: &str, zone: &str, conditions: &[(&str, &str)]) -> corev1::Node {
+        corev1::Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some(BTreeMap::from([(ZONE_LABEL.to_string(), zone.to_string())])),
+                ..Default::default()
+            },
+            status: Some(corev1::NodeStatus {
+                conditions: Some(
+                    conditions
+                        .iter()
+                        .map(|(type_, status)| corev1::NodeCondition {
+                            type_: type_.to_string(),
+                            status: status.to_string(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tenant_topology_maps_pods_to_nodes_and_flags_bad_placement() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pods = [
+            pod("test-tenant-pool-0-1", Some("node-a")),
+            pod("test-tenant-pool-0-0", Some("node-a")),
+            pod("test-tenant-pool-0-2", None),
+        ];
+        let nodes = [node(
+            "node-a",
+            "zone-1",
+            &[("Ready", "True"), ("DiskPressure", "True")],
+        )];
+
+        let topology = build_tenant_topology(&tenant, &pods, &nodes);
+
+        let pool = &topology.pools[0];
+        assert_eq!(pool.pods[0].name, "test-tenant-pool-0-0");
+        assert_eq!(pool.pods[0].zone.as_deref(), Some("zone-1"));
+        assert_eq!(topology.nodes.len(), 1);
+        assert_eq!(topology.nodes[0].pod_count, 2);
+        assert!(topology.nodes[0].ready && topology.nodes[0].disk_pressure);
+        assert_eq!(
+            topology.warnings,
+            vec![
+                "Pod 'test-tenant-pool-0-2' is not scheduled".to_string(),
+                "Pool 'pool-0' runs 2 pods on node 'node-a'; losing the node takes down 2 servers"
+                    .to_string(),
+                "Node 'node-a' hosting tenant pods has DiskPressure".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::console::{
+    error::{self, Error, Result},
+    handlers::cluster::{parse_cpu_to_millicores, parse_memory_to_bytes},
+    models::usage::*,
+    state::Claims,
+};
+use crate::types::v1alpha1::tenant::{POOL_LABEL, Tenant, tenant_selector};
+use axum::{Extension, Json, extract::Path};
+use k8s_openapi::api::core::v1 as corev1;
+use kube::{
+    Api, Client, ResourceExt,
+    api::{ApiResource, DynamicObject, ListParams},
+};
+use std::collections::BTreeMap;
+
+/// Resource usage of a tenant: CPU and memory requests vs. metrics-server usage, and
+/// PersistentVolumeClaim capacity vs. the raw capacity RustFS reports, per pool.
+pub async fn get_tenant_usage(
+    Path((namespace, name)): Path<(String, String)>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<TenantUsageResponse>> {
+    let client = create_client(&claims).await?;
+
+    let tenant_api: Api<Tenant> = Api::namespaced(client.clone(), &namespace);
+    let pod_api: Api<corev1::Pod> = Api::namespaced(client.clone(), &namespace);
+    let claim_api: Api<corev1::PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
+    let metrics_api: Api<DynamicObject> =
+        Api::namespaced_with(client, &namespace, &pod_metrics_resource());
+
+    let params = ListParams::default().labels(&tenant_selector(&name));
+    let (tenant_result, pods_result, claims_result, metrics_result) = tokio::join!(
+        tenant_api.get(&name),
+        pod_api.list(&params),
+        claim_api.list(&params),
+        metrics_api.list(&params),
+    );
+
+    let tenant =
+        tenant_result.map_err(|e| error::map_kube_error(e, format!("Tenant '{}'", name)))?;
+    let pods =
+        pods_result.map_err(|e| error::map_kube_error(e, format!("Pods for tenant '{}'", name)))?;
+    let volume_claims = claims_result.map_err(|e| {
+        error::map_kube_error(e, format!("PersistentVolumeClaims for tenant '{}'", name))
+    })?;
+    // metrics-server is optional; without it only requests are reported.
+    let pod_metrics = match metrics_result {
+        Ok(list) => Some(list.items),
+        Err(error) => {
+            tracing::debug!(%namespace, tenant = %name, %error, "pod metrics unavailable");
+            None
+        }
+    };
+
+    Ok(Json(build_tenant_usage(
+        &tenant,
+        &pods.items,
+        &volume_claims.items,
+        pod_metrics.as_deref(),
+    )))
+}
+
+fn pod_metrics_resource() -> ApiResource {
+    ApiResource {
+        group: "metrics.k8s.io".to_string(),
+        version: "v1beta1".to_string(),
+        api_version: "metrics.k8s.io/v1beta1".to_string(),
+        kind: "PodMetrics".to_string(),
+        plural: "pods".to_string(),
+    }
+}
+
+fn build_tenant_usage(
+    tenant: &Tenant,
+    pods: &[corev1::Pod],
+    volume_claims: &[corev1::PersistentVolumeClaim],
+    pod_metrics: Option<&[DynamicObject]>,
+) -> TenantUsageResponse {
+    let usage_by_pod: BTreeMap<String, (i64, i64)> = pod_metrics
+        .unwrap_or_default()
+        .iter()
+        .map(|metrics| (metrics.name_any(), pod_metrics_usage(metrics)))
+        .collect();
+    let capacity = tenant
+        .status
+        .as_ref()
+        .and_then(|status| status.health.as_ref())
+        .and_then(|health| health.capacity.as_ref().map(|capacity| (health, capacity)));
+    let in_pool = |labels: &BTreeMap<String, String>, pool: &str| {
+        labels.get(POOL_LABEL).map(String::as_str) == Some(pool)
+    };
+
+    let pools: Vec<PoolUsage> = tenant
+        .spec
+        .pools
+        .iter()
+        .map(|pool| {
+            let mut usage = UsageFigures::default();
+            if pod_metrics.is_some() {
+                usage.cpu_usage_millicores = Some(0);
+                usage.memory_usage_bytes = Some(0);
+            }
+            for pod in pods.iter().filter(|pod| in_pool(pod.labels(), &pool.name)) {
+                usage.pods += 1;
+                let (cpu, memory) = pod_requests(pod);
+                usage.cpu_request_millicores += cpu;
+                usage.memory_request_bytes += memory;
+                if let Some((cpu, memory)) = usage_by_pod.get(&pod.name_any()) {
+                    usage.cpu_usage_millicores = usage.cpu_usage_millicores.map(|sum| sum + cpu);
+                    usage.memory_usage_bytes = usage.memory_usage_bytes.map(|sum| sum + memory);
+                }
+            }
+            for claim in volume_claims
+                .iter()
+                .filter(|claim| in_pool(claim.labels(), &pool.name))
+            {
+                usage.volume_claims += 1;
+                usage.volume_requested_bytes += storage_quantity(
+                    claim
+                        .spec
+                        .as_ref()
+                        .and_then(|spec| spec.resources.as_ref())
+                        .and_then(|resources| resources.requests.as_ref()),
+                );
+                usage.volume_capacity_bytes += storage_quantity(
+                    claim
+                        .status
+                        .as_ref()
+                        .and_then(|status| status.capacity.as_ref()),
+                );
+            }
+            if let Some(reported) = capacity.and_then(|(_, capacity)| {
+                capacity
+                    .pools
+                    .iter()
+                    .find(|reported| reported.name == pool.name)
+            }) {
+                usage.s3_raw_capacity_bytes = Some(reported.raw_capacity_bytes);
+                usage.s3_raw_used_bytes = Some(reported.raw_used_bytes);
+            }
+            PoolUsage {
+                name: pool.name.clone(),
+                usage,
+            }
+        })
+        .collect();
+
+    let mut totals = UsageFigures {
+        cpu_usage_millicores: pod_metrics.map(|_| 0),
+        memory_usage_bytes: pod_metrics.map(|_| 0),
+        s3_raw_capacity_bytes: capacity.map(|(_, capacity)| capacity.raw_capacity_bytes),
+        s3_raw_used_bytes: capacity.map(|(_, capacity)| capacity.raw_used_bytes),
+        ..Default::default()
+    };
+    for pool in &pools {
+        let usage = &pool.usage;
+        totals.pods += usage.pods;
+        totals.cpu_request_millicores += usage.cpu_request_millicores;
+        totals.memory_request_bytes += usage.memory_request_bytes;
+        totals.cpu_usage_millicores = totals
+            .cpu_usage_millicores
+            .zip(usage.cpu_usage_millicores)
+            .map(|(total, pool)| total + pool);
+        totals.memory_usage_bytes = totals
+            .memory_usage_bytes
+            .zip(usage.memory_usage_bytes)
+            .map(|(total, pool)| total + pool);
+        totals.volume_claims += usage.volume_claims;
+        totals.volume_requested_bytes += usage.volume_requested_bytes;
+        totals.volume_capacity_bytes += usage.volume_capacity_bytes;
+    }
+
+    TenantUsageResponse {
+        name: tenant.name_any(),
+        namespace: tenant.namespace().unwrap_or_default(),
+        metrics_available: pod_metrics.is_some(),
+        storage_reported_at: capacity.and_then(|(health, _)| health.last_check_time.clone()),
+        totals,
+        pools,
+    }
+}
+
+/// Summed CPU (millicores) and memory (bytes) requests of the pod's containers.
+fn pod_requests(pod: &corev1::Pod) -> (i64, i64) {
+    pod.spec
+        .iter()
+        .flat_map(|spec| &spec.containers)
+        .filter_map(|container| container.resources.as_ref()?.requests.as_ref())
+        .fold((0, 0), |(cpu, memory), requests| {
+            (
+                cpu + requests
+                    .get("cpu")
+                    .map_or(0, |quantity| parse_cpu_to_millicores(&quantity.0)),
+                memory
+                    + requests
+                        .get("memory")
+                        .map_or(0, |quantity| parse_memory_to_bytes(&quantity.0)),
+            )
+        })
+}
+
+/// Summed CPU (millicores) and memory (bytes) usage of a `PodMetrics` object.
+fn pod_metrics_usage(metrics: &DynamicObject) -> (i64, i64) {
+    metrics
+        .data
+        .get("containers")
+        .and_then(|containers| containers.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|container| container.get("usage"))
+        .fold((0, 0), |(cpu, memory), usage| {
+            let quantity = |key: &str| usage.get(key).and_then(|value| value.as_str());
+            (
+                cpu + quantity("cpu").map_or(0, parse_cpu_to_millicores),
+                memory + quantity("memory").map_or(0, parse_memory_to_bytes),
+            )
+        })
+}
+
+fn storage_quantity(
+    quantities: Option<&BTreeMap<String, k8s_openapi::apimachinery::pkg::api::resource::Quantity>>,
+) -> i64 {
+    quantities
+        .and_then(|quantities| quantities.get("storage"))
+        .map_or(0, |quantity| parse_memory_to_bytes(&quantity.0))
+}
+
+/// Build a client acting as the session identity (bearer token or impersonation).
+async fn create_client(claims: &Claims) -> Result<Client> {
+    let mut config = kube::Config::infer()
+        .await
+        .map_err(|e| Error::InternalServer {
+            message: format!("Failed to load kubeconfig: {}", e),
+        })?;
+
+    claims.apply_to(&mut config);
+
+    Client::try_from(config).map_err(|e| Error::InternalServer {
+        message: format!("Failed to create K8s client: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{POOL_LABEL, build_tenant_usage, pod_metrics_resource};
+    use crate::types::v1alpha1::status::{
+        Status,
+        health::{CapacityStatus, HealthStatus, PoolCapacityStatus},
+    };
+    use k8s_openapi::api::core::v1 as corev1;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use kube::api::DynamicObject;
+    use std::collections::BTreeMap;
+
+    fn quantities(entries: &[(&str, &str)]) -> BTreeMap<String, Quantity> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), Quantity(value.to_string())))
+            .collect()
+    }
+
+    fn pool_labels() -> Option<BTreeMap<String, String>> {
+        Some(BTreeMap::from([(
+            POOL_LABEL.to_string(),
+            "pool-0".to_string(),
+        )]))
+    }
+
+    #[test]
+    fn aggregates_requests_usage_and_capacity_per_pool() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.status = Some(Status {
+            health: Some(HealthStatus {
+                last_check_time: Some("2026-01-01T00:00:00Z".to_string()),
+                capacity: Some(CapacityStatus {
+                    raw_capacity_bytes: 4000,
+                    raw_used_bytes: 1000,
+                    pools: vec![PoolCapacityStatus {
+                        name: "pool-0".to_string(),
+                        raw_capacity_bytes: 4000,
+                        raw_used_bytes: 1000,
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let pod = corev1::Pod {
+            metadata: ObjectMeta {
+                name: Some("test-tenant-pool-0-0".to_string()),
+                labels: pool_labels(),
+                ..Default::default()
+            },
+            spec: Some(corev1::PodSpec {
+                containers: vec![corev1::Container {
+                    resources: Some(corev1::ResourceRequirements {
+                        requests: Some(quantities(&[("cpu", "500m"), ("memory", "1Gi")])),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let claim = corev1::PersistentVolumeClaim {
+            metadata: ObjectMeta {
+                labels: pool_labels(),
+                ..Default::default()
+            },
+            spec: Some(corev1::PersistentVolumeClaimSpec {
+                resources: Some(corev1::VolumeResourceRequirements {
+                    requests: Some(quantities(&[("storage", "10Gi")])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            status: Some(corev1::PersistentVolumeClaimStatus {
+                capacity: Some(quantities(&[("storage", "20Gi")])),
+                ..Default::default()
+            }),
+        };
+        let mut metrics = DynamicObject::new("test-tenant-pool-0-0", &pod_metrics_resource());
+        metrics.data = serde_json::json!({
+            "containers": [{ "name": "rustfs", "usage": { "cpu": "250000000n", "memory": "512Mi" } }]
+        });
+
+        let usage = build_tenant_usage(&tenant, &[pod], &[claim], Some(&[metrics]));
+
+        assert!(usage.metrics_available);
+        assert_eq!(
+            usage.storage_reported_at.as_deref(),
+            Some("2026-01-01T00:00:00Z")
+        );
+        let pool = &usage.pools[0].usage;
+        assert_eq!(pool.pods, 1);
+        assert_eq!(pool.cpu_request_millicores, 500);
+        assert_eq!(pool.cpu_usage_millicores, Some(250));
+        assert_eq!(pool.memory_request_bytes, 1 << 30);
+        assert_eq!(pool.memory_usage_bytes, Some(512 << 20));
+        assert_eq!(pool.volume_claims, 1);
+        assert_eq!(pool.volume_requested_bytes, 10 << 30);
+        assert_eq!(pool.volume_capacity_bytes, 20 << 30);
+        assert_eq!(pool.s3_raw_used_bytes, Some(1000));
+        assert_eq!(usage.totals.cpu_usage_millicores, Some(250));
+        assert_eq!(usage.totals.s3_raw_capacity_bytes, Some(4000));
+    }
+
+    #[test]
+    fn reports_no_actual_usage_without_metrics_server() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+
+        let usage = build_tenant_usage(&tenant, &[], &[], None);
+
+        assert!(!usage.metrics_available);
+        assert_eq!(usage.totals.cpu_usage_millicores, None);
+        assert_eq!(usage.pools[0].usage.memory_usage_bytes, None);
+        assert_eq!(usage.pools[0].usage.s3_raw_used_bytes, None);
+    }
+}
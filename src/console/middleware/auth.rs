@@ -39,6 +39,7 @@ pub async fn auth_middleware(
     if path == "/healthz"
         || path == "/readyz"
         || path == "/metrics"
+        || path == "/version"
         || path.starts_with("/api/v1/login")
         || path.starts_with("/api/v1/logout")
         || path.starts_with("/swagger-ui")
@@ -20,6 +20,7 @@ pub mod error;
 pub mod handlers;
 pub mod middleware;
 pub mod models;
+pub mod namespace_template;
 #[allow(dead_code)]
 pub mod openapi;
 pub mod routes;
@@ -0,0 +1,71 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body to start an S3 benchmark against a tenant.
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct StartBenchmarkRequest {
+    /// Benchmark operation: `put`, `get` or `mixed` (default `mixed`)
+    pub operation: Option<String>,
+    /// Object size, such as `64KiB` or `4MiB` (default `1MiB`)
+    pub object_size: Option<String>,
+    /// Concurrent operations, 1 to 1024 (default 16)
+    pub concurrency: Option<u32>,
+    /// Benchmark duration in seconds, 10 to 3600 (default 60)
+    pub duration_seconds: Option<u32>,
+}
+
+/// Parameters a benchmark was started with.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct BenchmarkParameters {
+    pub operation: String,
+    pub object_size: String,
+    pub concurrency: u32,
+    pub duration_seconds: u32,
+}
+
+/// One benchmark run of a tenant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BenchmarkItem {
+    /// Name of the benchmark Job and of the ConfigMap holding its result
+    pub name: String,
+    /// `Pending`, `Running`, `Succeeded`, `Failed`, or `Unknown` once the Job has expired
+    /// without a collected result
+    pub phase: String,
+    pub parameters: BenchmarkParameters,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BenchmarkListResponse {
+    pub benchmarks: Vec<BenchmarkItem>,
+}
+
+/// A benchmark with the benchmark tool output once the run has finished.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BenchmarkDetailsResponse {
+    #[serde(flatten)]
+    pub benchmark: BenchmarkItem,
+    /// Summary printed by the benchmark tool (last lines of its output)
+    pub output: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeleteBenchmarkResponse {
+    pub success: bool,
+    pub message: String,
+}
@@ -0,0 +1,35 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Query parameters for a tenant console credential
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ConsoleAccessQuery {
+    /// Credential lifetime in seconds, 900 to 43200 (default 3600)
+    pub duration_seconds: Option<u64>,
+}
+
+/// Temporary RustFS credential for signing in to the tenant's own console
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConsoleAccessResponse {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: String,
+    /// RFC 3339 expiration time of the credential
+    pub expiration: String,
+    /// In-cluster URL of the tenant console Service
+    pub console_url: String,
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::console::models::cluster::ClusterResourcesResponse;
+use crate::console::models::event::EventItem;
+use crate::console::models::tenant::TenantStateCountsResponse;
+
+/// Everything the console landing page shows, in one response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DashboardResponse {
+    pub operator_version: String,
+    pub tenants: TenantStateCountsResponse,
+    pub storage: DashboardStorageSummary,
+    pub cluster: ClusterResourcesResponse,
+    /// Newest first, at most 20
+    pub recent_warnings: Vec<EventItem>,
+}
+
+/// Pool, server, and volume totals across all tenants
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct DashboardStorageSummary {
+    pub pools: usize,
+    pub servers: i64,
+    pub volumes: i64,
+    /// Requested PVC capacity of all volumes
+    pub capacity: String,
+    pub capacity_bytes: i64,
+}
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 pub mod auth;
+pub mod benchmark;
 pub mod cluster;
 pub mod common;
+pub mod console_access;
+pub mod dashboard;
 pub mod encryption;
 pub mod event;
 pub mod pod;
 pub mod pool;
 pub mod tenant;
 pub mod topology;
+pub mod usage;
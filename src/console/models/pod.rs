@@ -120,9 +120,13 @@ pub struct DeletePodResponse {
     pub message: String,
 }
 
-/// Optional flags when restarting a pod (delete/recreate)
+/// Restart request for a pod (evict/recreate)
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct RestartPodRequest {
+    /// Must repeat the pod name to confirm the restart
+    #[serde(default)]
+    pub confirm: String,
+    /// Skip the termination grace period (the PodDisruptionBudget still applies)
     #[serde(default)]
     pub force: bool,
 }
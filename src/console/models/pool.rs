@@ -30,6 +30,7 @@ pub struct PoolDetails {
     pub current_revision: Option<String>,
     pub update_revision: Option<String>,
     pub state: String,
+    pub paused: bool,
     pub lifecycle_state: Option<String>,
     pub workload_state: Option<String>,
     pub decommission_phase: Option<String>,
@@ -110,6 +111,43 @@ pub struct CancelPoolDecommissionRequest {
     pub reason: Option<String>,
 }
 
+/// Request body to restart every pod of a pool.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RolloutRestartPoolRequest {
+    /// Must repeat the pool name to confirm the restart
+    #[serde(default)]
+    pub confirm: String,
+}
+
+/// Response after triggering a pool rollout restart.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RolloutRestartPoolResponse {
+    pub success: bool,
+    pub message: String,
+    pub restarted_at: String,
+}
+
+/// Rollout progress of a pool's StatefulSet.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolRolloutResponse {
+    pub pool: String,
+    pub statefulset: String,
+    pub desired_replicas: i32,
+    pub replicas: i32,
+    pub ready_replicas: i32,
+    pub updated_replicas: i32,
+    pub current_revision: Option<String>,
+    pub update_revision: Option<String>,
+    /// Ordinals below the partition stay on the current revision (canary upgrades)
+    pub partition: Option<i32>,
+    /// Whether the StatefulSet controller has observed the latest spec
+    pub generation_observed: bool,
+    /// Same checks the operator uses to decide that a rollout has finished
+    pub complete: bool,
+    /// `updated_replicas` as a percentage of `desired_replicas`
+    pub progress_percent: u8,
+}
+
 /// Response after writing a pool decommission lifecycle request.
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
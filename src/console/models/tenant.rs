@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use crate::types::v1alpha1::{
+    pool::Pool,
+    profile::TenantProfile,
     provisioning::{ProvisioningBucket, ProvisioningPolicy, ProvisioningUser},
     status::{
-        ConditionStatus, ConditionType, CurrentState, Reason, Status, canonical_filter_state,
-        canonical_state, certificate, next_actions_for_reason, primary_condition, provisioning,
+        Condition, ConditionStatus, ConditionType, CurrentState, Reason, Status,
+        canonical_filter_state, canonical_state, certificate, health::HealthStatus,
+        history::ConditionTransition, next_actions_for_reason, primary_condition, provisioning,
         summarize_current_state,
     },
     tenant::Tenant,
@@ -42,6 +45,21 @@ pub struct TenantListItem {
     pub observed_generation: Option<i64>,
     pub stale: bool,
     pub created_at: Option<String>,
+    /// Ready RustFS pods across all pools (`status.availableReplicas`)
+    pub ready_replicas: i32,
+    pub conditions: Vec<TenantCondition>,
+    /// Result of the last periodic health check, if one has run
+    pub health: Option<TenantHealth>,
+}
+
+/// Health check summary embedded in tenant list/detail
+#[derive(Debug, Serialize, ToSchema, Clone, PartialEq)]
+pub struct TenantHealth {
+    pub ready_pods: i32,
+    pub total_pods: i32,
+    /// `Healthy`, `Degraded`, or `Unreachable`; unset when the Tenant has no `credsSecret`
+    pub admin_api: Option<String>,
+    pub last_check_time: Option<String>,
 }
 
 /// Pool summary embedded in tenant list/detail
@@ -82,6 +100,8 @@ pub struct TenantDetailsResponse {
     pub pools: Vec<PoolInfo>,
     pub state: String,
     pub status_summary: TenantStatusSummary,
+    pub ready_replicas: i32,
+    pub health: Option<TenantHealth>,
     pub conditions: Vec<TenantCondition>,
     pub next_actions: Vec<String>,
     #[serde(skip_serializing_if = "certificate::Status::is_empty")]
@@ -105,6 +125,32 @@ pub struct TenantCondition {
     pub observed_generation: Option<i64>,
 }
 
+/// A condition status change recorded in `status.conditionHistory`.
+#[derive(Debug, Serialize, ToSchema, Clone)]
+pub struct ConditionTransitionItem {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub reason: String,
+    pub message: String,
+    pub time: String,
+}
+
+/// Condition transitions of the last 7 days, newest first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantConditionHistoryResponse {
+    pub transitions: Vec<ConditionTransitionItem>,
+}
+
+/// Query parameters for the condition history
+#[derive(Debug, Deserialize, ToSchema, Default)]
+pub struct ConditionHistoryQuery {
+    /// Only return transitions of this condition type, e.g. `Ready`
+    #[serde(default, rename = "type")]
+    pub type_: Option<String>,
+}
+
 #[derive(Debug, Serialize, ToSchema, Clone)]
 pub struct TenantStatusSummary {
     pub current_state: String,
@@ -151,6 +197,8 @@ pub struct CreateTenantRequest {
     pub namespace: String,
     pub pools: Vec<CreatePoolRequest>,
     pub image: Option<String>,
+    /// `standalone` accepts pools with fewer than four drives. Defaults to `production`.
+    pub profile: Option<TenantProfile>,
     pub mount_path: Option<String>,
     pub creds_secret: Option<String>,
     pub policies: Option<Vec<ProvisioningPolicy>>,
@@ -236,6 +284,15 @@ pub struct UpdateTenantResponse {
     pub tenant: TenantListItem,
 }
 
+/// Query parameters for exporting a Tenant bundle
+#[derive(Debug, Deserialize, ToSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantExportQuery {
+    /// Replace Secret values with empty strings
+    #[serde(default)]
+    pub redact_secrets: bool,
+}
+
 /// Raw Tenant manifest get/update payload
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct TenantYAML {
@@ -344,14 +401,27 @@ pub fn tenant_conditions(tenant: &Tenant) -> Vec<TenantCondition> {
             status
                 .conditions
                 .iter()
-                .map(|condition| TenantCondition {
-                    type_: condition.type_.clone(),
-                    status: condition.status.clone(),
-                    reason: condition.reason.clone(),
-                    message: condition.message.clone(),
-                    last_transition_time: condition.last_transition_time.clone(),
-                    observed_generation: condition.observed_generation,
-                })
+                .map(TenantCondition::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Recorded condition transitions of `tenant`, newest first, optionally of one condition type.
+pub fn tenant_condition_history(
+    tenant: &Tenant,
+    type_: Option<&str>,
+) -> Vec<ConditionTransitionItem> {
+    tenant
+        .status
+        .as_ref()
+        .map(|status| {
+            status
+                .condition_history
+                .iter()
+                .rev()
+                .filter(|transition| type_.is_none_or(|type_| transition.type_ == type_))
+                .map(ConditionTransitionItem::from)
                 .collect()
         })
         .unwrap_or_default()
@@ -365,33 +435,101 @@ pub fn tenant_certificates(tenant: &Tenant) -> certificate::Status {
         .unwrap_or_default()
 }
 
-pub fn tenant_to_list_item(tenant: Tenant) -> TenantListItem {
-    let summary = tenant_status_summary(&tenant);
-    TenantListItem {
-        name: tenant.name_any(),
-        namespace: tenant.namespace().unwrap_or_default(),
-        pools: tenant
-            .spec
-            .pools
-            .iter()
-            .map(|p| PoolInfo {
-                name: p.name.clone(),
-                servers: p.servers,
-                volumes_per_server: p.persistence.volumes_per_server,
-            })
-            .collect(),
-        state: summary.current_state,
-        ready: summary.ready,
-        reconciling: summary.reconciling,
-        degraded: summary.degraded,
-        primary_reason: summary.primary_reason,
-        generation: tenant.metadata.generation,
-        observed_generation: summary.observed_generation,
-        stale: summary.stale,
-        created_at: tenant
-            .metadata
-            .creation_timestamp
-            .map(|ts| ts.0.to_rfc3339()),
+/// Health check summary of `tenant`, if a health check has run.
+pub fn tenant_health(tenant: &Tenant) -> Option<TenantHealth> {
+    tenant
+        .status
+        .as_ref()
+        .and_then(|status| status.health.as_ref())
+        .map(TenantHealth::from)
+}
+
+/// Ready RustFS pods of `tenant` as last recorded by the operator.
+pub fn tenant_ready_replicas(tenant: &Tenant) -> i32 {
+    tenant
+        .status
+        .as_ref()
+        .map(|status| status.available_replicas)
+        .unwrap_or_default()
+}
+
+impl From<&Pool> for PoolInfo {
+    fn from(pool: &Pool) -> Self {
+        Self {
+            name: pool.name.clone(),
+            servers: pool.servers,
+            volumes_per_server: pool.persistence.volumes_per_server,
+        }
+    }
+}
+
+impl From<&Condition> for TenantCondition {
+    fn from(condition: &Condition) -> Self {
+        Self {
+            type_: condition.type_.clone(),
+            status: condition.status.clone(),
+            reason: condition.reason.clone(),
+            message: condition.message.clone(),
+            last_transition_time: condition.last_transition_time.clone(),
+            observed_generation: condition.observed_generation,
+        }
+    }
+}
+
+impl From<&ConditionTransition> for ConditionTransitionItem {
+    fn from(transition: &ConditionTransition) -> Self {
+        Self {
+            type_: transition.type_.clone(),
+            previous_status: transition.previous_status.clone(),
+            status: transition.status.clone(),
+            reason: transition.reason.clone(),
+            message: transition.message.clone(),
+            time: transition.time.clone(),
+        }
+    }
+}
+
+impl From<&HealthStatus> for TenantHealth {
+    fn from(health: &HealthStatus) -> Self {
+        Self {
+            ready_pods: health.ready_pods,
+            total_pods: health.total_pods,
+            admin_api: health.admin_api.map(|admin_api| admin_api.to_string()),
+            last_check_time: health.last_check_time.clone(),
+        }
+    }
+}
+
+impl From<&Tenant> for TenantListItem {
+    fn from(tenant: &Tenant) -> Self {
+        let summary = tenant_status_summary(tenant);
+        Self {
+            name: tenant.name_any(),
+            namespace: tenant.namespace().unwrap_or_default(),
+            pools: tenant.spec.pools.iter().map(PoolInfo::from).collect(),
+            state: summary.current_state,
+            ready: summary.ready,
+            reconciling: summary.reconciling,
+            degraded: summary.degraded,
+            primary_reason: summary.primary_reason,
+            generation: tenant.metadata.generation,
+            observed_generation: summary.observed_generation,
+            stale: summary.stale,
+            created_at: tenant
+                .metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|ts| ts.0.to_rfc3339()),
+            ready_replicas: tenant_ready_replicas(tenant),
+            conditions: tenant_conditions(tenant),
+            health: tenant_health(tenant),
+        }
+    }
+}
+
+impl From<Tenant> for TenantListItem {
+    fn from(tenant: Tenant) -> Self {
+        Self::from(&tenant)
     }
 }
 
@@ -439,6 +577,40 @@ mod tests {
     use super::*;
     use crate::types::v1alpha1::status::{Condition, Status};
 
+    #[test]
+    fn condition_history_is_newest_first_and_filtered_by_type() {
+        use crate::types::v1alpha1::status::history::ConditionTransition;
+
+        let transition = |type_: &str, status: &str, time: &str| ConditionTransition {
+            type_: type_.to_string(),
+            status: status.to_string(),
+            time: time.to_string(),
+            ..Default::default()
+        };
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.status = Some(Status {
+            condition_history: vec![
+                transition("Ready", "False", "2025-01-01T00:00:00Z"),
+                transition("Degraded", "True", "2025-01-01T00:00:00Z"),
+                transition("Ready", "True", "2025-01-02T00:00:00Z"),
+            ],
+            ..Default::default()
+        });
+
+        let history = tenant_condition_history(&tenant, None);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].time, "2025-01-02T00:00:00Z");
+
+        let ready = tenant_condition_history(&tenant, Some("Ready"));
+        assert_eq!(
+            ready
+                .iter()
+                .map(|item| item.status.as_str())
+                .collect::<Vec<_>>(),
+            vec!["True", "False"]
+        );
+    }
+
     #[test]
     fn tenant_summary_prefers_blocked_reason_and_actions() {
         let mut tenant = crate::tests::create_test_tenant(None, None);
@@ -668,6 +840,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_item_carries_pools_conditions_replicas_and_health() {
+        use crate::types::v1alpha1::status::health::AdminApiHealth;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.generation = Some(2);
+        tenant.status = Some(Status {
+            current_state: "Ready".to_string(),
+            observed_generation: Some(2),
+            available_replicas: 4,
+            conditions: vec![condition("Ready", "True", "ReconcileSucceeded")],
+            health: Some(HealthStatus {
+                last_check_time: Some("2025-01-01T00:00:00Z".to_string()),
+                ready_pods: 4,
+                total_pods: 4,
+                admin_api: Some(AdminApiHealth::Healthy),
+                capacity: None,
+                synthetics: None,
+            }),
+            ..Default::default()
+        });
+
+        let item = TenantListItem::from(&tenant);
+
+        assert_eq!(item.name, tenant.name_any());
+        assert_eq!(item.state, "Ready");
+        assert!(item.ready);
+        assert_eq!(item.pools.len(), tenant.spec.pools.len());
+        assert_eq!(item.pools[0].name, tenant.spec.pools[0].name);
+        assert_eq!(item.ready_replicas, 4);
+        assert_eq!(item.conditions.len(), 1);
+        assert_eq!(item.conditions[0].type_, "Ready");
+        assert_eq!(item.conditions[0].reason, "ReconcileSucceeded");
+        assert_eq!(
+            item.health,
+            Some(TenantHealth {
+                ready_pods: 4,
+                total_pods: 4,
+                admin_api: Some("Healthy".to_string()),
+                last_check_time: Some("2025-01-01T00:00:00Z".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn list_item_without_status_has_empty_details() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+
+        let item = TenantListItem::from(tenant);
+
+        assert_eq!(item.ready_replicas, 0);
+        assert!(item.conditions.is_empty());
+        assert_eq!(item.health, None);
+    }
+
     fn condition(type_: &str, status: &str, reason: &str) -> Condition {
         Condition {
             type_: type_.to_string(),
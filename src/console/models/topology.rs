@@ -116,3 +116,44 @@ pub struct TopologyNode {
     pub cpu_allocatable: String,
     pub memory_allocatable: String,
 }
+
+/// Placement of one tenant: pools → pods → nodes → zones
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantTopologyResponse {
+    pub name: String,
+    pub namespace: String,
+    pub pools: Vec<TenantTopologyPool>,
+    /// Nodes running at least one pod of the tenant
+    pub nodes: Vec<TenantTopologyNode>,
+    /// Placement problems, e.g. two pods of a pool on one node
+    pub warnings: Vec<String>,
+}
+
+/// Pool with the pods it runs
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantTopologyPool {
+    pub name: String,
+    pub servers: i32,
+    pub pods: Vec<TenantTopologyPod>,
+}
+
+/// Pod placement within a pool
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantTopologyPod {
+    pub name: String,
+    pub phase: String,
+    pub ready: bool,
+    pub node: Option<String>,
+    pub zone: Option<String>,
+}
+
+/// Node hosting tenant pods, with the conditions that matter for placement
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantTopologyNode {
+    pub name: String,
+    pub zone: Option<String>,
+    pub ready: bool,
+    pub disk_pressure: bool,
+    pub unschedulable: bool,
+    pub pod_count: usize,
+}
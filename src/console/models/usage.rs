@@ -0,0 +1,56 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Requested vs. actual resource usage of one tenant, for showback and chargeback
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantUsageResponse {
+    pub name: String,
+    pub namespace: String,
+    /// Whether metrics-server returned pod usage; actual CPU and memory are unset otherwise
+    pub metrics_available: bool,
+    /// Time of the health check that reported S3 capacity; unset when none has
+    pub storage_reported_at: Option<String>,
+    pub totals: UsageFigures,
+    pub pools: Vec<PoolUsage>,
+}
+
+/// Usage of one pool
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolUsage {
+    pub name: String,
+    #[serde(flatten)]
+    pub usage: UsageFigures,
+}
+
+/// CPU in millicores, memory and storage in bytes
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct UsageFigures {
+    pub pods: usize,
+    pub cpu_request_millicores: i64,
+    pub cpu_usage_millicores: Option<i64>,
+    pub memory_request_bytes: i64,
+    pub memory_usage_bytes: Option<i64>,
+    pub volume_claims: usize,
+    /// Sum of the storage requests of the pool's PersistentVolumeClaims
+    pub volume_requested_bytes: i64,
+    /// Sum of the capacity bound to the pool's PersistentVolumeClaims
+    pub volume_capacity_bytes: i64,
+    /// Raw capacity RustFS reports for the pool's drives
+    pub s3_raw_capacity_bytes: Option<u64>,
+    /// Raw bytes RustFS reports as used on the pool's drives
+    pub s3_raw_used_bytes: Option<u64>,
+}
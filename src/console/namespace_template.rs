@@ -0,0 +1,192 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Baseline applied to namespaces the Console creates for new Tenants.
+//!
+//! `OPERATOR_NAMESPACE_TEMPLATE` holds the template as JSON (the chart renders it from
+//! `console.namespaceTemplate`). Namespaces that already exist are left untouched.
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::api::networking::v1 as networkingv1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::{Api, Client, api::PostParams};
+use serde::Deserialize;
+
+use crate::console::error::{self, Result};
+
+pub const NAMESPACE_TEMPLATE_ENV: &str = "OPERATOR_NAMESPACE_TEMPLATE";
+
+/// Name of the ResourceQuota and LimitRange created from the template.
+pub const TEMPLATE_OBJECT_NAME: &str = "rustfs-tenant-defaults";
+
+const MANAGED_BY_LABEL: &str = "app.kubernetes.io/managed-by";
+const MANAGED_BY_VALUE: &str = "rustfs-console";
+
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NamespaceTemplate {
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    pub resource_quota: Option<corev1::ResourceQuotaSpec>,
+    pub limit_range: Option<corev1::LimitRangeSpec>,
+    #[serde(default)]
+    pub network_policies: Vec<NetworkPolicyTemplate>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkPolicyTemplate {
+    pub name: String,
+    pub spec: networkingv1::NetworkPolicySpec,
+}
+
+impl NamespaceTemplate {
+    /// Reads [`NAMESPACE_TEMPLATE_ENV`]. Unset or blank means no template; invalid JSON is an
+    /// error so a typo never silently produces non-compliant namespaces.
+    pub fn from_env() -> std::result::Result<Option<Self>, String> {
+        match std::env::var(NAMESPACE_TEMPLATE_ENV) {
+            Ok(value) => Self::parse(&value),
+            Err(_) => Ok(None),
+        }
+    }
+
+    fn parse(value: &str) -> std::result::Result<Option<Self>, String> {
+        let value = value.trim();
+        if value.is_empty() || value == "{}" || value == "null" {
+            return Ok(None);
+        }
+        serde_json::from_str(value)
+            .map(Some)
+            .map_err(|e| format!("{NAMESPACE_TEMPLATE_ENV} is not a valid template: {e}"))
+    }
+
+    /// Namespace object carrying the template labels and annotations.
+    pub fn namespace(&self, name: &str) -> corev1::Namespace {
+        let mut labels = self.labels.clone();
+        labels.insert(MANAGED_BY_LABEL.to_string(), MANAGED_BY_VALUE.to_string());
+        corev1::Namespace {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some(labels),
+                annotations: (!self.annotations.is_empty()).then(|| self.annotations.clone()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Creates the ResourceQuota, LimitRange and NetworkPolicies in a new namespace.
+    pub async fn apply(&self, client: &Client, namespace: &str) -> Result<()> {
+        let params = PostParams::default();
+        if let Some(spec) = &self.resource_quota {
+            let api: Api<corev1::ResourceQuota> = Api::namespaced(client.clone(), namespace);
+            let quota = corev1::ResourceQuota {
+                metadata: managed_meta(TEMPLATE_OBJECT_NAME, namespace),
+                spec: Some(spec.clone()),
+                ..Default::default()
+            };
+            api.create(&params, &quota).await.map_err(|e| {
+                error::map_kube_error(e, format!("ResourceQuota '{}'", TEMPLATE_OBJECT_NAME))
+            })?;
+        }
+        if let Some(spec) = &self.limit_range {
+            let api: Api<corev1::LimitRange> = Api::namespaced(client.clone(), namespace);
+            let limit_range = corev1::LimitRange {
+                metadata: managed_meta(TEMPLATE_OBJECT_NAME, namespace),
+                spec: Some(spec.clone()),
+            };
+            api.create(&params, &limit_range).await.map_err(|e| {
+                error::map_kube_error(e, format!("LimitRange '{}'", TEMPLATE_OBJECT_NAME))
+            })?;
+        }
+        let api: Api<networkingv1::NetworkPolicy> = Api::namespaced(client.clone(), namespace);
+        for policy in &self.network_policies {
+            let network_policy = networkingv1::NetworkPolicy {
+                metadata: managed_meta(&policy.name, namespace),
+                spec: Some(policy.spec.clone()),
+            };
+            api.create(&params, &network_policy).await.map_err(|e| {
+                error::map_kube_error(e, format!("NetworkPolicy '{}'", policy.name))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+fn managed_meta(name: &str, namespace: &str) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(name.to_string()),
+        namespace: Some(namespace.to_string()),
+        labels: Some(BTreeMap::from([(
+            MANAGED_BY_LABEL.to_string(),
+            MANAGED_BY_VALUE.to_string(),
+        )])),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_values_mean_no_template() {
+        assert_eq!(NamespaceTemplate::parse(""), Ok(None));
+        assert_eq!(NamespaceTemplate::parse("{}"), Ok(None));
+        assert_eq!(NamespaceTemplate::parse("null"), Ok(None));
+    }
+
+    #[test]
+    fn parses_quota_limits_and_network_policies() {
+        let template = NamespaceTemplate::parse(
+            r#"{
+                "labels": {"team": "storage"},
+                "resourceQuota": {"hard": {"requests.storage": "10Ti"}},
+                "limitRange": {"limits": [{"type": "Container", "default": {"cpu": "1"}}]},
+                "networkPolicies": [
+                    {"name": "deny-ingress", "spec": {"podSelector": {}, "policyTypes": ["Ingress"]}}
+                ]
+            }"#,
+        )
+        .expect("valid template")
+        .expect("template set");
+
+        assert_eq!(
+            template.labels.get("team").map(String::as_str),
+            Some("storage")
+        );
+        assert!(template.resource_quota.is_some());
+        assert!(template.limit_range.is_some());
+        assert_eq!(template.network_policies[0].name, "deny-ingress");
+
+        let namespace = template.namespace("tenant-a");
+        let labels = namespace.metadata.labels.expect("labels");
+        assert_eq!(labels.get("team").map(String::as_str), Some("storage"));
+        assert_eq!(
+            labels.get(MANAGED_BY_LABEL).map(String::as_str),
+            Some(MANAGED_BY_VALUE)
+        );
+        assert!(namespace.metadata.annotations.is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_and_missing_fields() {
+        assert!(NamespaceTemplate::parse(r#"{"quota": {}}"#).is_err());
+        assert!(NamespaceTemplate::parse(r#"{"networkPolicies": [{"spec": {}}]}"#).is_err());
+    }
+}
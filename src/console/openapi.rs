@@ -21,6 +21,10 @@ use axum::Json;
 use utoipa::OpenApi;
 
 use crate::console::models::auth::{LoginRequest, LoginResponse, SessionResponse};
+use crate::console::models::benchmark::{
+    BenchmarkDetailsResponse, BenchmarkItem, BenchmarkListResponse, BenchmarkParameters,
+    DeleteBenchmarkResponse, StartBenchmarkRequest,
+};
 use crate::console::models::cluster::{
     ClusterResourcesResponse, CreateNamespaceRequest, NamespaceItem, NamespaceListResponse,
     NodeInfo, NodeListResponse,
@@ -28,6 +32,8 @@ use crate::console::models::cluster::{
 use crate::console::models::common::{
     ConsoleActionResponse, ConsoleErrorDetails, ConsoleErrorResponse,
 };
+use crate::console::models::console_access::{ConsoleAccessQuery, ConsoleAccessResponse};
+use crate::console::models::dashboard::{DashboardResponse, DashboardStorageSummary};
 use crate::console::models::event::{EventItem, EventListResponse};
 use crate::console::models::pod::{
     ContainerInfo, ContainerState, DeletePodResponse, LogsQuery, PodCondition, PodDetails,
@@ -35,19 +41,24 @@ use crate::console::models::pod::{
 };
 use crate::console::models::pool::{
     AddPoolRequest, AddPoolResponse, CancelPoolDecommissionRequest, DeletePoolResponse,
-    PoolDecommissionRequestResponse, PoolDetails, PoolListResponse, ResourceList,
-    ResourceRequirements, StartPoolDecommissionRequest,
+    PoolDecommissionRequestResponse, PoolDetails, PoolListResponse, PoolRolloutResponse,
+    ResourceList, ResourceRequirements, RolloutRestartPoolRequest, RolloutRestartPoolResponse,
+    StartPoolDecommissionRequest,
 };
 use crate::console::models::tenant::{
-    CreatePoolRequest, CreateTenantRequest, DeleteTenantResponse, EnvVar, LoggingConfig, PoolInfo,
-    ServiceInfo, ServicePort, TenantCondition, TenantDetailsResponse, TenantListItem,
-    TenantListQuery, TenantListResponse, TenantStateCountsResponse, TenantStatusSummary,
-    TenantYAML, UpdateTenantRequest, UpdateTenantResponse,
+    ConditionHistoryQuery, ConditionTransitionItem, CreatePoolRequest, CreateTenantRequest,
+    DeleteTenantResponse, EnvVar, LoggingConfig, PoolInfo, ServiceInfo, ServicePort,
+    TenantCondition, TenantConditionHistoryResponse, TenantDetailsResponse, TenantExportQuery,
+    TenantHealth, TenantListItem, TenantListQuery, TenantListResponse, TenantStateCountsResponse,
+    TenantStatusSummary, TenantYAML, UpdateTenantRequest, UpdateTenantResponse,
 };
 use crate::console::models::topology::{
+    TenantTopologyNode, TenantTopologyPod, TenantTopologyPool, TenantTopologyResponse,
     TopologyCluster, TopologyClusterSummary, TopologyNamespace, TopologyNode,
     TopologyOverviewResponse, TopologyPod, TopologyPool, TopologyTenant, TopologyTenantSummary,
 };
+use crate::console::models::usage::{PoolUsage, TenantUsageResponse, UsageFigures};
+use crate::types::v1alpha1::profile::TenantProfile;
 use crate::types::v1alpha1::provisioning::{
     ConfigMapKeyReference, PolicyDocumentSource, ProvisioningBucket, ProvisioningDeletionPolicy,
     ProvisioningPolicy, ProvisioningUser,
@@ -72,22 +83,34 @@ use crate::types::v1alpha1::status::provisioning::{
         api_delete_tenant,
         api_get_tenant_yaml,
         api_put_tenant_yaml,
+        api_export_tenant,
+        api_get_tenant_condition_history,
         api_list_pools,
         api_add_pool,
         api_delete_pool,
         api_start_pool_decommission,
         api_cancel_pool_decommission,
+        api_get_pool_rollout,
+        api_rollout_restart_pool,
         api_list_pods,
         api_get_pod,
         api_delete_pod,
         api_restart_pod,
         api_get_pod_logs,
+        api_list_benchmarks,
+        api_start_benchmark,
+        api_get_benchmark,
+        api_delete_benchmark,
         api_stream_tenant_events,
         api_list_nodes,
         api_get_cluster_resources,
         api_list_namespaces,
         api_create_namespace,
         api_get_topology_overview,
+        api_get_tenant_topology,
+        api_get_tenant_usage,
+        api_get_console_access,
+        api_get_dashboard,
     ),
     components(schemas(
         LoginRequest,
@@ -99,8 +122,13 @@ use crate::types::v1alpha1::status::provisioning::{
         TenantListItem,
         TenantListResponse,
         TenantListQuery,
+        TenantExportQuery,
         TenantStateCountsResponse,
         TenantCondition,
+        TenantHealth,
+        ConditionTransitionItem,
+        ConditionHistoryQuery,
+        TenantConditionHistoryResponse,
         TenantStatusSummary,
         TenantDetailsResponse,
         ProvisioningStatus,
@@ -114,6 +142,7 @@ use crate::types::v1alpha1::status::provisioning::{
         PolicyDocumentSource,
         ConfigMapKeyReference,
         CreateTenantRequest,
+        TenantProfile,
         CreatePoolRequest,
         PoolInfo,
         ServiceInfo,
@@ -134,6 +163,9 @@ use crate::types::v1alpha1::status::provisioning::{
         StartPoolDecommissionRequest,
         CancelPoolDecommissionRequest,
         PoolDecommissionRequestResponse,
+        PoolRolloutResponse,
+        RolloutRestartPoolRequest,
+        RolloutRestartPoolResponse,
         PodListItem,
         PodListResponse,
         PodDetails,
@@ -144,6 +176,12 @@ use crate::types::v1alpha1::status::provisioning::{
         VolumeInfo,
         RestartPodRequest,
         LogsQuery,
+        StartBenchmarkRequest,
+        BenchmarkParameters,
+        BenchmarkItem,
+        BenchmarkListResponse,
+        BenchmarkDetailsResponse,
+        DeleteBenchmarkResponse,
         EventItem,
         EventListResponse,
         NodeInfo,
@@ -161,15 +199,28 @@ use crate::types::v1alpha1::status::provisioning::{
         TopologyPool,
         TopologyPod,
         TopologyNode,
+        TenantTopologyResponse,
+        TenantTopologyPool,
+        TenantTopologyPod,
+        TenantTopologyNode,
+        TenantUsageResponse,
+        ConsoleAccessQuery,
+        ConsoleAccessResponse,
+        PoolUsage,
+        UsageFigures,
+        DashboardResponse,
+        DashboardStorageSummary,
     )),
     tags(
         (name = "auth", description = "Authentication"),
         (name = "tenants", description = "Tenant management"),
         (name = "pools", description = "Pool management"),
         (name = "pods", description = "Pod management"),
+        (name = "benchmarks", description = "S3 benchmark runs"),
         (name = "events", description = "Event management"),
         (name = "cluster", description = "Cluster resources"),
         (name = "topology", description = "Cluster topology overview"),
+        (name = "dashboard", description = "Console landing page summary"),
     ),
     info(
         title = "RustFS Console API",
@@ -210,7 +261,17 @@ fn api_get_tenant_state_counts() -> Json<TenantStateCountsResponse> {
     unimplemented!("Documentation only")
 }
 
-#[utoipa::path(post, path = "/api/v1/tenants", request_body = CreateTenantRequest, responses((status = 200, body = TenantListItem)), tag = "tenants")]
+#[utoipa::path(
+    post,
+    path = "/api/v1/tenants",
+    request_body = CreateTenantRequest,
+    responses(
+        (status = 200, body = TenantListItem),
+        (status = 400, body = ConsoleErrorResponse),
+        (status = 409, body = ConsoleErrorResponse)
+    ),
+    tag = "tenants"
+)]
 fn api_create_tenant(_body: Json<CreateTenantRequest>) -> Json<TenantListItem> {
     unimplemented!("Documentation only")
 }
@@ -254,11 +315,32 @@ fn api_get_tenant_yaml() -> Json<TenantYAML> {
     unimplemented!("Documentation only")
 }
 
-#[utoipa::path(put, path = "/api/v1/namespaces/{namespace}/tenants/{name}/yaml", params(("namespace" = String, Path), ("name" = String, Path)), request_body = TenantYAML, responses((status = 200, body = TenantYAML)), tag = "tenants")]
+#[utoipa::path(
+    put,
+    path = "/api/v1/namespaces/{namespace}/tenants/{name}/yaml",
+    params(("namespace" = String, Path), ("name" = String, Path)),
+    request_body = TenantYAML,
+    responses(
+        (status = 200, body = TenantYAML),
+        (status = 400, body = ConsoleErrorResponse),
+        (status = 409, body = ConsoleErrorResponse)
+    ),
+    tag = "tenants"
+)]
 fn api_put_tenant_yaml(_body: Json<TenantYAML>) -> Json<TenantYAML> {
     unimplemented!("Documentation only")
 }
 
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/export", params(("namespace" = String, Path), ("name" = String, Path), ("redactSecrets" = Option<bool>, Query, description = "Replace Secret values with empty strings")), responses((status = 200, body = TenantYAML)), tag = "tenants")]
+fn api_export_tenant() -> Json<TenantYAML> {
+    unimplemented!("Documentation only")
+}
+
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/conditions/history", params(("namespace" = String, Path), ("name" = String, Path), ("type" = Option<String>, Query, description = "Only return transitions of this condition type")), responses((status = 200, body = TenantConditionHistoryResponse)), tag = "tenants")]
+fn api_get_tenant_condition_history() -> Json<TenantConditionHistoryResponse> {
+    unimplemented!("Documentation only")
+}
+
 // --- Pools ---
 #[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/pools", params(("namespace" = String, Path), ("name" = String, Path)), responses((status = 200, body = PoolListResponse)), tag = "pools")]
 fn api_list_pools() -> Json<PoolListResponse> {
@@ -345,6 +427,52 @@ fn api_cancel_pool_decommission(
     unimplemented!("Documentation only")
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/namespaces/{namespace}/tenants/{name}/pools/{pool}/rollout",
+    params(
+        ("namespace" = String, Path),
+        ("name" = String, Path),
+        ("pool" = String, Path)
+    ),
+    responses(
+        (status = 200, body = PoolRolloutResponse),
+        (status = 401, body = ConsoleErrorResponse),
+        (status = 403, body = ConsoleErrorResponse),
+        (status = 404, body = ConsoleErrorResponse),
+        (status = 500, body = ConsoleErrorResponse)
+    ),
+    tag = "pools"
+)]
+fn api_get_pool_rollout() -> Json<PoolRolloutResponse> {
+    unimplemented!("Documentation only")
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/namespaces/{namespace}/tenants/{name}/pools/{pool}/rollout-restart",
+    params(
+        ("namespace" = String, Path),
+        ("name" = String, Path),
+        ("pool" = String, Path)
+    ),
+    request_body = RolloutRestartPoolRequest,
+    responses(
+        (status = 200, body = RolloutRestartPoolResponse),
+        (status = 400, body = ConsoleErrorResponse),
+        (status = 401, body = ConsoleErrorResponse),
+        (status = 403, body = ConsoleErrorResponse),
+        (status = 404, body = ConsoleErrorResponse),
+        (status = 500, body = ConsoleErrorResponse)
+    ),
+    tag = "pools"
+)]
+fn api_rollout_restart_pool(
+    _body: Json<RolloutRestartPoolRequest>,
+) -> Json<RolloutRestartPoolResponse> {
+    unimplemented!("Documentation only")
+}
+
 // --- Pods ---
 #[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/pods", params(("namespace" = String, Path), ("name" = String, Path)), responses((status = 200, body = PodListResponse)), tag = "pods")]
 fn api_list_pods() -> Json<PodListResponse> {
@@ -361,7 +489,7 @@ fn api_delete_pod() -> Json<DeletePodResponse> {
     unimplemented!("Documentation only")
 }
 
-#[utoipa::path(post, path = "/api/v1/namespaces/{namespace}/tenants/{name}/pods/{pod}/restart", params(("namespace" = String, Path), ("name" = String, Path), ("pod" = String, Path)), request_body = RestartPodRequest, responses((status = 200, body = DeletePodResponse)), tag = "pods")]
+#[utoipa::path(post, path = "/api/v1/namespaces/{namespace}/tenants/{name}/pods/{pod}/restart", params(("namespace" = String, Path), ("name" = String, Path), ("pod" = String, Path)), request_body = RestartPodRequest, responses((status = 200, body = DeletePodResponse), (status = 400, body = ConsoleErrorResponse), (status = 429, body = ConsoleErrorResponse)), tag = "pods")]
 fn api_restart_pod(_body: Json<RestartPodRequest>) -> Json<DeletePodResponse> {
     unimplemented!("Documentation only")
 }
@@ -369,6 +497,27 @@ fn api_restart_pod(_body: Json<RestartPodRequest>) -> Json<DeletePodResponse> {
 #[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/pods/{pod}/logs", params(("namespace" = String, Path), ("name" = String, Path), ("pod" = String, Path), ("container" = Option<String>, Query), ("tail_lines" = Option<i64>, Query), ("timestamps" = Option<bool>, Query)), responses((status = 200, description = "Plain text log output", content_type = "text/plain")), tag = "pods")]
 fn api_get_pod_logs() {}
 
+// --- Benchmarks ---
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/benchmarks", params(("namespace" = String, Path), ("name" = String, Path)), responses((status = 200, body = BenchmarkListResponse)), tag = "benchmarks")]
+fn api_list_benchmarks() -> Json<BenchmarkListResponse> {
+    unimplemented!("Documentation only")
+}
+
+#[utoipa::path(post, path = "/api/v1/namespaces/{namespace}/tenants/{name}/benchmarks", params(("namespace" = String, Path), ("name" = String, Path)), request_body = StartBenchmarkRequest, responses((status = 200, body = BenchmarkItem), (status = 400, body = ConsoleErrorResponse), (status = 404, body = ConsoleErrorResponse)), tag = "benchmarks")]
+fn api_start_benchmark(_body: Json<StartBenchmarkRequest>) -> Json<BenchmarkItem> {
+    unimplemented!("Documentation only")
+}
+
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/benchmarks/{benchmark}", params(("namespace" = String, Path), ("name" = String, Path), ("benchmark" = String, Path)), responses((status = 200, body = BenchmarkDetailsResponse), (status = 404, body = ConsoleErrorResponse)), tag = "benchmarks")]
+fn api_get_benchmark() -> Json<BenchmarkDetailsResponse> {
+    unimplemented!("Documentation only")
+}
+
+#[utoipa::path(delete, path = "/api/v1/namespaces/{namespace}/tenants/{name}/benchmarks/{benchmark}", params(("namespace" = String, Path), ("name" = String, Path), ("benchmark" = String, Path)), responses((status = 200, body = DeleteBenchmarkResponse), (status = 404, body = ConsoleErrorResponse)), tag = "benchmarks")]
+fn api_delete_benchmark() -> Json<DeleteBenchmarkResponse> {
+    unimplemented!("Documentation only")
+}
+
 // --- Events (SSE) ---
 #[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{tenant}/events/stream", params(("namespace" = String, Path), ("tenant" = String, Path)), responses((status = 200, description = "text/event-stream; `event: snapshot` + JSON EventListResponse; `event: stream_error` + JSON { message }", body = EventListResponse, content_type = "application/json")), tag = "events")]
 fn api_stream_tenant_events() {
@@ -402,6 +551,29 @@ fn api_get_topology_overview() -> Json<TopologyOverviewResponse> {
     unimplemented!("Documentation only")
 }
 
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/topology", params(("namespace" = String, Path), ("name" = String, Path)), responses((status = 200, body = TenantTopologyResponse)), tag = "topology")]
+fn api_get_tenant_topology() -> Json<TenantTopologyResponse> {
+    unimplemented!("Documentation only")
+}
+
+// --- Usage ---
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/usage", params(("namespace" = String, Path), ("name" = String, Path)), responses((status = 200, body = TenantUsageResponse)), tag = "tenants")]
+fn api_get_tenant_usage() -> Json<TenantUsageResponse> {
+    unimplemented!("Documentation only")
+}
+
+// --- Console access ---
+#[utoipa::path(get, path = "/api/v1/namespaces/{namespace}/tenants/{name}/console-access", params(("namespace" = String, Path), ("name" = String, Path), ("duration_seconds" = Option<u64>, Query)), responses((status = 200, body = ConsoleAccessResponse), (status = 400, body = ConsoleErrorResponse), (status = 403, body = ConsoleErrorResponse), (status = 404, body = ConsoleErrorResponse)), tag = "tenants")]
+fn api_get_console_access() -> Json<ConsoleAccessResponse> {
+    unimplemented!("Documentation only")
+}
+
+// --- Dashboard ---
+#[utoipa::path(get, path = "/api/v1/dashboard", responses((status = 200, body = DashboardResponse)), tag = "dashboard")]
+fn api_get_dashboard() -> Json<DashboardResponse> {
+    unimplemented!("Documentation only")
+}
+
 #[cfg(test)]
 mod tests {
     use super::ApiDoc;
@@ -64,6 +64,14 @@ pub fn tenant_routes() -> Router<AppState> {
             "/namespaces/:namespace/tenants/:name/yaml",
             put(handlers::tenants::put_tenant_yaml),
         )
+        .route(
+            "/namespaces/:namespace/tenants/:name/export",
+            get(handlers::tenants::export_tenant),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/conditions/history",
+            get(handlers::tenants::get_tenant_condition_history),
+        )
         .route(
             "/namespaces/:namespace/tenants/:name/encryption",
             get(handlers::encryption::get_encryption),
@@ -80,6 +88,18 @@ pub fn tenant_routes() -> Router<AppState> {
             "/namespaces/:namespace/tenants/:name/security-context",
             put(handlers::security_context::update_security_context),
         )
+        .route(
+            "/namespaces/:namespace/tenants/:name/topology",
+            get(handlers::topology::get_tenant_topology),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/usage",
+            get(handlers::usage::get_tenant_usage),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/console-access",
+            get(handlers::console_access::get_console_access),
+        )
 }
 
 /// Pool list / add / delete under a tenant
@@ -105,6 +125,14 @@ pub fn pool_routes() -> Router<AppState> {
             "/namespaces/:namespace/tenants/:name/pools/:pool/decommission/cancel",
             post(handlers::pools::cancel_pool_decommission),
         )
+        .route(
+            "/namespaces/:namespace/tenants/:name/pools/:pool/rollout",
+            get(handlers::pools::get_pool_rollout),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/pools/:pool/rollout-restart",
+            post(handlers::pools::rollout_restart_pool),
+        )
 }
 
 /// Pod list, detail, delete, restart, logs
@@ -132,6 +160,27 @@ pub fn pod_routes() -> Router<AppState> {
         )
 }
 
+/// S3 benchmark Jobs of a tenant
+pub fn benchmark_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/namespaces/:namespace/tenants/:name/benchmarks",
+            get(handlers::benchmarks::list_benchmarks),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/benchmarks",
+            post(handlers::benchmarks::start_benchmark),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/benchmarks/:benchmark",
+            get(handlers::benchmarks::get_benchmark),
+        )
+        .route(
+            "/namespaces/:namespace/tenants/:name/benchmarks/:benchmark",
+            delete(handlers::benchmarks::delete_benchmark),
+        )
+}
+
 /// Kubernetes events for a tenant (SSE)
 pub fn event_routes() -> Router<AppState> {
     Router::new().route(
@@ -152,6 +201,11 @@ pub fn cluster_routes() -> Router<AppState> {
         .route("/namespaces", post(handlers::cluster::create_namespace))
 }
 
+/// Landing page summary
+pub fn dashboard_routes() -> Router<AppState> {
+    Router::new().route("/dashboard", get(handlers::dashboard::get_dashboard))
+}
+
 /// Topology overview for the dashboard
 pub fn topology_routes() -> Router<AppState> {
     Router::new().route(
@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::console::{openapi::ApiDoc, routes, state::AppState};
+use crate::console::{
+    namespace_template::NamespaceTemplate,
+    openapi::ApiDoc,
+    routes,
+    state::{AppState, ConsoleAuthMode},
+};
 use axum::body::Body;
 use axum::http::{HeaderValue, Method, Request, Response, StatusCode, Uri, header};
 use axum::{Router, middleware, response::IntoResponse, routing::get};
@@ -62,19 +67,35 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
 
     tracing::info!(port, "Starting RustFS Operator Console");
 
-    let jwt_secret = load_jwt_secret();
+    let jwt_secret = load_jwt_secret()?;
+    let previous_jwt_secrets = load_previous_jwt_secrets();
+    let auth_mode = load_auth_mode()?;
+    let namespace_template = NamespaceTemplate::from_env()?;
+    if namespace_template.is_some() {
+        tracing::info!("New Tenant namespaces get the configured namespace template");
+    }
+    let base_state = AppState::new(jwt_secret)
+        .with_previous_secrets(previous_jwt_secrets)
+        .with_auth_mode(auth_mode)
+        .with_namespace_template(namespace_template);
 
     let state = match Client::try_default().await {
         Ok(kube_client) => {
             tracing::info!("Kubernetes client initialized for STS authorization flow");
-            AppState::new(jwt_secret).with_kube_client(kube_client)
+            base_state.with_kube_client(kube_client)
+        }
+        Err(error) if auth_mode == ConsoleAuthMode::Impersonation => {
+            return Err(format!(
+                "CONSOLE_AUTH_MODE=impersonation needs the Console ServiceAccount client: {error}"
+            )
+            .into());
         }
         Err(error) => {
             tracing::warn!(
                 %error,
                 "Kubernetes client unavailable; STS authorization paths fall back to compatibility mode"
             );
-            AppState::new(jwt_secret)
+            base_state
         }
     };
 
@@ -86,6 +107,7 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         .route("/healthz", get(health_check))
         .route("/readyz", get(ready_check))
         .route("/metrics", get(crate::metrics::handler))
+        .route("/version", get(crate::version::handler))
         // OpenAPI / Swagger (unauthenticated)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         // REST API v1
@@ -137,9 +159,11 @@ fn api_routes() -> Router<AppState> {
         .merge(routes::tenant_routes())
         .merge(routes::pool_routes())
         .merge(routes::pod_routes())
+        .merge(routes::benchmark_routes())
         .merge(routes::event_routes())
         .merge(routes::cluster_routes())
         .merge(routes::topology_routes())
+        .merge(routes::dashboard_routes())
 }
 
 fn with_static_frontend(app: Router) -> Router {
@@ -264,19 +288,81 @@ async fn check_k8s_connectivity() -> Result<(), String> {
     Ok(())
 }
 
-fn load_jwt_secret() -> String {
+/// Minimum length of an explicit `JWT_SECRET` outside dev mode.
+const MIN_JWT_SECRET_LEN: usize = 32;
+
+/// Placeholder values from examples and old defaults, refused outside dev mode.
+const INSECURE_JWT_SECRETS: &[&str] = &[
+    "secret",
+    "changeme",
+    "change-me",
+    "jwt-secret",
+    "rustfs",
+    "rustfsadmin",
+    "<stable-base64-or-random-secret>",
+];
+
+fn load_jwt_secret() -> Result<String, String> {
     if let Some(secret) = std::env::var("JWT_SECRET")
         .ok()
         .map(|value| value.trim().to_string())
         .filter(|value| !value.is_empty())
     {
-        return secret;
+        if let Err(message) = check_jwt_secret(&secret) {
+            if !console_dev_mode() {
+                return Err(format!(
+                    "{message}; set a random JWT_SECRET (openssl rand -base64 32) or CONSOLE_DEV_MODE=true"
+                ));
+            }
+            tracing::warn!(%message, "using insecure JWT_SECRET because CONSOLE_DEV_MODE is set");
+        }
+        return Ok(secret);
     }
 
     tracing::warn!(
         "JWT_SECRET is not set; generated an ephemeral Console session key for this process"
     );
-    generate_ephemeral_jwt_secret()
+    Ok(generate_ephemeral_jwt_secret())
+}
+
+/// `CONSOLE_AUTH_MODE`: `token` (default) or `impersonation`.
+fn load_auth_mode() -> Result<ConsoleAuthMode, String> {
+    let value = std::env::var("CONSOLE_AUTH_MODE").unwrap_or_default();
+    ConsoleAuthMode::parse(&value).ok_or_else(|| {
+        format!("invalid CONSOLE_AUTH_MODE '{value}'; expected 'token' or 'impersonation'")
+    })
+}
+
+/// Retired secrets from `JWT_PREVIOUS_SECRETS` (comma-separated), accepted for existing sessions.
+fn load_previous_jwt_secrets() -> Vec<String> {
+    std::env::var("JWT_PREVIOUS_SECRETS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|secret| !secret.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn check_jwt_secret(secret: &str) -> Result<(), String> {
+    if INSECURE_JWT_SECRETS
+        .iter()
+        .any(|insecure| secret.eq_ignore_ascii_case(insecure))
+    {
+        return Err("JWT_SECRET is a well-known placeholder value".to_string());
+    }
+    if secret.len() < MIN_JWT_SECRET_LEN {
+        return Err(format!(
+            "JWT_SECRET must be at least {MIN_JWT_SECRET_LEN} characters (got {})",
+            secret.len()
+        ));
+    }
+    Ok(())
+}
+
+fn console_dev_mode() -> bool {
+    std::env::var("CONSOLE_DEV_MODE")
+        .is_ok_and(|value| matches!(value.trim(), "1" | "true" | "TRUE" | "True"))
 }
 
 fn generate_ephemeral_jwt_secret() -> String {
@@ -361,4 +447,13 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         Ok(())
     }
+
+    #[test]
+    fn jwt_secret_check_refuses_placeholders_and_short_secrets() {
+        assert!(check_jwt_secret("changeme").is_err());
+        assert!(check_jwt_secret("<stable-base64-or-random-secret>").is_err());
+        let err = check_jwt_secret("short-secret").err().unwrap_or_default();
+        assert!(err.contains("at least 32 characters (got 12)"));
+        assert!(check_jwt_secret(&"a".repeat(MIN_JWT_SECRET_LEN)).is_ok());
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::console::namespace_template::NamespaceTemplate;
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use kube::Client;
 use ring::{
@@ -21,11 +22,13 @@ use ring::{
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use snafu::Snafu;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub const SESSION_TTL_SECONDS: usize = 12 * 3600;
 const SESSION_AAD: &[u8] = b"rustfs-operator-console-session-v1";
 const SESSION_KEY_CONTEXT: &[u8] = b"rustfs-operator-console-session-key-v1";
+const SESSION_KEY_ID_CONTEXT: &[u8] = b"rustfs-operator-console-session-key-id-v1";
 const SESSION_NONCE_LEN: usize = 12;
 
 /// Shared Axum application state.
@@ -36,6 +39,15 @@ pub struct AppState {
     /// Symmetric key source for encrypting session cookies.
     pub jwt_secret: Arc<String>,
 
+    /// Retired session secrets, still accepted for decrypting sessions issued before a rotation.
+    pub previous_jwt_secrets: Arc<Vec<String>>,
+
+    /// How Console requests authenticate to the Kubernetes API.
+    pub auth_mode: ConsoleAuthMode,
+
+    /// Baseline for namespaces created along with a Tenant (`OPERATOR_NAMESPACE_TEMPLATE`).
+    pub namespace_template: Option<Arc<NamespaceTemplate>>,
+
     /// Optional Kubernetes client used by control-plane APIs that need cluster access.
     ///
     /// Most unit tests run without a live cluster, so this is optional.
@@ -47,10 +59,31 @@ impl AppState {
     pub fn new(jwt_secret: String) -> Self {
         Self {
             jwt_secret: Arc::new(jwt_secret),
+            previous_jwt_secrets: Arc::new(Vec::new()),
+            auth_mode: ConsoleAuthMode::default(),
+            namespace_template: None,
             kube_client: None,
         }
     }
 
+    /// Accept sessions encrypted with retired secrets until they expire.
+    pub fn with_previous_secrets(mut self, previous_jwt_secrets: Vec<String>) -> Self {
+        self.previous_jwt_secrets = Arc::new(previous_jwt_secrets);
+        self
+    }
+
+    /// Select how Console requests authenticate to the Kubernetes API.
+    pub fn with_auth_mode(mut self, auth_mode: ConsoleAuthMode) -> Self {
+        self.auth_mode = auth_mode;
+        self
+    }
+
+    /// Apply `template` to namespaces the Console creates for new Tenants.
+    pub fn with_namespace_template(mut self, template: Option<NamespaceTemplate>) -> Self {
+        self.namespace_template = template.map(Arc::new);
+        self
+    }
+
     /// Attach a Kubernetes client for request handlers that need cluster reads.
     pub fn with_kube_client(mut self, kube_client: Client) -> Self {
         self.kube_client = Some(kube_client);
@@ -58,18 +91,36 @@ impl AppState {
     }
 
     pub fn create_session(&self, k8s_token: String) -> Result<String, SessionError> {
+        self.seal_session(k8s_token, None)
+    }
+
+    /// Session for impersonation mode: stores the authenticated identity, never the token.
+    pub fn create_impersonation_session(
+        &self,
+        user: ImpersonatedUser,
+    ) -> Result<String, SessionError> {
+        self.seal_session(String::new(), Some(user))
+    }
+
+    fn seal_session(
+        &self,
+        k8s_token: String,
+        impersonate: Option<ImpersonatedUser>,
+    ) -> Result<String, SessionError> {
         let iat = current_timestamp();
         let exp = iat.saturating_add(SESSION_TTL_SECONDS);
         let claims = SessionClaims {
             k8s_token,
+            impersonate,
             exp,
             iat,
         };
-        seal_session_token(&self.jwt_secret, &claims)
+        let token = seal_session_token(&self.jwt_secret, &claims)?;
+        Ok(format!("{}.{}", session_key_id(&self.jwt_secret), token))
     }
 
     pub fn resolve_session(&self, token: &str) -> Option<Claims> {
-        let session_claims = match open_session_token(&self.jwt_secret, token) {
+        let session_claims = match self.open_session(token) {
             Ok(claims) => claims,
             Err(error) => {
                 tracing::warn!(%error, "Console session token validation failed");
@@ -83,25 +134,158 @@ impl AppState {
 
         Some(Claims {
             k8s_token: session_claims.k8s_token,
+            impersonate: session_claims.impersonate,
             exp: session_claims.exp,
             iat: session_claims.iat,
         })
     }
 }
 
+impl AppState {
+    /// Decrypts `token` with the secret named by its key ID, or with every configured secret for
+    /// tokens issued before key IDs were added.
+    fn open_session(&self, token: &str) -> Result<SessionClaims, SessionError> {
+        let (key_id, sealed) = match token.split_once('.') {
+            Some((key_id, sealed)) => (Some(key_id), sealed),
+            None => (None, token),
+        };
+
+        let mut result = Err(SessionError::UnknownKey);
+        for secret in std::iter::once(self.jwt_secret.as_str())
+            .chain(self.previous_jwt_secrets.iter().map(String::as_str))
+            .filter(|secret| key_id.is_none_or(|key_id| session_key_id(secret) == key_id))
+        {
+            result = open_session_token(secret, sealed);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+/// How the Console authenticates its Kubernetes API calls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConsoleAuthMode {
+    /// Calls use the bearer token the user logged in with, stored in the encrypted session.
+    #[default]
+    Token,
+    /// Calls use the Console ServiceAccount with `Impersonate-User`/`Impersonate-Group`
+    /// headers for the user authenticated at login; the token is not kept.
+    Impersonation,
+}
+
+impl ConsoleAuthMode {
+    /// Parses `CONSOLE_AUTH_MODE` (`token` or `impersonation`).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "token" => Some(Self::Token),
+            "impersonation" | "impersonate" => Some(Self::Impersonation),
+            _ => None,
+        }
+    }
+}
+
+/// Identity a session acts as in impersonation mode, from the login TokenReview.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImpersonatedUser {
+    pub username: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// Extra attributes of the user, such as token scopes, that authorizers and admission
+    /// webhooks may check.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, Vec<String>>,
+}
+
+impl ImpersonatedUser {
+    /// Add `Impersonate-User`, `Impersonate-Group`, `Impersonate-Uid`, and `Impersonate-Extra-*`
+    /// headers to requests made with `config`. Values that are not valid header values are
+    /// dropped.
+    pub fn apply_to(&self, config: &mut kube::Config) {
+        use http::{HeaderName, HeaderValue};
+
+        config.auth_info.impersonate = Some(self.username.clone());
+        config.auth_info.impersonate_groups =
+            (!self.groups.is_empty()).then(|| self.groups.clone());
+
+        if let Some(uid) = self
+            .uid
+            .as_deref()
+            .filter(|uid| !uid.is_empty())
+            .and_then(|uid| HeaderValue::from_str(uid).ok())
+        {
+            config
+                .headers
+                .push((HeaderName::from_static("impersonate-uid"), uid));
+        }
+        for (key, values) in &self.extra {
+            let Ok(name) = HeaderName::from_bytes(
+                format!("impersonate-extra-{}", escape_extra_key(key)).as_bytes(),
+            ) else {
+                continue;
+            };
+            for value in values {
+                if let Ok(value) = HeaderValue::from_str(value) {
+                    config.headers.push((name.clone(), value));
+                }
+            }
+        }
+    }
+}
+
+/// Percent-encodes an extra key for its `Impersonate-Extra-<key>` header name, as the API
+/// server expects for characters outside the unreserved set (for example `/`).
+fn escape_extra_key(key: &str) -> String {
+    key.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
 /// Authenticated request context inserted by middleware.
 #[derive(Debug, Clone)]
 pub struct Claims {
     pub k8s_token: String,
+    pub impersonate: Option<ImpersonatedUser>,
     pub exp: usize,
     pub iat: usize,
 }
 
+impl Claims {
+    /// Who the session acts as, for audit logs. Token sessions are identified by the
+    /// Kubernetes audit log of the token itself.
+    pub fn actor(&self) -> &str {
+        match &self.impersonate {
+            Some(user) => &user.username,
+            None => "token-session",
+        }
+    }
+
+    /// Point `config` at the session identity: impersonation headers on top of the Console's
+    /// own credentials, or the user's bearer token.
+    pub fn apply_to(&self, config: &mut kube::Config) {
+        match &self.impersonate {
+            Some(user) => user.apply_to(config),
+            None => config.auth_info.token = Some(self.k8s_token.clone().into()),
+        }
+    }
+}
+
 /// Encrypted browser cookie session claims.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct SessionClaims {
+    #[serde(default)]
     pub k8s_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonate: Option<ImpersonatedUser>,
     pub exp: usize,
     pub iat: usize,
 }
@@ -131,6 +315,9 @@ pub enum SessionError {
 
     #[snafu(display("failed to decrypt session token"))]
     Decrypt,
+
+    #[snafu(display("session token was encrypted with an unknown key"))]
+    UnknownKey,
 }
 
 fn current_timestamp() -> usize {
@@ -181,6 +368,16 @@ fn open_session_token(jwt_secret: &str, token: &str) -> Result<SessionClaims, Se
     serde_json::from_slice(plaintext).map_err(|source| SessionError::Deserialize { source })
 }
 
+/// Short public identifier of a session secret, prefixed to tokens so the matching key is
+/// picked directly after a rotation.
+fn session_key_id(jwt_secret: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(SESSION_KEY_ID_CONTEXT);
+    hasher.update([0]);
+    hasher.update(jwt_secret.as_bytes());
+    hex::encode(&hasher.finalize()[..4])
+}
+
 fn session_key(jwt_secret: &str) -> Result<LessSafeKey, SessionError> {
     let mut hasher = Sha256::new();
     hasher.update(SESSION_KEY_CONTEXT);
@@ -243,13 +440,109 @@ mod tests {
         let token = state
             .create_session("sensitive-k8s-token".to_string())
             .expect("session token is encrypted");
+        let (key_id, sealed) = token.split_once('.').expect("session token has a key ID");
         let mut token_bytes = URL_SAFE_NO_PAD
-            .decode(&token)
+            .decode(sealed)
             .expect("session token decodes");
         let last_byte = token_bytes.last_mut().expect("session token is non-empty");
         *last_byte ^= 1;
-        let tampered_token = URL_SAFE_NO_PAD.encode(token_bytes);
+        let tampered_token = format!("{key_id}.{}", URL_SAFE_NO_PAD.encode(token_bytes));
 
         assert!(state.resolve_session(&tampered_token).is_none());
     }
+
+    #[test]
+    fn impersonation_session_carries_identity_without_token() {
+        let state =
+            AppState::new("test-secret".to_string()).with_auth_mode(ConsoleAuthMode::Impersonation);
+        let user = ImpersonatedUser {
+            username: "alice@example.com".to_string(),
+            groups: vec!["storage-admins".to_string()],
+            uid: Some("4f6d2c1e".to_string()),
+            extra: [(
+                "authentication.kubernetes.io/credential-id".to_string(),
+                vec!["JTI=abc".to_string()],
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let token = state
+            .create_impersonation_session(user.clone())
+            .expect("session token is encrypted");
+        let claims = state
+            .resolve_session(&token)
+            .expect("encrypted session resolves");
+        assert_eq!(claims.impersonate, Some(user));
+        assert!(claims.k8s_token.is_empty());
+
+        let mut config = kube::Config::new("https://kubernetes.default.svc".parse().expect("uri"));
+        config.auth_info.token = Some("console-sa-token".to_string().into());
+        claims.apply_to(&mut config);
+        assert_eq!(
+            config.auth_info.impersonate.as_deref(),
+            Some("alice@example.com")
+        );
+        assert_eq!(
+            config.auth_info.impersonate_groups,
+            Some(vec!["storage-admins".to_string()])
+        );
+        let header = |name: &str| {
+            config
+                .headers
+                .iter()
+                .filter(|(header, _)| header.as_str() == name)
+                .map(|(_, value)| value.to_str().unwrap_or_default().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(header("impersonate-uid"), vec!["4f6d2c1e"]);
+        assert_eq!(
+            header("impersonate-extra-authentication.kubernetes.io%2fcredential-id"),
+            vec!["JTI=abc"]
+        );
+        assert!(config.auth_info.token.is_some());
+        assert_eq!(
+            ConsoleAuthMode::parse("Impersonation"),
+            Some(ConsoleAuthMode::Impersonation)
+        );
+        assert_eq!(ConsoleAuthMode::parse("bogus"), None);
+    }
+
+    #[test]
+    fn session_cookie_token_resolves_with_previous_secret_after_rotation() {
+        let before_rotation = AppState::new("old-secret".to_string());
+        let token = before_rotation
+            .create_session("rotated-token".to_string())
+            .expect("session token is encrypted");
+        let legacy_token = seal_session_token(
+            "old-secret",
+            &SessionClaims {
+                k8s_token: "legacy-token".to_string(),
+                impersonate: None,
+                exp: current_timestamp() + 60,
+                iat: current_timestamp(),
+            },
+        )
+        .expect("session token is encrypted");
+
+        let rotated = AppState::new("new-secret".to_string())
+            .with_previous_secrets(vec!["old-secret".into()]);
+        let claims = rotated
+            .resolve_session(&token)
+            .expect("previous secret resolves session");
+        assert_eq!(claims.k8s_token, "rotated-token");
+        let claims = rotated
+            .resolve_session(&legacy_token)
+            .expect("token without key ID resolves");
+        assert_eq!(claims.k8s_token, "legacy-token");
+
+        let new_token = rotated
+            .create_session("fresh-token".to_string())
+            .expect("session token is encrypted");
+        assert!(new_token.starts_with(&format!("{}.", session_key_id("new-secret"))));
+        assert!(
+            AppState::new("new-secret".to_string())
+                .resolve_session(&token)
+                .is_none()
+        );
+    }
 }
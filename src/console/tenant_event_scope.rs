@@ -19,6 +19,7 @@ use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 
 use futures::stream::{self, StreamExt};
+use k8s_openapi::api::apps::v1 as appsv1;
 use k8s_openapi::api::core::v1 as corev1;
 use k8s_openapi::api::events::v1 as eventsv1;
 use kube::{Api, Client, ResourceExt, api::ListParams};
@@ -40,7 +41,7 @@ const EVENT_LIST_CONCURRENCY: usize = 16;
 
 /// Label selector `rustfs.tenant=<tenant>` — must match [`crate::console::handlers::pods::list_pods`].
 pub fn tenant_label_selector(tenant: &str) -> String {
-    format!("rustfs.tenant={}", tenant)
+    crate::types::v1alpha1::tenant::tenant_selector(tenant)
 }
 
 /// Allowed `(kind, name)` pairs for `Event.regarding` in this tenant scope.
@@ -53,7 +54,7 @@ pub struct TenantEventScope {
     pub involved: HashSet<(String, String)>,
 }
 
-/// Load Pod / StatefulSet / PVC names and Tenant CR row — same discovery rules as `list_pods` / `list_pools`.
+/// Load Pod / StatefulSet / Service / PVC names and Tenant CR row — same discovery rules as `list_pods` / `list_pools`.
 pub async fn discover_tenant_event_scope(
     client: &Client,
     namespace: &str,
@@ -73,6 +74,25 @@ pub async fn discover_tenant_event_scope(
         involved.insert(("StatefulSet".to_string(), ss_name));
     }
 
+    // Labelled StatefulSets also cover pools already removed from spec (decommission cleanup).
+    let ss_api: Api<appsv1::StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let statefulsets = ss_api
+        .list(&ListParams::default().labels(&tenant_label_selector(tenant)))
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("StatefulSets for tenant '{}'", tenant)))?;
+    for ss in statefulsets.items {
+        involved.insert(("StatefulSet".to_string(), ss.name_any()));
+    }
+
+    let svc_api: Api<corev1::Service> = Api::namespaced(client.clone(), namespace);
+    let services = svc_api
+        .list(&ListParams::default().labels(&tenant_label_selector(tenant)))
+        .await
+        .map_err(|e| error::map_kube_error(e, format!("Services for tenant '{}'", tenant)))?;
+    for svc in services.items {
+        involved.insert(("Service".to_string(), svc.name_any()));
+    }
+
     let pod_api: Api<corev1::Pod> = Api::namespaced(client.clone(), namespace);
     let pods = pod_api
         .list(&ListParams::default().labels(&tenant_label_selector(tenant)))
@@ -182,20 +202,19 @@ fn weak_dedup_key_v1(e: &eventsv1::Event) -> (String, String, String, String, St
     (kind, name, reason, first, msg)
 }
 
+/// Most recent observation: series last-observed time, then legacy `lastTimestamp`, then creation.
+fn event_v1_last_observed(e: &eventsv1::Event) -> Option<chrono::DateTime<chrono::Utc>> {
+    e.series
+        .as_ref()
+        .map(|s| s.last_observed_time.0)
+        .or_else(|| e.deprecated_last_timestamp.as_ref().map(|lt| lt.0))
+        .or_else(|| e.event_time.as_ref().map(|et| et.0))
+        .or_else(|| e.deprecated_first_timestamp.as_ref().map(|ft| ft.0))
+}
+
 fn event_v1_sort_key(e: &eventsv1::Event) -> chrono::DateTime<chrono::Utc> {
-    if let Some(ref et) = e.event_time {
-        return et.0;
-    }
-    if let Some(ref s) = e.series {
-        return s.last_observed_time.0;
-    }
-    if let Some(ref lt) = e.deprecated_last_timestamp {
-        return lt.0;
-    }
-    if let Some(ref ft) = e.deprecated_first_timestamp {
-        return ft.0;
-    }
-    chrono::DateTime::from_timestamp(0, 0).unwrap_or_else(chrono::Utc::now)
+    event_v1_last_observed(e)
+        .unwrap_or_else(|| chrono::DateTime::from_timestamp(0, 0).unwrap_or_else(chrono::Utc::now))
 }
 
 fn events_v1_to_item(e: eventsv1::Event) -> EventItem {
@@ -209,6 +228,7 @@ fn events_v1_to_item(e: eventsv1::Event) -> EventItem {
         .as_ref()
         .and_then(|r| r.name.clone())
         .unwrap_or_default();
+    let last_timestamp = event_v1_last_observed(&e).map(|ts| ts.to_rfc3339());
     let count = e
         .series
         .as_ref()
@@ -221,10 +241,7 @@ fn events_v1_to_item(e: eventsv1::Event) -> EventItem {
         message: e.note.unwrap_or_default(),
         involved_object: format!("{}/{}", kind, name),
         first_timestamp: e.deprecated_first_timestamp.map(|ts| ts.0.to_rfc3339()),
-        last_timestamp: e
-            .deprecated_last_timestamp
-            .map(|ts| ts.0.to_rfc3339())
-            .or_else(|| e.event_time.map(|ts| ts.0.to_rfc3339())),
+        last_timestamp,
         count,
     }
 }
@@ -259,4 +276,29 @@ mod tests {
         assert_eq!(items.len(), 1);
         assert_eq!(items[0].involved_object, "Pod/p1");
     }
+
+    #[test]
+    fn merge_sorts_by_latest_observation() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::{MicroTime, Time};
+        let at = |secs: i64| chrono::DateTime::from_timestamp(secs, 0).unwrap_or_default();
+
+        // Series started long ago but observed most recently.
+        let mut recurring = mk_event_v1("StatefulSet", "t-pool-0", Some("uid-a"));
+        recurring.event_time = Some(MicroTime(at(100)));
+        recurring.series = Some(eventsv1::EventSeries {
+            count: 5,
+            last_observed_time: MicroTime(at(300)),
+        });
+        let mut legacy = mk_event_v1("Service", "t-io", Some("uid-b"));
+        legacy.deprecated_last_timestamp = Some(Time(at(200)));
+
+        let items = merge_events_v1(vec![legacy, recurring]);
+
+        assert_eq!(items[0].involved_object, "StatefulSet/t-pool-0");
+        assert_eq!(
+            items[0].last_timestamp.as_deref(),
+            Some(at(300).to_rfc3339().as_str())
+        );
+        assert_eq!(items[1].involved_object, "Service/t-io");
+    }
 }
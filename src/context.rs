@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use crate::types;
+use crate::types::v1alpha1::credentials::{
+    CredsSecretReference, LOW_ENTROPY_BITS, estimated_entropy_bits,
+};
 use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::ByteString;
 use k8s_openapi::NamespaceResourceScope;
 use k8s_openapi::api::core::v1::Secret;
 use kube::api::{DeleteParams, ListParams, ObjectList, Patch, PatchParams, PostParams};
@@ -21,9 +25,12 @@ use kube::runtime::events::{Event, EventType, Recorder, Reporter};
 use kube::{Resource, ResourceExt, api::Api};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use snafu::Snafu;
 use snafu::futures::TryFutureExt;
+use snafu::{OptionExt, Snafu};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::info;
 
 #[derive(Debug, Snafu)]
@@ -51,15 +58,17 @@ pub enum Error {
     CredentialSecretInvalidEncoding { secret_name: String, key: String },
 
     #[snafu(display(
-        "credential secret '{}' key '{}' must be at least 8 characters (got {} characters)",
+        "credential secret '{}' key '{}' must be at least {} characters (got {} characters)",
         secret_name,
         key,
+        min_length,
         length
     ))]
     CredentialSecretTooShort {
         secret_name: String,
         key: String,
         length: usize,
+        min_length: usize,
     },
 
     #[snafu(display("KMS secret '{}' not found", name))]
@@ -71,6 +80,15 @@ pub enum Error {
     #[snafu(display("KMS configuration invalid: {}", message))]
     KmsConfigInvalid { message: String },
 
+    #[snafu(display("existing headless service '{}' is not usable: {}", name, message))]
+    ExistingHeadlessServiceInvalid { name: String, message: String },
+
+    #[snafu(display("existing claim '{}' cannot be reused: {}", claim, message))]
+    ExistingClaimUnavailable { claim: String, message: String },
+
+    #[snafu(display("volume snapshot '{}' cannot be restored: {}", snapshot, message))]
+    SnapshotSourceUnavailable { snapshot: String, message: String },
+
     #[snafu(transparent)]
     Serde { source: serde_json::Error },
 }
@@ -108,6 +126,10 @@ fn status_semantically_equal(
         return false;
     };
 
+    if reconcile_heartbeat_due(current, next) || next_reconcile_time_expired(current, next) {
+        return false;
+    }
+
     let mut current = current.clone();
     let mut next = next.clone();
     normalize_status_for_compare(&mut current);
@@ -115,10 +137,110 @@ fn status_semantically_equal(
     current == next
 }
 
+/// Reconcile bookkeeping fields alone only trigger a status write once per heartbeat interval;
+/// writing them every reconcile would re-trigger the Tenant watch in a loop.
+const RECONCILE_HEARTBEAT_INTERVAL: chrono::TimeDelta = chrono::TimeDelta::minutes(5);
+
+fn reconcile_heartbeat_due(
+    current: &types::v1alpha1::status::Status,
+    next: &types::v1alpha1::status::Status,
+) -> bool {
+    let Some(next_time) = next
+        .last_reconcile_time
+        .as_deref()
+        .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok())
+    else {
+        return false;
+    };
+
+    current
+        .last_reconcile_time
+        .as_deref()
+        .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok())
+        .is_none_or(|current_time| next_time - current_time >= RECONCILE_HEARTBEAT_INTERVAL)
+}
+
+/// Whether the stored `nextReconcileTime` has already passed when `next` was built and `next`
+/// schedules a different time, so the field would otherwise show a time in the past.
+fn next_reconcile_time_expired(
+    current: &types::v1alpha1::status::Status,
+    next: &types::v1alpha1::status::Status,
+) -> bool {
+    let parse =
+        |time: Option<&str>| time.and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok());
+    let (Some(scheduled), Some(now)) = (
+        parse(current.next_reconcile_time.as_deref()),
+        parse(next.last_reconcile_time.as_deref()),
+    ) else {
+        return false;
+    };
+    scheduled <= now && current.next_reconcile_time != next.next_reconcile_time
+}
+
+/// The lifecycle phase change between two statuses, if any. A Tenant's first phase is not a
+/// transition.
+fn lifecycle_transition(
+    current: Option<&types::v1alpha1::status::Status>,
+    next: &types::v1alpha1::status::Status,
+) -> Option<(
+    types::v1alpha1::status::state::LifecyclePhase,
+    types::v1alpha1::status::state::LifecyclePhase,
+)> {
+    let from = current?.lifecycle.as_ref()?.phase;
+    let to = next.lifecycle.as_ref()?.phase;
+    (from != to).then_some((from, to))
+}
+
+/// Whether `next` changes something users and automation act on: the summary state, the
+/// lifecycle phase, observed generation or spec, the requeue reason, an expired
+/// `nextReconcileTime`, or the status and reason of a condition. Such writes bypass
+/// [`StatusWrites`] throttling; message and counter updates do not.
+fn status_change_is_significant(
+    current: Option<&types::v1alpha1::status::Status>,
+    next: &types::v1alpha1::status::Status,
+) -> bool {
+    let Some(current) = current else {
+        return true;
+    };
+    let conditions = |status: &types::v1alpha1::status::Status| {
+        status
+            .conditions
+            .iter()
+            .map(|condition| {
+                (
+                    condition.type_.clone(),
+                    condition.status.clone(),
+                    condition.reason.clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    current.current_state != next.current_state
+        || current.lifecycle.as_ref().map(|lifecycle| lifecycle.phase)
+            != next.lifecycle.as_ref().map(|lifecycle| lifecycle.phase)
+        || current.observed_generation != next.observed_generation
+        || current.observed_spec_hash != next.observed_spec_hash
+        || current.requeue_reason != next.requeue_reason
+        || next_reconcile_time_expired(current, next)
+        || conditions(current) != conditions(next)
+}
+
 fn normalize_status_for_compare(status: &mut types::v1alpha1::status::Status) {
     for pool in &mut status.pools {
         pool.last_update_time = None;
     }
+    status.last_reconcile_time = None;
+    status.last_reconcile_duration = None;
+    status.reconcile_error_count = None;
+    // Moves with every reconcile; `requeue_reason` still forces a write when it changes, and so
+    // does a stored time that has already passed.
+    status.next_reconcile_time = None;
+    // Derived from the clock rather than the certificate; ignore so they don't force writes.
+    if let Some(tls) = status.certificates.tls.as_mut() {
+        tls.expires_in_seconds = None;
+        tls.last_validated_time = None;
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -127,6 +249,38 @@ pub(crate) enum SecretValidationKind {
     Kms,
 }
 
+/// What [`Context::apply_if_changed_by`] did with a generated child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum ApplyOutcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// Default comparator for [`Context::apply_if_changed_by`]: the live object carries the
+/// [`SPEC_HASH_LABEL`](crate::types::v1alpha1::tenant::SPEC_HASH_LABEL) of the rendering.
+pub fn spec_hash_unchanged<T: Resource>(live: &T, desired: &T) -> bool {
+    use crate::types::v1alpha1::tenant::SPEC_HASH_LABEL;
+
+    desired
+        .labels()
+        .get(SPEC_HASH_LABEL)
+        .is_some_and(|hash| live.labels().get(SPEC_HASH_LABEL) == Some(hash))
+}
+
+/// Default comparator for [`Context::apply_if_changed`]: the spec hash label is current and the
+/// live object still holds every field of the rendering, so a hand edit is reverted on the next
+/// reconcile. Fields only the live object has, such as server defaults, are ignored.
+pub fn rendering_unchanged<T: Resource + Serialize>(live: &T, desired: &T) -> bool {
+    use crate::types::v1alpha1::tenant::rendering_contained;
+
+    spec_hash_unchanged(live, desired)
+        && matches!(
+            (serde_json::to_value(live), serde_json::to_value(desired)),
+            (Ok(live), Ok(desired)) if rendering_contained(&live, &desired)
+        )
+}
+
 pub(crate) fn is_kube_not_found(error: &Error) -> bool {
     matches!(
         error,
@@ -151,9 +305,263 @@ pub(crate) fn map_secret_get_error(
     }
 }
 
+/// Non-fatal findings from credential Secret validation.
+#[derive(Debug, Default, PartialEq)]
+pub struct CredentialSecretReport {
+    /// Secret key whose value has low estimated entropy.
+    pub low_entropy_key: Option<String>,
+}
+
+/// Checks the credential values against the key names and length policy of `cfg`.
+pub(crate) fn validate_credential_data(
+    cfg: &CredsSecretReference,
+    data: &BTreeMap<String, ByteString>,
+) -> Result<CredentialSecretReport, Error> {
+    let read_value = |key: &str, min_length: usize| -> Result<String, Error> {
+        let bytes = data.get(key).context(CredentialSecretMissingKeySnafu {
+            secret_name: cfg.name.clone(),
+            key,
+        })?;
+        let value = String::from_utf8(bytes.0.clone()).map_err(|_| {
+            Error::CredentialSecretInvalidEncoding {
+                secret_name: cfg.name.clone(),
+                key: key.to_string(),
+            }
+        })?;
+        if value.len() < min_length {
+            return CredentialSecretTooShortSnafu {
+                secret_name: cfg.name.clone(),
+                key,
+                length: value.len(),
+                min_length,
+            }
+            .fail();
+        }
+        Ok(value)
+    };
+
+    read_value(cfg.access_key(), cfg.access_key_min_length())?;
+    let secret_value = read_value(cfg.secret_key(), cfg.secret_key_min_length())?;
+
+    let low_entropy_key = (estimated_entropy_bits(&secret_value) < LOW_ENTROPY_BITS)
+        .then(|| cfg.secret_key().to_string());
+    Ok(CredentialSecretReport { low_entropy_key })
+}
+
+/// `Kind/name` of a child object, for chaos logs and errors.
+fn object_ref<T>(name: &str) -> String
+where
+    T: Resource,
+    <T as kube::Resource>::DynamicType: Default,
+{
+    format!("{}/{}", T::kind(&Default::default()), name)
+}
+
+/// Page size of [`Context::list_labeled`].
+const LIST_PAGE_SIZE: u32 = 500;
+
 pub struct Context {
     pub(crate) client: kube::Client,
     pub(crate) recorder: Recorder,
+    pub(crate) reconcile_fingerprints: ReconcileFingerprints,
+    /// Child metadata caches for the no-op reconcile gate; unset outside the controller.
+    pub(crate) child_stores: Option<Arc<crate::reconcile::gating::ChildStores>>,
+    pub(crate) node_down_deletions: NodeDownDeletions,
+    pub(crate) warning_findings: WarningFindings,
+    pub(crate) remote_clients: crate::reconcile::federation::RemoteClients,
+    pub(crate) status_writes: StatusWrites,
+    pub(crate) resync: crate::reconcile::priority::ResyncConfig,
+    pub(crate) proxy: crate::proxy::ProxyConfig,
+    pub(crate) notifier: crate::reconcile::notifications::Notifier,
+    /// Set by `reconcile-once --dry-run`: the client sends every write with `dryRun=All`, and
+    /// side effects outside the Kubernetes API (RustFS admin writes, webhooks, remote clusters)
+    /// are skipped.
+    pub(crate) dry_run: bool,
+    /// Fault injection for the Tenants selected by chaos mode; see [`crate::chaos`].
+    pub(crate) chaos: Option<crate::chaos::Chaos>,
+    /// Operator-wide switch to status-only reconciles; see [`crate::reconcile::maintenance`].
+    pub(crate) maintenance: crate::reconcile::maintenance::MaintenanceConfig,
+}
+
+/// Child fingerprints recorded after successful reconciles, keyed by `namespace/name`.
+///
+/// Entries expire so settled Tenants are still fully reconciled periodically.
+#[derive(Default)]
+pub(crate) struct ReconcileFingerprints {
+    entries: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl ReconcileFingerprints {
+    const MAX_AGE: Duration = Duration::from_secs(600);
+
+    pub(crate) fn matches(&self, key: &str, fingerprint: &str) -> bool {
+        let Ok(entries) = self.entries.lock() else {
+            return false;
+        };
+        entries.get(key).is_some_and(|(recorded, recorded_at)| {
+            recorded == fingerprint && recorded_at.elapsed() < Self::MAX_AGE
+        })
+    }
+
+    pub(crate) fn record(&self, key: String, fingerprint: String) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(key, (fingerprint, Instant::now()));
+        }
+    }
+
+    pub(crate) fn forget(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+}
+
+/// Last time the node-down cleanup deleted pods on a node, keyed by `namespace/tenant/node`.
+#[derive(Default)]
+pub(crate) struct NodeDownDeletions {
+    entries: Mutex<HashMap<String, Instant>>,
+}
+
+impl NodeDownDeletions {
+    pub(crate) fn cooling_down(&self, key: &str, cooldown: Duration) -> bool {
+        let Ok(entries) = self.entries.lock() else {
+            return false;
+        };
+        entries
+            .get(key)
+            .is_some_and(|deleted_at| deleted_at.elapsed() < cooldown)
+    }
+
+    pub(crate) fn record(&self, key: String) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(key, Instant::now());
+        }
+    }
+}
+
+/// Warning findings last reported per Tenant, keyed by `namespace/name/reason`, so a warning
+/// event is recorded when a finding appears or changes rather than on every reconcile.
+#[derive(Default)]
+pub(crate) struct WarningFindings {
+    entries: Mutex<HashMap<String, String>>,
+}
+
+impl WarningFindings {
+    /// Records the current `reason` finding of `tenant` and returns whether it is new. `None`
+    /// clears it.
+    pub(crate) fn report(&self, tenant: &Tenant, reason: &str, finding: Option<&str>) -> bool {
+        let key = format!(
+            "{}/{}/{reason}",
+            tenant.metadata.namespace.as_deref().unwrap_or_default(),
+            tenant.name_any()
+        );
+        let Ok(mut entries) = self.entries.lock() else {
+            return finding.is_some();
+        };
+        match finding {
+            Some(finding) => {
+                if entries
+                    .get(&key)
+                    .is_some_and(|reported| reported == finding)
+                {
+                    return false;
+                }
+                entries.insert(key, finding.to_string());
+                true
+            }
+            None => {
+                entries.remove(&key);
+                false
+            }
+        }
+    }
+}
+
+/// Last status write per Tenant, keyed by `namespace/name`, used to flush minor status changes
+/// at most once per `OPERATOR_STATUS_MIN_WRITE_INTERVAL_SECONDS`.
+///
+/// A deferred change is not stored: the Tenant is requeued for when the interval ends, and that
+/// reconcile recomputes the status and writes it. Throttling is disabled when the interval is
+/// unset or `0`.
+#[derive(Default)]
+pub(crate) struct StatusWrites {
+    min_interval: Option<Duration>,
+    /// Time of the last write, and whether a change was deferred since.
+    entries: Mutex<HashMap<String, (Instant, bool)>>,
+}
+
+impl StatusWrites {
+    pub(crate) fn new(min_interval: Option<Duration>) -> Self {
+        Self {
+            min_interval,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Reads `OPERATOR_STATUS_MIN_WRITE_INTERVAL_SECONDS`; unset or `0` disables throttling.
+    pub(crate) fn from_env() -> Self {
+        const NAME: &str = "OPERATOR_STATUS_MIN_WRITE_INTERVAL_SECONDS";
+        let min_interval = match std::env::var(NAME) {
+            Ok(value) => match value.trim().parse::<u64>() {
+                Ok(0) => None,
+                Ok(seconds) => Some(Duration::from_secs(seconds)),
+                Err(_) => {
+                    tracing::warn!(
+                        name = NAME,
+                        value,
+                        "invalid status write interval, disabling throttling"
+                    );
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+        Self::new(min_interval)
+    }
+
+    /// Marks a minor change of `key` as deferred when its last write is within the interval.
+    pub(crate) fn defer(&self, key: &str) -> bool {
+        let Some(min_interval) = self.min_interval else {
+            return false;
+        };
+        let Ok(mut entries) = self.entries.lock() else {
+            return false;
+        };
+        match entries.get_mut(key) {
+            Some((written_at, pending)) if written_at.elapsed() < min_interval => {
+                *pending = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn record(&self, key: String) {
+        if self.min_interval.is_none() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(key, (Instant::now(), false));
+        }
+    }
+
+    /// Time left until a deferred change of `key` may be written, if one is pending.
+    pub(crate) fn flush_delay(&self, key: &str) -> Option<Duration> {
+        let min_interval = self.min_interval?;
+        let entries = self.entries.lock().ok()?;
+        let (written_at, pending) = entries.get(key)?;
+        pending.then(|| {
+            min_interval
+                .saturating_sub(written_at.elapsed())
+                .max(Duration::from_secs(1))
+        })
+    }
+
+    pub(crate) fn forget(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
 }
 
 impl Context {
@@ -164,7 +572,49 @@ impl Context {
         };
 
         let recorder = Recorder::new(client.clone(), reporter);
-        Self { client, recorder }
+        let proxy = crate::proxy::ProxyConfig::from_env();
+        Self {
+            client,
+            recorder,
+            reconcile_fingerprints: ReconcileFingerprints::default(),
+            child_stores: None,
+            node_down_deletions: NodeDownDeletions::default(),
+            warning_findings: WarningFindings::default(),
+            remote_clients: Default::default(),
+            status_writes: StatusWrites::from_env(),
+            resync: crate::reconcile::priority::ResyncConfig::from_env(),
+            notifier: crate::reconcile::notifications::Notifier::new(&proxy),
+            proxy,
+            dry_run: false,
+            chaos: None,
+            maintenance: Default::default(),
+        }
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn with_chaos(mut self, chaos: Option<crate::chaos::ChaosConfig>) -> Self {
+        self.chaos = chaos.map(crate::chaos::Chaos::new);
+        self
+    }
+
+    pub(crate) fn with_child_stores(
+        mut self,
+        child_stores: crate::reconcile::gating::ChildStores,
+    ) -> Self {
+        self.child_stores = Some(Arc::new(child_stores));
+        self
+    }
+
+    pub fn with_maintenance(
+        mut self,
+        maintenance: crate::reconcile::maintenance::MaintenanceConfig,
+    ) -> Self {
+        self.maintenance = maintenance;
+        self
     }
 
     /// send event
@@ -240,10 +690,43 @@ impl Context {
         status: crate::types::v1alpha1::status::Status,
     ) -> Result<Option<Tenant>, Error> {
         if status_semantically_equal(resource.status.as_ref(), &status) {
+            crate::metrics::record_status_write("unchanged");
+            crate::reconcile::summary::record_status_write("unchanged");
+            return Ok(None);
+        }
+
+        let key = format!(
+            "{}/{}",
+            resource.namespace().unwrap_or_default(),
+            resource.name()
+        );
+        if !status_change_is_significant(resource.status.as_ref(), &status)
+            && self.status_writes.defer(&key)
+        {
+            crate::metrics::record_status_write("deferred");
+            crate::reconcile::summary::record_status_write("deferred");
             return Ok(None);
         }
 
-        self.update_status(resource, status).await.map(Some)
+        let transitions =
+            crate::reconcile::notifications::transitions(resource.status.as_ref(), &status);
+        let lifecycle_transition = lifecycle_transition(resource.status.as_ref(), &status);
+        let updated = self.update_status(resource, status).await?;
+        self.status_writes.record(key);
+        crate::metrics::record_status_write("written");
+        crate::reconcile::summary::record_status_write("written");
+        if let Some((from, to)) = lifecycle_transition {
+            let _ = self
+                .record(
+                    resource,
+                    EventType::Normal,
+                    "LifecycleTransition",
+                    &format!("Tenant lifecycle changed from {from} to {to}"),
+                )
+                .await;
+        }
+        crate::reconcile::notifications::notify(self, resource, &transitions).await;
+        Ok(Some(updated))
     }
 
     pub async fn delete<T>(&self, name: &str, namespace: &str) -> Result<(), Error>
@@ -265,8 +748,12 @@ impl Context {
         T: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
         <T as kube::Resource>::DynamicType: Default,
     {
+        crate::chaos::before_write("delete", &object_ref::<T>(name))
+            .context(KubeSnafu)
+            .await?;
         let api: Api<T> = Api::namespaced(self.client.clone(), namespace);
         api.delete(name, params).context(KubeSnafu).await?;
+        crate::reconcile::summary::record_deleted();
         Ok(())
     }
 
@@ -299,6 +786,33 @@ impl Context {
         api.list(&ListParams::default()).context(KubeSnafu).await
     }
 
+    /// Lists the `T` objects in `namespace` matching the label `selector`, in pages of
+    /// [`LIST_PAGE_SIZE`] so a large namespace is never returned in one response.
+    pub async fn list_labeled<T>(&self, namespace: &str, selector: &str) -> Result<Vec<T>, Error>
+    where
+        T: Clone + DeserializeOwned + Debug + Resource<Scope = NamespaceResourceScope>,
+        <T as kube::Resource>::DynamicType: Default,
+    {
+        let api: Api<T> = Api::namespaced(self.client.clone(), namespace);
+        let mut objects = Vec::new();
+        let mut continue_token = None;
+        loop {
+            let mut params = ListParams::default().labels(selector).limit(LIST_PAGE_SIZE);
+            if let Some(token) = continue_token.as_deref() {
+                params = params.continue_token(token);
+            }
+            let page = api.list(&params).context(KubeSnafu).await?;
+            objects.extend(page.items);
+            continue_token = page
+                .metadata
+                .continue_
+                .filter(|token| !token.trim().is_empty());
+            if continue_token.is_none() {
+                return Ok(objects);
+            }
+        }
+    }
+
     pub async fn list_with_params<T>(
         &self,
         namespace: &str,
@@ -317,6 +831,19 @@ impl Context {
         T: Clone + Serialize + DeserializeOwned + Debug + Resource<Scope = NamespaceResourceScope>,
         <T as kube::Resource>::DynamicType: Default,
     {
+        let applied = self.server_side_apply(resource, namespace).await?;
+        crate::reconcile::summary::record_applied();
+        Ok(applied)
+    }
+
+    async fn server_side_apply<T>(&self, resource: &T, namespace: &str) -> Result<T, Error>
+    where
+        T: Clone + Serialize + DeserializeOwned + Debug + Resource<Scope = NamespaceResourceScope>,
+        <T as kube::Resource>::DynamicType: Default,
+    {
+        crate::chaos::before_write("apply", &object_ref::<T>(&resource.name_any()))
+            .context(KubeSnafu)
+            .await?;
         let api: Api<T> = Api::namespaced(self.client.clone(), namespace);
         api.patch(
             &resource.name_any(),
@@ -327,6 +854,76 @@ impl Context {
         .await
     }
 
+    /// Server-side applies `resource` with `force`, taking back fields another field manager
+    /// has changed since the last apply.
+    pub async fn force_apply<T>(&self, resource: &T, namespace: &str) -> Result<T, Error>
+    where
+        T: Clone + Serialize + DeserializeOwned + Debug + Resource<Scope = NamespaceResourceScope>,
+        <T as kube::Resource>::DynamicType: Default,
+    {
+        crate::chaos::before_write("apply", &object_ref::<T>(&resource.name_any()))
+            .context(KubeSnafu)
+            .await?;
+        let api: Api<T> = Api::namespaced(self.client.clone(), namespace);
+        api.patch(
+            &resource.name_any(),
+            &PatchParams::apply("rustfs-operator").force(),
+            &Patch::Apply(resource),
+        )
+        .context(KubeSnafu)
+        .await
+        .inspect(|_| crate::reconcile::summary::record_applied())
+    }
+
+    /// Server-side applies a generated child unless the live object already carries the
+    /// [`SPEC_HASH_LABEL`](crate::types::v1alpha1::tenant::SPEC_HASH_LABEL) of this rendering
+    /// and still matches it (see [`rendering_unchanged`]). Saves a write per unchanged child on
+    /// every reconcile; edits made to the child by hand are reverted on the next one.
+    pub async fn apply_if_changed<T>(&self, resource: &T, namespace: &str) -> Result<T, Error>
+    where
+        T: Clone + Serialize + DeserializeOwned + Debug + Resource<Scope = NamespaceResourceScope>,
+        <T as kube::Resource>::DynamicType: Default,
+    {
+        self.apply_if_changed_by(resource, namespace, rendering_unchanged)
+            .await
+            .map(|(applied, _)| applied)
+    }
+
+    /// Like [`Self::apply_if_changed`], with `unchanged(live, desired)` deciding whether the live
+    /// object already matches. `desired` carries the spec hash label, so a comparator can start
+    /// from [`rendering_unchanged`] and add checks of its own. Returns the live or applied object
+    /// and what was done, for callers that report changes as events.
+    pub async fn apply_if_changed_by<T, F>(
+        &self,
+        resource: &T,
+        namespace: &str,
+        unchanged: F,
+    ) -> Result<(T, ApplyOutcome), Error>
+    where
+        T: Clone + Serialize + DeserializeOwned + Debug + Resource<Scope = NamespaceResourceScope>,
+        <T as kube::Resource>::DynamicType: Default,
+        F: FnOnce(&T, &T) -> bool,
+    {
+        use crate::types::v1alpha1::tenant::{SPEC_HASH_LABEL, spec_hash_label_value};
+
+        let mut desired = resource.clone();
+        desired
+            .labels_mut()
+            .insert(SPEC_HASH_LABEL.to_string(), spec_hash_label_value(resource));
+        let api: Api<T> = Api::namespaced(self.client.clone(), namespace);
+        let outcome = match api.get_opt(&desired.name_any()).context(KubeSnafu).await? {
+            Some(existing) if unchanged(&existing, &desired) => {
+                crate::reconcile::summary::record_apply(ApplyOutcome::Unchanged);
+                return Ok((existing, ApplyOutcome::Unchanged));
+            }
+            Some(_) => ApplyOutcome::Updated,
+            None => ApplyOutcome::Created,
+        };
+        let applied = self.server_side_apply(&desired, namespace).await?;
+        crate::reconcile::summary::record_apply(outcome);
+        Ok((applied, outcome))
+    }
+
     /// Validates that a credential Secret exists and contains required keys.
     ///
     /// This function only validates the Secret structure when `spec.credsSecret` is configured.
@@ -335,19 +932,23 @@ impl Context {
     ///
     /// # Validation Rules
     /// - Secret must exist in the same namespace as the Tenant
-    /// - Secret must contain both `accesskey` and `secretkey` keys
+    /// - Secret must contain the access and secret keys (`accesskey` and `secretkey` unless
+    ///   overridden by `spec.credsSecret.keys`)
     /// - Both keys must be valid UTF-8 strings
-    /// - Both keys must be at least 8 characters long
+    /// - Both keys must be at least `spec.credsSecret.minLength` characters long (default 8)
     ///
     /// # Returns
-    /// - `Ok(())` if Secret is valid or not configured
+    /// - `Ok(report)` if Secret is valid or not configured
     /// - `Err(...)` if Secret is configured but invalid (not found, missing keys, invalid encoding, too short)
     ///
     /// # Note
     /// If no credentials are provided via Secret or environment variables, RustFS will use
     /// its built-in defaults (`rustfsadmin`/`rustfsadmin`).
     /// **This is acceptable for development but should be changed for production.**
-    pub async fn validate_credential_secret(&self, tenant: &Tenant) -> Result<(), Error> {
+    pub async fn validate_credential_secret(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<CredentialSecretReport, Error> {
         // Only validate if credsSecret is configured
         if let Some(ref cfg) = tenant.spec.creds_secret
             && !cfg.name.is_empty()
@@ -363,64 +964,12 @@ impl Context {
                 }
             };
 
-            // Validate Secret has required keys
             if let Some(data) = secret.data {
-                let access_key = "accesskey".to_string();
-                let secret_key = "secretkey".to_string();
-
-                // Validate accesskey exists, is valid UTF-8, and meets minimum length
-                if let Some(accesskey_bytes) = data.get(&access_key) {
-                    let accesskey = String::from_utf8(accesskey_bytes.0.clone()).map_err(|_| {
-                        Error::CredentialSecretInvalidEncoding {
-                            secret_name: cfg.name.clone(),
-                            key: access_key.clone(),
-                        }
-                    })?;
-
-                    if accesskey.len() < 8 {
-                        return CredentialSecretTooShortSnafu {
-                            secret_name: cfg.name.clone(),
-                            key: access_key.clone(),
-                            length: accesskey.len(),
-                        }
-                        .fail();
-                    }
-                } else {
-                    return CredentialSecretMissingKeySnafu {
-                        secret_name: cfg.name.clone(),
-                        key: access_key,
-                    }
-                    .fail();
-                }
-
-                // Validate secretkey exists, is valid UTF-8, and meets minimum length
-                if let Some(secretkey_bytes) = data.get(&secret_key) {
-                    let secretkey = String::from_utf8(secretkey_bytes.0.clone()).map_err(|_| {
-                        Error::CredentialSecretInvalidEncoding {
-                            secret_name: cfg.name.clone(),
-                            key: secret_key.clone(),
-                        }
-                    })?;
-
-                    if secretkey.len() < 8 {
-                        return CredentialSecretTooShortSnafu {
-                            secret_name: cfg.name.clone(),
-                            key: secret_key.clone(),
-                            length: secretkey.len(),
-                        }
-                        .fail();
-                    }
-                } else {
-                    return CredentialSecretMissingKeySnafu {
-                        secret_name: cfg.name.clone(),
-                        key: secret_key,
-                    }
-                    .fail();
-                }
+                return validate_credential_data(cfg, &data);
             }
         }
 
-        Ok(())
+        Ok(CredentialSecretReport::default())
     }
 
     /// Validates encryption configuration and the KMS Secret.
@@ -539,37 +1088,22 @@ impl Context {
     pub async fn is_rollout_complete(&self, name: &str, namespace: &str) -> Result<bool, Error> {
         let ss: k8s_openapi::api::apps::v1::StatefulSet = self.get(name, namespace).await?;
 
-        let metadata = &ss.metadata;
-        let spec = ss.spec.as_ref().ok_or_else(|| Error::Types {
-            source: types::error::Error::InternalError {
-                msg: format!("StatefulSet {} missing spec", name),
-            },
-        })?;
-
-        let status = ss.status.as_ref().ok_or_else(|| Error::Types {
-            source: types::error::Error::InternalError {
-                msg: format!("StatefulSet {} missing status", name),
-            },
-        })?;
-
-        let desired_replicas = spec.replicas.unwrap_or(1);
-
-        // Check if controller has observed the latest generation
-        let generation_current = metadata.generation.is_some()
-            && status.observed_generation.is_some()
-            && metadata.generation == status.observed_generation;
-
-        // Check if all replicas are ready
-        let replicas_ready = status.replicas == desired_replicas
-            && status.ready_replicas.unwrap_or(0) == desired_replicas
-            && status.updated_replicas.unwrap_or(0) == desired_replicas;
-
-        // Check if all pods are on the same revision
-        let revisions_match = status.current_revision.is_some()
-            && status.update_revision.is_some()
-            && status.current_revision == status.update_revision;
+        if ss.spec.is_none() {
+            return Err(Error::Types {
+                source: types::error::Error::InternalError {
+                    msg: format!("StatefulSet {} missing spec", name),
+                },
+            });
+        }
+        if ss.status.is_none() {
+            return Err(Error::Types {
+                source: types::error::Error::InternalError {
+                    msg: format!("StatefulSet {} missing status", name),
+                },
+            });
+        }
 
-        Ok(generation_current && replicas_ready && revisions_match)
+        Ok(statefulset_rollout_complete(&ss))
     }
 
     /// Gets the current and update revision of a StatefulSet
@@ -588,6 +1122,32 @@ impl Context {
     }
 }
 
+/// The checks behind [`Context::is_rollout_complete`], on an already fetched StatefulSet. A
+/// StatefulSet without spec or status is not complete.
+pub fn statefulset_rollout_complete(ss: &k8s_openapi::api::apps::v1::StatefulSet) -> bool {
+    let (Some(spec), Some(status)) = (ss.spec.as_ref(), ss.status.as_ref()) else {
+        return false;
+    };
+    let desired_replicas = spec.replicas.unwrap_or(1);
+
+    // Check if controller has observed the latest generation
+    let generation_current = ss.metadata.generation.is_some()
+        && status.observed_generation.is_some()
+        && ss.metadata.generation == status.observed_generation;
+
+    // Check if all replicas are ready
+    let replicas_ready = status.replicas == desired_replicas
+        && status.ready_replicas.unwrap_or(0) == desired_replicas
+        && status.updated_replicas.unwrap_or(0) == desired_replicas;
+
+    // Check if all pods are on the same revision
+    let revisions_match = status.current_revision.is_some()
+        && status.update_revision.is_some()
+        && status.current_revision == status.update_revision;
+
+    generation_current && replicas_ready && revisions_match
+}
+
 #[cfg(test)]
 mod validate_local_kms_tests {
     use super::Error;
@@ -605,6 +1165,11 @@ mod validate_local_kms_tests {
                 volumes_per_server: 4,
                 ..Default::default()
             },
+            env: Vec::new(),
+            service_account_name: None,
+            pod_metadata: None,
+            pod_deletion_policy_when_node_is_down: None,
+            paused: None,
             scheduling: Default::default(),
         }
     }
@@ -677,3 +1242,248 @@ mod validate_local_kms_tests {
         assert!(matches!(err, Error::KmsConfigInvalid { .. }));
     }
 }
+
+#[cfg(test)]
+mod status_compare_tests {
+    use super::{
+        StatusWrites, lifecycle_transition, status_change_is_significant, status_semantically_equal,
+    };
+    use crate::types::v1alpha1::status::state::{LifecyclePhase, LifecycleSignal, LifecycleStatus};
+    use crate::types::v1alpha1::status::{Condition, Status};
+    use std::time::Duration;
+
+    fn status_at(time: &str) -> Status {
+        Status {
+            current_state: "Ready".to_string(),
+            last_reconcile_time: Some(time.to_string()),
+            reconcile_error_count: Some(0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reconcile_bookkeeping_only_changes_status_after_heartbeat() {
+        let current = status_at("2025-01-01T00:00:00Z");
+
+        let mut next = status_at("2025-01-01T00:01:00Z");
+        next.reconcile_error_count = Some(3);
+        assert!(status_semantically_equal(Some(&current), &next));
+
+        let next = status_at("2025-01-01T00:05:00Z");
+        assert!(!status_semantically_equal(Some(&current), &next));
+
+        let mut next = status_at("2025-01-01T00:01:00Z");
+        next.current_state = "Degraded".to_string();
+        assert!(!status_semantically_equal(Some(&current), &next));
+    }
+
+    #[test]
+    fn requeue_reason_changes_status_but_next_reconcile_time_does_not() {
+        let mut current = status_at("2025-01-01T00:00:00Z");
+        current.next_reconcile_time = Some("2025-01-01T00:00:10Z".to_string());
+        current.requeue_reason = Some("RolloutInProgress".to_string());
+
+        let mut next = current.clone();
+        next.next_reconcile_time = Some("2025-01-01T00:00:20Z".to_string());
+        assert!(status_semantically_equal(Some(&current), &next));
+
+        next.requeue_reason = Some("ErrorBackoff".to_string());
+        assert!(!status_semantically_equal(Some(&current), &next));
+    }
+
+    #[test]
+    fn expired_next_reconcile_time_is_rewritten() {
+        let mut current = status_at("2025-01-01T00:00:00Z");
+        current.next_reconcile_time = Some("2025-01-01T00:00:30Z".to_string());
+        current.requeue_reason = Some("PeriodicResync".to_string());
+
+        let mut next = current.clone();
+        next.last_reconcile_time = Some("2025-01-01T00:00:31Z".to_string());
+        next.next_reconcile_time = Some("2025-01-01T00:01:01Z".to_string());
+        assert!(!status_semantically_equal(Some(&current), &next));
+        assert!(status_change_is_significant(Some(&current), &next));
+
+        next.last_reconcile_time = Some("2025-01-01T00:00:20Z".to_string());
+        next.next_reconcile_time = Some("2025-01-01T00:00:50Z".to_string());
+        assert!(status_semantically_equal(Some(&current), &next));
+    }
+
+    #[test]
+    fn only_condition_and_state_changes_bypass_write_throttling() {
+        let mut current = status_at("2025-01-01T00:00:00Z");
+        current.conditions = vec![Condition {
+            type_: "Ready".to_string(),
+            status: "False".to_string(),
+            reason: "PodsNotReady".to_string(),
+            message: "1/4 pods ready".to_string(),
+            last_transition_time: None,
+            observed_generation: None,
+        }];
+
+        let mut next = current.clone();
+        next.available_replicas = 2;
+        next.conditions[0].message = "2/4 pods ready".to_string();
+        assert!(!status_change_is_significant(Some(&current), &next));
+
+        next.conditions[0].status = "True".to_string();
+        assert!(status_change_is_significant(Some(&current), &next));
+        assert!(status_change_is_significant(None, &current));
+    }
+
+    #[test]
+    fn status_writes_defer_minor_changes_within_interval() {
+        let disabled = StatusWrites::default();
+        disabled.record("ns/t".to_string());
+        assert!(!disabled.defer("ns/t"));
+        assert_eq!(disabled.flush_delay("ns/t"), None);
+
+        let writes = StatusWrites::new(Some(Duration::from_secs(30)));
+        assert!(!writes.defer("ns/t"));
+        writes.record("ns/t".to_string());
+        assert_eq!(writes.flush_delay("ns/t"), None);
+
+        assert!(writes.defer("ns/t"));
+        assert!(
+            writes
+                .flush_delay("ns/t")
+                .is_some_and(|delay| delay <= Duration::from_secs(30))
+        );
+
+        writes.record("ns/t".to_string());
+        assert_eq!(writes.flush_delay("ns/t"), None);
+        assert!(!StatusWrites::new(Some(Duration::ZERO)).defer("ns/t"));
+    }
+
+    #[test]
+    fn lifecycle_transition_reports_phase_changes_only() {
+        let now = "2025-01-01T00:00:00Z";
+        let mut current = status_at(now);
+        current.lifecycle = Some(LifecycleStatus::advance(
+            None,
+            LifecycleSignal::Progressing,
+            now,
+        ));
+        assert_eq!(lifecycle_transition(None, &current), None);
+
+        let mut next = current.clone();
+        next.lifecycle = Some(LifecycleStatus::advance(
+            current.lifecycle.as_ref(),
+            LifecycleSignal::Ready,
+            now,
+        ));
+        assert_eq!(
+            lifecycle_transition(Some(&current), &next),
+            Some((LifecyclePhase::Provisioning, LifecyclePhase::Ready))
+        );
+        assert_eq!(lifecycle_transition(Some(&next), &next), None);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod credential_data_tests {
+    use super::{CredentialSecretReport, Error, validate_credential_data};
+    use crate::types::v1alpha1::credentials::{CredentialKeys, CredsSecretReference};
+    use k8s_openapi::ByteString;
+    use std::collections::BTreeMap;
+
+    fn credential_data(fields: &[(&str, &str)]) -> BTreeMap<String, ByteString> {
+        fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), ByteString(value.as_bytes().to_vec())))
+            .collect()
+    }
+
+    #[test]
+    fn credential_data_uses_configured_key_names_and_length_policy() {
+        let mut cfg = CredsSecretReference::new("aws-creds");
+        cfg.keys = Some(CredentialKeys {
+            access_key: Some("AWS_ACCESS_KEY_ID".to_string()),
+            secret_key: Some("AWS_SECRET_ACCESS_KEY".to_string()),
+        });
+        let data = credential_data(&[
+            ("AWS_ACCESS_KEY_ID", "AKIA"),
+            (
+                "AWS_SECRET_ACCESS_KEY",
+                "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+            ),
+        ]);
+
+        let err = validate_credential_data(&cfg, &data).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::CredentialSecretTooShort { key, length: 4, min_length: 8, .. }
+                if key == "AWS_ACCESS_KEY_ID"
+        ));
+
+        cfg.min_length = Some(0);
+        let report = validate_credential_data(&cfg, &data).unwrap();
+        assert_eq!(report, CredentialSecretReport::default());
+
+        let err =
+            validate_credential_data(&cfg, &credential_data(&[("accesskey", "x")])).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::CredentialSecretMissingKey { key, .. } if key == "AWS_ACCESS_KEY_ID"
+        ));
+    }
+
+    #[test]
+    fn credential_data_reports_low_entropy_secret_key() {
+        let cfg = CredsSecretReference::new("creds");
+        let data = credential_data(&[("accesskey", "rustfsadmin"), ("secretkey", "rustfsadmin")]);
+
+        let report = validate_credential_data(&cfg, &data).unwrap();
+        assert_eq!(report.low_entropy_key.as_deref(), Some("secretkey"));
+    }
+
+    #[test]
+    fn zero_min_length_still_rejects_empty_credentials() {
+        let mut cfg = CredsSecretReference::new("creds");
+        cfg.min_length = Some(0);
+        let data = credential_data(&[("accesskey", ""), ("secretkey", "")]);
+
+        let err = validate_credential_data(&cfg, &data).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::CredentialSecretTooShort {
+                length: 0,
+                min_length: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn low_min_length_keeps_the_secret_key_floor() {
+        let mut cfg = CredsSecretReference::new("creds");
+        cfg.min_length = Some(3);
+
+        let data = credential_data(&[("accesskey", "abc"), ("secretkey", "abcdefg")]);
+        let err = validate_credential_data(&cfg, &data).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::CredentialSecretTooShort {
+                ref key,
+                length: 7,
+                min_length: 8,
+                ..
+            } if key == "secretkey"
+        ));
+
+        let data = credential_data(&[("accesskey", "abc"), ("secretkey", "abcdefgh")]);
+        assert!(validate_credential_data(&cfg, &data).is_ok());
+    }
+
+    #[test]
+    fn warning_findings_are_reported_when_they_change() {
+        let findings = super::WarningFindings::default();
+        let tenant = crate::tests::create_test_tenant(None, None);
+
+        assert!(findings.report(&tenant, "CredentialLowEntropy", Some("weak")));
+        assert!(!findings.report(&tenant, "CredentialLowEntropy", Some("weak")));
+        assert!(findings.report(&tenant, "CredentialLowEntropy", Some("weaker")));
+        assert!(!findings.report(&tenant, "CredentialLowEntropy", None));
+        assert!(findings.report(&tenant, "CredentialLowEntropy", Some("weaker")));
+    }
+}
@@ -0,0 +1,724 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! COSI (Container Object Storage Interface) driver for RustFS Tenants.
+//!
+//! Off by default. With `OPERATOR_COSI_ENABLED=true` the leader takes the part of the COSI
+//! provisioner sidecar and driver for `driverName: cosi.rustfs.com`. The COSI controller still
+//! turns BucketClaims into Buckets; every `OPERATOR_COSI_INTERVAL_SECONDS` this module:
+//!
+//! - creates the RustFS bucket of each new Bucket and sets `status.bucketReady` and
+//!   `status.bucketID` (`<tenant-namespace>/<tenant>/<bucket>`);
+//! - for each new BucketAccess of a ready BucketClaim, creates a RustFS user whose policy only
+//!   allows that bucket, writes the `BucketInfo` Secret named by `credentialsSecretName`, and
+//!   sets `status.accessGranted` and `status.accountID`;
+//! - removes the user and Secret of deleted BucketAccesses, and the bucket of deleted Buckets
+//!   with `deletionPolicy: Delete`.
+//!
+//! BucketClass parameters `tenant` and `tenantNamespace` (default: the BucketClaim namespace)
+//! choose the Tenant. Only `authenticationType: Key` is supported. Without the COSI CRDs the
+//! module does nothing.
+
+use crate::bucket_binding::{self, BindingTarget, BucketCredentials, Error};
+use crate::reconcile::maintenance::MaintenanceConfig;
+use crate::tenant_monitor;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::ByteString;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind, ListParams, Patch, PatchParams};
+use kube::{Api, Client, ResourceExt};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use snafu::ResultExt;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// `driverName` of BucketClasses and BucketAccessClasses served by the operator.
+pub const DRIVER_NAME: &str = "cosi.rustfs.com";
+/// BucketClass parameter naming the Tenant.
+pub const TENANT_PARAMETER: &str = "tenant";
+/// BucketClass parameter naming the Tenant namespace.
+pub const TENANT_NAMESPACE_PARAMETER: &str = "tenantNamespace";
+
+const GROUP: &str = "objectstorage.k8s.io";
+const VERSION: &str = "v1alpha1";
+const BUCKET_FINALIZER: &str = "cosi.rustfs.com/bucket";
+const BUCKET_ACCESS_FINALIZER: &str = "cosi.rustfs.com/bucket-access";
+const BUCKET_INFO_KEY: &str = "BucketInfo";
+const DEFAULT_COSI_INTERVAL: Duration = Duration::from_secs(30);
+const FIELD_MANAGER: &str = "rustfs-operator-cosi";
+
+pub fn is_enabled() -> bool {
+    tenant_monitor::env_bool("OPERATOR_COSI_ENABLED", false)
+}
+
+pub fn interval() -> Duration {
+    match std::env::var("OPERATOR_COSI_INTERVAL_SECONDS") {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(seconds) if seconds > 0 => Duration::from_secs(seconds),
+            Ok(_) | Err(_) => {
+                warn!(
+                    value,
+                    "invalid OPERATOR_COSI_INTERVAL_SECONDS value, using default"
+                );
+                DEFAULT_COSI_INTERVAL
+            }
+        },
+        Err(_) => DEFAULT_COSI_INTERVAL,
+    }
+}
+
+pub async fn run(client: Client, maintenance: MaintenanceConfig, cancel: CancellationToken) {
+    let interval = interval();
+    info!(
+        interval_seconds = interval.as_secs(),
+        driver = DRIVER_NAME,
+        "COSI driver started"
+    );
+    loop {
+        if maintenance.allows_changes(&client, "cosi").await {
+            reconcile_buckets(&client).await;
+        }
+        if maintenance.allows_changes(&client, "cosi").await {
+            reconcile_bucket_accesses(&client).await;
+        }
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                info!("COSI driver cancellation requested");
+                break;
+            }
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
+fn api_resource(kind: &str, plural: &str) -> ApiResource {
+    ApiResource::from_gvk_with_plural(&GroupVersionKind::gvk(GROUP, VERSION, kind), plural)
+}
+
+/// Lists every object of a COSI kind, or `None` when the COSI CRDs are not installed.
+async fn list_all(client: &Client, resource: &ApiResource) -> Option<Vec<DynamicObject>> {
+    match Api::<DynamicObject>::all_with(client.clone(), resource)
+        .list(&ListParams::default())
+        .await
+    {
+        Ok(list) => Some(list.items),
+        Err(kube::Error::Api(error)) if error.code == 404 => {
+            debug!(kind = %resource.kind, "COSI API not installed");
+            None
+        }
+        Err(error) => {
+            warn!(kind = %resource.kind, %error, "COSI driver failed listing objects");
+            None
+        }
+    }
+}
+
+fn field<'a>(object: &'a DynamicObject, path: &[&str]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(&object.data, |value, key| value.get(*key))
+}
+
+fn string_field<'a>(object: &'a DynamicObject, path: &[&str]) -> Option<&'a str> {
+    field(object, path)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+}
+
+fn bool_field(object: &DynamicObject, path: &[&str]) -> bool {
+    field(object, path)
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+async fn reconcile_buckets(client: &Client) {
+    let resource = api_resource("Bucket", "buckets");
+    let Some(buckets) = list_all(client, &resource).await else {
+        return;
+    };
+    let api = Api::<DynamicObject>::all_with(client.clone(), &resource);
+    for bucket in buckets
+        .iter()
+        .filter(|bucket| string_field(bucket, &["spec", "driverName"]) == Some(DRIVER_NAME))
+    {
+        let name = bucket.name_any();
+        let result = if bucket.metadata.deletion_timestamp.is_some() {
+            delete_bucket(client, &api, bucket).await
+        } else if bool_field(bucket, &["status", "bucketReady"]) {
+            Ok(())
+        } else {
+            create_bucket(client, &api, bucket).await
+        };
+        if let Err(error) = result {
+            warn!(bucket = %name, %error, "COSI Bucket reconcile failed");
+        }
+    }
+}
+
+/// Tenant and RustFS bucket name of a COSI Bucket.
+pub(crate) fn bucket_target(bucket: &DynamicObject) -> Result<BindingTarget, Error> {
+    if let Some(id) = string_field(bucket, &["spec", "existingBucketID"])
+        .or_else(|| string_field(bucket, &["status", "bucketID"]))
+    {
+        return BindingTarget::parse(id, "");
+    }
+    let parameter = |key: &str| string_field(bucket, &["spec", "parameters", key]);
+    let tenant = parameter(TENANT_PARAMETER).ok_or_else(|| Error::Invalid {
+        message: format!("BucketClass parameter {TENANT_PARAMETER} is required"),
+    })?;
+    let namespace = parameter(TENANT_NAMESPACE_PARAMETER)
+        .or_else(|| string_field(bucket, &["spec", "bucketClaim", "namespace"]))
+        .unwrap_or_default();
+    BindingTarget::parse(&format!("{tenant}/{}", bucket.name_any()), namespace)
+}
+
+fn bucket_id(target: &BindingTarget) -> String {
+    format!(
+        "{}/{}/{}",
+        target.tenant_namespace, target.tenant, target.bucket
+    )
+}
+
+async fn tenant_for(client: &Client, target: &BindingTarget) -> Result<Tenant, Error> {
+    Api::<Tenant>::namespaced(client.clone(), &target.tenant_namespace)
+        .get(&target.tenant)
+        .await
+        .map_err(|source| Error::Kube {
+            action: format!(
+                "reading Tenant {}/{}",
+                target.tenant_namespace, target.tenant
+            ),
+            source: Box::new(source),
+        })
+}
+
+async fn create_bucket(
+    client: &Client,
+    api: &Api<DynamicObject>,
+    bucket: &DynamicObject,
+) -> Result<(), Error> {
+    let target = bucket_target(bucket)?;
+    let tenant = tenant_for(client, &target).await?;
+    add_finalizer(api, bucket, BUCKET_FINALIZER).await?;
+    let admin = bucket_binding::admin_client(client, &tenant).await?;
+    admin
+        .create_bucket(&target.bucket, None, false)
+        .await
+        .context(bucket_binding::AdminSnafu {
+            action: format!("creating bucket {}", target.bucket),
+        })?;
+
+    let status = serde_json::json!({
+        "status": { "bucketReady": true, "bucketID": bucket_id(&target) }
+    });
+    api.patch_status(
+        &bucket.name_any(),
+        &PatchParams::default(),
+        &Patch::Merge(&status),
+    )
+    .await
+    .map_err(|source| Error::Kube {
+        action: format!("updating Bucket {} status", bucket.name_any()),
+        source: Box::new(source),
+    })?;
+    info!(bucket = %bucket.name_any(), id = %bucket_id(&target), "COSI bucket provisioned");
+    Ok(())
+}
+
+async fn delete_bucket(
+    client: &Client,
+    api: &Api<DynamicObject>,
+    bucket: &DynamicObject,
+) -> Result<(), Error> {
+    if !bucket.finalizers().iter().any(|f| f == BUCKET_FINALIZER) {
+        return Ok(());
+    }
+    // Buckets that pointed at an existing RustFS bucket never delete it.
+    let delete = string_field(bucket, &["spec", "deletionPolicy"]) == Some("Delete")
+        && string_field(bucket, &["spec", "existingBucketID"]).is_none();
+    if delete && bool_field(bucket, &["status", "bucketReady"]) {
+        let target = bucket_target(bucket)?;
+        let tenant = tenant_for(client, &target).await?;
+        bucket_binding::admin_client(client, &tenant)
+            .await?
+            .delete_bucket(&target.bucket)
+            .await
+            .context(bucket_binding::AdminSnafu {
+                action: format!("deleting bucket {}", target.bucket),
+            })?;
+        info!(bucket = %bucket.name_any(), id = %bucket_id(&target), "COSI bucket deleted");
+    }
+    remove_finalizer(api, bucket, BUCKET_FINALIZER).await
+}
+
+async fn reconcile_bucket_accesses(client: &Client) {
+    let Some(classes) = list_all(
+        client,
+        &api_resource("BucketAccessClass", "bucketaccessclasses"),
+    )
+    .await
+    else {
+        return;
+    };
+    let classes: BTreeMap<String, DynamicObject> = classes
+        .into_iter()
+        .filter(|class| string_field(class, &["driverName"]) == Some(DRIVER_NAME))
+        .map(|class| (class.name_any(), class))
+        .collect();
+    if classes.is_empty() {
+        return;
+    }
+
+    let resource = api_resource("BucketAccess", "bucketaccesses");
+    let Some(accesses) = list_all(client, &resource).await else {
+        return;
+    };
+    for access in &accesses {
+        let Some(class) = string_field(access, &["spec", "bucketAccessClassName"])
+            .and_then(|name| classes.get(name))
+        else {
+            continue;
+        };
+        let Some(namespace) = access.namespace() else {
+            continue;
+        };
+        let api = Api::<DynamicObject>::namespaced_with(client.clone(), &namespace, &resource);
+        let result = if access.metadata.deletion_timestamp.is_some() {
+            revoke_access(client, &api, access, &namespace).await
+        } else if bool_field(access, &["status", "accessGranted"]) {
+            Ok(())
+        } else {
+            grant_access(client, &api, access, class, &namespace).await
+        };
+        if let Err(error) = result {
+            warn!(bucket_access = %access.name_any(), namespace, %error, "COSI BucketAccess reconcile failed");
+        }
+    }
+}
+
+/// Stable RustFS access key for a BucketAccess.
+pub(crate) fn access_key(namespace: &str, name: &str) -> String {
+    let digest = Sha256::digest(format!("{namespace}/{name}"));
+    format!("cosi-{}", &hex::encode(digest)[..16])
+}
+
+/// Target of the Bucket bound to the BucketAccess' BucketClaim.
+async fn access_target(
+    client: &Client,
+    access: &DynamicObject,
+    namespace: &str,
+) -> Result<BindingTarget, Error> {
+    let claim_name =
+        string_field(access, &["spec", "bucketClaimName"]).ok_or_else(|| Error::Invalid {
+            message: "spec.bucketClaimName is required".to_string(),
+        })?;
+    let claim = Api::<DynamicObject>::namespaced_with(
+        client.clone(),
+        namespace,
+        &api_resource("BucketClaim", "bucketclaims"),
+    )
+    .get(claim_name)
+    .await
+    .map_err(|source| Error::Kube {
+        action: format!("reading BucketClaim {namespace}/{claim_name}"),
+        source: Box::new(source),
+    })?;
+    let bucket_name = string_field(&claim, &["status", "bucketName"])
+        .filter(|_| bool_field(&claim, &["status", "bucketReady"]))
+        .ok_or_else(|| Error::Invalid {
+            message: format!("BucketClaim {namespace}/{claim_name} is not ready"),
+        })?;
+    let bucket = Api::<DynamicObject>::all_with(client.clone(), &api_resource("Bucket", "buckets"))
+        .get(bucket_name)
+        .await
+        .map_err(|source| Error::Kube {
+            action: format!("reading Bucket {bucket_name}"),
+            source: Box::new(source),
+        })?;
+    let id = string_field(&bucket, &["status", "bucketID"]).ok_or_else(|| Error::Invalid {
+        message: format!("Bucket {bucket_name} has no bucketID"),
+    })?;
+    BindingTarget::parse(id, "")
+}
+
+async fn grant_access(
+    client: &Client,
+    api: &Api<DynamicObject>,
+    access: &DynamicObject,
+    class: &DynamicObject,
+    namespace: &str,
+) -> Result<(), Error> {
+    let authentication = string_field(class, &["authenticationType"]).unwrap_or("Key");
+    if authentication != "Key" {
+        return bucket_binding::InvalidSnafu {
+            message: format!(
+                "BucketAccessClass {} uses authenticationType {authentication}; only Key is supported",
+                class.name_any()
+            ),
+        }
+        .fail();
+    }
+    let secret_name =
+        string_field(access, &["spec", "credentialsSecretName"]).ok_or_else(|| Error::Invalid {
+            message: "spec.credentialsSecretName is required".to_string(),
+        })?;
+    let target = access_target(client, access, namespace).await?;
+    let tenant = tenant_for(client, &target).await?;
+    add_finalizer(api, access, BUCKET_ACCESS_FINALIZER).await?;
+
+    let credentials = BucketCredentials {
+        access_key: access_key(namespace, &access.name_any()),
+        secret_key: bucket_binding::generate_secret_key()?,
+        endpoint: bucket_binding::tenant_endpoint(&tenant, &target.tenant_namespace),
+        bucket: target.bucket.clone(),
+    };
+    let admin = bucket_binding::admin_client(client, &tenant).await?;
+    bucket_binding::grant_bucket_access(
+        &admin,
+        &target.bucket,
+        &credentials.access_key,
+        &credentials.secret_key,
+    )
+    .await?;
+
+    let secret = access_secret(access, secret_name, &credentials);
+    Api::<corev1::Secret>::namespaced(client.clone(), namespace)
+        .patch(
+            secret_name,
+            &PatchParams::apply(FIELD_MANAGER).force(),
+            &Patch::Apply(&secret),
+        )
+        .await
+        .map_err(|source| Error::Kube {
+            action: format!("applying Secret {namespace}/{secret_name}"),
+            source: Box::new(source),
+        })?;
+
+    let status = serde_json::json!({
+        "status": { "accessGranted": true, "accountID": credentials.access_key }
+    });
+    api.patch_status(
+        &access.name_any(),
+        &PatchParams::default(),
+        &Patch::Merge(&status),
+    )
+    .await
+    .map_err(|source| Error::Kube {
+        action: format!(
+            "updating BucketAccess {namespace}/{} status",
+            access.name_any()
+        ),
+        source: Box::new(source),
+    })?;
+    info!(bucket_access = %access.name_any(), namespace, bucket = %target.bucket, "COSI bucket access granted");
+    Ok(())
+}
+
+async fn revoke_access(
+    client: &Client,
+    api: &Api<DynamicObject>,
+    access: &DynamicObject,
+    namespace: &str,
+) -> Result<(), Error> {
+    if !access
+        .finalizers()
+        .iter()
+        .any(|f| f == BUCKET_ACCESS_FINALIZER)
+    {
+        return Ok(());
+    }
+    // The Bucket may already be gone; then there is no Tenant left to revoke from.
+    if let Ok(target) = access_target(client, access, namespace).await {
+        let tenant = tenant_for(client, &target).await?;
+        bucket_binding::admin_client(client, &tenant)
+            .await?
+            .remove_user(&access_key(namespace, &access.name_any()))
+            .await
+            .context(bucket_binding::AdminSnafu {
+                action: "removing BucketAccess user".to_string(),
+            })?;
+    }
+    if let Some(secret_name) = string_field(access, &["spec", "credentialsSecretName"]) {
+        match Api::<corev1::Secret>::namespaced(client.clone(), namespace)
+            .delete(secret_name, &Default::default())
+            .await
+        {
+            Ok(_) => {}
+            Err(kube::Error::Api(error)) if error.code == 404 => {}
+            Err(source) => {
+                return Err(Error::Kube {
+                    action: format!("deleting Secret {namespace}/{secret_name}"),
+                    source: Box::new(source),
+                });
+            }
+        }
+    }
+    info!(bucket_access = %access.name_any(), namespace, "COSI bucket access revoked");
+    remove_finalizer(api, access, BUCKET_ACCESS_FINALIZER).await
+}
+
+/// `BucketInfo` document the COSI sidecar writes for workloads.
+pub(crate) fn bucket_info(credentials: &BucketCredentials, bucket_name: &str) -> Value {
+    serde_json::json!({
+        "metadata": { "name": bucket_name },
+        "spec": {
+            "bucketName": credentials.bucket,
+            "authenticationType": "KEY",
+            "secretS3": {
+                "endpoint": credentials.endpoint,
+                "region": "us-east-1",
+                "accessKeyID": credentials.access_key,
+                "accessSecretKey": credentials.secret_key,
+            },
+            "secretAzure": null,
+            "protocols": ["s3"],
+        },
+    })
+}
+
+fn access_secret(
+    access: &DynamicObject,
+    name: &str,
+    credentials: &BucketCredentials,
+) -> corev1::Secret {
+    let owner = access
+        .types
+        .as_ref()
+        .zip(access.metadata.uid.as_ref())
+        .map(|(types, uid)| metav1::OwnerReference {
+            api_version: types.api_version.clone(),
+            kind: types.kind.clone(),
+            name: access.name_any(),
+            uid: uid.clone(),
+            ..Default::default()
+        });
+    corev1::Secret {
+        metadata: metav1::ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: access.namespace(),
+            labels: Some(BTreeMap::from([(
+                "app.kubernetes.io/managed-by".to_string(),
+                "rustfs-operator".to_string(),
+            )])),
+            owner_references: owner.map(|owner| vec![owner]),
+            ..Default::default()
+        },
+        type_: Some("Opaque".to_string()),
+        data: Some(BTreeMap::from([(
+            BUCKET_INFO_KEY.to_string(),
+            ByteString(
+                bucket_info(credentials, &credentials.bucket)
+                    .to_string()
+                    .into_bytes(),
+            ),
+        )])),
+        ..Default::default()
+    }
+}
+
+/// Finalizers of `object` with `finalizer` added or removed, or `None` when nothing changes.
+fn finalizers_with(object: &DynamicObject, finalizer: &str, present: bool) -> Option<Vec<String>> {
+    let current = object.finalizers();
+    if current.iter().any(|f| f == finalizer) == present {
+        return None;
+    }
+    let mut finalizers: Vec<String> = current
+        .iter()
+        .filter(|f| f.as_str() != finalizer)
+        .cloned()
+        .collect();
+    if present {
+        finalizers.push(finalizer.to_string());
+    }
+    Some(finalizers)
+}
+
+async fn patch_finalizers(
+    api: &Api<DynamicObject>,
+    object: &DynamicObject,
+    finalizers: Vec<String>,
+) -> Result<(), Error> {
+    let patch = serde_json::json!({
+        "metadata": {
+            "finalizers": finalizers,
+            "resourceVersion": object.resource_version(),
+        }
+    });
+    api.patch(
+        &object.name_any(),
+        &PatchParams::default(),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map(|_| ())
+    .map_err(|source| Error::Kube {
+        action: format!("updating finalizers of {}", object.name_any()),
+        source: Box::new(source),
+    })
+}
+
+async fn add_finalizer(
+    api: &Api<DynamicObject>,
+    object: &DynamicObject,
+    finalizer: &str,
+) -> Result<(), Error> {
+    match finalizers_with(object, finalizer, true) {
+        Some(finalizers) => patch_finalizers(api, object, finalizers).await,
+        None => Ok(()),
+    }
+}
+
+async fn remove_finalizer(
+    api: &Api<DynamicObject>,
+    object: &DynamicObject,
+    finalizer: &str,
+) -> Result<(), Error> {
+    match finalizers_with(object, finalizer, false) {
+        Some(finalizers) => patch_finalizers(api, object, finalizers).await,
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(kind: &str, plural: &str, name: &str, data: Value) -> DynamicObject {
+        let mut object = DynamicObject::new(name, &api_resource(kind, plural)).data(data);
+        object.metadata.uid = Some("uid-1".to_string());
+        object
+    }
+
+    fn credentials() -> BucketCredentials {
+        BucketCredentials {
+            access_key: access_key("apps", "uploads-access"),
+            secret_key: "secret".to_string(),
+            endpoint: "http://storage-io.rustfs.svc:9000".to_string(),
+            bucket: "bc-1234".to_string(),
+        }
+    }
+
+    #[test]
+    fn bucket_class_parameters_choose_the_tenant() {
+        let bucket = object(
+            "Bucket",
+            "buckets",
+            "bc-1234",
+            serde_json::json!({
+                "spec": {
+                    "driverName": DRIVER_NAME,
+                    "parameters": { "tenant": "storage" },
+                    "bucketClaim": { "namespace": "apps", "name": "uploads" },
+                }
+            }),
+        );
+        let target = bucket_target(&bucket).unwrap();
+        assert_eq!(bucket_id(&target), "apps/storage/bc-1234");
+
+        let bucket = object(
+            "Bucket",
+            "buckets",
+            "bc-1234",
+            serde_json::json!({
+                "spec": {
+                    "parameters": { "tenant": "storage", "tenantNamespace": "rustfs" },
+                    "bucketClaim": { "namespace": "apps" },
+                }
+            }),
+        );
+        assert_eq!(
+            bucket_id(&bucket_target(&bucket).unwrap()),
+            "rustfs/storage/bc-1234"
+        );
+
+        let missing = object(
+            "Bucket",
+            "buckets",
+            "bc-1234",
+            serde_json::json!({ "spec": {} }),
+        );
+        assert!(bucket_target(&missing).is_err());
+    }
+
+    #[test]
+    fn existing_bucket_id_points_at_a_tenant_bucket() {
+        let bucket = object(
+            "Bucket",
+            "buckets",
+            "imported",
+            serde_json::json!({
+                "spec": {
+                    "existingBucketID": "rustfs/storage/legacy-data",
+                    "parameters": { "tenant": "other" },
+                }
+            }),
+        );
+        let target = bucket_target(&bucket).unwrap();
+        assert_eq!(target.tenant, "storage");
+        assert_eq!(target.bucket, "legacy-data");
+    }
+
+    #[test]
+    fn bucket_info_secret_follows_the_cosi_format() {
+        let access = object(
+            "BucketAccess",
+            "bucketaccesses",
+            "uploads-access",
+            serde_json::json!({ "spec": { "credentialsSecretName": "uploads-creds" } }),
+        )
+        .within("apps");
+        let secret = access_secret(&access, "uploads-creds", &credentials());
+
+        let owner = &secret.metadata.owner_references.as_ref().unwrap()[0];
+        assert_eq!(owner.kind, "BucketAccess");
+        assert_eq!(owner.api_version, "objectstorage.k8s.io/v1alpha1");
+        let info: Value = serde_json::from_slice(&secret.data.unwrap()[BUCKET_INFO_KEY].0).unwrap();
+        assert_eq!(info["spec"]["bucketName"], "bc-1234");
+        assert_eq!(info["spec"]["authenticationType"], "KEY");
+        assert_eq!(
+            info["spec"]["secretS3"]["accessKeyID"],
+            access_key("apps", "uploads-access")
+        );
+        assert_eq!(info["spec"]["protocols"], serde_json::json!(["s3"]));
+    }
+
+    #[test]
+    fn finalizers_are_only_patched_on_change() {
+        let mut bucket = object("Bucket", "buckets", "bc-1234", serde_json::json!({}));
+        bucket.metadata.finalizers = Some(vec![
+            "cosi.objectstorage.k8s.io/bucket-protection".to_string(),
+        ]);
+
+        assert_eq!(
+            finalizers_with(&bucket, BUCKET_FINALIZER, true),
+            Some(vec![
+                "cosi.objectstorage.k8s.io/bucket-protection".to_string(),
+                BUCKET_FINALIZER.to_string()
+            ])
+        );
+        assert_eq!(finalizers_with(&bucket, BUCKET_FINALIZER, false), None);
+
+        bucket.metadata.finalizers = finalizers_with(&bucket, BUCKET_FINALIZER, true);
+        assert_eq!(finalizers_with(&bucket, BUCKET_FINALIZER, true), None);
+        assert_eq!(
+            finalizers_with(&bucket, BUCKET_FINALIZER, false),
+            Some(vec![
+                "cosi.objectstorage.k8s.io/bucket-protection".to_string()
+            ])
+        );
+    }
+}
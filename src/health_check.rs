@@ -0,0 +1,570 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodic health check, separate from spec reconciliation.
+//!
+//! The reconcile only runs when the Tenant or its children change, so pod readiness and the
+//! RustFS admin API view in `status` can go stale between reconciles. This task refreshes
+//! `status.pools[].pods` and `status.health` on a short interval without touching workloads.
+//! Status is written only when something other than the check time changes, and writes carry
+//! the Tenant's `resourceVersion`, so a reconcile that wrote status in the meantime wins and the
+//! check retries on the next tick. The admin API poll is shared with [`tenant_monitor`].
+//!
+//! Tenants with `spec.synthetics` enabled also get a PUT/GET probe of their S3 endpoint every
+//! `intervalSeconds`. Its latency and failures go to the operator metrics on every probe, and
+//! to `status.health.synthetics` under the same write rules as the rest of the health status.
+
+use crate::metrics::{self, SyntheticProbeMetrics, TenantStorageMetrics};
+use crate::tenant_monitor;
+use crate::types::v1alpha1::status::Status;
+use crate::types::v1alpha1::status::health::{
+    AdminApiHealth, CapacityStatus, HealthStatus, PoolCapacityStatus, SyntheticsStatus,
+};
+use crate::types::v1alpha1::tenant::Tenant;
+use futures::{StreamExt, stream};
+use k8s_openapi::api::core::v1 as corev1;
+use kube::api::{ListParams, Patch, PatchParams};
+use kube::{Api, Client, ResourceExt};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const MAX_CONCURRENT_TENANT_CHECKS: usize = 4;
+
+/// Probes `status.health.synthetics.errorRatePercent` is computed over.
+const SYNTHETICS_WINDOW: usize = 20;
+const SYNTHETICS_OBJECT_KEY: &str = "probe";
+
+/// Synthetic probe results of one Tenant, kept in memory since the operator process started.
+#[derive(Debug, Default)]
+struct ProbeHistory {
+    last_probe: Option<Instant>,
+    bucket_ready: bool,
+    results: VecDeque<bool>,
+    consecutive_failures: u32,
+}
+
+impl ProbeHistory {
+    fn record(&mut self, success: bool) {
+        if self.results.len() == SYNTHETICS_WINDOW {
+            self.results.pop_front();
+        }
+        self.results.push_back(success);
+        self.consecutive_failures = if success {
+            0
+        } else {
+            self.consecutive_failures.saturating_add(1)
+        };
+    }
+
+    fn error_rate_percent(&self) -> u32 {
+        if self.results.is_empty() {
+            return 0;
+        }
+        let failures = self.results.iter().filter(|success| !**success).count();
+        (failures * 100 / self.results.len()) as u32
+    }
+}
+
+fn probe_histories() -> &'static Mutex<BTreeMap<(String, String), ProbeHistory>> {
+    static HISTORIES: OnceLock<Mutex<BTreeMap<(String, String), ProbeHistory>>> = OnceLock::new();
+    HISTORIES.get_or_init(Default::default)
+}
+
+pub fn is_enabled() -> bool {
+    tenant_monitor::env_bool("OPERATOR_HEALTH_CHECK_ENABLED", true)
+}
+
+pub fn interval() -> Duration {
+    match std::env::var("OPERATOR_HEALTH_CHECK_INTERVAL_SECONDS") {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(seconds) if seconds > 0 => Duration::from_secs(seconds),
+            Ok(_) | Err(_) => {
+                warn!(
+                    value,
+                    "invalid OPERATOR_HEALTH_CHECK_INTERVAL_SECONDS value, using default"
+                );
+                DEFAULT_HEALTH_CHECK_INTERVAL
+            }
+        },
+        Err(_) => DEFAULT_HEALTH_CHECK_INTERVAL,
+    }
+}
+
+pub async fn run(client: Client, cancel: CancellationToken) {
+    let interval = interval();
+    info!(
+        interval_seconds = interval.as_secs(),
+        "tenant health check started"
+    );
+
+    loop {
+        check_all_tenants(client.clone()).await;
+
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                info!("tenant health check cancellation requested");
+                break;
+            }
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
+async fn check_all_tenants(client: Client) {
+    let tenants = match tenant_monitor::list_all_tenants(client.clone()).await {
+        Ok(tenants) => tenants,
+        Err(error) => {
+            warn!(%error, "tenant health check failed listing tenants");
+            return;
+        }
+    };
+
+    let probed = tenants
+        .iter()
+        .filter(|tenant| synthetics_enabled(tenant))
+        .filter_map(|tenant| {
+            tenant
+                .namespace()
+                .ok()
+                .map(|namespace| (namespace, tenant.name()))
+        })
+        .collect::<Vec<_>>();
+    metrics::prune_tenant_synthetics(&probed);
+    probe_histories()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .retain(|key, _| probed.contains(key));
+
+    stream::iter(tenants)
+        .for_each_concurrent(MAX_CONCURRENT_TENANT_CHECKS, |tenant| {
+            let client = client.clone();
+            async move {
+                let name = tenant.name();
+                if let Err(error) = check_tenant(client, tenant).await {
+                    debug!(tenant = %name, %error, "tenant health check did not update status");
+                }
+            }
+        })
+        .await;
+}
+
+async fn check_tenant(client: Client, tenant: Tenant) -> Result<(), kube::Error> {
+    // Tenants the reconcile has not written yet, or that are going away, are left alone.
+    let Some(current) = tenant.status.as_ref() else {
+        return Ok(());
+    };
+    if tenant.metadata.deletion_timestamp.is_some() {
+        return Ok(());
+    }
+    let Ok(namespace) = tenant.namespace() else {
+        return Ok(());
+    };
+
+    let pods = Api::<corev1::Pod>::namespaced(client.clone(), &namespace)
+        .list(&ListParams::default().labels(&tenant.pod_selector()))
+        .await?
+        .items;
+    let admin = if tenant.spec.creds_secret.is_some() {
+        Some(
+            tenant_monitor::shared_tenant_storage(&client, &tenant, interval() / 2)
+                .await
+                .map_err(|error| {
+                    debug!(tenant = %tenant.name(), namespace, %error, "RustFS admin API health probe failed");
+                }),
+        )
+    } else {
+        None
+    };
+
+    let now = chrono::Utc::now();
+    let synthetics = if synthetics_due(&tenant, current, &namespace) {
+        Some(run_synthetic_probe(&client, &tenant, &namespace, now).await)
+    } else {
+        None
+    };
+    let mut next = refresh(&tenant, current, &namespace, &pods, admin, now);
+    if let Some(health) = next.health.as_mut() {
+        health.synthetics = synthetics_enabled(&tenant)
+            .then(|| {
+                synthetics.or_else(|| {
+                    current
+                        .health
+                        .as_ref()
+                        .and_then(|health| health.synthetics.clone())
+                })
+            })
+            .flatten();
+    }
+    next.refresh_summary();
+    if !needs_write(current, &next) {
+        return Ok(());
+    }
+
+    let patch = serde_json::json!({
+        "metadata": { "resourceVersion": tenant.resource_version() },
+        "status": {
+            "pools": next.pools,
+            "health": next.health,
+            "ready": next.ready,
+            "totalReplicas": next.total_replicas,
+            "poolCount": next.pool_count,
+            "capacity": next.capacity,
+        },
+    });
+    Api::<Tenant>::namespaced(client, &namespace)
+        .patch_status(
+            &tenant.name(),
+            &PatchParams::default(),
+            &Patch::Merge(patch),
+        )
+        .await?;
+    Ok(())
+}
+
+fn synthetics_enabled(tenant: &Tenant) -> bool {
+    tenant.spec.creds_secret.is_some()
+        && tenant
+            .spec
+            .synthetics
+            .as_ref()
+            .is_some_and(|synthetics| synthetics.enabled())
+}
+
+/// Whether the Tenant has been Ready once and its last probe is at least `intervalSeconds` old.
+fn synthetics_due(tenant: &Tenant, current: &Status, namespace: &str) -> bool {
+    let Some(interval) = tenant
+        .spec
+        .synthetics
+        .as_ref()
+        .map(|synthetics| synthetics.interval())
+    else {
+        return false;
+    };
+    if !synthetics_enabled(tenant)
+        || current
+            .lifecycle
+            .as_ref()
+            .and_then(|lifecycle| lifecycle.first_ready_time.as_ref())
+            .is_none()
+    {
+        return false;
+    }
+    probe_histories()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(&(namespace.to_string(), tenant.name()))
+        .and_then(|history| history.last_probe)
+        .is_none_or(|last_probe| last_probe.elapsed() >= interval)
+}
+
+/// Writes the probe object, reads it back, and records the result in the metrics and the
+/// in-memory history.
+async fn run_synthetic_probe(
+    client: &Client,
+    tenant: &Tenant,
+    namespace: &str,
+    now: chrono::DateTime<chrono::Utc>,
+) -> SyntheticsStatus {
+    let key = (namespace.to_string(), tenant.name());
+    let bucket_ready = probe_histories()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(&key)
+        .is_some_and(|history| history.bucket_ready);
+    let bucket = tenant
+        .spec
+        .synthetics
+        .as_ref()
+        .map(|synthetics| synthetics.bucket().to_string())
+        .unwrap_or_default();
+
+    let mut probe = SyntheticProbeMetrics::default();
+    let result = synthetic_probe(client, tenant, &bucket, bucket_ready, now, &mut probe).await;
+    probe.success = result.is_ok();
+    if let Err(message) = &result {
+        debug!(tenant = %tenant.name(), namespace, %message, "synthetic S3 probe failed");
+    }
+    metrics::record_tenant_synthetic_probe(namespace, &tenant.name(), probe);
+
+    let mut histories = probe_histories()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let history = histories.entry(key).or_default();
+    history.last_probe = Some(Instant::now());
+    // Re-create the bucket after a failure, in case it was deleted.
+    history.bucket_ready = probe.success;
+    history.record(probe.success);
+
+    SyntheticsStatus {
+        last_probe_time: Some(now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)),
+        success: probe.success,
+        put_latency_ms: probe.put_latency.map(|latency| latency.as_millis() as u64),
+        get_latency_ms: probe.get_latency.map(|latency| latency.as_millis() as u64),
+        consecutive_failures: history.consecutive_failures,
+        error_rate_percent: history.error_rate_percent(),
+        message: result.err(),
+    }
+}
+
+async fn synthetic_probe(
+    client: &Client,
+    tenant: &Tenant,
+    bucket: &str,
+    bucket_ready: bool,
+    now: chrono::DateTime<chrono::Utc>,
+    probe: &mut SyntheticProbeMetrics,
+) -> Result<(), String> {
+    let rustfs = tenant_monitor::tenant_admin_client(client, tenant)
+        .await
+        .map_err(|error| format!("cannot connect to the Tenant S3 endpoint: {error}"))?;
+    if !bucket_ready {
+        rustfs
+            .create_bucket(bucket, None, false)
+            .await
+            .map_err(|error| format!("create bucket '{bucket}' failed: {error}"))?;
+    }
+
+    let body = now.to_rfc3339();
+    let started = Instant::now();
+    rustfs
+        .put_object(bucket, SYNTHETICS_OBJECT_KEY, &body)
+        .await
+        .map_err(|error| {
+            format!("put object '{bucket}/{SYNTHETICS_OBJECT_KEY}' failed: {error}")
+        })?;
+    probe.put_latency = Some(started.elapsed());
+
+    let started = Instant::now();
+    let read = rustfs
+        .get_object(bucket, SYNTHETICS_OBJECT_KEY)
+        .await
+        .map_err(|error| {
+            format!("get object '{bucket}/{SYNTHETICS_OBJECT_KEY}' failed: {error}")
+        })?;
+    probe.get_latency = Some(started.elapsed());
+    if read != body {
+        return Err(format!(
+            "object '{bucket}/{SYNTHETICS_OBJECT_KEY}' read back different content than was written"
+        ));
+    }
+    Ok(())
+}
+
+/// `current` with pod readiness and the admin API result of this check.
+fn refresh(
+    tenant: &Tenant,
+    current: &Status,
+    namespace: &str,
+    pods: &[corev1::Pod],
+    admin: Option<Result<TenantStorageMetrics, ()>>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Status {
+    let mut next = current.clone();
+    let mut health = HealthStatus {
+        last_check_time: Some(now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)),
+        ..Default::default()
+    };
+
+    for pool in &tenant.spec.pools {
+        let pod_statuses = tenant.build_pool_pod_statuses(pool, namespace, pods);
+        health.total_pods += pod_statuses.len() as i32;
+        health.ready_pods += pod_statuses.iter().filter(|pod| pod.ready).count() as i32;
+        if let Some(status) = next
+            .pools
+            .iter_mut()
+            .find(|status| status.name.as_deref() == Some(pool.name.as_str()))
+        {
+            status.pods = pod_statuses;
+        }
+    }
+
+    match admin {
+        Some(Ok(storage)) => {
+            health.admin_api = Some(if storage.healthy {
+                AdminApiHealth::Healthy
+            } else {
+                AdminApiHealth::Degraded
+            });
+            health.capacity = Some(CapacityStatus {
+                raw_capacity_bytes: storage.raw_capacity_bytes,
+                raw_used_bytes: storage.raw_used_bytes,
+                online_drives: storage.online_drives,
+                offline_drives: storage.offline_drives,
+                // RustFS numbers pools in RUSTFS_VOLUMES order, which follows spec.pools.
+                pools: tenant
+                    .spec
+                    .pools
+                    .iter()
+                    .zip(&storage.pools)
+                    .map(|(pool, usage)| PoolCapacityStatus {
+                        name: pool.name.clone(),
+                        raw_capacity_bytes: usage.raw_capacity_bytes,
+                        raw_used_bytes: usage.raw_used_bytes,
+                    })
+                    .collect(),
+            });
+        }
+        Some(Err(())) => {
+            health.admin_api = Some(AdminApiHealth::Unreachable);
+            // Keep the last capacity the API reported.
+            health.capacity = current
+                .health
+                .as_ref()
+                .and_then(|health| health.capacity.clone());
+        }
+        None => {}
+    }
+
+    next.health = Some(health);
+    next
+}
+
+/// Whether the refreshed status differs from `current` beyond the check time. Unchanged checks
+/// are not written, so the Tenant watch is not triggered by the health check alone.
+fn needs_write(current: &Status, next: &Status) -> bool {
+    // Probe times and latencies change on every probe; they are written with the next change.
+    let without_check_time = |status: &Status| {
+        let mut status = status.clone();
+        if let Some(health) = status.health.as_mut() {
+            health.last_check_time = None;
+            if let Some(synthetics) = health.synthetics.as_mut() {
+                synthetics.last_probe_time = None;
+                synthetics.put_latency_ms = None;
+                synthetics.get_latency_ms = None;
+            }
+        }
+        status
+    };
+    without_check_time(current) != without_check_time(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn ready_pod(name: &str) -> corev1::Pod {
+        corev1::Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(corev1::PodStatus {
+                conditions: Some(vec![corev1::PodCondition {
+                    type_: "Ready".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn refresh_counts_pods_and_only_rewrites_on_change() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+        let now = chrono::Utc::now();
+        let current = Status::default();
+
+        let pods = [ready_pod(&tenant.pool_pod_name(&pool.name, 0))];
+        let next = refresh(&tenant, &current, "default", &pods, Some(Err(())), now);
+        let health = next.health.clone().unwrap_or_default();
+        assert_eq!(health.ready_pods, 1);
+        assert_eq!(health.total_pods, pool.servers);
+        assert_eq!(health.admin_api, Some(AdminApiHealth::Unreachable));
+        assert!(needs_write(&current, &next));
+
+        let later = now + chrono::TimeDelta::seconds(30);
+        let again = refresh(&tenant, &next, "default", &pods, Some(Err(())), later);
+        assert!(!needs_write(&next, &again));
+
+        let much_later = now + chrono::TimeDelta::hours(1);
+        let unchanged = refresh(&tenant, &next, "default", &pods, Some(Err(())), much_later);
+        assert!(!needs_write(&next, &unchanged));
+
+        let not_ready = refresh(&tenant, &next, "default", &[], Some(Err(())), much_later);
+        assert!(needs_write(&next, &not_ready));
+    }
+
+    #[test]
+    fn probe_history_tracks_error_rate_over_the_window() {
+        let mut history = ProbeHistory::default();
+        assert_eq!(history.error_rate_percent(), 0);
+
+        history.record(false);
+        history.record(false);
+        assert_eq!(history.consecutive_failures, 2);
+        assert_eq!(history.error_rate_percent(), 100);
+
+        for _ in 0..SYNTHETICS_WINDOW - 1 {
+            history.record(true);
+        }
+        assert_eq!(history.consecutive_failures, 0);
+        assert_eq!(history.results.len(), SYNTHETICS_WINDOW);
+        assert_eq!(history.error_rate_percent(), 5);
+    }
+
+    #[test]
+    fn synthetics_wait_for_ready_and_ignore_latency_changes() {
+        use crate::types::v1alpha1::credentials::CredsSecretReference;
+        use crate::types::v1alpha1::status::state::{LifecyclePhase, LifecycleStatus};
+        use crate::types::v1alpha1::synthetics::SyntheticsSpec;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.creds_secret = Some(CredsSecretReference::new("creds"));
+        tenant.spec.synthetics = Some(SyntheticsSpec::default());
+        let mut current = Status::default();
+        assert!(!synthetics_due(&tenant, &current, "synthetics-due"));
+
+        current.lifecycle = Some(LifecycleStatus {
+            phase: LifecyclePhase::Ready,
+            previous_phase: None,
+            last_transition_time: None,
+            first_ready_time: Some("2026-01-01T00:00:00Z".to_string()),
+        });
+        assert!(synthetics_due(&tenant, &current, "synthetics-due"));
+
+        let now = chrono::Utc::now();
+        let probed = |latency: u64| {
+            let mut status = refresh(&tenant, &current, "default", &[], None, now);
+            if let Some(health) = status.health.as_mut() {
+                health.synthetics = Some(SyntheticsStatus {
+                    success: true,
+                    put_latency_ms: Some(latency),
+                    ..Default::default()
+                });
+            }
+            status
+        };
+        let written = probed(12);
+        assert!(!needs_write(&written, &probed(40)));
+
+        let mut failed = probed(12);
+        if let Some(synthetics) = failed
+            .health
+            .as_mut()
+            .and_then(|health| health.synthetics.as_mut())
+        {
+            synthetics.success = false;
+            synthetics.consecutive_failures = 1;
+        }
+        assert!(needs_write(&written, &failed));
+    }
+}
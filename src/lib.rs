@@ -15,9 +15,11 @@
 #![allow(clippy::single_match)]
 
 use crate::context::Context;
+use crate::reconcile::namespace_policy::NamespaceFilter;
 use crate::reconcile::{error_policy, reconcile_rustfs};
+use crate::types::v1alpha1::federation::RemoteCluster;
 use crate::types::v1alpha1::policy_binding::PolicyBinding;
-use crate::types::v1alpha1::tenant::Tenant;
+use crate::types::v1alpha1::tenant::{TENANT_LABEL, Tenant};
 use axum::{
     Router, body::Body, extract::State, http::StatusCode, middleware, response::IntoResponse,
     routing::get,
@@ -33,7 +35,7 @@ use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 use kube::core::{ApiResource, DynamicObject, GroupVersionKind};
 use kube::runtime::reflector::ObjectRef;
 use kube::runtime::{Controller, watcher};
-use kube::{Api, Client, CustomResourceExt, Resource, api::ListParams};
+use kube::{Api, Client, CustomResourceExt, Resource, ResourceExt, api::ListParams};
 use kube_leader_election::{
     LeaderCallbacks, LeaderElector, LeaderElectorConfig, LeaseLock, SystemClock,
 };
@@ -48,7 +50,6 @@ use tokio_util::sync::CancellationToken;
 use tower::ServiceExt as _;
 use tracing::{info, warn};
 
-const RUSTFS_TENANT_LABEL: &str = "rustfs.tenant";
 const CERT_MANAGER_GROUP: &str = "cert-manager.io";
 const CERT_MANAGER_VERSION: &str = "v1";
 const CERT_MANAGER_CERTIFICATE_KIND: &str = "Certificate";
@@ -64,6 +65,57 @@ pub struct ServerOptions {
     pub leader_elect_namespace: String,
     /// Identity of this instance in leader election.
     pub leader_elect_identity: String,
+    /// Tenant controller watch and resync settings.
+    pub controller: ControllerOptions,
+}
+
+/// Watch and resync settings for the Tenant controller.
+#[derive(Clone, Debug)]
+pub struct ControllerOptions {
+    /// Page size of the initial paginated list made by each watch.
+    pub watch_page_size: u32,
+    /// Use streaming lists instead of paginated lists for the initial watch state
+    /// (requires the Kubernetes WatchList feature).
+    pub watch_streaming_list: bool,
+    /// Fault injection for resilience testing; `None` unless chaos mode is enabled.
+    pub chaos: Option<chaos::ChaosConfig>,
+    /// Operator-wide maintenance switch that limits reconciles to status updates.
+    pub maintenance: reconcile::maintenance::MaintenanceConfig,
+}
+
+impl Default for ControllerOptions {
+    fn default() -> Self {
+        Self {
+            watch_page_size: 500,
+            watch_streaming_list: false,
+            chaos: None,
+            maintenance: Default::default(),
+        }
+    }
+}
+
+impl ControllerOptions {
+    /// Watcher configuration shared by the Tenant watch and every child watch.
+    ///
+    /// Bookmarks are kept enabled so restarted watches resume from a recent resourceVersion
+    /// instead of relisting.
+    fn watcher_config(&self) -> watcher::Config {
+        let config = watcher::Config::default().page_size(self.watch_page_size.max(1));
+        if self.watch_streaming_list {
+            config.streaming_lists()
+        } else {
+            config
+        }
+    }
+
+    /// Child watch configuration. Denied namespaces are excluded server-side; the Tenant watch
+    /// keeps them so a Tenant there still gets its `NamespaceNotAllowed` condition.
+    fn child_watcher_config(&self, namespaces: &NamespaceFilter) -> watcher::Config {
+        match namespaces.deny_field_selector() {
+            Some(selector) => self.watcher_config().fields(&selector),
+            None => self.watcher_config(),
+        }
+    }
 }
 
 pub fn install_rustls_crypto_provider() {
@@ -83,13 +135,25 @@ pub fn init_tracing() {
     });
 }
 
+mod bucket_binding;
+pub mod bundle;
+pub mod chaos;
 mod context;
+mod cosi;
+mod health_check;
 pub mod metrics;
+pub mod migrate_minio;
+pub mod preflight;
+pub mod proxy;
+pub mod rbac;
 pub mod reconcile;
+pub mod reconcile_once;
 mod status;
 mod tenant_monitor;
 pub mod types;
+mod usage_metering;
 pub mod utils;
+pub mod version;
 
 // Console module (Web UI)
 pub mod console;
@@ -166,6 +230,7 @@ pub async fn run(options: ServerOptions) -> Result<(), Box<dyn std::error::Error
 
         let callbacks = ControllerCallbacks {
             client: client.clone(),
+            controller_options: options.controller.clone(),
         };
 
         let cancel = CancellationToken::new();
@@ -174,7 +239,7 @@ pub async fn run(options: ServerOptions) -> Result<(), Box<dyn std::error::Error
     } else {
         info!("starting with leader election disabled");
         metrics::set_operator_leader(true);
-        run_active_leader_tasks(client, CancellationToken::new()).await;
+        run_active_leader_tasks(client, options.controller, CancellationToken::new()).await;
         metrics::set_operator_leader(false);
     }
 
@@ -182,42 +247,101 @@ pub async fn run(options: ServerOptions) -> Result<(), Box<dyn std::error::Error
 }
 
 /// Build and run the controller reconcile loop.
-async fn run_controller(client: Client, cancel: CancellationToken) {
+async fn run_controller(client: Client, options: ControllerOptions, cancel: CancellationToken) {
     let tenant_client = Api::<Tenant>::all(client.clone());
-    let context = Context::new(client.clone());
-    let controller = Controller::new(tenant_client, watcher::Config::default())
+    if let Some(chaos) = &options.chaos {
+        warn!(
+            tenants = ?chaos.tenants,
+            seed = chaos.seed,
+            error_rate = chaos.error_rate,
+            max_delay_ms = chaos.max_delay.as_millis() as u64,
+            "chaos mode enabled: writes for the listed Tenants are delayed and may fail"
+        );
+    }
+    if options.maintenance.forced {
+        warn!(
+            "maintenance mode enabled: Tenants only get status updates until the operator restarts"
+        );
+    }
+    let namespaces = NamespaceFilter::from_env();
+    let context = Context::new(client.clone())
+        .with_chaos(options.chaos.clone())
+        .with_maintenance(options.maintenance.clone())
+        .with_child_stores(reconcile::gating::ChildStores::spawn(
+            &client,
+            options.child_watcher_config(&namespaces),
+            &cancel,
+        ));
+    let controller = Controller::new(tenant_client, options.watcher_config());
+    let tenants = controller.store();
+    let cached_tenants = tenants.clone();
+    metrics::set_tenant_cache(Some(Box::new(move || {
+        tenant_cache_snapshot(&cached_tenants.state())
+    })));
+    let mut watched_kinds = vec![
+        "Tenant",
+        "ConfigMap",
+        "Secret",
+        "ServiceAccount",
+        "Pod",
+        "StatefulSet",
+    ];
+    let config_map_tenants = tenants.clone();
+    let config_map_namespaces = namespaces.clone();
+    let secret_namespaces = namespaces.clone();
+    let controller = controller
         .watches(
             Api::<corev1::ConfigMap>::all(client.clone()),
-            watcher::Config::default(),
-            tenant_refs_for_config_map,
+            options.child_watcher_config(&namespaces),
+            move |config_map| {
+                let mut refs =
+                    tenant_refs_naming_config_map(&config_map, &config_map_tenants.state());
+                for tenant_ref in tenant_refs_for_config_map(config_map) {
+                    push_unique_tenant_ref(&mut refs, tenant_ref);
+                }
+                retain_allowed_tenant_refs(&mut refs, &config_map_namespaces);
+                refs
+            },
         )
         .watches(
             Api::<corev1::Secret>::all(client.clone()),
-            watcher::Config::default(),
-            tenant_refs_for_secret,
+            options.child_watcher_config(&namespaces),
+            move |secret| {
+                let mut refs = tenant_refs_naming_secret(&secret, &tenants.state());
+                for tenant_ref in tenant_refs_for_secret(secret) {
+                    push_unique_tenant_ref(&mut refs, tenant_ref);
+                }
+                retain_allowed_tenant_refs(&mut refs, &secret_namespaces);
+                refs
+            },
         )
         .owns(
             Api::<corev1::ServiceAccount>::all(client.clone()),
-            watcher::Config::default(),
+            options.child_watcher_config(&namespaces),
         )
         .owns(
             Api::<corev1::Pod>::all(client.clone()),
-            watcher::Config::default(),
+            options.child_watcher_config(&namespaces),
         )
         .owns(
             Api::<appsv1::StatefulSet>::all(client.clone()),
-            watcher::Config::default(),
+            options.child_watcher_config(&namespaces),
         );
 
     let certificate_gvk = cert_manager_certificate_gvk();
     let controller = match kube::discovery::pinned_kind(&client, &certificate_gvk).await {
         Ok((_resource, _capabilities)) => {
+            watched_kinds.push(CERT_MANAGER_CERTIFICATE_KIND);
             let resource = cert_manager_certificate_api_resource();
             controller.watches_with(
                 Api::<DynamicObject>::all_with(client.clone(), &resource),
                 resource,
-                watcher::Config::default(),
-                tenant_refs_for_cert_manager_certificate,
+                options.child_watcher_config(&namespaces),
+                move |certificate| {
+                    let mut refs = tenant_refs_for_cert_manager_certificate(certificate);
+                    retain_allowed_tenant_refs(&mut refs, &namespaces);
+                    refs
+                },
             )
         }
         Err(error) => {
@@ -229,6 +353,7 @@ async fn run_controller(client: Client, cancel: CancellationToken) {
         }
     };
 
+    metrics::set_active_watchers(&watched_kinds);
     let mut reconcile_stream = controller
         .run(
             instrumented_reconcile_rustfs,
@@ -251,11 +376,34 @@ async fn run_controller(client: Client, cancel: CancellationToken) {
                             "reconcile completed successfully"
                         );
                     }
-                    Err(error) => warn!(%error, "controller reconcile stream item failed"),
+                    Err(error) => {
+                        if matches!(error, kube::runtime::controller::Error::QueueError(_)) {
+                            metrics::record_watch_error();
+                        }
+                        warn!(%error, "controller reconcile stream item failed");
+                    }
                 }
             }
         } => {}
     }
+    metrics::set_active_watchers(&[]);
+    metrics::set_tenant_cache(None);
+}
+
+fn tenant_cache_snapshot(tenants: &[Arc<Tenant>]) -> metrics::TenantCacheSnapshot {
+    metrics::TenantCacheSnapshot {
+        objects: tenants.len(),
+        awaiting_reconcile: tenants
+            .iter()
+            .filter(|tenant| {
+                tenant.metadata.generation
+                    != tenant
+                        .status
+                        .as_ref()
+                        .and_then(|status| status.observed_generation)
+            })
+            .count(),
+    }
 }
 
 async fn instrumented_reconcile_rustfs(
@@ -263,29 +411,103 @@ async fn instrumented_reconcile_rustfs(
     ctx: Arc<Context>,
 ) -> Result<kube::runtime::controller::Action, reconcile::Error> {
     let started = metrics::reconcile_started();
-    let result = reconcile_rustfs(tenant, ctx).await;
+    let namespace = tenant.namespace().unwrap_or_default();
+    let name = tenant.name_any();
+    let deleting = tenant.metadata.deletion_timestamp.is_some();
+    let status_key = format!("{namespace}/{name}");
+    let (result, summary) = reconcile::summary::scope(chaos::scope(
+        ctx.chaos.as_ref(),
+        &namespace,
+        &name,
+        reconcile_rustfs(tenant.clone(), ctx.clone()),
+    ))
+    .await;
+    let result = result
+        .map(|action| ctx.resync.schedule(action, &tenant))
+        .map(|action| match ctx.status_writes.flush_delay(&status_key) {
+            Some(delay) if action == kube::runtime::controller::Action::await_change() => {
+                kube::runtime::controller::Action::requeue(delay)
+            }
+            _ => action,
+        });
     metrics::reconcile_finished(result.is_ok(), started.elapsed());
+    summary.log(&namespace, &name, result.as_ref(), started.elapsed());
+    if deleting {
+        ctx.status_writes.forget(&status_key);
+        metrics::forget_tenant_reconcile(&namespace, &name);
+    } else {
+        metrics::record_tenant_reconcile(&namespace, &name, result.is_ok(), started.elapsed());
+    }
     result
 }
 
-async fn run_active_leader_tasks(client: Client, cancel: CancellationToken) {
+async fn run_active_leader_tasks(
+    client: Client,
+    controller_options: ControllerOptions,
+    cancel: CancellationToken,
+) {
     let tasks_cancel = CancellationToken::new();
+    let maintenance = controller_options.maintenance.clone();
     let controller_client = client.clone();
     let controller_cancel = tasks_cancel.clone();
     let mut controller_handle = tokio::spawn(async move {
-        run_controller(controller_client, controller_cancel).await;
+        run_controller(controller_client, controller_options, controller_cancel).await;
     });
 
     let mut monitor_handle = if tenant_monitor::is_enabled() {
+        let monitor_client = client.clone();
         let monitor_cancel = tasks_cancel.clone();
         Some(tokio::spawn(async move {
-            tenant_monitor::run(client, monitor_cancel).await;
+            tenant_monitor::run(monitor_client, monitor_cancel).await;
         }))
     } else {
         info!("tenant storage monitor disabled by OPERATOR_TENANT_MONITOR_ENABLED=false");
         None
     };
 
+    let mut health_handle = if health_check::is_enabled() {
+        let health_client = client.clone();
+        let health_cancel = tasks_cancel.clone();
+        Some(tokio::spawn(async move {
+            health_check::run(health_client, health_cancel).await;
+        }))
+    } else {
+        info!("tenant health check disabled by OPERATOR_HEALTH_CHECK_ENABLED=false");
+        None
+    };
+
+    let mut metering_handle = if usage_metering::is_enabled() {
+        let metering_client = client.clone();
+        let metering_cancel = tasks_cancel.clone();
+        Some(tokio::spawn(async move {
+            usage_metering::run(metering_client, metering_cancel).await;
+        }))
+    } else {
+        None
+    };
+
+    let mut binding_handle = if bucket_binding::is_enabled() {
+        let binding_client = client.clone();
+        let binding_maintenance = maintenance.clone();
+        let binding_cancel = tasks_cancel.clone();
+        Some(tokio::spawn(async move {
+            bucket_binding::run(binding_client, binding_maintenance, binding_cancel).await;
+        }))
+    } else {
+        None
+    };
+
+    let mut cosi_handle = if cosi::is_enabled() {
+        let cosi_client = client.clone();
+        let cosi_maintenance = maintenance.clone();
+        let cosi_cancel = tasks_cancel.clone();
+        Some(tokio::spawn(async move {
+            cosi::run(cosi_client, cosi_maintenance, cosi_cancel).await;
+        }))
+    } else {
+        None
+    };
+
     let mut controller_finished = false;
     tokio::select! {
         result = &mut controller_handle => {
@@ -308,6 +530,18 @@ async fn run_active_leader_tasks(client: Client, cancel: CancellationToken) {
     if let Some(handle) = monitor_handle.take() {
         stop_task("tenant storage monitor", handle).await;
     }
+    if let Some(handle) = health_handle.take() {
+        stop_task("tenant health check", handle).await;
+    }
+    if let Some(handle) = metering_handle.take() {
+        stop_task("usage metering", handle).await;
+    }
+    if let Some(handle) = binding_handle.take() {
+        stop_task("bucket binding", handle).await;
+    }
+    if let Some(handle) = cosi_handle.take() {
+        stop_task("COSI driver", handle).await;
+    }
 }
 
 async fn stop_task(name: &str, mut handle: JoinHandle<()>) {
@@ -324,6 +558,7 @@ async fn stop_task(name: &str, mut handle: JoinHandle<()>) {
 /// Callbacks for running the controller inside leader election.
 struct ControllerCallbacks {
     client: Client,
+    controller_options: ControllerOptions,
 }
 
 #[async_trait::async_trait]
@@ -331,7 +566,7 @@ impl LeaderCallbacks for ControllerCallbacks {
     async fn on_started_leading(&self, cancel: CancellationToken) {
         info!("acquired leader lease, starting active leader tasks");
         metrics::set_operator_leader(true);
-        run_active_leader_tasks(self.client.clone(), cancel).await;
+        run_active_leader_tasks(self.client.clone(), self.controller_options.clone(), cancel).await;
         metrics::set_operator_leader(false);
     }
 
@@ -359,6 +594,7 @@ async fn run_operator_observability_server(
         .route("/metrics", get(metrics::handler))
         .route("/healthz", get(operator_health_check))
         .route("/readyz", get(operator_ready_check))
+        .route("/version", get(version::handler))
         .with_state(state)
         .layer(middleware::from_fn(metrics::record_operator_http));
 
@@ -565,6 +801,40 @@ fn tenant_refs_for_secret(secret: corev1::Secret) -> Vec<ObjectRef<Tenant>> {
     )
 }
 
+/// Tenants that reference the Secret by name, such as a credential Secret created by an
+/// external secret store without operator labels.
+fn tenant_refs_naming_secret(
+    secret: &corev1::Secret,
+    tenants: &[Arc<Tenant>],
+) -> Vec<ObjectRef<Tenant>> {
+    let (Some(name), Some(namespace)) = (&secret.metadata.name, &secret.metadata.namespace) else {
+        return Vec::new();
+    };
+    tenants
+        .iter()
+        .filter(|tenant| tenant.metadata.namespace.as_ref() == Some(namespace))
+        .filter(|tenant| tenant.referenced_secret_names().contains(name))
+        .map(|tenant| ObjectRef::from_obj(tenant.as_ref()))
+        .collect()
+}
+
+/// Tenants that list the ConfigMap in `spec.envFrom`.
+fn tenant_refs_naming_config_map(
+    config_map: &corev1::ConfigMap,
+    tenants: &[Arc<Tenant>],
+) -> Vec<ObjectRef<Tenant>> {
+    let (Some(name), Some(namespace)) = (&config_map.metadata.name, &config_map.metadata.namespace)
+    else {
+        return Vec::new();
+    };
+    tenants
+        .iter()
+        .filter(|tenant| tenant.metadata.namespace.as_ref() == Some(namespace))
+        .filter(|tenant| tenant.env_from_config_map_names().contains(name))
+        .map(|tenant| ObjectRef::from_obj(tenant.as_ref()))
+        .collect()
+}
+
 fn tenant_refs_for_config_map(config_map: corev1::ConfigMap) -> Vec<ObjectRef<Tenant>> {
     tenant_refs_from_metadata(
         config_map.metadata.namespace.as_deref(),
@@ -625,13 +895,23 @@ fn tenant_ref_from_labels(
     labels: &BTreeMap<String, String>,
 ) -> Option<ObjectRef<Tenant>> {
     let name = labels
-        .get(RUSTFS_TENANT_LABEL)
+        .get(TENANT_LABEL)
         .map(String::as_str)
         .filter(|name| !name.is_empty())?;
 
     Some(ObjectRef::new(name).within(namespace?))
 }
 
+/// Drops watch-triggered reconciles of Tenants outside the operator namespace allowlist.
+fn retain_allowed_tenant_refs(refs: &mut Vec<ObjectRef<Tenant>>, namespaces: &NamespaceFilter) {
+    refs.retain(|tenant_ref| {
+        tenant_ref
+            .namespace
+            .as_deref()
+            .is_none_or(|namespace| namespaces.allows(namespace))
+    });
+}
+
 fn push_unique_tenant_ref(refs: &mut Vec<ObjectRef<Tenant>>, tenant_ref: ObjectRef<Tenant>) {
     if !refs.iter().any(|existing| existing == &tenant_ref) {
         refs.push(tenant_ref);
@@ -641,11 +921,39 @@ fn push_unique_tenant_ref(refs: &mut Vec<ObjectRef<Tenant>>, tenant_ref: ObjectR
 pub fn render_crds_yaml() -> Result<String, serde_yaml_ng::Error> {
     let tenant = serde_yaml_ng::to_string(&Tenant::crd())?;
     let policy_binding = serde_yaml_ng::to_string(&PolicyBinding::crd())?;
-    Ok(format!("{tenant}---\n{policy_binding}"))
+    let remote_cluster = serde_yaml_ng::to_string(&RemoteCluster::crd())?;
+    Ok(format!(
+        "{tenant}---\n{policy_binding}---\n{remote_cluster}"
+    ))
 }
 
 pub async fn crd(file: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
-    let mut writer: Pin<Box<dyn AsyncWrite + Send>> = if let Some(file) = file {
+    let yaml = render_crds_yaml()?;
+    output_writer(file)
+        .await?
+        .write_all(yaml.as_bytes())
+        .await?;
+
+    Ok(())
+}
+
+/// Writes the operator ClusterRole and the aggregated Tenant ClusterRoles.
+pub async fn rbac(
+    file: Option<String>,
+    name: String,
+    console_impersonation: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let yaml = rbac::render_rbac_yaml(&name, console_impersonation)?;
+    output_writer(file)
+        .await?
+        .write_all(yaml.as_bytes())
+        .await?;
+
+    Ok(())
+}
+
+async fn output_writer(file: Option<String>) -> std::io::Result<Pin<Box<dyn AsyncWrite + Send>>> {
+    Ok(if let Some(file) = file {
         Box::pin(
             tokio::fs::OpenOptions::new()
                 .create(true)
@@ -656,12 +964,7 @@ pub async fn crd(file: Option<String>) -> Result<(), Box<dyn std::error::Error>>
         )
     } else {
         Box::pin(tokio::io::stdout())
-    };
-
-    let yaml = render_crds_yaml()?;
-    writer.write_all(yaml.as_bytes()).await?;
-
-    Ok(())
+    })
 }
 
 #[cfg(test)]
@@ -670,6 +973,32 @@ mod controller_watch_tests {
     use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
     use std::collections::BTreeMap;
 
+    #[test]
+    fn controller_watcher_config_keeps_bookmarks_and_paginates() {
+        let options = ControllerOptions {
+            watch_page_size: 100,
+            ..ControllerOptions::default()
+        };
+
+        let config = options.watcher_config();
+        assert!(config.bookmarks);
+        assert_eq!(config.page_size, Some(100));
+        assert_eq!(
+            config.initial_list_strategy,
+            watcher::InitialListStrategy::ListWatch
+        );
+
+        let streaming = ControllerOptions {
+            watch_streaming_list: true,
+            ..options
+        }
+        .watcher_config();
+        assert_eq!(
+            streaming.initial_list_strategy,
+            watcher::InitialListStrategy::StreamingList
+        );
+    }
+
     #[test]
     fn cert_manager_certificate_api_resource_is_stable() {
         let resource = cert_manager_certificate_api_resource();
@@ -721,6 +1050,53 @@ mod controller_watch_tests {
         assert_single_ref(&refs, "tenant-b", "storage");
     }
 
+    #[test]
+    fn secret_mapper_finds_tenants_referencing_unlabelled_secret() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.creds_secret =
+            Some(crate::types::v1alpha1::credentials::CredsSecretReference::new("external-creds"));
+        let tenants = vec![Arc::new(tenant)];
+        let secret = |name: &str, namespace: &str| corev1::Secret {
+            metadata: metav1::ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let refs = tenant_refs_naming_secret(&secret("external-creds", "default"), &tenants);
+        assert_single_ref(&refs, "test-tenant", "default");
+
+        assert!(tenant_refs_naming_secret(&secret("external-creds", "other"), &tenants).is_empty());
+        assert!(tenant_refs_naming_secret(&secret("unrelated", "default"), &tenants).is_empty());
+    }
+
+    #[test]
+    fn config_map_mapper_finds_tenants_listing_it_in_env_from() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.env_from = vec![corev1::EnvFromSource {
+            config_map_ref: Some(corev1::ConfigMapEnvSource {
+                name: "rustfs-env".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let tenants = vec![Arc::new(tenant)];
+        let config_map = |name: &str| corev1::ConfigMap {
+            metadata: metav1::ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let refs = tenant_refs_naming_config_map(&config_map("rustfs-env"), &tenants);
+        assert_single_ref(&refs, "test-tenant", "default");
+        assert!(tenant_refs_naming_config_map(&config_map("unrelated"), &tenants).is_empty());
+    }
+
     #[test]
     fn config_map_mapper_uses_owner_reference_or_label() {
         let owned = corev1::ConfigMap {
@@ -773,7 +1149,7 @@ mod controller_watch_tests {
     }
 
     #[test]
-    fn crd_output_includes_tenant_and_policy_binding_documents() {
+    fn crd_output_includes_tenant_policy_binding_and_remote_cluster_documents() {
         let yaml = render_crds_yaml().expect("CRDs render to YAML");
         let documents = yaml
             .split("---")
@@ -781,11 +1157,32 @@ mod controller_watch_tests {
             .filter(|document| !document.is_empty())
             .collect::<Vec<_>>();
 
-        assert_eq!(documents.len(), 2);
+        assert_eq!(documents.len(), 3);
         assert!(documents[0].contains("name: tenants.rustfs.com"));
         assert!(documents[1].contains("name: policybindings.sts.rustfs.com"));
         assert!(documents[1].contains("kind: PolicyBinding"));
         assert!(documents[1].contains("scope: Namespaced"));
+        assert!(documents[2].contains("name: remoteclusters.rustfs.com"));
+        assert!(documents[2].contains("scope: Namespaced"));
+    }
+
+    #[test]
+    fn tenant_cache_snapshot_counts_unobserved_generations() {
+        let mut reconciled = crate::tests::create_test_tenant(None, None);
+        reconciled.metadata.generation = Some(2);
+        reconciled.status = Some(crate::types::v1alpha1::status::Status {
+            observed_generation: Some(2),
+            ..Default::default()
+        });
+        let mut edited = reconciled.clone();
+        edited.metadata.generation = Some(3);
+        let mut created = crate::tests::create_test_tenant(None, None);
+        created.metadata.generation = Some(1);
+
+        let snapshot =
+            tenant_cache_snapshot(&[Arc::new(reconciled), Arc::new(edited), Arc::new(created)]);
+        assert_eq!(snapshot.objects, 3);
+        assert_eq!(snapshot.awaiting_reconcile, 2);
     }
 
     fn tenant_owner_ref(name: &str) -> metav1::OwnerReference {
@@ -13,42 +13,17 @@
 // limitations under the License.
 
 use clap::{Parser, Subcommand};
-use const_str::concat;
-use operator::{ServerOptions, crd, run};
-
-shadow_rs::shadow!(build);
+use operator::bundle::{ExportOptions, TenantBundle};
+use operator::chaos::ChaosConfig;
+use operator::preflight::{CheckStatus, PreflightOptions, preflight};
+use operator::reconcile::maintenance::MaintenanceConfig;
+use operator::reconcile_once::{ReconcileOnceOptions, reconcile_once};
+use operator::version::{LONG_VERSION, SHORT_VERSION};
+use operator::{ControllerOptions, ServerOptions, crd, rbac, run};
 
 const SERVICE_ACCOUNT_NAMESPACE_PATH: &str =
     "/var/run/secrets/kubernetes.io/serviceaccount/namespace";
 
-#[allow(clippy::const_is_empty)]
-const SHORT_VERSION: &str = {
-    if !build::TAG.is_empty() {
-        build::TAG
-    } else if !build::SHORT_COMMIT.is_empty() {
-        concat!("@", build::SHORT_COMMIT)
-    } else {
-        build::PKG_VERSION
-    }
-};
-
-const LONG_VERSION: &str = concat!(
-    concat!(SHORT_VERSION, "\n"),
-    concat!("build time   : ", build::BUILD_TIME, "\n"),
-    concat!("build profile: ", build::BUILD_RUST_CHANNEL, "\n"),
-    concat!("build os     : ", build::BUILD_OS, "\n"),
-    concat!("rust version : ", build::RUST_VERSION, "\n"),
-    concat!("rust channel : ", build::RUST_CHANNEL, "\n"),
-    concat!("git branch   : ", build::BRANCH, "\n"),
-    concat!("git commit   : ", build::COMMIT_HASH,),
-    #[allow(clippy::const_is_empty)]
-    if !build::TAG.is_empty() {
-        concat!("\n", "git tag      : ", build::TAG)
-    } else {
-        ""
-    },
-);
-
 #[derive(Parser)]
 #[command(name = "rustfs-operator")]
 #[command(about = "RustFS Kubernetes Operator CLI", long_about = None)]
@@ -68,6 +43,21 @@ enum Commands {
         file: Option<String>,
     },
 
+    /// Output the operator ClusterRole and aggregated Tenant ClusterRoles in YAML
+    Rbac {
+        /// Name of the operator ClusterRole, also used as the aggregated ClusterRole prefix
+        #[arg(long, default_value = operator::rbac::OPERATOR_CLUSTER_ROLE_NAME)]
+        name: String,
+
+        /// Also output the ClusterRole the Console needs with CONSOLE_AUTH_MODE=impersonation
+        #[arg(long, default_value = "false")]
+        console_impersonation: bool,
+
+        /// Optional output path. If not set, the output will be written to stdout.
+        #[arg(short, long)]
+        file: Option<String>,
+    },
+
     /// Run the controller
     Server {
         /// Enable leader election (disable for single-replica/local mode)
@@ -85,6 +75,24 @@ enum Commands {
         /// Identity for this instance in leader election (defaults to POD_NAME env or hostname)
         #[arg(long)]
         leader_elect_identity: Option<String>,
+
+        /// Page size of the initial list made by each watch
+        #[arg(long, default_value = "500")]
+        watch_page_size: u32,
+
+        /// Use streaming lists for the initial watch state (requires the WatchList feature)
+        #[arg(long, default_value = "false")]
+        watch_streaming_list: bool,
+
+        /// Randomly delay or fail writes for the Tenants in OPERATOR_CHAOS_TENANTS (staging only;
+        /// also enabled by OPERATOR_CHAOS_ENABLED=true)
+        #[arg(long, default_value = "false")]
+        chaos: bool,
+
+        /// Only update Tenant status, e.g. during a control-plane upgrade (also enabled by
+        /// OPERATOR_MAINTENANCE_MODE=true or the OPERATOR_MAINTENANCE_CONFIGMAP ConfigMap)
+        #[arg(long, default_value = "false")]
+        maintenance: bool,
     },
 
     /// Run the console web server
@@ -93,6 +101,71 @@ enum Commands {
         #[arg(long, default_value = "9090")]
         port: u16,
     },
+
+    /// Export a Tenant with its Secrets, Services and PVC specs as a restorable YAML bundle
+    Export {
+        /// Namespace of the Tenant
+        #[arg(short, long, default_value = "default")]
+        namespace: String,
+
+        /// Name of the Tenant
+        name: String,
+
+        /// Replace Secret values with empty strings
+        #[arg(long, default_value = "false")]
+        redact_secrets: bool,
+
+        /// Optional output path. If not set, the output will be written to stdout.
+        #[arg(short, long)]
+        file: Option<String>,
+    },
+
+    /// Re-create the objects of an exported Tenant bundle
+    Import {
+        /// Path of the bundle produced by `export`
+        #[arg(short, long)]
+        file: String,
+
+        /// Namespace to create the objects in (defaults to the namespace recorded in the bundle)
+        #[arg(short, long)]
+        namespace: Option<String>,
+    },
+
+    /// Convert a MinIO operator Tenant manifest into a RustFS Tenant
+    MigrateMinio {
+        /// Path of the MinIO Tenant YAML
+        #[arg(short, long)]
+        file: String,
+
+        /// Optional output path. If not set, the output will be written to stdout.
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Check that the current cluster can run the operator, for install scripts
+    Preflight {
+        /// Check the RBAC of the operator ServiceAccount (namespace/name) instead of the caller
+        #[arg(long)]
+        service_account: Option<String>,
+
+        /// Exit non-zero on warnings as well as failures
+        #[arg(long, default_value = "false")]
+        strict: bool,
+    },
+
+    /// Run a single reconcile of one Tenant and print the objects it created or updated
+    ReconcileOnce {
+        /// Namespace of the Tenant
+        #[arg(short, long, default_value = "default")]
+        namespace: String,
+
+        /// Name of the Tenant
+        name: String,
+
+        /// Send writes with dryRun=All and skip RustFS admin calls, webhooks, and mirroring
+        #[arg(long, default_value = "false")]
+        dry_run: bool,
+    },
 }
 
 #[tokio::main]
@@ -101,11 +174,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match cli.command {
         Commands::Crd { file } => crd(file).await,
+        Commands::Rbac {
+            name,
+            console_impersonation,
+            file,
+        } => rbac(file, name, console_impersonation).await,
         Commands::Server {
             leader_elect,
             leader_elect_lease_name,
             leader_elect_namespace,
             leader_elect_identity,
+            watch_page_size,
+            watch_streaming_list,
+            chaos,
+            maintenance,
         } => {
             let namespace = resolve_leader_elect_namespace(leader_elect_namespace);
             let identity = leader_elect_identity
@@ -121,10 +203,102 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 leader_elect_lease_name,
                 leader_elect_namespace: namespace,
                 leader_elect_identity: identity,
+                controller: ControllerOptions {
+                    watch_page_size,
+                    watch_streaming_list,
+                    chaos: ChaosConfig::from_env(chaos)?,
+                    maintenance: MaintenanceConfig::from_env(
+                        maintenance,
+                        &resolve_leader_elect_namespace(None),
+                    ),
+                },
             };
             run(options).await
         }
         Commands::Console { port } => operator::console::server::run(port).await,
+        Commands::Export {
+            namespace,
+            name,
+            redact_secrets,
+            file,
+        } => {
+            let client = kube::Client::try_default().await?;
+            let options = ExportOptions { redact_secrets };
+            let yaml = TenantBundle::export(client, &namespace, &name, &options)
+                .await?
+                .to_yaml()?;
+            match file {
+                Some(file) => tokio::fs::write(file, yaml).await?,
+                None => print!("{yaml}"),
+            }
+            Ok(())
+        }
+        Commands::Import { file, namespace } => {
+            let client = kube::Client::try_default().await?;
+            let yaml = tokio::fs::read_to_string(file).await?;
+            let report = TenantBundle::from_yaml(&yaml)?
+                .import(client, namespace.as_deref())
+                .await?;
+            for object in &report.created {
+                println!("created {object}");
+            }
+            for object in &report.skipped {
+                println!("unchanged {object} (already exists)");
+            }
+            for secret in &report.redacted_secrets {
+                eprintln!("Secret/{secret} was redacted in the bundle; create it manually");
+            }
+            Ok(())
+        }
+        Commands::MigrateMinio { file, output } => {
+            let yaml = tokio::fs::read_to_string(file).await?;
+            let conversion = operator::migrate_minio::convert(&yaml)?;
+            for warning in &conversion.warnings {
+                eprintln!("warning: {warning}");
+            }
+            let yaml = conversion.to_yaml()?;
+            match output {
+                Some(output) => tokio::fs::write(output, yaml).await?,
+                None => print!("{yaml}"),
+            }
+            Ok(())
+        }
+        Commands::Preflight {
+            service_account,
+            strict,
+        } => {
+            let client = kube::Client::try_default().await?;
+            let options = PreflightOptions { service_account };
+            let report = preflight(&client, &options).await;
+            print!("{}", report.render());
+            let failed = report.count(CheckStatus::Fail);
+            let warnings = report.count(CheckStatus::Warn);
+            if failed > 0 || (strict && warnings > 0) {
+                return Err("preflight checks did not pass".into());
+            }
+            Ok(())
+        }
+        Commands::ReconcileOnce {
+            namespace,
+            name,
+            dry_run,
+        } => {
+            let options = ReconcileOnceOptions { dry_run };
+            let report = reconcile_once(&namespace, &name, &options).await?;
+            for change in &report.changes {
+                println!("{} {}", change.kind.as_str(), change.object);
+                for line in &change.diff {
+                    println!("    {line}");
+                }
+            }
+            match report.outcome {
+                Ok(action) => {
+                    println!("reconcile succeeded: {action}");
+                    Ok(())
+                }
+                Err(error) => Err(format!("reconcile failed: {error}").into()),
+            }
+        }
     }
 }
 
@@ -35,6 +35,9 @@ struct Metrics {
     reconcile_duration: Mutex<BTreeMap<String, DurationSummary>>,
     reconcile_requeues_total: Mutex<BTreeMap<String, u64>>,
     reconcile_inflight: AtomicU64,
+    status_writes_total: Mutex<BTreeMap<String, u64>>,
+    child_writes_total: Mutex<BTreeMap<String, u64>>,
+    chaos_injections_total: Mutex<BTreeMap<String, u64>>,
     operator_leader: AtomicU64,
     sts_requests_total: Mutex<BTreeMap<String, u64>>,
     sts_request_duration: Mutex<BTreeMap<String, DurationSummary>>,
@@ -43,6 +46,23 @@ struct Metrics {
     tenant_monitor_polls_total: Mutex<BTreeMap<String, u64>>,
     tenant_monitor_poll_duration: Mutex<BTreeMap<String, DurationSummary>>,
     tenant_storage: Mutex<BTreeMap<TenantKey, TenantStorageSnapshot>>,
+    tenant_reconcile: Mutex<BTreeMap<TenantKey, TenantReconcileSnapshot>>,
+    tenant_synthetics: Mutex<BTreeMap<TenantKey, TenantSyntheticsSnapshot>>,
+    watchers: Mutex<BTreeSet<String>>,
+    watch_errors_total: AtomicU64,
+    last_watch_error_timestamp_seconds: AtomicU64,
+    tenant_cache: Mutex<Option<TenantCacheProbe>>,
+}
+
+/// Reads the controller's Tenant watch cache when metrics are rendered.
+pub type TenantCacheProbe = Box<dyn Fn() -> TenantCacheSnapshot + Send + Sync>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TenantCacheSnapshot {
+    /// Tenants held in the reflector store.
+    pub objects: usize,
+    /// Tenants whose `metadata.generation` is ahead of `status.observedGeneration`.
+    pub awaiting_reconcile: usize,
 }
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -85,6 +105,34 @@ struct TenantStorageSnapshot {
     healthy: bool,
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+struct TenantReconcileSnapshot {
+    last_timestamp_seconds: u64,
+    last_duration_seconds: f64,
+    errors_total: u64,
+    tls_not_after_seconds: Option<i64>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct TenantSyntheticsSnapshot {
+    probes_total: u64,
+    failures_total: u64,
+    last_success: bool,
+    last_probe_timestamp_seconds: u64,
+    put_latency: DurationSummary,
+    get_latency: DurationSummary,
+    last_put_latency_seconds: Option<f64>,
+    last_get_latency_seconds: Option<f64>,
+}
+
+/// Outcome of one synthetic PUT/GET probe of a tenant.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SyntheticProbeMetrics {
+    pub success: bool,
+    pub put_latency: Option<Duration>,
+    pub get_latency: Option<Duration>,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct TenantStorageMetrics {
     pub online_drives: u64,
@@ -95,6 +143,14 @@ pub struct TenantStorageMetrics {
     pub object_usage_bytes: u64,
     pub write_quorum_drives: u64,
     pub healthy: bool,
+    /// Per-pool raw capacity, in RustFS pool index order.
+    pub pools: Vec<PoolStorageMetrics>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolStorageMetrics {
+    pub raw_capacity_bytes: u64,
+    pub raw_used_bytes: u64,
 }
 
 fn metrics() -> &'static Metrics {
@@ -120,11 +176,105 @@ pub fn reconcile_finished(success: bool, duration: Duration) {
     observe_string_duration(&metrics().reconcile_duration, result, duration);
 }
 
+pub fn record_tenant_reconcile(namespace: &str, tenant: &str, success: bool, duration: Duration) {
+    let key = TenantKey {
+        namespace: namespace.to_string(),
+        tenant: tenant.to_string(),
+    };
+    let mut snapshots = metrics()
+        .tenant_reconcile
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let snapshot = snapshots.entry(key).or_default();
+    snapshot.last_timestamp_seconds = unix_timestamp_seconds();
+    snapshot.last_duration_seconds = duration.as_secs_f64();
+    if !success {
+        snapshot.errors_total += 1;
+    }
+}
+
+/// Failed reconciles recorded for a tenant since the operator process started.
+pub fn tenant_reconcile_errors(namespace: &str, tenant: &str) -> u64 {
+    let key = TenantKey {
+        namespace: namespace.to_string(),
+        tenant: tenant.to_string(),
+    };
+    metrics()
+        .tenant_reconcile
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(&key)
+        .map_or(0, |snapshot| snapshot.errors_total)
+}
+
+/// Expiry of the TLS certificate serving a tenant, or `None` when it has no TLS certificate.
+pub fn record_tenant_tls_expiry(namespace: &str, tenant: &str, not_after_seconds: Option<i64>) {
+    let key = TenantKey {
+        namespace: namespace.to_string(),
+        tenant: tenant.to_string(),
+    };
+    metrics()
+        .tenant_reconcile
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .entry(key)
+        .or_default()
+        .tls_not_after_seconds = not_after_seconds;
+}
+
+pub fn forget_tenant_reconcile(namespace: &str, tenant: &str) {
+    let key = TenantKey {
+        namespace: namespace.to_string(),
+        tenant: tenant.to_string(),
+    };
+    metrics()
+        .tenant_reconcile
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(&key);
+}
+
+/// Kinds the controller currently watches; empty while this process is not the leader.
+pub fn set_active_watchers(kinds: &[&str]) {
+    *metrics()
+        .watchers
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        kinds.iter().map(|kind| kind.to_string()).collect();
+}
+
+pub fn record_watch_error() {
+    metrics().watch_errors_total.fetch_add(1, Ordering::Relaxed);
+    metrics()
+        .last_watch_error_timestamp_seconds
+        .store(unix_timestamp_seconds(), Ordering::Relaxed);
+}
+
+pub fn set_tenant_cache(probe: Option<TenantCacheProbe>) {
+    *metrics()
+        .tenant_cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = probe;
+}
+
 pub fn record_reconcile_requeue(duration: Duration) {
     let delay = duration.as_secs().to_string();
     increment_string_counter(&metrics().reconcile_requeues_total, &delay);
 }
 
+/// Counts a Tenant status write decision: `written`, `unchanged`, or `deferred`.
+pub fn record_status_write(result: &str) {
+    increment_string_counter(&metrics().status_writes_total, result);
+}
+
+pub fn record_child_write(outcome: &str) {
+    increment_string_counter(&metrics().child_writes_total, outcome);
+}
+
+pub fn record_chaos_injection(kind: &str) {
+    increment_string_counter(&metrics().chaos_injections_total, kind);
+}
+
 pub fn record_sts_request(success: bool, duration: Duration) {
     let result = result_label(success);
     increment_string_counter(&metrics().sts_requests_total, result);
@@ -236,6 +386,48 @@ pub fn prune_tenant_storage(active_tenants: &[(String, String)]) {
     snapshots.retain(|key, _| active_tenants.contains(key));
 }
 
+pub fn record_tenant_synthetic_probe(namespace: &str, tenant: &str, probe: SyntheticProbeMetrics) {
+    let key = TenantKey {
+        namespace: namespace.to_string(),
+        tenant: tenant.to_string(),
+    };
+    let mut snapshots = metrics()
+        .tenant_synthetics
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let snapshot = snapshots.entry(key).or_default();
+    snapshot.probes_total += 1;
+    if !probe.success {
+        snapshot.failures_total += 1;
+    }
+    snapshot.last_success = probe.success;
+    snapshot.last_probe_timestamp_seconds = unix_timestamp_seconds();
+    if let Some(latency) = probe.put_latency {
+        snapshot.put_latency.observe(latency);
+    }
+    if let Some(latency) = probe.get_latency {
+        snapshot.get_latency.observe(latency);
+    }
+    snapshot.last_put_latency_seconds = probe.put_latency.map(|latency| latency.as_secs_f64());
+    snapshot.last_get_latency_seconds = probe.get_latency.map(|latency| latency.as_secs_f64());
+}
+
+pub fn prune_tenant_synthetics(active_tenants: &[(String, String)]) {
+    let active_tenants = active_tenants
+        .iter()
+        .map(|(namespace, tenant)| TenantKey {
+            namespace: namespace.clone(),
+            tenant: tenant.clone(),
+        })
+        .collect::<BTreeSet<_>>();
+
+    metrics()
+        .tenant_synthetics
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .retain(|key, _| active_tenants.contains(key));
+}
+
 pub async fn handler() -> impl IntoResponse {
     let mut headers = HeaderMap::new();
     headers.insert(
@@ -275,12 +467,34 @@ pub fn render() -> String {
         "delay_seconds",
         &metrics().reconcile_requeues_total,
     );
+    render_string_counter(
+        &mut output,
+        "rustfs_operator_status_writes_total",
+        "Total number of Tenant status write decisions by result.",
+        "result",
+        &metrics().status_writes_total,
+    );
+    render_string_counter(
+        &mut output,
+        "rustfs_operator_child_writes_total",
+        "Total number of Tenant child resource writes by outcome.",
+        "outcome",
+        &metrics().child_writes_total,
+    );
+    render_string_counter(
+        &mut output,
+        "rustfs_operator_chaos_injections_total",
+        "Total number of faults injected by chaos mode by kind.",
+        "kind",
+        &metrics().chaos_injections_total,
+    );
     render_gauge(
         &mut output,
         "rustfs_operator_leader",
         "Whether this process is the active operator leader.",
         metrics().operator_leader.load(Ordering::Relaxed) as f64,
     );
+    render_controller_health(&mut output);
 
     render_string_counter(
         &mut output,
@@ -315,10 +529,62 @@ pub fn render() -> String {
         &metrics().tenant_monitor_poll_duration,
     );
     render_tenant_storage(&mut output);
+    render_tenant_reconcile(&mut output);
+    render_tenant_synthetics(&mut output);
 
     output
 }
 
+fn render_controller_health(output: &mut String) {
+    let name = "rustfs_operator_watchers_active";
+    output.push_str(&format!(
+        "# HELP {name} Watch streams the controller runs, by kind.\n# TYPE {name} gauge\n"
+    ));
+    for kind in metrics()
+        .watchers
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        output.push_str(&format!("{name}{{{}}} 1\n", labels(&[("kind", kind)])));
+    }
+
+    output.push_str(&format!(
+        "# HELP rustfs_operator_watch_errors_total Total number of watch stream errors.\n# TYPE rustfs_operator_watch_errors_total counter\nrustfs_operator_watch_errors_total {}\n",
+        metrics().watch_errors_total.load(Ordering::Relaxed)
+    ));
+    render_gauge(
+        output,
+        "rustfs_operator_last_watch_error_timestamp_seconds",
+        "Unix timestamp of the last watch stream error, 0 if none.",
+        metrics()
+            .last_watch_error_timestamp_seconds
+            .load(Ordering::Relaxed) as f64,
+    );
+
+    let snapshot = metrics()
+        .tenant_cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .map(|probe| probe());
+    let Some(snapshot) = snapshot else {
+        return;
+    };
+    let name = "rustfs_operator_watch_cache_objects";
+    output.push_str(&format!(
+        "# HELP {name} Objects held in the controller reflector store, by kind.\n# TYPE {name} gauge\n{name}{{{}}} {}\n",
+        labels(&[("kind", "Tenant")]),
+        snapshot.objects
+    ));
+    render_gauge(
+        output,
+        "rustfs_operator_reconcile_queue_depth",
+        "Tenants whose latest generation has not been reconciled yet.",
+        snapshot.awaiting_reconcile as f64,
+    );
+}
+
 fn update_tenant_storage_snapshot(namespace: &str, tenant: &str, snapshot: TenantStorageSnapshot) {
     let key = TenantKey {
         namespace: namespace.to_string(),
@@ -531,13 +797,150 @@ fn render_tenant_storage(output: &mut String) {
     );
 }
 
+fn render_tenant_reconcile(output: &mut String) {
+    let snapshots = metrics()
+        .tenant_reconcile
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    render_tenant_gauge_family(
+        output,
+        "rustfs_operator_tenant_last_reconcile_timestamp_seconds",
+        "Unix timestamp of the last completed reconcile for a tenant.",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, snapshot.last_timestamp_seconds as f64)),
+    );
+    render_tenant_gauge_family(
+        output,
+        "rustfs_operator_tenant_last_reconcile_duration_seconds",
+        "Duration of the last completed reconcile for a tenant.",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, snapshot.last_duration_seconds)),
+    );
+    render_tenant_family(
+        output,
+        "rustfs_operator_tenant_reconcile_errors_total",
+        "Total number of failed reconciles for a tenant.",
+        "counter",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, snapshot.errors_total as f64)),
+    );
+    render_tenant_gauge_family(
+        output,
+        "rustfs_operator_tenant_tls_certificate_expiry_timestamp_seconds",
+        "Unix timestamp at which the TLS certificate of a tenant expires.",
+        snapshots.iter().filter_map(|(key, snapshot)| {
+            snapshot
+                .tls_not_after_seconds
+                .map(|not_after| (key, not_after as f64))
+        }),
+    );
+}
+
+fn render_tenant_synthetics(output: &mut String) {
+    let snapshots = metrics()
+        .tenant_synthetics
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    render_tenant_family(
+        output,
+        "rustfs_operator_tenant_synthetic_probes_total",
+        "Total number of synthetic S3 probes run against a tenant.",
+        "counter",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, snapshot.probes_total as f64)),
+    );
+    render_tenant_family(
+        output,
+        "rustfs_operator_tenant_synthetic_probe_failures_total",
+        "Total number of failed synthetic S3 probes for a tenant.",
+        "counter",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, snapshot.failures_total as f64)),
+    );
+    render_tenant_gauge_family(
+        output,
+        "rustfs_operator_tenant_synthetic_probe_success",
+        "Whether the last synthetic S3 probe of a tenant succeeded.",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, if snapshot.last_success { 1.0 } else { 0.0 })),
+    );
+    render_tenant_gauge_family(
+        output,
+        "rustfs_operator_tenant_synthetic_probe_last_timestamp_seconds",
+        "Unix timestamp of the last synthetic S3 probe of a tenant.",
+        snapshots
+            .iter()
+            .map(|(key, snapshot)| (key, snapshot.last_probe_timestamp_seconds as f64)),
+    );
+
+    let name = "rustfs_operator_tenant_synthetic_probe_latency_seconds";
+    output.push_str(&format!(
+        "# HELP {name} Latency of the last synthetic S3 probe request of a tenant, by operation.\n# TYPE {name} gauge\n"
+    ));
+    for (key, snapshot) in snapshots.iter() {
+        for (operation, latency) in [
+            ("put", snapshot.last_put_latency_seconds),
+            ("get", snapshot.last_get_latency_seconds),
+        ] {
+            if let Some(latency) = latency {
+                output.push_str(&format!(
+                    "{name}{{{}}} {latency:.6}\n",
+                    synthetics_labels(key, operation)
+                ));
+            }
+        }
+    }
+
+    let name = "rustfs_operator_tenant_synthetic_probe_duration_seconds";
+    output.push_str(&format!(
+        "# HELP {name} Synthetic S3 probe request duration of a tenant, by operation.\n# TYPE {name} summary\n"
+    ));
+    for (key, snapshot) in snapshots.iter() {
+        for (operation, summary) in [("put", snapshot.put_latency), ("get", snapshot.get_latency)] {
+            let label = synthetics_labels(key, operation);
+            output.push_str(&format!(
+                "{name}_count{{{label}}} {}\n{name}_sum{{{label}}} {:.6}\n",
+                summary.count, summary.sum_seconds
+            ));
+        }
+    }
+}
+
+fn synthetics_labels(key: &TenantKey, operation: &str) -> String {
+    labels(&[
+        ("namespace", &key.namespace),
+        ("tenant", &key.tenant),
+        ("operation", operation),
+    ])
+}
+
 fn render_tenant_gauge_family<'a>(
     output: &mut String,
     name: &str,
     help: &str,
     values: impl Iterator<Item = (&'a TenantKey, f64)>,
 ) {
-    output.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n"));
+    render_tenant_family(output, name, help, "gauge", values);
+}
+
+fn render_tenant_family<'a>(
+    output: &mut String,
+    name: &str,
+    help: &str,
+    metric_type: &str,
+    values: impl Iterator<Item = (&'a TenantKey, f64)>,
+) {
+    output.push_str(&format!(
+        "# HELP {name} {help}\n# TYPE {name} {metric_type}\n"
+    ));
     for (key, value) in values {
         output.push_str(&format!(
             "{name}{{{}}} {:.6}\n",
@@ -634,4 +1037,78 @@ mod tests {
         assert!(rendered.contains("tenant=\"prune-active\""));
         assert!(!rendered.contains("tenant=\"prune-stale\""));
     }
+
+    #[test]
+    fn tenant_reconcile_counts_errors_per_tenant() {
+        let namespace = "reconcile-namespace";
+        let tenant = "reconcile-tenant";
+
+        record_tenant_reconcile(namespace, tenant, true, Duration::from_millis(250));
+        record_tenant_reconcile(namespace, tenant, false, Duration::from_millis(500));
+        assert_eq!(tenant_reconcile_errors(namespace, tenant), 1);
+        assert!(render().contains(
+            "rustfs_operator_tenant_reconcile_errors_total{namespace=\"reconcile-namespace\",tenant=\"reconcile-tenant\"} 1.000000"
+        ));
+
+        forget_tenant_reconcile(namespace, tenant);
+        assert_eq!(tenant_reconcile_errors(namespace, tenant), 0);
+        assert!(!render().contains("tenant=\"reconcile-tenant\""));
+    }
+
+    #[test]
+    fn tenant_synthetics_export_latency_and_failures() {
+        let namespace = "synthetics-namespace";
+        let tenant = "synthetics-tenant";
+
+        record_tenant_synthetic_probe(
+            namespace,
+            tenant,
+            SyntheticProbeMetrics {
+                success: true,
+                put_latency: Some(Duration::from_millis(20)),
+                get_latency: Some(Duration::from_millis(10)),
+            },
+        );
+        record_tenant_synthetic_probe(namespace, tenant, SyntheticProbeMetrics::default());
+
+        let rendered = render();
+        let labels = "namespace=\"synthetics-namespace\",tenant=\"synthetics-tenant\"";
+        assert!(rendered.contains(&format!(
+            "rustfs_operator_tenant_synthetic_probes_total{{{labels}}} 2.000000"
+        )));
+        assert!(rendered.contains(&format!(
+            "rustfs_operator_tenant_synthetic_probe_failures_total{{{labels}}} 1.000000"
+        )));
+        assert!(rendered.contains(&format!(
+            "rustfs_operator_tenant_synthetic_probe_success{{{labels}}} 0.000000"
+        )));
+        assert!(rendered.contains(&format!(
+            "rustfs_operator_tenant_synthetic_probe_duration_seconds_count{{{labels},operation=\"put\"}} 1"
+        )));
+        // The failed probe did not finish a PUT, so no last latency is exported.
+        assert!(!rendered.contains("rustfs_operator_tenant_synthetic_probe_latency_seconds{namespace=\"synthetics-namespace\""));
+
+        prune_tenant_synthetics(&[]);
+        assert!(!render().contains("tenant=\"synthetics-tenant\""));
+    }
+
+    #[test]
+    fn controller_health_reports_watchers_and_cache() {
+        set_active_watchers(&["Tenant", "Pod"]);
+        set_tenant_cache(Some(Box::new(|| TenantCacheSnapshot {
+            objects: 3,
+            awaiting_reconcile: 1,
+        })));
+
+        let rendered = render();
+        assert!(rendered.contains("rustfs_operator_watchers_active{kind=\"Pod\"} 1"));
+        assert!(rendered.contains("rustfs_operator_watch_cache_objects{kind=\"Tenant\"} 3"));
+        assert!(rendered.contains("rustfs_operator_reconcile_queue_depth 1.000000"));
+
+        set_active_watchers(&[]);
+        set_tenant_cache(None);
+        let rendered = render();
+        assert!(!rendered.contains("rustfs_operator_watchers_active{"));
+        assert!(!rendered.contains("rustfs_operator_watch_cache_objects"));
+    }
 }
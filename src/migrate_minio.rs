@@ -0,0 +1,439 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of MinIO operator Tenants (`minio.min.io/v2`) into RustFS Tenants.
+//!
+//! Pools, volumes per server, volume claim templates, scheduling, credentials, buckets, and a
+//! few workload settings carry over. Everything else is dropped and reported as a warning, so the
+//! generated Tenant can be reviewed before it is applied. Data is not migrated: the RustFS Tenant
+//! provisions new volumes.
+
+use crate::types::v1alpha1::credentials::CredsSecretReference;
+use crate::types::v1alpha1::persistence::PersistenceConfig;
+use crate::types::v1alpha1::pool::{Pool, SchedulingConfig};
+use crate::types::v1alpha1::provisioning::ProvisioningBucket;
+use crate::types::v1alpha1::tenant::{Tenant, TenantSpec};
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use snafu::{OptionExt, ResultExt, Snafu, ensure};
+
+const MINIO_API_GROUP: &str = "minio.min.io/";
+
+/// MinIO Tenant spec fields that are converted or handled by a specific warning.
+const HANDLED_SPEC_FIELDS: &[&str] = &[
+    "pools",
+    "image",
+    "imagePullSecret",
+    "imagePullPolicy",
+    "mountPath",
+    "credsSecret",
+    "configuration",
+    "env",
+    "buckets",
+    "users",
+    "priorityClassName",
+    "serviceAccountName",
+    "podManagementPolicy",
+    "lifecycle",
+    "exposeServices",
+    "requestAutoCert",
+    "externalCertSecret",
+    "externalCaCertSecret",
+    "externalClientCertSecret",
+    "externalClientCertSecrets",
+    "kes",
+];
+
+/// MinIO pool fields carried over to the RustFS pool.
+const CONVERTED_POOL_FIELDS: &[&str] = &[
+    "name",
+    "servers",
+    "volumesPerServer",
+    "volumeClaimTemplate",
+    "nodeSelector",
+    "affinity",
+    "tolerations",
+    "topologySpreadConstraints",
+    "resources",
+];
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("failed to parse MinIO Tenant YAML: {}", source))]
+    ParseYaml { source: serde_yaml_ng::Error },
+
+    #[snafu(display("expected a MinIO Tenant, found {} '{}'", kind, api_version))]
+    NotMinioTenant { api_version: String, kind: String },
+
+    #[snafu(display("MinIO Tenant has no {}", field))]
+    MissingField { field: String },
+
+    #[snafu(display("invalid {} in MinIO Tenant: {}", field, source))]
+    InvalidField {
+        field: String,
+        source: serde_json::Error,
+    },
+
+    #[snafu(display("failed to render Tenant YAML: {}", source))]
+    RenderYaml { source: serde_yaml_ng::Error },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A converted Tenant and the MinIO settings that did not carry over.
+#[derive(Clone, Debug)]
+pub struct Conversion {
+    pub tenant: Tenant,
+    pub warnings: Vec<String>,
+}
+
+impl Conversion {
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml_ng::to_string(&self.tenant).context(RenderYamlSnafu)
+    }
+}
+
+/// Converts a MinIO Tenant manifest into a RustFS Tenant.
+pub fn convert(yaml: &str) -> Result<Conversion> {
+    let minio: Value = serde_yaml_ng::from_str(yaml).context(ParseYamlSnafu)?;
+    let api_version = minio["apiVersion"].as_str().unwrap_or_default();
+    let kind = minio["kind"].as_str().unwrap_or_default();
+    ensure!(
+        api_version.starts_with(MINIO_API_GROUP) && kind == "Tenant",
+        NotMinioTenantSnafu { api_version, kind }
+    );
+
+    let name = minio["metadata"]["name"]
+        .as_str()
+        .filter(|name| !name.is_empty())
+        .context(MissingFieldSnafu {
+            field: "metadata.name",
+        })?;
+    let empty = Map::new();
+    let spec = minio["spec"].as_object().unwrap_or(&empty);
+    let mut warnings = Vec::new();
+
+    let pools = match spec.get("pools").and_then(Value::as_array) {
+        Some(pools) if !pools.is_empty() => pools
+            .iter()
+            .enumerate()
+            .map(|(index, pool)| convert_pool(index, pool, &mut warnings))
+            .collect::<Result<Vec<_>>>()?,
+        _ => {
+            return MissingFieldSnafu {
+                field: "spec.pools",
+            }
+            .fail();
+        }
+    };
+
+    let mut tenant_spec = TenantSpec {
+        pools,
+        image: None,
+        mount_path: None,
+        image_pull_secret: field(spec, "imagePullSecret", "spec")?,
+        image_pull_policy: field(spec, "imagePullPolicy", "spec")?,
+        priority_class_name: field(spec, "priorityClassName", "spec")?,
+        service_account_name: field(spec, "serviceAccountName", "spec")?,
+        pod_management_policy: field(spec, "podManagementPolicy", "spec")?,
+        lifecycle: field(spec, "lifecycle", "spec")?,
+        ..Default::default()
+    };
+
+    if let Some(image) = spec.get("image").and_then(Value::as_str) {
+        warnings.push(format!(
+            "spec.image '{image}' is a MinIO image; the RustFS default image is used"
+        ));
+    }
+    if spec.contains_key("mountPath") {
+        warnings.push("spec.mountPath was dropped; RustFS mounts volumes under /data".to_string());
+    }
+
+    let creds_secret: Option<corev1::LocalObjectReference> = field(spec, "credsSecret", "spec")?;
+    tenant_spec.creds_secret = creds_secret.map(|secret| CredsSecretReference::new(secret.name));
+    if let Some(configuration) =
+        field::<corev1::LocalObjectReference>(spec, "configuration", "spec")?
+    {
+        warnings.push(format!(
+            "spec.configuration Secret '{}' holds a MinIO config.env; create a Secret with \
+             'accesskey' and 'secretkey' keys and set spec.credsSecret",
+            configuration.name
+        ));
+    }
+
+    let env: Vec<corev1::EnvVar> = field(spec, "env", "spec")?.unwrap_or_default();
+    for var in env.iter().filter(|var| var.name.starts_with("MINIO_")) {
+        warnings.push(format!(
+            "spec.env '{}' is a MinIO setting and was dropped",
+            var.name
+        ));
+    }
+    tenant_spec.env = env
+        .into_iter()
+        .filter(|var| !var.name.starts_with("MINIO_"))
+        .collect();
+
+    if let Some(buckets) = spec.get("buckets").and_then(Value::as_array) {
+        for (index, bucket) in buckets.iter().enumerate() {
+            let bucket: ProvisioningBucket =
+                serde_json::from_value(bucket.clone()).context(InvalidFieldSnafu {
+                    field: format!("spec.buckets[{index}]"),
+                })?;
+            tenant_spec.buckets.push(bucket);
+        }
+    }
+    if spec.contains_key("users") {
+        warnings.push(
+            "spec.users was dropped; RustFS users need policies, declare them in spec.users \
+             with spec.policies"
+                .to_string(),
+        );
+    }
+    if spec.contains_key("exposeServices") {
+        warnings.push(
+            "spec.exposeServices was dropped; the RustFS io and console Services are ClusterIP, \
+             expose them with a LoadBalancer Service or an Ingress"
+                .to_string(),
+        );
+    }
+    if spec.get("requestAutoCert").and_then(Value::as_bool) != Some(false)
+        || [
+            "externalCertSecret",
+            "externalCaCertSecret",
+            "externalClientCertSecret",
+            "externalClientCertSecrets",
+        ]
+        .iter()
+        .any(|key| spec.contains_key(*key))
+    {
+        warnings.push(
+            "MinIO TLS settings were not converted; configure spec.tls on the RustFS Tenant"
+                .to_string(),
+        );
+    }
+    if spec.contains_key("kes") {
+        warnings.push(
+            "spec.kes was dropped; configure spec.encryption on the RustFS Tenant".to_string(),
+        );
+    }
+    for key in spec
+        .keys()
+        .filter(|key| !HANDLED_SPEC_FIELDS.contains(&key.as_str()))
+    {
+        warnings.push(format!("spec.{key} is not supported and was dropped"));
+    }
+
+    let tenant = Tenant {
+        metadata: metav1::ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: minio["metadata"]["namespace"].as_str().map(str::to_string),
+            ..Default::default()
+        },
+        spec: tenant_spec,
+        status: None,
+    };
+    if let Err(error) = tenant.validate_pools() {
+        warnings.push(format!("converted pools are not valid for RustFS: {error}"));
+    }
+
+    Ok(Conversion { tenant, warnings })
+}
+
+fn convert_pool(index: usize, pool: &Value, warnings: &mut Vec<String>) -> Result<Pool> {
+    let path = format!("spec.pools[{index}]");
+    let empty = Map::new();
+    let pool = pool.as_object().unwrap_or(&empty);
+
+    let name = pool
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("pool-{index}"));
+    let servers = field(pool, "servers", &path)?.context(MissingFieldSnafu {
+        field: format!("{path}.servers"),
+    })?;
+    let volumes_per_server =
+        field(pool, "volumesPerServer", &path)?.context(MissingFieldSnafu {
+            field: format!("{path}.volumesPerServer"),
+        })?;
+    let claim_template: Option<corev1::PersistentVolumeClaim> =
+        field(pool, "volumeClaimTemplate", &path)?;
+    let claim_metadata = claim_template
+        .as_ref()
+        .map(|template| template.metadata.clone())
+        .unwrap_or_default();
+
+    for key in pool
+        .keys()
+        .filter(|key| !CONVERTED_POOL_FIELDS.contains(&key.as_str()))
+    {
+        warnings.push(format!("{path}.{key} is not supported and was dropped"));
+    }
+
+    Ok(Pool {
+        name,
+        servers,
+        persistence: PersistenceConfig {
+            volumes_per_server,
+            volume_claim_template: claim_template.and_then(|template| template.spec),
+            labels: claim_metadata.labels,
+            annotations: claim_metadata.annotations,
+            ..Default::default()
+        },
+        paused: None,
+        env: Vec::new(),
+        service_account_name: None,
+        pod_metadata: None,
+        pod_deletion_policy_when_node_is_down: None,
+        scheduling: SchedulingConfig {
+            node_selector: field(pool, "nodeSelector", &path)?,
+            affinity: field(pool, "affinity", &path)?,
+            tolerations: field(pool, "tolerations", &path)?,
+            topology_spread_constraints: field(pool, "topologySpreadConstraints", &path)?,
+            resources: field(pool, "resources", &path)?,
+            priority_class_name: None,
+        },
+    })
+}
+
+/// Deserializes `object[key]`, naming `{path}.{key}` when it has the wrong shape.
+fn field<T: DeserializeOwned>(
+    object: &Map<String, Value>,
+    key: &str,
+    path: &str,
+) -> Result<Option<T>> {
+    object
+        .get(key)
+        .filter(|value| !value.is_null())
+        .map(|value| {
+            serde_json::from_value(value.clone()).context(InvalidFieldSnafu {
+                field: format!("{path}.{key}"),
+            })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, convert};
+
+    const MINIO_TENANT: &str = r#"
+apiVersion: minio.min.io/v2
+kind: Tenant
+metadata:
+  name: storage
+  namespace: minio-tenant
+spec:
+  image: quay.io/minio/minio:RELEASE.2024-10-02T17-50-41Z
+  credsSecret:
+    name: storage-creds
+  requestAutoCert: false
+  exposeServices:
+    minio: true
+  env:
+    - name: MINIO_PROMETHEUS_AUTH_TYPE
+      value: public
+    - name: TZ
+      value: UTC
+  buckets:
+    - name: backups
+      objectLock: true
+  prometheusOperator: true
+  pools:
+    - name: pool-0
+      servers: 4
+      volumesPerServer: 4
+      runtimeClassName: gvisor
+      nodeSelector:
+        disktype: ssd
+      volumeClaimTemplate:
+        metadata:
+          name: data
+        spec:
+          accessModes: [ReadWriteOnce]
+          storageClassName: fast
+          resources:
+            requests:
+              storage: 1Ti
+"#;
+
+    #[test]
+    fn converts_pools_credentials_and_buckets() {
+        let conversion = convert(MINIO_TENANT).expect("MinIO Tenant should convert");
+        let tenant = &conversion.tenant;
+
+        assert_eq!(tenant.metadata.name.as_deref(), Some("storage"));
+        assert_eq!(tenant.metadata.namespace.as_deref(), Some("minio-tenant"));
+        assert_eq!(
+            tenant
+                .spec
+                .creds_secret
+                .as_ref()
+                .map(|creds| creds.name.as_str()),
+            Some("storage-creds")
+        );
+        assert_eq!(tenant.spec.buckets[0].name, "backups");
+        assert!(tenant.spec.buckets[0].object_lock_enabled());
+        assert_eq!(tenant.spec.env.len(), 1);
+        assert_eq!(tenant.spec.env[0].name, "TZ");
+
+        let pool = &tenant.spec.pools[0];
+        assert_eq!((pool.servers, pool.persistence.volumes_per_server), (4, 4));
+        assert_eq!(
+            pool.persistence
+                .volume_claim_template
+                .as_ref()
+                .and_then(|spec| spec.storage_class_name.as_deref()),
+            Some("fast")
+        );
+        assert_eq!(
+            pool.scheduling
+                .node_selector
+                .as_ref()
+                .and_then(|selector| selector.get("disktype"))
+                .map(String::as_str),
+            Some("ssd")
+        );
+        assert!(conversion.to_yaml().is_ok());
+    }
+
+    #[test]
+    fn flags_unsupported_fields() {
+        let warnings = convert(MINIO_TENANT)
+            .expect("MinIO Tenant should convert")
+            .warnings;
+        for expected in [
+            "spec.image",
+            "MINIO_PROMETHEUS_AUTH_TYPE",
+            "spec.exposeServices",
+            "spec.prometheusOperator is not supported",
+            "spec.pools[0].runtimeClassName is not supported",
+        ] {
+            assert!(
+                warnings.iter().any(|warning| warning.contains(expected)),
+                "missing warning for {expected}: {warnings:?}"
+            );
+        }
+        assert!(!warnings.iter().any(|warning| warning.contains("TLS")));
+    }
+
+    #[test]
+    fn rejects_other_kinds() {
+        let error = convert("apiVersion: rustfs.com/v1alpha1\nkind: Tenant\n")
+            .expect_err("RustFS Tenant is not a MinIO Tenant");
+        assert!(matches!(error, Error::NotMinioTenant { .. }));
+    }
+}
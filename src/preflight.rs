@@ -0,0 +1,600 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `rustfs-operator preflight`: checks that a cluster can run the operator before installing it.
+//!
+//! Failed checks block an install; warnings name features that will not work until the cluster
+//! provides what they need.
+
+use crate::rbac::OPERATOR_RULES;
+use crate::types::v1alpha1::federation::RemoteCluster;
+use crate::types::v1alpha1::policy_binding::PolicyBinding;
+use crate::types::v1alpha1::tenant::Tenant;
+use futures::{StreamExt, stream};
+use k8s_openapi::api::authorization::v1 as authorizationv1;
+use k8s_openapi::api::storage::v1::StorageClass;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use k8s_openapi::apimachinery::pkg::version::Info;
+use kube::api::{ListParams, PostParams};
+use kube::{Api, Client, CustomResourceExt};
+use std::fmt;
+
+/// Oldest Kubernetes minor version the operator supports.
+pub const MIN_KUBERNETES_MINOR: u32 = 30;
+
+const DEFAULT_STORAGE_CLASS_ANNOTATION: &str = "storageclass.kubernetes.io/is-default-class";
+
+const SNAPSHOT_CRDS: &[&str] = &[
+    "volumesnapshots.snapshot.storage.k8s.io",
+    "volumesnapshotcontents.snapshot.storage.k8s.io",
+    "volumesnapshotclasses.snapshot.storage.k8s.io",
+];
+
+/// Access reviews sent at once by the RBAC check.
+const REVIEW_CONCURRENCY: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PreflightOptions {
+    /// `namespace/name` of the operator ServiceAccount to check the RBAC of. Without it the
+    /// RBAC check runs for the caller, who must hold every permission it grants the operator.
+    pub service_account: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PreflightReport {
+    pub checks: Vec<Check>,
+}
+
+impl PreflightReport {
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status == status)
+            .count()
+    }
+
+    /// One line per check, then a summary line.
+    pub fn render(&self) -> String {
+        let width = self
+            .checks
+            .iter()
+            .map(|check| check.name.len())
+            .max()
+            .unwrap_or(0);
+        let mut output = String::new();
+        for check in &self.checks {
+            output.push_str(&format!(
+                "{}  {:width$}  {}\n",
+                check.status, check.name, check.message
+            ));
+        }
+        output.push_str(&format!(
+            "preflight: {} passed, {} warnings, {} failed\n",
+            self.count(CheckStatus::Pass),
+            self.count(CheckStatus::Warn),
+            self.count(CheckStatus::Fail)
+        ));
+        output
+    }
+}
+
+/// Runs every check against the cluster of `client`. API errors fail the check they occur in.
+pub async fn preflight(client: &Client, options: &PreflightOptions) -> PreflightReport {
+    let mut checks = vec![match client.apiserver_version().await {
+        Ok(info) => kubernetes_version_check(&info),
+        Err(error) => Check::new(
+            "Kubernetes version",
+            CheckStatus::Fail,
+            format!("cannot reach the API server: {error}"),
+        ),
+    }];
+
+    let crds: Api<CustomResourceDefinition> = Api::all(client.clone());
+    checks.push(operator_crds_check(&crds).await);
+    checks.push(match crds.list(&ListParams::default()).await {
+        Ok(list) => snapshot_crds_check(&crd_names(&list.items)),
+        Err(error) => Check::new(
+            "Snapshot CRDs",
+            CheckStatus::Fail,
+            format!("cannot list CustomResourceDefinitions: {error}"),
+        ),
+    });
+
+    let storage_classes: Api<StorageClass> = Api::all(client.clone());
+    checks.push(match storage_classes.list(&ListParams::default()).await {
+        Ok(list) => default_storage_class_check(&list.items),
+        Err(error) => Check::new(
+            "Default StorageClass",
+            CheckStatus::Fail,
+            format!("cannot list StorageClasses: {error}"),
+        ),
+    });
+
+    checks.push(match client.list_api_group_resources("policy/v1").await {
+        Ok(list)
+            if list
+                .resources
+                .iter()
+                .any(|r| r.name == "poddisruptionbudgets") =>
+        {
+            Check::new(
+                "PodDisruptionBudget",
+                CheckStatus::Pass,
+                "policy/v1 is served",
+            )
+        }
+        Ok(_) => Check::new(
+            "PodDisruptionBudget",
+            CheckStatus::Fail,
+            "policy/v1 does not serve poddisruptionbudgets",
+        ),
+        Err(error) => Check::new(
+            "PodDisruptionBudget",
+            CheckStatus::Fail,
+            format!("policy/v1 is not available: {error}"),
+        ),
+    });
+
+    checks.push(rbac_check(client, options.service_account.as_deref()).await);
+    PreflightReport { checks }
+}
+
+fn kubernetes_version_check(info: &Info) -> Check {
+    let digits = |value: &str| -> Option<u32> {
+        value
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok()
+    };
+    match (digits(&info.major), digits(&info.minor)) {
+        (Some(1), Some(minor)) if minor >= MIN_KUBERNETES_MINOR => Check::new(
+            "Kubernetes version",
+            CheckStatus::Pass,
+            info.git_version.clone(),
+        ),
+        (Some(major), Some(_)) if major > 1 => Check::new(
+            "Kubernetes version",
+            CheckStatus::Pass,
+            info.git_version.clone(),
+        ),
+        _ => Check::new(
+            "Kubernetes version",
+            CheckStatus::Fail,
+            format!(
+                "{} is older than the minimum v1.{MIN_KUBERNETES_MINOR}",
+                info.git_version
+            ),
+        ),
+    }
+}
+
+async fn operator_crds_check(api: &Api<CustomResourceDefinition>) -> Check {
+    let mut missing = Vec::new();
+    let mut not_established = Vec::new();
+    for name in [
+        Tenant::crd_name(),
+        PolicyBinding::crd_name(),
+        RemoteCluster::crd_name(),
+    ] {
+        match api.get_opt(name).await {
+            Ok(Some(crd)) if crd_established(&crd) => {}
+            Ok(Some(_)) => not_established.push(name),
+            Ok(None) => missing.push(name),
+            Err(error) => {
+                return Check::new(
+                    "Operator CRDs",
+                    CheckStatus::Fail,
+                    format!("cannot read CustomResourceDefinition {name}: {error}"),
+                );
+            }
+        }
+    }
+    if !not_established.is_empty() {
+        Check::new(
+            "Operator CRDs",
+            CheckStatus::Fail,
+            format!("not established: {}", not_established.join(", ")),
+        )
+    } else if !missing.is_empty() {
+        Check::new(
+            "Operator CRDs",
+            CheckStatus::Warn,
+            format!(
+                "not installed yet: {}; the chart or `rustfs-operator crd` installs them",
+                missing.join(", ")
+            ),
+        )
+    } else {
+        Check::new(
+            "Operator CRDs",
+            CheckStatus::Pass,
+            "installed and established",
+        )
+    }
+}
+
+fn crd_established(crd: &CustomResourceDefinition) -> bool {
+    crd.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|condition| condition.type_ == "Established" && condition.status == "True")
+        })
+}
+
+fn crd_names(crds: &[CustomResourceDefinition]) -> Vec<String> {
+    crds.iter()
+        .filter_map(|crd| crd.metadata.name.clone())
+        .collect()
+}
+
+fn snapshot_crds_check(installed: &[String]) -> Check {
+    let missing: Vec<&str> = SNAPSHOT_CRDS
+        .iter()
+        .copied()
+        .filter(|name| !installed.iter().any(|installed| installed == name))
+        .collect();
+    if missing.is_empty() {
+        Check::new("Snapshot CRDs", CheckStatus::Pass, "installed")
+    } else {
+        Check::new(
+            "Snapshot CRDs",
+            CheckStatus::Warn,
+            format!(
+                "missing {}; restoring pools from VolumeSnapshots is unavailable",
+                missing.join(", ")
+            ),
+        )
+    }
+}
+
+fn default_storage_class_check(classes: &[StorageClass]) -> Check {
+    let defaults: Vec<String> = classes
+        .iter()
+        .filter(|class| {
+            class
+                .metadata
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(DEFAULT_STORAGE_CLASS_ANNOTATION))
+                .is_some_and(|value| value == "true")
+        })
+        .filter_map(|class| class.metadata.name.clone())
+        .collect();
+    match defaults.as_slice() {
+        [name] => Check::new("Default StorageClass", CheckStatus::Pass, name.clone()),
+        [] => Check::new(
+            "Default StorageClass",
+            CheckStatus::Warn,
+            "none; set storageClassName on every pool's volume claim template",
+        ),
+        names => Check::new(
+            "Default StorageClass",
+            CheckStatus::Warn,
+            format!(
+                "several defaults ({}); set storageClassName on every pool's volume claim template",
+                names.join(", ")
+            ),
+        ),
+    }
+}
+
+/// One API permission of an [`OPERATOR_RULES`] rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Permission {
+    group: &'static str,
+    resource: &'static str,
+    subresource: Option<&'static str>,
+    name: Option<&'static str>,
+    verb: &'static str,
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.verb, self.resource)?;
+        if let Some(subresource) = self.subresource {
+            write!(f, "/{subresource}")?;
+        }
+        if !self.group.is_empty() {
+            write!(f, ".{}", self.group)?;
+        }
+        Ok(())
+    }
+}
+
+fn operator_permissions() -> Vec<Permission> {
+    let mut permissions = Vec::new();
+    for rule in OPERATOR_RULES {
+        for resource in rule.resources {
+            let (resource, subresource) = match resource.split_once('/') {
+                Some((resource, subresource)) => (resource, Some(subresource)),
+                None => (*resource, None),
+            };
+            for verb in rule.verbs {
+                permissions.push(Permission {
+                    group: rule.api_group,
+                    resource,
+                    subresource,
+                    name: rule.resource_names.first().copied(),
+                    verb,
+                });
+            }
+        }
+    }
+    permissions
+}
+
+fn service_account_parts(service_account: &str) -> Option<(&str, &str)> {
+    service_account
+        .split_once('/')
+        .filter(|(namespace, name)| !namespace.is_empty() && !name.is_empty())
+}
+
+fn resource_attributes(permission: &Permission) -> authorizationv1::ResourceAttributes {
+    authorizationv1::ResourceAttributes {
+        group: Some(permission.group.to_string()),
+        resource: Some(permission.resource.to_string()),
+        subresource: permission.subresource.map(str::to_string),
+        name: permission.name.map(str::to_string),
+        verb: Some(permission.verb.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Whether the caller, or `service_account` when set, is allowed `permission`.
+async fn allowed(
+    client: &Client,
+    service_account: Option<&str>,
+    permission: &Permission,
+) -> Result<bool, kube::Error> {
+    let params = PostParams::default();
+    let status = match service_account {
+        Some(service_account) => {
+            let (namespace, name) = service_account_parts(service_account).unwrap_or(("", ""));
+            let api: Api<authorizationv1::SubjectAccessReview> = Api::all(client.clone());
+            let review = authorizationv1::SubjectAccessReview {
+                spec: authorizationv1::SubjectAccessReviewSpec {
+                    user: Some(format!("system:serviceaccount:{namespace}:{name}")),
+                    groups: Some(vec![
+                        "system:serviceaccounts".to_string(),
+                        format!("system:serviceaccounts:{namespace}"),
+                        "system:authenticated".to_string(),
+                    ]),
+                    resource_attributes: Some(resource_attributes(permission)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            api.create(&params, &review).await?.status
+        }
+        None => {
+            let api: Api<authorizationv1::SelfSubjectAccessReview> = Api::all(client.clone());
+            let review = authorizationv1::SelfSubjectAccessReview {
+                spec: authorizationv1::SelfSubjectAccessReviewSpec {
+                    resource_attributes: Some(resource_attributes(permission)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            api.create(&params, &review).await?.status
+        }
+    };
+    Ok(status.is_some_and(|status| status.allowed))
+}
+
+async fn rbac_check(client: &Client, service_account: Option<&str>) -> Check {
+    if let Some(service_account) = service_account
+        && service_account_parts(service_account).is_none()
+    {
+        return Check::new(
+            "RBAC",
+            CheckStatus::Fail,
+            format!("service account '{service_account}' must be namespace/name"),
+        );
+    }
+    let permissions = operator_permissions();
+    let results: Vec<(Permission, Result<bool, kube::Error>)> = stream::iter(permissions)
+        .map(|permission| async move {
+            let result = allowed(client, service_account, &permission).await;
+            (permission, result)
+        })
+        .buffer_unordered(REVIEW_CONCURRENCY)
+        .collect()
+        .await;
+    let subject = service_account
+        .map(|service_account| format!("ServiceAccount {service_account}"))
+        .unwrap_or_else(|| "the current user".to_string());
+    rbac_result(&subject, results)
+}
+
+fn rbac_result(subject: &str, results: Vec<(Permission, Result<bool, kube::Error>)>) -> Check {
+    let total = results.len();
+    let mut denied = Vec::new();
+    for (permission, result) in results {
+        match result {
+            Ok(true) => {}
+            Ok(false) => denied.push(permission.to_string()),
+            Err(error) => {
+                return Check::new(
+                    "RBAC",
+                    CheckStatus::Fail,
+                    format!("access review for {permission} failed: {error}"),
+                );
+            }
+        }
+    }
+    if denied.is_empty() {
+        Check::new(
+            "RBAC",
+            CheckStatus::Pass,
+            format!("{subject} holds all {total} operator permissions"),
+        )
+    } else {
+        denied.sort();
+        Check::new(
+            "RBAC",
+            CheckStatus::Fail,
+            format!("{subject} is missing: {}", denied.join(", ")),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn version(major: &str, minor: &str) -> Info {
+        Info {
+            major: major.to_string(),
+            minor: minor.to_string(),
+            git_version: format!("v{major}.{minor}"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn kubernetes_version_has_a_minimum() {
+        assert_eq!(
+            kubernetes_version_check(&version("1", "30")).status,
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            kubernetes_version_check(&version("1", "31+")).status,
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            kubernetes_version_check(&version("1", "29")).status,
+            CheckStatus::Fail
+        );
+        assert_eq!(
+            kubernetes_version_check(&version("", "")).status,
+            CheckStatus::Fail
+        );
+    }
+
+    #[test]
+    fn exactly_one_default_storage_class_passes() {
+        let class = |name: &str, default: bool| StorageClass {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                annotations: default.then(|| {
+                    BTreeMap::from([(
+                        DEFAULT_STORAGE_CLASS_ANNOTATION.to_string(),
+                        "true".to_string(),
+                    )])
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            default_storage_class_check(&[class("local", false), class("standard", true)]),
+            Check::new("Default StorageClass", CheckStatus::Pass, "standard")
+        );
+        assert_eq!(
+            default_storage_class_check(&[class("local", false)]).status,
+            CheckStatus::Warn
+        );
+        assert_eq!(
+            default_storage_class_check(&[class("a", true), class("b", true)]).status,
+            CheckStatus::Warn
+        );
+    }
+
+    #[test]
+    fn snapshot_crds_are_optional() {
+        let all: Vec<String> = SNAPSHOT_CRDS.iter().map(|name| name.to_string()).collect();
+        assert_eq!(snapshot_crds_check(&all).status, CheckStatus::Pass);
+        assert_eq!(snapshot_crds_check(&all[..1]).status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn rbac_lists_denied_permissions() {
+        let permissions = operator_permissions();
+        assert!(permissions.contains(&Permission {
+            group: "",
+            resource: "pods",
+            subresource: Some("log"),
+            name: None,
+            verb: "get",
+        }));
+
+        let results = permissions
+            .iter()
+            .cloned()
+            .map(|permission| {
+                let allowed = permission.resource != "leases";
+                (permission, Ok(allowed))
+            })
+            .collect();
+        let check = rbac_result("the current user", results);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.message.contains("create leases.coordination.k8s.io"));
+        assert!(!check.message.contains("statefulsets"));
+    }
+
+    #[test]
+    fn report_summarizes_the_checks() {
+        let report = PreflightReport {
+            checks: vec![
+                Check::new("Kubernetes version", CheckStatus::Pass, "v1.30.2"),
+                Check::new("Snapshot CRDs", CheckStatus::Warn, "missing"),
+            ],
+        };
+        let rendered = report.render();
+        assert!(rendered.contains("PASS  Kubernetes version  v1.30.2"));
+        assert!(rendered.ends_with("preflight: 1 passed, 1 warnings, 0 failed\n"));
+    }
+}
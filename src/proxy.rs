@@ -0,0 +1,297 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Outbound HTTP proxy settings for proxied and air-gapped clusters.
+//!
+//! The settings apply to the RustFS admin client, kube clients for remote clusters, and, when
+//! enabled, the RustFS pods. In-cluster names and the API server address always bypass the
+//! proxy, so a proxy meant for external egress never intercepts Service traffic.
+
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::core::v1 as corev1;
+use std::borrow::Cow;
+use tracing::warn;
+
+/// Hosts that never go through the proxy.
+const IN_CLUSTER_NO_PROXY: &[&str] = &[
+    "localhost",
+    "127.0.0.1",
+    "::1",
+    ".svc",
+    ".svc.cluster.local",
+    ".cluster.local",
+];
+
+/// Proxy variables written to RustFS pods, in both spellings common tools read.
+const POD_PROXY_ENV: &[(&str, ProxyField)] = &[
+    ("HTTP_PROXY", ProxyField::Http),
+    ("http_proxy", ProxyField::Http),
+    ("HTTPS_PROXY", ProxyField::Https),
+    ("https_proxy", ProxyField::Https),
+    ("NO_PROXY", ProxyField::NoProxy),
+    ("no_proxy", ProxyField::NoProxy),
+];
+
+#[derive(Clone, Copy)]
+enum ProxyField {
+    Http,
+    Https,
+    NoProxy,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// Proxy for plain HTTP requests.
+    pub http_proxy: Option<String>,
+    /// Proxy for HTTPS requests.
+    pub https_proxy: Option<String>,
+    /// Additional hosts or domain suffixes that bypass the proxy.
+    pub no_proxy: Vec<String>,
+    /// Add the proxy variables to RustFS pods that do not set them in `spec.env`.
+    pub inject_pod_env: bool,
+}
+
+impl ProxyConfig {
+    /// Reads `OPERATOR_HTTP_PROXY`, `OPERATOR_HTTPS_PROXY`, `OPERATOR_NO_PROXY` (comma
+    /// separated), and `OPERATOR_PROXY_POD_ENV`.
+    pub fn from_env() -> Self {
+        Self {
+            http_proxy: env_value("OPERATOR_HTTP_PROXY"),
+            https_proxy: env_value("OPERATOR_HTTPS_PROXY"),
+            no_proxy: env_value("OPERATOR_NO_PROXY")
+                .map(|value| split_no_proxy(&value))
+                .unwrap_or_default(),
+            inject_pod_env: env_value("OPERATOR_PROXY_POD_ENV").is_some_and(|value| {
+                match value.to_ascii_lowercase().as_str() {
+                    "1" | "true" | "yes" | "on" => true,
+                    "0" | "false" | "no" | "off" => false,
+                    _ => {
+                        warn!(
+                            value,
+                            "invalid OPERATOR_PROXY_POD_ENV value, defaulting to off"
+                        );
+                        false
+                    }
+                }
+            }),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.http_proxy.is_some() || self.https_proxy.is_some()
+    }
+
+    /// Bypass list: the configured entries, in-cluster names, and the API server host.
+    pub fn no_proxy_hosts(&self) -> Vec<String> {
+        let mut hosts = self.no_proxy.clone();
+        hosts.extend(IN_CLUSTER_NO_PROXY.iter().map(|host| host.to_string()));
+        if let Some(api_host) = env_value("KUBERNETES_SERVICE_HOST") {
+            hosts.push(api_host);
+        }
+        let mut seen = std::collections::HashSet::new();
+        hosts.retain(|host| seen.insert(host.clone()));
+        hosts
+    }
+
+    /// Whether requests to `host` bypass the proxy.
+    pub fn bypasses(&self, host: &str) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        self.no_proxy_hosts().iter().any(|entry| {
+            let entry = entry.as_str();
+            entry == "*"
+                || entry == host
+                || entry
+                    .strip_prefix('.')
+                    .is_some_and(|suffix| host == suffix || host.ends_with(entry))
+                || (!entry.starts_with('.') && host.ends_with(&format!(".{entry}")))
+        })
+    }
+
+    /// Applies the proxy to an HTTP client. Without explicit settings the client keeps reqwest's
+    /// default of reading the standard `HTTP(S)_PROXY` variables.
+    pub fn apply_to_http_client(
+        &self,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, reqwest::Error> {
+        if !self.is_enabled() {
+            return Ok(builder);
+        }
+        let no_proxy = reqwest::NoProxy::from_string(&self.no_proxy_hosts().join(","));
+        let mut builder = builder.no_proxy();
+        if let Some(url) = &self.http_proxy {
+            builder = builder.proxy(reqwest::Proxy::http(url)?.no_proxy(no_proxy.clone()));
+        }
+        if let Some(url) = &self.https_proxy {
+            builder = builder.proxy(reqwest::Proxy::https(url)?.no_proxy(no_proxy));
+        }
+        Ok(builder)
+    }
+
+    /// Routes a kube client through the proxy unless its API server is bypassed. Only `http://`
+    /// proxy URLs are supported by the kube client.
+    pub fn apply_to_kube_config(&self, config: &mut kube::Config) {
+        if config.proxy_url.is_some() {
+            return;
+        }
+        let Some(url) = self.https_proxy.as_ref().or(self.http_proxy.as_ref()) else {
+            return;
+        };
+        if config
+            .cluster_url
+            .host()
+            .is_some_and(|host| self.bypasses(host))
+        {
+            return;
+        }
+        match url.parse::<http::Uri>() {
+            Ok(uri) => config.proxy_url = Some(uri),
+            Err(error) => warn!(%error, "invalid proxy URL; kube client will connect directly"),
+        }
+    }
+
+    /// Proxy variables for RustFS pods, empty unless pod injection is enabled.
+    pub fn pod_env(&self) -> Vec<corev1::EnvVar> {
+        if !self.inject_pod_env || !self.is_enabled() {
+            return Vec::new();
+        }
+        let no_proxy = self.no_proxy_hosts().join(",");
+        POD_PROXY_ENV
+            .iter()
+            .filter_map(|(name, field)| {
+                let value = match field {
+                    ProxyField::Http => self.http_proxy.clone()?,
+                    ProxyField::Https => self.https_proxy.clone()?,
+                    ProxyField::NoProxy => no_proxy.clone(),
+                };
+                Some(corev1::EnvVar {
+                    name: name.to_string(),
+                    value: Some(value),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    /// The Tenant to render pods from, with proxy variables the user did not set in `spec.env`.
+    pub fn render_tenant<'a>(&self, tenant: Cow<'a, Tenant>) -> Cow<'a, Tenant> {
+        let missing: Vec<_> = self
+            .pod_env()
+            .into_iter()
+            .filter(|var| !tenant.spec.env.iter().any(|env| env.name == var.name))
+            .collect();
+        if missing.is_empty() {
+            return tenant;
+        }
+        let mut tenant = tenant.into_owned();
+        tenant.spec.env.extend(missing);
+        Cow::Owned(tenant)
+    }
+}
+
+fn env_value(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn split_no_proxy(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn proxied() -> ProxyConfig {
+        ProxyConfig {
+            http_proxy: Some("http://proxy.corp:3128".to_string()),
+            https_proxy: Some("http://proxy.corp:3128".to_string()),
+            no_proxy: vec!["internal.corp".to_string()],
+            inject_pod_env: true,
+        }
+    }
+
+    #[test]
+    fn in_cluster_and_configured_hosts_bypass_the_proxy() {
+        let config = proxied();
+
+        assert!(config.bypasses("rustfs-io.storage.svc"));
+        assert!(config.bypasses("rustfs-io.storage.svc.cluster.local"));
+        assert!(config.bypasses("localhost"));
+        assert!(config.bypasses("[::1]"));
+        assert!(config.bypasses("internal.corp"));
+        assert!(config.bypasses("api.internal.corp"));
+        assert!(!config.bypasses("api.remote.example.com"));
+        assert!(!config.bypasses("notinternal.corp"));
+    }
+
+    #[test]
+    fn kube_config_uses_proxy_only_for_external_api_servers() {
+        let config = proxied();
+
+        let mut remote = kube::Config::new("https://api.remote.example.com:6443".parse().unwrap());
+        config.apply_to_kube_config(&mut remote);
+        assert_eq!(
+            remote.proxy_url.map(|uri| uri.to_string()),
+            Some("http://proxy.corp:3128/".to_string())
+        );
+
+        let mut local = kube::Config::new("https://kubernetes.default.svc".parse().unwrap());
+        config.apply_to_kube_config(&mut local);
+        assert!(local.proxy_url.is_none());
+    }
+
+    #[test]
+    fn pod_env_is_opt_in_and_keeps_user_values() {
+        let mut config = proxied();
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.env.push(corev1::EnvVar {
+            name: "HTTPS_PROXY".to_string(),
+            value: Some("http://user.proxy:8080".to_string()),
+            ..Default::default()
+        });
+
+        let rendered = config.render_tenant(Cow::Borrowed(&tenant));
+        let value = |name: &str| {
+            rendered
+                .spec
+                .env
+                .iter()
+                .find(|env| env.name == name)
+                .and_then(|env| env.value.clone())
+        };
+        assert_eq!(
+            value("HTTPS_PROXY").as_deref(),
+            Some("http://user.proxy:8080")
+        );
+        assert_eq!(
+            value("HTTP_PROXY").as_deref(),
+            Some("http://proxy.corp:3128")
+        );
+        assert!(value("NO_PROXY").is_some_and(|hosts| hosts.contains(".svc")));
+
+        config.inject_pod_env = false;
+        assert!(matches!(
+            config.render_tenant(Cow::Borrowed(&tenant)),
+            Cow::Borrowed(_)
+        ));
+    }
+}
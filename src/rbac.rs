@@ -0,0 +1,446 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ClusterRoles derived from the resources the operator manages.
+//!
+//! `rustfs-operator rbac` renders these rules; the Helm chart's ClusterRole is kept in sync
+//! with [`OPERATOR_RULES`] by a test.
+
+use k8s_openapi::api::rbac::v1 as rbacv1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use std::collections::BTreeMap;
+
+/// Default name of the operator ClusterRole.
+pub const OPERATOR_CLUSTER_ROLE_NAME: &str = "rustfs-operator";
+
+const ALL_VERBS: &[&str] = &[
+    "get", "list", "watch", "create", "update", "patch", "delete",
+];
+const READ_VERBS: &[&str] = &["get", "list", "watch"];
+
+/// One rule of the operator ClusterRole and the reason the operator needs it.
+pub struct OperatorRule {
+    pub api_group: &'static str,
+    pub resources: &'static [&'static str],
+    pub resource_names: &'static [&'static str],
+    pub verbs: &'static [&'static str],
+    pub reason: &'static str,
+}
+
+impl OperatorRule {
+    const fn new(
+        api_group: &'static str,
+        resources: &'static [&'static str],
+        verbs: &'static [&'static str],
+        reason: &'static str,
+    ) -> Self {
+        Self {
+            api_group,
+            resources,
+            resource_names: &[],
+            verbs,
+            reason,
+        }
+    }
+
+    fn policy_rule(&self) -> rbacv1::PolicyRule {
+        rbacv1::PolicyRule {
+            api_groups: Some(vec![self.api_group.to_string()]),
+            resources: Some(strings(self.resources)),
+            resource_names: (!self.resource_names.is_empty()).then(|| strings(self.resource_names)),
+            verbs: strings(self.verbs),
+            ..Default::default()
+        }
+    }
+}
+
+/// Every API the controller and its background tasks call.
+pub const OPERATOR_RULES: &[OperatorRule] = &[
+    OperatorRule::new(
+        "rustfs.com",
+        &["tenants"],
+        &["get", "list", "watch", "update", "patch"],
+        "Tenant resources",
+    ),
+    OperatorRule::new(
+        "rustfs.com",
+        &["tenants/status"],
+        &["update", "patch"],
+        "Tenant status",
+    ),
+    OperatorRule::new(
+        "rustfs.com",
+        &["remoteclusters"],
+        READ_VERBS,
+        "Remote clusters referenced by spec.federation.mirrors",
+    ),
+    OperatorRule::new(
+        "",
+        &[
+            "configmaps",
+            "secrets",
+            "serviceaccounts",
+            "pods",
+            "services",
+        ],
+        ALL_VERBS,
+        "Core resources managed by the operator",
+    ),
+    OperatorRule::new("", &["pods/log"], &["get"], "Pod logs"),
+    OperatorRule::new(
+        "policy",
+        &["poddisruptionbudgets"],
+        &["get", "list"],
+        "Deferring updates during disruptions",
+    ),
+    OperatorRule::new(
+        "scheduling.k8s.io",
+        &["priorityclasses"],
+        &["get"],
+        "PriorityClass existence check",
+    ),
+    OperatorRule::new("", &["nodes"], READ_VERBS, "Node down detection"),
+    OperatorRule::new(
+        "rbac.authorization.k8s.io",
+        &["roles", "rolebindings"],
+        ALL_VERBS,
+        "RBAC resources created for tenants",
+    ),
+    OperatorRule::new(
+        "sts.rustfs.com",
+        &["policybindings"],
+        ALL_VERBS,
+        "STS / PolicyBinding authorization flow",
+    ),
+    OperatorRule::new(
+        "authentication.k8s.io",
+        &["tokenreviews"],
+        &["create"],
+        "STS token review",
+    ),
+    OperatorRule::new(
+        "apps",
+        &["statefulsets"],
+        ALL_VERBS,
+        "StatefulSets for tenant pools",
+    ),
+    OperatorRule::new(
+        "apps",
+        &["deployments"],
+        &["get", "list", "watch", "patch"],
+        "Workload bucket bindings (rustfs.com/bind-bucket)",
+    ),
+    OperatorRule::new(
+        "objectstorage.k8s.io",
+        &["buckets", "bucketaccesses"],
+        &["get", "list", "watch", "update", "patch"],
+        "COSI driver for cosi.rustfs.com",
+    ),
+    OperatorRule::new(
+        "objectstorage.k8s.io",
+        &["buckets/status", "bucketaccesses/status"],
+        &["update", "patch"],
+        "COSI Bucket and BucketAccess status",
+    ),
+    OperatorRule::new(
+        "objectstorage.k8s.io",
+        &["bucketclaims", "bucketclasses", "bucketaccessclasses"],
+        READ_VERBS,
+        "COSI claims and classes",
+    ),
+    OperatorRule::new(
+        "cert-manager.io",
+        &["certificates"],
+        &["get", "list", "watch", "create", "patch", "update"],
+        "cert-manager Certificate orchestration",
+    ),
+    OperatorRule::new(
+        "cert-manager.io",
+        &["certificates/status"],
+        &["patch"],
+        "cert-manager renewal of expiring certificates",
+    ),
+    OperatorRule::new(
+        "cert-manager.io",
+        &["issuers", "clusterissuers"],
+        READ_VERBS,
+        "cert-manager issuer readiness",
+    ),
+    OperatorRule {
+        api_group: "apiextensions.k8s.io",
+        resources: &["customresourcedefinitions"],
+        resource_names: &["certificates.cert-manager.io"],
+        verbs: &["get"],
+        reason: "cert-manager installation check",
+    },
+    OperatorRule::new(
+        "monitoring.coreos.com",
+        &["prometheusrules"],
+        &["get", "create", "patch", "delete"],
+        "Per-Tenant alerting rules",
+    ),
+    OperatorRule::new(
+        "external-secrets.io",
+        &["externalsecrets"],
+        &["get", "create", "patch", "delete"],
+        "ExternalSecrets listed in spec.additionalResources",
+    ),
+    OperatorRule::new(
+        "",
+        &["persistentvolumeclaims"],
+        &["get", "list", "watch", "create", "patch", "delete"],
+        "Handover and snapshot restore claims, failed drive replacement, and stuck deletion cleanup",
+    ),
+    OperatorRule::new(
+        "",
+        &["persistentvolumes"],
+        &["get", "create", "patch"],
+        "Released volume handover and static local volumes",
+    ),
+    OperatorRule::new(
+        "snapshot.storage.k8s.io",
+        &["volumesnapshots"],
+        &["get"],
+        "Snapshot restore into new pools",
+    ),
+    OperatorRule::new(
+        "",
+        &["events"],
+        &["get", "list", "watch", "create", "patch"],
+        "Reconcile events (core)",
+    ),
+    OperatorRule::new(
+        "events.k8s.io",
+        &["events"],
+        &["get", "list", "watch", "create", "patch"],
+        "Reconcile events (events.k8s.io)",
+    ),
+    OperatorRule::new(
+        "coordination.k8s.io",
+        &["leases"],
+        ALL_VERBS,
+        "Leader election",
+    ),
+];
+
+/// Extra rules the Console ServiceAccount needs with `CONSOLE_AUTH_MODE=impersonation`.
+///
+/// What a Console user may do is still decided by the RBAC of the impersonated user; these rules
+/// only let the Console authenticate logins and act on the user's behalf.
+pub const CONSOLE_IMPERSONATION_RULES: &[OperatorRule] = &[
+    OperatorRule::new(
+        "authentication.k8s.io",
+        &["tokenreviews"],
+        &["create"],
+        "Authenticate Console login tokens",
+    ),
+    OperatorRule::new(
+        "",
+        &["users", "groups", "serviceaccounts"],
+        &["impersonate"],
+        "Act as the logged-in user for Console API calls",
+    ),
+    OperatorRule::new(
+        "authentication.k8s.io",
+        &["uids", "userextras/*"],
+        &["impersonate"],
+        "Carry the logged-in user's UID and extra attributes, such as token scopes",
+    ),
+];
+
+/// ClusterRole granting the operator exactly [`OPERATOR_RULES`].
+pub fn operator_cluster_role(name: &str) -> rbacv1::ClusterRole {
+    rbacv1::ClusterRole {
+        metadata: metav1::ObjectMeta {
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        rules: Some(
+            OPERATOR_RULES
+                .iter()
+                .map(OperatorRule::policy_rule)
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+/// ClusterRole granting the Console ServiceAccount [`CONSOLE_IMPERSONATION_RULES`].
+pub fn console_impersonation_cluster_role(name: &str) -> rbacv1::ClusterRole {
+    rbacv1::ClusterRole {
+        metadata: metav1::ObjectMeta {
+            name: Some(format!("{name}-console-impersonation")),
+            ..Default::default()
+        },
+        rules: Some(
+            CONSOLE_IMPERSONATION_RULES
+                .iter()
+                .map(OperatorRule::policy_rule)
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+/// ClusterRoles aggregated into the built-in `admin`, `edit` and `view` roles, so namespace users
+/// can manage Tenants and PolicyBindings without a separate grant.
+pub fn aggregated_cluster_roles(prefix: &str) -> Vec<rbacv1::ClusterRole> {
+    let aggregated = |suffix: &str, targets: &[&str], verbs: &[&str]| rbacv1::ClusterRole {
+        metadata: metav1::ObjectMeta {
+            name: Some(format!("{prefix}-{suffix}")),
+            labels: Some(
+                targets
+                    .iter()
+                    .map(|target| {
+                        (
+                            format!("rbac.authorization.k8s.io/aggregate-to-{target}"),
+                            "true".to_string(),
+                        )
+                    })
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            ..Default::default()
+        },
+        rules: Some(vec![
+            rbacv1::PolicyRule {
+                api_groups: Some(vec!["rustfs.com".to_string()]),
+                resources: Some(strings(&["tenants"])),
+                verbs: strings(verbs),
+                ..Default::default()
+            },
+            rbacv1::PolicyRule {
+                api_groups: Some(vec!["sts.rustfs.com".to_string()]),
+                resources: Some(strings(&["policybindings"])),
+                verbs: strings(verbs),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+
+    vec![
+        aggregated("edit", &["admin", "edit"], ALL_VERBS),
+        aggregated("view", &["view"], READ_VERBS),
+    ]
+}
+
+/// Operator ClusterRole followed by the aggregated ClusterRoles, as a multi-document YAML stream.
+/// With `console_impersonation`, the Console impersonation ClusterRole is appended.
+pub fn render_rbac_yaml(
+    name: &str,
+    console_impersonation: bool,
+) -> Result<String, serde_yaml_ng::Error> {
+    let mut documents = vec![serde_yaml_ng::to_string(&with_type_meta(
+        operator_cluster_role(name),
+    ))?];
+    for role in aggregated_cluster_roles(name) {
+        documents.push(serde_yaml_ng::to_string(&with_type_meta(role))?);
+    }
+    if console_impersonation {
+        documents.push(serde_yaml_ng::to_string(&with_type_meta(
+            console_impersonation_cluster_role(name),
+        ))?);
+    }
+    Ok(documents.join("---\n"))
+}
+
+fn with_type_meta(role: rbacv1::ClusterRole) -> serde_json::Value {
+    let mut value = serde_json::to_value(role).unwrap_or_default();
+    if let Some(object) = value.as_object_mut() {
+        object.insert(
+            "apiVersion".to_string(),
+            "rbac.authorization.k8s.io/v1".into(),
+        );
+        object.insert("kind".to_string(), "ClusterRole".into());
+    }
+    value
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| (*value).to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rules of a Helm ClusterRole template with template directives removed.
+    fn template_rules(template: &str) -> Vec<rbacv1::PolicyRule> {
+        let yaml: String = template
+            .lines()
+            .filter(|line| !line.contains("{{"))
+            .map(|line| format!("{line}\n"))
+            .collect();
+        serde_yaml_ng::from_str::<rbacv1::ClusterRole>(&yaml)
+            .map(|role| role.rules.unwrap_or_default())
+            .unwrap_or_else(|error| panic!("template should parse: {error}"))
+    }
+
+    #[test]
+    fn helm_cluster_role_matches_operator_rules() {
+        let template = include_str!("../deploy/rustfs-operator/templates/clusterrole.yaml");
+
+        assert_eq!(
+            template_rules(template),
+            operator_cluster_role(OPERATOR_CLUSTER_ROLE_NAME)
+                .rules
+                .unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn aggregated_roles_carry_aggregation_labels() {
+        let roles = aggregated_cluster_roles("rustfs-operator");
+
+        assert_eq!(roles.len(), 2);
+        let labels = roles[0].metadata.labels.clone().unwrap_or_default();
+        assert_eq!(
+            labels
+                .get("rbac.authorization.k8s.io/aggregate-to-edit")
+                .map(String::as_str),
+            Some("true")
+        );
+        let view_rules = roles[1].rules.clone().unwrap_or_default();
+        assert!(
+            view_rules
+                .iter()
+                .all(|rule| !rule.verbs.contains(&"delete".to_string()))
+        );
+    }
+
+    #[test]
+    fn rendered_rbac_has_kind_and_api_version() {
+        let yaml = render_rbac_yaml("rustfs-operator", false).unwrap_or_default();
+
+        assert_eq!(yaml.matches("kind: ClusterRole").count(), 3);
+        assert!(yaml.contains("apiVersion: rbac.authorization.k8s.io/v1"));
+    }
+
+    #[test]
+    fn console_impersonation_role_matches_helm_template() {
+        let yaml = render_rbac_yaml("rustfs-operator", true).unwrap_or_default();
+        assert_eq!(yaml.matches("kind: ClusterRole").count(), 4);
+        assert!(yaml.contains("name: rustfs-operator-console-impersonation"));
+
+        let template = include_str!(
+            "../deploy/rustfs-operator/templates/console-impersonation-clusterrole.yaml"
+        );
+        assert_eq!(
+            template_rules(template),
+            console_impersonation_cluster_role("rustfs-operator")
+                .rules
+                .unwrap_or_default()
+        );
+    }
+}
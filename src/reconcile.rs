@@ -14,23 +14,50 @@
 
 use crate::context::Context;
 use crate::status::{StatusBuilder, StatusError};
-use crate::types::v1alpha1::status::{ConditionType, Reason, Status};
+use crate::types::v1alpha1::status::{ConditionType, Reason, RequeueReason, Status};
 use crate::types::v1alpha1::tenant::Tenant;
 use crate::{context, types};
 use k8s_openapi::api::core::v1 as corev1;
 use kube::ResourceExt;
-use kube::api::{DeleteParams, ListParams, PropagationPolicy};
+use kube::api::{DeleteParams, PropagationPolicy};
 use kube::runtime::controller::Action;
 use kube::runtime::events::EventType;
 use snafu::Snafu;
+use std::collections::BTreeSet;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
+mod additional_resources;
+mod adoption;
+mod alerting;
+mod canary;
+mod certificate;
+mod deletion;
+mod disruption;
+mod drives;
+mod env_from;
+mod event_notifications;
+pub(crate) mod federation;
+pub(crate) mod gating;
+pub mod maintenance;
+pub(crate) mod namespace_policy;
+pub(crate) mod notifications;
 mod phases;
+mod pod_health;
 mod pool_lifecycle;
-mod provisioning;
+pub(crate) mod priority;
+pub(crate) mod provisioning;
+mod rebalance;
+mod restore;
+mod rollout_events;
+mod services;
+pub(crate) mod summary;
+mod teardown;
 mod tls;
+mod upgrade;
+mod verification;
+mod zone_spread;
 
 use phases::{
     cleanup_removed_decommissioned_pool_statefulsets, finalize_tenant_status,
@@ -52,9 +79,24 @@ pub enum Error {
 
     #[snafu(display("TLS reconciliation pending ({reason}): {message}"))]
     TlsPending { reason: String, message: String },
+
+    #[snafu(display("image upgrade blocked: {message}"))]
+    UpgradeBlocked { message: String },
+
+    #[snafu(display("waiting for external credential Secret '{name}'"))]
+    CredentialsPending { name: String },
 }
 
 pub async fn reconcile_rustfs(tenant: Arc<Tenant>, ctx: Arc<Context>) -> Result<Action, Error> {
+    let result = reconcile_tenant(tenant.clone(), ctx.clone()).await;
+    if let Err(error) = &result {
+        record_error_backoff(&ctx, &tenant, tenant_error_requeue_interval(&tenant, error)).await;
+    }
+    result
+}
+
+async fn reconcile_tenant(tenant: Arc<Tenant>, ctx: Arc<Context>) -> Result<Action, Error> {
+    let started = Instant::now();
     let ns = tenant.namespace()?;
     let latest_tenant = ctx.get::<Tenant>(&tenant.name(), &ns).await?;
 
@@ -65,15 +107,63 @@ pub async fn reconcile_rustfs(tenant: Arc<Tenant>, ctx: Arc<Context>) -> Result<
             deletion_timestamp = ?latest_tenant.metadata.deletion_timestamp,
             "tenant is deleting; skipping reconcile"
         );
+        let status = StatusBuilder::from_tenant(&latest_tenant).build();
+        if let Err(error) = ctx.patch_status_if_changed(&latest_tenant, status).await {
+            debug!(tenant = %tenant.name(), namespace = %ns, %error, "failed to record Deleting lifecycle phase");
+        }
+        if teardown::reconcile_deleting(&ctx, &latest_tenant, &ns).await
+            && !deletion::is_stuck(&latest_tenant)
+        {
+            return Ok(Action::requeue(teardown::WAIT_INTERVAL));
+        }
+        return Ok(deletion::reconcile_deleting(&ctx, &latest_tenant, &ns).await);
+    }
+
+    // Deletion is handled above so finalizers still run while maintenance is active.
+    if let Some(message) = ctx.maintenance.active(&ctx).await? {
+        ctx.reconcile_fingerprints
+            .forget(&format!("{}/{}", ns, latest_tenant.name()));
+        return maintenance::reconcile_status_only(&ctx, &latest_tenant, &ns, message).await;
+    }
+
+    teardown::ensure_finalizer(&ctx, &latest_tenant).await?;
+
+    let fingerprint_key = format!("{}/{}", ns, latest_tenant.name());
+    let fingerprint = gating::child_fingerprint(&ctx, &latest_tenant, &ns);
+    if gating::is_settled(&latest_tenant)
+        && fingerprint.as_deref().is_some_and(|fingerprint| {
+            ctx.reconcile_fingerprints
+                .matches(&fingerprint_key, fingerprint)
+        })
+    {
+        debug!(
+            tenant = %tenant.name(),
+            namespace = %ns,
+            "tenant spec and children unchanged since last reconcile; skipping"
+        );
         return Ok(Action::await_change());
     }
+    ctx.reconcile_fingerprints.forget(&fingerprint_key);
 
     if should_mark_reconcile_started(&latest_tenant) {
         patch_reconcile_started(&ctx, &latest_tenant).await;
     }
 
+    let latest_tenant =
+        additional_resources::reconcile_additional_resources(&ctx, latest_tenant, &ns).await?;
+
     validate_tenant_prerequisites(&ctx, &latest_tenant).await?;
-    let tls_plan = tls::reconcile_tls(&ctx, &latest_tenant, &ns).await?;
+    let mut tls_plan = tls::reconcile_tls(&ctx, &latest_tenant, &ns).await?;
+    env_from::annotate(&ctx, &latest_tenant, &ns, &mut tls_plan).await?;
+    event_notifications::annotate(&ctx, &latest_tenant, &ns, &mut tls_plan).await?;
+    zone_spread::detect(&ctx, &latest_tenant, &mut tls_plan).await?;
+    certificate::renew(&ctx, &latest_tenant, &ns, &tls_plan).await;
+    crate::metrics::record_tenant_tls_expiry(
+        &ns,
+        &latest_tenant.name(),
+        certificate::not_after(&tls_plan).map(|time| time.timestamp()),
+    );
+    alerting::reconcile_prometheus_rule(&ctx, &latest_tenant, &ns).await;
 
     maybe_cleanup_terminating_pods(&ctx, &latest_tenant, &ns).await?;
 
@@ -94,16 +184,27 @@ pub async fn reconcile_rustfs(tenant: Arc<Tenant>, ctx: Arc<Context>) -> Result<
 
     let lifecycle_decisions = reconcile_pool_lifecycle(&ctx, &latest_tenant, &ns).await?;
 
+    upgrade::preflight_image_upgrade(&ctx, &latest_tenant, &ns).await?;
+    let canary_plan = canary::plan_canary_upgrade(&ctx, &latest_tenant, &ns).await?;
+
     let summary = reconcile_pool_statefulsets(
         &ctx,
-        &latest_tenant,
+        &ctx.proxy
+            .render_tenant(canary_plan.render_tenant(&latest_tenant)),
         &ns,
         &tls_plan,
         &lifecycle_decisions,
         &removed_pool_cleanup,
+        canary_plan,
     )
     .await?;
-    finalize_tenant_status(&ctx, &latest_tenant, summary, tls_plan).await
+    let action = finalize_tenant_status(&ctx, &latest_tenant, summary, tls_plan, started).await?;
+
+    if let Some(fingerprint) = gating::child_fingerprint(&ctx, &latest_tenant, &ns) {
+        ctx.reconcile_fingerprints
+            .record(fingerprint_key, fingerprint);
+    }
+    Ok(action)
 }
 
 #[cfg(test)]
@@ -144,6 +245,12 @@ async fn types_result<T>(
 async fn patch_status_error(ctx: &Context, tenant: &Tenant, status_error: &StatusError) {
     let mut builder = StatusBuilder::from_tenant(tenant);
     builder.mark_error(status_error);
+    if let Ok(namespace) = tenant.namespace() {
+        // Counts the reconcile that is failing now; the metric is recorded once it returns.
+        builder.set_reconcile_error_count(
+            crate::metrics::tenant_reconcile_errors(&namespace, &tenant.name()) + 1,
+        );
+    }
     let status = builder.build();
     let should_record =
         condition_marker_changed(tenant.status.as_ref(), &status, status_error.condition_type);
@@ -169,14 +276,7 @@ async fn patch_status_error(ctx: &Context, tenant: &Tenant, status_error: &Statu
                 "patched Tenant status for reconcile error"
             );
         }
-        Ok(None) => {
-            debug!(
-                tenant = %tenant.name(),
-                namespace = ?tenant.namespace(),
-                reason = status_error.reason.as_str(),
-                "skipped Tenant status patch because error status is unchanged"
-            );
-        }
+        Ok(None) => {}
         Err(error) => {
             warn!(
                 tenant = %tenant.name(),
@@ -240,13 +340,7 @@ async fn patch_reconcile_started(ctx: &Context, tenant: &Tenant) {
                 "patched Tenant ReconcileStarted status"
             );
         }
-        Ok(None) => {
-            debug!(
-                tenant = %tenant.name(),
-                namespace = ?tenant.namespace(),
-                "ReconcileStarted status patch was a no-op"
-            );
-        }
+        Ok(None) => {}
         Err(error) => {
             warn!(
                 tenant = %tenant.name(),
@@ -292,29 +386,18 @@ async fn patch_status_and_record(
     message: &str,
 ) -> Result<(), Error> {
     let should_record = condition_marker_changed(tenant.status.as_ref(), &status, condition_type);
-    let patched = ctx.patch_status_if_changed(tenant, status).await?;
-    match patched {
-        Some(_) => {
-            info!(
-                tenant = %tenant.name(),
-                namespace = ?tenant.namespace(),
-                reason = reason.as_str(),
-                condition = condition_type.as_str(),
-                "patched Tenant status after reconciliation"
-            );
-            if should_record {
-                let _ = ctx
-                    .record(tenant, event_type, reason.as_str(), message)
-                    .await;
-            }
-        }
-        None => {
-            debug!(
-                tenant = %tenant.name(),
-                namespace = ?tenant.namespace(),
-                reason = reason.as_str(),
-                "skipped Tenant status patch because reconciled status is unchanged"
-            );
+    if ctx.patch_status_if_changed(tenant, status).await?.is_some() {
+        info!(
+            tenant = %tenant.name(),
+            namespace = ?tenant.namespace(),
+            reason = reason.as_str(),
+            condition = condition_type.as_str(),
+            "patched Tenant status after reconciliation"
+        );
+        if should_record {
+            let _ = ctx
+                .record(tenant, event_type, reason.as_str(), message)
+                .await;
         }
     }
     Ok(())
@@ -355,25 +438,38 @@ async fn cleanup_stuck_terminating_pods_on_down_nodes(
     tenant: &Tenant,
     namespace: &str,
     ctx: &Context,
-    policy: crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown,
 ) -> Result<(), Error> {
+    use crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown as P;
+
     let pods_api: kube::Api<corev1::Pod> = kube::Api::namespaced(ctx.client.clone(), namespace);
     let nodes_api: kube::Api<corev1::Node> = kube::Api::all(ctx.client.clone());
-
-    let selector = format!("rustfs.tenant={}", tenant.name());
-    let pods = pods_api
-        .list(&ListParams::default().labels(&selector))
-        .await
-        .map_err(|source| Error::Context {
-            source: context::Error::Kube { source },
-        })?;
-
-    for pod in pods.items {
+    let cleanup = tenant.spec.node_down_cleanup.clone().unwrap_or_default();
+    let dry_run = cleanup.dry_run.unwrap_or(false);
+    let cooldown = Duration::from_secs(u64::from(
+        cleanup
+            .cooldown_seconds
+            .unwrap_or(DEFAULT_NODE_DOWN_COOLDOWN_SECONDS),
+    ));
+
+    let pods = ctx
+        .list_labeled::<corev1::Pod>(namespace, &tenant.pod_selector())
+        .await?;
+
+    // Nodes whose pods were deleted during this pass; the cooldown only applies across passes so
+    // every stuck pod on a node that just went down is handled together.
+    let mut acted_on: BTreeSet<String> = BTreeSet::new();
+
+    for pod in pods {
         // Only act on terminating pods to keep the behavior conservative.
         if pod.metadata.deletion_timestamp.is_none() {
             continue;
         }
 
+        let policy = node_down_policy_for_pod(tenant, &pod);
+        if policy == P::DoNothing {
+            continue;
+        }
+
         // Longhorn behavior: only force delete terminating pods managed by a controller.
         // We approximate controller type via ownerReferences:
         // - StatefulSet pod: owner kind == "StatefulSet"
@@ -401,6 +497,37 @@ async fn cleanup_stuck_terminating_pods_on_down_nodes(
         }
 
         let pod_name = pod.name_any();
+        if dry_run {
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Normal,
+                    "WouldDeletePodOnDownNode",
+                    &format!(
+                        "Pod '{}' is terminating on down node '{}'; policy {:?} would delete it (dry run)",
+                        pod_name, node_name, policy
+                    ),
+                )
+                .await;
+            continue;
+        }
+
+        let cooldown_key = format!("{}/{}/{}", namespace, tenant.name(), node_name);
+        if !acted_on.contains(&node_name)
+            && ctx
+                .node_down_deletions
+                .cooling_down(&cooldown_key, cooldown)
+        {
+            debug!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                node = %node_name,
+                pod = %pod_name,
+                "skipping node-down pod deletion during cooldown"
+            );
+            continue;
+        }
+
         warn!(
             tenant = %tenant.name(),
             namespace = %namespace,
@@ -410,42 +537,34 @@ async fn cleanup_stuck_terminating_pods_on_down_nodes(
             "terminating pod is scheduled on a down node"
         );
         let delete_params = match policy {
-            crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DoNothing => continue,
+            P::DoNothing => continue,
             // Legacy option: normal delete.
-            crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::Delete => {
-                DeleteParams::default()
-            }
+            P::Delete => DeleteParams::default(),
             // Legacy option: explicit force delete.
-            crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::ForceDelete
+            P::ForceDelete
             // Longhorn-compatible options: always force delete.
-            | crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DeleteStatefulSetPod
-            | crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DeleteDeploymentPod
-            | crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DeleteBothStatefulSetAndDeploymentPod => {
-                DeleteParams {
-                    grace_period_seconds: Some(0),
-                    propagation_policy: Some(PropagationPolicy::Background),
-                    ..DeleteParams::default()
-                }
-            }
+            | P::DeleteStatefulSetPod
+            | P::DeleteDeploymentPod
+            | P::DeleteBothStatefulSetAndDeploymentPod => DeleteParams {
+                grace_period_seconds: Some(0),
+                propagation_policy: Some(PropagationPolicy::Background),
+                ..DeleteParams::default()
+            },
         };
 
         match pods_api.delete(&pod_name, &delete_params).await {
             Ok(_) => {
+                acted_on.insert(node_name.clone());
+                ctx.node_down_deletions.record(cooldown_key);
                 let reason = match policy {
-                    crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::ForceDelete => {
-                        "ForceDeletedPodOnDownNode"
-                    }
-                    crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::Delete => {
-                        "DeletedPodOnDownNode"
-                    }
-                    crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DeleteStatefulSetPod
-                    | crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DeleteDeploymentPod
-                    | crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DeleteBothStatefulSetAndDeploymentPod => {
+                    P::ForceDelete => "ForceDeletedPodOnDownNode",
+                    P::Delete => "DeletedPodOnDownNode",
+                    P::DeleteStatefulSetPod
+                    | P::DeleteDeploymentPod
+                    | P::DeleteBothStatefulSetAndDeploymentPod => {
                         "LonghornLikeForceDeletedPodOnDownNode"
                     }
-                    crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DoNothing => {
-                        ""
-                    }
+                    P::DoNothing => "",
                 };
                 let _ = ctx
                     .record(
@@ -473,6 +592,36 @@ async fn cleanup_stuck_terminating_pods_on_down_nodes(
     Ok(())
 }
 
+/// Default `spec.nodeDownCleanup.cooldownSeconds`.
+const DEFAULT_NODE_DOWN_COOLDOWN_SECONDS: u32 = 300;
+
+/// Effective node-down deletion policy for a pod: its pool's policy when set, else the Tenant's.
+fn node_down_policy_for_pod(
+    tenant: &Tenant,
+    pod: &corev1::Pod,
+) -> crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown {
+    let pool = pod
+        .labels()
+        .get(crate::types::v1alpha1::tenant::POOL_LABEL)
+        .and_then(|name| tenant.spec.pools.iter().find(|p| &p.name == name));
+    pool.and_then(|p| p.pod_deletion_policy_when_node_is_down.clone())
+        .or_else(|| tenant.spec.pod_deletion_policy_when_node_is_down.clone())
+        .unwrap_or_default()
+}
+
+/// Whether the Tenant or any of its pools opts into the node-down pod cleanup.
+pub(super) fn node_down_cleanup_enabled(tenant: &Tenant) -> bool {
+    use crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown as P;
+
+    let active = |policy: &Option<P>| policy.as_ref().is_some_and(|p| *p != P::DoNothing);
+    active(&tenant.spec.pod_deletion_policy_when_node_is_down)
+        || tenant
+            .spec
+            .pools
+            .iter()
+            .any(|p| active(&p.pod_deletion_policy_when_node_is_down))
+}
+
 fn pod_matches_policy_controller_kind(
     pod: &corev1::Pod,
     policy: &crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown,
@@ -526,11 +675,115 @@ pub fn error_policy(object: Arc<Tenant>, error: &Error, _ctx: Arc<Context>) -> A
     // The reconcile function sets appropriate conditions (Ready=False, Degraded=True)
     // and records events for failures before propagating errors.
     // This error_policy function only determines requeue strategy.
+    let requeue = tenant_error_requeue_interval(&object, error);
+
+    warn!(
+        tenant = %object.name(),
+        namespace = ?object.namespace(),
+        reason = reconcile_error_reason(error),
+        class = error_class(error),
+        requeue_seconds = requeue.as_secs(),
+        %error,
+        "reconcile failed; scheduling retry"
+    );
 
+    requeue_after(requeue)
+}
+
+/// Surfaces the retry of a failed reconcile as `requeueReason: ErrorBackoff`. The status is
+/// only written when the reason changes, so repeated failures do not re-trigger the Tenant watch
+/// ahead of the backoff.
+async fn record_error_backoff(ctx: &Context, tenant: &Tenant, requeue: Duration) {
+    let Ok(namespace) = tenant.namespace() else {
+        return;
+    };
+    // Re-read the Tenant: the failed reconcile may have just patched its error conditions.
+    let Ok(latest) = ctx.get::<Tenant>(&tenant.name(), &namespace).await else {
+        return;
+    };
+    let mut status = latest.status.clone().unwrap_or_default();
+    if status.requeue_reason.as_deref() == Some(RequeueReason::ErrorBackoff.as_str()) {
+        return;
+    }
+    status.next_reconcile_time = Some(crate::status::next_reconcile_time(requeue));
+    status.requeue_reason = Some(RequeueReason::ErrorBackoff.as_str().to_string());
+    if let Err(error) = ctx.patch_status_if_changed(&latest, status).await {
+        debug!(tenant = %tenant.name(), namespace, %error, "failed to record error backoff");
+    }
+}
+
+/// Prefix of the Tenant annotations that replace the retry interval of one error class, for
+/// example `rustfs.com/requeue-on-immutable: 300s`.
+const REQUEUE_ANNOTATION_PREFIX: &str = "rustfs.com/requeue-on-";
+const MIN_REQUEUE_OVERRIDE: Duration = Duration::from_secs(5);
+const MAX_REQUEUE_OVERRIDE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Retry interval after a failed reconcile, honoring the Tenant's requeue annotation for the
+/// error class. Unparseable values fall back to the default interval.
+fn tenant_error_requeue_interval(tenant: &Tenant, error: &Error) -> Duration {
+    let key = format!("{REQUEUE_ANNOTATION_PREFIX}{}", error_class(error));
+    tenant
+        .annotations()
+        .get(&key)
+        .and_then(|value| parse_requeue_duration(value))
+        .map(|duration| duration.clamp(MIN_REQUEUE_OVERRIDE, MAX_REQUEUE_OVERRIDE))
+        .unwrap_or_else(|| error_requeue_interval(error))
+}
+
+/// Parses `300`, `300s`, `5m`, or `1h`.
+fn parse_requeue_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (number, unit) = match value.char_indices().last()? {
+        (index, 's') => (&value[..index], 1),
+        (index, 'm') => (&value[..index], 60),
+        (index, 'h') => (&value[..index], 60 * 60),
+        _ => (value, 1),
+    };
+    let seconds = number.trim().parse::<u64>().ok()?.checked_mul(unit)?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// Error class named by the `rustfs.com/requeue-on-<class>` annotations.
+fn error_class(error: &Error) -> &'static str {
+    match error {
+        Error::Context { source } => match source {
+            context::Error::CredentialSecretNotFound { .. }
+            | context::Error::CredentialSecretMissingKey { .. }
+            | context::Error::CredentialSecretInvalidEncoding { .. }
+            | context::Error::CredentialSecretTooShort { .. }
+            | context::Error::KmsSecretNotFound { .. }
+            | context::Error::KmsSecretMissingKey { .. }
+            | context::Error::KmsConfigInvalid { .. } => "credentials",
+            context::Error::ExistingHeadlessServiceInvalid { .. }
+            | context::Error::ExistingClaimUnavailable { .. }
+            | context::Error::SnapshotSourceUnavailable { .. } => "invalid-spec",
+            context::Error::Kube { .. } | context::Error::Record { .. } => "api",
+            _ => "other",
+        },
+        Error::Types { source } => match source {
+            types::error::Error::ImmutableFieldModified { .. }
+            | types::error::Error::PoolDeleteBlocked { .. } => "immutable",
+            types::error::Error::InvalidTenantName { .. }
+            | types::error::Error::InvalidPoolSpec { .. }
+            | types::error::Error::InvalidPortSpec { .. }
+            | types::error::Error::InvalidRbacSpec { .. }
+            | types::error::Error::ProtectedEnvOverride { .. }
+            | types::error::Error::NamespaceTenantConflict { .. }
+            | types::error::Error::NamespaceNotAllowed { .. } => "invalid-spec",
+            _ => "other",
+        },
+        Error::TlsBlocked { .. } | Error::TlsPending { .. } => "tls",
+        Error::CredentialsPending { .. } => "credentials",
+        Error::UpgradeBlocked { .. } => "upgrade",
+    }
+}
+
+/// Retry interval after a failed reconcile.
+fn error_requeue_interval(error: &Error) -> Duration {
     // Use different requeue strategies based on error type:
     // - User-fixable errors (credentials, validation): Longer intervals to reduce spam
     // - Transient errors (API, network): Shorter intervals for quick recovery
-    let requeue = match error {
+    match error {
         Error::Context { source } => match source {
             // Credential / KMS validation errors - require user intervention
             // Use 60-second requeue to reduce event/log spam while user fixes the issue
@@ -540,7 +793,10 @@ pub fn error_policy(object: Arc<Tenant>, error: &Error, _ctx: Arc<Context>) -> A
             | context::Error::CredentialSecretTooShort { .. }
             | context::Error::KmsSecretNotFound { .. }
             | context::Error::KmsSecretMissingKey { .. }
-            | context::Error::KmsConfigInvalid { .. } => Duration::from_secs(60),
+            | context::Error::KmsConfigInvalid { .. }
+            | context::Error::ExistingHeadlessServiceInvalid { .. }
+            | context::Error::ExistingClaimUnavailable { .. }
+            | context::Error::SnapshotSourceUnavailable { .. } => Duration::from_secs(60),
 
             // Kubernetes API errors - might be transient (network, API server issues)
             // Use shorter requeue for faster recovery
@@ -556,6 +812,8 @@ pub fn error_policy(object: Arc<Tenant>, error: &Error, _ctx: Arc<Context>) -> A
             // Use 60-second requeue to reduce event/log spam while user fixes the issue
             types::error::Error::ImmutableFieldModified { .. }
             | types::error::Error::InvalidTenantName { .. }
+            | types::error::Error::NamespaceTenantConflict { .. }
+            | types::error::Error::NamespaceNotAllowed { .. }
             | types::error::Error::PoolDeleteBlocked { .. } => Duration::from_secs(60),
 
             // Other type errors - use moderate requeue
@@ -564,18 +822,10 @@ pub fn error_policy(object: Arc<Tenant>, error: &Error, _ctx: Arc<Context>) -> A
 
         Error::TlsBlocked { .. } => Duration::from_secs(60),
         Error::TlsPending { .. } => Duration::from_secs(20),
-    };
-
-    warn!(
-        tenant = %object.name(),
-        namespace = ?object.namespace(),
-        reason = reconcile_error_reason(error),
-        requeue_seconds = requeue.as_secs(),
-        %error,
-        "reconcile failed; scheduling retry"
-    );
-
-    requeue_after(requeue)
+        // The Secret watch enqueues the Tenant as soon as the Secret is created.
+        Error::CredentialsPending { .. } => Duration::from_secs(30),
+        Error::UpgradeBlocked { .. } => Duration::from_secs(60),
+    }
 }
 
 fn reconcile_error_reason(error: &Error) -> &'static str {
@@ -590,6 +840,11 @@ fn reconcile_error_reason(error: &Error) -> &'static str {
             context::Error::KmsSecretNotFound { .. } => "KmsSecretNotFound",
             context::Error::KmsSecretMissingKey { .. } => "KmsSecretMissingKey",
             context::Error::KmsConfigInvalid { .. } => "KmsConfigInvalid",
+            context::Error::ExistingHeadlessServiceInvalid { .. } => {
+                "ExistingHeadlessServiceInvalid"
+            }
+            context::Error::ExistingClaimUnavailable { .. } => "ExistingClaimUnavailable",
+            context::Error::SnapshotSourceUnavailable { .. } => "SnapshotSourceUnavailable",
             context::Error::Kube { .. } => "KubernetesApiError",
             context::Error::Record { .. } => "KubernetesEventRecordError",
             context::Error::Types { .. } => "TypeError",
@@ -598,6 +853,11 @@ fn reconcile_error_reason(error: &Error) -> &'static str {
         Error::Types { source } => match source {
             types::error::Error::InvalidTenantName { .. } => "InvalidTenantName",
             types::error::Error::InvalidPoolSpec { .. } => "InvalidPoolSpec",
+            types::error::Error::InvalidPortSpec { .. } => "InvalidPortSpec",
+            types::error::Error::InvalidRbacSpec { .. } => "InvalidRbacSpec",
+            types::error::Error::ProtectedEnvOverride { .. } => "ProtectedEnvOverride",
+            types::error::Error::NamespaceTenantConflict { .. } => "NamespaceTenantConflict",
+            types::error::Error::NamespaceNotAllowed { .. } => "NamespaceNotAllowed",
             types::error::Error::ImmutableFieldModified { .. } => "ImmutableFieldModified",
             types::error::Error::PoolDeleteBlocked { .. } => "PoolDeleteBlocked",
             types::error::Error::NoNamespace => "NoNamespace",
@@ -606,6 +866,8 @@ fn reconcile_error_reason(error: &Error) -> &'static str {
         },
         Error::TlsBlocked { .. } => "TlsBlocked",
         Error::TlsPending { .. } => "TlsPending",
+        Error::UpgradeBlocked { .. } => "UpgradeBlocked",
+        Error::CredentialsPending { .. } => "CredentialsPending",
     }
 }
 
@@ -613,12 +875,61 @@ fn reconcile_error_reason(error: &Error) -> &'static str {
 mod tests {
     use super::is_node_down;
     use super::{
+        Error, node_down_cleanup_enabled, node_down_policy_for_pod, parse_requeue_duration,
         pod_has_owner_kind, pod_matches_policy_controller_kind, should_create_rbac,
-        should_mark_reconcile_started,
+        should_mark_reconcile_started, tenant_error_requeue_interval,
     };
     use crate::types::v1alpha1::status::Status;
     use k8s_openapi::api::core::v1 as corev1;
     use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+    use std::time::Duration;
+
+    #[test]
+    fn requeue_annotation_overrides_interval_for_its_error_class() {
+        let immutable: Error = crate::types::error::Error::ImmutableFieldModified {
+            name: "test-tenant".to_string(),
+            field: "spec.pools".to_string(),
+            message: "servers changed".to_string(),
+        }
+        .into();
+        let upgrade = Error::UpgradeBlocked {
+            message: "major version skipped".to_string(),
+        };
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert_eq!(
+            tenant_error_requeue_interval(&tenant, &immutable),
+            Duration::from_secs(60)
+        );
+
+        tenant.metadata.annotations = Some(
+            [
+                ("rustfs.com/requeue-on-immutable", "5m"),
+                ("rustfs.com/requeue-on-upgrade", "1s"),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        );
+        assert_eq!(
+            tenant_error_requeue_interval(&tenant, &immutable),
+            Duration::from_secs(300)
+        );
+        // Overrides are clamped to at least 5 seconds.
+        assert_eq!(
+            tenant_error_requeue_interval(&tenant, &upgrade),
+            Duration::from_secs(5)
+        );
+
+        assert_eq!(
+            parse_requeue_duration("300"),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_requeue_duration("2h"),
+            Some(Duration::from_secs(7200))
+        );
+        assert_eq!(parse_requeue_duration("soon"), None);
+    }
 
     #[test]
     fn should_not_mark_reconcile_started_when_generation_is_current() {
@@ -744,6 +1055,58 @@ mod tests {
         assert!(is_node_down(&node));
     }
 
+    #[test]
+    fn test_node_down_policy_pool_override() {
+        use crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown as P;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let pod = corev1::Pod {
+            metadata: metav1::ObjectMeta {
+                labels: Some(
+                    [("rustfs.pool".to_string(), "pool-0".to_string())]
+                        .into_iter()
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(!node_down_cleanup_enabled(&tenant));
+        assert_eq!(node_down_policy_for_pod(&tenant, &pod), P::DoNothing);
+
+        tenant.spec.pools[0].pod_deletion_policy_when_node_is_down = Some(P::DeleteStatefulSetPod);
+        assert!(node_down_cleanup_enabled(&tenant));
+        assert_eq!(
+            node_down_policy_for_pod(&tenant, &pod),
+            P::DeleteStatefulSetPod
+        );
+        assert_eq!(
+            node_down_policy_for_pod(&tenant, &corev1::Pod::default()),
+            P::DoNothing
+        );
+
+        tenant.spec.pod_deletion_policy_when_node_is_down = Some(P::ForceDelete);
+        tenant.spec.pools[0].pod_deletion_policy_when_node_is_down = Some(P::DoNothing);
+        assert!(node_down_cleanup_enabled(&tenant));
+        assert_eq!(node_down_policy_for_pod(&tenant, &pod), P::DoNothing);
+        assert_eq!(
+            node_down_policy_for_pod(&tenant, &corev1::Pod::default()),
+            P::ForceDelete
+        );
+    }
+
+    #[test]
+    fn test_node_down_deletions_cooldown() {
+        let deletions = crate::context::NodeDownDeletions::default();
+        let cooldown = std::time::Duration::from_secs(300);
+        assert!(!deletions.cooling_down("default/t/node-a", cooldown));
+
+        deletions.record("default/t/node-a".to_string());
+        assert!(deletions.cooling_down("default/t/node-a", cooldown));
+        assert!(!deletions.cooling_down("default/t/node-b", cooldown));
+        assert!(!deletions.cooling_down("default/t/node-a", std::time::Duration::ZERO));
+    }
+
     #[test]
     fn test_pod_owner_kind_helpers() {
         let pod = corev1::Pod {
@@ -0,0 +1,454 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Auxiliary objects declared in `spec.additionalResources`.
+//!
+//! Each object is server-side applied into the Tenant namespace with the Tenant as owner, before
+//! the Tenant prerequisites are checked, so an ExternalSecret listed here can produce the
+//! credential Secret. `status.additionalResources` records what was applied; entries that
+//! disappear from the spec are deleted if the Tenant still owns them.
+//!
+//! Only the kinds in [`ALLOWED_KINDS`] are accepted: the operator's ClusterRole is much broader
+//! than what a Tenant author may hold, so an open list would let them create arbitrary objects
+//! through the operator.
+//!
+//! Failures are reported per object in the status and as events, and never fail the reconcile.
+
+use crate::context::{self, Context};
+use crate::types::v1alpha1::status::additional_resource::AdditionalResourceStatus;
+use crate::types::v1alpha1::tenant::{TENANT_LABEL, Tenant};
+use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
+use kube::api::{Api, ApiResource, DeleteParams, DynamicObject, Patch, PatchParams};
+use kube::core::GroupVersionKind;
+use kube::discovery::{self, Scope};
+use kube::runtime::events::EventType;
+use kube::{Resource, ResourceExt};
+use serde_json::json;
+use tracing::warn;
+
+/// Field manager for applied objects; distinct from the operator's own children so an entry that
+/// names an operator-managed object conflicts instead of silently taking it over.
+const FIELD_MANAGER: &str = "rustfs-operator-additional-resources";
+
+/// API group and kind of every object `spec.additionalResources` may contain. The operator
+/// ClusterRole covers each of them.
+pub(crate) const ALLOWED_KINDS: &[(&str, &str)] = &[
+    ("", "ConfigMap"),
+    ("", "Secret"),
+    ("", "Service"),
+    ("external-secrets.io", "ExternalSecret"),
+];
+
+const REASON_FAILED: &str = "AdditionalResourceFailed";
+const REASON_PRUNED: &str = "AdditionalResourcePruned";
+
+/// Applies `spec.additionalResources`, prunes removed entries, and returns the Tenant with the
+/// updated status.
+pub(super) async fn reconcile_additional_resources(
+    ctx: &Context,
+    tenant: Tenant,
+    namespace: &str,
+) -> Result<Tenant, context::Error> {
+    let previous: Vec<AdditionalResourceStatus> = tenant
+        .status
+        .as_ref()
+        .map(|status| status.additional_resources.clone())
+        .unwrap_or_default();
+    if tenant.spec.additional_resources.is_empty() && previous.is_empty() {
+        return Ok(tenant);
+    }
+
+    let mut statuses = Vec::with_capacity(tenant.spec.additional_resources.len());
+    for (index, raw) in tenant.spec.additional_resources.iter().enumerate() {
+        let object = match parse_manifest(raw, namespace) {
+            Ok(object) => object,
+            Err(message) => {
+                let message = format!("spec.additionalResources[{index}]: {message}");
+                warn!(tenant = %tenant.name_any(), namespace, %message, "invalid additional resource");
+                let _ = ctx
+                    .record(&tenant, EventType::Warning, REASON_FAILED, &message)
+                    .await;
+                continue;
+            }
+        };
+        let mut status = object_status(&object);
+        if statuses
+            .iter()
+            .any(|existing: &AdditionalResourceStatus| existing.same_object(&status))
+        {
+            status.message = Some("duplicate entry in spec.additionalResources".to_string());
+            report_failure(ctx, &tenant, &previous, &status).await;
+            continue;
+        }
+        match apply(ctx, &tenant, namespace, object).await {
+            Ok(()) => status.applied = true,
+            Err(message) => {
+                status.message = Some(message);
+                report_failure(ctx, &tenant, &previous, &status).await;
+            }
+        }
+        statuses.push(status);
+    }
+
+    let removed: Vec<AdditionalResourceStatus> = previous
+        .iter()
+        .filter(|entry| !statuses.iter().any(|status| status.same_object(entry)))
+        .cloned()
+        .collect();
+    for stale in removed {
+        match prune(ctx, &tenant, namespace, &stale).await {
+            Ok(deleted) => {
+                if deleted {
+                    let _ = ctx
+                        .record(
+                            &tenant,
+                            EventType::Normal,
+                            REASON_PRUNED,
+                            &format!(
+                                "Deleted {} '{}' removed from spec.additionalResources",
+                                stale.kind, stale.name
+                            ),
+                        )
+                        .await;
+                }
+            }
+            Err(message) => {
+                // Keep the entry so the next reconcile retries the deletion.
+                let status = AdditionalResourceStatus {
+                    applied: false,
+                    message: Some(message),
+                    ..stale
+                };
+                report_failure(ctx, &tenant, &previous, &status).await;
+                statuses.push(status);
+            }
+        }
+    }
+
+    if statuses == previous {
+        return Ok(tenant);
+    }
+    let api: Api<Tenant> = Api::namespaced(ctx.client.clone(), namespace);
+    let mut patched = api
+        .patch_status(
+            &tenant.name_any(),
+            &PatchParams::default(),
+            &Patch::Merge(json!({ "status": { "additionalResources": statuses } })),
+        )
+        .await
+        .map_err(|source| context::Error::Kube { source })?;
+    // Keep the spec the reconcile started from; only the status moved on.
+    patched.spec = tenant.spec;
+    Ok(patched)
+}
+
+/// Emits a warning unless the previous status already reported the same failure.
+async fn report_failure(
+    ctx: &Context,
+    tenant: &Tenant,
+    previous: &[AdditionalResourceStatus],
+    status: &AdditionalResourceStatus,
+) {
+    let Some(message) = status.message.as_deref() else {
+        return;
+    };
+    warn!(
+        tenant = %tenant.name_any(),
+        kind = %status.kind,
+        name = %status.name,
+        %message,
+        "additional resource failed"
+    );
+    let reported = previous
+        .iter()
+        .any(|entry| entry.same_object(status) && entry.message == status.message);
+    if !reported {
+        let _ = ctx
+            .record(
+                tenant,
+                EventType::Warning,
+                REASON_FAILED,
+                &format!("{} '{}': {message}", status.kind, status.name),
+            )
+            .await;
+    }
+}
+
+/// Parses a manifest and checks it targets the Tenant namespace.
+fn parse_manifest(raw: &RawExtension, namespace: &str) -> Result<DynamicObject, String> {
+    let object: DynamicObject = serde_json::from_value(raw.0.clone())
+        .map_err(|error| format!("not a Kubernetes object: {error}"))?;
+    let types = object
+        .types
+        .as_ref()
+        .filter(|types| !types.api_version.is_empty() && !types.kind.is_empty())
+        .ok_or_else(|| "apiVersion and kind are required".to_string())?;
+    if object
+        .metadata
+        .name
+        .as_deref()
+        .unwrap_or_default()
+        .is_empty()
+    {
+        return Err(format!("{} has no metadata.name", types.kind));
+    }
+    if object.metadata.generate_name.is_some() {
+        return Err("metadata.generateName is not supported".to_string());
+    }
+    if let Some(object_namespace) = object.metadata.namespace.as_deref()
+        && object_namespace != namespace
+    {
+        return Err(format!(
+            "{} '{}' must be in the Tenant namespace '{namespace}', not '{object_namespace}'",
+            types.kind,
+            object.name_any()
+        ));
+    }
+    let group = types
+        .api_version
+        .split_once('/')
+        .map_or("", |(group, _)| group);
+    if !ALLOWED_KINDS.contains(&(group, types.kind.as_str())) {
+        return Err(format!(
+            "{}/{} is not supported; allowed kinds are {}",
+            types.api_version,
+            types.kind,
+            ALLOWED_KINDS
+                .iter()
+                .map(|(group, kind)| match *group {
+                    "" => kind.to_string(),
+                    group => format!("{kind}.{group}"),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    Ok(object)
+}
+
+fn object_status(object: &DynamicObject) -> AdditionalResourceStatus {
+    let types = object.types.clone().unwrap_or_default();
+    AdditionalResourceStatus {
+        api_version: types.api_version,
+        kind: types.kind,
+        name: object.name_any(),
+        ..Default::default()
+    }
+}
+
+/// Sets the namespace, Tenant owner reference, and tenant label.
+fn render(mut object: DynamicObject, tenant: &Tenant, namespace: &str) -> DynamicObject {
+    object.metadata.namespace = Some(namespace.to_string());
+    let owner = tenant.new_owner_ref();
+    let owners = object
+        .metadata
+        .owner_references
+        .get_or_insert_with(Vec::new);
+    owners.retain(|existing| existing.uid != owner.uid);
+    owners.push(owner);
+    object
+        .metadata
+        .labels
+        .get_or_insert_with(Default::default)
+        .insert(TENANT_LABEL.to_string(), tenant.name());
+    object
+}
+
+async fn namespaced_api(
+    ctx: &Context,
+    namespace: &str,
+    api_version: &str,
+    kind: &str,
+) -> Result<Api<DynamicObject>, String> {
+    let (group, version) = api_version.split_once('/').unwrap_or(("", api_version));
+    let gvk = GroupVersionKind::gvk(group, version, kind);
+    let (resource, capabilities): (ApiResource, _) = discovery::pinned_kind(&ctx.client, &gvk)
+        .await
+        .map_err(|error| format!("unknown kind {api_version}/{kind}: {error}"))?;
+    if capabilities.scope != Scope::Namespaced {
+        return Err(format!(
+            "{kind} is cluster-scoped; only namespaced kinds are supported"
+        ));
+    }
+    Ok(Api::namespaced_with(
+        ctx.client.clone(),
+        namespace,
+        &resource,
+    ))
+}
+
+async fn apply(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    object: DynamicObject,
+) -> Result<(), String> {
+    let types = object.types.clone().unwrap_or_default();
+    let api = namespaced_api(ctx, namespace, &types.api_version, &types.kind).await?;
+    let object = render(object, tenant, namespace);
+    api.patch(
+        &object.name_any(),
+        &PatchParams::apply(FIELD_MANAGER),
+        &Patch::Apply(&object),
+    )
+    .await
+    .map(|_| ())
+    .map_err(|error| format!("apply failed: {error}"))
+}
+
+/// Deletes a removed entry. Returns `false` when it is already gone or no longer owned by the
+/// Tenant.
+async fn prune(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    entry: &AdditionalResourceStatus,
+) -> Result<bool, String> {
+    let api = match namespaced_api(ctx, namespace, &entry.api_version, &entry.kind).await {
+        Ok(api) => api,
+        // The kind is gone (e.g. CRD uninstalled), and its objects with it.
+        Err(_) if !entry.applied => return Ok(false),
+        Err(message) => return Err(message),
+    };
+    let Some(object) = api
+        .get_opt(&entry.name)
+        .await
+        .map_err(|error| format!("failed to read before pruning: {error}"))?
+    else {
+        return Ok(false);
+    };
+    if !owned_by(&object, tenant) {
+        return Ok(false);
+    }
+    match api.delete(&entry.name, &DeleteParams::background()).await {
+        Ok(_) => Ok(true),
+        Err(kube::Error::Api(response)) if response.code == 404 => Ok(false),
+        Err(error) => Err(format!("prune failed: {error}")),
+    }
+}
+
+fn owned_by(object: &DynamicObject, tenant: &Tenant) -> bool {
+    let Some(uid) = tenant.meta().uid.as_deref() else {
+        return false;
+    };
+    object
+        .owner_references()
+        .iter()
+        .any(|owner| owner.uid == uid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(value: serde_json::Value) -> RawExtension {
+        RawExtension(value)
+    }
+
+    #[test]
+    fn parse_manifest_requires_type_name_and_tenant_namespace() {
+        let config_map = raw(json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": { "name": "glue" },
+            "data": { "key": "value" }
+        }));
+        let object = parse_manifest(&config_map, "storage");
+        assert!(object.is_ok());
+
+        let missing_kind = raw(json!({ "apiVersion": "v1", "metadata": { "name": "glue" } }));
+        assert!(parse_manifest(&missing_kind, "storage").is_err());
+
+        let missing_name = raw(json!({ "apiVersion": "v1", "kind": "ConfigMap", "metadata": {} }));
+        assert!(parse_manifest(&missing_name, "storage").is_err());
+
+        let other_namespace = raw(json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": { "name": "glue", "namespace": "default" }
+        }));
+        assert!(
+            parse_manifest(&other_namespace, "storage")
+                .is_err_and(|message| message.contains("Tenant namespace"))
+        );
+
+        let tenant = raw(json!({
+            "apiVersion": "rustfs.com/v1alpha1",
+            "kind": "Tenant",
+            "metadata": { "name": "nested" }
+        }));
+        assert!(parse_manifest(&tenant, "storage").is_err());
+    }
+
+    #[test]
+    fn parse_manifest_rejects_kinds_outside_the_allowlist() {
+        let external_secret = raw(json!({
+            "apiVersion": "external-secrets.io/v1beta1",
+            "kind": "ExternalSecret",
+            "metadata": { "name": "credentials" }
+        }));
+        assert!(parse_manifest(&external_secret, "storage").is_ok());
+
+        for (api_version, kind) in [
+            ("rbac.authorization.k8s.io/v1", "RoleBinding"),
+            ("v1", "Pod"),
+            ("apps/v1", "StatefulSet"),
+            ("v1", "PersistentVolumeClaim"),
+        ] {
+            let object = raw(json!({
+                "apiVersion": api_version,
+                "kind": kind,
+                "metadata": { "name": "escalate" }
+            }));
+            assert!(
+                parse_manifest(&object, "storage")
+                    .is_err_and(|message| message.contains("not supported")),
+                "{kind} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn render_sets_namespace_owner_and_label() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.uid = Some("tenant-uid".to_string());
+        let object = parse_manifest(
+            &raw(json!({
+                "apiVersion": "v1",
+                "kind": "Service",
+                "metadata": { "name": "glue", "labels": { "app": "glue" } }
+            })),
+            "default",
+        )
+        .unwrap_or_else(|message| panic!("{message}"));
+
+        let rendered = render(object, &tenant, "default");
+
+        assert_eq!(rendered.metadata.namespace.as_deref(), Some("default"));
+        assert!(owned_by(&rendered, &tenant));
+        assert_eq!(rendered.owner_references().len(), 1);
+        let labels = rendered.labels();
+        assert_eq!(labels.get("app").map(String::as_str), Some("glue"));
+        assert_eq!(labels.get("rustfs.tenant"), Some(&tenant.name()));
+        assert_eq!(
+            object_status(&rendered),
+            AdditionalResourceStatus {
+                api_version: "v1".to_string(),
+                kind: "Service".to_string(),
+                name: "glue".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,475 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pool storage and networking backed by resources the operator does not provision through a
+//! StorageClass: `spec.existingHeadlessService`, `spec.pools[].persistence.existingClaims` for
+//! tenants migrated from other deployments, and `spec.pools[].persistence.localVolumes` for
+//! bare-metal disks.
+
+use super::{Error, context_result, types_result};
+use crate::context::{self, Context, is_kube_not_found};
+use crate::types::v1alpha1::pool::Pool;
+use crate::types::v1alpha1::tenant::{ExistingClaimSlot, Tenant};
+use k8s_openapi::api::core::v1 as corev1;
+use kube::ResourceExt;
+use kube::api::{Api, Patch, PatchParams, PostParams};
+use kube::runtime::events::EventType;
+use std::collections::BTreeMap;
+use tracing::{debug, info};
+
+/// Verifies that the user-provided headless Service exists and is headless.
+/// The operator never creates or mutates it.
+pub(super) async fn verify_existing_headless_service(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    name: &str,
+) -> Result<(), Error> {
+    let service = match ctx.get::<corev1::Service>(name, namespace).await {
+        Ok(service) => service,
+        Err(error) if is_kube_not_found(&error) => {
+            return context_result(
+                Err(context::Error::ExistingHeadlessServiceInvalid {
+                    name: name.to_string(),
+                    message: "Service was not found".to_string(),
+                }),
+                ctx,
+                tenant,
+            )
+            .await;
+        }
+        Err(error) => return context_result(Err(error), ctx, tenant).await,
+    };
+
+    let cluster_ip = service
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.cluster_ip.as_deref());
+    if cluster_ip != Some("None") {
+        return context_result(
+            Err(context::Error::ExistingHeadlessServiceInvalid {
+                name: name.to_string(),
+                message: "Service must set clusterIP: None".to_string(),
+            }),
+            ctx,
+            tenant,
+        )
+        .await;
+    }
+
+    Ok(())
+}
+
+/// Makes every StatefulSet claim slot of `pool` resolve to its configured existing claim.
+///
+/// Claims already named like StatefulSet claims are adopted in place. Other claims are handed
+/// over: the operator creates the expected claim pre-bound to the source claim's volume, and once
+/// the source claim is deleted (volume `Released`) re-points the volume's `claimRef` to it.
+///
+/// The namespace's claims are listed once per pass; the source claims carry no operator labels,
+/// so a labeled list would miss them.
+pub(super) async fn reconcile_existing_claims(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool: &Pool,
+) -> Result<(), Error> {
+    let slots = tenant.existing_claim_slots(pool);
+    if slots.is_empty() {
+        return Ok(());
+    }
+    let claims: BTreeMap<String, corev1::PersistentVolumeClaim> = context_result(
+        ctx.list::<corev1::PersistentVolumeClaim>(namespace).await,
+        ctx,
+        tenant,
+    )
+    .await?
+    .items
+    .into_iter()
+    .map(|claim| (claim.name_any(), claim))
+    .collect();
+
+    for slot in slots {
+        if slot.is_in_place() {
+            if claims.contains_key(&slot.source) {
+                continue;
+            }
+            return context_result(
+                Err(claim_unavailable(&slot.source, "claim was not found")),
+                ctx,
+                tenant,
+            )
+            .await;
+        }
+
+        let volume_name = match claims.get(&slot.target) {
+            Some(target) => {
+                if claim_phase(target) == Some("Bound") {
+                    continue;
+                }
+                target
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| spec.volume_name.clone())
+            }
+            None => Some(create_handover_claim(ctx, tenant, namespace, pool, &slot).await?),
+        };
+
+        if let Some(volume_name) = volume_name {
+            hand_over_volume(ctx, tenant, namespace, &slot, &volume_name).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the static local PersistentVolumes of `pool` that do not exist yet.
+///
+/// Existing volumes are left untouched: their `claimRef` is completed by the PV controller
+/// once bound, and re-applying the desired spec would clear it.
+pub(super) async fn reconcile_local_volumes(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool: &Pool,
+) -> Result<(), Error> {
+    let desired = types_result(tenant.new_local_persistent_volumes(pool), ctx, tenant).await?;
+    if desired.is_empty() {
+        return Ok(());
+    }
+
+    let volumes: Api<corev1::PersistentVolume> = Api::all(ctx.client.clone());
+    for volume in desired {
+        let name = volume.name_any();
+        let existing = context_result(
+            volumes
+                .get_opt(&name)
+                .await
+                .map_err(|source| context::Error::Kube { source }),
+            ctx,
+            tenant,
+        )
+        .await?;
+        if existing.is_some() {
+            continue;
+        }
+
+        context_result(
+            volumes
+                .create(&PostParams::default(), &volume)
+                .await
+                .map_err(|source| context::Error::Kube { source }),
+            ctx,
+            tenant,
+        )
+        .await?;
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            pool = %pool.name,
+            volume = %name,
+            "created local PersistentVolume"
+        );
+    }
+
+    Ok(())
+}
+
+async fn create_handover_claim(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool: &Pool,
+    slot: &ExistingClaimSlot,
+) -> Result<String, Error> {
+    let source = match ctx
+        .get::<corev1::PersistentVolumeClaim>(&slot.source, namespace)
+        .await
+    {
+        Ok(source) => source,
+        Err(error) if is_kube_not_found(&error) => {
+            return context_result(
+                Err(claim_unavailable(
+                    &slot.source,
+                    &format!("claim was not found and '{}' does not exist", slot.target),
+                )),
+                ctx,
+                tenant,
+            )
+            .await;
+        }
+        Err(error) => return context_result(Err(error), ctx, tenant).await,
+    };
+
+    let Some(volume_name) = source
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.volume_name.clone())
+        .filter(|_| claim_phase(&source) == Some("Bound"))
+    else {
+        return context_result(
+            Err(claim_unavailable(
+                &slot.source,
+                "claim is not bound to a PersistentVolume",
+            )),
+            ctx,
+            tenant,
+        )
+        .await;
+    };
+
+    let claim = tenant.new_handover_claim(pool, slot, &source, &volume_name);
+    context_result(ctx.create(&claim, namespace).await, ctx, tenant).await?;
+
+    info!(
+        tenant = %tenant.name(),
+        namespace = %namespace,
+        pool = %pool.name,
+        source_claim = %slot.source,
+        claim = %slot.target,
+        volume = %volume_name,
+        "created handover claim for existing volume"
+    );
+    let _ = ctx
+        .record(
+            tenant,
+            EventType::Normal,
+            "ExistingClaimHandoverStarted",
+            &format!(
+                "Created claim {} for volume {} of {}; delete {} to complete the handover",
+                slot.target, volume_name, slot.source, slot.source
+            ),
+        )
+        .await;
+
+    Ok(volume_name)
+}
+
+async fn hand_over_volume(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    slot: &ExistingClaimSlot,
+    volume_name: &str,
+) -> Result<(), Error> {
+    let volumes: Api<corev1::PersistentVolume> = Api::all(ctx.client.clone());
+    let volume = context_result(
+        volumes
+            .get(volume_name)
+            .await
+            .map_err(|source| context::Error::Kube { source }),
+        ctx,
+        tenant,
+    )
+    .await?;
+
+    match handover_step(&volume, namespace, slot) {
+        HandoverStep::Done => Ok(()),
+        HandoverStep::WaitForSourceRelease => {
+            debug!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                source_claim = %slot.source,
+                volume = %volume_name,
+                "waiting for source claim to release volume"
+            );
+            Ok(())
+        }
+        HandoverStep::Conflict(message) => {
+            context_result(Err(claim_unavailable(&slot.source, &message)), ctx, tenant).await
+        }
+        HandoverStep::RepointClaimRef => {
+            let patch = serde_json::json!({
+                "spec": {
+                    "claimRef": {
+                        "namespace": namespace,
+                        "name": slot.target,
+                        "uid": null,
+                        "resourceVersion": null,
+                    }
+                }
+            });
+            context_result(
+                volumes
+                    .patch(volume_name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await
+                    .map_err(|source| context::Error::Kube { source }),
+                ctx,
+                tenant,
+            )
+            .await?;
+            info!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                claim = %slot.target,
+                volume = %volume_name,
+                "re-pointed released volume to handover claim"
+            );
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum HandoverStep {
+    /// The volume already references the handover claim.
+    Done,
+    /// The source claim still holds the volume.
+    WaitForSourceRelease,
+    /// The source claim is gone and the volume can be bound to the handover claim.
+    RepointClaimRef,
+    /// The volume is owned by an unrelated claim or cannot be reused.
+    Conflict(String),
+}
+
+fn handover_step(
+    volume: &corev1::PersistentVolume,
+    namespace: &str,
+    slot: &ExistingClaimSlot,
+) -> HandoverStep {
+    let claim_ref = volume
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.claim_ref.as_ref());
+    let claim_ref_name = claim_ref.and_then(|claim_ref| claim_ref.name.as_deref());
+    let claim_ref_namespace = claim_ref.and_then(|claim_ref| claim_ref.namespace.as_deref());
+
+    if claim_ref_namespace.is_some_and(|ns| ns != namespace) {
+        return HandoverStep::Conflict(format!(
+            "volume is claimed from namespace '{}'",
+            claim_ref_namespace.unwrap_or_default()
+        ));
+    }
+    if claim_ref_name == Some(slot.target.as_str()) {
+        return HandoverStep::Done;
+    }
+    if claim_ref_name.is_some_and(|name| name != slot.source) {
+        return HandoverStep::Conflict(format!(
+            "volume is claimed by '{}'",
+            claim_ref_name.unwrap_or_default()
+        ));
+    }
+
+    let phase = volume
+        .status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref());
+    match phase {
+        Some("Released") | Some("Available") => {
+            let reclaim_policy = volume
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.persistent_volume_reclaim_policy.as_deref());
+            if reclaim_policy == Some("Retain") {
+                HandoverStep::RepointClaimRef
+            } else {
+                HandoverStep::Conflict(
+                    "volume reclaim policy must be Retain before the source claim is deleted"
+                        .to_string(),
+                )
+            }
+        }
+        Some("Failed") => HandoverStep::Conflict("volume is in phase Failed".to_string()),
+        _ => HandoverStep::WaitForSourceRelease,
+    }
+}
+
+fn claim_phase(claim: &corev1::PersistentVolumeClaim) -> Option<&str> {
+    claim
+        .status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+}
+
+fn claim_unavailable(claim: &str, message: &str) -> context::Error {
+    context::Error::ExistingClaimUnavailable {
+        claim: claim.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HandoverStep, handover_step};
+    use crate::types::v1alpha1::tenant::ExistingClaimSlot;
+    use k8s_openapi::api::core::v1 as corev1;
+
+    fn slot() -> ExistingClaimSlot {
+        ExistingClaimSlot {
+            target: "vol-0-tenant-pool-0-0".to_string(),
+            source: "legacy-0".to_string(),
+        }
+    }
+
+    fn volume(claim: Option<(&str, &str)>, phase: &str, reclaim: &str) -> corev1::PersistentVolume {
+        corev1::PersistentVolume {
+            spec: Some(corev1::PersistentVolumeSpec {
+                claim_ref: claim.map(|(namespace, name)| corev1::ObjectReference {
+                    namespace: Some(namespace.to_string()),
+                    name: Some(name.to_string()),
+                    ..Default::default()
+                }),
+                persistent_volume_reclaim_policy: Some(reclaim.to_string()),
+                ..Default::default()
+            }),
+            status: Some(corev1::PersistentVolumeStatus {
+                phase: Some(phase.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn waits_while_source_claim_still_holds_volume() {
+        let pv = volume(Some(("rustfs", "legacy-0")), "Bound", "Retain");
+        assert_eq!(
+            handover_step(&pv, "rustfs", &slot()),
+            HandoverStep::WaitForSourceRelease
+        );
+    }
+
+    #[test]
+    fn repoints_released_retained_volume() {
+        let pv = volume(Some(("rustfs", "legacy-0")), "Released", "Retain");
+        assert_eq!(
+            handover_step(&pv, "rustfs", &slot()),
+            HandoverStep::RepointClaimRef
+        );
+
+        let pv = volume(Some(("rustfs", "vol-0-tenant-pool-0-0")), "Bound", "Retain");
+        assert_eq!(handover_step(&pv, "rustfs", &slot()), HandoverStep::Done);
+    }
+
+    #[test]
+    fn refuses_deleting_or_foreign_volumes() {
+        let pv = volume(Some(("rustfs", "legacy-0")), "Released", "Delete");
+        assert!(matches!(
+            handover_step(&pv, "rustfs", &slot()),
+            HandoverStep::Conflict(_)
+        ));
+
+        let pv = volume(Some(("rustfs", "other")), "Bound", "Retain");
+        assert!(matches!(
+            handover_step(&pv, "rustfs", &slot()),
+            HandoverStep::Conflict(_)
+        ));
+
+        let pv = volume(Some(("other-ns", "legacy-0")), "Released", "Retain");
+        assert!(matches!(
+            handover_step(&pv, "rustfs", &slot()),
+            HandoverStep::Conflict(_)
+        ));
+    }
+}
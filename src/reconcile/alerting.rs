@@ -0,0 +1,285 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-Tenant PrometheusRule (`spec.alerting`).
+//!
+//! When the Prometheus Operator CRDs are installed, the operator server-side applies a
+//! `{tenant}-alerts` PrometheusRule owned by the Tenant. The alerts use kube-state-metrics and
+//! kubelet volume metrics scoped to the Tenant pools, plus the operator's own TLS expiry gauge.
+//! `spec.alerting.enabled: false` deletes the rule. Failures are logged and reported as
+//! `PrometheusRuleFailed` events and never fail the reconcile.
+
+use super::certificate::DEFAULT_CERT_EXPIRY_ALERT_THRESHOLD_DAYS;
+use crate::context::Context;
+use crate::types::v1alpha1::alerting::AlertingSpec;
+use crate::types::v1alpha1::tenant::{Tenant, VOLUME_CLAIM_TEMPLATE_PREFIX};
+use kube::api::{Api, ApiResource, DeleteParams, DynamicObject, Patch, PatchParams};
+use kube::core::GroupVersionKind;
+use kube::runtime::events::EventType;
+use kube::{Resource, ResourceExt, discovery};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use tracing::{debug, warn};
+
+const PROMETHEUS_RULE_GROUP: &str = "monitoring.coreos.com";
+const PROMETHEUS_RULE_VERSION: &str = "v1";
+const PROMETHEUS_RULE_KIND: &str = "PrometheusRule";
+
+const REASON_FAILED: &str = "PrometheusRuleFailed";
+
+pub(super) fn prometheus_rule_name(tenant: &Tenant) -> String {
+    format!("{}-alerts", tenant.name())
+}
+
+/// Applies or deletes the Tenant PrometheusRule. Does nothing without the Prometheus Operator.
+pub(super) async fn reconcile_prometheus_rule(ctx: &Context, tenant: &Tenant, namespace: &str) {
+    let gvk = GroupVersionKind::gvk(
+        PROMETHEUS_RULE_GROUP,
+        PROMETHEUS_RULE_VERSION,
+        PROMETHEUS_RULE_KIND,
+    );
+    let resource: ApiResource = match discovery::pinned_kind(&ctx.client, &gvk).await {
+        Ok((resource, _capabilities)) => resource,
+        Err(error) => {
+            debug!(%error, "PrometheusRule API not discovered; skipping Tenant alerts");
+            return;
+        }
+    };
+    let api: Api<DynamicObject> = Api::namespaced_with(ctx.client.clone(), namespace, &resource);
+    let name = prometheus_rule_name(tenant);
+    let alerting = tenant.spec.alerting.clone().unwrap_or_default();
+
+    let result = if alerting.enabled() {
+        let rule = prometheus_rule(tenant, namespace, &alerting, &resource);
+        api.patch(
+            &name,
+            &PatchParams::apply("rustfs-operator"),
+            &Patch::Apply(&rule),
+        )
+        .await
+        .map(|_| ())
+    } else {
+        delete_owned(&api, tenant, &name).await
+    };
+    if let Err(error) = result {
+        warn!(
+            tenant = %tenant.name(),
+            namespace,
+            rule = %name,
+            %error,
+            "failed to reconcile Tenant PrometheusRule"
+        );
+        let _ = ctx
+            .record(
+                tenant,
+                EventType::Warning,
+                REASON_FAILED,
+                &format!("PrometheusRule '{name}': {error}"),
+            )
+            .await;
+    }
+}
+
+async fn delete_owned(
+    api: &Api<DynamicObject>,
+    tenant: &Tenant,
+    name: &str,
+) -> Result<(), kube::Error> {
+    let Some(rule) = api.get_opt(name).await? else {
+        return Ok(());
+    };
+    let uid = tenant.meta().uid.as_deref();
+    if !rule
+        .owner_references()
+        .iter()
+        .any(|owner| Some(owner.uid.as_str()) == uid)
+    {
+        return Ok(());
+    }
+    match api.delete(name, &DeleteParams::background()).await {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(response)) if response.code == 404 => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+fn prometheus_rule(
+    tenant: &Tenant,
+    namespace: &str,
+    alerting: &AlertingSpec,
+    resource: &ApiResource,
+) -> DynamicObject {
+    let mut rule = DynamicObject::new(&prometheus_rule_name(tenant), resource)
+        .within(namespace)
+        .data(json!({
+            "spec": {
+                "groups": [{
+                    "name": format!("rustfs-tenant-{namespace}-{}", tenant.name()),
+                    "rules": alert_rules(tenant, namespace, alerting),
+                }],
+            },
+        }));
+    let mut labels = alerting.rule_labels.clone();
+    labels.extend(tenant.common_labels());
+    rule.metadata.labels = Some(labels);
+    rule.metadata.owner_references = Some(vec![tenant.new_owner_ref()]);
+    rule
+}
+
+fn alert_rules(tenant: &Tenant, namespace: &str, alerting: &AlertingSpec) -> Vec<Value> {
+    let name = tenant.name();
+    let statefulsets = format!(
+        "{}-({})",
+        regex_escape(&name),
+        tenant
+            .spec
+            .pools
+            .iter()
+            .map(|pool| regex_escape(&pool.name))
+            .collect::<Vec<_>>()
+            .join("|")
+    );
+    let scope = format!("namespace=\"{namespace}\"");
+    let statefulset_selector = format!("{scope},statefulset=~\"{statefulsets}\"");
+    let pod_selector = format!("{scope},pod=~\"{statefulsets}-[0-9]+\"");
+    let claim_selector = format!(
+        "{scope},persistentvolumeclaim=~\"{VOLUME_CLAIM_TEMPLATE_PREFIX}-[0-9]+-{statefulsets}-[0-9]+\""
+    );
+    let threshold = alerting.volume_usage_threshold_percent();
+    let cert_days = tenant
+        .spec
+        .cert_expiry_alert_threshold
+        .filter(|days| *days > 0)
+        .unwrap_or(DEFAULT_CERT_EXPIRY_ALERT_THRESHOLD_DAYS);
+
+    let mut labels = alerting.labels.clone();
+    labels.insert("namespace".to_string(), namespace.to_string());
+    labels.insert("tenant".to_string(), name.clone());
+    let rule = |alert: &str,
+                expr: String,
+                duration: &str,
+                severity: &str,
+                summary: &str,
+                description: String| {
+        let mut labels: BTreeMap<String, String> = labels.clone();
+        labels.insert("severity".to_string(), severity.to_string());
+        json!({
+            "alert": alert,
+            "expr": expr,
+            "for": duration,
+            "labels": labels,
+            "annotations": {
+                "summary": summary,
+                "description": description,
+            },
+        })
+    };
+
+    vec![
+        rule(
+            "RustfsTenantPoolDegraded",
+            format!(
+                "kube_statefulset_status_replicas_ready{{{statefulset_selector}}} < kube_statefulset_replicas{{{statefulset_selector}}}"
+            ),
+            "5m",
+            "critical",
+            "RustFS pool has pods that are not ready",
+            format!(
+                "StatefulSet {{{{ $labels.statefulset }}}} of Tenant {namespace}/{name} has fewer ready pods than replicas."
+            ),
+        ),
+        rule(
+            "RustfsTenantPodNotReady",
+            format!("kube_pod_status_ready{{{pod_selector},condition=\"false\"}} == 1"),
+            "10m",
+            "warning",
+            "RustFS pod is not ready",
+            format!(
+                "Pod {{{{ $labels.pod }}}} of Tenant {namespace}/{name} has not been Ready for 10 minutes."
+            ),
+        ),
+        rule(
+            "RustfsTenantVolumeNearlyFull",
+            format!(
+                "100 * kubelet_volume_stats_used_bytes{{{claim_selector}}} / kubelet_volume_stats_capacity_bytes{{{claim_selector}}} > {threshold}"
+            ),
+            "15m",
+            "warning",
+            "RustFS data volume is nearly full",
+            format!(
+                "PersistentVolumeClaim {{{{ $labels.persistentvolumeclaim }}}} of Tenant {namespace}/{name} is more than {threshold}% full."
+            ),
+        ),
+        rule(
+            "RustfsTenantCertificateExpiring",
+            format!(
+                "rustfs_operator_tenant_tls_certificate_expiry_timestamp_seconds{{namespace=\"{namespace}\",tenant=\"{name}\"}} - time() < {}",
+                i64::from(cert_days) * 86_400
+            ),
+            "1h",
+            "warning",
+            "RustFS TLS certificate expires soon",
+            format!(
+                "The TLS certificate of Tenant {namespace}/{name} expires in less than {cert_days} days."
+            ),
+        ),
+    ]
+}
+
+/// Escapes the regex metacharacters Kubernetes names can contain.
+fn regex_escape(value: &str) -> String {
+    value.replace('.', "\\\\.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_are_scoped_to_the_tenant_pools() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let alerting = AlertingSpec {
+            labels: BTreeMap::from([("team".to_string(), "storage".to_string())]),
+            volume_usage_threshold_percent: Some(90),
+            ..Default::default()
+        };
+        let rules = alert_rules(&tenant, "default", &alerting);
+
+        let alerts: Vec<&str> = rules
+            .iter()
+            .filter_map(|rule| rule["alert"].as_str())
+            .collect();
+        assert_eq!(
+            alerts,
+            vec![
+                "RustfsTenantPoolDegraded",
+                "RustfsTenantPodNotReady",
+                "RustfsTenantVolumeNearlyFull",
+                "RustfsTenantCertificateExpiring",
+            ]
+        );
+        assert!(
+            rules[1]["expr"]
+                .as_str()
+                .is_some_and(|expr| expr.contains("pod=~\"test-tenant-(pool-0)-[0-9]+\""))
+        );
+        assert!(rules[2]["expr"].as_str().is_some_and(|expr| {
+            expr.contains("persistentvolumeclaim=~\"vol-[0-9]+-test-tenant-(pool-0)-[0-9]+\"")
+                && expr.ends_with("> 90")
+        }));
+        assert_eq!(rules[0]["labels"]["team"], "storage");
+        assert_eq!(rules[0]["labels"]["tenant"], "test-tenant");
+        assert_eq!(rules[0]["labels"]["severity"], "critical");
+    }
+}
@@ -0,0 +1,606 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Canary image upgrades.
+//!
+//! With `spec.upgradeStrategy.mode: Canary`, an image change first updates only the
+//! highest-ordinal pod of the first pool by holding StatefulSet rolling-update partitions.
+//! Once that pod has stayed Ready for the soak period and RustFS reports it online, the
+//! partitions are released. A canary that fails is reverted to the previous image.
+
+use super::pool_lifecycle::rustfs_admin_client;
+use super::upgrade::rustfs_container_image;
+use super::{Error, context, context_result, statefulset_owned_by_tenant};
+use crate::context::Context;
+use crate::status::StatusBuilder;
+use crate::sts::rustfs_client::RustfsClientError;
+use crate::types::v1alpha1::status::upgrade::{UpgradePhase, UpgradeStatus};
+use crate::types::v1alpha1::tenant::Tenant;
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::apps::v1 as appsv1;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::ResourceExt;
+use kube::api::Api;
+use kube::runtime::events::EventType;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::{info, warn};
+
+pub(super) const CANARY_REQUEUE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long the canary pod may take to become Ready on the target image.
+const CANARY_START_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Container waiting reasons that fail the canary immediately.
+const FAILED_WAITING_REASONS: [&str; 5] = [
+    "CrashLoopBackOff",
+    "ImagePullBackOff",
+    "ErrImagePull",
+    "InvalidImageName",
+    "CreateContainerConfigError",
+];
+
+#[derive(Default)]
+pub(super) struct CanaryPlan {
+    /// Image rendered into pool StatefulSets instead of `spec.image` after a rollback.
+    rendered_image: Option<String>,
+    /// Rolling-update partition per pool name. Empty unless canary mode is enabled.
+    partitions: BTreeMap<String, i32>,
+    pub(super) status: Option<UpgradeStatus>,
+    pub(super) requeue_after: Option<Duration>,
+}
+
+impl CanaryPlan {
+    /// The Tenant to render pool StatefulSets from.
+    pub(super) fn render_tenant<'a>(&self, tenant: &'a Tenant) -> Cow<'a, Tenant> {
+        match &self.rendered_image {
+            Some(image) => {
+                let mut rendered = tenant.clone();
+                rendered.spec.image = Some(image.clone());
+                Cow::Owned(rendered)
+            }
+            None => Cow::Borrowed(tenant),
+        }
+    }
+
+    /// Whether the partition of an existing StatefulSet differs from the plan.
+    pub(super) fn partition_changed(&self, pool_name: &str, ss: &appsv1::StatefulSet) -> bool {
+        self.partitions
+            .get(pool_name)
+            .is_some_and(|desired| statefulset_partition(ss).unwrap_or(0) != *desired)
+    }
+
+    pub(super) fn apply_partition(&self, pool_name: &str, ss: &mut appsv1::StatefulSet) {
+        let (Some(partition), Some(spec)) = (self.partitions.get(pool_name), ss.spec.as_mut())
+        else {
+            return;
+        };
+        spec.update_strategy = Some(appsv1::StatefulSetUpdateStrategy {
+            type_: Some("RollingUpdate".to_string()),
+            rolling_update: Some(appsv1::RollingUpdateStatefulSetStrategy {
+                partition: Some(*partition),
+                ..Default::default()
+            }),
+        });
+    }
+}
+
+pub(super) async fn plan_canary_upgrade(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+) -> Result<CanaryPlan, Error> {
+    let Some(strategy) = tenant
+        .spec
+        .upgrade_strategy
+        .as_ref()
+        .filter(|strategy| strategy.is_canary())
+    else {
+        return Ok(CanaryPlan::default());
+    };
+
+    let target_image = tenant.rustfs_image();
+    let previous = tenant
+        .status
+        .as_ref()
+        .and_then(|status| status.upgrade.clone());
+
+    if let Some(upgrade) = previous
+        .as_ref()
+        .filter(|upgrade| upgrade.target_image == target_image)
+    {
+        match upgrade.phase {
+            UpgradePhase::Promoted => {
+                return Ok(CanaryPlan {
+                    partitions: released_partitions(tenant),
+                    status: previous,
+                    ..Default::default()
+                });
+            }
+            UpgradePhase::RolledBack => {
+                return Ok(CanaryPlan {
+                    rendered_image: Some(upgrade.previous_image.clone()),
+                    partitions: released_partitions(tenant),
+                    status: previous,
+                    ..Default::default()
+                });
+            }
+            UpgradePhase::Soaking => {
+                return soak_canary(
+                    ctx,
+                    tenant,
+                    namespace,
+                    upgrade.clone(),
+                    strategy.canary_soak(),
+                )
+                .await;
+            }
+        }
+    }
+
+    let statefulsets = context_result(
+        ctx.list_labeled::<appsv1::StatefulSet>(namespace, &tenant.pod_selector())
+            .await,
+        ctx,
+        tenant,
+    )
+    .await?;
+    let canary = tenant.spec.pools.iter().find_map(|pool| {
+        let ss_name = format!("{}-{}", tenant.name(), pool.name);
+        statefulsets
+            .iter()
+            .filter(|ss| statefulset_owned_by_tenant(ss, tenant))
+            .find(|ss| ss.name_any() == ss_name)
+            .and_then(rustfs_container_image)
+            .map(|image| (pool, image))
+    });
+    let Some((canary_pool, previous_image)) = canary.filter(|(_, image)| *image != target_image)
+    else {
+        return Ok(CanaryPlan {
+            partitions: released_partitions(tenant),
+            status: previous,
+            ..Default::default()
+        });
+    };
+
+    let canary_pod = tenant.pool_pod_name(&canary_pool.name, canary_pool.servers - 1);
+    info!(
+        tenant = %tenant.name(),
+        namespace = %namespace,
+        canary_pod = %canary_pod,
+        previous_image = %previous_image,
+        target_image = %target_image,
+        "starting canary upgrade"
+    );
+    let _ = ctx
+        .record(
+            tenant,
+            EventType::Normal,
+            "CanaryUpgradeStarted",
+            &format!("Updating canary pod {canary_pod} to {target_image}"),
+        )
+        .await;
+
+    let upgrade = UpgradeStatus {
+        phase: UpgradePhase::Soaking,
+        target_image,
+        previous_image,
+        canary_pod,
+        started_at: Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        soak_started_at: None,
+        message: Some("Waiting for the canary pod to become Ready".to_string()),
+    };
+    // Persist the canary before any StatefulSet is updated, so a failed reconcile cannot
+    // forget the previous image and release the partitions.
+    let mut builder = StatusBuilder::from_tenant(tenant);
+    builder.set_upgrade_status(Some(upgrade.clone()));
+    context_result(
+        ctx.patch_status_if_changed(tenant, builder.build()).await,
+        ctx,
+        tenant,
+    )
+    .await?;
+    Ok(soaking_plan(tenant, upgrade))
+}
+
+async fn soak_canary(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    mut upgrade: UpgradeStatus,
+    soak: Duration,
+) -> Result<CanaryPlan, Error> {
+    let pod = context_result(
+        Api::<corev1::Pod>::namespaced(ctx.client.clone(), namespace)
+            .get_opt(&upgrade.canary_pod)
+            .await
+            .map_err(|source| context::Error::Kube { source }),
+        ctx,
+        tenant,
+    )
+    .await?;
+
+    let now = Utc::now();
+    let mut verdict = evaluate_canary(&upgrade, pod.as_ref(), now, soak);
+    if verdict == CanaryVerdict::Passed
+        && let Err(message) = canary_server_online(ctx, tenant, &upgrade.canary_pod).await
+    {
+        verdict = CanaryVerdict::Failed(message);
+    }
+
+    match verdict {
+        CanaryVerdict::Waiting { soak_started_at } => {
+            if upgrade.soak_started_at.is_none() && soak_started_at.is_some() {
+                upgrade.message = Some(format!(
+                    "Canary pod is Ready; soaking for {}s",
+                    soak.as_secs()
+                ));
+            }
+            upgrade.soak_started_at = soak_started_at;
+            Ok(soaking_plan(tenant, upgrade))
+        }
+        CanaryVerdict::Passed => {
+            let message = format!(
+                "Canary pod {} passed a {}s soak; updating remaining pods",
+                upgrade.canary_pod,
+                soak.as_secs()
+            );
+            info!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                target_image = %upgrade.target_image,
+                "canary upgrade promoted"
+            );
+            let _ = ctx
+                .record(tenant, EventType::Normal, "CanaryUpgradePromoted", &message)
+                .await;
+            upgrade.phase = UpgradePhase::Promoted;
+            upgrade.message = Some(message);
+            Ok(CanaryPlan {
+                partitions: released_partitions(tenant),
+                status: Some(upgrade),
+                ..Default::default()
+            })
+        }
+        CanaryVerdict::Failed(reason) => {
+            let message = format!(
+                "Canary pod {} failed on {}: {reason}; reverted to {}",
+                upgrade.canary_pod, upgrade.target_image, upgrade.previous_image
+            );
+            warn!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                target_image = %upgrade.target_image,
+                previous_image = %upgrade.previous_image,
+                %reason,
+                "canary upgrade failed; rolling back"
+            );
+            let _ = ctx
+                .record(tenant, EventType::Warning, "CanaryUpgradeFailed", &message)
+                .await;
+            upgrade.phase = UpgradePhase::RolledBack;
+            upgrade.message = Some(message);
+            Ok(CanaryPlan {
+                rendered_image: Some(upgrade.previous_image.clone()),
+                partitions: released_partitions(tenant),
+                status: Some(upgrade),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+/// Holds every pod except the canary on the previous revision.
+fn soaking_plan(tenant: &Tenant, upgrade: UpgradeStatus) -> CanaryPlan {
+    let partitions = tenant
+        .spec
+        .pools
+        .iter()
+        .map(|pool| {
+            let canary = tenant.pool_pod_name(&pool.name, pool.servers - 1) == upgrade.canary_pod;
+            let partition = if canary {
+                pool.servers - 1
+            } else {
+                pool.servers
+            };
+            (pool.name.clone(), partition)
+        })
+        .collect();
+    CanaryPlan {
+        rendered_image: None,
+        partitions,
+        status: Some(upgrade),
+        requeue_after: Some(CANARY_REQUEUE_INTERVAL),
+    }
+}
+
+fn released_partitions(tenant: &Tenant) -> BTreeMap<String, i32> {
+    tenant
+        .spec
+        .pools
+        .iter()
+        .map(|pool| (pool.name.clone(), 0))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum CanaryVerdict {
+    Waiting { soak_started_at: Option<String> },
+    Passed,
+    Failed(String),
+}
+
+fn evaluate_canary(
+    upgrade: &UpgradeStatus,
+    pod: Option<&corev1::Pod>,
+    now: DateTime<Utc>,
+    soak: Duration,
+) -> CanaryVerdict {
+    let started = parse_time(&upgrade.started_at).unwrap_or(now);
+    let start_timed_out = elapsed(started, now) >= CANARY_START_TIMEOUT;
+
+    let Some(pod) = pod.filter(|pod| pod_image(pod).as_deref() == Some(&upgrade.target_image))
+    else {
+        return if start_timed_out {
+            CanaryVerdict::Failed(format!(
+                "pod was not recreated on the target image within {}s",
+                CANARY_START_TIMEOUT.as_secs()
+            ))
+        } else {
+            CanaryVerdict::Waiting {
+                soak_started_at: None,
+            }
+        };
+    };
+
+    let container = pod
+        .status
+        .as_ref()
+        .and_then(|status| status.container_statuses.as_ref())
+        .and_then(|statuses| statuses.iter().find(|status| status.name == "rustfs"));
+    if let Some(reason) = container
+        .and_then(|status| status.state.as_ref())
+        .and_then(|state| state.waiting.as_ref())
+        .and_then(|waiting| waiting.reason.as_deref())
+        .filter(|reason| FAILED_WAITING_REASONS.contains(reason))
+    {
+        return CanaryVerdict::Failed(format!("container is waiting with {reason}"));
+    }
+    if let Some(restarts) = container
+        .map(|status| status.restart_count)
+        .filter(|restarts| *restarts > 0)
+    {
+        return CanaryVerdict::Failed(format!("container restarted {restarts} time(s)"));
+    }
+
+    if !pod_ready(pod) {
+        return if upgrade.soak_started_at.is_some() {
+            CanaryVerdict::Failed("pod became NotReady during the soak period".to_string())
+        } else if start_timed_out {
+            CanaryVerdict::Failed(format!(
+                "pod did not become Ready within {}s",
+                CANARY_START_TIMEOUT.as_secs()
+            ))
+        } else {
+            CanaryVerdict::Waiting {
+                soak_started_at: None,
+            }
+        };
+    }
+
+    let soak_started_at = upgrade
+        .soak_started_at
+        .clone()
+        .unwrap_or_else(|| now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    let soak_started = parse_time(&soak_started_at).unwrap_or(now);
+    if elapsed(soak_started, now) >= soak {
+        CanaryVerdict::Passed
+    } else {
+        CanaryVerdict::Waiting {
+            soak_started_at: Some(soak_started_at),
+        }
+    }
+}
+
+/// Checks that RustFS reports the canary server online. Skipped for Tenants without admin
+/// credentials, where the pod readiness probe is the only available health signal.
+async fn canary_server_online(
+    ctx: &Context,
+    tenant: &Tenant,
+    pod_name: &str,
+) -> Result<(), String> {
+    let client = match rustfs_admin_client(ctx, tenant).await {
+        Ok(client) => client,
+        Err(RustfsClientError::MissingCredsSecret) => return Ok(()),
+        Err(error) => return Err(format!("RustFS admin client unavailable: {error}")),
+    };
+    let info = client
+        .server_info()
+        .await
+        .map_err(|error| format!("RustFS server info unavailable: {error}"))?;
+
+    match info
+        .servers
+        .iter()
+        .find(|server| server.endpoint.starts_with(&format!("{pod_name}.")))
+    {
+        Some(server) if !server.state.is_empty() && server.state != "online" => {
+            Err(format!("RustFS reports the canary server {}", server.state))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn statefulset_partition(ss: &appsv1::StatefulSet) -> Option<i32> {
+    ss.spec
+        .as_ref()?
+        .update_strategy
+        .as_ref()?
+        .rolling_update
+        .as_ref()?
+        .partition
+}
+
+fn pod_image(pod: &corev1::Pod) -> Option<String> {
+    pod.spec
+        .as_ref()?
+        .containers
+        .iter()
+        .find(|container| container.name == "rustfs")?
+        .image
+        .clone()
+}
+
+fn pod_ready(pod: &corev1::Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|condition| condition.type_ == "Ready" && condition.status == "True")
+        })
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn elapsed(since: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+    (now - since).to_std().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CanaryVerdict, evaluate_canary, soaking_plan};
+    use crate::types::v1alpha1::status::upgrade::{UpgradePhase, UpgradeStatus};
+    use chrono::{DateTime, Duration as ChronoDuration, Utc};
+    use k8s_openapi::api::core::v1 as corev1;
+    use std::time::Duration;
+
+    const SOAK: Duration = Duration::from_secs(300);
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T00:20:00Z")
+            .map(|time| time.with_timezone(&Utc))
+            .unwrap_or_default()
+    }
+
+    fn upgrade(started_minutes_ago: i64, soak_minutes_ago: Option<i64>) -> UpgradeStatus {
+        let at = |minutes: i64| {
+            (now() - ChronoDuration::minutes(minutes))
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        };
+        UpgradeStatus {
+            phase: UpgradePhase::Soaking,
+            target_image: "rustfs/rustfs:1.0.1".to_string(),
+            previous_image: "rustfs/rustfs:1.0.0".to_string(),
+            canary_pod: "test-tenant-pool-0-3".to_string(),
+            started_at: at(started_minutes_ago),
+            soak_started_at: soak_minutes_ago.map(at),
+            message: None,
+        }
+    }
+
+    fn pod(image: &str, ready: bool, restarts: i32) -> corev1::Pod {
+        corev1::Pod {
+            spec: Some(corev1::PodSpec {
+                containers: vec![corev1::Container {
+                    name: "rustfs".to_string(),
+                    image: Some(image.to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: Some(corev1::PodStatus {
+                conditions: Some(vec![corev1::PodCondition {
+                    type_: "Ready".to_string(),
+                    status: if ready { "True" } else { "False" }.to_string(),
+                    ..Default::default()
+                }]),
+                container_statuses: Some(vec![corev1::ContainerStatus {
+                    name: "rustfs".to_string(),
+                    restart_count: restarts,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn canary_soaks_after_becoming_ready_and_then_passes() {
+        let ready = pod("rustfs/rustfs:1.0.1", true, 0);
+
+        assert_eq!(
+            evaluate_canary(&upgrade(1, None), Some(&ready), now(), SOAK),
+            CanaryVerdict::Waiting {
+                soak_started_at: Some("2026-01-01T00:20:00Z".to_string())
+            }
+        );
+        assert_eq!(
+            evaluate_canary(&upgrade(8, Some(6)), Some(&ready), now(), SOAK),
+            CanaryVerdict::Passed
+        );
+    }
+
+    #[test]
+    fn canary_fails_on_restarts_readiness_loss_or_start_timeout() {
+        assert!(matches!(
+            evaluate_canary(
+                &upgrade(2, Some(1)),
+                Some(&pod("rustfs/rustfs:1.0.1", true, 1)),
+                now(),
+                SOAK
+            ),
+            CanaryVerdict::Failed(_)
+        ));
+        assert!(matches!(
+            evaluate_canary(
+                &upgrade(2, Some(1)),
+                Some(&pod("rustfs/rustfs:1.0.1", false, 0)),
+                now(),
+                SOAK
+            ),
+            CanaryVerdict::Failed(_)
+        ));
+        assert_eq!(
+            evaluate_canary(
+                &upgrade(2, None),
+                Some(&pod("rustfs/rustfs:1.0.0", true, 0)),
+                now(),
+                SOAK
+            ),
+            CanaryVerdict::Waiting {
+                soak_started_at: None
+            }
+        );
+        assert!(matches!(
+            evaluate_canary(&upgrade(11, None), None, now(), SOAK),
+            CanaryVerdict::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn soaking_plan_holds_every_pod_except_the_canary() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let plan = soaking_plan(&tenant, upgrade(1, None));
+
+        assert_eq!(plan.partitions.get("pool-0"), Some(&3));
+    }
+}
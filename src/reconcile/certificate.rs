@@ -0,0 +1,296 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TLS certificate expiry alerts and renewal (`spec.certExpiryAlertThreshold`).
+//!
+//! Pods roll on their own once the Secret changes, because the TLS hash annotation covers the
+//! certificate bytes.
+
+use super::tls::certificate_api_resource;
+use crate::context::Context;
+use crate::types::v1alpha1::status::certificate::TlsCertificateStatus;
+use crate::types::v1alpha1::tenant::Tenant;
+use crate::types::v1alpha1::tls::TlsPlan;
+use crate::utils::tls::CertificateValidity;
+use chrono::{DateTime, TimeDelta, Utc};
+use kube::api::{Api, DynamicObject, Patch, PatchParams};
+use kube::runtime::events::EventType;
+use serde_json::{Value, json};
+use tracing::warn;
+
+pub(super) const DEFAULT_CERT_EXPIRY_ALERT_THRESHOLD_DAYS: i32 = 10;
+
+const REASON_EXPIRING_SOON: &str = "CertificateExpiringSoon";
+const REASON_RENEWAL_TRIGGERED: &str = "CertificateRenewalTriggered";
+const CERT_MANAGER_ISSUING_CONDITION: &str = "Issuing";
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum ExpiryState {
+    Valid,
+    ExpiringSoon { remaining: TimeDelta },
+    Expired,
+}
+
+/// Records the certificate validity window in the TLS status.
+pub(super) fn set_validity(
+    status: &mut TlsCertificateStatus,
+    validity: &CertificateValidity,
+    now: DateTime<Utc>,
+) {
+    status.not_before = Some(rfc3339(validity.not_before));
+    status.not_after = Some(rfc3339(validity.not_after));
+    status.expires_in_seconds = Some((validity.not_after - now).num_seconds().max(0));
+}
+
+pub(super) fn expiry_state(
+    not_after: DateTime<Utc>,
+    now: DateTime<Utc>,
+    threshold_days: i32,
+) -> ExpiryState {
+    let remaining = not_after - now;
+    if remaining <= TimeDelta::zero() {
+        ExpiryState::Expired
+    } else if remaining <= TimeDelta::days(i64::from(threshold_days)) {
+        ExpiryState::ExpiringSoon { remaining }
+    } else {
+        ExpiryState::Valid
+    }
+}
+
+/// Expiry of the certificate the Tenant serves, when TLS is enabled and the certificate parsed.
+pub(super) fn not_after(tls_plan: &TlsPlan) -> Option<DateTime<Utc>> {
+    tls_plan
+        .status
+        .as_ref()?
+        .not_after
+        .as_deref()
+        .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// Warns about a TLS certificate close to expiry and asks cert-manager to re-issue it when the
+/// operator manages the Certificate.
+///
+/// Failures are logged and never fail the reconcile; the next reconcile retries.
+pub(super) async fn renew(ctx: &Context, tenant: &Tenant, namespace: &str, tls_plan: &TlsPlan) {
+    let threshold_days = tenant
+        .spec
+        .cert_expiry_alert_threshold
+        .unwrap_or(DEFAULT_CERT_EXPIRY_ALERT_THRESHOLD_DAYS);
+    if threshold_days <= 0 {
+        return;
+    }
+    let Some(status) = tls_plan.status.as_ref() else {
+        return;
+    };
+    let Some(not_after) = not_after(tls_plan) else {
+        return;
+    };
+
+    let state = expiry_state(not_after, Utc::now(), threshold_days);
+    let Some(message) = expiry_message(status, not_after, &state) else {
+        return;
+    };
+    let _ = ctx
+        .record(tenant, EventType::Warning, REASON_EXPIRING_SOON, &message)
+        .await;
+
+    let Some(certificate_ref) = status
+        .certificate_ref
+        .as_ref()
+        .filter(|_| status.managed_certificate == Some(true))
+    else {
+        return;
+    };
+    match trigger_cert_manager_renewal(ctx, namespace, &certificate_ref.name).await {
+        Ok(true) => {
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Normal,
+                    REASON_RENEWAL_TRIGGERED,
+                    &format!(
+                        "Requested re-issuance of cert-manager Certificate '{}'",
+                        certificate_ref.name
+                    ),
+                )
+                .await;
+        }
+        Ok(false) => {}
+        Err(error) => warn!(
+            certificate = %certificate_ref.name,
+            %error,
+            "failed to trigger cert-manager certificate renewal"
+        ),
+    }
+}
+
+fn expiry_message(
+    status: &TlsCertificateStatus,
+    not_after: DateTime<Utc>,
+    state: &ExpiryState,
+) -> Option<String> {
+    let secret = status
+        .server_secret_ref
+        .as_ref()
+        .map(|secret| secret.name.as_str())
+        .unwrap_or("<unknown>");
+    match state {
+        ExpiryState::Valid => None,
+        ExpiryState::ExpiringSoon { remaining } => Some(format!(
+            "TLS certificate in Secret '{secret}' expires at {} (in {} hours)",
+            rfc3339(not_after),
+            remaining.num_hours()
+        )),
+        ExpiryState::Expired => Some(format!(
+            "TLS certificate in Secret '{secret}' expired at {}",
+            rfc3339(not_after)
+        )),
+    }
+}
+
+/// Sets the cert-manager `Issuing` condition, the same trigger `cmctl renew` uses.
+///
+/// Returns `false` when cert-manager is already issuing the Certificate.
+async fn trigger_cert_manager_renewal(
+    ctx: &Context,
+    namespace: &str,
+    certificate_name: &str,
+) -> Result<bool, kube::Error> {
+    let resource = certificate_api_resource();
+    let api: Api<DynamicObject> = Api::namespaced_with(ctx.client.clone(), namespace, &resource);
+    let certificate = api.get(certificate_name).await?;
+    let Some(conditions) = renewal_conditions(&certificate, Utc::now()) else {
+        return Ok(false);
+    };
+    api.patch_status(
+        certificate_name,
+        &PatchParams::default(),
+        &Patch::Merge(json!({ "status": { "conditions": conditions } })),
+    )
+    .await?;
+    Ok(true)
+}
+
+/// Certificate conditions with `Issuing=True` appended, or `None` when already issuing.
+fn renewal_conditions(certificate: &DynamicObject, now: DateTime<Utc>) -> Option<Vec<Value>> {
+    let mut conditions = certificate
+        .data
+        .pointer("/status/conditions")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let issuing = conditions.iter().any(|condition| {
+        condition.get("type").and_then(Value::as_str) == Some(CERT_MANAGER_ISSUING_CONDITION)
+            && condition.get("status").and_then(Value::as_str) == Some("True")
+    });
+    if issuing {
+        return None;
+    }
+
+    conditions.retain(|condition| {
+        condition.get("type").and_then(Value::as_str) != Some(CERT_MANAGER_ISSUING_CONDITION)
+    });
+    conditions.push(json!({
+        "type": CERT_MANAGER_ISSUING_CONDITION,
+        "status": "True",
+        "reason": "ManuallyTriggered",
+        "message": "Certificate re-issuance requested by rustfs-operator before expiry",
+        "lastTransitionTime": rfc3339(now),
+        "observedGeneration": certificate.metadata.generation,
+    }));
+    Some(conditions)
+}
+
+fn rfc3339(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube::core::ObjectMeta;
+
+    fn at(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .map(|time| time.with_timezone(&Utc))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn expiry_state_uses_threshold_days() {
+        let now = at("2026-01-01T00:00:00Z");
+
+        assert_eq!(
+            expiry_state(at("2026-02-01T00:00:00Z"), now, 10),
+            ExpiryState::Valid
+        );
+        assert_eq!(
+            expiry_state(at("2026-01-05T00:00:00Z"), now, 10),
+            ExpiryState::ExpiringSoon {
+                remaining: TimeDelta::days(4)
+            }
+        );
+        assert_eq!(
+            expiry_state(at("2025-12-31T00:00:00Z"), now, 10),
+            ExpiryState::Expired
+        );
+    }
+
+    #[test]
+    fn set_validity_fills_status_window() {
+        let mut status = TlsCertificateStatus::default();
+        let validity = CertificateValidity {
+            not_before: at("2025-12-01T00:00:00Z"),
+            not_after: at("2026-01-02T00:00:00Z"),
+        };
+
+        set_validity(&mut status, &validity, at("2026-01-01T00:00:00Z"));
+
+        assert_eq!(status.not_before.as_deref(), Some("2025-12-01T00:00:00Z"));
+        assert_eq!(status.not_after.as_deref(), Some("2026-01-02T00:00:00Z"));
+        assert_eq!(status.expires_in_seconds, Some(86_400));
+    }
+
+    #[test]
+    fn renewal_conditions_append_issuing_unless_already_issuing() {
+        let mut certificate = DynamicObject {
+            types: None,
+            metadata: ObjectMeta {
+                generation: Some(3),
+                ..Default::default()
+            },
+            data: json!({
+                "status": {
+                    "conditions": [
+                        { "type": "Ready", "status": "True" },
+                        { "type": "Issuing", "status": "False" }
+                    ]
+                }
+            }),
+        };
+
+        let conditions =
+            renewal_conditions(&certificate, at("2026-01-01T00:00:00Z")).unwrap_or_default();
+        assert_eq!(conditions.len(), 2);
+        assert_eq!(conditions[0]["type"], "Ready");
+        assert_eq!(conditions[1]["type"], "Issuing");
+        assert_eq!(conditions[1]["status"], "True");
+        assert_eq!(conditions[1]["reason"], "ManuallyTriggered");
+        assert_eq!(conditions[1]["observedGeneration"], 3);
+
+        certificate.data = json!({ "status": { "conditions": conditions } });
+        assert!(renewal_conditions(&certificate, at("2026-01-01T00:00:00Z")).is_none());
+    }
+}
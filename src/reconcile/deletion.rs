@@ -0,0 +1,336 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detection and opt-in remediation of Tenants stuck terminating.
+//!
+//! Tenant children are garbage collected through owner references, so a Tenant deleted with
+//! foreground propagation, or a namespace being deleted, waits until every child is gone. A
+//! terminating pod on a lost node or a claim held by a CSI finalizer can block that forever.
+//! Once a deleting Tenant passes `spec.stuckDeletion.thresholdSeconds`, the blocking objects and
+//! their finalizers are reported in a `TenantDeletionStuck` event, and with `forceCleanup` the
+//! operator force deletes the pods and strips the finalizers.
+
+use crate::context::Context;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{DeleteParams, Patch, PatchParams};
+use kube::runtime::controller::Action;
+use kube::runtime::events::EventType;
+use kube::{Api, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+use tracing::warn;
+
+/// Default `spec.stuckDeletion.thresholdSeconds`.
+const DEFAULT_THRESHOLD_SECONDS: u32 = 600;
+
+/// How often a stuck Tenant is re-examined and reported.
+const STUCK_RECHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+/// A terminating object that keeps the Tenant from going away.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Blocker {
+    kind: &'static str,
+    name: String,
+    finalizers: Vec<String>,
+}
+
+impl fmt::Display for Blocker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.finalizers.is_empty() {
+            write!(f, "{}/{} (terminating)", self.kind, self.name)
+        } else {
+            write!(
+                f,
+                "{}/{} (finalizers: {})",
+                self.kind,
+                self.name,
+                self.finalizers.join(", ")
+            )
+        }
+    }
+}
+
+/// Reconcile step for a deleting Tenant: waits out the threshold, then reports and optionally
+/// removes whatever blocks the deletion.
+pub(super) async fn reconcile_deleting(ctx: &Context, tenant: &Tenant, namespace: &str) -> Action {
+    let threshold = threshold(tenant);
+    let elapsed = terminating_for(tenant.meta(), chrono::Utc::now());
+    if elapsed < threshold {
+        return Action::requeue(threshold - elapsed);
+    }
+
+    let (pods, claims, statefulsets) = match list_children(ctx, tenant, namespace).await {
+        Ok(children) => children,
+        Err(error) => {
+            warn!(tenant = %tenant.name(), namespace, %error, "failed to list children of deleting Tenant");
+            return Action::requeue(STUCK_RECHECK_INTERVAL);
+        }
+    };
+    let blockers = blockers(tenant, &pods, &claims, &statefulsets);
+    let listed = if blockers.is_empty() {
+        "no terminating children found".to_string()
+    } else {
+        blockers
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    warn!(tenant = %tenant.name(), namespace, blockers = %listed, "Tenant deletion is stuck");
+    let _ = ctx
+        .record(
+            tenant,
+            EventType::Warning,
+            "TenantDeletionStuck",
+            &format!(
+                "Tenant has been terminating for {}s; blocked by: {}",
+                elapsed.as_secs(),
+                listed
+            ),
+        )
+        .await;
+
+    let force = tenant
+        .spec
+        .stuck_deletion
+        .as_ref()
+        .and_then(|config| config.force_cleanup)
+        .unwrap_or(false);
+    if force && !blockers.is_empty() {
+        force_cleanup(ctx, tenant, namespace, &blockers).await;
+    }
+    Action::requeue(STUCK_RECHECK_INTERVAL)
+}
+
+/// Whether the deleting Tenant has passed its stuck threshold.
+pub(super) fn is_stuck(tenant: &Tenant) -> bool {
+    terminating_for(tenant.meta(), chrono::Utc::now()) >= threshold(tenant)
+}
+
+fn threshold(tenant: &Tenant) -> Duration {
+    let seconds = tenant
+        .spec
+        .stuck_deletion
+        .as_ref()
+        .and_then(|config| config.threshold_seconds)
+        .unwrap_or(DEFAULT_THRESHOLD_SECONDS);
+    Duration::from_secs(u64::from(seconds))
+}
+
+fn terminating_for(meta: &ObjectMeta, now: chrono::DateTime<chrono::Utc>) -> Duration {
+    meta.deletion_timestamp
+        .as_ref()
+        .and_then(|deleted_at| (now - deleted_at.0).to_std().ok())
+        .unwrap_or_default()
+}
+
+async fn list_children(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+) -> Result<
+    (
+        Vec<corev1::Pod>,
+        Vec<corev1::PersistentVolumeClaim>,
+        Vec<StatefulSet>,
+    ),
+    crate::context::Error,
+> {
+    let selector = tenant.pod_selector();
+    let pods = ctx
+        .list_labeled::<corev1::Pod>(namespace, &selector)
+        .await?;
+    let claims = ctx
+        .list_labeled::<corev1::PersistentVolumeClaim>(namespace, &selector)
+        .await?;
+    let statefulsets = ctx
+        .list_labeled::<StatefulSet>(namespace, &selector)
+        .await?;
+    Ok((pods, claims, statefulsets))
+}
+
+/// The Tenant's own finalizers and its terminating children, in that order.
+pub(super) fn blockers(
+    tenant: &Tenant,
+    pods: &[corev1::Pod],
+    claims: &[corev1::PersistentVolumeClaim],
+    statefulsets: &[StatefulSet],
+) -> Vec<Blocker> {
+    let mut blockers = Vec::new();
+    if !tenant.finalizers().is_empty() {
+        blockers.push(Blocker {
+            kind: "Tenant",
+            name: tenant.name_any(),
+            finalizers: tenant.finalizers().to_vec(),
+        });
+    }
+    blockers.extend(terminating("StatefulSet", statefulsets));
+    blockers.extend(terminating("Pod", pods));
+    blockers.extend(terminating("PersistentVolumeClaim", claims));
+    blockers
+}
+
+fn terminating<'a, K: Resource + 'a>(
+    kind: &'static str,
+    objects: &'a [K],
+) -> impl Iterator<Item = Blocker> + 'a {
+    objects
+        .iter()
+        .filter(|object| object.meta().deletion_timestamp.is_some())
+        .map(move |object| Blocker {
+            kind,
+            name: object.name_any(),
+            finalizers: object.finalizers().to_vec(),
+        })
+}
+
+/// Force deletes blocking pods and clears the finalizers of other blocking children. The Tenant's
+/// own finalizers are left to the garbage collector, which removes them once children are gone.
+async fn force_cleanup(ctx: &Context, tenant: &Tenant, namespace: &str, blockers: &[Blocker]) {
+    let force_delete = DeleteParams {
+        grace_period_seconds: Some(0),
+        ..DeleteParams::default()
+    };
+    let mut cleaned = Vec::new();
+    for blocker in blockers {
+        let result = match blocker.kind {
+            "Pod" => {
+                let pods: Api<corev1::Pod> = Api::namespaced(ctx.client.clone(), namespace);
+                match pods.delete(&blocker.name, &force_delete).await {
+                    Ok(_) => Ok(()),
+                    Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+                    Err(error) => Err(error),
+                }
+            }
+            "PersistentVolumeClaim" if !blocker.finalizers.is_empty() => {
+                clear_finalizers::<corev1::PersistentVolumeClaim>(ctx, namespace, &blocker.name)
+                    .await
+            }
+            "StatefulSet" if !blocker.finalizers.is_empty() => {
+                clear_finalizers::<StatefulSet>(ctx, namespace, &blocker.name).await
+            }
+            _ => continue,
+        };
+        match result {
+            Ok(()) => cleaned.push(format!("{}/{}", blocker.kind, blocker.name)),
+            Err(error) => {
+                warn!(tenant = %tenant.name(), namespace, object = %blocker, %error, "forced cleanup failed");
+            }
+        }
+    }
+    if !cleaned.is_empty() {
+        let _ = ctx
+            .record(
+                tenant,
+                EventType::Warning,
+                "TenantDeletionForcedCleanup",
+                &format!(
+                    "Forced cleanup of stuck Tenant children: {}",
+                    cleaned.join(", ")
+                ),
+            )
+            .await;
+    }
+}
+
+async fn clear_finalizers<K>(ctx: &Context, namespace: &str, name: &str) -> Result<(), kube::Error>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + DeserializeOwned
+        + fmt::Debug,
+    <K as Resource>::DynamicType: Default,
+{
+    let api: Api<K> = Api::namespaced(ctx.client.clone(), namespace);
+    let patch = serde_json::json!({ "metadata": { "finalizers": null } });
+    match api
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    #[test]
+    fn reports_tenant_finalizers_and_terminating_children() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.finalizers = Some(vec!["foregroundDeletion".to_string()]);
+        let deleted = Some(Time(chrono::Utc::now()));
+        let claim = corev1::PersistentVolumeClaim {
+            metadata: ObjectMeta {
+                name: Some("data-0".to_string()),
+                deletion_timestamp: deleted.clone(),
+                finalizers: Some(vec!["kubernetes.io/pvc-protection".to_string()]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let stuck_pod = corev1::Pod {
+            metadata: ObjectMeta {
+                name: Some("pod-0".to_string()),
+                deletion_timestamp: deleted,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let running_pod = corev1::Pod {
+            metadata: ObjectMeta {
+                name: Some("pod-1".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let found = blockers(&tenant, &[stuck_pod, running_pod], &[claim], &[]);
+        let rendered: Vec<String> = found.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "Tenant/test-tenant (finalizers: foregroundDeletion)",
+                "Pod/pod-0 (terminating)",
+                "PersistentVolumeClaim/data-0 (finalizers: kubernetes.io/pvc-protection)",
+            ]
+        );
+    }
+
+    #[test]
+    fn measures_time_since_deletion() {
+        let now = chrono::Utc::now();
+        let meta = ObjectMeta {
+            deletion_timestamp: Some(Time(now - chrono::Duration::seconds(700))),
+            ..Default::default()
+        };
+        assert_eq!(terminating_for(&meta, now), Duration::from_secs(700));
+        assert_eq!(terminating_for(&ObjectMeta::default(), now), Duration::ZERO);
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert_eq!(threshold(&tenant), Duration::from_secs(600));
+        tenant.spec.stuck_deletion = Some(crate::types::v1alpha1::deletion::StuckDeletionConfig {
+            threshold_seconds: Some(120),
+            force_cleanup: None,
+        });
+        assert_eq!(threshold(&tenant), Duration::from_secs(120));
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detection of voluntary disruptions that are already in progress for a Tenant.
+//!
+//! A StatefulSet update rolls pods one at a time. Applying it while a node drain is evicting
+//! other Tenant pods takes down two servers at once, so pool updates are deferred while a
+//! PodDisruptionBudget covering the Tenant records disrupted pods or a Tenant pod carries the
+//! `DisruptionTarget` condition. Rollouts started by the StatefulSet controller set neither.
+
+use super::rollout_events::selector_matches;
+use crate::context::Context;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::ResourceExt;
+use kube::api::ListParams;
+use std::time::Duration;
+use tracing::warn;
+
+/// Requeue interval while pool updates are deferred.
+pub(super) const DEFERRED_REQUEUE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Describes the disruption in progress, if any. List failures are logged and treated as no
+/// disruption, so an API hiccup never freezes updates.
+pub(super) async fn in_progress(ctx: &Context, tenant: &Tenant, namespace: &str) -> Option<String> {
+    let budgets = match ctx
+        .list_with_params::<PodDisruptionBudget>(namespace, &ListParams::default())
+        .await
+    {
+        Ok(budgets) => budgets,
+        Err(error) => {
+            warn!(tenant = %tenant.name(), namespace, %error, "failed to list PodDisruptionBudgets");
+            return None;
+        }
+    };
+    let pods = match ctx
+        .list_labeled::<corev1::Pod>(namespace, &tenant.pod_selector())
+        .await
+    {
+        Ok(pods) => pods,
+        Err(error) => {
+            warn!(tenant = %tenant.name(), namespace, %error, "failed to list Tenant pods");
+            return None;
+        }
+    };
+    disruption_message(tenant, &budgets.items, &pods)
+}
+
+fn disruption_message(
+    tenant: &Tenant,
+    budgets: &[PodDisruptionBudget],
+    pods: &[corev1::Pod],
+) -> Option<String> {
+    let pool_labels: Vec<_> = tenant
+        .spec
+        .pools
+        .iter()
+        .map(|pool| tenant.pool_labels(pool))
+        .collect();
+    for budget in budgets {
+        let covers_tenant = budget
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.selector.as_ref())
+            .is_some_and(|selector| {
+                pool_labels
+                    .iter()
+                    .any(|labels| selector_matches(selector, labels))
+            });
+        let disrupted = budget
+            .status
+            .as_ref()
+            .and_then(|status| status.disrupted_pods.as_ref())
+            .map_or(0, |pods| pods.len());
+        if covers_tenant && disrupted > 0 {
+            return Some(format!(
+                "PodDisruptionBudget '{}' has {disrupted} pod(s) being evicted",
+                budget.name_any()
+            ));
+        }
+    }
+
+    pods.iter()
+        .find(|pod| {
+            pod.status
+                .as_ref()
+                .and_then(|status| status.conditions.as_ref())
+                .is_some_and(|conditions| {
+                    conditions.iter().any(|condition| {
+                        condition.type_ == "DisruptionTarget" && condition.status == "True"
+                    })
+                })
+        })
+        .map(|pod| format!("Pod '{}' is being disrupted", pod.name_any()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::disruption_message;
+    use k8s_openapi::api::core::v1 as corev1;
+    use k8s_openapi::api::policy::v1::{
+        PodDisruptionBudget, PodDisruptionBudgetSpec, PodDisruptionBudgetStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+
+    fn budget(tenant: &str, disrupted: &[&str]) -> PodDisruptionBudget {
+        PodDisruptionBudget {
+            metadata: metav1::ObjectMeta {
+                name: Some("rustfs-pdb".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodDisruptionBudgetSpec {
+                selector: Some(metav1::LabelSelector {
+                    match_labels: Some([("rustfs.tenant".to_string(), tenant.to_string())].into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            status: Some(PodDisruptionBudgetStatus {
+                disrupted_pods: Some(
+                    disrupted
+                        .iter()
+                        .map(|pod| (pod.to_string(), metav1::Time(chrono::Utc::now())))
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn pod(name: &str, disruption_target: bool) -> corev1::Pod {
+        corev1::Pod {
+            metadata: metav1::ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(corev1::PodStatus {
+                conditions: Some(vec![corev1::PodCondition {
+                    type_: "DisruptionTarget".to_string(),
+                    status: if disruption_target { "True" } else { "False" }.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn disruption_message_reports_tenant_budgets_and_disruption_targets() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+
+        assert_eq!(disruption_message(&tenant, &[], &[]), None);
+        assert_eq!(
+            disruption_message(
+                &tenant,
+                &[
+                    budget("test-tenant", &[]),
+                    budget("other-tenant", &["other-pool-0-0"])
+                ],
+                &[pod("test-tenant-pool-0-0", false)],
+            ),
+            None
+        );
+        assert_eq!(
+            disruption_message(
+                &tenant,
+                &[budget("test-tenant", &["test-tenant-pool-0-1"])],
+                &[]
+            )
+            .as_deref(),
+            Some("PodDisruptionBudget 'rustfs-pdb' has 1 pod(s) being evicted")
+        );
+        assert_eq!(
+            disruption_message(&tenant, &[], &[pod("test-tenant-pool-0-2", true)]).as_deref(),
+            Some("Pod 'test-tenant-pool-0-2' is being disrupted")
+        );
+    }
+}
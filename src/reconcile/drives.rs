@@ -0,0 +1,485 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Failed data volume detection and the opt-in replacement workflow
+//! (`spec.autoReplaceFailedDrives`).
+
+use super::pool_lifecycle::rustfs_admin_client;
+use super::{Error, context_result};
+use crate::context::{self, Context};
+use crate::types::v1alpha1::pool::Pool;
+use crate::types::v1alpha1::status::pool::{FailedVolumeStatus, PoolLifecycleState};
+use crate::types::v1alpha1::tenant::{Tenant, pool_selector};
+use k8s_openapi::api::core::v1 as corev1;
+use kube::api::{Api, DeleteParams};
+use kube::runtime::events::EventType;
+use std::collections::{BTreeMap, HashMap};
+use tracing::{info, warn};
+
+const REASON_CLAIM_LOST: &str = "ClaimLost";
+const REASON_VOLUME_FAILED: &str = "VolumeFailed";
+const REASON_NODE_GONE: &str = "NodeGone";
+
+/// Detects failed data volumes of every active pool, records them in the pool statuses, and
+/// starts at most one replacement when `spec.autoReplaceFailedDrives` is enabled.
+///
+/// Returns whether any pool has failed volumes.
+pub(super) async fn reconcile_failed_volumes(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool_statuses: &mut [crate::types::v1alpha1::status::pool::Pool],
+) -> Result<bool, Error> {
+    let mut inspector = VolumeInspector::new(ctx);
+    let mut any_failed = false;
+    let mut replacement_in_progress = false;
+    let mut candidate: Option<(usize, usize)> = None;
+
+    for (status_index, pool_status) in pool_statuses.iter_mut().enumerate() {
+        if matches!(
+            pool_status.lifecycle_state,
+            Some(PoolLifecycleState::Decommissioned)
+        ) {
+            continue;
+        }
+        let Some(pool) = tenant
+            .spec
+            .pools
+            .iter()
+            .find(|pool| pool_status.name.as_deref() == Some(pool.name.as_str()))
+        else {
+            continue;
+        };
+
+        let failed = detect_failed_volumes(ctx, tenant, namespace, pool, &mut inspector).await?;
+        for volume in failed.iter().filter(|volume| !was_reported(tenant, volume)) {
+            warn!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                pool = %pool.name,
+                claim = %volume.claim_name,
+                reason = %volume.reason,
+                "detected failed data volume"
+            );
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Warning,
+                    "FailedDriveDetected",
+                    &format!(
+                        "Volume {} of pod {} failed: {}",
+                        volume.claim_name, volume.pod_name, volume.reason
+                    ),
+                )
+                .await;
+        }
+
+        replacement_in_progress |= failed.iter().any(|volume| volume.replacement_requested);
+        if candidate.is_none() && !pool.is_paused() && supports_replacement(pool) {
+            candidate = failed
+                .iter()
+                .position(|volume| !volume.replacement_requested)
+                .map(|volume_index| (status_index, volume_index));
+        }
+        any_failed |= !failed.is_empty();
+        pool_status.failed_volumes = failed;
+    }
+
+    if tenant.spec.auto_replace_failed_drives != Some(true) || replacement_in_progress {
+        return Ok(any_failed);
+    }
+    let Some((status_index, volume_index)) = candidate else {
+        return Ok(any_failed);
+    };
+    let Some(volume) = pool_statuses
+        .get_mut(status_index)
+        .and_then(|pool_status| pool_status.failed_volumes.get_mut(volume_index))
+    else {
+        return Ok(any_failed);
+    };
+
+    if let Some(reason) = healing_in_progress(ctx, tenant).await {
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            claim = %volume.claim_name,
+            %reason,
+            "deferring failed volume replacement"
+        );
+        return Ok(any_failed);
+    }
+
+    replace_volume(ctx, tenant, namespace, volume).await?;
+    Ok(any_failed)
+}
+
+async fn detect_failed_volumes(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool: &Pool,
+    inspector: &mut VolumeInspector,
+) -> Result<Vec<FailedVolumeStatus>, Error> {
+    let claims = context_result(
+        ctx.list_labeled::<corev1::PersistentVolumeClaim>(
+            namespace,
+            &pool_selector(&tenant.name(), &pool.name),
+        )
+        .await,
+        ctx,
+        tenant,
+    )
+    .await?;
+    let claims: BTreeMap<_, _> = claims
+        .into_iter()
+        .filter_map(|claim| claim.metadata.name.clone().map(|name| (name, claim)))
+        .collect();
+
+    let mut failed = Vec::new();
+    for ordinal in 0..pool.servers {
+        for volume in 0..pool.persistence.volumes_per_server {
+            let claim_name = tenant.pool_claim_name(pool, ordinal, volume);
+            let Some(claim) = claims.get(&claim_name) else {
+                continue;
+            };
+            let pod_name = tenant.pool_pod_name(&pool.name, ordinal);
+
+            if claim.metadata.deletion_timestamp.is_some() {
+                // Only a replacement the operator started itself may take the pod down; a claim
+                // deleted by anyone else is left to whoever deleted it.
+                let Some(previous) = started_replacement(tenant, &claim_name) else {
+                    continue;
+                };
+                let reason = previous.reason.clone();
+                // The claim is held by its pod until the pod goes.
+                let pod = context_result(
+                    Api::<corev1::Pod>::namespaced(ctx.client.clone(), namespace)
+                        .get_opt(&pod_name)
+                        .await
+                        .map_err(|source| context::Error::Kube { source }),
+                    ctx,
+                    tenant,
+                )
+                .await?;
+                if pod.is_some_and(|pod| pod.metadata.deletion_timestamp.is_none()) {
+                    delete_pod(ctx, tenant, namespace, &pod_name).await?;
+                }
+                failed.push(FailedVolumeStatus {
+                    claim_name,
+                    pod_name,
+                    reason,
+                    replacement_requested: true,
+                });
+                continue;
+            }
+
+            if let Some(reason) = inspector.classify(ctx, tenant, claim).await? {
+                failed.push(FailedVolumeStatus {
+                    claim_name,
+                    pod_name,
+                    reason: reason.to_string(),
+                    replacement_requested: false,
+                });
+            }
+        }
+    }
+
+    Ok(failed)
+}
+
+/// Looks up PersistentVolumes and nodes once per reconcile.
+struct VolumeInspector {
+    volumes: Api<corev1::PersistentVolume>,
+    nodes: Api<corev1::Node>,
+    node_exists: HashMap<String, bool>,
+}
+
+impl VolumeInspector {
+    fn new(ctx: &Context) -> Self {
+        Self {
+            volumes: Api::all(ctx.client.clone()),
+            nodes: Api::all(ctx.client.clone()),
+            node_exists: HashMap::new(),
+        }
+    }
+
+    async fn classify(
+        &mut self,
+        ctx: &Context,
+        tenant: &Tenant,
+        claim: &corev1::PersistentVolumeClaim,
+    ) -> Result<Option<&'static str>, Error> {
+        let phase = claim
+            .status
+            .as_ref()
+            .and_then(|status| status.phase.as_deref());
+        if phase == Some("Lost") {
+            return Ok(Some(REASON_CLAIM_LOST));
+        }
+        if phase != Some("Bound") {
+            return Ok(None);
+        }
+        let Some(volume_name) = claim
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.volume_name.as_deref())
+        else {
+            return Ok(None);
+        };
+
+        let volume = context_result(
+            self.volumes
+                .get_opt(volume_name)
+                .await
+                .map_err(|source| context::Error::Kube { source }),
+            ctx,
+            tenant,
+        )
+        .await?;
+        let Some(volume) = volume else {
+            return Ok(Some(REASON_CLAIM_LOST));
+        };
+        if volume_phase(&volume) == Some("Failed") {
+            return Ok(Some(REASON_VOLUME_FAILED));
+        }
+
+        for node_name in local_volume_nodes(&volume) {
+            if !self.node_exists(ctx, tenant, &node_name).await? {
+                return Ok(Some(REASON_NODE_GONE));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn node_exists(
+        &mut self,
+        ctx: &Context,
+        tenant: &Tenant,
+        node_name: &str,
+    ) -> Result<bool, Error> {
+        if let Some(exists) = self.node_exists.get(node_name) {
+            return Ok(*exists);
+        }
+        let node = context_result(
+            self.nodes
+                .get_opt(node_name)
+                .await
+                .map_err(|source| context::Error::Kube { source }),
+            ctx,
+            tenant,
+        )
+        .await?;
+        self.node_exists
+            .insert(node_name.to_string(), node.is_some());
+        Ok(node.is_some())
+    }
+}
+
+fn volume_phase(volume: &corev1::PersistentVolume) -> Option<&str> {
+    volume
+        .status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+}
+
+/// Hostnames a node-local (`local` or `hostPath`) volume is pinned to.
+fn local_volume_nodes(volume: &corev1::PersistentVolume) -> Vec<String> {
+    let Some(spec) = &volume.spec else {
+        return Vec::new();
+    };
+    if spec.local.is_none() && spec.host_path.is_none() {
+        return Vec::new();
+    }
+
+    spec.node_affinity
+        .iter()
+        .filter_map(|affinity| affinity.required.as_ref())
+        .flat_map(|selector| &selector.node_selector_terms)
+        .flat_map(|term| term.match_expressions.iter().flatten())
+        .filter(|expression| {
+            expression.key == "kubernetes.io/hostname" && expression.operator == "In"
+        })
+        .flat_map(|expression| expression.values.iter().flatten().cloned())
+        .collect()
+}
+
+/// Pools whose volumes the operator cannot reprovision are reported but never replaced.
+fn supports_replacement(pool: &Pool) -> bool {
+    pool.persistence.local_volumes.is_none() && pool.persistence.existing_claims.is_none()
+}
+
+fn was_reported(tenant: &Tenant, volume: &FailedVolumeStatus) -> bool {
+    previous_volume(tenant, &volume.claim_name).is_some()
+}
+
+/// The previously reported volume whose replacement the operator started, while
+/// `spec.autoReplaceFailedDrives` is still enabled.
+fn started_replacement<'a>(tenant: &'a Tenant, claim_name: &str) -> Option<&'a FailedVolumeStatus> {
+    if tenant.spec.auto_replace_failed_drives != Some(true) {
+        return None;
+    }
+    previous_volume(tenant, claim_name).filter(|volume| volume.replacement_requested)
+}
+
+fn previous_volume<'a>(tenant: &'a Tenant, claim_name: &str) -> Option<&'a FailedVolumeStatus> {
+    tenant
+        .status
+        .as_ref()?
+        .pools
+        .iter()
+        .flat_map(|pool| &pool.failed_volumes)
+        .find(|volume| volume.claim_name == claim_name)
+}
+
+/// Returns why replacement must wait, or `None` when RustFS reports no healing drives.
+/// Replacement is deferred whenever healing status cannot be confirmed.
+async fn healing_in_progress(ctx: &Context, tenant: &Tenant) -> Option<String> {
+    let client = match rustfs_admin_client(ctx, tenant).await {
+        Ok(client) => client,
+        Err(error) => return Some(format!("RustFS admin client unavailable: {error}")),
+    };
+    let info = match client.server_info().await {
+        Ok(info) => info,
+        Err(error) => return Some(format!("RustFS server info unavailable: {error}")),
+    };
+
+    let healing: u64 = info
+        .pools
+        .iter()
+        .flat_map(|pools| pools.values())
+        .flat_map(|sets| sets.values())
+        .map(|set| set.heal_disks)
+        .sum();
+    (healing > 0).then(|| format!("RustFS is healing {healing} drive(s)"))
+}
+
+async fn replace_volume(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    volume: &mut FailedVolumeStatus,
+) -> Result<(), Error> {
+    context_result(
+        ctx.delete::<corev1::PersistentVolumeClaim>(&volume.claim_name, namespace)
+            .await,
+        ctx,
+        tenant,
+    )
+    .await?;
+    delete_pod(ctx, tenant, namespace, &volume.pod_name).await?;
+    volume.replacement_requested = true;
+
+    info!(
+        tenant = %tenant.name(),
+        namespace = %namespace,
+        claim = %volume.claim_name,
+        pod = %volume.pod_name,
+        "started failed volume replacement"
+    );
+    let _ = ctx
+        .record(
+            tenant,
+            EventType::Normal,
+            "FailedDriveReplacementStarted",
+            &format!(
+                "Deleted claim {} and pod {} so the StatefulSet provisions a new volume",
+                volume.claim_name, volume.pod_name
+            ),
+        )
+        .await;
+
+    Ok(())
+}
+
+async fn delete_pod(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pod_name: &str,
+) -> Result<(), Error> {
+    match ctx
+        .delete_with_params::<corev1::Pod>(pod_name, namespace, &DeleteParams::default())
+        .await
+    {
+        Err(error) if context::is_kube_not_found(&error) => Ok(()),
+        result => context_result(result, ctx, tenant).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{local_volume_nodes, started_replacement};
+    use crate::types::v1alpha1::status::pool::FailedVolumeStatus;
+    use k8s_openapi::api::core::v1 as corev1;
+
+    #[test]
+    fn only_replacements_the_operator_started_count_as_replacements() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let volume = |claim_name: &str, replacement_requested| FailedVolumeStatus {
+            claim_name: claim_name.to_string(),
+            pod_name: "test-tenant-pool-0-0".to_string(),
+            reason: "VolumeFailed".to_string(),
+            replacement_requested,
+        };
+        let mut pool = tenant.build_pool_status("pool-0", &Default::default());
+        pool.failed_volumes = vec![volume("replaced", true), volume("reported", false)];
+        tenant.status = Some(crate::types::v1alpha1::status::Status {
+            pools: vec![pool],
+            ..Default::default()
+        });
+
+        // Without the opt-in, no terminating claim is treated as a replacement.
+        assert!(started_replacement(&tenant, "replaced").is_none());
+
+        tenant.spec.auto_replace_failed_drives = Some(true);
+        assert!(started_replacement(&tenant, "replaced").is_some());
+        assert!(started_replacement(&tenant, "reported").is_none());
+        assert!(started_replacement(&tenant, "deleted-by-hand").is_none());
+    }
+
+    #[test]
+    fn local_volume_nodes_only_reports_node_pinned_volumes() {
+        let affinity = corev1::VolumeNodeAffinity {
+            required: Some(corev1::NodeSelector {
+                node_selector_terms: vec![corev1::NodeSelectorTerm {
+                    match_expressions: Some(vec![corev1::NodeSelectorRequirement {
+                        key: "kubernetes.io/hostname".to_string(),
+                        operator: "In".to_string(),
+                        values: Some(vec!["node-a".to_string()]),
+                    }]),
+                    ..Default::default()
+                }],
+            }),
+        };
+        let mut volume = corev1::PersistentVolume {
+            spec: Some(corev1::PersistentVolumeSpec {
+                node_affinity: Some(affinity),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // Network-attached volumes may carry zone/host affinity without being node-local.
+        assert!(local_volume_nodes(&volume).is_empty());
+
+        if let Some(spec) = volume.spec.as_mut() {
+            spec.local = Some(corev1::LocalVolumeSource {
+                path: "/mnt/disk0".to_string(),
+                fs_type: None,
+            });
+        }
+        assert_eq!(local_volume_nodes(&volume), vec!["node-a".to_string()]);
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checksum of the ConfigMaps and Secrets listed in `spec.envFrom`.
+//!
+//! Env vars are read once when a container starts, so the checksum is stamped on the pod
+//! template as [`ENV_FROM_CHECKSUM_ANNOTATION`] and a change to a referenced object rolls the pods.
+//! A missing object hashes as absent, so creating it later also rolls.
+
+use super::{Error, context_result};
+use crate::context::{Context, is_kube_not_found};
+use crate::types::v1alpha1::tenant::{ENV_FROM_CHECKSUM_ANNOTATION, Tenant};
+use crate::types::v1alpha1::tls::TlsPlan;
+use k8s_openapi::api::core::v1 as corev1;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// Adds the envFrom checksum to the pod template annotations of `tls_plan`.
+pub(super) async fn annotate(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    tls_plan: &mut TlsPlan,
+) -> Result<(), Error> {
+    if tenant.spec.env_from.is_empty() {
+        return Ok(());
+    }
+
+    let mut sources = Vec::with_capacity(tenant.spec.env_from.len());
+    for source in &tenant.spec.env_from {
+        let data = if let Some(config_map) = &source.config_map_ref {
+            let found =
+                get_opt::<corev1::ConfigMap>(ctx, tenant, &config_map.name, namespace).await?;
+            SourceData::ConfigMap(&config_map.name, found.map(config_map_data))
+        } else if let Some(secret) = &source.secret_ref {
+            let found = get_opt::<corev1::Secret>(ctx, tenant, &secret.name, namespace).await?;
+            SourceData::Secret(&secret.name, found.map(secret_data))
+        } else {
+            continue;
+        };
+        sources.push((source.prefix.as_deref().unwrap_or(""), data));
+    }
+
+    tls_plan
+        .pod_template_annotations
+        .insert(ENV_FROM_CHECKSUM_ANNOTATION.to_string(), checksum(&sources));
+    Ok(())
+}
+
+pub(super) enum SourceData<'a> {
+    ConfigMap(&'a str, Option<BTreeMap<String, Vec<u8>>>),
+    Secret(&'a str, Option<BTreeMap<String, Vec<u8>>>),
+}
+
+pub(super) async fn get_opt<K>(
+    ctx: &Context,
+    tenant: &Tenant,
+    name: &str,
+    namespace: &str,
+) -> Result<Option<K>, Error>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    <K as kube::Resource>::DynamicType: Default,
+{
+    match ctx.get::<K>(name, namespace).await {
+        Ok(object) => Ok(Some(object)),
+        Err(error) if is_kube_not_found(&error) => Ok(None),
+        Err(error) => context_result(Err(error), ctx, tenant).await,
+    }
+}
+
+fn config_map_data(config_map: corev1::ConfigMap) -> BTreeMap<String, Vec<u8>> {
+    let mut data: BTreeMap<String, Vec<u8>> = config_map
+        .data
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| (key, value.into_bytes()))
+        .collect();
+    data.extend(
+        config_map
+            .binary_data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| (key, value.0)),
+    );
+    data
+}
+
+pub(super) fn secret_data(secret: corev1::Secret) -> BTreeMap<String, Vec<u8>> {
+    let mut data: BTreeMap<String, Vec<u8>> = secret
+        .data
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| (key, value.0))
+        .collect();
+    data.extend(
+        secret
+            .string_data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_bytes())),
+    );
+    data
+}
+
+pub(super) fn checksum(sources: &[(&str, SourceData<'_>)]) -> String {
+    let mut hasher = Sha256::new();
+    for (prefix, source) in sources {
+        let (kind, name, data) = match source {
+            SourceData::ConfigMap(name, data) => ("ConfigMap", name, data),
+            SourceData::Secret(name, data) => ("Secret", name, data),
+        };
+        for field in [kind, name, prefix] {
+            hasher.update(field.len().to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        match data {
+            Some(data) => {
+                hasher.update(data.len().to_le_bytes());
+                for (key, value) in data {
+                    hasher.update(key.len().to_le_bytes());
+                    hasher.update(key.as_bytes());
+                    hasher.update(value.len().to_le_bytes());
+                    hasher.update(value);
+                }
+            }
+            None => hasher.update(usize::MAX.to_le_bytes()),
+        }
+    }
+    format!("sha256:{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SourceData, checksum};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn checksum_tracks_data_presence_and_prefix() {
+        let data = |value: &str| {
+            Some(BTreeMap::from([(
+                "RUSTFS_REGION".to_string(),
+                value.as_bytes().to_vec(),
+            )]))
+        };
+        let base = checksum(&[("", SourceData::ConfigMap("env", data("us-east-1")))]);
+        assert_eq!(
+            base,
+            checksum(&[("", SourceData::ConfigMap("env", data("us-east-1")))])
+        );
+        assert_ne!(
+            base,
+            checksum(&[("", SourceData::ConfigMap("env", data("eu-west-1")))])
+        );
+        assert_ne!(
+            base,
+            checksum(&[("X_", SourceData::ConfigMap("env", data("us-east-1")))])
+        );
+        assert_ne!(
+            base,
+            checksum(&[("", SourceData::Secret("env", data("us-east-1")))])
+        );
+        assert_ne!(
+            checksum(&[("", SourceData::ConfigMap("env", None))]),
+            checksum(&[("", SourceData::ConfigMap("env", Some(BTreeMap::new())))])
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets and reachability of `spec.eventNotifications` targets.
+//!
+//! Target credentials reach RustFS as env vars and mounted client certificates, so the referenced
+//! Secrets are hashed into [`EVENT_NOTIFICATIONS_CHECKSUM_ANNOTATION`] and a rotation rolls the
+//! pods. Each target address gets a TCP probe from the operator; an unreachable target only
+//! produces a Warning event because RustFS queues events until the target comes back.
+
+use super::Error;
+use super::env_from::{SourceData, checksum, get_opt, secret_data};
+use crate::context::Context;
+use crate::types::v1alpha1::tenant::{EVENT_NOTIFICATIONS_CHECKSUM_ANNOTATION, Tenant};
+use crate::types::v1alpha1::tls::TlsPlan;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::runtime::events::EventType;
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+const REASON_TARGET_UNREACHABLE: &str = "EventNotificationTargetUnreachable";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Adds the event notification secret checksum to the pod template annotations of `tls_plan`
+/// and warns about targets the operator cannot connect to.
+pub(super) async fn annotate(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    tls_plan: &mut TlsPlan,
+) -> Result<(), Error> {
+    let Some(event_notifications) = &tenant.spec.event_notifications else {
+        return Ok(());
+    };
+
+    let names: BTreeSet<&str> = event_notifications
+        .targets
+        .iter()
+        .flat_map(|target| target.secret_names())
+        .collect();
+    let mut sources = Vec::with_capacity(names.len());
+    for name in names {
+        let found = get_opt::<corev1::Secret>(ctx, tenant, name, namespace).await?;
+        sources.push(("", SourceData::Secret(name, found.map(secret_data))));
+    }
+    tls_plan.pod_template_annotations.insert(
+        EVENT_NOTIFICATIONS_CHECKSUM_ANNOTATION.to_string(),
+        checksum(&sources),
+    );
+
+    if ctx.dry_run {
+        return Ok(());
+    }
+    for target in &event_notifications.targets {
+        let Some(address) = target.address() else {
+            continue;
+        };
+        if let Err(message) = probe(&address).await {
+            let message = format!(
+                "Event notification target {} ({address}) is unreachable: {message}",
+                target.name
+            );
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Warning,
+                    REASON_TARGET_UNREACHABLE,
+                    &message,
+                )
+                .await;
+        }
+    }
+    Ok(())
+}
+
+async fn probe(address: &str) -> Result<(), String> {
+    match tokio::time::timeout(CONNECT_TIMEOUT, tokio::net::TcpStream::connect(address)).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(_) => Err(format!(
+            "connection timed out after {}s",
+            CONNECT_TIMEOUT.as_secs()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::probe;
+
+    #[tokio::test]
+    async fn probe_reports_listening_and_closed_addresses() {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .expect("bind listener");
+        let address = listener.local_addr().expect("local addr").to_string();
+        assert!(probe(&address).await.is_ok());
+
+        drop(listener);
+        assert!(probe(&address).await.is_err());
+    }
+}
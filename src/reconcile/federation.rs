@@ -0,0 +1,467 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tenant mirroring to remote clusters.
+//!
+//! Each `spec.federation.mirrors` entry names a RemoteCluster whose kubeconfig Secret gives the
+//! operator a client for that cluster. The operator server-side applies a copy of the Tenant (and,
+//! by default, the Secrets it references) there and reads back the remote Tenant state. The remote
+//! cluster's own operator reconciles the copy into an independent RustFS cluster: all pools of a
+//! Tenant form one RustFS cluster, so pools are never split across Kubernetes clusters.
+//!
+//! Sync failures are reported per mirror in `status.mirrors` and never fail the local reconcile.
+//! Every remote call is bounded by [`REMOTE_CALL_TIMEOUT`], remote clients are cached per
+//! RemoteCluster, and deleting Tenants are never synced so a down peer cannot hold up deletion.
+
+use crate::bundle;
+use crate::context::Context;
+use crate::types::v1alpha1::federation::{RemoteCluster, TenantMirror};
+use crate::types::v1alpha1::status::federation::MirrorStatus;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::api::{Patch, PatchParams};
+use kube::runtime::events::EventType;
+use kube::{Api, Client, ResourceExt};
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::warn;
+
+/// Annotation on mirrored Tenants naming the source Tenant as `namespace/name`.
+pub(crate) const MIRROR_OF_ANNOTATION: &str = "operator.rustfs.com/mirror-of";
+
+/// How often mirrors are re-synced while the Tenant is otherwise settled.
+pub(super) const MIRROR_RESYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Upper bound for each call to a remote cluster.
+const REMOTE_CALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Remote clients keyed by `namespace/cluster`, with the resource versions of the RemoteCluster
+/// and kubeconfig Secret they were built from.
+#[derive(Default)]
+pub(crate) struct RemoteClients {
+    entries: Mutex<HashMap<String, (String, Client)>>,
+}
+
+impl RemoteClients {
+    fn get(&self, key: &str, version: &str) -> Option<Client> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(key)
+            .filter(|(cached, _)| cached == version)
+            .map(|(_, client)| client.clone())
+    }
+
+    fn insert(&self, key: String, version: String, client: Client) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(key, (version, client));
+        }
+    }
+}
+
+pub(super) fn federation_enabled() -> bool {
+    match std::env::var("OPERATOR_FEDERATION_ENABLED") {
+        Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => true,
+            "0" | "false" | "no" | "off" => false,
+            _ => {
+                warn!(
+                    value,
+                    "invalid OPERATOR_FEDERATION_ENABLED value, defaulting to disabled"
+                );
+                false
+            }
+        },
+        Err(_) => false,
+    }
+}
+
+pub(super) fn mirrors(tenant: &Tenant) -> &[TenantMirror] {
+    tenant
+        .spec
+        .federation
+        .as_ref()
+        .map_or(&[], |federation| federation.mirrors.as_slice())
+}
+
+/// Apply the Tenant to every mirror cluster and return the per-mirror status.
+pub(super) async fn sync_mirrors(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+) -> Vec<MirrorStatus> {
+    let mirrors = mirrors(tenant);
+    if mirrors.is_empty() {
+        return Vec::new();
+    }
+    if ctx.dry_run || tenant.metadata.deletion_timestamp.is_some() {
+        return tenant
+            .status
+            .as_ref()
+            .map(|status| status.mirrors.clone())
+            .unwrap_or_default();
+    }
+
+    let previous: BTreeMap<&str, &MirrorStatus> = tenant
+        .status
+        .iter()
+        .flat_map(|status| status.mirrors.iter())
+        .map(|mirror| (mirror.cluster.as_str(), mirror))
+        .collect();
+    let enabled = federation_enabled();
+
+    let mut statuses = Vec::with_capacity(mirrors.len());
+    for mirror in mirrors {
+        let remote_namespace = mirror.namespace.as_deref().unwrap_or(namespace);
+        let previous = previous.get(mirror.cluster.as_str()).copied();
+        let mut status = MirrorStatus {
+            cluster: mirror.cluster.clone(),
+            namespace: remote_namespace.to_string(),
+            last_sync_time: previous.and_then(|previous| previous.last_sync_time.clone()),
+            ..Default::default()
+        };
+
+        if !enabled {
+            status.message = Some(
+                "Federation is disabled; set OPERATOR_FEDERATION_ENABLED=true on the operator"
+                    .to_string(),
+            );
+            statuses.push(status);
+            continue;
+        }
+
+        match sync_mirror(ctx, tenant, namespace, remote_namespace, mirror).await {
+            Ok(remote_state) => {
+                let changed = sync_state_changed(previous, remote_state.as_deref());
+                status.synced = true;
+                status.remote_state = remote_state;
+                if changed {
+                    status.last_sync_time = Some(chrono::Utc::now().to_rfc3339());
+                }
+            }
+            Err(message) => {
+                warn!(
+                    tenant = %tenant.name_any(),
+                    namespace = %namespace,
+                    cluster = %mirror.cluster,
+                    %message,
+                    "failed to sync Tenant mirror"
+                );
+                if previous.is_none_or(|previous| previous.synced) {
+                    let _ = ctx
+                        .record(
+                            tenant,
+                            EventType::Warning,
+                            "MirrorSyncFailed",
+                            &format!("Mirror to cluster '{}': {message}", mirror.cluster),
+                        )
+                        .await;
+                }
+                status.message = Some(message);
+            }
+        }
+        statuses.push(status);
+    }
+    statuses
+}
+
+/// Whether a successful sync changes the mirror state, which is when `lastSyncTime` moves.
+fn sync_state_changed(previous: Option<&MirrorStatus>, remote_state: Option<&str>) -> bool {
+    previous.is_none_or(|previous| {
+        !previous.synced
+            || previous.remote_state.as_deref() != remote_state
+            || previous.last_sync_time.is_none()
+    })
+}
+
+/// Returns the remote Tenant's `status.currentState`, if it has one yet.
+async fn sync_mirror(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    remote_namespace: &str,
+    mirror: &TenantMirror,
+) -> Result<Option<String>, String> {
+    let remote = remote_client(ctx, namespace, &mirror.cluster).await?;
+    let params = PatchParams::apply("rustfs-operator");
+
+    if mirror.sync_secrets() {
+        let secret_api = Api::<corev1::Secret>::namespaced(remote.clone(), remote_namespace);
+        for secret_name in tenant.referenced_secret_names() {
+            let secret = match ctx.get::<corev1::Secret>(&secret_name, namespace).await {
+                Ok(secret) => secret,
+                // Missing Secrets are reported by the local reconcile.
+                Err(error) if crate::context::is_kube_not_found(&error) => continue,
+                Err(error) => {
+                    return Err(format!("failed to read Secret '{secret_name}': {error}"));
+                }
+            };
+            let mut secret = bundle::portable_secret(secret, false);
+            secret.metadata.namespace = Some(remote_namespace.to_string());
+            remote_call(
+                &format!("apply Secret '{secret_name}'"),
+                secret_api.patch(&secret_name, &params, &Patch::Apply(&secret)),
+            )
+            .await?;
+        }
+    }
+
+    let mirrored = mirrored_tenant(tenant, namespace, remote_namespace);
+    let applied = remote_call(
+        "apply Tenant",
+        Api::<Tenant>::namespaced(remote, remote_namespace).patch(
+            &mirrored.name_any(),
+            &params,
+            &Patch::Apply(&mirrored),
+        ),
+    )
+    .await?;
+    Ok(applied
+        .status
+        .map(|status| status.current_state)
+        .filter(|state| !state.is_empty()))
+}
+
+async fn remote_call<T>(
+    action: &str,
+    call: impl Future<Output = Result<T, kube::Error>>,
+) -> Result<T, String> {
+    match tokio::time::timeout(REMOTE_CALL_TIMEOUT, call).await {
+        Ok(result) => result.map_err(|error| format!("failed to {action}: {error}")),
+        Err(_) => Err(format!(
+            "failed to {action}: no response within {}s",
+            REMOTE_CALL_TIMEOUT.as_secs()
+        )),
+    }
+}
+
+async fn remote_client(ctx: &Context, namespace: &str, cluster: &str) -> Result<Client, String> {
+    let remote_cluster: RemoteCluster = ctx
+        .get(cluster, namespace)
+        .await
+        .map_err(|error| format!("failed to read RemoteCluster '{cluster}': {error}"))?;
+    let secret_ref = &remote_cluster.spec.kubeconfig_secret;
+    let secret: corev1::Secret = ctx
+        .get(&secret_ref.name, namespace)
+        .await
+        .map_err(|error| format!("failed to read Secret '{}': {error}", secret_ref.name))?;
+    let key = format!("{namespace}/{cluster}");
+    let version = format!(
+        "{}/{}",
+        remote_cluster.resource_version().unwrap_or_default(),
+        secret.resource_version().unwrap_or_default()
+    );
+    if let Some(client) = ctx.remote_clients.get(&key, &version) {
+        return Ok(client);
+    }
+
+    let kubeconfig = secret
+        .data
+        .as_ref()
+        .and_then(|data| data.get(&secret_ref.key))
+        .and_then(|value| std::str::from_utf8(&value.0).ok())
+        .ok_or_else(|| {
+            format!(
+                "Secret '{}' has no UTF-8 key '{}'",
+                secret_ref.name, secret_ref.key
+            )
+        })?;
+
+    let kubeconfig = kube::config::Kubeconfig::from_yaml(kubeconfig).map_err(|error| {
+        format!(
+            "invalid kubeconfig in Secret '{}': {error}",
+            secret_ref.name
+        )
+    })?;
+    inline_only(&kubeconfig).map_err(|problem| {
+        format!(
+            "kubeconfig in Secret '{}' is not allowed: {problem}",
+            secret_ref.name
+        )
+    })?;
+    let options = kube::config::KubeConfigOptions {
+        context: remote_cluster.spec.context.clone(),
+        ..Default::default()
+    };
+    let mut config = kube::Config::from_custom_kubeconfig(kubeconfig, &options)
+        .await
+        .map_err(|error| format!("invalid kubeconfig for RemoteCluster '{cluster}': {error}"))?;
+    ctx.proxy.apply_to_kube_config(&mut config);
+    config.connect_timeout = Some(REMOTE_CALL_TIMEOUT);
+    config.read_timeout = Some(REMOTE_CALL_TIMEOUT);
+    let client = Client::try_from(config).map_err(|error| {
+        format!("failed to create client for RemoteCluster '{cluster}': {error}")
+    })?;
+    ctx.remote_clients.insert(key, version, client.clone());
+    Ok(client)
+}
+
+/// Refuses kubeconfig settings that make the operator run commands or read its own files, since
+/// anyone who can write a Secret in a Tenant namespace controls the kubeconfig. Only inline
+/// credentials (`token`, `username`/`password`, `*-data`) are allowed.
+fn inline_only(kubeconfig: &kube::config::Kubeconfig) -> Result<(), String> {
+    for named in &kubeconfig.auth_infos {
+        let Some(user) = &named.auth_info else {
+            continue;
+        };
+        let field = if user.exec.is_some() {
+            Some("exec")
+        } else if user.auth_provider.is_some() {
+            Some("auth-provider")
+        } else if user.token_file.is_some() {
+            Some("tokenFile")
+        } else if user.client_certificate.is_some() {
+            Some("client-certificate")
+        } else if user.client_key.is_some() {
+            Some("client-key")
+        } else {
+            None
+        };
+        if let Some(field) = field {
+            return Err(format!("user '{}' sets {field}", named.name));
+        }
+    }
+    for named in &kubeconfig.clusters {
+        if named
+            .cluster
+            .as_ref()
+            .is_some_and(|cluster| cluster.certificate_authority.is_some())
+        {
+            return Err(format!(
+                "cluster '{}' sets certificate-authority",
+                named.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Copy of the Tenant to apply in a remote cluster. Federation settings are not copied so the
+/// mirror never mirrors itself onward.
+fn mirrored_tenant(tenant: &Tenant, namespace: &str, remote_namespace: &str) -> Tenant {
+    let mut mirrored = bundle::portable_tenant(tenant.clone());
+    mirrored.spec.federation = None;
+    mirrored.metadata.namespace = Some(remote_namespace.to_string());
+    mirrored
+        .metadata
+        .annotations
+        .get_or_insert_with(BTreeMap::new)
+        .insert(
+            MIRROR_OF_ANNOTATION.to_string(),
+            format!("{namespace}/{}", tenant.name_any()),
+        );
+    mirrored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inline_only, mirrored_tenant};
+    use crate::types::v1alpha1::federation::{FederationSpec, TenantMirror};
+
+    #[test]
+    fn mirrored_tenant_drops_federation_and_records_source() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.uid = Some("uid".to_string());
+        tenant.spec.federation = Some(FederationSpec {
+            mirrors: vec![TenantMirror {
+                cluster: "dr".to_string(),
+                ..Default::default()
+            }],
+        });
+
+        let mirrored = mirrored_tenant(&tenant, "default", "storage");
+
+        assert_eq!(mirrored.spec.federation, None);
+        assert_eq!(mirrored.metadata.uid, None);
+        assert_eq!(mirrored.metadata.namespace.as_deref(), Some("storage"));
+        assert_eq!(
+            mirrored
+                .metadata
+                .annotations
+                .and_then(|annotations| annotations.get(super::MIRROR_OF_ANNOTATION).cloned()),
+            Some("default/test-tenant".to_string())
+        );
+        assert_eq!(mirrored.spec.pools.len(), tenant.spec.pools.len());
+    }
+
+    #[test]
+    fn last_sync_time_moves_only_when_the_mirror_state_changes() {
+        use super::sync_state_changed;
+        use crate::types::v1alpha1::status::federation::MirrorStatus;
+
+        let synced = MirrorStatus {
+            cluster: "dr".to_string(),
+            synced: true,
+            remote_state: Some("Ready".to_string()),
+            last_sync_time: Some("2025-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let failed = MirrorStatus {
+            synced: false,
+            ..synced.clone()
+        };
+
+        assert!(!sync_state_changed(Some(&synced), Some("Ready")));
+        assert!(sync_state_changed(Some(&synced), Some("Degraded")));
+        assert!(sync_state_changed(Some(&failed), Some("Ready")));
+        assert!(sync_state_changed(None, None));
+    }
+
+    #[test]
+    fn kubeconfigs_that_run_commands_or_read_files_are_refused() {
+        let kubeconfig = |user: &str| {
+            kube::config::Kubeconfig::from_yaml(&format!(
+                r#"
+apiVersion: v1
+kind: Config
+clusters:
+- name: dr
+  cluster:
+    server: https://dr.example.com:6443
+    certificate-authority-data: Y2E=
+users:
+- name: dr
+  user:
+{user}
+contexts:
+- name: dr
+  context: {{cluster: dr, user: dr}}
+current-context: dr
+"#
+            ))
+            .unwrap_or_default()
+        };
+
+        let token = kubeconfig("    token: abc");
+        assert_eq!(token.auth_infos.len(), 1);
+        assert!(inline_only(&token).is_ok());
+        assert!(
+            inline_only(&kubeconfig(
+                "    exec:\n      apiVersion: client.authentication.k8s.io/v1\n      command: sh\n      args: [\"-c\", \"id\"]"
+            ))
+            .is_err_and(|problem| problem.contains("exec"))
+        );
+        assert!(
+            inline_only(&kubeconfig(
+                "    tokenFile: /var/run/secrets/kubernetes.io/serviceaccount/token"
+            ))
+            .is_err_and(|problem| problem.contains("tokenFile"))
+        );
+        assert!(
+            inline_only(&kubeconfig("    client-key: /etc/key.pem"))
+                .is_err_and(|problem| problem.contains("client-key"))
+        );
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! No-op reconcile gating.
+//!
+//! A Ready Tenant whose spec hash, generation, and child resourceVersions are unchanged since
+//! the last successful reconcile skips the full child diff. A Tenant with a scheduled requeue
+//! (`status.nextReconcileTime`) is never skipped, so mirror resyncs, verification retries, and
+//! rebalance polls still run. The periodic resync of a Ready Tenant is the exception: it only
+//! looks for drift, which the unchanged child resourceVersions already rule out.
+//!
+//! Child resourceVersions are read from metadata-only reflectors of the objects labeled
+//! `rustfs.tenant`, started with the controller; without them the gate stays disabled.
+
+use crate::context::Context;
+use crate::types::v1alpha1::status::{CurrentState, RequeueReason};
+use crate::types::v1alpha1::tenant::{TENANT_LABEL, Tenant};
+use futures::{FutureExt, StreamExt};
+use k8s_openapi::NamespaceResourceScope;
+use k8s_openapi::api::apps::v1 as appsv1;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::core::PartialObjectMeta;
+use kube::runtime::reflector::{self, Store};
+use kube::runtime::{WatchStreamExt, metadata_watcher, watcher};
+use kube::{Api, Client, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256};
+use std::fmt::Debug;
+use tokio_util::sync::CancellationToken;
+
+/// Hex SHA-256 of the serialized Tenant spec.
+pub(crate) fn spec_hash(tenant: &Tenant) -> String {
+    let spec = serde_json::to_vec(&tenant.spec).unwrap_or_default();
+    hex::encode(Sha256::digest(spec))
+}
+
+/// Whether the last reconcile fully converged on the Tenant as it is now and scheduled no
+/// follow-up reconcile other than the periodic resync.
+pub(super) fn is_settled(tenant: &Tenant) -> bool {
+    let Some(status) = &tenant.status else {
+        return false;
+    };
+
+    status.current_state == CurrentState::Ready.as_str()
+        && (status.next_reconcile_time.is_none()
+            || status.requeue_reason.as_deref() == Some(RequeueReason::PeriodicResync.as_str()))
+        && tenant.metadata.generation.is_some()
+        && status.observed_generation == tenant.metadata.generation
+        && status.operator_version.as_deref() == Some(crate::version::SHORT_VERSION)
+        && status.observed_spec_hash.as_deref() == Some(spec_hash(tenant).as_str())
+        && status
+            .pools
+            .iter()
+            .all(|pool| pool.failed_volumes.is_empty())
+}
+
+/// Metadata caches of every Tenant-labeled child kind that can trigger a reconcile.
+pub(crate) struct ChildStores {
+    stateful_sets: Store<PartialObjectMeta<appsv1::StatefulSet>>,
+    pods: Store<PartialObjectMeta<corev1::Pod>>,
+    services: Store<PartialObjectMeta<corev1::Service>>,
+    service_accounts: Store<PartialObjectMeta<corev1::ServiceAccount>>,
+    config_maps: Store<PartialObjectMeta<corev1::ConfigMap>>,
+    secrets: Store<PartialObjectMeta<corev1::Secret>>,
+    volume_claims: Store<PartialObjectMeta<corev1::PersistentVolumeClaim>>,
+}
+
+impl ChildStores {
+    /// Starts the reflectors; they stop when `cancel` fires.
+    pub(crate) fn spawn(
+        client: &Client,
+        config: watcher::Config,
+        cancel: &CancellationToken,
+    ) -> Self {
+        let config = config.labels(TENANT_LABEL);
+        Self {
+            stateful_sets: reflect(client, &config, cancel),
+            pods: reflect(client, &config, cancel),
+            services: reflect(client, &config, cancel),
+            service_accounts: reflect(client, &config, cancel),
+            config_maps: reflect(client, &config, cancel),
+            secrets: reflect(client, &config, cancel),
+            volume_claims: reflect(client, &config, cancel),
+        }
+    }
+}
+
+fn reflect<K>(
+    client: &Client,
+    config: &watcher::Config,
+    cancel: &CancellationToken,
+) -> Store<PartialObjectMeta<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Debug
+        + Send
+        + Sync
+        + 'static,
+{
+    let (reader, writer) = reflector::store();
+    let stream = reflector::reflector(
+        writer,
+        metadata_watcher(Api::<K>::all(client.clone()), config.clone()).default_backoff(),
+    )
+    .for_each(|_| futures::future::ready(()));
+    let cancel = cancel.clone();
+    tokio::spawn(async move {
+        tokio::select! {
+            _ = cancel.cancelled() => {}
+            _ = stream => {}
+        }
+    });
+    reader
+}
+
+/// Fingerprint of the Tenant metadata and the resourceVersions of every object that can trigger
+/// a reconcile for it. Returns `None` when the caches are missing or not synced yet, which
+/// disables the gate.
+pub(super) fn child_fingerprint(ctx: &Context, tenant: &Tenant, namespace: &str) -> Option<String> {
+    let stores = ctx.child_stores.as_deref()?;
+    let tenant_name = tenant.name_any();
+    let mut entries = Vec::new();
+    collect_versions(&stores.stateful_sets, namespace, &tenant_name, &mut entries)?;
+    collect_versions(&stores.pods, namespace, &tenant_name, &mut entries)?;
+    collect_versions(&stores.services, namespace, &tenant_name, &mut entries)?;
+    collect_versions(
+        &stores.service_accounts,
+        namespace,
+        &tenant_name,
+        &mut entries,
+    )?;
+    collect_versions(&stores.config_maps, namespace, &tenant_name, &mut entries)?;
+    collect_versions(&stores.secrets, namespace, &tenant_name, &mut entries)?;
+    collect_versions(&stores.volume_claims, namespace, &tenant_name, &mut entries)?;
+    Some(fingerprint(tenant, entries))
+}
+
+fn fingerprint(tenant: &Tenant, mut entries: Vec<String>) -> String {
+    entries.sort();
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(&tenant.metadata.labels).unwrap_or_default());
+    hasher.update(serde_json::to_vec(&tenant.metadata.annotations).unwrap_or_default());
+    for entry in entries {
+        hasher.update(entry.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+fn collect_versions<K>(
+    store: &Store<PartialObjectMeta<K>>,
+    namespace: &str,
+    tenant: &str,
+    entries: &mut Vec<String>,
+) -> Option<()>
+where
+    K: Resource<DynamicType = ()> + k8s_openapi::Resource + Clone,
+{
+    store.wait_until_ready().now_or_never()?.ok()?;
+    entries.extend(
+        store
+            .state()
+            .iter()
+            .filter(|object| {
+                object.metadata.namespace.as_deref() == Some(namespace)
+                    && object.labels().get(TENANT_LABEL).map(String::as_str) == Some(tenant)
+            })
+            .map(|object| {
+                format!(
+                    "{}/{}={}",
+                    <K as k8s_openapi::Resource>::KIND,
+                    object.name_any(),
+                    object.resource_version().unwrap_or_default()
+                )
+            }),
+    );
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_settled, spec_hash};
+    use crate::types::v1alpha1::status::Status;
+
+    fn settled_tenant() -> crate::types::v1alpha1::tenant::Tenant {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.generation = Some(3);
+        tenant.status = Some(Status {
+            current_state: "Ready".to_string(),
+            observed_generation: Some(3),
+            operator_version: Some(crate::version::SHORT_VERSION.to_string()),
+            observed_spec_hash: Some(spec_hash(&tenant)),
+            ..Default::default()
+        });
+        tenant
+    }
+
+    #[test]
+    fn settled_requires_matching_generation_and_spec_hash() {
+        assert!(is_settled(&settled_tenant()));
+
+        let mut tenant = settled_tenant();
+        tenant.metadata.generation = Some(4);
+        assert!(!is_settled(&tenant));
+
+        let mut tenant = settled_tenant();
+        tenant.spec.image = Some("rustfs/rustfs:changed".to_string());
+        assert!(!is_settled(&tenant));
+
+        let mut tenant = settled_tenant();
+        if let Some(status) = tenant.status.as_mut() {
+            status.current_state = "Degraded".to_string();
+        }
+        assert!(!is_settled(&tenant));
+    }
+
+    #[test]
+    fn settled_requires_current_operator_version() {
+        let mut tenant = settled_tenant();
+        if let Some(status) = tenant.status.as_mut() {
+            status.operator_version = Some("v0.0.0-previous".to_string());
+        }
+        assert!(!is_settled(&tenant));
+    }
+
+    #[test]
+    fn scheduled_requeue_is_not_settled() {
+        let mut tenant = settled_tenant();
+        if let Some(status) = tenant.status.as_mut() {
+            status.next_reconcile_time = Some("2025-01-01T00:00:00Z".to_string());
+            status.requeue_reason = Some("MirrorResync".to_string());
+        }
+        assert!(!is_settled(&tenant));
+    }
+
+    #[test]
+    fn periodic_resync_of_a_ready_tenant_is_settled() {
+        use crate::reconcile::priority::ResyncConfig;
+        use crate::status::StatusBuilder;
+        use crate::types::v1alpha1::status::RequeueReason;
+
+        // The status `finalize_tenant_status` writes for a converged Ready Tenant with the
+        // default resync settings.
+        let mut tenant = settled_tenant();
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.set_observed_spec_hash(spec_hash(&tenant));
+        builder.finish_success();
+        builder.set_requeue(
+            ResyncConfig::default().interval(&tenant),
+            RequeueReason::PeriodicResync,
+        );
+        tenant.status = Some(builder.build());
+
+        assert!(
+            tenant
+                .status
+                .as_ref()
+                .is_some_and(|status| status.next_reconcile_time.is_some())
+        );
+        assert!(is_settled(&tenant));
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Operator-wide maintenance mode.
+//!
+//! While maintenance mode is on, every Tenant gets a status-only reconcile: pool statuses are
+//! refreshed from the live StatefulSets, but nothing is created, updated, or deleted. This avoids
+//! racing a Kubernetes control-plane upgrade. Deleting Tenants still run their teardown and
+//! finalizer, so a deletion is never held until maintenance ends.
+//!
+//! The mode is on when the operator runs with `server --maintenance` or
+//! `OPERATOR_MAINTENANCE_MODE=true`, or while the ConfigMap named by
+//! `OPERATOR_MAINTENANCE_CONFIGMAP` (default `rustfs-operator-maintenance`) in the operator
+//! namespace has `enabled: "true"`. The ConfigMap is read on every reconcile, so the mode can be
+//! switched without restarting the operator.
+//!
+//! The COSI driver and the bucket binding controller check the same switch before each pass and
+//! make no changes while it is on.
+
+use super::Error;
+use super::phases::observed_pool_status;
+use crate::context::{self, Context};
+use crate::status::StatusBuilder;
+use crate::types::v1alpha1::status::{ConditionType, Reason, RequeueReason};
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::runtime::controller::Action;
+use kube::runtime::events::EventType;
+use kube::{Api, Client, ResourceExt};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+pub const MAINTENANCE_MODE_ENV: &str = "OPERATOR_MAINTENANCE_MODE";
+pub const MAINTENANCE_CONFIGMAP_ENV: &str = "OPERATOR_MAINTENANCE_CONFIGMAP";
+pub const DEFAULT_MAINTENANCE_CONFIGMAP: &str = "rustfs-operator-maintenance";
+
+/// Interval at which Tenants are looked at again while maintenance mode is on.
+pub(crate) const INTERVAL: Duration = Duration::from_secs(60);
+
+/// Where the operator looks for the maintenance switch.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MaintenanceConfig {
+    /// Maintenance mode was turned on at startup and stays on until the operator restarts.
+    pub forced: bool,
+    /// `(namespace, name)` of the ConfigMap that switches maintenance mode at runtime.
+    pub config_map: Option<(String, String)>,
+}
+
+impl MaintenanceConfig {
+    /// Reads `OPERATOR_MAINTENANCE_MODE` and `OPERATOR_MAINTENANCE_CONFIGMAP`; `flag` is the
+    /// `--maintenance` argument. An empty ConfigMap name disables the runtime switch.
+    pub fn from_env(flag: bool, namespace: &str) -> Self {
+        let name = std::env::var(MAINTENANCE_CONFIGMAP_ENV)
+            .unwrap_or_else(|_| DEFAULT_MAINTENANCE_CONFIGMAP.to_string());
+        let name = name.trim();
+        Self {
+            forced: flag || crate::tenant_monitor::env_bool(MAINTENANCE_MODE_ENV, false),
+            config_map: (!name.is_empty()).then(|| (namespace.to_string(), name.to_string())),
+        }
+    }
+
+    /// Message explaining why maintenance mode is on, or `None` when it is off.
+    pub(crate) async fn active(&self, ctx: &Context) -> Result<Option<String>, Error> {
+        self.active_with(&ctx.client)
+            .await
+            .map_err(|source| context::Error::Kube { source }.into())
+    }
+
+    /// Whether a background controller may make changes in this pass. Maintenance mode, or a
+    /// maintenance ConfigMap that cannot be read, skips the pass.
+    pub(crate) async fn allows_changes(&self, client: &Client, controller: &str) -> bool {
+        match self.active_with(client).await {
+            Ok(None) => true,
+            Ok(Some(message)) => {
+                debug!(controller, %message, "skipping pass while maintenance mode is on");
+                false
+            }
+            Err(error) => {
+                warn!(controller, %error, "skipping pass: cannot read maintenance mode");
+                false
+            }
+        }
+    }
+
+    async fn active_with(&self, client: &Client) -> Result<Option<String>, kube::Error> {
+        if self.forced {
+            return Ok(Some(
+                "Operator maintenance mode is on (--maintenance); only status is updated"
+                    .to_string(),
+            ));
+        }
+        let Some((namespace, name)) = &self.config_map else {
+            return Ok(None);
+        };
+        Ok(
+            Api::<corev1::ConfigMap>::namespaced(client.clone(), namespace)
+                .get_opt(name)
+                .await?
+                .and_then(|config_map| message_from_config_map(&config_map)),
+        )
+    }
+}
+
+fn message_from_config_map(config_map: &corev1::ConfigMap) -> Option<String> {
+    let data = config_map.data.as_ref()?;
+    let enabled = data.get("enabled")?.trim();
+    if !matches!(enabled.to_ascii_lowercase().as_str(), "true" | "1" | "yes") {
+        return None;
+    }
+    let message = format!(
+        "Operator maintenance mode is on (ConfigMap {}); only status is updated",
+        config_map.name_any()
+    );
+    Some(
+        match data
+            .get("reason")
+            .map(|reason| reason.trim())
+            .filter(|reason| !reason.is_empty())
+        {
+            Some(reason) => format!("{message}: {reason}"),
+            None => message,
+        },
+    )
+}
+
+/// Refreshes the Tenant status from its live StatefulSets without mutating any other object.
+///
+/// A Normal `MaintenanceMode` event is recorded when the Tenant enters maintenance mode.
+pub(super) async fn reconcile_status_only(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    message: String,
+) -> Result<Action, Error> {
+    debug!(tenant = %tenant.name(), namespace, "maintenance mode is on; status-only reconcile");
+
+    let mut pools = Vec::with_capacity(tenant.spec.pools.len());
+    for pool in &tenant.spec.pools {
+        let ss_name = format!("{}-{}", tenant.name(), pool.name);
+        pools.push(observed_pool_status(ctx, tenant, namespace, &pool.name, &ss_name).await?);
+    }
+
+    let entering = !in_maintenance(tenant);
+    let mut builder = StatusBuilder::from_tenant(tenant);
+    builder.keep_observed_generation();
+    builder.set_pool_statuses(pools);
+    builder.set_maintenance(message.clone());
+    builder.set_requeue(Some(INTERVAL), RequeueReason::MaintenanceMode);
+    if let Err(error) = ctx.patch_status_if_changed(tenant, builder.build()).await {
+        warn!(tenant = %tenant.name(), namespace, %error, "failed to record maintenance mode status");
+    }
+
+    if entering {
+        info!(tenant = %tenant.name(), namespace, "tenant entered operator maintenance mode");
+        if let Err(error) = ctx
+            .record(
+                tenant,
+                EventType::Normal,
+                Reason::MaintenanceMode.as_str(),
+                &message,
+            )
+            .await
+        {
+            debug!(tenant = %tenant.name(), namespace, %error, "failed to record maintenance mode event");
+        }
+    }
+
+    Ok(Action::requeue(INTERVAL))
+}
+
+/// Whether the Tenant status still says maintenance mode from an earlier reconcile.
+fn in_maintenance(tenant: &Tenant) -> bool {
+    tenant
+        .status
+        .as_ref()
+        .and_then(|status| status.condition(ConditionType::Reconciling))
+        .is_some_and(|condition| condition.reason == Reason::MaintenanceMode.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn config_map(data: &[(&str, &str)]) -> corev1::ConfigMap {
+        corev1::ConfigMap {
+            metadata: kube::api::ObjectMeta {
+                name: Some(DEFAULT_MAINTENANCE_CONFIGMAP.to_string()),
+                ..Default::default()
+            },
+            data: Some(
+                data.iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn config_map_switches_maintenance_mode() {
+        assert_eq!(message_from_config_map(&config_map(&[])), None);
+        assert_eq!(
+            message_from_config_map(&config_map(&[("enabled", "false")])),
+            None
+        );
+        assert_eq!(
+            message_from_config_map(&config_map(&[("enabled", " True ")])).as_deref(),
+            Some(
+                "Operator maintenance mode is on (ConfigMap rustfs-operator-maintenance); only status is updated"
+            )
+        );
+        assert_eq!(
+            message_from_config_map(&config_map(&[
+                ("enabled", "true"),
+                ("reason", "upgrading control plane to 1.33")
+            ]))
+            .as_deref(),
+            Some(
+                "Operator maintenance mode is on (ConfigMap rustfs-operator-maintenance); only status is updated: upgrading control plane to 1.33"
+            )
+        );
+    }
+
+    #[test]
+    fn status_only_update_keeps_observed_generation() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.generation = Some(3);
+        tenant.status = Some(crate::types::v1alpha1::status::Status {
+            observed_generation: Some(2),
+            ..Default::default()
+        });
+
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.keep_observed_generation();
+        builder.set_maintenance("maintenance".to_string());
+        let status = builder.build();
+
+        assert_eq!(status.observed_generation, Some(2));
+        let condition = status.condition(ConditionType::Reconciling).unwrap();
+        assert_eq!(condition.status, "False");
+        assert_eq!(condition.reason, "MaintenanceMode");
+
+        tenant.status = Some(status);
+        assert!(in_maintenance(&tenant));
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How many Tenants the operator reconciles per namespace.
+//!
+//! `OPERATOR_TENANTS_PER_NAMESPACE=multiple` (the default) reconciles every Tenant; their
+//! generated objects are prefixed with the Tenant name, so they never collide. With `single`,
+//! the oldest Tenant in a namespace owns it and every other Tenant there is Blocked with reason
+//! `NamespaceTenantConflict` until it is moved or the owner is deleted.
+//!
+//! `OPERATOR_NAMESPACE_ALLOWLIST` and `OPERATOR_NAMESPACE_DENYLIST` limit which namespaces hold
+//! Tenants at all. A Tenant outside them is Blocked with reason `NamespaceNotAllowed`, child
+//! watch events from those namespaces are dropped, and the console refuses to create Tenants or
+//! namespaces there.
+
+use crate::types;
+use crate::types::v1alpha1::tenant::Tenant;
+use kube::ResourceExt;
+use tracing::warn;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TenantsPerNamespace {
+    Single,
+    Multiple,
+}
+
+pub(super) fn tenants_per_namespace() -> TenantsPerNamespace {
+    match std::env::var("OPERATOR_TENANTS_PER_NAMESPACE") {
+        Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
+            "single" => TenantsPerNamespace::Single,
+            "multiple" | "" => TenantsPerNamespace::Multiple,
+            _ => {
+                warn!(
+                    value,
+                    "invalid OPERATOR_TENANTS_PER_NAMESPACE value, defaulting to multiple"
+                );
+                TenantsPerNamespace::Multiple
+            }
+        },
+        Err(_) => TenantsPerNamespace::Multiple,
+    }
+}
+
+/// Namespaces the operator manages Tenants in. Both lists are comma-separated; an empty
+/// allowlist allows every namespace, and the denylist wins over the allowlist.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct NamespaceFilter {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl NamespaceFilter {
+    pub(crate) fn from_env() -> Self {
+        Self::new(
+            &std::env::var("OPERATOR_NAMESPACE_ALLOWLIST").unwrap_or_default(),
+            &std::env::var("OPERATOR_NAMESPACE_DENYLIST").unwrap_or_default(),
+        )
+    }
+
+    fn new(allow: &str, deny: &str) -> Self {
+        fn split(list: &str) -> Vec<String> {
+            list.split(',')
+                .map(str::trim)
+                .filter(|namespace| !namespace.is_empty())
+                .map(str::to_string)
+                .collect()
+        }
+        Self {
+            allow: split(allow),
+            deny: split(deny),
+        }
+    }
+
+    pub(crate) fn allows(&self, namespace: &str) -> bool {
+        self.check(namespace).is_ok()
+    }
+
+    /// Field selector excluding denied namespaces, so child watches never receive their objects.
+    /// The allowlist cannot be expressed as a field selector and is applied to watch events.
+    pub(crate) fn deny_field_selector(&self) -> Option<String> {
+        (!self.deny.is_empty()).then(|| {
+            self.deny
+                .iter()
+                .map(|namespace| format!("metadata.namespace!={namespace}"))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+    }
+
+    /// Explains why `namespace` is not managed, for conditions and console errors.
+    pub(crate) fn check(&self, namespace: &str) -> Result<(), String> {
+        if self.deny.iter().any(|denied| denied == namespace) {
+            return Err(format!(
+                "namespace '{namespace}' is listed in the operator namespace denylist"
+            ));
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|allowed| allowed == namespace) {
+            return Err(format!(
+                "namespace '{namespace}' is not in the operator namespace allowlist ({})",
+                self.allow.join(", ")
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Errors when `tenant` lives in a namespace the operator does not manage.
+pub(super) fn check_namespace_allowed(
+    tenant: &Tenant,
+    filter: &NamespaceFilter,
+) -> Result<(), types::error::Error> {
+    let namespace = tenant.namespace()?;
+    filter
+        .check(&namespace)
+        .map_err(|message| types::error::Error::NamespaceNotAllowed {
+            name: tenant.name(),
+            message,
+        })
+}
+
+/// Errors when `tenant` is not the namespace owner under the `single` policy. The owner is the
+/// oldest live Tenant, with the name breaking ties, so the choice is stable across reconciles.
+pub(super) fn check_single_tenant(
+    tenant: &Tenant,
+    namespace_tenants: &[Tenant],
+) -> Result<(), types::error::Error> {
+    let owner = namespace_tenants
+        .iter()
+        .filter(|other| other.metadata.deletion_timestamp.is_none())
+        .min_by(|left, right| {
+            left.metadata
+                .creation_timestamp
+                .cmp(&right.metadata.creation_timestamp)
+                .then_with(|| left.name_any().cmp(&right.name_any()))
+        });
+    match owner {
+        Some(owner) if owner.name_any() != tenant.name() => {
+            Err(types::error::Error::NamespaceTenantConflict {
+                name: tenant.name(),
+                message: format!(
+                    "Tenant '{}' already owns this namespace and the operator allows one Tenant per namespace",
+                    owner.name_any()
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NamespaceFilter, check_namespace_allowed, check_single_tenant};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    fn tenant(name: &str, created_secs: i64) -> crate::types::v1alpha1::tenant::Tenant {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.name = Some(name.to_string());
+        tenant.metadata.creation_timestamp =
+            chrono::DateTime::from_timestamp(created_secs, 0).map(Time);
+        tenant
+    }
+
+    #[test]
+    fn oldest_tenant_owns_the_namespace() {
+        let first = tenant("zeta", 100);
+        let second = tenant("alpha", 200);
+        let all = [first.clone(), second.clone()];
+
+        assert!(check_single_tenant(&first, &all).is_ok());
+        assert!(
+            check_single_tenant(&second, &all)
+                .is_err_and(|error| error.to_string().contains("Tenant 'zeta' already owns"))
+        );
+    }
+
+    #[test]
+    fn denylist_wins_over_allowlist() {
+        let filter = NamespaceFilter::new("tenants, kube-system", "kube-system,");
+
+        assert!(filter.allows("tenants"));
+        assert!(!filter.allows("default"));
+        assert!(
+            filter
+                .check("kube-system")
+                .is_err_and(|message| message.contains("denylist"))
+        );
+        assert!(NamespaceFilter::new("", "").allows("kube-system"));
+        assert_eq!(
+            filter.deny_field_selector().as_deref(),
+            Some("metadata.namespace!=kube-system")
+        );
+        assert_eq!(
+            NamespaceFilter::new("tenants", "").deny_field_selector(),
+            None
+        );
+    }
+
+    #[test]
+    fn tenant_in_denied_namespace_is_not_allowed() {
+        let tenant = tenant("alpha", 100);
+        let filter = NamespaceFilter::new("", "default");
+
+        assert!(matches!(
+            check_namespace_allowed(&tenant, &filter),
+            Err(crate::types::error::Error::NamespaceNotAllowed { .. })
+        ));
+        assert!(check_namespace_allowed(&tenant, &NamespaceFilter::default()).is_ok());
+    }
+}
@@ -0,0 +1,596 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Webhook notifications for Tenant state transitions (`spec.notifications`).
+//!
+//! Transitions are derived by comparing the status about to be written with the previous one,
+//! so each one is posted once, after the status patch succeeds. Every webhook gets at most one
+//! notification per event kind within `minIntervalSeconds`; later ones are dropped. Delivery
+//! failures are logged and recorded as `NotificationFailed` events and never fail the reconcile.
+//!
+//! Anyone who can write the URL Secret chooses where the operator sends a POST from its own
+//! network position. URLs must use `https`, redirects are not followed, and hosts that are or
+//! resolve to loopback, private, link-local, or cluster-internal addresses are refused unless the
+//! operator lists them in `OPERATOR_NOTIFICATION_ALLOWED_HOSTS`.
+
+use crate::context::Context;
+use crate::types::v1alpha1::notifications::{
+    NotificationEvent, NotificationWebhook, WebhookFormat,
+};
+use crate::types::v1alpha1::status::Status;
+use crate::types::v1alpha1::status::pool::PoolState;
+use crate::types::v1alpha1::status::upgrade::UpgradePhase;
+use crate::types::v1alpha1::status::{ConditionType, CurrentState};
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::core::v1::Secret;
+use kube::runtime::events::EventType;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_TEMPLATE: &str = "RustFS Tenant {namespace}/{tenant} is {event}: {message}";
+
+/// Host suffixes that name in-cluster or node-local endpoints.
+const INTERNAL_HOST_SUFFIXES: &[&str] = &[
+    ".svc",
+    ".cluster.local",
+    ".local",
+    ".internal",
+    ".localhost",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Transition {
+    pub event: NotificationEvent,
+    pub state: String,
+    pub message: String,
+}
+
+/// HTTP client and per-webhook send times shared by all reconciles.
+pub(crate) struct Notifier {
+    http: reqwest::Client,
+    proxy: crate::proxy::ProxyConfig,
+    last_sent: Mutex<HashMap<String, Instant>>,
+    /// Hosts from `OPERATOR_NOTIFICATION_ALLOWED_HOSTS` that may be internal.
+    allowed_hosts: Vec<String>,
+}
+
+impl Notifier {
+    pub(crate) fn new(proxy: &crate::proxy::ProxyConfig) -> Self {
+        Self {
+            http: http_client(proxy, reqwest::Client::builder()),
+            proxy: proxy.clone(),
+            last_sent: Mutex::default(),
+            allowed_hosts: std::env::var("OPERATOR_NOTIFICATION_ALLOWED_HOSTS")
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(|host| host.trim().to_ascii_lowercase())
+                        .filter(|host| !host.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Client that connects to `host` only at the `addresses` checked by [`webhook_target`], so
+    /// a second DNS lookup cannot point the request somewhere else.
+    fn pinned_client(&self, host: &str, addresses: &[SocketAddr]) -> reqwest::Client {
+        http_client(
+            &self.proxy,
+            reqwest::Client::builder().resolve_to_addrs(host, addresses),
+        )
+    }
+
+    /// Reserves a send for `key` unless one happened within `interval`.
+    fn try_acquire(&self, key: &str, interval: Duration, now: Instant) -> bool {
+        let Ok(mut last_sent) = self.last_sent.lock() else {
+            return false;
+        };
+        if last_sent
+            .get(key)
+            .is_some_and(|sent| now.saturating_duration_since(*sent) < interval)
+        {
+            return false;
+        }
+        last_sent.insert(key.to_string(), now);
+        true
+    }
+}
+
+/// Transitions between the previous and the next status that webhooks can subscribe to.
+pub(crate) fn transitions(previous: Option<&Status>, next: &Status) -> Vec<Transition> {
+    let mut transitions = Vec::new();
+    let previous_state = previous.map(|status| status.current_state.as_str());
+    let condition_message = |type_: ConditionType| {
+        next.condition(type_)
+            .map(|condition| {
+                if condition.message.is_empty() {
+                    condition.reason.clone()
+                } else {
+                    condition.message.clone()
+                }
+            })
+            .unwrap_or_default()
+    };
+
+    for (state, event, condition_type) in [
+        (
+            CurrentState::Degraded,
+            NotificationEvent::Degraded,
+            ConditionType::Degraded,
+        ),
+        (
+            CurrentState::Ready,
+            NotificationEvent::Ready,
+            ConditionType::Ready,
+        ),
+    ] {
+        if next.current_state == state.as_str() && previous_state != Some(state.as_str()) {
+            transitions.push(Transition {
+                event,
+                state: next.current_state.clone(),
+                message: condition_message(condition_type),
+            });
+        }
+    }
+
+    for pool in &next.pools {
+        let was_failed = previous
+            .and_then(|status| {
+                status
+                    .pools
+                    .iter()
+                    .find(|before| before.ss_name == pool.ss_name)
+            })
+            .is_some_and(|before| before.state == PoolState::RolloutFailed);
+        if pool.state == PoolState::RolloutFailed && !was_failed {
+            transitions.push(Transition {
+                event: NotificationEvent::RolloutFailed,
+                state: next.current_state.clone(),
+                message: format!(
+                    "Pool '{}' rollout failed",
+                    pool.name.as_deref().unwrap_or(&pool.ss_name)
+                ),
+            });
+        }
+    }
+
+    if let Some(target_image) = rolled_back(Some(next))
+        && rolled_back(previous) != Some(target_image)
+    {
+        let mut message = format!("Canary upgrade to {target_image} was rolled back");
+        if let Some(detail) = next
+            .upgrade
+            .as_ref()
+            .and_then(|upgrade| upgrade.message.as_deref())
+        {
+            message.push_str(": ");
+            message.push_str(detail);
+        }
+        transitions.push(Transition {
+            event: NotificationEvent::RolloutFailed,
+            state: next.current_state.clone(),
+            message,
+        });
+    }
+
+    transitions
+}
+
+/// Target image of a canary upgrade that was rolled back.
+fn rolled_back(status: Option<&Status>) -> Option<&str> {
+    status
+        .and_then(|status| status.upgrade.as_ref())
+        .filter(|upgrade| upgrade.phase == UpgradePhase::RolledBack)
+        .map(|upgrade| upgrade.target_image.as_str())
+}
+
+/// Replaces the template placeholders with the transition details.
+fn render(template: &str, tenant: &str, namespace: &str, transition: &Transition) -> String {
+    template
+        .replace("{tenant}", tenant)
+        .replace("{namespace}", namespace)
+        .replace("{event}", &transition.event.to_string())
+        .replace("{state}", &transition.state)
+        .replace("{message}", &transition.message)
+}
+
+fn payload(
+    webhook: &NotificationWebhook,
+    tenant: &str,
+    namespace: &str,
+    transition: &Transition,
+) -> serde_json::Value {
+    let text = render(
+        webhook.template.as_deref().unwrap_or(DEFAULT_TEMPLATE),
+        tenant,
+        namespace,
+        transition,
+    );
+    match webhook.format {
+        WebhookFormat::Slack => serde_json::json!({ "text": text }),
+        WebhookFormat::Generic => serde_json::json!({
+            "tenant": tenant,
+            "namespace": namespace,
+            "event": transition.event.to_string(),
+            "state": transition.state,
+            "message": transition.message,
+            "text": text,
+        }),
+    }
+}
+
+/// Posts `transitions` to the webhooks of `tenant` that subscribe to them.
+pub(crate) async fn notify(ctx: &Context, tenant: &Tenant, transitions: &[Transition]) {
+    let Some(notifications) = tenant.spec.notifications.as_ref() else {
+        return;
+    };
+    if transitions.is_empty() || ctx.dry_run {
+        return;
+    }
+    let Ok(namespace) = tenant.namespace() else {
+        return;
+    };
+    let name = tenant.name();
+    let interval = notifications.min_interval();
+
+    for webhook in &notifications.webhooks {
+        for transition in transitions
+            .iter()
+            .filter(|transition| webhook.wants(transition.event))
+        {
+            let key = format!("{namespace}/{name}/{}/{}", webhook.name, transition.event);
+            if !ctx.notifier.try_acquire(&key, interval, Instant::now()) {
+                debug!(
+                    tenant = %name,
+                    namespace = %namespace,
+                    webhook = %webhook.name,
+                    event = %transition.event,
+                    "dropped notification inside the webhook minimum interval"
+                );
+                continue;
+            }
+            if let Err(reason) = send(ctx, &namespace, &name, webhook, transition).await {
+                warn!(
+                    tenant = %name,
+                    namespace = %namespace,
+                    webhook = %webhook.name,
+                    event = %transition.event,
+                    reason,
+                    "failed to deliver Tenant notification"
+                );
+                let _ = ctx
+                    .record(
+                        tenant,
+                        EventType::Warning,
+                        "NotificationFailed",
+                        &format!(
+                            "Webhook '{}' notification for {} failed: {reason}",
+                            webhook.name, transition.event
+                        ),
+                    )
+                    .await;
+            }
+        }
+    }
+}
+
+fn http_client(
+    proxy: &crate::proxy::ProxyConfig,
+    builder: reqwest::ClientBuilder,
+) -> reqwest::Client {
+    let builder = builder
+        .timeout(WEBHOOK_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none());
+    proxy
+        .apply_to_http_client(builder)
+        .and_then(|builder| builder.build())
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+async fn send(
+    ctx: &Context,
+    namespace: &str,
+    tenant: &str,
+    webhook: &NotificationWebhook,
+    transition: &Transition,
+) -> Result<(), String> {
+    let secret = &webhook.url_secret;
+    let url = ctx
+        .get::<Secret>(&secret.name, namespace)
+        .await
+        .map_err(|_| format!("cannot read Secret '{}'", secret.name))?
+        .data
+        .and_then(|mut data| data.remove(&secret.key))
+        .and_then(|value| String::from_utf8(value.0).ok())
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "Secret '{}' has no URL in key '{}'",
+                secret.name, secret.key
+            )
+        })?;
+
+    // The URL is a credential, so errors only carry the status code or error kind.
+    let (url, pinned) = webhook_target(ctx, &url).await?;
+    let http = match &pinned {
+        Some((host, addresses)) => ctx.notifier.pinned_client(host, addresses),
+        None => ctx.notifier.http.clone(),
+    };
+    let response = http
+        .post(url)
+        .json(&payload(webhook, tenant, namespace, transition))
+        .send()
+        .await
+        .map_err(|error| {
+            if error.is_timeout() {
+                "request timed out".to_string()
+            } else {
+                "request failed".to_string()
+            }
+        })?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "webhook returned HTTP {}",
+            response.status().as_u16()
+        ))
+    }
+}
+
+/// A host name and the addresses it was checked at.
+type PinnedHost = (String, Vec<SocketAddr>);
+
+/// Parses the webhook URL and refuses targets inside the cluster or on the node. A host name is
+/// returned with the addresses it resolved to, which the request must connect to. Hosts that do
+/// not resolve are refused unless listed in `OPERATOR_NOTIFICATION_ALLOWED_HOSTS`.
+async fn webhook_target(
+    ctx: &Context,
+    url: &str,
+) -> Result<(url::Url, Option<PinnedHost>), String> {
+    let url = url::Url::parse(url).map_err(|_| "webhook URL is not a valid URL".to_string())?;
+    let Some(host) = checked_host(&url, &ctx.notifier.allowed_hosts)? else {
+        return Ok((url, None));
+    };
+
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .map_err(|_| "webhook host does not resolve".to_string())?
+        .collect();
+    if addresses.is_empty() {
+        return Err("webhook host does not resolve".to_string());
+    }
+    if addresses.iter().any(|address| internal_ip(address.ip())) {
+        return Err(internal_host_error());
+    }
+    Ok((url, Some((host, addresses))))
+}
+
+/// Checks the scheme and host of `url`. Returns the host name that still has to be resolved and
+/// checked, or `None` when the host is allowed as is.
+fn checked_host(url: &url::Url, allowed_hosts: &[String]) -> Result<Option<String>, String> {
+    if url.scheme() != "https" {
+        return Err("webhook URL must use https".to_string());
+    }
+    let host = match url.host() {
+        Some(url::Host::Domain(domain)) => domain.trim_end_matches('.').to_ascii_lowercase(),
+        Some(url::Host::Ipv4(ip)) => ip.to_string(),
+        Some(url::Host::Ipv6(ip)) => ip.to_string(),
+        None => return Err("webhook URL has no host".to_string()),
+    };
+    if allowed_hosts.contains(&host) {
+        return Ok(None);
+    }
+    match url.host() {
+        Some(url::Host::Ipv4(ip)) if internal_ip(IpAddr::V4(ip)) => Err(internal_host_error()),
+        Some(url::Host::Ipv6(ip)) if internal_ip(IpAddr::V6(ip)) => Err(internal_host_error()),
+        Some(url::Host::Ipv4(_) | url::Host::Ipv6(_)) => Ok(None),
+        _ if host == "localhost"
+            || !host.contains('.')
+            || INTERNAL_HOST_SUFFIXES
+                .iter()
+                .any(|suffix| host.ends_with(suffix)) =>
+        {
+            Err(internal_host_error())
+        }
+        _ => Ok(Some(host)),
+    }
+}
+
+fn internal_host_error() -> String {
+    "webhook host is internal to the cluster or node; list it in \
+     OPERATOR_NOTIFICATION_ALLOWED_HOSTS to allow it"
+        .to_string()
+}
+
+/// Loopback, private, link-local (including cloud metadata), shared, and other non-public
+/// addresses.
+fn internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || first == 0
+                || (first == 100 && (64..128).contains(&second))
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return internal_ip(IpAddr::V4(mapped));
+            }
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::v1alpha1::status::pool::Pool as PoolStatus;
+
+    fn status(state: &str) -> Status {
+        Status {
+            current_state: state.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn state_transitions_are_reported_once() {
+        let degraded = status("Degraded");
+        let events: Vec<_> = transitions(Some(&status("Ready")), &degraded)
+            .into_iter()
+            .map(|transition| transition.event)
+            .collect();
+        assert_eq!(events, vec![NotificationEvent::Degraded]);
+        assert!(transitions(Some(&degraded), &degraded).is_empty());
+
+        let events: Vec<_> = transitions(Some(&degraded), &status("Ready"))
+            .into_iter()
+            .map(|transition| transition.event)
+            .collect();
+        assert_eq!(events, vec![NotificationEvent::Ready]);
+    }
+
+    #[test]
+    fn failed_pool_rollout_is_reported() {
+        let pool = |state: PoolState| PoolStatus {
+            name: Some("pool-0".to_string()),
+            ss_name: "t-pool-0".to_string(),
+            state: state.clone(),
+            lifecycle_state: None,
+            workload_state: Some(state),
+            decommission: None,
+            replicas: Some(4),
+            ready_replicas: None,
+            current_replicas: None,
+            updated_replicas: None,
+            current_revision: None,
+            update_revision: None,
+            last_update_time: None,
+            rollout_started_at: None,
+            pods: Vec::new(),
+            failed_volumes: Vec::new(),
+        };
+        let mut previous = status("Reconciling");
+        previous.pools = vec![pool(PoolState::Updating)];
+        let mut next = status("Reconciling");
+        next.pools = vec![pool(PoolState::RolloutFailed)];
+
+        let reported = transitions(Some(&previous), &next);
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].event, NotificationEvent::RolloutFailed);
+        assert_eq!(reported[0].message, "Pool 'pool-0' rollout failed");
+        assert!(transitions(Some(&next), &next).is_empty());
+    }
+
+    #[test]
+    fn templates_and_formats_render_the_transition() {
+        let transition = Transition {
+            event: NotificationEvent::Degraded,
+            state: "Degraded".to_string(),
+            message: "2/4 pods ready".to_string(),
+        };
+        let mut webhook = NotificationWebhook {
+            name: "oncall".to_string(),
+            format: WebhookFormat::Slack,
+            ..Default::default()
+        };
+        assert_eq!(
+            payload(&webhook, "t", "ns", &transition),
+            serde_json::json!({ "text": "RustFS Tenant ns/t is Degraded: 2/4 pods ready" })
+        );
+
+        webhook.format = WebhookFormat::Generic;
+        webhook.template = Some(":warning: {tenant} ({state})".to_string());
+        let body = payload(&webhook, "t", "ns", &transition);
+        assert_eq!(body["text"], ":warning: t (Degraded)");
+        assert_eq!(body["event"], "Degraded");
+        assert_eq!(body["message"], "2/4 pods ready");
+    }
+
+    #[test]
+    fn sends_are_limited_per_key() {
+        let notifier = Notifier::new(&crate::proxy::ProxyConfig::default());
+        let start = Instant::now();
+        let interval = Duration::from_secs(300);
+
+        assert!(notifier.try_acquire("ns/t/oncall/Degraded", interval, start));
+        assert!(!notifier.try_acquire("ns/t/oncall/Degraded", interval, start));
+        assert!(notifier.try_acquire("ns/t/oncall/Ready", interval, start));
+        assert!(notifier.try_acquire(
+            "ns/t/oncall/Degraded",
+            interval,
+            start + Duration::from_secs(301)
+        ));
+    }
+
+    #[test]
+    fn webhook_urls_must_be_https_and_external() {
+        let check = |url: &str, allowed: &[&str]| {
+            let allowed: Vec<String> = allowed.iter().map(|host| host.to_string()).collect();
+            let url = url::Url::parse(url).map_err(|error| error.to_string())?;
+            checked_host(&url, &allowed)
+        };
+
+        assert_eq!(
+            check("https://hooks.slack.com/services/x", &[]),
+            Ok(Some("hooks.slack.com".to_string()))
+        );
+        assert_eq!(check("https://8.8.8.8/hook", &[]), Ok(None));
+        assert!(check("http://hooks.slack.com/services/x", &[]).is_err());
+        for internal in [
+            "https://169.254.169.254/latest/meta-data",
+            "https://10.0.0.1/",
+            "https://127.0.0.1/",
+            "https://[::1]/",
+            "https://[::ffff:192.168.1.1]/",
+            "https://[fd00::1]/",
+            "https://localhost/",
+            "https://alertmanager/",
+            "https://alertmanager.monitoring.svc/",
+            "https://alertmanager.monitoring.svc.cluster.local/",
+            "https://metadata.google.internal/",
+        ] {
+            assert_eq!(
+                check(internal, &[]),
+                Err(internal_host_error()),
+                "{internal}"
+            );
+        }
+        assert_eq!(
+            check(
+                "https://alertmanager.monitoring.svc/",
+                &["alertmanager.monitoring.svc"]
+            ),
+            Ok(None)
+        );
+    }
+}
@@ -12,25 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::adoption::{reconcile_existing_claims, reconcile_local_volumes};
+use super::canary::CanaryPlan;
+use super::disruption;
+use super::drives;
+use super::federation;
+use super::gating;
+use super::namespace_policy::{self, NamespaceFilter, TenantsPerNamespace};
+use super::pod_health;
 use super::pool_lifecycle::{PoolLifecycleDecision, PoolLifecycleDecisions};
-use super::provisioning::{ProvisioningOutcome, reconcile_provisioning};
+use super::provisioning::{self, ProvisioningOutcome, reconcile_provisioning};
+use super::rebalance;
+use super::restore;
+use super::rollout_events;
+use super::verification;
 use super::{
     Error, cleanup_stuck_terminating_pods_on_down_nodes, context, context_result,
-    patch_status_and_record, patch_status_error, statefulset_owned_by_tenant, types_result,
+    node_down_cleanup_enabled, patch_status_and_record, patch_status_error,
+    statefulset_owned_by_tenant, types_result,
 };
-use crate::context::Context;
+use crate::context::{ApplyOutcome, Context, rendering_unchanged};
 use crate::status::{StatusBuilder, StatusError};
 use crate::types;
 use crate::types::v1alpha1::status::pool::PoolLifecycleState;
-use crate::types::v1alpha1::status::{ConditionType, Reason};
+use crate::types::v1alpha1::status::{ConditionStatus, ConditionType, Reason, RequeueReason};
 use crate::types::v1alpha1::tenant::Tenant;
 use crate::types::v1alpha1::tls::TlsPlan;
+use k8s_openapi::api::core::v1 as corev1;
 use kube::ResourceExt;
-use kube::api::{DeleteParams, ListParams, PropagationPolicy};
+use kube::api::{DeleteParams, PropagationPolicy};
 use kube::runtime::controller::Action;
 use kube::runtime::events::EventType;
 use std::collections::HashSet;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
 #[derive(Default)]
@@ -44,15 +58,22 @@ pub(super) struct PoolReconcileSummary {
     any_lifecycle_failed: bool,
     any_lifecycle_canceled: bool,
     lifecycle_requeue_after: Option<Duration>,
+    upgrade_status: Option<crate::types::v1alpha1::status::upgrade::UpgradeStatus>,
+    /// Pools whose StatefulSet update waits for the disruption in `disruption`.
+    deferred_pools: Vec<String>,
+    disruption: Option<String>,
     total_replicas: i32,
     ready_replicas: i32,
 }
 
 const REMOVED_POOL_CLEANUP_REQUEUE_INTERVAL: Duration = Duration::from_secs(10);
+const FAILED_VOLUME_REQUEUE_INTERVAL: Duration = Duration::from_secs(30);
 
 #[derive(Default)]
 pub(super) struct RemovedDecommissionedPoolCleanup {
     pub(super) allowed_removed_pool_names: HashSet<String>,
+    /// `Terminating` status entries for removed pools whose cleanup is still in progress.
+    pub(super) terminating_pools: Vec<crate::types::v1alpha1::status::pool::Pool>,
     pub(super) any_reconciling: bool,
     pub(super) requeue_after: Option<Duration>,
 }
@@ -81,16 +102,112 @@ pub(super) async fn validate_tenant_prerequisites(
         return Err(e.into());
     }
 
+    if let Err(e) = tenant.validate_ports() {
+        let status_error = StatusError::from_types_error(&e);
+        patch_status_error(ctx, tenant, &status_error).await;
+        return Err(e.into());
+    }
+
+    if let Err(e) = tenant.validate_rbac() {
+        let status_error = StatusError::from_types_error(&e);
+        patch_status_error(ctx, tenant, &status_error).await;
+        return Err(e.into());
+    }
+
+    if let Err(e) = tenant.validate_env_overrides() {
+        let status_error = StatusError::from_types_error(&e);
+        patch_status_error(ctx, tenant, &status_error).await;
+        return Err(e.into());
+    }
+
+    if let Err(e) = namespace_policy::check_namespace_allowed(tenant, &NamespaceFilter::from_env())
+    {
+        let status_error = StatusError::from_types_error(&e);
+        patch_status_error(ctx, tenant, &status_error).await;
+        return Err(e.into());
+    }
+
+    if namespace_policy::tenants_per_namespace() == TenantsPerNamespace::Single {
+        let namespace = types_result(tenant.namespace(), ctx, tenant).await?;
+        let namespace_tenants =
+            context_result(ctx.list::<Tenant>(&namespace).await, ctx, tenant).await?;
+        if let Err(e) = namespace_policy::check_single_tenant(tenant, &namespace_tenants.items) {
+            let status_error = StatusError::from_types_error(&e);
+            patch_status_error(ctx, tenant, &status_error).await;
+            return Err(e.into());
+        }
+    }
+
     // Validate credential Secret if configured.
     // This only validates the Secret exists and has required keys.
     // Actual credential injection happens via secretKeyRef in the StatefulSet.
     if let Some(ref cfg) = tenant.spec.creds_secret
         && !cfg.name.is_empty()
-        && let Err(e) = ctx.validate_credential_secret(tenant).await
     {
-        let status_error = StatusError::from_context_error(&e);
-        patch_status_error(ctx, tenant, &status_error).await;
-        return Err(e.into());
+        match ctx.validate_credential_secret(tenant).await {
+            Ok(report) => {
+                let finding = report.low_entropy_key.map(|key| {
+                    format!(
+                        "Credential Secret '{}' key '{key}' looks easy to guess; use a longer random value",
+                        cfg.name
+                    )
+                });
+                if ctx
+                    .warning_findings
+                    .report(tenant, "CredentialLowEntropy", finding.as_deref())
+                    && let Some(message) = finding
+                {
+                    let _ = ctx
+                        .record(tenant, EventType::Warning, "CredentialLowEntropy", &message)
+                        .await;
+                }
+            }
+            // Externally materialized Secrets are expected to be missing for a while.
+            Err(context::Error::CredentialSecretNotFound { .. }) if cfg.is_external() => {
+                let status_error = StatusError::waiting_for_credentials(&cfg.name);
+                patch_status_error(ctx, tenant, &status_error).await;
+                return Err(Error::CredentialsPending {
+                    name: cfg.name.clone(),
+                });
+            }
+            Err(e) => {
+                let status_error = StatusError::from_context_error(&e);
+                patch_status_error(ctx, tenant, &status_error).await;
+                return Err(e.into());
+            }
+        }
+    }
+
+    // A bad pull Secret does not block the reconcile: public images still pull without it.
+    // The event is recorded when the problem appears or changes, not on every reconcile.
+    if let Ok(namespace) = tenant.namespace() {
+        let problem = pod_health::image_pull_secret_problem(ctx, tenant, &namespace).await;
+        if ctx
+            .warning_findings
+            .report(tenant, "ImagePullSecretInvalid", problem.as_deref())
+            && let Some(problem) = problem
+        {
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Warning,
+                    "ImagePullSecretInvalid",
+                    &problem,
+                )
+                .await;
+        }
+    }
+
+    // A missing PriorityClass only keeps the affected pools from starting, so it is a warning.
+    for name in pod_health::missing_priority_classes(ctx, tenant).await {
+        let _ = ctx
+            .record(
+                tenant,
+                EventType::Warning,
+                "PriorityClassNotFound",
+                &format!("PriorityClass '{name}' was not found; pods using it will be rejected"),
+            )
+            .await;
     }
 
     // Validate encryption / KMS: Vault requires endpoint + kmsSecret (and correct keys);
@@ -131,10 +248,8 @@ pub(super) async fn maybe_cleanup_terminating_pods(
 ) -> Result<(), Error> {
     // Optional: unblock StatefulSet pods stuck terminating when their node is down.
     // This is inspired by Longhorn's "Pod Deletion Policy When Node is Down".
-    if let Some(policy) = tenant.spec.pod_deletion_policy_when_node_is_down.clone()
-        && policy != crate::types::v1alpha1::k8s::PodDeletionPolicyWhenNodeIsDown::DoNothing
-    {
-        cleanup_stuck_terminating_pods_on_down_nodes(tenant, namespace, ctx, policy).await?;
+    if node_down_cleanup_enabled(tenant) {
+        cleanup_stuck_terminating_pods_on_down_nodes(tenant, namespace, ctx).await?;
     }
     Ok(())
 }
@@ -145,6 +260,40 @@ pub(super) fn should_create_rbac(tenant: &Tenant) -> bool {
     !custom_sa || create_rbac
 }
 
+/// Applies the generated ServiceAccount, recording an event when a hand edit removed or changed
+/// one of `spec.serviceAccountAnnotations`.
+async fn apply_service_account(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+) -> Result<corev1::ServiceAccount, Error> {
+    let desired = tenant.new_service_account();
+    let mut drifted = false;
+    let (live, outcome) = context_result(
+        ctx.apply_if_changed_by(&desired, namespace, |live, desired| {
+            drifted = tenant.service_account_annotations_drifted(live);
+            rendering_unchanged(live, desired) && !drifted
+        })
+        .await,
+        ctx,
+        tenant,
+    )
+    .await?;
+    if outcome == ApplyOutcome::Updated && drifted {
+        let _ = ctx.record(
+            tenant,
+            EventType::Normal,
+            "ServiceAccountAnnotationsRestored",
+            &format!(
+                "ServiceAccount '{}' annotations differed from spec.serviceAccountAnnotations; re-applied",
+                live.name_any()
+            ),
+        )
+        .await;
+    }
+    Ok(live)
+}
+
 pub(super) async fn reconcile_rbac_resources(
     ctx: &Context,
     tenant: &Tenant,
@@ -154,26 +303,40 @@ pub(super) async fn reconcile_rbac_resources(
         return Ok(());
     }
 
-    let role = context_result(ctx.apply(&tenant.new_role(), namespace).await, ctx, tenant).await?;
+    if !tenant
+        .spec
+        .rbac
+        .as_ref()
+        .is_none_or(|rbac| rbac.create_role())
+    {
+        // The workload still runs as the default ServiceAccount.
+        if tenant.spec.service_account_name.is_none() {
+            apply_service_account(ctx, tenant, namespace).await?;
+        }
+        reconcile_pool_role_bindings(ctx, tenant, namespace, None).await?;
+        return remove_rbac_role(ctx, tenant, namespace).await;
+    }
+
+    let role = context_result(
+        ctx.apply_if_changed(&tenant.new_role(), namespace).await,
+        ctx,
+        tenant,
+    )
+    .await?;
 
     if tenant.spec.service_account_name.is_some() {
         let sa_name = tenant.service_account_name();
         context_result(
-            ctx.apply(&tenant.new_role_binding(&sa_name, &role), namespace)
+            ctx.apply_if_changed(&tenant.new_role_binding(&sa_name, &role), namespace)
                 .await,
             ctx,
             tenant,
         )
         .await?;
     } else {
-        let service_account = context_result(
-            ctx.apply(&tenant.new_service_account(), namespace).await,
-            ctx,
-            tenant,
-        )
-        .await?;
+        let service_account = apply_service_account(ctx, tenant, namespace).await?;
         context_result(
-            ctx.apply(
+            ctx.apply_if_changed(
                 &tenant.new_role_binding(&service_account.name_any(), &role),
                 namespace,
             )
@@ -184,40 +347,102 @@ pub(super) async fn reconcile_rbac_resources(
         .await?;
     }
 
-    Ok(())
+    reconcile_pool_role_bindings(ctx, tenant, namespace, Some(&role)).await
 }
 
-pub(super) async fn reconcile_services(
+/// Binds `role` to every pool ServiceAccount set in `spec.pools[].serviceAccountName` and
+/// deletes per-pool RoleBindings that are no longer wanted. With no `role`, deletes them all.
+async fn reconcile_pool_role_bindings(
     ctx: &Context,
     tenant: &Tenant,
     namespace: &str,
-    tls_plan: &TlsPlan,
+    role: Option<&k8s_openapi::api::rbac::v1::Role>,
 ) -> Result<(), Error> {
-    context_result(
-        ctx.apply(&tenant.new_io_service_with_tls_plan(tls_plan), namespace)
+    let desired: Vec<_> = role
+        .map(|role| {
+            tenant
+                .spec
+                .pools
+                .iter()
+                .filter_map(|pool| tenant.new_pool_role_binding(pool, role))
+                .collect()
+        })
+        .unwrap_or_default();
+    for role_binding in &desired {
+        context_result(
+            ctx.apply_if_changed(role_binding, namespace).await,
+            ctx,
+            tenant,
+        )
+        .await?;
+    }
+
+    let selector = format!(
+        "{},{}",
+        tenant.pod_selector(),
+        crate::types::v1alpha1::tenant::POOL_LABEL
+    );
+    let existing = context_result(
+        ctx.list_labeled::<k8s_openapi::api::rbac::v1::RoleBinding>(namespace, &selector)
             .await,
         ctx,
         tenant,
     )
     .await?;
+    for role_binding in existing {
+        let name = role_binding.name_any();
+        if desired.iter().any(|desired| desired.name_any() == name)
+            || !role_binding
+                .owner_references()
+                .iter()
+                .any(|owner| owner.uid == tenant.uid().unwrap_or_default())
+        {
+            continue;
+        }
+        match ctx
+            .delete::<k8s_openapi::api::rbac::v1::RoleBinding>(&name, namespace)
+            .await
+        {
+            Err(error) if crate::context::is_kube_not_found(&error) => {}
+            result => context_result(result, ctx, tenant).await?,
+        }
+    }
+    Ok(())
+}
+
+/// Deletes a Role and RoleBinding created before `spec.rbac.createRole` was turned off.
+async fn remove_rbac_role(ctx: &Context, tenant: &Tenant, namespace: &str) -> Result<(), Error> {
+    match ctx
+        .delete::<k8s_openapi::api::rbac::v1::RoleBinding>(&tenant.role_binding_name(), namespace)
+        .await
+    {
+        Err(error) if crate::context::is_kube_not_found(&error) => {}
+        result => context_result(result, ctx, tenant).await?,
+    }
+    match ctx
+        .delete::<k8s_openapi::api::rbac::v1::Role>(&tenant.role_name(), namespace)
+        .await
+    {
+        Err(error) if crate::context::is_kube_not_found(&error) => Ok(()),
+        result => context_result(result, ctx, tenant).await,
+    }
+}
+
+pub(super) async fn reconcile_services(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    tls_plan: &TlsPlan,
+) -> Result<(), Error> {
+    let config_map = types_result(tenant.new_config_map(tls_plan), ctx, tenant).await?;
     context_result(
-        ctx.apply(&tenant.new_console_service(), namespace).await,
-        ctx,
-        tenant,
-    )
-    .await?;
-    context_result(
-        ctx.apply(
-            &tenant.new_headless_service_with_tls_plan(tls_plan),
-            namespace,
-        )
-        .await,
+        ctx.apply_if_changed(&config_map, namespace).await,
         ctx,
         tenant,
     )
     .await?;
 
-    Ok(())
+    super::services::reconcile_services(ctx, tenant, namespace, tls_plan).await
 }
 
 pub(super) async fn cleanup_removed_decommissioned_pool_statefulsets(
@@ -226,9 +451,9 @@ pub(super) async fn cleanup_removed_decommissioned_pool_statefulsets(
     namespace: &str,
 ) -> Result<RemovedDecommissionedPoolCleanup, Error> {
     let owned_statefulsets = context_result(
-        ctx.list_with_params::<k8s_openapi::api::apps::v1::StatefulSet>(
+        ctx.list_labeled::<k8s_openapi::api::apps::v1::StatefulSet>(
             namespace,
-            &ListParams::default().labels(&format!("rustfs.tenant={}", tenant.name())),
+            &tenant.pod_selector(),
         )
         .await,
         ctx,
@@ -310,16 +535,174 @@ pub(super) async fn cleanup_removed_decommissioned_pool_statefulsets(
         }
     }
 
+    track_terminating_pools(
+        ctx,
+        tenant,
+        namespace,
+        &owned_statefulsets,
+        &current_pool_names,
+        &mut cleanup,
+    )
+    .await?;
+
     Ok(cleanup)
 }
 
+/// What is left of a pool that was removed from spec.
+#[derive(Clone, Copy, Debug, Default)]
+struct RemovedPoolProgress {
+    statefulset_exists: bool,
+    remaining_pods: i32,
+    retained_claims: i32,
+    terminating_claims: i32,
+}
+
+/// Keeps a `Terminating` status entry for every decommissioned pool removed from spec until
+/// its StatefulSet and pods are gone and none of its PVCs is still being deleted.
+async fn track_terminating_pools(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    owned_statefulsets: &[k8s_openapi::api::apps::v1::StatefulSet],
+    current_pool_names: &HashSet<&str>,
+    cleanup: &mut RemovedDecommissionedPoolCleanup,
+) -> Result<(), Error> {
+    let previous_pools = tenant
+        .status
+        .as_ref()
+        .map(|status| status.pools.as_slice())
+        .unwrap_or_default();
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    for previous in previous_pools {
+        let Some(pool_name) = previous.name.as_deref() else {
+            continue;
+        };
+        if current_pool_names.contains(pool_name)
+            || !matches!(
+                previous.lifecycle_state,
+                Some(PoolLifecycleState::Decommissioned | PoolLifecycleState::Terminating)
+            )
+        {
+            continue;
+        }
+
+        let selector = crate::types::v1alpha1::tenant::pool_selector(&tenant.name(), pool_name);
+        let pods = context_result(
+            ctx.list_labeled::<k8s_openapi::api::core::v1::Pod>(namespace, &selector)
+                .await,
+            ctx,
+            tenant,
+        )
+        .await?;
+        let claims = context_result(
+            ctx.list_labeled::<k8s_openapi::api::core::v1::PersistentVolumeClaim>(
+                namespace, &selector,
+            )
+            .await,
+            ctx,
+            tenant,
+        )
+        .await?;
+        let terminating_claims = claims
+            .iter()
+            .filter(|claim| claim.metadata.deletion_timestamp.is_some())
+            .count() as i32;
+        let progress = RemovedPoolProgress {
+            statefulset_exists: owned_statefulsets
+                .iter()
+                .any(|ss| ss.metadata.name.as_deref() == Some(previous.ss_name.as_str())),
+            remaining_pods: pods.len() as i32,
+            retained_claims: claims.len() as i32 - terminating_claims,
+            terminating_claims,
+        };
+
+        match terminating_pool_status(previous, progress, &now) {
+            Some(status) => {
+                cleanup.mark_reconciling();
+                cleanup.terminating_pools.push(status);
+            }
+            None if previous.lifecycle_state == Some(PoolLifecycleState::Terminating) => {
+                let _ = ctx
+                    .record(
+                        tenant,
+                        EventType::Normal,
+                        "PoolRemovalCompleted",
+                        &format!(
+                            "Pool '{}' StatefulSet and pods are deleted; {} PVC(s) retained",
+                            pool_name, progress.retained_claims
+                        ),
+                    )
+                    .await;
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// The `Terminating` entry for a removed pool, or `None` once its cleanup is complete.
+fn terminating_pool_status(
+    previous: &crate::types::v1alpha1::status::pool::Pool,
+    progress: RemovedPoolProgress,
+    now: &str,
+) -> Option<crate::types::v1alpha1::status::pool::Pool> {
+    use crate::types::v1alpha1::status::pool::{
+        PoolDecommissionCleanupState, PoolDecommissionCleanupStatus,
+    };
+
+    if !progress.statefulset_exists
+        && progress.remaining_pods == 0
+        && progress.terminating_claims == 0
+    {
+        return None;
+    }
+
+    let mut status = previous.clone();
+    status.lifecycle_state = Some(PoolLifecycleState::Terminating);
+    status.pods = Vec::new();
+    status.failed_volumes = Vec::new();
+    status.last_update_time = Some(now.to_string());
+
+    let decommission = status
+        .decommission
+        .get_or_insert_with(super::pool_lifecycle::empty_decommission_status);
+    let cleanup = decommission
+        .cleanup
+        .get_or_insert_with(|| PoolDecommissionCleanupStatus {
+            state: PoolDecommissionCleanupState::StatefulSetDeleting,
+            stateful_set_deleted_at: None,
+            pvc_retention_policy: Some("Retain".to_string()),
+            removed_from_spec_at: None,
+            remaining_pods: None,
+            retained_claims: None,
+            terminating_claims: None,
+        });
+    if progress.statefulset_exists {
+        cleanup.state = PoolDecommissionCleanupState::StatefulSetDeleting;
+    } else {
+        cleanup.state = PoolDecommissionCleanupState::PvcRetained;
+        cleanup
+            .stateful_set_deleted_at
+            .get_or_insert_with(|| now.to_string());
+    }
+    cleanup
+        .removed_from_spec_at
+        .get_or_insert_with(|| now.to_string());
+    cleanup.remaining_pods = Some(progress.remaining_pods);
+    cleanup.retained_claims = Some(progress.retained_claims);
+    cleanup.terminating_claims = Some(progress.terminating_claims);
+    Some(status)
+}
+
 fn removed_pool_is_decommissioned(tenant: &Tenant, pool_name: &str, ss_name: &str) -> bool {
     tenant.status.as_ref().is_some_and(|status| {
         status.pools.iter().any(|pool_status| {
             (pool_status.name.as_deref() == Some(pool_name) || pool_status.ss_name == ss_name)
                 && matches!(
                     pool_status.lifecycle_state,
-                    Some(PoolLifecycleState::Decommissioned)
+                    Some(PoolLifecycleState::Decommissioned | PoolLifecycleState::Terminating)
                 )
         })
     })
@@ -332,9 +715,9 @@ pub(super) async fn validate_no_pool_rename(
     allowed_removed_pool_names: &HashSet<String>,
 ) -> Result<(), Error> {
     let owned_statefulsets = context_result(
-        ctx.list_with_params::<k8s_openapi::api::apps::v1::StatefulSet>(
+        ctx.list_labeled::<k8s_openapi::api::apps::v1::StatefulSet>(
             namespace,
-            &ListParams::default().labels(&format!("rustfs.tenant={}", tenant.name())),
+            &tenant.pod_selector(),
         )
         .await,
         ctx,
@@ -416,6 +799,7 @@ pub(super) async fn reconcile_pool_statefulsets(
     tls_plan: &TlsPlan,
     lifecycle_decisions: &PoolLifecycleDecisions,
     removed_pool_cleanup: &RemovedDecommissionedPoolCleanup,
+    canary_plan: CanaryPlan,
 ) -> Result<PoolReconcileSummary, Error> {
     let mut summary = PoolReconcileSummary {
         any_lifecycle_reconciling: lifecycle_decisions.any_reconciling,
@@ -423,8 +807,11 @@ pub(super) async fn reconcile_pool_statefulsets(
         any_lifecycle_failed: lifecycle_decisions.any_failed,
         any_lifecycle_canceled: lifecycle_decisions.any_canceled,
         lifecycle_requeue_after: earliest_requeue_after(
-            lifecycle_decisions.requeue_after,
-            removed_pool_cleanup.requeue_after,
+            earliest_requeue_after(
+                lifecycle_decisions.requeue_after,
+                removed_pool_cleanup.requeue_after,
+            ),
+            canary_plan.requeue_after,
         ),
         ..Default::default()
     };
@@ -434,6 +821,22 @@ pub(super) async fn reconcile_pool_statefulsets(
 
     for pool in &tenant.spec.pools {
         let ss_name = format!("{}-{}", tenant.name(), pool.name);
+        if let Ok((_, overridden)) = tenant.pool_env(pool, tls_plan)
+            && !overridden.is_empty()
+        {
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Warning,
+                    "OperatorEnvOverridden",
+                    &format!(
+                        "Pool '{}' env replaces operator-managed variables: {}",
+                        pool.name,
+                        overridden.join(", ")
+                    ),
+                )
+                .await;
+        }
         let lifecycle_decision = lifecycle_decisions.decision_for(&pool.name);
         if lifecycle_decision.is_some_and(|decision| decision.skip_workload_reconcile) {
             reconcile_lifecycle_gated_pool_statefulset(
@@ -448,6 +851,15 @@ pub(super) async fn reconcile_pool_statefulsets(
             .await?;
             continue;
         }
+        if pool.is_paused() {
+            reconcile_paused_pool_statefulset(ctx, tenant, namespace, pool, &ss_name, &mut summary)
+                .await?;
+            continue;
+        }
+
+        reconcile_existing_claims(ctx, tenant, namespace, pool).await?;
+        reconcile_local_volumes(ctx, tenant, namespace, pool).await?;
+        restore::reconcile_snapshot_restore(ctx, tenant, namespace, pool, &ss_name).await?;
 
         match ctx
             .get::<k8s_openapi::api::apps::v1::StatefulSet>(&ss_name, namespace)
@@ -464,6 +876,7 @@ pub(super) async fn reconcile_pool_statefulsets(
                     pool,
                     &ss_name,
                     tls_plan,
+                    &canary_plan,
                     &mut summary,
                 )
                 .await?;
@@ -490,25 +903,79 @@ pub(super) async fn reconcile_pool_statefulsets(
             let pool_status = tenant.build_pool_status(&pool.name, &existing_ss);
             update_pool_summary(&mut summary, pool_status);
         }
-        return Ok(summary);
+    } else {
+        // A standalone Tenant has no redundancy to protect, so drains never defer its updates.
+        if !existing_pool_statefulsets.is_empty() && !tenant.is_standalone() {
+            summary.disruption = disruption::in_progress(ctx, tenant, namespace).await;
+        }
+        for (pool, existing_ss) in existing_pool_statefulsets {
+            reconcile_existing_pool_statefulset(
+                ctx,
+                tenant,
+                namespace,
+                pool,
+                existing_ss,
+                tls_plan,
+                &canary_plan,
+                &mut summary,
+            )
+            .await?;
+        }
     }
 
-    for (pool, existing_ss) in existing_pool_statefulsets {
-        reconcile_existing_pool_statefulset(
-            ctx,
-            tenant,
-            namespace,
-            pool,
-            existing_ss,
-            tls_plan,
-            &mut summary,
-        )
-        .await?;
+    attach_pool_pod_statuses(ctx, tenant, namespace, &mut summary).await?;
+    if drives::reconcile_failed_volumes(ctx, tenant, namespace, &mut summary.pool_statuses).await? {
+        summary.any_degraded = true;
+        summary.lifecycle_requeue_after = earliest_requeue_after(
+            summary.lifecycle_requeue_after,
+            Some(FAILED_VOLUME_REQUEUE_INTERVAL),
+        );
     }
+    summary.upgrade_status = canary_plan.status;
+    summary
+        .pool_statuses
+        .extend(removed_pool_cleanup.terminating_pools.iter().cloned());
 
     Ok(summary)
 }
 
+/// Refresh `status.pools[].pods` so external tooling can address individual servers
+/// without reimplementing the StatefulSet/headless Service naming scheme.
+async fn attach_pool_pod_statuses(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    summary: &mut PoolReconcileSummary,
+) -> Result<(), Error> {
+    let pods = context_result(
+        ctx.list_labeled::<k8s_openapi::api::core::v1::Pod>(namespace, &tenant.pod_selector())
+            .await,
+        ctx,
+        tenant,
+    )
+    .await?;
+
+    for pool_status in &mut summary.pool_statuses {
+        if matches!(
+            pool_status.lifecycle_state,
+            Some(PoolLifecycleState::Decommissioned)
+        ) {
+            continue;
+        }
+        let Some(pool) = tenant
+            .spec
+            .pools
+            .iter()
+            .find(|pool| pool_status.name.as_deref() == Some(pool.name.as_str()))
+        else {
+            continue;
+        };
+        pool_status.pods = tenant.build_pool_pod_statuses(pool, namespace, &pods);
+    }
+
+    Ok(())
+}
+
 fn earliest_requeue_after(left: Option<Duration>, right: Option<Duration>) -> Option<Duration> {
     match (left, right) {
         (Some(left), Some(right)) => Some(left.min(right)),
@@ -543,18 +1010,7 @@ async fn reconcile_lifecycle_gated_pool_statefulset(
         "skipping normal StatefulSet reconcile because pool lifecycle gate is active"
     );
 
-    let mut pool_status = match ctx
-        .get::<k8s_openapi::api::apps::v1::StatefulSet>(ss_name, namespace)
-        .await
-    {
-        Ok(ss) => tenant.build_pool_status(&pool.name, &ss),
-        Err(error) if is_not_found_context_error(&error) => missing_pool_status(tenant, &pool.name),
-        Err(error) => {
-            let status_error = StatusError::from_context_error(&error);
-            patch_status_error(ctx, tenant, &status_error).await;
-            return Err(error.into());
-        }
-    };
+    let mut pool_status = observed_pool_status(ctx, tenant, namespace, &pool.name, ss_name).await?;
 
     if let Some(decision) = lifecycle_decision {
         apply_lifecycle_decision(&mut pool_status, decision);
@@ -565,6 +1021,54 @@ async fn reconcile_lifecycle_gated_pool_statefulset(
     Ok(())
 }
 
+/// Reports a paused pool without creating, updating, or scaling its StatefulSet.
+async fn reconcile_paused_pool_statefulset(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool: &crate::types::v1alpha1::pool::Pool,
+    ss_name: &str,
+    summary: &mut PoolReconcileSummary,
+) -> Result<(), Error> {
+    debug!(
+        tenant = %tenant.name(),
+        namespace = %namespace,
+        pool = %pool.name,
+        "skipping StatefulSet reconcile because the pool is paused"
+    );
+
+    let mut pool_status = observed_pool_status(ctx, tenant, namespace, &pool.name, ss_name).await?;
+    pool_status.workload_state = Some(pool_status.state.clone());
+    pool_status.state = crate::types::v1alpha1::status::pool::PoolState::Paused;
+    update_pool_summary(summary, pool_status);
+
+    Ok(())
+}
+
+/// Status of a pool as its StatefulSet currently stands, or `NotCreated` if it does not exist.
+pub(super) async fn observed_pool_status(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool_name: &str,
+    ss_name: &str,
+) -> Result<crate::types::v1alpha1::status::pool::Pool, Error> {
+    match ctx
+        .get::<k8s_openapi::api::apps::v1::StatefulSet>(ss_name, namespace)
+        .await
+    {
+        Ok(ss) => Ok(tenant.build_pool_status(pool_name, &ss)),
+        Err(error) if is_not_found_context_error(&error) => {
+            Ok(missing_pool_status(tenant, pool_name))
+        }
+        Err(error) => {
+            let status_error = StatusError::from_context_error(&error);
+            patch_status_error(ctx, tenant, &status_error).await;
+            Err(error.into())
+        }
+    }
+}
+
 fn missing_pool_status(
     tenant: &Tenant,
     pool_name: &str,
@@ -585,6 +1089,9 @@ fn missing_pool_status(
         last_update_time: Some(
             chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
         ),
+        rollout_started_at: None,
+        pods: Vec::new(),
+        failed_volumes: Vec::new(),
     }
 }
 
@@ -597,6 +1104,7 @@ fn apply_lifecycle_decision(
     pool_status.decommission = decision.decommission.clone();
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn reconcile_existing_pool_statefulset(
     ctx: &Context,
     tenant: &Tenant,
@@ -604,6 +1112,7 @@ async fn reconcile_existing_pool_statefulset(
     pool: &crate::types::v1alpha1::pool::Pool,
     existing_ss: k8s_openapi::api::apps::v1::StatefulSet,
     tls_plan: &TlsPlan,
+    canary_plan: &CanaryPlan,
     summary: &mut PoolReconcileSummary,
 ) -> Result<(), Error> {
     let ss_name = existing_ss.name_any();
@@ -636,7 +1145,23 @@ async fn reconcile_existing_pool_statefulset(
         tenant,
     )
     .await?
+        || canary_plan.partition_changed(&pool.name, &existing_ss)
     {
+        if let Some(disruption) = &summary.disruption {
+            info!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                pool = %pool.name,
+                statefulset = %ss_name,
+                %disruption,
+                "deferring StatefulSet update until the disruption finishes"
+            );
+            summary.deferred_pools.push(pool.name.clone());
+            let pool_status = tenant.build_pool_status(&pool.name, &existing_ss);
+            update_pool_summary(summary, pool_status);
+            return Ok(());
+        }
+
         info!(
             tenant = %tenant.name(),
             namespace = %namespace,
@@ -654,12 +1179,13 @@ async fn reconcile_existing_pool_statefulset(
             )
             .await;
 
-        let desired = types_result(
+        let mut desired = types_result(
             tenant.new_statefulset_with_tls_plan(pool, tls_plan),
             ctx,
             tenant,
         )
         .await?;
+        canary_plan.apply_partition(&pool.name, &mut desired);
         if let Err(e) = ctx.apply(&desired, namespace).await {
             let status_error = StatusError::statefulset_apply_failed(&ss_name);
             patch_status_error(ctx, tenant, &status_error).await;
@@ -696,6 +1222,7 @@ async fn reconcile_existing_pool_statefulset(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn reconcile_missing_pool_statefulset(
     ctx: &Context,
     tenant: &Tenant,
@@ -703,6 +1230,7 @@ async fn reconcile_missing_pool_statefulset(
     pool: &crate::types::v1alpha1::pool::Pool,
     ss_name: &str,
     tls_plan: &TlsPlan,
+    canary_plan: &CanaryPlan,
     summary: &mut PoolReconcileSummary,
 ) -> Result<(), Error> {
     info!(
@@ -722,12 +1250,13 @@ async fn reconcile_missing_pool_statefulset(
         )
         .await;
 
-    let desired = types_result(
+    let mut desired = types_result(
         tenant.new_statefulset_with_tls_plan(pool, tls_plan),
         ctx,
         tenant,
     )
     .await?;
+    canary_plan.apply_partition(&pool.name, &mut desired);
     if let Err(e) = ctx.apply(&desired, namespace).await {
         let status_error = StatusError::statefulset_apply_failed(ss_name);
         patch_status_error(ctx, tenant, &status_error).await;
@@ -799,14 +1328,60 @@ pub(super) async fn finalize_tenant_status(
     tenant: &Tenant,
     summary: PoolReconcileSummary,
     tls_plan: TlsPlan,
+    started: Instant,
 ) -> Result<Action, Error> {
     let mut builder = StatusBuilder::from_tenant(tenant);
+    builder.set_observed_spec_hash(gating::spec_hash(tenant));
+    if let Ok(namespace) = tenant.namespace() {
+        builder.set_reconcile_error_count(crate::metrics::tenant_reconcile_errors(
+            &namespace,
+            &tenant.name(),
+        ));
+    }
     let pool_count = summary.pool_statuses.len();
-    builder.set_pool_statuses(summary.pool_statuses);
+    let previous_pools = tenant
+        .status
+        .as_ref()
+        .map(|status| status.pools.clone())
+        .unwrap_or_default();
+    let mut pool_statuses = summary.pool_statuses;
+    let now = chrono::Utc::now();
+    rollout_events::track_rollout_start(
+        &previous_pools,
+        &mut pool_statuses,
+        &now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    );
+    let mut milestones = rollout_events::milestones(&previous_pools, &pool_statuses, now);
+    milestones.extend(
+        rollout_events::stalled_rollouts(
+            ctx,
+            tenant,
+            &tenant.namespace()?,
+            &previous_pools,
+            &pool_statuses,
+        )
+        .await,
+    );
+    builder.set_pool_statuses(pool_statuses);
     if let Some(tls_status) = tls_plan.status {
         builder.set_tls_status(tls_status);
     }
+    let pod_health = pod_health::inspect(ctx, tenant, &tenant.namespace()?).await;
+    let image_pull_message = pod_health.image_pull_message();
+    builder.set_image_pull_failed(image_pull_message.clone());
+    let crash_loop_message = pod_health.crash_loop_message();
+    builder.set_pods_crash_looping(crash_loop_message.clone());
+    let deferred_message = summary.disruption.as_ref().and_then(|disruption| {
+        (!summary.deferred_pools.is_empty()).then(|| {
+            format!(
+                "StatefulSet update of pool(s) {} deferred: {disruption}",
+                summary.deferred_pools.join(", ")
+            )
+        })
+    });
+    builder.set_deferred_due_to_disruption(deferred_message.clone());
 
+    let mut verification_retry = false;
     let (event_condition, event_reason, event_type, event_message) = if summary.any_lifecycle_failed
     {
         builder.finish_degraded(
@@ -877,6 +1452,38 @@ pub(super) async fn finalize_tenant_status(
             EventType::Warning,
             "One or more pools are degraded".to_string(),
         )
+    } else if let Some(message) = image_pull_message.clone() {
+        builder.finish_degraded(
+            Reason::ImagePullBackOff,
+            ConditionType::WorkloadsReady,
+            message.clone(),
+        );
+        (
+            ConditionType::ImagePullFailed,
+            Reason::ImagePullBackOff,
+            EventType::Warning,
+            message,
+        )
+    } else if let Some(message) = crash_loop_message.clone() {
+        builder.finish_degraded(
+            Reason::CrashLoopBackOff,
+            ConditionType::WorkloadsReady,
+            message.clone(),
+        );
+        (
+            ConditionType::PodsCrashLooping,
+            Reason::CrashLoopBackOff,
+            EventType::Warning,
+            message,
+        )
+    } else if let Some(message) = deferred_message.clone() {
+        builder.finish_reconciling(Reason::DisruptionInProgress, message.clone());
+        (
+            ConditionType::DeferredDueToDisruption,
+            Reason::DisruptionInProgress,
+            EventType::Normal,
+            message,
+        )
     } else if summary.any_updating {
         builder.finish_reconciling(
             Reason::RolloutInProgress,
@@ -890,11 +1497,16 @@ pub(super) async fn finalize_tenant_status(
         )
     } else if summary.ready_replicas == summary.total_replicas && summary.total_replicas > 0 {
         let namespace = tenant.namespace()?;
+        let bootstrap_pending = provisioning::bootstrap_pending(tenant);
         let provisioning = reconcile_provisioning(ctx, tenant, &namespace).await;
         builder.set_provisioning_status(provisioning.status);
+        if bootstrap_pending {
+            record_bootstrap_outcome(ctx, tenant, &mut builder, &provisioning.outcome).await;
+        }
         match provisioning.outcome {
             ProvisioningOutcome::Ready => {
                 builder.finish_provisioning_ready();
+                verification_retry = verification::verify_if_due(ctx, tenant, &mut builder).await;
                 (
                     ConditionType::Ready,
                     Reason::ReconcileSucceeded,
@@ -943,6 +1555,58 @@ pub(super) async fn finalize_tenant_status(
         )
     };
 
+    builder.set_upgrade_status(summary.upgrade_status);
+    if let Ok(namespace) = tenant.namespace() {
+        builder.set_mirror_statuses(federation::sync_mirrors(ctx, tenant, &namespace).await);
+    }
+    let workloads_ready = summary.total_replicas > 0
+        && summary.ready_replicas == summary.total_replicas
+        && !summary.any_updating
+        && !summary.any_lifecycle_reconciling;
+    let (rebalance_status, rebalance_requeue) =
+        rebalance::reconcile(ctx, tenant, workloads_ready).await;
+    builder.set_rebalance_status(rebalance_status);
+    let (requeue_after, requeue_reason) = if let Some(after) = summary.lifecycle_requeue_after {
+        (Some(after), RequeueReason::PoolLifecycle)
+    } else if image_pull_message.is_some() {
+        (
+            Some(pod_health::IMAGE_PULL_REQUEUE_INTERVAL),
+            RequeueReason::ImagePullBackOff,
+        )
+    } else if let Some(after) = pod_health.crash_loop_requeue() {
+        (Some(after), RequeueReason::CrashLoopBackOff)
+    } else if deferred_message.is_some() {
+        (
+            Some(disruption::DEFERRED_REQUEUE_INTERVAL),
+            RequeueReason::DisruptionInProgress,
+        )
+    } else if summary.any_updating {
+        (
+            Some(Duration::from_secs(10)),
+            RequeueReason::RolloutInProgress,
+        )
+    } else if verification_retry {
+        (
+            Some(verification::RETRY_INTERVAL),
+            RequeueReason::VerificationRetry,
+        )
+    } else if rebalance_requeue {
+        (
+            Some(rebalance::POLL_INTERVAL),
+            RequeueReason::RebalanceInProgress,
+        )
+    } else if !federation::mirrors(tenant).is_empty() {
+        (
+            Some(federation::MIRROR_RESYNC_INTERVAL),
+            RequeueReason::MirrorResync,
+        )
+    } else if let Some(after) = ctx.resync.interval(tenant) {
+        (Some(after), RequeueReason::PeriodicResync)
+    } else {
+        (None, RequeueReason::WaitingForChange)
+    };
+    builder.set_requeue(requeue_after, requeue_reason);
+    builder.set_reconcile_duration(started.elapsed());
     let status = builder.build();
     debug!(
         tenant = %tenant.name(),
@@ -967,25 +1631,57 @@ pub(super) async fn finalize_tenant_status(
         &event_message,
     )
     .await?;
+    rollout_events::record(ctx, tenant, milestones).await;
+
+    match requeue_after {
+        Some(requeue_after) if requeue_reason != RequeueReason::PeriodicResync => {
+            debug!(
+                tenant = %tenant.name(),
+                namespace = ?tenant.namespace(),
+                seconds = requeue_after.as_secs(),
+                reason = requeue_reason.as_str(),
+                "requeuing Tenant"
+            );
+            Ok(Action::requeue(requeue_after))
+        }
+        // The periodic resync is applied by the controller from the Tenant priority.
+        _ => Ok(Action::await_change()),
+    }
+}
 
-    if let Some(requeue_after) = summary.lifecycle_requeue_after {
-        debug!(
-            tenant = %tenant.name(),
-            namespace = ?tenant.namespace(),
-            seconds = requeue_after.as_secs(),
-            "Pool lifecycle is active, requeuing"
-        );
-        Ok(Action::requeue(requeue_after))
-    } else if summary.any_updating {
-        debug!(
-            tenant = %tenant.name(),
-            namespace = ?tenant.namespace(),
-            seconds = 10,
-            "Pools are updating, requeuing"
-        );
-        Ok(Action::requeue(Duration::from_secs(10)))
-    } else {
-        Ok(Action::await_change())
+/// Records the `Bootstrapped` condition while `spec.bootstrap` has not completed yet.
+async fn record_bootstrap_outcome(
+    ctx: &Context,
+    tenant: &Tenant,
+    builder: &mut StatusBuilder,
+    outcome: &ProvisioningOutcome,
+) {
+    match outcome {
+        ProvisioningOutcome::Ready => {
+            builder.set_bootstrapped(
+                ConditionStatus::True,
+                Reason::ProvisioningConfigured,
+                "Bootstrap buckets, users, and policies were created".to_string(),
+            );
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Normal,
+                    "Bootstrapped",
+                    "Bootstrap buckets, users, and policies were created",
+                )
+                .await;
+        }
+        ProvisioningOutcome::Pending { message } => {
+            builder.set_bootstrapped(
+                ConditionStatus::False,
+                Reason::ProvisioningPending,
+                message.clone(),
+            );
+        }
+        ProvisioningOutcome::Failed { reason, message } => {
+            builder.set_bootstrapped(ConditionStatus::False, *reason, message.clone());
+        }
     }
 }
 
@@ -993,6 +1689,69 @@ pub(super) async fn finalize_tenant_status(
 mod tests {
     use super::*;
 
+    #[test]
+    fn removed_pool_stays_terminating_until_statefulset_and_pods_are_gone() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let mut previous = missing_pool_status(&tenant, "pool-0");
+        previous.lifecycle_state = Some(PoolLifecycleState::Decommissioned);
+        let t0 = "2025-01-01T00:00:00Z";
+        let t1 = "2025-01-01T00:01:00Z";
+
+        let deleting = terminating_pool_status(
+            &previous,
+            RemovedPoolProgress {
+                statefulset_exists: true,
+                remaining_pods: 2,
+                retained_claims: 4,
+                terminating_claims: 0,
+            },
+            t0,
+        );
+        let deleting = deleting.expect("pool with a StatefulSet is still terminating");
+        assert_eq!(
+            deleting.lifecycle_state,
+            Some(PoolLifecycleState::Terminating)
+        );
+        let cleanup = deleting
+            .decommission
+            .as_ref()
+            .and_then(|status| status.cleanup.as_ref())
+            .expect("cleanup progress is recorded");
+        assert_eq!(cleanup.removed_from_spec_at.as_deref(), Some(t0));
+        assert_eq!(cleanup.remaining_pods, Some(2));
+        assert_eq!(cleanup.stateful_set_deleted_at, None);
+
+        let draining = terminating_pool_status(
+            &deleting,
+            RemovedPoolProgress {
+                remaining_pods: 1,
+                retained_claims: 4,
+                ..Default::default()
+            },
+            t1,
+        )
+        .expect("pool with pods is still terminating");
+        let cleanup = draining
+            .decommission
+            .as_ref()
+            .and_then(|status| status.cleanup.as_ref())
+            .expect("cleanup progress is recorded");
+        assert_eq!(cleanup.removed_from_spec_at.as_deref(), Some(t0));
+        assert_eq!(cleanup.stateful_set_deleted_at.as_deref(), Some(t1));
+
+        assert!(
+            terminating_pool_status(
+                &draining,
+                RemovedPoolProgress {
+                    retained_claims: 4,
+                    ..Default::default()
+                },
+                t1,
+            )
+            .is_none()
+        );
+    }
+
     #[test]
     fn removed_pool_cleanup_marks_reconciling_and_requeues() {
         let mut cleanup = RemovedDecommissionedPoolCleanup::default();
@@ -0,0 +1,606 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tenant pod diagnostics that StatefulSet status does not show.
+//!
+//! A rollout stuck on an image that cannot be pulled, or on a crashing container, only looks like
+//! "pods not ready" from the StatefulSet. The container statuses carry the kubelet message and
+//! the last termination, which are surfaced in the `ImagePullFailed` and `PodsCrashLooping`
+//! conditions. With `spec.diagnostics.crashLogLines`, the tail of the crashed run's log is kept in
+//! a ConfigMap because the kubelet only retains the previous run.
+
+use crate::context::{Context, is_kube_not_found};
+use crate::types::v1alpha1::tenant::{Component, Tenant};
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::api::scheduling::v1::PriorityClass;
+use kube::api::{Api, LogParams, ObjectMeta};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+use tracing::warn;
+
+/// How often pods are re-inspected while an image cannot be pulled.
+pub(super) const IMAGE_PULL_REQUEUE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// First requeue interval while a container is crash looping; doubled per restart.
+const CRASH_LOOP_REQUEUE_BASE: Duration = Duration::from_secs(30);
+
+/// Longest requeue interval while a container is crash looping.
+const CRASH_LOOP_REQUEUE_MAX: Duration = Duration::from_secs(300);
+
+/// Bytes of previous-run log read and stored per crash-looping container.
+const CRASH_LOG_MAX_BYTES: usize = 64 * 1024;
+
+/// Bytes of log stored in the crash diagnostics ConfigMap in total, well below the 1 MiB
+/// object limit.
+const CRASH_LOG_CONFIG_MAP_MAX_BYTES: usize = 512 * 1024;
+
+/// Bytes of the container termination message copied into the `PodsCrashLooping` condition.
+const TERMINATION_MESSAGE_MAX_BYTES: usize = 1024;
+
+/// Container waiting reasons reported by the kubelet when the image cannot be pulled.
+const IMAGE_PULL_WAITING_REASONS: [&str; 4] = [
+    "ImagePullBackOff",
+    "ErrImagePull",
+    "InvalidImageName",
+    "ErrImageNeverPull",
+];
+
+/// Secret types the kubelet accepts as image pull credentials.
+const IMAGE_PULL_SECRET_TYPES: [&str; 2] =
+    ["kubernetes.io/dockerconfigjson", "kubernetes.io/dockercfg"];
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct ImagePullFailure {
+    pub pod: String,
+    pub container: String,
+    pub image: String,
+    pub reason: String,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct CrashLoop {
+    pub pod: String,
+    pub container: String,
+    pub restart_count: i32,
+    pub exit_code: Option<i32>,
+    pub termination_reason: Option<String>,
+    pub termination_message: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct PodHealth {
+    pub image_pull_failures: Vec<ImagePullFailure>,
+    /// Problem with `spec.imagePullSecret`, reported only alongside pull failures.
+    pub image_pull_secret_problem: Option<String>,
+    pub crash_loops: Vec<CrashLoop>,
+    /// ConfigMap holding the captured logs of the crash-looping containers.
+    pub crash_log_config_map: Option<String>,
+}
+
+impl PodHealth {
+    /// Message for the `ImagePullFailed` condition, or `None` when every image was pulled.
+    pub(super) fn image_pull_message(&self) -> Option<String> {
+        let first = self.image_pull_failures.first()?;
+        let mut message = format!(
+            "Pod '{}' container '{}' cannot pull image '{}' ({})",
+            first.pod, first.container, first.image, first.reason
+        );
+        if let Some(detail) = first.message.as_deref().filter(|detail| !detail.is_empty()) {
+            message.push_str(": ");
+            message.push_str(detail);
+        }
+        let others = self.image_pull_failures.len() - 1;
+        if others > 0 {
+            message.push_str(&format!("; {others} more container(s) affected"));
+        }
+        if let Some(problem) = &self.image_pull_secret_problem {
+            message.push_str("; ");
+            message.push_str(problem);
+        }
+        Some(message)
+    }
+
+    /// Message for the `PodsCrashLooping` condition, or `None` when no container crash loops.
+    pub(super) fn crash_loop_message(&self) -> Option<String> {
+        let first = self.crash_loops.first()?;
+        let mut message = format!(
+            "Pod '{}' container '{}' is in CrashLoopBackOff after {} restart(s)",
+            first.pod, first.container, first.restart_count
+        );
+        match (first.exit_code, first.termination_reason.as_deref()) {
+            (Some(code), Some(reason)) => {
+                message.push_str(&format!(" (exit code {code}, {reason})"))
+            }
+            (Some(code), None) => message.push_str(&format!(" (exit code {code})")),
+            _ => {}
+        }
+        if let Some(detail) = first
+            .termination_message
+            .as_deref()
+            .map(str::trim)
+            .filter(|detail| !detail.is_empty())
+        {
+            message.push_str(": ");
+            message.push_str(&truncate_bytes(detail, TERMINATION_MESSAGE_MAX_BYTES));
+        }
+        let others = self.crash_loops.len() - 1;
+        if others > 0 {
+            message.push_str(&format!("; {others} more container(s) affected"));
+        }
+        if let Some(config_map) = &self.crash_log_config_map {
+            message.push_str(&format!("; recent logs are in ConfigMap '{config_map}'"));
+        }
+        Some(message)
+    }
+
+    /// Requeue interval while containers crash loop, growing with the restart count so a
+    /// crashing Tenant is not reconciled in a hot loop.
+    pub(super) fn crash_loop_requeue(&self) -> Option<Duration> {
+        let restarts = self
+            .crash_loops
+            .iter()
+            .map(|crash_loop| crash_loop.restart_count)
+            .max()?;
+        let doublings = u32::try_from(restarts.saturating_sub(1))
+            .unwrap_or(0)
+            .min(8);
+        Some((CRASH_LOOP_REQUEUE_BASE * 2u32.pow(doublings)).min(CRASH_LOOP_REQUEUE_MAX))
+    }
+}
+
+/// Name of the ConfigMap that keeps crash-loop logs for a Tenant.
+fn crash_diagnostics_config_map_name(tenant: &Tenant) -> String {
+    format!("{}-crash-diagnostics", tenant.name())
+}
+
+/// Inspects the Tenant pods. List failures are logged and treated as healthy.
+pub(super) async fn inspect(ctx: &Context, tenant: &Tenant, namespace: &str) -> PodHealth {
+    let pods = match ctx
+        .list_labeled::<corev1::Pod>(namespace, &tenant.pod_selector())
+        .await
+    {
+        Ok(pods) => pods,
+        Err(error) => {
+            warn!(tenant = %tenant.name(), namespace, %error, "failed to list Tenant pods");
+            return PodHealth::default();
+        }
+    };
+
+    let image_pull_failures: Vec<_> = pods.iter().flat_map(image_pull_failures).collect();
+    let image_pull_secret_problem = if image_pull_failures.is_empty() {
+        None
+    } else {
+        image_pull_secret_problem(ctx, tenant, namespace).await
+    };
+    let crash_loops: Vec<_> = pods.iter().flat_map(crash_loops).collect();
+    let crash_log_config_map = capture_crash_logs(ctx, tenant, namespace, &crash_loops).await;
+    PodHealth {
+        image_pull_failures,
+        image_pull_secret_problem,
+        crash_loops,
+        crash_log_config_map,
+    }
+}
+
+/// Stores the tail of the previous run of each crash-looping container. Returns the ConfigMap
+/// name when logs were captured.
+async fn capture_crash_logs(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    crash_loops: &[CrashLoop],
+) -> Option<String> {
+    let lines = tenant
+        .spec
+        .diagnostics
+        .as_ref()
+        .map(|diagnostics| diagnostics.crash_log_lines())
+        .filter(|lines| *lines > 0)?;
+    if crash_loops.is_empty() {
+        return None;
+    }
+
+    let pods: Api<corev1::Pod> = Api::namespaced(ctx.client.clone(), namespace);
+    let mut data = BTreeMap::new();
+    let mut stored_bytes = 0;
+    for crash_loop in crash_loops {
+        let limit =
+            CRASH_LOG_MAX_BYTES.min(CRASH_LOG_CONFIG_MAP_MAX_BYTES.saturating_sub(stored_bytes));
+        if limit == 0 {
+            warn!(
+                pod = %crash_loop.pod,
+                container = %crash_loop.container,
+                "crash diagnostics ConfigMap is full; skipping container logs"
+            );
+            continue;
+        }
+        let params = LogParams {
+            container: Some(crash_loop.container.clone()),
+            previous: true,
+            tail_lines: Some(i64::from(lines)),
+            limit_bytes: Some(limit as i64),
+            ..Default::default()
+        };
+        match pods.logs(&crash_loop.pod, &params).await {
+            Ok(logs) => {
+                let logs = truncate_bytes(&logs, limit);
+                stored_bytes += logs.len();
+                data.insert(
+                    format!("{}.{}.log", crash_loop.pod, crash_loop.container),
+                    logs,
+                );
+            }
+            Err(error) => warn!(
+                pod = %crash_loop.pod,
+                container = %crash_loop.container,
+                %error,
+                "failed to read logs of crash-looping container"
+            ),
+        }
+    }
+    if data.is_empty() {
+        return None;
+    }
+
+    let name = crash_diagnostics_config_map_name(tenant);
+    let config_map = corev1::ConfigMap {
+        metadata: ObjectMeta {
+            name: Some(name.clone()),
+            namespace: Some(namespace.to_string()),
+            labels: Some(tenant.component_labels(Component::Diagnostics)),
+            owner_references: Some(vec![tenant.new_owner_ref()]),
+            ..Default::default()
+        },
+        data: Some(data),
+        ..Default::default()
+    };
+    match ctx.apply(&config_map, namespace).await {
+        Ok(_) => Some(name),
+        Err(error) => {
+            warn!(config_map = %name, namespace, %error, "failed to store crash-loop logs");
+            None
+        }
+    }
+}
+
+/// `text` cut to at most `max_bytes` on a character boundary, with a marker when it was cut.
+fn truncate_bytes(text: &str, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text.to_string();
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}\n[truncated]", &text[..end])
+}
+
+/// Checks that `spec.imagePullSecret` exists and holds registry credentials.
+pub(super) async fn image_pull_secret_problem(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+) -> Option<String> {
+    let name = tenant
+        .spec
+        .image_pull_secret
+        .as_ref()
+        .map(|reference| reference.name.as_str())
+        .filter(|name| !name.is_empty())?;
+    match ctx.get::<corev1::Secret>(name, namespace).await {
+        Ok(secret) => secret_type_problem(name, secret.type_.as_deref()),
+        Err(error) if is_kube_not_found(&error) => {
+            Some(format!("imagePullSecret '{name}' was not found"))
+        }
+        Err(error) => {
+            warn!(secret = name, namespace, %error, "failed to read imagePullSecret");
+            None
+        }
+    }
+}
+
+/// Names the PriorityClasses referenced by the Tenant or its pools that do not exist. Pods
+/// referring to a missing PriorityClass are rejected at admission, so the pool never starts.
+pub(super) async fn missing_priority_classes(ctx: &Context, tenant: &Tenant) -> Vec<String> {
+    let api: Api<PriorityClass> = Api::all(ctx.client.clone());
+    let mut missing = Vec::new();
+    for name in priority_class_names(tenant) {
+        match api.get_opt(name).await {
+            Ok(Some(_)) => {}
+            Ok(None) => missing.push(name.to_string()),
+            Err(error) => warn!(priority_class = name, %error, "failed to read PriorityClass"),
+        }
+    }
+    missing
+}
+
+/// PriorityClasses used by the pools: the pool override, or else the Tenant default.
+fn priority_class_names(tenant: &Tenant) -> BTreeSet<&str> {
+    tenant
+        .spec
+        .pools
+        .iter()
+        .filter_map(|pool| {
+            pool.scheduling
+                .priority_class_name
+                .as_deref()
+                .or(tenant.spec.priority_class_name.as_deref())
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn secret_type_problem(name: &str, type_: Option<&str>) -> Option<String> {
+    let type_ = type_.unwrap_or("Opaque");
+    (!IMAGE_PULL_SECRET_TYPES.contains(&type_)).then(|| {
+        format!(
+            "imagePullSecret '{name}' has type '{type_}'; expected one of {}",
+            IMAGE_PULL_SECRET_TYPES.join(", ")
+        )
+    })
+}
+
+fn image_pull_failures(pod: &corev1::Pod) -> Vec<ImagePullFailure> {
+    let Some(status) = pod.status.as_ref() else {
+        return Vec::new();
+    };
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+    status
+        .init_container_statuses
+        .iter()
+        .chain(status.container_statuses.iter())
+        .flatten()
+        .filter_map(|container| {
+            let waiting = container.state.as_ref()?.waiting.as_ref()?;
+            let reason = waiting
+                .reason
+                .as_deref()
+                .filter(|reason| IMAGE_PULL_WAITING_REASONS.contains(reason))?;
+            Some(ImagePullFailure {
+                pod: pod_name.clone(),
+                container: container.name.clone(),
+                image: container.image.clone(),
+                reason: reason.to_string(),
+                message: waiting.message.clone(),
+            })
+        })
+        .collect()
+}
+
+fn crash_loops(pod: &corev1::Pod) -> Vec<CrashLoop> {
+    let Some(status) = pod.status.as_ref() else {
+        return Vec::new();
+    };
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+    status
+        .init_container_statuses
+        .iter()
+        .chain(status.container_statuses.iter())
+        .flatten()
+        .filter(|container| {
+            container
+                .state
+                .as_ref()
+                .and_then(|state| state.waiting.as_ref())
+                .and_then(|waiting| waiting.reason.as_deref())
+                == Some("CrashLoopBackOff")
+        })
+        .map(|container| {
+            let terminated = container
+                .last_state
+                .as_ref()
+                .and_then(|state| state.terminated.as_ref());
+            CrashLoop {
+                pod: pod_name.clone(),
+                container: container.name.clone(),
+                restart_count: container.restart_count,
+                exit_code: terminated.map(|terminated| terminated.exit_code),
+                termination_reason: terminated.and_then(|terminated| terminated.reason.clone()),
+                termination_message: terminated.and_then(|terminated| terminated.message.clone()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(name: &str, containers: Vec<corev1::ContainerStatus>) -> corev1::Pod {
+        corev1::Pod {
+            metadata: kube::api::ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(corev1::PodStatus {
+                container_statuses: Some(containers),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn waiting(name: &str, reason: &str, message: &str) -> corev1::ContainerStatus {
+        corev1::ContainerStatus {
+            name: name.to_string(),
+            image: "registry.example.com/rustfs/rustfs:missing".to_string(),
+            state: Some(corev1::ContainerState {
+                waiting: Some(corev1::ContainerStateWaiting {
+                    reason: Some(reason.to_string()),
+                    message: Some(message.to_string()),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn image_pull_failures_only_match_pull_reasons() {
+        let failing = pod(
+            "t-pool-0-0",
+            vec![
+                waiting("rustfs", "ImagePullBackOff", "Back-off pulling image"),
+                waiting("sidecar", "ContainerCreating", ""),
+            ],
+        );
+
+        let failures = image_pull_failures(&failing);
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].pod, "t-pool-0-0");
+        assert_eq!(failures[0].container, "rustfs");
+        assert_eq!(failures[0].reason, "ImagePullBackOff");
+        assert!(image_pull_failures(&pod("t-pool-0-1", Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn image_pull_message_includes_kubelet_message_and_secret_problem() {
+        let health = PodHealth {
+            image_pull_failures: [
+                waiting("rustfs", "ErrImagePull", "pull access denied"),
+                waiting("rustfs", "ImagePullBackOff", ""),
+            ]
+            .into_iter()
+            .flat_map(|status| image_pull_failures(&pod("t-pool-0-0", vec![status])))
+            .collect(),
+            image_pull_secret_problem: Some("imagePullSecret 'registry' was not found".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            health.image_pull_message().as_deref(),
+            Some(
+                "Pod 't-pool-0-0' container 'rustfs' cannot pull image \
+                 'registry.example.com/rustfs/rustfs:missing' (ErrImagePull): pull access denied; \
+                 1 more container(s) affected; imagePullSecret 'registry' was not found"
+            )
+        );
+        assert!(PodHealth::default().image_pull_message().is_none());
+    }
+
+    fn crash_looping(name: &str, restart_count: i32) -> corev1::ContainerStatus {
+        corev1::ContainerStatus {
+            restart_count,
+            last_state: Some(corev1::ContainerState {
+                terminated: Some(corev1::ContainerStateTerminated {
+                    exit_code: 101,
+                    reason: Some("Error".to_string()),
+                    message: Some("invalid volume layout\n".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..waiting(
+                name,
+                "CrashLoopBackOff",
+                "back-off 40s restarting failed container",
+            )
+        }
+    }
+
+    #[test]
+    fn crash_loops_carry_last_termination() {
+        let health = PodHealth {
+            crash_loops: crash_loops(&pod(
+                "t-pool-0-0",
+                vec![
+                    crash_looping("rustfs", 3),
+                    waiting("sidecar", "ImagePullBackOff", ""),
+                ],
+            )),
+            crash_log_config_map: Some("t-crash-diagnostics".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(health.crash_loops.len(), 1);
+        assert_eq!(
+            health.crash_loop_message().as_deref(),
+            Some(
+                "Pod 't-pool-0-0' container 'rustfs' is in CrashLoopBackOff after 3 restart(s) \
+                 (exit code 101, Error): invalid volume layout; recent logs are in ConfigMap \
+                 't-crash-diagnostics'"
+            )
+        );
+        assert!(PodHealth::default().crash_loop_message().is_none());
+    }
+
+    #[test]
+    fn crash_loop_requeue_backs_off_with_restarts() {
+        let requeue = |restarts| {
+            PodHealth {
+                crash_loops: crash_loops(&pod("p", vec![crash_looping("rustfs", restarts)])),
+                ..Default::default()
+            }
+            .crash_loop_requeue()
+        };
+
+        assert_eq!(requeue(1), Some(Duration::from_secs(30)));
+        assert_eq!(requeue(3), Some(Duration::from_secs(120)));
+        assert_eq!(requeue(50), Some(Duration::from_secs(300)));
+        assert_eq!(PodHealth::default().crash_loop_requeue(), None);
+    }
+
+    #[test]
+    fn secret_type_problem_requires_registry_credentials() {
+        assert!(secret_type_problem("registry", Some("kubernetes.io/dockerconfigjson")).is_none());
+        assert!(secret_type_problem("registry", Some("kubernetes.io/dockercfg")).is_none());
+        assert!(
+            secret_type_problem("registry", None).is_some_and(|problem| problem.contains("Opaque"))
+        );
+    }
+
+    #[test]
+    fn priority_class_names_prefer_pool_override() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert!(priority_class_names(&tenant).is_empty());
+
+        tenant.spec.priority_class_name = Some("tenant-default".to_string());
+        let mut second = tenant.spec.pools[0].clone();
+        second.name = "pool-1".to_string();
+        second.scheduling.priority_class_name = Some("pool-high".to_string());
+        tenant.spec.pools.push(second);
+
+        assert_eq!(
+            priority_class_names(&tenant)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["pool-high", "tenant-default"]
+        );
+    }
+
+    #[test]
+    fn truncate_bytes_cuts_on_a_char_boundary() {
+        assert_eq!(truncate_bytes("short", 10), "short");
+        assert_eq!(truncate_bytes("abcdef", 4), "abcd\n[truncated]");
+        // 'é' is two bytes; cutting inside it keeps the character whole or drops it.
+        assert_eq!(truncate_bytes("aé", 2), "a\n[truncated]");
+
+        let mut status = crash_looping("rustfs", 3);
+        if let Some(terminated) = status
+            .last_state
+            .as_mut()
+            .and_then(|state| state.terminated.as_mut())
+        {
+            terminated.message = Some("x".repeat(10 * TERMINATION_MESSAGE_MAX_BYTES));
+        }
+        let health = PodHealth {
+            crash_loops: crash_loops(&pod("t-pool-0-0", vec![status])),
+            ..Default::default()
+        };
+        let message = health.crash_loop_message().unwrap_or_default();
+        assert!(message.len() < 2 * TERMINATION_MESSAGE_MAX_BYTES);
+    }
+}
@@ -235,6 +235,17 @@ async fn reconcile_single_pool_lifecycle(
         );
     }
 
+    if request.action == DecommissionAction::Start
+        && !matches!(existing_state, Some(PoolLifecycleState::Decommissioning))
+        && let Err(message) = tenant.validate_pool_decommission(&pool.name)
+    {
+        return failed_decision(
+            Some(request.request_id.clone()),
+            "UnsafeScaleDown",
+            &message,
+        );
+    }
+
     let client = match rustfs_admin_client(ctx, tenant).await {
         Ok(client) => client,
         Err(error) => {
@@ -312,7 +323,15 @@ async fn reconcile_single_pool_lifecycle(
                 );
             }
         };
-    if should_start {
+    if should_start && ctx.dry_run {
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            pool = %pool.name,
+            request_id = %request.request_id,
+            "dry run: skipping RustFS pool decommission start"
+        );
+    } else if should_start {
         match client.start_pool_decommission_by_id(&pool_id).await {
             Ok(()) => {
                 info!(
@@ -349,7 +368,15 @@ async fn reconcile_single_pool_lifecycle(
             existing_state,
             Some(PoolLifecycleState::DecommissionCanceled)
         );
-    if should_cancel {
+    if should_cancel && ctx.dry_run {
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            pool = %pool.name,
+            request_id = %request.request_id,
+            "dry run: skipping RustFS pool decommission cancel"
+        );
+    } else if should_cancel {
         match client.cancel_pool_decommission_by_id(&pool_id).await {
             Ok(()) => {
                 info!(
@@ -433,7 +460,7 @@ async fn reconcile_single_pool_lifecycle(
     }
 }
 
-async fn rustfs_admin_client(
+pub(super) async fn rustfs_admin_client(
     ctx: &Context,
     tenant: &Tenant,
 ) -> Result<RustfsAdminClient, RustfsClientError> {
@@ -978,7 +1005,7 @@ fn cleanup_retriable_decision(
     }
 }
 
-fn empty_decommission_status() -> PoolDecommissionStatus {
+pub(super) fn empty_decommission_status() -> PoolDecommissionStatus {
     PoolDecommissionStatus {
         request_id: None,
         rustfs_pool_id: None,
@@ -1018,6 +1045,10 @@ fn cleanup_status(state: PoolDecommissionCleanupState) -> PoolDecommissionCleanu
         state,
         stateful_set_deleted_at,
         pvc_retention_policy: Some("Retain".to_string()),
+        removed_from_spec_at: None,
+        remaining_pods: None,
+        retained_claims: None,
+        terminating_claims: None,
     }
 }
 
@@ -1096,6 +1127,11 @@ mod tests {
                 volumes_per_server: 2,
                 ..Default::default()
             },
+            env: Vec::new(),
+            service_account_name: None,
+            pod_metadata: None,
+            pod_deletion_policy_when_node_is_down: None,
+            paused: None,
             scheduling: SchedulingConfig::default(),
         }
     }
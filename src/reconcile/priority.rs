@@ -0,0 +1,179 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Health-based resync scheduling.
+//!
+//! The controller queue is ordered by due time, so tenants that need attention are prioritized by
+//! requeuing them sooner. After a reconcile that would otherwise wait for the next watch event,
+//! Degraded and NotReady tenants are resynced on a short interval and Ready tenants on a long one.
+//! Other tenants (Reconciling, Blocked or unknown) are only resynced when an interval is configured.
+//! The priority is taken from the status the reconcile started with; status changes trigger a new
+//! reconcile through the Tenant watch, which then picks the updated priority.
+
+use crate::types::v1alpha1::status::{CurrentState, canonical_state};
+use crate::types::v1alpha1::tenant::Tenant;
+use kube::runtime::controller::Action;
+use std::time::Duration;
+use tracing::warn;
+
+const DEFAULT_DEGRADED_RESYNC: Duration = Duration::from_secs(30);
+const DEFAULT_READY_RESYNC: Duration = Duration::from_secs(600);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ReconcilePriority {
+    /// Degraded or NotReady: resync soon.
+    High,
+    /// Reconciling, Blocked or unknown: wait for changes unless configured.
+    Normal,
+    /// Ready: resync rarely.
+    Low,
+}
+
+impl ReconcilePriority {
+    pub(crate) fn of(tenant: &Tenant) -> Self {
+        let state = canonical_state(tenant.status.as_ref().map(|s| s.current_state.as_str()));
+        if state == CurrentState::Degraded.as_str() || state == CurrentState::NotReady.as_str() {
+            Self::High
+        } else if state == CurrentState::Ready.as_str() {
+            Self::Low
+        } else {
+            Self::Normal
+        }
+    }
+}
+
+/// Resync intervals by priority. `None` leaves the Tenant waiting for watch events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ResyncConfig {
+    pub(crate) degraded: Option<Duration>,
+    pub(crate) ready: Option<Duration>,
+    pub(crate) normal: Option<Duration>,
+}
+
+impl Default for ResyncConfig {
+    fn default() -> Self {
+        Self {
+            degraded: Some(DEFAULT_DEGRADED_RESYNC),
+            ready: Some(DEFAULT_READY_RESYNC),
+            normal: None,
+        }
+    }
+}
+
+impl ResyncConfig {
+    /// Reads `OPERATOR_RESYNC_DEGRADED_SECONDS`, `OPERATOR_RESYNC_READY_SECONDS` and
+    /// `OPERATOR_RESYNC_NORMAL_SECONDS`; `0` disables.
+    pub(crate) fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            degraded: resync_from_env("OPERATOR_RESYNC_DEGRADED_SECONDS", defaults.degraded),
+            ready: resync_from_env("OPERATOR_RESYNC_READY_SECONDS", defaults.ready),
+            normal: resync_from_env("OPERATOR_RESYNC_NORMAL_SECONDS", defaults.normal),
+        }
+    }
+
+    /// Replace an idle `await_change` with the resync interval for the Tenant's priority.
+    pub(crate) fn schedule(&self, action: Action, tenant: &Tenant) -> Action {
+        if action != Action::await_change() {
+            return action;
+        }
+        self.interval(tenant).map_or(action, super::requeue_after)
+    }
+
+    /// The resync interval an idle reconcile of `tenant` gets.
+    pub(crate) fn interval(&self, tenant: &Tenant) -> Option<Duration> {
+        match ReconcilePriority::of(tenant) {
+            ReconcilePriority::High => self.degraded,
+            ReconcilePriority::Normal => self.normal,
+            ReconcilePriority::Low => self.ready,
+        }
+    }
+}
+
+fn resync_from_env(name: &str, default: Option<Duration>) -> Option<Duration> {
+    match std::env::var(name) {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(0) => None,
+            Ok(seconds) => Some(Duration::from_secs(seconds)),
+            Err(_) => {
+                warn!(name, value, "invalid resync interval, using default");
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReconcilePriority, ResyncConfig};
+    use crate::types::v1alpha1::status::Status;
+    use kube::runtime::controller::Action;
+    use std::time::Duration;
+
+    fn tenant_in_state(state: &str) -> crate::types::v1alpha1::tenant::Tenant {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.status = Some(Status {
+            current_state: state.to_string(),
+            ..Default::default()
+        });
+        tenant
+    }
+
+    #[test]
+    fn idle_reconciles_are_resynced_by_health() {
+        let config = ResyncConfig::default();
+
+        assert_eq!(
+            config.schedule(Action::await_change(), &tenant_in_state("Degraded")),
+            Action::requeue(Duration::from_secs(30))
+        );
+        assert_eq!(
+            config.schedule(Action::await_change(), &tenant_in_state("Ready")),
+            Action::requeue(Duration::from_secs(600))
+        );
+        assert_eq!(
+            config.schedule(Action::await_change(), &tenant_in_state("Blocked")),
+            Action::await_change()
+        );
+        assert_eq!(
+            config.schedule(
+                Action::requeue(Duration::from_secs(10)),
+                &tenant_in_state("Ready")
+            ),
+            Action::requeue(Duration::from_secs(10))
+        );
+        assert_eq!(
+            ReconcilePriority::of(&tenant_in_state("NotReady")),
+            ReconcilePriority::High
+        );
+    }
+
+    #[test]
+    fn configured_normal_interval_resyncs_other_tenants() {
+        let config = ResyncConfig {
+            normal: Some(Duration::from_secs(300)),
+            ..ResyncConfig::default()
+        };
+
+        assert_eq!(
+            config.schedule(Action::await_change(), &tenant_in_state("Blocked")),
+            Action::requeue(Duration::from_secs(300))
+        );
+        assert_eq!(
+            config.interval(&tenant_in_state("Ready")),
+            Some(Duration::from_secs(600))
+        );
+    }
+}
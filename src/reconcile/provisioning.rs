@@ -13,14 +13,16 @@
 // limitations under the License.
 
 use crate::context::{self, Context};
-use crate::sts::rustfs_client::{CreateBucketResult, RustfsAdminClient, RustfsClientError};
+use crate::sts::rustfs_client::{
+    BucketLifecycleRule, CreateBucketResult, RustfsAdminClient, RustfsClientError,
+};
 use crate::types::v1alpha1::provisioning::{
-    ProvisioningBucket, ProvisioningPolicy, ProvisioningUser,
+    LifecyclePolicy, ProvisioningBucket, ProvisioningPolicy, ProvisioningUser,
 };
-use crate::types::v1alpha1::status::Reason;
 use crate::types::v1alpha1::status::provisioning::{
     ProvisioningItemState, ProvisioningItemStatus, ProvisioningPhase, ProvisioningStatus,
 };
+use crate::types::v1alpha1::status::{ConditionType, Reason};
 use crate::types::v1alpha1::tenant::Tenant;
 use k8s_openapi::ByteString;
 use k8s_openapi::api::core::v1::{ConfigMap, Secret};
@@ -94,6 +96,13 @@ impl ProvisioningRun<'_> {
         self.previous.buckets.iter().find(|item| item.name == name)
     }
 
+    fn previous_lifecycle_policy(&self, name: &str) -> Option<&ProvisioningItemStatus> {
+        self.previous
+            .lifecycle_policies
+            .iter()
+            .find(|item| item.name == name)
+    }
+
     fn push_policy(&mut self, item: ProvisioningItemStatus) {
         self.log_item_transition("policy", self.previous_policy(&item.name), &item);
         if item.state == ProvisioningItemState::Failed.as_str() {
@@ -121,6 +130,19 @@ impl ProvisioningRun<'_> {
         self.status.buckets.push(item);
     }
 
+    fn push_lifecycle_policy(&mut self, item: ProvisioningItemStatus) {
+        self.log_item_transition(
+            "lifecycle policy",
+            self.previous_lifecycle_policy(&item.name),
+            &item,
+        );
+        if item.state == ProvisioningItemState::Failed.as_str() {
+            self.failures
+                .push((reason_from_str(&item.reason), item_message(&item)));
+        }
+        self.status.lifecycle_policies.push(item);
+    }
+
     fn log_item_transition(
         &self,
         item_type: &'static str,
@@ -204,6 +226,7 @@ impl ProvisioningRun<'_> {
         item.policies = previous.policies.clone();
         item.region = previous.region.clone();
         item.object_lock = previous.object_lock;
+        item.buckets = previous.buckets.clone();
         item
     }
 
@@ -248,6 +271,16 @@ impl ProvisioningRun<'_> {
             );
             self.push_bucket(item);
         }
+        for policy in &self.tenant.spec.lifecycle_policies {
+            let previous = self.previous_lifecycle_policy(&policy.name);
+            let mut item = self.item(previous, &policy.name, state.clone(), reason, message);
+            if let Some(previous) = previous {
+                item.last_applied_hash = previous.last_applied_hash.clone();
+                item.last_applied_generation = previous.last_applied_generation;
+                item.buckets = previous.buckets.clone();
+            }
+            self.push_lifecycle_policy(item);
+        }
     }
 
     fn fail_all_active(&mut self, reason: Reason, message: &str) {
@@ -275,6 +308,21 @@ impl ProvisioningRun<'_> {
                 self.status.buckets.push(self.retained_item(previous));
             }
         }
+
+        let lifecycle_policies = desired_names(
+            self.tenant
+                .spec
+                .lifecycle_policies
+                .iter()
+                .map(|policy| &policy.name),
+        );
+        for previous in &self.previous.lifecycle_policies {
+            if !lifecycle_policies.contains(&previous.name) {
+                self.status
+                    .lifecycle_policies
+                    .push(self.retained_item(previous));
+            }
+        }
     }
 
     fn prepare_status(&mut self, phase: ProvisioningPhase) {
@@ -282,6 +330,9 @@ impl ProvisioningRun<'_> {
         self.status.policies.sort_by(|a, b| a.name.cmp(&b.name));
         self.status.users.sort_by(|a, b| a.name.cmp(&b.name));
         self.status.buckets.sort_by(|a, b| a.name.cmp(&b.name));
+        self.status
+            .lifecycle_policies
+            .sort_by(|a, b| a.name.cmp(&b.name));
         if !self.status.is_empty() {
             self.status.observed_generation = self.tenant.metadata.generation;
             self.status.phase = Some(phase);
@@ -322,6 +373,8 @@ pub(super) async fn reconcile_provisioning(
         .map(|status| status.provisioning.clone())
         .unwrap_or_default();
     let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let with_bootstrap = with_pending_bootstrap(tenant);
+    let tenant = with_bootstrap.as_ref().unwrap_or(tenant);
     let mut run = ProvisioningRun {
         ctx,
         tenant,
@@ -339,6 +392,17 @@ pub(super) async fn reconcile_provisioning(
             outcome: ProvisioningOutcome::Ready,
         };
     }
+    if ctx.dry_run {
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            "dry run: skipping RustFS provisioning"
+        );
+        return ProvisioningReconcileResult {
+            status: run.previous,
+            outcome: ProvisioningOutcome::Ready,
+        };
+    }
 
     let client = match rustfs_admin_client(ctx, tenant).await {
         Ok(client) => client,
@@ -399,10 +463,11 @@ pub(super) async fn reconcile_provisioning(
     reconcile_policies(&mut run, &client, &mut live_policies).await;
     reconcile_users(&mut run, &client, &live_policies).await;
     reconcile_buckets(&mut run, &client).await;
+    reconcile_lifecycle_policies(&mut run, &client).await;
     run.finish()
 }
 
-async fn rustfs_admin_client(
+pub(super) async fn rustfs_admin_client(
     ctx: &Context,
     tenant: &Tenant,
 ) -> Result<RustfsAdminClient, RustfsClientError> {
@@ -1075,17 +1140,261 @@ fn annotate_bucket_item(
     item
 }
 
+/// Applies `spec.lifecyclePolicies` after the buckets, so buckets created in this run can be
+/// selected. All policies selecting a bucket are merged into its one lifecycle configuration.
+async fn reconcile_lifecycle_policies(run: &mut ProvisioningRun<'_>, client: &RustfsAdminClient) {
+    let policies = &run.tenant.spec.lifecycle_policies;
+    if policies.is_empty() {
+        return;
+    }
+
+    let live_buckets = client
+        .list_buckets()
+        .await
+        .map_err(|error| format!("failed to list RustFS buckets: {error}"));
+    let targets = policies
+        .iter()
+        .map(|policy| {
+            let buckets = live_buckets
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|live| selected_buckets(policy, live));
+            (policy, buckets)
+        })
+        .collect::<Vec<_>>();
+
+    // Rules of a policy that could not be resolved are left as they are on every bucket.
+    let managed = targets
+        .iter()
+        .filter(|(_, buckets)| buckets.is_ok())
+        .map(|(policy, _)| policy.name.as_str())
+        .collect::<Vec<_>>();
+    let mut desired = BTreeMap::<&str, Vec<BucketLifecycleRule>>::new();
+    for (policy, buckets) in &targets {
+        for bucket in buckets.iter().flatten() {
+            desired
+                .entry(bucket.as_str())
+                .or_default()
+                .extend(bucket_lifecycle_rules(policy));
+        }
+    }
+
+    let mut bucket_errors = BTreeMap::<&str, String>::new();
+    for (bucket, rules) in desired {
+        if let Err(message) = apply_bucket_lifecycle(client, bucket, rules, &managed).await {
+            bucket_errors.insert(bucket, message);
+        }
+    }
+
+    for (policy, buckets) in &targets {
+        let item = lifecycle_policy_item(run, policy, buckets, &bucket_errors);
+        run.push_lifecycle_policy(item);
+    }
+}
+
+async fn apply_bucket_lifecycle(
+    client: &RustfsAdminClient,
+    bucket: &str,
+    rules: Vec<BucketLifecycleRule>,
+    managed: &[&str],
+) -> Result<(), String> {
+    let mut live = client
+        .get_bucket_lifecycle(bucket)
+        .await
+        .map_err(|error| format!("failed to read lifecycle of bucket '{bucket}': {error}"))?;
+    let merged = merge_lifecycle_rules(&live, rules, managed);
+    live.sort();
+    if merged == live {
+        return Ok(());
+    }
+    client
+        .put_bucket_lifecycle(bucket, &merged)
+        .await
+        .map_err(|error| format!("failed to apply lifecycle to bucket '{bucket}': {error}"))
+}
+
+fn lifecycle_policy_item(
+    run: &ProvisioningRun<'_>,
+    policy: &LifecyclePolicy,
+    buckets: &Result<Vec<String>, String>,
+    bucket_errors: &BTreeMap<&str, String>,
+) -> ProvisioningItemStatus {
+    let previous = run.previous_lifecycle_policy(&policy.name);
+    let failure = match buckets {
+        Ok(buckets) => buckets
+            .iter()
+            .find_map(|bucket| bucket_errors.get(bucket.as_str()).cloned()),
+        Err(message) => Some(message.clone()),
+    };
+    let desired_hash = hash_document(&serde_json::to_string(policy).unwrap_or_default());
+
+    let bucket_count = buckets.as_ref().map_or(0, Vec::len);
+    let mut item = if let Some(message) = &failure {
+        run.item(
+            previous,
+            &policy.name,
+            ProvisioningItemState::Failed,
+            Reason::LifecyclePolicyApplyFailed,
+            message.clone(),
+        )
+    } else if bucket_count == 0 {
+        run.item(
+            previous,
+            &policy.name,
+            ProvisioningItemState::Ready,
+            Reason::ProvisioningConfigured,
+            "No bucket matches the bucket selector",
+        )
+    } else {
+        run.item(
+            previous,
+            &policy.name,
+            ProvisioningItemState::Ready,
+            Reason::ProvisioningConfigured,
+            format!("Lifecycle rules are applied to {bucket_count} bucket(s)"),
+        )
+    };
+    if failure.is_none() {
+        item.last_applied_hash = Some(desired_hash.clone());
+        item.last_applied_generation = run.tenant.metadata.generation;
+    } else if let Some(previous) = previous {
+        item.last_applied_hash = previous.last_applied_hash.clone();
+        item.last_applied_generation = previous.last_applied_generation;
+    }
+    item.desired_hash = Some(desired_hash);
+    item.buckets = buckets.clone().unwrap_or_default();
+    item
+}
+
+/// Buckets `policy` selects among the `live` ones. Every listed name must exist.
+fn selected_buckets(policy: &LifecyclePolicy, live: &[String]) -> Result<Vec<String>, String> {
+    let selector = &policy.bucket_selector;
+    let missing = selector
+        .names
+        .iter()
+        .filter(|name| !live.contains(name))
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(format!("bucket(s) not found: {}", missing.join(", ")));
+    }
+
+    let mut buckets = selector.names.iter().cloned().collect::<BTreeSet<_>>();
+    if let Some(prefix) = &selector.prefix {
+        buckets.extend(
+            live.iter()
+                .filter(|bucket| bucket.starts_with(prefix.as_str()))
+                .cloned(),
+        );
+    }
+    Ok(buckets.into_iter().collect())
+}
+
+fn bucket_lifecycle_rules(policy: &LifecyclePolicy) -> Vec<BucketLifecycleRule> {
+    policy
+        .rules
+        .iter()
+        .map(|rule| BucketLifecycleRule {
+            id: format!("{}.{}", policy.name, rule.id),
+            prefix: rule.prefix.clone().unwrap_or_default(),
+            enabled: rule.is_enabled(),
+            expiration_days: rule.expiration.as_ref().map(|expiration| expiration.days),
+            noncurrent_expiration_days: rule
+                .noncurrent_version_expiration
+                .as_ref()
+                .map(|expiration| expiration.noncurrent_days),
+            transition: rule
+                .transition
+                .as_ref()
+                .map(|transition| (transition.days, transition.storage_class.clone())),
+        })
+        .collect()
+}
+
+/// `desired` plus the `live` rules no policy in `managed` owns, sorted by rule ID.
+fn merge_lifecycle_rules(
+    live: &[BucketLifecycleRule],
+    desired: Vec<BucketLifecycleRule>,
+    managed: &[&str],
+) -> Vec<BucketLifecycleRule> {
+    let mut merged = live
+        .iter()
+        .filter(|rule| {
+            !managed
+                .iter()
+                .any(|policy| rule.id.starts_with(&format!("{policy}.")))
+        })
+        .cloned()
+        .chain(desired)
+        .collect::<Vec<_>>();
+    merged.sort();
+    merged
+}
+
+/// Whether `spec.bootstrap` still has to run: it is set and `Bootstrapped` is not yet `True`.
+pub(super) fn bootstrap_pending(tenant: &Tenant) -> bool {
+    tenant
+        .spec
+        .bootstrap
+        .as_ref()
+        .is_some_and(|bootstrap| !bootstrap.is_empty())
+        && !tenant
+            .status
+            .as_ref()
+            .is_some_and(|status| status.condition_is_true(ConditionType::Bootstrapped))
+}
+
+/// Returns the Tenant with pending bootstrap items merged into the provisioning lists, so the
+/// first run creates them with the regular provisioning flow. Once bootstrapped, the items drop
+/// out of the desired lists and are reported as retained.
+fn with_pending_bootstrap(tenant: &Tenant) -> Option<Tenant> {
+    if !bootstrap_pending(tenant) {
+        return None;
+    }
+    let bootstrap = tenant.spec.bootstrap.as_ref()?;
+    let mut merged = tenant.clone();
+
+    for policy in &bootstrap.policies {
+        if !merged
+            .spec
+            .policies
+            .iter()
+            .any(|item| item.name == policy.name)
+        {
+            merged.spec.policies.push(policy.clone());
+        }
+    }
+    for user in &bootstrap.users {
+        if !merged.spec.users.iter().any(|item| item.name == user.name) {
+            merged.spec.users.push(user.clone());
+        }
+    }
+    for bucket in &bootstrap.buckets {
+        if !merged
+            .spec
+            .buckets
+            .iter()
+            .any(|item| item.name == bucket.name)
+        {
+            merged.spec.buckets.push(bucket.clone());
+        }
+    }
+
+    Some(merged)
+}
+
 fn has_active_spec(tenant: &Tenant) -> bool {
     !tenant.spec.policies.is_empty()
         || !tenant.spec.users.is_empty()
         || !tenant.spec.buckets.is_empty()
+        || !tenant.spec.lifecycle_policies.is_empty()
 }
 
 fn desired_names<'a>(names: impl Iterator<Item = &'a String>) -> BTreeSet<String> {
     names.cloned().collect()
 }
 
-fn validate_bucket_name(bucket_name: &str) -> Result<(), String> {
+pub(crate) fn validate_bucket_name(bucket_name: &str) -> Result<(), String> {
     if bucket_name.trim() != bucket_name {
         return Err("bucket name must not contain leading or trailing whitespace".to_string());
     }
@@ -1260,6 +1569,7 @@ fn reason_from_str(reason: &str) -> Reason {
         "UserPolicySetFailed" => Reason::UserPolicySetFailed,
         "BucketCreateFailed" => Reason::BucketCreateFailed,
         "BucketObjectLockConflict" => Reason::BucketObjectLockConflict,
+        "LifecyclePolicyApplyFailed" => Reason::LifecyclePolicyApplyFailed,
         _ => Reason::ProvisioningFailed,
     }
 }
@@ -1330,6 +1640,59 @@ mod tests {
         assert!(error.contains("at least one policy"));
     }
 
+    #[test]
+    fn pending_bootstrap_items_are_merged_until_bootstrapped() {
+        use crate::types::v1alpha1::provisioning::BootstrapSpec;
+        use crate::types::v1alpha1::status::{ConditionInput, ConditionStatus, Status};
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.buckets = vec![ProvisioningBucket {
+            name: "shared".to_string(),
+            region: None,
+            object_lock: None,
+            deletion_policy: Default::default(),
+        }];
+        tenant.spec.bootstrap = Some(BootstrapSpec {
+            buckets: ["shared", "seed-data"]
+                .into_iter()
+                .map(|name| ProvisioningBucket {
+                    name: name.to_string(),
+                    region: None,
+                    object_lock: None,
+                    deletion_policy: Default::default(),
+                })
+                .collect(),
+            ..Default::default()
+        });
+
+        assert!(bootstrap_pending(&tenant));
+        let names = with_pending_bootstrap(&tenant).map(|merged| {
+            merged
+                .spec
+                .buckets
+                .into_iter()
+                .map(|bucket| bucket.name)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(
+            names,
+            Some(vec!["shared".to_string(), "seed-data".to_string()])
+        );
+
+        let mut status = Status::default();
+        status.upsert_condition(ConditionInput {
+            type_: ConditionType::Bootstrapped,
+            status: ConditionStatus::True,
+            reason: Reason::ProvisioningConfigured,
+            message: "done".to_string(),
+            observed_generation: None,
+            now: "2026-01-01T00:00:00Z".to_string(),
+        });
+        tenant.status = Some(status);
+        assert!(!bootstrap_pending(&tenant));
+        assert!(with_pending_bootstrap(&tenant).is_none());
+    }
+
     #[test]
     fn policy_document_hash_uses_compact_json() {
         let normalized = normalize_policy_document(
@@ -1616,4 +1979,54 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn lifecycle_policies_select_buckets_and_keep_unmanaged_rules() {
+        use crate::types::v1alpha1::provisioning::{
+            BucketSelector, LifecycleExpiration, LifecycleRule,
+        };
+
+        let policy = LifecyclePolicy {
+            name: "logs".to_string(),
+            bucket_selector: BucketSelector {
+                names: vec!["audit".to_string()],
+                prefix: Some("logs-".to_string()),
+            },
+            rules: vec![LifecycleRule {
+                id: "expire".to_string(),
+                expiration: Some(LifecycleExpiration { days: 30 }),
+                ..Default::default()
+            }],
+            deletion_policy: Default::default(),
+        };
+        let live = ["audit", "data", "logs-a", "logs-b"].map(String::from);
+        assert_eq!(
+            selected_buckets(&policy, &live).unwrap(),
+            vec!["audit", "logs-a", "logs-b"]
+        );
+        assert!(
+            selected_buckets(&policy, &live[1..])
+                .unwrap_err()
+                .contains("audit")
+        );
+
+        let foreign = BucketLifecycleRule {
+            id: "manual".to_string(),
+            enabled: true,
+            expiration_days: Some(7),
+            ..Default::default()
+        };
+        let stale = BucketLifecycleRule {
+            id: "logs.old".to_string(),
+            enabled: true,
+            expiration_days: Some(1),
+            ..Default::default()
+        };
+        let desired = bucket_lifecycle_rules(&policy);
+        assert_eq!(desired[0].id, "logs.expire");
+        assert_eq!(desired[0].expiration_days, Some(30));
+
+        let merged = merge_lifecycle_rules(&[stale, foreign.clone()], desired.clone(), &["logs"]);
+        assert_eq!(merged, vec![desired[0].clone(), foreign]);
+    }
 }
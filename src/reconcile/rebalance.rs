@@ -0,0 +1,352 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Automatic rebalance after pool expansion (`spec.rebalance.auto`).
+//!
+//! The first reconcile with `auto` enabled records the active pool count as the baseline. Once a
+//! later spec has more active pools and every pod is Ready, the operator starts a RustFS
+//! rebalance through the admin API and polls `rebalance/status` on each reconcile until RustFS
+//! reports it finished. Progress is how far the usage spread between the fullest and emptiest
+//! pool has closed since the start.
+
+use super::pool_lifecycle::rustfs_admin_client;
+use crate::context::Context;
+use crate::sts::rustfs_client::RustfsRebalanceStatus;
+use crate::types::v1alpha1::pool_lifecycle::DecommissionAction;
+use crate::types::v1alpha1::status::pool::PoolLifecycleState;
+use crate::types::v1alpha1::status::rebalance::{RebalancePhase, RebalanceStatus};
+use crate::types::v1alpha1::tenant::Tenant;
+use kube::ResourceExt;
+use kube::runtime::events::EventType;
+use std::time::Duration;
+use tracing::warn;
+
+/// Requeue interval while a rebalance runs or waits to be retried.
+pub(super) const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+pub(super) fn auto_enabled(tenant: &Tenant) -> bool {
+    tenant
+        .spec
+        .rebalance
+        .as_ref()
+        .and_then(|rebalance| rebalance.auto)
+        .unwrap_or(false)
+}
+
+/// Next `status.rebalance` and whether the Tenant must be requeued to follow it.
+pub(super) async fn reconcile(
+    ctx: &Context,
+    tenant: &Tenant,
+    workloads_ready: bool,
+) -> (Option<RebalanceStatus>, bool) {
+    if !auto_enabled(tenant) {
+        return (None, false);
+    }
+    let previous = tenant
+        .status
+        .as_ref()
+        .and_then(|status| status.rebalance.clone());
+    if ctx.dry_run {
+        return (previous, false);
+    }
+    let pools = active_pool_count(tenant);
+    let mut status = previous.unwrap_or_else(|| RebalanceStatus {
+        pool_count: baseline_pool_count(tenant, pools),
+        ..Default::default()
+    });
+
+    if status.phase == Some(RebalancePhase::Running) {
+        let remote = match rustfs_admin_client(ctx, tenant).await {
+            Ok(client) => client.rebalance_status().await,
+            Err(error) => Err(error),
+        };
+        match remote {
+            Ok(remote) => {
+                let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+                if let Some(phase) = apply_remote_status(&mut status, &remote, &now) {
+                    record_finished(ctx, tenant, phase, status.pool_count).await;
+                }
+            }
+            Err(error) => {
+                warn!(tenant = %tenant.name_any(), %error, "failed to read rebalance status");
+                status.message = Some(format!("Reading rebalance status failed: {error}"));
+            }
+        }
+        let running = status.phase == Some(RebalancePhase::Running);
+        return (Some(status), running);
+    }
+
+    if pools < status.pool_count {
+        // A pool left; lower the baseline so adding one back rebalances again.
+        status.pool_count = pools;
+    }
+    if pools <= status.pool_count {
+        return (Some(status), false);
+    }
+    if !workloads_ready {
+        // Wait for the new pool's pods; the rollout requeues the Tenant.
+        return (Some(status), false);
+    }
+
+    let started = match rustfs_admin_client(ctx, tenant).await {
+        Ok(client) => client.start_rebalance().await,
+        Err(error) => Err(error),
+    };
+    match started {
+        Ok(()) => {
+            status = RebalanceStatus {
+                pool_count: pools,
+                phase: Some(RebalancePhase::Running),
+                progress_percent: Some(0),
+                started_at: Some(
+                    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                ),
+                ..Default::default()
+            };
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Normal,
+                    "RebalanceStarted",
+                    &format!("Rebalancing data across {pools} pools after pool expansion"),
+                )
+                .await;
+            (Some(status), true)
+        }
+        Err(error) => {
+            let message = format!("Starting the rebalance failed: {error}");
+            if status.phase != Some(RebalancePhase::Failed) {
+                let _ = ctx
+                    .record(tenant, EventType::Warning, "RebalanceFailed", &message)
+                    .await;
+            }
+            status.phase = Some(RebalancePhase::Failed);
+            status.message = Some(message);
+            // The pool count stays at the baseline, so the start is retried.
+            (Some(status), true)
+        }
+    }
+}
+
+async fn record_finished(ctx: &Context, tenant: &Tenant, phase: RebalancePhase, pools: i32) {
+    let (type_, reason, message) = match phase {
+        RebalancePhase::Completed => (
+            EventType::Normal,
+            "RebalanceCompleted",
+            format!("Rebalance across {pools} pools completed"),
+        ),
+        RebalancePhase::Stopped => (
+            EventType::Warning,
+            "RebalanceStopped",
+            "Rebalance was stopped before it completed".to_string(),
+        ),
+        RebalancePhase::Failed => (
+            EventType::Warning,
+            "RebalanceFailed",
+            "RustFS reported the rebalance as failed".to_string(),
+        ),
+        RebalancePhase::Running => return,
+    };
+    let _ = ctx.record(tenant, type_, reason, &message).await;
+}
+
+/// Folds a `rebalance/status` response into the status. Returns the new phase when the
+/// rebalance just finished.
+fn apply_remote_status(
+    status: &mut RebalanceStatus,
+    remote: &RustfsRebalanceStatus,
+    now: &str,
+) -> Option<RebalancePhase> {
+    let spread = usage_spread(remote);
+    if status.initial_usage_spread.is_none() {
+        status.initial_usage_spread = spread;
+    }
+    let has = |state: &str| {
+        remote
+            .pools
+            .iter()
+            .any(|pool| pool.status.eq_ignore_ascii_case(state))
+    };
+    let phase = if has("Failed") {
+        RebalancePhase::Failed
+    } else if has("Started") {
+        RebalancePhase::Running
+    } else if has("Stopped") || remote.stopped_at.is_some() {
+        RebalancePhase::Stopped
+    } else {
+        RebalancePhase::Completed
+    };
+
+    status.progress_percent = Some(match (phase, status.initial_usage_spread, spread) {
+        (RebalancePhase::Completed, _, _) => 100,
+        (_, Some(initial), Some(current)) if initial > 0.0 => {
+            (((initial - current) / initial) * 100.0).clamp(0.0, 99.0) as i32
+        }
+        _ => status.progress_percent.unwrap_or(0),
+    });
+    status.message = None;
+    status.phase = Some(phase);
+    if phase == RebalancePhase::Running {
+        return None;
+    }
+    status.completed_at = Some(now.to_string());
+    Some(phase)
+}
+
+fn usage_spread(remote: &RustfsRebalanceStatus) -> Option<f64> {
+    let used = remote.pools.iter().map(|pool| pool.used);
+    let max = used.clone().reduce(f64::max)?;
+    let min = used.reduce(f64::min)?;
+    Some(max - min)
+}
+
+/// Pools that keep data: in the spec and neither decommissioning nor decommissioned.
+fn active_pool_count(tenant: &Tenant) -> i32 {
+    tenant
+        .spec
+        .pools
+        .iter()
+        .filter(|pool| {
+            let requested = tenant
+                .spec
+                .pool_lifecycle
+                .as_ref()
+                .and_then(|lifecycle| lifecycle.request_for_pool(&pool.name))
+                .is_some_and(|request| request.action == DecommissionAction::Start);
+            let draining = tenant.status.as_ref().is_some_and(|status| {
+                status.pools.iter().any(|pool_status| {
+                    pool_status.name.as_deref() == Some(pool.name.as_str())
+                        && matches!(
+                            pool_status.lifecycle_state,
+                            Some(
+                                PoolLifecycleState::Decommissioning
+                                    | PoolLifecycleState::Decommissioned
+                            )
+                        )
+                })
+            });
+            !requested && !draining
+        })
+        .count() as i32
+}
+
+/// Pools before this reconcile: those already in status, so enabling `auto` together with a
+/// new pool still rebalances onto it. A new Tenant starts from its current pools.
+fn baseline_pool_count(tenant: &Tenant, active: i32) -> i32 {
+    let known = tenant
+        .status
+        .as_ref()
+        .map_or(0, |status| status.pools.len() as i32);
+    if known == 0 {
+        active
+    } else {
+        known.min(active)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sts::rustfs_client::RustfsRebalancePoolStatus;
+
+    fn remote(pools: &[(&str, f64)]) -> RustfsRebalanceStatus {
+        RustfsRebalanceStatus {
+            id: "rebalance-1".to_string(),
+            pools: pools
+                .iter()
+                .enumerate()
+                .map(|(id, (status, used))| RustfsRebalancePoolStatus {
+                    id,
+                    status: status.to_string(),
+                    used: *used,
+                })
+                .collect(),
+            stopped_at: None,
+        }
+    }
+
+    #[test]
+    fn progress_follows_usage_spread_until_completed() {
+        let mut status = RebalanceStatus {
+            pool_count: 2,
+            phase: Some(RebalancePhase::Running),
+            ..Default::default()
+        };
+
+        let finished = apply_remote_status(
+            &mut status,
+            &remote(&[("Started", 0.8), ("Started", 0.0)]),
+            "t0",
+        );
+        assert_eq!(finished, None);
+        assert_eq!(status.progress_percent, Some(0));
+        assert_eq!(status.initial_usage_spread, Some(0.8));
+
+        apply_remote_status(
+            &mut status,
+            &remote(&[("Started", 0.6), ("Started", 0.2)]),
+            "t1",
+        );
+        assert_eq!(status.progress_percent, Some(50));
+
+        let finished = apply_remote_status(
+            &mut status,
+            &remote(&[("Completed", 0.4), ("Completed", 0.4)]),
+            "t2",
+        );
+        assert_eq!(finished, Some(RebalancePhase::Completed));
+        assert_eq!(status.progress_percent, Some(100));
+        assert_eq!(status.completed_at.as_deref(), Some("t2"));
+    }
+
+    #[test]
+    fn failed_or_stopped_pools_end_the_rebalance() {
+        let mut status = RebalanceStatus::default();
+        assert_eq!(
+            apply_remote_status(
+                &mut status,
+                &remote(&[("Failed", 0.5), ("Started", 0.1)]),
+                "t"
+            ),
+            Some(RebalancePhase::Failed)
+        );
+
+        let mut status = RebalanceStatus::default();
+        assert_eq!(
+            apply_remote_status(
+                &mut status,
+                &remote(&[("Stopped", 0.5), ("Completed", 0.1)]),
+                "t"
+            ),
+            Some(RebalancePhase::Stopped)
+        );
+    }
+
+    #[test]
+    fn baseline_uses_pools_already_in_status() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert_eq!(baseline_pool_count(&tenant, 1), 1);
+
+        let mut second = tenant.spec.pools[0].clone();
+        second.name = "pool-1".to_string();
+        tenant.spec.pools.push(second);
+        let existing = tenant.build_pool_status("pool-0", &Default::default());
+        tenant.status = Some(crate::types::v1alpha1::status::Status {
+            pools: vec![existing],
+            ..Default::default()
+        });
+        assert_eq!(active_pool_count(&tenant), 2);
+        assert_eq!(baseline_pool_count(&tenant, 2), 1);
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Restore of a new pool from CSI VolumeSnapshots (`spec.pools[].persistence.dataSource`).
+//!
+//! A StatefulSet claim template holds one `dataSource` for every server, while each RustFS drive
+//! holds different data. So before the pool's StatefulSet is created, the operator creates every
+//! claim itself under the name the StatefulSet expects, with `dataSource` pointing at the
+//! snapshot listed for that slot. Each snapshot must exist, be ready to use, and fit in the
+//! claim's requested storage. Once the StatefulSet exists, the field is no longer consulted.
+
+use super::{Error, context_result, types_result};
+use crate::console::handlers::cluster::parse_memory_to_bytes;
+use crate::context::{self, Context, is_kube_not_found};
+use crate::types::v1alpha1::pool::Pool;
+use crate::types::v1alpha1::tenant::{Tenant, VOLUME_SNAPSHOT_GROUP};
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::api::{Api, ApiResource, DynamicObject};
+use kube::core::GroupVersionKind;
+use kube::runtime::events::EventType;
+use tracing::info;
+
+/// Creates the snapshot-backed claims of a pool whose StatefulSet does not exist yet.
+pub(super) async fn reconcile_snapshot_restore(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    pool: &Pool,
+    statefulset_name: &str,
+) -> Result<(), Error> {
+    let slots = tenant.snapshot_restore_slots(pool);
+    if slots.is_empty() {
+        return Ok(());
+    }
+    match ctx.get::<StatefulSet>(statefulset_name, namespace).await {
+        Ok(_) => return Ok(()),
+        Err(error) if is_kube_not_found(&error) => {}
+        Err(error) => return context_result(Err(error), ctx, tenant).await,
+    }
+
+    let gvk = GroupVersionKind::gvk(VOLUME_SNAPSHOT_GROUP, "v1", "VolumeSnapshot");
+    let resource = ApiResource::from_gvk_with_plural(&gvk, "volumesnapshots");
+    let snapshots: Api<DynamicObject> =
+        Api::namespaced_with(ctx.client.clone(), namespace, &resource);
+
+    let mut created = 0;
+    for slot in &slots {
+        match ctx
+            .get::<corev1::PersistentVolumeClaim>(&slot.target, namespace)
+            .await
+        {
+            Ok(_) => continue,
+            Err(error) if is_kube_not_found(&error) => {}
+            Err(error) => return context_result(Err(error), ctx, tenant).await,
+        }
+
+        let claim =
+            types_result(tenant.new_snapshot_restore_claim(pool, slot), ctx, tenant).await?;
+        let snapshot = context_result(
+            snapshots
+                .get_opt(&slot.snapshot)
+                .await
+                .map_err(|source| context::Error::Kube { source }),
+            ctx,
+            tenant,
+        )
+        .await?;
+        let checked = match &snapshot {
+            Some(snapshot) => check_snapshot(snapshot, &claim),
+            None => Err("VolumeSnapshot was not found".to_string()),
+        };
+        if let Err(message) = checked {
+            return context_result(
+                Err(context::Error::SnapshotSourceUnavailable {
+                    snapshot: slot.snapshot.clone(),
+                    message,
+                }),
+                ctx,
+                tenant,
+            )
+            .await;
+        }
+
+        context_result(ctx.create(&claim, namespace).await, ctx, tenant).await?;
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            pool = %pool.name,
+            snapshot = %slot.snapshot,
+            claim = %slot.target,
+            "created claim restored from VolumeSnapshot"
+        );
+        created += 1;
+    }
+
+    if created > 0 {
+        let _ = ctx
+            .record(
+                tenant,
+                EventType::Normal,
+                "SnapshotRestoreStarted",
+                &format!(
+                    "Created {} of {} claims of pool {} from VolumeSnapshots",
+                    created,
+                    slots.len(),
+                    pool.name
+                ),
+            )
+            .await;
+    }
+    Ok(())
+}
+
+/// Checks that `snapshot` is ready to use and that its restore size fits in the storage
+/// requested by `claim`.
+fn check_snapshot(
+    snapshot: &DynamicObject,
+    claim: &corev1::PersistentVolumeClaim,
+) -> Result<(), String> {
+    let status = snapshot.data.get("status");
+    let ready = status
+        .and_then(|status| status.get("readyToUse"))
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    if !ready {
+        return Err("VolumeSnapshot is not ready to use".to_string());
+    }
+
+    let restore_size = status
+        .and_then(|status| status.get("restoreSize"))
+        .and_then(serde_json::Value::as_str);
+    let requested = claim
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.resources.as_ref())
+        .and_then(|resources| resources.requests.as_ref())
+        .and_then(|requests| requests.get("storage"));
+    if let (Some(restore_size), Some(requested)) = (restore_size, requested)
+        && parse_memory_to_bytes(restore_size) > parse_memory_to_bytes(&requested.0)
+    {
+        return Err(format!(
+            "restore size {} is larger than the claim request {}",
+            restore_size, requested.0
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use kube::ResourceExt;
+
+    fn snapshot(status: serde_json::Value) -> DynamicObject {
+        let gvk = GroupVersionKind::gvk(VOLUME_SNAPSHOT_GROUP, "v1", "VolumeSnapshot");
+        let mut snapshot = DynamicObject::new("snap-0", &ApiResource::from_gvk(&gvk));
+        snapshot.data = serde_json::json!({ "status": status });
+        snapshot
+    }
+
+    fn claim(size: &str) -> corev1::PersistentVolumeClaim {
+        corev1::PersistentVolumeClaim {
+            spec: Some(corev1::PersistentVolumeClaimSpec {
+                resources: Some(corev1::VolumeResourceRequirements {
+                    requests: Some(
+                        [("storage".to_string(), Quantity(size.to_string()))]
+                            .into_iter()
+                            .collect(),
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn requires_ready_snapshot_that_fits_the_claim() {
+        let ready = snapshot(serde_json::json!({ "readyToUse": true, "restoreSize": "10Gi" }));
+        assert!(check_snapshot(&ready, &claim("10Gi")).is_ok());
+        assert!(check_snapshot(&ready, &claim("20Gi")).is_ok());
+        let err = check_snapshot(&ready, &claim("5Gi"))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("restore size 10Gi is larger than the claim request 5Gi"));
+
+        let pending = snapshot(serde_json::json!({ "readyToUse": false }));
+        assert!(check_snapshot(&pending, &claim("10Gi")).is_err());
+        assert!(check_snapshot(&snapshot(serde_json::Value::Null), &claim("10Gi")).is_err());
+    }
+
+    #[test]
+    fn restore_claims_follow_server_major_order() {
+        use crate::types::v1alpha1::persistence::SnapshotDataSource;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &mut tenant.spec.pools[0];
+        pool.servers = 2;
+        pool.persistence.volumes_per_server = 2;
+        pool.persistence.data_source = Some(SnapshotDataSource {
+            volume_snapshots: ["s0", "s1", "s2", "s3"].map(String::from).to_vec(),
+        });
+        let pool = tenant.spec.pools[0].clone();
+
+        let slots = tenant.snapshot_restore_slots(&pool);
+        assert_eq!(slots[1].target, "vol-1-test-tenant-pool-0-0");
+        assert_eq!(slots[2].target, "vol-0-test-tenant-pool-0-1");
+        assert_eq!(slots[2].snapshot, "s2");
+
+        let Ok(restored) = tenant.new_snapshot_restore_claim(&pool, &slots[2]) else {
+            panic!("Should build restore claim");
+        };
+        assert_eq!(restored.name_any(), "vol-0-test-tenant-pool-0-1");
+        let data_source = restored.spec.and_then(|spec| spec.data_source);
+        assert_eq!(
+            data_source,
+            Some(corev1::TypedLocalObjectReference {
+                api_group: Some(VOLUME_SNAPSHOT_GROUP.to_string()),
+                kind: "VolumeSnapshot".to_string(),
+                name: "s2".to_string(),
+            })
+        );
+    }
+}
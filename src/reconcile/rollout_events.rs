@@ -0,0 +1,564 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-pool rollout and decommission milestones as Tenant events.
+//!
+//! The milestones are derived by comparing the pool statuses of the previous reconcile with the
+//! ones about to be written, so each transition is reported once and `kubectl describe tenant`
+//! reads as a timeline: rollout progress, completion with its duration, the cause of a stalled
+//! rollout, and decommission start and outcome.
+
+use crate::context::Context;
+use crate::types::v1alpha1::status::pool::{Pool as PoolStatus, PoolLifecycleState, PoolState};
+use crate::types::v1alpha1::tenant::{Tenant, pool_selector};
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::ListParams;
+use kube::runtime::events::EventType;
+use std::collections::BTreeMap;
+use tracing::warn;
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Milestone {
+    pub event_type: EventType,
+    pub reason: &'static str,
+    pub message: String,
+}
+
+impl Milestone {
+    fn normal(reason: &'static str, message: String) -> Self {
+        Self {
+            event_type: EventType::Normal,
+            reason,
+            message,
+        }
+    }
+
+    fn warning(reason: &'static str, message: String) -> Self {
+        Self {
+            event_type: EventType::Warning,
+            reason,
+            message,
+        }
+    }
+}
+
+/// Sets `rolloutStartedAt` on updating pools, keeping the time a rollout was first observed.
+pub(super) fn track_rollout_start(previous: &[PoolStatus], pools: &mut [PoolStatus], now: &str) {
+    for pool in pools {
+        pool.rollout_started_at = if pool.state == PoolState::Updating {
+            find(previous, pool)
+                .filter(|before| before.state == PoolState::Updating)
+                .and_then(|before| before.rollout_started_at.clone())
+                .or_else(|| Some(now.to_string()))
+        } else {
+            None
+        };
+    }
+}
+
+/// Milestones between the previous and the next pool statuses, in pool order.
+pub(super) fn milestones(
+    previous: &[PoolStatus],
+    pools: &[PoolStatus],
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<Milestone> {
+    let mut milestones = Vec::new();
+    for pool in pools {
+        let before = find(previous, pool);
+        milestones.extend(rollout_milestone(pool, before, now));
+        milestones.extend(decommission_milestone(pool, before));
+    }
+    milestones
+}
+
+fn rollout_milestone(
+    pool: &PoolStatus,
+    before: Option<&PoolStatus>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<Milestone> {
+    let name = pool_name(pool);
+    let was_updating = before.is_some_and(|before| before.state == PoolState::Updating);
+    match pool.state {
+        PoolState::Updating => {
+            let updated = pool.updated_replicas.unwrap_or(0);
+            let unchanged = was_updating
+                && before.map(|before| before.updated_replicas.unwrap_or(0)) == Some(updated);
+            (!unchanged).then(|| {
+                Milestone::normal(
+                    "RolloutProgress",
+                    format!(
+                        "Pool '{name}': {updated}/{} pods updated",
+                        pool.replicas.unwrap_or(0)
+                    ),
+                )
+            })
+        }
+        PoolState::RolloutComplete if was_updating => {
+            let replicas = pool.replicas.unwrap_or(0);
+            let mut message = format!(
+                "Pool '{name}' rollout completed: {replicas}/{replicas} pods updated and ready"
+            );
+            if let Some(elapsed) = before
+                .and_then(|before| before.rollout_started_at.as_deref())
+                .and_then(|started| elapsed_since(started, now))
+            {
+                message.push_str(&format!(" in {}", format_elapsed(elapsed)));
+            }
+            Some(Milestone::normal("RolloutCompleted", message))
+        }
+        _ => None,
+    }
+}
+
+fn decommission_milestone(pool: &PoolStatus, before: Option<&PoolStatus>) -> Option<Milestone> {
+    let state = pool.lifecycle_state.as_ref()?;
+    if before.and_then(|before| before.lifecycle_state.as_ref()) == Some(state) {
+        return None;
+    }
+    let name = pool_name(pool);
+    let decommission = pool.decommission.as_ref();
+    match state {
+        PoolLifecycleState::Active => None,
+        PoolLifecycleState::Decommissioning => Some(Milestone::normal(
+            "PoolDecommissionStarted",
+            format!("Pool '{name}' is being decommissioned; data is migrating to the other pools"),
+        )),
+        PoolLifecycleState::Decommissioned => {
+            let mut message = format!("Pool '{name}' decommission completed");
+            if let Some(progress) = decommission.and_then(|status| status.progress.as_ref())
+                && let Some(objects) = progress.objects_migrated
+            {
+                message.push_str(&format!(", {objects} object(s) migrated"));
+            }
+            if let Some(elapsed) = decommission.and_then(|status| {
+                let completed = status.completed_at.as_deref()?;
+                let completed = chrono::DateTime::parse_from_rfc3339(completed).ok()?;
+                elapsed_since(status.started_at.as_deref()?, completed.to_utc())
+            }) {
+                message.push_str(&format!(" in {}", format_elapsed(elapsed)));
+            }
+            Some(Milestone::normal("PoolDecommissionCompleted", message))
+        }
+        PoolLifecycleState::DecommissionCanceled => Some(Milestone::warning(
+            "PoolDecommissionCanceled",
+            format!("Pool '{name}' decommission was canceled"),
+        )),
+        PoolLifecycleState::DecommissionFailed => {
+            let mut message = format!("Pool '{name}' decommission failed");
+            if let Some(detail) = decommission
+                .and_then(|status| status.last_error.as_ref())
+                .and_then(|error| error.message.as_deref())
+            {
+                message.push_str(": ");
+                message.push_str(detail);
+            }
+            Some(Milestone::warning("PoolDecommissionFailed", message))
+        }
+        PoolLifecycleState::Terminating => Some(Milestone::normal(
+            "PoolTerminating",
+            format!(
+                "Pool '{name}' was removed from spec; waiting for its StatefulSet and pods to be deleted"
+            ),
+        )),
+    }
+}
+
+/// Warnings naming why a pool rollout has not moved since the previous reconcile. Read failures
+/// are logged and the pool is skipped.
+///
+/// A StatefulSet rolling update deletes pods itself rather than evicting them, so
+/// PodDisruptionBudgets never hold it back; it waits for the partition or for an unready pod.
+pub(super) async fn stalled_rollouts(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    previous: &[PoolStatus],
+    pools: &[PoolStatus],
+) -> Vec<Milestone> {
+    let stalled = pools
+        .iter()
+        .filter(|pool| pool.state == PoolState::Updating)
+        .filter(|pool| {
+            find(previous, pool).is_some_and(|before| {
+                before.state == PoolState::Updating
+                    && before.updated_replicas == pool.updated_replicas
+            })
+        });
+
+    let mut milestones = Vec::new();
+    for pool in stalled {
+        let statefulset = match ctx.get::<StatefulSet>(&pool.ss_name, namespace).await {
+            Ok(statefulset) => statefulset,
+            Err(error) => {
+                warn!(tenant = %tenant.name(), namespace, statefulset = %pool.ss_name, %error, "failed to read stalled StatefulSet");
+                continue;
+            }
+        };
+        let params = ListParams::default().labels(&pool_selector(&tenant.name(), pool_name(pool)));
+        let pods = match ctx.list_with_params::<Pod>(namespace, &params).await {
+            Ok(pods) => pods,
+            Err(error) => {
+                warn!(tenant = %tenant.name(), namespace, statefulset = %pool.ss_name, %error, "failed to list pods of stalled rollout");
+                continue;
+            }
+        };
+        milestones.push(Milestone::warning(
+            "RolloutStalled",
+            format!(
+                "Pool '{}': rollout has not progressed; {}",
+                pool_name(pool),
+                stall_cause(&statefulset, &pods.items)
+            ),
+        ));
+    }
+    milestones
+}
+
+/// What a StatefulSet rolling update is waiting for.
+fn stall_cause(statefulset: &StatefulSet, pods: &[Pod]) -> String {
+    let spec = statefulset.spec.as_ref();
+    let rolling_update = spec
+        .and_then(|spec| spec.update_strategy.as_ref())
+        .and_then(|strategy| strategy.rolling_update.as_ref());
+    let partition = rolling_update
+        .and_then(|rolling_update| rolling_update.partition)
+        .unwrap_or(0);
+    if partition > 0 {
+        return format!(
+            "the StatefulSet partition is {partition}, so pods with a lower ordinal keep the old revision"
+        );
+    }
+
+    let mut unready: Vec<&str> = pods
+        .iter()
+        .filter(|pod| !pod_ready(pod))
+        .filter_map(|pod| pod.metadata.name.as_deref())
+        .collect();
+    unready.sort_unstable();
+    if !unready.is_empty() {
+        let max_unavailable = rolling_update
+            .and_then(|rolling_update| rolling_update.max_unavailable.as_ref())
+            .map_or_else(
+                || "1".to_string(),
+                |value| match value {
+                    IntOrString::Int(value) => value.to_string(),
+                    IntOrString::String(value) => value.clone(),
+                },
+            );
+        return format!(
+            "waiting for pod(s) {} to become Ready (maxUnavailable {max_unavailable})",
+            unready.join(", ")
+        );
+    }
+
+    "all pods are Ready but none moved to the new revision; check the StatefulSet controller"
+        .to_string()
+}
+
+fn pod_ready(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|condition| condition.type_ == "Ready" && condition.status == "True")
+        })
+}
+
+/// Evaluates a label selector. An empty selector matches everything, as for PDBs in policy/v1.
+pub(super) fn selector_matches(
+    selector: &LabelSelector,
+    labels: &BTreeMap<String, String>,
+) -> bool {
+    let labels_match = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .all(|(key, value)| labels.get(key) == Some(value));
+    let expressions_match = selector
+        .match_expressions
+        .iter()
+        .flatten()
+        .all(|expression| {
+            let value = labels.get(&expression.key);
+            let values = expression.values.as_deref().unwrap_or_default();
+            match expression.operator.as_str() {
+                "In" => value.is_some_and(|value| values.contains(value)),
+                "NotIn" => value.is_none_or(|value| !values.contains(value)),
+                "Exists" => value.is_some(),
+                "DoesNotExist" => value.is_none(),
+                _ => false,
+            }
+        });
+    labels_match && expressions_match
+}
+
+pub(super) async fn record(ctx: &Context, tenant: &Tenant, milestones: Vec<Milestone>) {
+    for milestone in milestones {
+        let _ = ctx
+            .record(
+                tenant,
+                milestone.event_type,
+                milestone.reason,
+                &milestone.message,
+            )
+            .await;
+    }
+}
+
+fn find<'a>(previous: &'a [PoolStatus], pool: &PoolStatus) -> Option<&'a PoolStatus> {
+    previous
+        .iter()
+        .find(|before| before.ss_name == pool.ss_name)
+}
+
+fn pool_name(pool: &PoolStatus) -> &str {
+    pool.name.as_deref().unwrap_or(&pool.ss_name)
+}
+
+fn elapsed_since(started: &str, now: chrono::DateTime<chrono::Utc>) -> Option<chrono::Duration> {
+    let started = chrono::DateTime::parse_from_rfc3339(started).ok()?;
+    let elapsed = now.signed_duration_since(started);
+    (elapsed >= chrono::Duration::zero()).then_some(elapsed)
+}
+
+/// Compact duration such as `45s`, `3m12s`, or `2h5m`.
+fn format_elapsed(elapsed: chrono::Duration) -> String {
+    let hours = elapsed.num_hours();
+    let minutes = elapsed.num_minutes() % 60;
+    let seconds = elapsed.num_seconds() % 60;
+    if hours > 0 {
+        format!("{hours}h{minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m{seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::v1alpha1::status::pool::{
+        PoolDecommissionPhase, PoolDecommissionProgress, PoolDecommissionStatus,
+    };
+
+    fn pool(state: PoolState, updated: i32) -> PoolStatus {
+        PoolStatus {
+            name: Some("pool-0".to_string()),
+            ss_name: "tenant-pool-0".to_string(),
+            state: state.clone(),
+            lifecycle_state: Some(PoolLifecycleState::Active),
+            workload_state: Some(state),
+            decommission: None,
+            replicas: Some(4),
+            ready_replicas: Some(4),
+            current_replicas: Some(4),
+            updated_replicas: Some(updated),
+            current_revision: None,
+            update_revision: None,
+            last_update_time: None,
+            rollout_started_at: None,
+            pods: Vec::new(),
+            failed_volumes: Vec::new(),
+        }
+    }
+
+    fn at(time: &str) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(time)
+            .map(|time| time.to_utc())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn rollout_start_is_kept_until_the_rollout_completes() {
+        let mut first = vec![pool(PoolState::Updating, 1)];
+        track_rollout_start(&[], &mut first, "2025-01-01T00:00:00Z");
+        let mut second = vec![pool(PoolState::Updating, 2)];
+        track_rollout_start(&first, &mut second, "2025-01-01T00:01:00Z");
+        let mut done = vec![pool(PoolState::RolloutComplete, 4)];
+        track_rollout_start(&second, &mut done, "2025-01-01T00:02:00Z");
+
+        assert_eq!(
+            second[0].rollout_started_at.as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+        assert_eq!(done[0].rollout_started_at, None);
+    }
+
+    #[test]
+    fn progress_is_reported_only_when_updated_pods_change() {
+        let now = at("2025-01-01T00:01:00Z");
+        let before = vec![pool(PoolState::Updating, 1)];
+
+        let moved = milestones(&before, &[pool(PoolState::Updating, 2)], now);
+        assert_eq!(
+            moved,
+            vec![Milestone::normal(
+                "RolloutProgress",
+                "Pool 'pool-0': 2/4 pods updated".to_string()
+            )]
+        );
+        assert!(milestones(&before, &[pool(PoolState::Updating, 1)], now).is_empty());
+    }
+
+    #[test]
+    fn completion_reports_the_rollout_duration() {
+        let mut before = pool(PoolState::Updating, 3);
+        before.rollout_started_at = Some("2025-01-01T00:00:00Z".to_string());
+
+        let events = milestones(
+            &[before],
+            &[pool(PoolState::RolloutComplete, 4)],
+            at("2025-01-01T00:03:12Z"),
+        );
+
+        assert_eq!(
+            events,
+            vec![Milestone::normal(
+                "RolloutCompleted",
+                "Pool 'pool-0' rollout completed: 4/4 pods updated and ready in 3m12s".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn decommission_transitions_are_reported_once() {
+        let now = at("2025-01-01T01:00:00Z");
+        let active = pool(PoolState::RolloutComplete, 4);
+        let mut decommissioning = active.clone();
+        decommissioning.lifecycle_state = Some(PoolLifecycleState::Decommissioning);
+        let mut decommissioned = active.clone();
+        decommissioned.lifecycle_state = Some(PoolLifecycleState::Decommissioned);
+        decommissioned.decommission = Some(PoolDecommissionStatus {
+            request_id: None,
+            rustfs_pool_id: None,
+            endpoint_set_hash: None,
+            phase: Some(PoolDecommissionPhase::Complete),
+            started_at: Some("2025-01-01T00:00:00Z".to_string()),
+            last_poll_time: None,
+            completed_at: Some("2025-01-01T00:45:30Z".to_string()),
+            progress: Some(PoolDecommissionProgress {
+                objects_migrated: Some(120),
+                ..Default::default()
+            }),
+            cleanup: None,
+            last_error: None,
+        });
+
+        let started = milestones(&[active], std::slice::from_ref(&decommissioning), now);
+        assert_eq!(started.len(), 1);
+        assert_eq!(started[0].reason, "PoolDecommissionStarted");
+        assert!(
+            milestones(
+                std::slice::from_ref(&decommissioning),
+                std::slice::from_ref(&decommissioning),
+                now
+            )
+            .is_empty()
+        );
+
+        let completed = milestones(&[decommissioning], &[decommissioned], now);
+        assert_eq!(
+            completed,
+            vec![Milestone::normal(
+                "PoolDecommissionCompleted",
+                "Pool 'pool-0' decommission completed, 120 object(s) migrated in 45m30s"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn selector_matching_follows_label_selector_semantics() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement;
+
+        let labels: BTreeMap<String, String> = [
+            ("rustfs.tenant".to_string(), "tenant".to_string()),
+            ("rustfs.pool".to_string(), "pool-0".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let by_label = LabelSelector {
+            match_labels: Some(
+                [("rustfs.tenant".to_string(), "tenant".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        let other_pool = LabelSelector {
+            match_expressions: Some(vec![LabelSelectorRequirement {
+                key: "rustfs.pool".to_string(),
+                operator: "In".to_string(),
+                values: Some(vec!["pool-1".to_string()]),
+            }]),
+            ..Default::default()
+        };
+
+        assert!(selector_matches(&by_label, &labels));
+        assert!(selector_matches(&LabelSelector::default(), &labels));
+        assert!(!selector_matches(&other_pool, &labels));
+    }
+
+    #[test]
+    fn stall_cause_names_partition_then_unready_pods() {
+        use k8s_openapi::api::apps::v1::{
+            RollingUpdateStatefulSetStrategy, StatefulSetSpec, StatefulSetUpdateStrategy,
+        };
+        use k8s_openapi::api::core::v1::{PodCondition, PodStatus};
+
+        let statefulset = |partition: Option<i32>| StatefulSet {
+            spec: Some(StatefulSetSpec {
+                update_strategy: Some(StatefulSetUpdateStrategy {
+                    rolling_update: Some(RollingUpdateStatefulSetStrategy {
+                        partition,
+                        max_unavailable: None,
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pod = |name: &str, ready: &str| {
+            let mut pod = Pod::default();
+            pod.metadata.name = Some(name.to_string());
+            pod.status = Some(PodStatus {
+                conditions: Some(vec![PodCondition {
+                    type_: "Ready".to_string(),
+                    status: ready.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            });
+            pod
+        };
+        let pods = vec![
+            pod("tenant-pool-0-1", "False"),
+            pod("tenant-pool-0-0", "True"),
+        ];
+
+        assert!(stall_cause(&statefulset(Some(2)), &pods).contains("partition is 2"));
+        assert_eq!(
+            stall_cause(&statefulset(None), &pods),
+            "waiting for pod(s) tenant-pool-0-1 to become Ready (maxUnavailable 1)"
+        );
+        assert!(stall_cause(&statefulset(None), &pods[1..]).starts_with("all pods are Ready"));
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `{tenant}-io`, `{tenant}-console`, and `{tenant}-hl` Services.
+//!
+//! A Service is applied when missing or when its rendering changed. A Service whose
+//! [`SPEC_HASH_LABEL`] is current is still compared with the rendering, and fields edited by hand
+//! (type, selector, ports, `publishNotReadyAddresses`) are taken back with a forced apply.
+//! `clusterIP` cannot be changed in place, so a Service that is headless when it should not be, or
+//! the other way round, is deleted and created again.
+
+use super::adoption::verify_existing_headless_service;
+use super::{Error, context_result};
+use crate::context::{Context, is_kube_not_found};
+use crate::types::v1alpha1::tenant::{SPEC_HASH_LABEL, Tenant, spec_hash_label_value};
+use crate::types::v1alpha1::tls::TlsPlan;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::ResourceExt;
+use kube::runtime::events::EventType;
+use tracing::info;
+
+pub(super) async fn reconcile_services(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    tls_plan: &TlsPlan,
+) -> Result<(), Error> {
+    reconcile_service(
+        ctx,
+        tenant,
+        namespace,
+        tenant.new_io_service_with_tls_plan(tls_plan),
+    )
+    .await?;
+    reconcile_service(ctx, tenant, namespace, tenant.new_console_service()).await?;
+    match &tenant.spec.existing_headless_service {
+        Some(name) => verify_existing_headless_service(ctx, tenant, namespace, name).await,
+        None => {
+            reconcile_service(
+                ctx,
+                tenant,
+                namespace,
+                tenant.new_headless_service_with_tls_plan(tls_plan),
+            )
+            .await
+        }
+    }
+}
+
+async fn reconcile_service(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    mut desired: corev1::Service,
+) -> Result<(), Error> {
+    let name = desired.name_any();
+    let hash = spec_hash_label_value(&desired);
+    desired
+        .labels_mut()
+        .insert(SPEC_HASH_LABEL.to_string(), hash.clone());
+
+    let live = match ctx.get::<corev1::Service>(&name, namespace).await {
+        Ok(live) => Some(live),
+        Err(error) if is_kube_not_found(&error) => None,
+        Err(error) => return context_result(Err(error), ctx, tenant).await,
+    };
+    let Some(live) = live else {
+        return context_result(ctx.apply(&desired, namespace).await, ctx, tenant)
+            .await
+            .map(drop);
+    };
+
+    if is_headless(&live) != is_headless(&desired) {
+        info!(service = %name, "recreating Service to change clusterIP");
+        context_result(
+            ctx.delete::<corev1::Service>(&name, namespace).await,
+            ctx,
+            tenant,
+        )
+        .await?;
+        context_result(ctx.apply(&desired, namespace).await, ctx, tenant).await?;
+        let _ = ctx
+            .record(
+                tenant,
+                EventType::Warning,
+                "ServiceRecreated",
+                &format!("Service {name} was recreated to restore its clusterIP"),
+            )
+            .await;
+        return Ok(());
+    }
+
+    if live.labels().get(SPEC_HASH_LABEL) != Some(&hash) {
+        return context_result(ctx.apply(&desired, namespace).await, ctx, tenant)
+            .await
+            .map(drop);
+    }
+
+    let Some(field) = drifted_field(&live, &desired) else {
+        return Ok(());
+    };
+    info!(service = %name, field, "repairing Service edited outside the operator");
+    context_result(ctx.force_apply(&desired, namespace).await, ctx, tenant).await?;
+    let _ = ctx
+        .record(
+            tenant,
+            EventType::Warning,
+            "ServiceRepaired",
+            &format!("Service {name} had a modified {field} and was restored"),
+        )
+        .await;
+    Ok(())
+}
+
+fn is_headless(service: &corev1::Service) -> bool {
+    service
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.cluster_ip.as_deref())
+        == Some("None")
+}
+
+/// First operator-owned field of `live` that no longer matches `desired`, defaults included.
+fn drifted_field(live: &corev1::Service, desired: &corev1::Service) -> Option<&'static str> {
+    let default = corev1::ServiceSpec::default();
+    let live = live.spec.as_ref().unwrap_or(&default);
+    let desired = desired.spec.as_ref().unwrap_or(&default);
+
+    let service_type = |spec: &corev1::ServiceSpec| {
+        spec.type_
+            .clone()
+            .unwrap_or_else(|| "ClusterIP".to_string())
+    };
+    if service_type(live) != service_type(desired) {
+        return Some("type");
+    }
+    if live.selector != desired.selector {
+        return Some("selector");
+    }
+    if live.publish_not_ready_addresses.unwrap_or(false)
+        != desired.publish_not_ready_addresses.unwrap_or(false)
+    {
+        return Some("publishNotReadyAddresses");
+    }
+    let ports_match = desired.ports.iter().flatten().all(|desired_port| {
+        live.ports.iter().flatten().any(|live_port| {
+            live_port.name == desired_port.name
+                && live_port.port == desired_port.port
+                && target_port(live_port) == target_port(desired_port)
+                && protocol(live_port) == protocol(desired_port)
+        })
+    });
+    if !ports_match {
+        return Some("ports");
+    }
+    None
+}
+
+/// Target port with the API server default, the Service port, filled in.
+fn target_port(port: &corev1::ServicePort) -> IntOrString {
+    port.target_port
+        .clone()
+        .unwrap_or(IntOrString::Int(port.port))
+}
+
+fn protocol(port: &corev1::ServicePort) -> &str {
+    port.protocol.as_deref().unwrap_or("TCP")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{drifted_field, is_headless};
+    use crate::types::v1alpha1::tls::TlsPlan;
+    use k8s_openapi::api::core::v1 as corev1;
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    /// Fills in what the API server adds to a created Service.
+    fn as_stored(mut service: corev1::Service) -> corev1::Service {
+        if let Some(spec) = service.spec.as_mut() {
+            spec.cluster_ip
+                .get_or_insert_with(|| "10.0.0.1".to_string());
+            spec.session_affinity = Some("None".to_string());
+            for port in spec.ports.iter_mut().flatten() {
+                port.protocol.get_or_insert_with(|| "TCP".to_string());
+                port.target_port.get_or_insert(IntOrString::Int(port.port));
+            }
+        }
+        service
+    }
+
+    fn spec(service: &mut corev1::Service) -> &mut corev1::ServiceSpec {
+        service.spec.get_or_insert_with(Default::default)
+    }
+
+    #[test]
+    fn io_service_drift() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let desired = tenant.new_io_service_with_tls_plan(&TlsPlan::disabled());
+        assert_eq!(drifted_field(&as_stored(desired.clone()), &desired), None);
+
+        let mut live = as_stored(desired.clone());
+        spec(&mut live).type_ = Some("NodePort".to_string());
+        assert_eq!(drifted_field(&live, &desired), Some("type"));
+
+        let mut live = as_stored(desired.clone());
+        if let Some(port) = spec(&mut live).ports.iter_mut().flatten().next() {
+            port.target_port = Some(IntOrString::Int(8000));
+        }
+        assert_eq!(drifted_field(&live, &desired), Some("ports"));
+    }
+
+    #[test]
+    fn console_service_drift() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let desired = tenant.new_console_service();
+        assert_eq!(drifted_field(&as_stored(desired.clone()), &desired), None);
+
+        let mut live = as_stored(desired.clone());
+        spec(&mut live)
+            .selector
+            .get_or_insert_with(Default::default)
+            .insert("app".to_string(), "other".to_string());
+        assert_eq!(drifted_field(&live, &desired), Some("selector"));
+
+        let mut live = as_stored(desired.clone());
+        spec(&mut live).ports = None;
+        assert_eq!(drifted_field(&live, &desired), Some("ports"));
+    }
+
+    #[test]
+    fn headless_service_drift() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let desired = tenant.new_headless_service_with_tls_plan(&TlsPlan::disabled());
+        let stored = as_stored(desired.clone());
+        assert!(is_headless(&desired) && is_headless(&stored));
+        assert_eq!(drifted_field(&stored, &desired), None);
+
+        let mut live = stored.clone();
+        spec(&mut live).publish_not_ready_addresses = None;
+        assert_eq!(
+            drifted_field(&live, &desired),
+            Some("publishNotReadyAddresses")
+        );
+
+        let mut live = stored;
+        spec(&mut live).cluster_ip = Some("10.0.0.2".to_string());
+        assert!(!is_headless(&live));
+        assert!(!is_headless(&tenant.new_io_service()));
+    }
+}
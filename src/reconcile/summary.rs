@@ -0,0 +1,187 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! One structured summary line per reconcile.
+//!
+//! The child writes and the status write decision of a reconcile are counted through the
+//! [`Context`](crate::context::Context) write helpers into a task-local [`ReconcileSummary`].
+//! Reconciles of different Tenants run concurrently on separate tasks, so the counts never mix.
+//! Writes made outside [`scope`] are only counted in the metrics.
+
+use super::Error;
+use crate::context::ApplyOutcome;
+use kube::runtime::controller::Action;
+use std::cell::Cell;
+use std::future::Future;
+use std::time::Duration;
+use tracing::info;
+
+tokio::task_local! {
+    static CURRENT: ReconcileSummary;
+}
+
+/// Child writes and the last status write decision of one reconcile.
+#[derive(Debug, Default)]
+pub(crate) struct ReconcileSummary {
+    created: Cell<u32>,
+    updated: Cell<u32>,
+    unchanged: Cell<u32>,
+    applied: Cell<u32>,
+    deleted: Cell<u32>,
+    status_write: Cell<Option<&'static str>>,
+}
+
+/// Snapshot of a [`ReconcileSummary`] taken when the reconcile ends.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct SummaryCounts {
+    pub(crate) created: u32,
+    pub(crate) updated: u32,
+    pub(crate) unchanged: u32,
+    /// Server-side applies made without reading the live object first, so it is not known
+    /// whether they created, changed or kept the child.
+    pub(crate) applied: u32,
+    pub(crate) deleted: u32,
+    pub(crate) status_write: Option<&'static str>,
+}
+
+impl ReconcileSummary {
+    fn counts(&self) -> SummaryCounts {
+        SummaryCounts {
+            created: self.created.get(),
+            updated: self.updated.get(),
+            unchanged: self.unchanged.get(),
+            applied: self.applied.get(),
+            deleted: self.deleted.get(),
+            status_write: self.status_write.get(),
+        }
+    }
+}
+
+/// Runs `reconcile` with a fresh summary and returns its output with the counts.
+pub(crate) async fn scope<F: Future>(reconcile: F) -> (F::Output, SummaryCounts) {
+    CURRENT
+        .scope(ReconcileSummary::default(), async {
+            let output = reconcile.await;
+            (output, CURRENT.with(ReconcileSummary::counts))
+        })
+        .await
+}
+
+fn with_current(update: impl FnOnce(&ReconcileSummary)) {
+    let _ = CURRENT.try_with(update);
+}
+
+fn bump(counter: &Cell<u32>) {
+    counter.set(counter.get().saturating_add(1));
+}
+
+/// Counts a child write whose outcome is known.
+pub(crate) fn record_apply(outcome: ApplyOutcome) {
+    let label = match outcome {
+        ApplyOutcome::Created => "created",
+        ApplyOutcome::Updated => "updated",
+        ApplyOutcome::Unchanged => "unchanged",
+    };
+    crate::metrics::record_child_write(label);
+    with_current(|summary| match outcome {
+        ApplyOutcome::Created => bump(&summary.created),
+        ApplyOutcome::Updated => bump(&summary.updated),
+        ApplyOutcome::Unchanged => bump(&summary.unchanged),
+    });
+}
+
+/// Counts a server-side apply made without reading the live object.
+pub(crate) fn record_applied() {
+    crate::metrics::record_child_write("applied");
+    with_current(|summary| bump(&summary.applied));
+}
+
+/// Counts a child deletion.
+pub(crate) fn record_deleted() {
+    crate::metrics::record_child_write("deleted");
+    with_current(|summary| bump(&summary.deleted));
+}
+
+/// Keeps the last status write decision (`unchanged`, `deferred` or `written`).
+pub(crate) fn record_status_write(result: &'static str) {
+    with_current(|summary| summary.status_write.set(Some(result)));
+}
+
+impl SummaryCounts {
+    /// Emits the summary as one `info` line. Failed reconciles carry the error instead of the
+    /// requeue decision, which the error policy makes.
+    pub(crate) fn log(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        result: Result<&Action, &Error>,
+        duration: Duration,
+    ) {
+        let (outcome, requeue, error) = match result {
+            Ok(action) if *action == Action::await_change() => {
+                ("success", "awaitChange".to_string(), None)
+            }
+            Ok(action) => ("success", format!("{action:?}"), None),
+            Err(error) => ("error", "errorPolicy".to_string(), Some(error.to_string())),
+        };
+        info!(
+            tenant,
+            namespace,
+            result = outcome,
+            duration_ms = duration.as_millis() as u64,
+            created = self.created,
+            updated = self.updated,
+            unchanged = self.unchanged,
+            applied = self.applied,
+            deleted = self.deleted,
+            status_write = self.status_write.unwrap_or("none"),
+            requeue,
+            error = error.as_deref(),
+            "reconcile finished"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn scope_counts_writes_of_its_own_task_only() {
+        let (_, counts) = scope(async {
+            record_apply(ApplyOutcome::Created);
+            record_apply(ApplyOutcome::Unchanged);
+            record_apply(ApplyOutcome::Unchanged);
+            record_applied();
+            record_status_write("deferred");
+            record_status_write("written");
+        })
+        .await;
+
+        assert_eq!(
+            counts,
+            SummaryCounts {
+                created: 1,
+                updated: 0,
+                unchanged: 2,
+                applied: 1,
+                deleted: 0,
+                status_write: Some("written"),
+            }
+        );
+
+        let (_, other) = scope(async {}).await;
+        assert_eq!(other, SummaryCounts::default());
+    }
+}
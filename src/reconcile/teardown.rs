@@ -0,0 +1,269 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Operator-driven teardown of Tenant children for `spec.deletionPropagation`.
+//!
+//! Without the field, children are garbage collected through owner references once the Tenant is
+//! gone, and nothing guarantees they are gone when the Tenant disappears. With it, the
+//! `operator.rustfs.com/teardown` finalizer holds the Tenant while the operator handles the
+//! StatefulSets, Services, ServiceAccounts, Roles, RoleBindings, and ConfigMaps it owns:
+//! - Foreground: deletes them with foreground propagation and releases the Tenant only after they
+//!   and their pods are gone.
+//! - Background: deletes them and releases the Tenant right away.
+//! - Orphan: removes the Tenant owner reference so they outlive the Tenant.
+
+use crate::context::Context;
+use crate::types::v1alpha1::deletion::DeletionPropagation;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::api::rbac::v1 as rbacv1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use kube::api::{DeleteParams, Patch, PatchParams, PropagationPolicy};
+use kube::runtime::events::EventType;
+use kube::{Api, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Finalizer that holds a Tenant with `spec.deletionPropagation` until its children are handled.
+pub(crate) const TEARDOWN_FINALIZER: &str = "operator.rustfs.com/teardown";
+
+/// How often a Foreground teardown checks whether the children are gone. Services and RBAC
+/// objects are not watched, so their removal does not trigger a reconcile by itself.
+pub(super) const WAIT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Adds the teardown finalizer when `spec.deletionPropagation` is set and removes it when the
+/// field is cleared.
+pub(super) async fn ensure_finalizer(
+    ctx: &Context,
+    tenant: &Tenant,
+) -> Result<(), crate::context::Error> {
+    let wanted = tenant.spec.deletion_propagation.is_some();
+    if wanted == has_finalizer(tenant) || ctx.dry_run {
+        return Ok(());
+    }
+    patch_finalizers(ctx, tenant, wanted).await
+}
+
+/// Reconcile step for a deleting Tenant that carries the teardown finalizer: applies the
+/// configured propagation to its children and releases the Tenant once they are handled.
+/// Returns true while a Foreground teardown is still waiting on children.
+pub(super) async fn reconcile_deleting(ctx: &Context, tenant: &Tenant, namespace: &str) -> bool {
+    if !has_finalizer(tenant) || ctx.dry_run {
+        return false;
+    }
+    // A finalizer left behind after the field was cleared only needs releasing.
+    let remaining = match tenant.spec.deletion_propagation {
+        Some(policy) => match teardown(ctx, tenant, namespace, policy).await {
+            Ok(remaining) => remaining,
+            Err(error) => {
+                warn!(tenant = %tenant.name(), namespace, %error, "failed to tear down Tenant children");
+                return true;
+            }
+        },
+        None => Vec::new(),
+    };
+    if !remaining.is_empty() {
+        info!(tenant = %tenant.name(), namespace, remaining = %remaining.join(", "), "waiting for Tenant children to be deleted");
+        return true;
+    }
+
+    if let Err(error) = patch_finalizers(ctx, tenant, false).await {
+        warn!(tenant = %tenant.name(), namespace, %error, "failed to remove Tenant teardown finalizer");
+        return true;
+    }
+    let policy = tenant
+        .spec
+        .deletion_propagation
+        .map(|policy| policy.to_string())
+        .unwrap_or_else(|| "none".to_string());
+    let _ = ctx
+        .record(
+            tenant,
+            EventType::Normal,
+            "TenantTeardownComplete",
+            &format!("Tenant children handled with {policy} propagation; releasing the Tenant"),
+        )
+        .await;
+    false
+}
+
+fn has_finalizer(tenant: &Tenant) -> bool {
+    tenant.finalizers().iter().any(|f| f == TEARDOWN_FINALIZER)
+}
+
+/// The Tenant's finalizers with the teardown finalizer added or removed.
+pub(super) fn finalizers_with(tenant: &Tenant, present: bool) -> Vec<String> {
+    let mut finalizers: Vec<String> = tenant
+        .finalizers()
+        .iter()
+        .filter(|f| f.as_str() != TEARDOWN_FINALIZER)
+        .cloned()
+        .collect();
+    if present {
+        finalizers.push(TEARDOWN_FINALIZER.to_string());
+    }
+    finalizers
+}
+
+/// Writes the finalizer list, guarded by the resource version so a finalizer added concurrently
+/// by someone else is never dropped.
+async fn patch_finalizers(
+    ctx: &Context,
+    tenant: &Tenant,
+    present: bool,
+) -> Result<(), crate::context::Error> {
+    let api: Api<Tenant> = Api::namespaced(ctx.client.clone(), &tenant.namespace()?);
+    let patch = serde_json::json!({
+        "metadata": {
+            "resourceVersion": tenant.resource_version(),
+            "finalizers": finalizers_with(tenant, present),
+        }
+    });
+    api.patch(
+        &tenant.name(),
+        &PatchParams::default(),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map(|_| ())
+    .map_err(|source| crate::context::Error::Kube { source })
+}
+
+/// Applies `policy` to every child kind and returns the children still present, as `Kind/name`.
+async fn teardown(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    policy: DeletionPropagation,
+) -> Result<Vec<String>, crate::context::Error> {
+    let mut remaining = Vec::new();
+    remaining.extend(teardown_kind::<StatefulSet>(ctx, tenant, namespace, policy).await?);
+    remaining.extend(teardown_kind::<corev1::Service>(ctx, tenant, namespace, policy).await?);
+    remaining.extend(teardown_kind::<rbacv1::RoleBinding>(ctx, tenant, namespace, policy).await?);
+    remaining.extend(teardown_kind::<rbacv1::Role>(ctx, tenant, namespace, policy).await?);
+    remaining
+        .extend(teardown_kind::<corev1::ServiceAccount>(ctx, tenant, namespace, policy).await?);
+    remaining.extend(teardown_kind::<corev1::ConfigMap>(ctx, tenant, namespace, policy).await?);
+    Ok(remaining)
+}
+
+async fn teardown_kind<K>(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+    policy: DeletionPropagation,
+) -> Result<Vec<String>, crate::context::Error>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + DeserializeOwned
+        + fmt::Debug,
+    <K as Resource>::DynamicType: Default,
+{
+    let kind = K::kind(&K::DynamicType::default()).to_string();
+    let uid = tenant.uid().unwrap_or_default();
+    let api: Api<K> = Api::namespaced(ctx.client.clone(), namespace);
+    let children: Vec<K> = ctx
+        .list_labeled::<K>(namespace, &tenant.pod_selector())
+        .await?
+        .into_iter()
+        .filter(|child| owned_by(child.owner_references(), &uid))
+        .collect();
+
+    let mut remaining = Vec::new();
+    for child in children {
+        let name = child.name_any();
+        let result = match policy {
+            DeletionPropagation::Orphan => {
+                let owners: Vec<&OwnerReference> = child
+                    .owner_references()
+                    .iter()
+                    .filter(|owner| owner.uid != uid)
+                    .collect();
+                let patch = serde_json::json!({ "metadata": { "ownerReferences": owners } });
+                api.patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await
+                    .map(|_| ())
+            }
+            DeletionPropagation::Foreground | DeletionPropagation::Background => {
+                if child.meta().deletion_timestamp.is_none() {
+                    let params = DeleteParams {
+                        propagation_policy: Some(propagation(policy)),
+                        ..DeleteParams::default()
+                    };
+                    api.delete(&name, &params).await.map(|_| ())
+                } else {
+                    Ok(())
+                }
+            }
+        };
+        match result {
+            Ok(()) => {}
+            Err(kube::Error::Api(ae)) if ae.code == 404 => continue,
+            Err(source) => return Err(crate::context::Error::Kube { source }),
+        }
+        if policy == DeletionPropagation::Foreground {
+            remaining.push(format!("{kind}/{name}"));
+        }
+    }
+    Ok(remaining)
+}
+
+fn owned_by(owners: &[OwnerReference], uid: &str) -> bool {
+    !uid.is_empty() && owners.iter().any(|owner| owner.uid == uid)
+}
+
+fn propagation(policy: DeletionPropagation) -> PropagationPolicy {
+    match policy {
+        DeletionPropagation::Foreground => PropagationPolicy::Foreground,
+        DeletionPropagation::Background => PropagationPolicy::Background,
+        DeletionPropagation::Orphan => PropagationPolicy::Orphan,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_and_removes_only_the_teardown_finalizer() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.finalizers = Some(vec!["example.com/keep".to_string()]);
+        assert!(!has_finalizer(&tenant));
+        assert_eq!(
+            finalizers_with(&tenant, true),
+            vec!["example.com/keep", TEARDOWN_FINALIZER]
+        );
+
+        tenant.metadata.finalizers = Some(finalizers_with(&tenant, true));
+        assert!(has_finalizer(&tenant));
+        assert_eq!(finalizers_with(&tenant, true).len(), 2);
+        assert_eq!(finalizers_with(&tenant, false), vec!["example.com/keep"]);
+    }
+
+    #[test]
+    fn matches_children_by_owner_uid() {
+        let owner = OwnerReference {
+            uid: "tenant-uid".to_string(),
+            ..Default::default()
+        };
+        assert!(owned_by(std::slice::from_ref(&owner), "tenant-uid"));
+        assert!(!owned_by(std::slice::from_ref(&owner), "other-uid"));
+        assert!(!owned_by(&[], "tenant-uid"));
+        assert!(!owned_by(std::slice::from_ref(&owner), ""));
+    }
+}
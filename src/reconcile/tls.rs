@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{Error, patch_status_and_record, patch_status_error};
+use super::{Error, certificate, patch_status_and_record, patch_status_error};
 use crate::context::{self, Context};
 use crate::status::{StatusBuilder, StatusError};
 use crate::types::v1alpha1::status::Reason;
@@ -449,7 +449,7 @@ async fn reconcile_cert_manager_tls(
         client_ca_bytes.as_deref(),
         trust_system_ca,
     );
-    let status = cert_manager_tls_status(
+    let mut status = cert_manager_tls_status(
         config,
         secret_name,
         &secret,
@@ -458,6 +458,10 @@ async fn reconcile_cert_manager_tls(
         &hash,
         certificate_ref,
     );
+    // Expiry is informational here; `certificate::renew` acts on it.
+    if let Ok(validity) = crate::utils::tls::certificate_validity(&cert_bytes) {
+        certificate::set_validity(&mut status, &validity, chrono::Utc::now());
+    }
 
     Ok(TlsPlan::rollout(
         config.mount_path.clone(),
@@ -807,12 +811,12 @@ fn certificate_dns_names(
         names.insert(format!("{headless_service}.{namespace}.svc"));
         names.insert(format!("{headless_service}.{namespace}.svc.cluster.local"));
         for pool in &tenant.spec.pools {
-            for ordinal in 0..pool.servers.max(0) {
-                names.insert(format!(
-                    "{tenant_name}-{}-{ordinal}.{headless_service}.{namespace}.svc.cluster.local",
-                    pool.name
-                ));
-            }
+            names.extend(crate::utils::tls::headless_pod_dns_names(
+                &format!("{tenant_name}-{}", pool.name),
+                pool.servers,
+                &headless_service,
+                namespace,
+            ));
         }
     }
     names.into_iter().collect()
@@ -838,7 +842,7 @@ fn issuer_is_cluster_scoped(issuer_ref: &CertManagerIssuerRef) -> bool {
     issuer_ref.kind == CERT_MANAGER_CLUSTER_ISSUER_KIND
 }
 
-fn certificate_api_resource() -> ApiResource {
+pub(super) fn certificate_api_resource() -> ApiResource {
     ApiResource::from_gvk_with_plural(
         &GroupVersionKind::gvk(
             CERT_MANAGER_GROUP,
@@ -0,0 +1,306 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Upgrade pre-flight checks.
+//!
+//! Before a changed RustFS image is rendered into pool StatefulSets, the version running in the
+//! Tenant is compared with the target image tag. Upgrades that skip a major version are blocked
+//! unless `spec.forceUpgrade` is set; downgrades are allowed with a Warning event.
+
+use super::pool_lifecycle::rustfs_admin_client;
+use super::{Error, context_result, patch_status_error, statefulset_owned_by_tenant};
+use crate::context::Context;
+use crate::status::StatusError;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::apps::v1 as appsv1;
+use kube::runtime::events::EventType;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use tracing::{debug, info, warn};
+
+pub(super) async fn preflight_image_upgrade(
+    ctx: &Context,
+    tenant: &Tenant,
+    namespace: &str,
+) -> Result<(), Error> {
+    let target_image = tenant.rustfs_image();
+    let statefulsets = context_result(
+        ctx.list_labeled::<appsv1::StatefulSet>(namespace, &tenant.pod_selector())
+            .await,
+        ctx,
+        tenant,
+    )
+    .await?;
+    let current_images: BTreeSet<String> = statefulsets
+        .iter()
+        .filter(|ss| statefulset_owned_by_tenant(ss, tenant))
+        .filter_map(rustfs_container_image)
+        .filter(|image| *image != target_image)
+        .collect();
+    if current_images.is_empty() {
+        return Ok(());
+    }
+
+    if tenant.spec.force_upgrade.unwrap_or(false) {
+        info!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            target_image = %target_image,
+            "forceUpgrade is set; skipping upgrade pre-flight check"
+        );
+        return Ok(());
+    }
+
+    let Some(target) = image_version(&target_image) else {
+        debug!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            target_image = %target_image,
+            "target image tag is not a version; skipping upgrade pre-flight check"
+        );
+        return Ok(());
+    };
+    let current = match running_version(ctx, tenant).await {
+        Some(version) => Some(version),
+        None => current_images
+            .iter()
+            .filter_map(|image| image_version(image))
+            .min(),
+    };
+    let Some(current) = current else {
+        debug!(
+            tenant = %tenant.name(),
+            namespace = %namespace,
+            "running RustFS version is unknown; skipping upgrade pre-flight check"
+        );
+        return Ok(());
+    };
+
+    match check_upgrade(&current, &target) {
+        UpgradeCheck::Allowed => Ok(()),
+        UpgradeCheck::Downgrade => {
+            warn!(
+                tenant = %tenant.name(),
+                namespace = %namespace,
+                current = %current,
+                target = %target,
+                "rolling out an older RustFS version"
+            );
+            let _ = ctx
+                .record(
+                    tenant,
+                    EventType::Warning,
+                    "ImageDowngrade",
+                    &format!("Downgrading RustFS from {current} to {target}"),
+                )
+                .await;
+            Ok(())
+        }
+        UpgradeCheck::Blocked(message) => {
+            let status_error = StatusError::upgrade_blocked(message.clone());
+            patch_status_error(ctx, tenant, &status_error).await;
+            Err(Error::UpgradeBlocked { message })
+        }
+    }
+}
+
+pub(super) fn rustfs_container_image(ss: &appsv1::StatefulSet) -> Option<String> {
+    ss.spec
+        .as_ref()?
+        .template
+        .spec
+        .as_ref()?
+        .containers
+        .iter()
+        .find(|container| container.name == "rustfs")?
+        .image
+        .clone()
+}
+
+/// Oldest version reported by the running RustFS servers, if the admin API is reachable.
+async fn running_version(ctx: &Context, tenant: &Tenant) -> Option<RustfsVersion> {
+    let client = rustfs_admin_client(ctx, tenant).await.ok()?;
+    let info = client.server_info().await.ok()?;
+    info.servers
+        .iter()
+        .filter_map(|server| RustfsVersion::parse(&server.version))
+        .min()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum UpgradeCheck {
+    Allowed,
+    Downgrade,
+    Blocked(String),
+}
+
+fn check_upgrade(current: &RustfsVersion, target: &RustfsVersion) -> UpgradeCheck {
+    if target < current {
+        return UpgradeCheck::Downgrade;
+    }
+    if target.major > current.major + 1 {
+        return UpgradeCheck::Blocked(format!(
+            "Upgrading RustFS from {current} to {target} skips major version {}; upgrade through it first or set spec.forceUpgrade",
+            current.major + 1
+        ));
+    }
+    UpgradeCheck::Allowed
+}
+
+/// Version parsed from an image tag, ignoring any digest.
+fn image_version(image: &str) -> Option<RustfsVersion> {
+    let image = image.split('@').next().unwrap_or(image);
+    let (repository, tag) = image.rsplit_once(':')?;
+    if tag.contains('/') || repository.is_empty() {
+        return None;
+    }
+    RustfsVersion::parse(tag)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RustfsVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Option<String>,
+}
+
+impl RustfsVersion {
+    /// Parses `[v]MAJOR.MINOR[.PATCH][-PRERELEASE][+BUILD]`.
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let value = value.strip_prefix('v').unwrap_or(value);
+        let value = value.split('+').next().unwrap_or(value);
+        let (core, pre) = match value.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (value, None),
+        };
+        let mut parts = core.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next().map_or(Some(0), |part| part.parse().ok())?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+}
+
+impl Ord for RustfsVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(left), Some(right)) => compare_prerelease(left, right),
+            })
+    }
+}
+
+impl PartialOrd for RustfsVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for RustfsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{pre}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Semver pre-release ordering: numeric identifiers compare numerically and sort first.
+fn compare_prerelease(left: &str, right: &str) -> Ordering {
+    let mut left_ids = left.split('.');
+    let mut right_ids = right.split('.');
+    loop {
+        match (left_ids.next(), right_ids.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(left), Some(right)) => {
+                let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+                    (Ok(left), Ok(right)) => left.cmp(&right),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => left.cmp(right),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RustfsVersion, UpgradeCheck, check_upgrade, image_version};
+
+    fn version(value: &str) -> RustfsVersion {
+        RustfsVersion::parse(value).unwrap_or_else(|| panic!("{value} should parse"))
+    }
+
+    #[test]
+    fn image_version_reads_tag_and_ignores_registry_port_and_digest() {
+        assert_eq!(
+            image_version("registry.local:5000/rustfs/rustfs:1.0.0-alpha.50"),
+            Some(version("1.0.0-alpha.50"))
+        );
+        assert_eq!(
+            image_version("rustfs/rustfs:v1.2@sha256:abcd"),
+            Some(version("1.2.0"))
+        );
+        assert_eq!(image_version("rustfs/rustfs:latest"), None);
+        assert_eq!(image_version("registry.local:5000/rustfs/rustfs"), None);
+    }
+
+    #[test]
+    fn prerelease_versions_order_numerically() {
+        assert!(version("1.0.0-alpha.9") < version("1.0.0-alpha.50"));
+        assert!(version("1.0.0-alpha.50") < version("1.0.0-beta.1"));
+        assert!(version("1.0.0-beta.1") < version("1.0.0"));
+    }
+
+    #[test]
+    fn check_upgrade_blocks_major_skips_and_flags_downgrades() {
+        assert_eq!(
+            check_upgrade(&version("1.0.0-alpha.50"), &version("1.0.0-alpha.51")),
+            UpgradeCheck::Allowed
+        );
+        assert_eq!(
+            check_upgrade(&version("1.4.0"), &version("2.0.0")),
+            UpgradeCheck::Allowed
+        );
+        assert_eq!(
+            check_upgrade(&version("1.0.0"), &version("1.0.0-alpha.50")),
+            UpgradeCheck::Downgrade
+        );
+        assert!(matches!(
+            check_upgrade(&version("1.4.0"), &version("3.0.0")),
+            UpgradeCheck::Blocked(message) if message.contains("skips major version 2")
+        ));
+    }
+}
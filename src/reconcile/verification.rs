@@ -0,0 +1,186 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Post-readiness S3 check of a Tenant (`spec.verification`).
+//!
+//! Once every pod is Ready and provisioning has converged, the operator creates the
+//! verification bucket, writes a probe object, lists it back, and deletes both with the Tenant
+//! credentials. The result is the `Verified` condition; a passed check is not repeated until the
+//! Tenant generation changes, and a failed one is retried every [`RETRY_INTERVAL`].
+
+use super::provisioning::rustfs_admin_client;
+use crate::context::Context;
+use crate::status::StatusBuilder;
+use crate::types::v1alpha1::status::{ConditionStatus, ConditionType, Reason};
+use crate::types::v1alpha1::tenant::Tenant;
+use kube::runtime::events::EventType;
+use std::time::Duration;
+use tracing::warn;
+
+pub(super) const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+const PROBE_PREFIX: &str = "probe-";
+
+/// Whether the check has to run: it is enabled and has not passed for this generation.
+pub(super) fn verification_due(tenant: &Tenant) -> bool {
+    if !tenant
+        .spec
+        .verification
+        .as_ref()
+        .is_some_and(|verification| verification.enabled())
+    {
+        return false;
+    }
+    !tenant
+        .status
+        .as_ref()
+        .and_then(|status| {
+            status
+                .conditions
+                .iter()
+                .find(|condition| condition.type_ == ConditionType::Verified.as_str())
+        })
+        .is_some_and(|condition| {
+            condition.status == ConditionStatus::True.as_str()
+                && condition.observed_generation == tenant.metadata.generation
+        })
+}
+
+/// Runs the check when due and records the `Verified` condition. Returns true when it failed
+/// and should be retried.
+pub(super) async fn verify_if_due(
+    ctx: &Context,
+    tenant: &Tenant,
+    builder: &mut StatusBuilder,
+) -> bool {
+    if ctx.dry_run || !verification_due(tenant) {
+        return false;
+    }
+    let Some(bucket) = tenant
+        .spec
+        .verification
+        .as_ref()
+        .map(|verification| verification.bucket().to_string())
+    else {
+        return false;
+    };
+
+    match probe(ctx, tenant, &bucket).await {
+        Ok(()) => {
+            let message =
+                format!("Created, listed, and deleted a probe object in bucket '{bucket}'");
+            builder.set_verified(
+                ConditionStatus::True,
+                Reason::VerificationSucceeded,
+                message.clone(),
+            );
+            let _ = ctx
+                .record(tenant, EventType::Normal, "Verified", &message)
+                .await;
+            false
+        }
+        Err(message) => {
+            warn!(tenant = %tenant.name(), %message, "Tenant S3 verification failed");
+            builder.set_verified(
+                ConditionStatus::False,
+                Reason::VerificationFailed,
+                message.clone(),
+            );
+            let _ = ctx
+                .record(tenant, EventType::Warning, "VerificationFailed", &message)
+                .await;
+            true
+        }
+    }
+}
+
+async fn probe(ctx: &Context, tenant: &Tenant, bucket: &str) -> Result<(), String> {
+    let client = rustfs_admin_client(ctx, tenant)
+        .await
+        .map_err(|error| format!("cannot connect to the Tenant S3 endpoint: {error}"))?;
+    let key = format!(
+        "{PROBE_PREFIX}{}",
+        chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+    );
+
+    client
+        .create_bucket(bucket, None, false)
+        .await
+        .map_err(|error| format!("create bucket '{bucket}' failed: {error}"))?;
+    client
+        .put_object(bucket, &key, "rustfs-operator verification probe")
+        .await
+        .map_err(|error| format!("put object '{bucket}/{key}' failed: {error}"))?;
+    let keys = client
+        .list_object_keys(bucket, PROBE_PREFIX)
+        .await
+        .map_err(|error| format!("list bucket '{bucket}' failed: {error}"))?;
+    if !keys.contains(&key) {
+        return Err(format!(
+            "object '{bucket}/{key}' was written but is missing from the bucket listing"
+        ));
+    }
+    client
+        .delete_object(bucket, &key)
+        .await
+        .map_err(|error| format!("delete object '{bucket}/{key}' failed: {error}"))?;
+    client
+        .delete_bucket(bucket)
+        .await
+        .map_err(|error| format!("delete bucket '{bucket}' failed: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verification_due;
+    use crate::types::v1alpha1::status::{
+        ConditionInput, ConditionStatus, ConditionType, Reason, Status,
+    };
+    use crate::types::v1alpha1::verification::VerificationSpec;
+
+    fn verified_at(generation: i64) -> Status {
+        let mut status = Status::default();
+        status.upsert_condition(ConditionInput {
+            type_: ConditionType::Verified,
+            status: ConditionStatus::True,
+            reason: Reason::VerificationSucceeded,
+            message: "ok".to_string(),
+            observed_generation: Some(generation),
+            now: "2026-01-01T00:00:00Z".to_string(),
+        });
+        status
+    }
+
+    #[test]
+    fn verification_runs_once_per_generation() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.generation = Some(3);
+        assert!(!verification_due(&tenant));
+
+        tenant.spec.verification = Some(VerificationSpec::default());
+        assert!(verification_due(&tenant));
+
+        tenant.status = Some(verified_at(3));
+        assert!(!verification_due(&tenant));
+
+        tenant.metadata.generation = Some(4);
+        assert!(verification_due(&tenant));
+
+        tenant.spec.verification = Some(VerificationSpec {
+            enabled: Some(false),
+            bucket: None,
+        });
+        assert!(!verification_due(&tenant));
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-zone detection for the default pool zone spread.
+//!
+//! The StatefulSets only get a zone constraint when the nodes carry at least two distinct
+//! `topology.kubernetes.io/zone` values. Cordoned nodes still count, so draining the last node of
+//! a zone for maintenance does not drop the constraint and roll every pool.
+
+use super::{Error, context_result};
+use crate::context::Context;
+use crate::types::v1alpha1::tenant::{Tenant, ZONE_TOPOLOGY_KEY};
+use crate::types::v1alpha1::tls::TlsPlan;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::api::ListParams;
+use std::collections::BTreeSet;
+
+/// Sets `tls_plan.multi_zone` unless `spec.zoneSpread.enabled` is false.
+pub(super) async fn detect(
+    ctx: &Context,
+    tenant: &Tenant,
+    tls_plan: &mut TlsPlan,
+) -> Result<(), Error> {
+    if tenant
+        .spec
+        .zone_spread
+        .as_ref()
+        .and_then(|zone_spread| zone_spread.enabled)
+        == Some(false)
+    {
+        return Ok(());
+    }
+    let nodes: kube::Api<corev1::Node> = kube::Api::all(ctx.client.clone());
+    let nodes = context_result(
+        nodes
+            .list(&ListParams::default().labels(ZONE_TOPOLOGY_KEY))
+            .await
+            .map_err(|source| crate::context::Error::Kube { source }),
+        ctx,
+        tenant,
+    )
+    .await?;
+    tls_plan.multi_zone = zone_count(&nodes.items) > 1;
+    Ok(())
+}
+
+fn zone_count(nodes: &[corev1::Node]) -> usize {
+    nodes
+        .iter()
+        .filter_map(|node| node.metadata.labels.as_ref()?.get(ZONE_TOPOLOGY_KEY))
+        .collect::<BTreeSet<_>>()
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::zone_count;
+    use k8s_openapi::api::core::v1 as corev1;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+
+    fn node(zone: &str, unschedulable: bool) -> corev1::Node {
+        corev1::Node {
+            metadata: metav1::ObjectMeta {
+                labels: Some([(super::ZONE_TOPOLOGY_KEY.to_string(), zone.to_string())].into()),
+                ..Default::default()
+            },
+            spec: Some(corev1::NodeSpec {
+                unschedulable: Some(unschedulable),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn zone_count_counts_distinct_zones_including_cordoned_nodes() {
+        assert_eq!(zone_count(&[]), 0);
+        assert_eq!(zone_count(&[node("a", false), node("a", false)]), 1);
+        assert_eq!(zone_count(&[node("a", false), node("b", true)]), 2);
+        assert_eq!(zone_count(&[node("a", false), node("b", false)]), 2);
+    }
+}
@@ -0,0 +1,511 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `reconcile-once`: a single reconcile pass against one Tenant, for debugging.
+//!
+//! The pass runs the controller's reconcile function with a client whose writes go through a
+//! journaling layer. Each create, update, and delete is recorded with a field diff between the
+//! object before the write and the object the API server returned. With `--dry-run` every write
+//! carries `dryRun=All`, so the API server validates and renders it without persisting anything,
+//! and side effects outside the Kubernetes API are skipped.
+
+use crate::context::Context;
+use crate::types::v1alpha1::tenant::Tenant;
+use http::{Method, Request, Response};
+use http_body_util::{BodyExt, Full};
+use kube::client::{Body, ClientBuilder, DynBody};
+use kube::{Api, Client, Config};
+use serde_json::Value;
+use snafu::{ResultExt, Snafu};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context as TaskContext, Poll};
+use tower::{BoxError, Layer, Service};
+
+/// Server-populated metadata that changes on every write and would drown the diff.
+const VOLATILE_METADATA: &[&str] = &[
+    "resourceVersion",
+    "managedFields",
+    "generation",
+    "creationTimestamp",
+    "uid",
+];
+
+/// Longest rendered value in a diff line.
+const MAX_DIFF_VALUE_LENGTH: usize = 120;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("failed to load kubeconfig: {}", source))]
+    Kubeconfig {
+        source: kube::config::InferConfigError,
+    },
+
+    #[snafu(display("Kubernetes API error while {}: {}", action, source))]
+    Kube {
+        action: String,
+        #[snafu(source(from(kube::Error, Box::new)))]
+        source: Box<kube::Error>,
+    },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug, Default)]
+pub struct ReconcileOnceOptions {
+    /// Send every write with `dryRun=All` and skip side effects outside the Kubernetes API.
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Create,
+    Update,
+    Unchanged,
+    Delete,
+}
+
+impl ChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Unchanged => "unchanged",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// One write made by the reconcile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// `resource[.group][/subresource] namespace/name`, e.g. `statefulsets.apps default/t-pool-0`.
+    pub object: String,
+    /// `path: before -> after` lines. Secret values are never shown.
+    pub diff: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub changes: Vec<Change>,
+    /// The requeue action on success, or the reconcile error.
+    pub outcome: std::result::Result<String, String>,
+}
+
+/// Runs one reconcile of `namespace/name` and reports the writes it made or would make.
+pub async fn reconcile_once(
+    namespace: &str,
+    name: &str,
+    options: &ReconcileOnceOptions,
+) -> Result<Report> {
+    let config = Config::infer().await.context(KubeconfigSnafu)?;
+    let reader = Client::try_from(config.clone()).context(KubeSnafu {
+        action: "creating client",
+    })?;
+    let journal = Arc::new(Mutex::new(Vec::new()));
+    let client = ClientBuilder::try_from(config)
+        .context(KubeSnafu {
+            action: "creating client",
+        })?
+        .with_layer(&JournalLayer {
+            reader: reader.clone(),
+            journal: journal.clone(),
+            dry_run: options.dry_run,
+        })
+        .build();
+
+    let tenant = Api::<Tenant>::namespaced(reader, namespace)
+        .get(name)
+        .await
+        .context(KubeSnafu {
+            action: format!("reading Tenant {namespace}/{name}"),
+        })?;
+    let ctx = Context::new(client).with_dry_run(options.dry_run);
+    let outcome = crate::reconcile::reconcile_rustfs(Arc::new(tenant), Arc::new(ctx))
+        .await
+        .map(|action| format!("{action:?}"))
+        .map_err(|error| error.to_string());
+
+    let changes = journal
+        .lock()
+        .map(|changes| changes.clone())
+        .unwrap_or_default();
+    Ok(Report { changes, outcome })
+}
+
+struct JournalLayer {
+    reader: Client,
+    journal: Arc<Mutex<Vec<Change>>>,
+    dry_run: bool,
+}
+
+impl<S> Layer<S> for JournalLayer {
+    type Service = JournalService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        JournalService {
+            inner: Arc::new(tokio::sync::Mutex::new(inner)),
+            reader: self.reader.clone(),
+            journal: self.journal.clone(),
+            dry_run: self.dry_run,
+        }
+    }
+}
+
+struct JournalService<S> {
+    inner: Arc<tokio::sync::Mutex<S>>,
+    reader: Client,
+    journal: Arc<Mutex<Vec<Change>>>,
+    dry_run: bool,
+}
+
+impl<S> Service<Request<Body>> for JournalService<S>
+where
+    S: Service<Request<Body>, Response = Response<Box<DynBody>>, Error = BoxError> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Box<DynBody>>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut TaskContext<'_>) -> Poll<Result<(), BoxError>> {
+        // Readiness of the inner service is awaited per request, under its lock.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        let inner = self.inner.clone();
+        let method = request.method().clone();
+        let path = request.uri().path().to_string();
+        let write = is_write(&method) && !path.contains("/events");
+        if write && self.dry_run {
+            let uri = with_dry_run(request.uri());
+            if let Ok(uri) = uri {
+                *request.uri_mut() = uri;
+            }
+        }
+        let reader = self.reader.clone();
+        let journal = self.journal.clone();
+
+        Box::pin(async move {
+            let before = if write && method != Method::POST {
+                read_object(&reader, &path).await
+            } else {
+                None
+            };
+            let response = {
+                let mut inner = inner.lock().await;
+                std::future::poll_fn(|cx| inner.poll_ready(cx)).await?;
+                inner.call(request)
+            }
+            .await?;
+            if !write || !response.status().is_success() {
+                return Ok(response);
+            }
+
+            let (parts, body) = response.into_parts();
+            let bytes = body.collect().await?.to_bytes();
+            let after: Option<Value> = serde_json::from_slice(&bytes).ok();
+            let change = change_for(
+                &method,
+                parts.status.as_u16(),
+                &path,
+                before.as_ref(),
+                after.as_ref(),
+            );
+            if let Ok(mut journal) = journal.lock() {
+                journal.push(change);
+            }
+            let body: Box<DynBody> = Box::new(Full::new(bytes).map_err(|never| match never {}));
+            Ok(Response::from_parts(parts, body))
+        })
+    }
+}
+
+fn is_write(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+fn with_dry_run(uri: &http::Uri) -> std::result::Result<http::Uri, http::uri::InvalidUri> {
+    let query = match uri.query() {
+        Some(query) if !query.is_empty() => format!("{query}&dryRun=All"),
+        _ => "dryRun=All".to_string(),
+    };
+    format!("{}?{query}", uri.path()).parse()
+}
+
+async fn read_object(reader: &Client, path: &str) -> Option<Value> {
+    let request = Request::get(path).body(Vec::new()).ok()?;
+    let text = reader.request_text(request).await.ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+fn change_for(
+    method: &Method,
+    status: u16,
+    path: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+) -> Change {
+    let target = ObjectPath::parse(path);
+    let name = target.name.clone().or_else(|| {
+        after
+            .and_then(|object| object.pointer("/metadata/name"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    });
+    let object = target.describe(name.as_deref());
+
+    if *method == Method::DELETE {
+        return Change {
+            kind: ChangeKind::Delete,
+            object,
+            diff: Vec::new(),
+        };
+    }
+    if *method == Method::POST || status == 201 || before.is_none() {
+        return Change {
+            kind: ChangeKind::Create,
+            object,
+            diff: Vec::new(),
+        };
+    }
+
+    let mut diff = Vec::new();
+    if let (Some(before), Some(after)) = (before, after) {
+        diff_values(
+            "",
+            &without_volatile_metadata(before),
+            &without_volatile_metadata(after),
+            &mut diff,
+        );
+    }
+    if target.resource == "secrets" {
+        for line in &mut diff {
+            if let Some((path, _)) = line.split_once(": ") {
+                *line = format!("{path}: <redacted>");
+            }
+        }
+    }
+    Change {
+        kind: if diff.is_empty() {
+            ChangeKind::Unchanged
+        } else {
+            ChangeKind::Update
+        },
+        object,
+        diff,
+    }
+}
+
+/// The parts of an API path that identify an object.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ObjectPath {
+    group: Option<String>,
+    namespace: Option<String>,
+    resource: String,
+    name: Option<String>,
+    subresource: Option<String>,
+}
+
+impl ObjectPath {
+    /// Parses `/api/v1/...` and `/apis/{group}/{version}/...` paths.
+    fn parse(path: &str) -> Self {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let (group, rest) = match segments.as_slice() {
+            ["api", _version, rest @ ..] => (None, rest),
+            ["apis", group, _version, rest @ ..] => (Some(group.to_string()), rest),
+            _ => (None, &segments[..]),
+        };
+        let (namespace, rest) = match rest {
+            ["namespaces", namespace, rest @ ..] if !rest.is_empty() => {
+                (Some(namespace.to_string()), rest)
+            }
+            _ => (None, rest),
+        };
+        let mut rest = rest.iter().map(|segment| segment.to_string());
+        Self {
+            group,
+            namespace,
+            resource: rest.next().unwrap_or_default(),
+            name: rest.next(),
+            subresource: rest.next(),
+        }
+    }
+
+    fn describe(&self, name: Option<&str>) -> String {
+        let mut resource = self.resource.clone();
+        if let Some(group) = &self.group {
+            resource.push('.');
+            resource.push_str(group);
+        }
+        if let Some(subresource) = &self.subresource {
+            resource.push('/');
+            resource.push_str(subresource);
+        }
+        let name = name.unwrap_or("<generated>");
+        match &self.namespace {
+            Some(namespace) => format!("{resource} {namespace}/{name}"),
+            None => format!("{resource} {name}"),
+        }
+    }
+}
+
+fn without_volatile_metadata(object: &Value) -> Value {
+    let mut object = object.clone();
+    if let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) {
+        for field in VOLATILE_METADATA {
+            metadata.remove(*field);
+        }
+    }
+    object
+}
+
+/// Appends a `path: before -> after` line for every leaf that differs.
+fn diff_values(path: &str, before: &Value, after: &Value, diff: &mut Vec<String>) {
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            let keys: std::collections::BTreeSet<&String> =
+                before.keys().chain(after.keys()).collect();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_values(
+                    &child,
+                    before.get(key).unwrap_or(&Value::Null),
+                    after.get(key).unwrap_or(&Value::Null),
+                    diff,
+                );
+            }
+        }
+        (Value::Array(before_items), Value::Array(after_items))
+            if before_items.len() == after_items.len() =>
+        {
+            for (index, (before, after)) in before_items.iter().zip(after_items).enumerate() {
+                diff_values(&format!("{path}[{index}]"), before, after, diff);
+            }
+        }
+        _ if before != after => diff.push(format!(
+            "{path}: {} -> {}",
+            render_value(before),
+            render_value(after)
+        )),
+        _ => {}
+    }
+}
+
+fn render_value(value: &Value) -> String {
+    let rendered = match value {
+        Value::Null => "<none>".to_string(),
+        value => value.to_string(),
+    };
+    if rendered.chars().count() > MAX_DIFF_VALUE_LENGTH {
+        let truncated: String = rendered.chars().take(MAX_DIFF_VALUE_LENGTH).collect();
+        format!("{truncated}...")
+    } else {
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn api_paths_name_the_object() {
+        let path = ObjectPath::parse("/apis/apps/v1/namespaces/storage/statefulsets/t-pool-0");
+        assert_eq!(
+            path.describe(path.name.as_deref()),
+            "statefulsets.apps storage/t-pool-0"
+        );
+
+        let path =
+            ObjectPath::parse("/apis/rustfs.com/v1alpha1/namespaces/storage/tenants/t/status");
+        assert_eq!(
+            path.describe(path.name.as_deref()),
+            "tenants.rustfs.com/status storage/t"
+        );
+
+        let path = ObjectPath::parse("/api/v1/namespaces/storage/configmaps");
+        assert_eq!(
+            path.describe(Some("t-config")),
+            "configmaps storage/t-config"
+        );
+    }
+
+    #[test]
+    fn updates_report_changed_fields_and_redact_secrets() {
+        let before = json!({
+            "metadata": { "name": "t-pool-0", "resourceVersion": "1" },
+            "spec": { "replicas": 4, "template": { "image": "rustfs:1" } },
+        });
+        let after = json!({
+            "metadata": { "name": "t-pool-0", "resourceVersion": "2" },
+            "spec": { "replicas": 4, "template": { "image": "rustfs:2" } },
+        });
+        let change = change_for(
+            &Method::PATCH,
+            200,
+            "/apis/apps/v1/namespaces/ns/statefulsets/t-pool-0",
+            Some(&before),
+            Some(&after),
+        );
+        assert_eq!(change.kind, ChangeKind::Update);
+        assert_eq!(
+            change.diff,
+            vec![r#"spec.template.image: "rustfs:1" -> "rustfs:2""#]
+        );
+
+        let unchanged = change_for(
+            &Method::PATCH,
+            200,
+            "/apis/apps/v1/namespaces/ns/statefulsets/t-pool-0",
+            Some(&before),
+            Some(&before),
+        );
+        assert_eq!(unchanged.kind, ChangeKind::Unchanged);
+
+        let secret = change_for(
+            &Method::PATCH,
+            200,
+            "/api/v1/namespaces/ns/secrets/creds",
+            Some(&json!({ "data": { "key": "b2xk" } })),
+            Some(&json!({ "data": { "key": "bmV3" } })),
+        );
+        assert_eq!(secret.diff, vec!["data.key: <redacted>"]);
+    }
+
+    #[test]
+    fn dry_run_is_appended_to_the_query() {
+        let uri: http::Uri = "/api/v1/namespaces/ns/configmaps/c?fieldManager=rustfs-operator"
+            .parse()
+            .unwrap_or_default();
+        assert_eq!(
+            with_dry_run(&uri)
+                .map(|uri| uri.to_string())
+                .ok()
+                .as_deref(),
+            Some("/api/v1/namespaces/ns/configmaps/c?fieldManager=rustfs-operator&dryRun=All")
+        );
+    }
+}
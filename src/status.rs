@@ -15,8 +15,10 @@
 use crate::context;
 use crate::types;
 use crate::types::v1alpha1::status::{
-    ConditionInput, ConditionStatus, ConditionType, Reason, Status, certificate, is_blocked_reason,
-    pool, summarize_current_state,
+    ConditionInput, ConditionStatus, ConditionType, Reason, RequeueReason, Status, certificate,
+    is_blocked_reason, pool,
+    state::{LifecycleSignal, LifecycleStatus},
+    summarize_current_state,
 };
 use crate::types::v1alpha1::tenant::Tenant;
 use kube::runtime::events::EventType;
@@ -64,13 +66,16 @@ impl StatusError {
                 ),
             ),
             context::Error::CredentialSecretTooShort {
-                secret_name, key, ..
+                secret_name,
+                key,
+                min_length,
+                ..
             } => Self::blocked(
                 Reason::CredentialSecretTooShort,
                 ConditionType::CredentialsReady,
                 format!(
-                    "Credential Secret '{}' key '{}' must be at least 8 characters",
-                    secret_name, key
+                    "Credential Secret '{}' key '{}' must be at least {} characters",
+                    secret_name, key, min_length
                 ),
             ),
             context::Error::KmsSecretNotFound { name } => Self::blocked(
@@ -91,6 +96,33 @@ impl StatusError {
                 ConditionType::KmsReady,
                 sanitize_message(message),
             ),
+            context::Error::ExistingHeadlessServiceInvalid { name, message } => Self::blocked(
+                Reason::ExistingHeadlessServiceInvalid,
+                ConditionType::WorkloadsReady,
+                format!(
+                    "Existing headless Service '{}' is not usable: {}",
+                    name,
+                    sanitize_message(message)
+                ),
+            ),
+            context::Error::ExistingClaimUnavailable { claim, message } => Self::blocked(
+                Reason::ExistingClaimUnavailable,
+                ConditionType::PoolsReady,
+                format!(
+                    "Existing claim '{}' cannot be reused: {}",
+                    claim,
+                    sanitize_message(message)
+                ),
+            ),
+            context::Error::SnapshotSourceUnavailable { snapshot, message } => Self::blocked(
+                Reason::SnapshotSourceUnavailable,
+                ConditionType::PoolsReady,
+                format!(
+                    "VolumeSnapshot '{}' cannot be restored: {}",
+                    snapshot,
+                    sanitize_message(message)
+                ),
+            ),
             context::Error::Types { source } => Self::from_types_error(source),
             context::Error::Kube { .. } => Self::transient(
                 Reason::KubernetesApiError,
@@ -135,6 +167,31 @@ impl StatusError {
                 ConditionType::SpecValid,
                 sanitize_message(message),
             ),
+            types::error::Error::InvalidPortSpec { message, .. } => Self::blocked(
+                Reason::InvalidPortSpec,
+                ConditionType::SpecValid,
+                sanitize_message(message),
+            ),
+            types::error::Error::InvalidRbacSpec { message, .. } => Self::blocked(
+                Reason::InvalidRbacSpec,
+                ConditionType::SpecValid,
+                sanitize_message(message),
+            ),
+            types::error::Error::ProtectedEnvOverride { message, .. } => Self::blocked(
+                Reason::ProtectedEnvOverride,
+                ConditionType::SpecValid,
+                sanitize_message(message),
+            ),
+            types::error::Error::NamespaceTenantConflict { message, .. } => Self::blocked(
+                Reason::NamespaceTenantConflict,
+                ConditionType::SpecValid,
+                sanitize_message(message),
+            ),
+            types::error::Error::NamespaceNotAllowed { message, .. } => Self::blocked(
+                Reason::NamespaceNotAllowed,
+                ConditionType::SpecValid,
+                sanitize_message(message),
+            ),
             types::error::Error::ImmutableFieldModified { field, .. } => Self::blocked(
                 Reason::ImmutableFieldModified,
                 ConditionType::SpecValid,
@@ -179,6 +236,22 @@ impl StatusError {
         )
     }
 
+    pub fn waiting_for_credentials(secret_name: &str) -> Self {
+        Self::transient(
+            Reason::WaitingForCredentials,
+            ConditionType::CredentialsReady,
+            format!("Waiting for the external store to create credential Secret '{secret_name}'"),
+        )
+    }
+
+    pub fn upgrade_blocked(safe_message: String) -> Self {
+        Self::blocked(
+            Reason::UpgradeBlocked,
+            ConditionType::WorkloadsReady,
+            safe_message,
+        )
+    }
+
     pub fn tls_blocked(reason: Reason, safe_message: String) -> Self {
         Self::blocked(reason, ConditionType::TlsReady, safe_message)
     }
@@ -221,15 +294,20 @@ impl StatusError {
 pub struct StatusBuilder {
     generation: Option<i64>,
     now: String,
+    previous_conditions: Vec<crate::types::v1alpha1::status::Condition>,
     next: Status,
+    deleting: bool,
 }
 
 impl StatusBuilder {
     pub fn from_tenant(tenant: &Tenant) -> Self {
+        let next = tenant.status.clone().unwrap_or_default();
         Self {
             generation: tenant.metadata.generation,
             now: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
-            next: tenant.status.clone().unwrap_or_default(),
+            previous_conditions: next.conditions.clone(),
+            next,
+            deleting: tenant.metadata.deletion_timestamp.is_some(),
         }
     }
 
@@ -238,6 +316,24 @@ impl StatusBuilder {
         self.next.pools = pools;
     }
 
+    pub fn set_reconcile_duration(&mut self, duration: std::time::Duration) {
+        self.next.last_reconcile_duration = Some(format!("{:.3}s", duration.as_secs_f64()));
+    }
+
+    /// Records when and why the controller reconciles next. `None` means it waits for a change.
+    pub fn set_requeue(&mut self, after: Option<std::time::Duration>, reason: RequeueReason) {
+        self.next.next_reconcile_time = after.map(next_reconcile_time);
+        self.next.requeue_reason = Some(reason.as_str().to_string());
+    }
+
+    pub fn set_reconcile_error_count(&mut self, count: u64) {
+        self.next.reconcile_error_count = Some(count);
+    }
+
+    pub fn set_observed_spec_hash(&mut self, hash: String) {
+        self.next.observed_spec_hash = Some(hash);
+    }
+
     pub fn set_tls_status(&mut self, tls: certificate::TlsCertificateStatus) {
         let ready = tls.ready;
         self.next.certificates.tls = Some(tls);
@@ -251,6 +347,27 @@ impl StatusBuilder {
         }
     }
 
+    pub fn set_upgrade_status(
+        &mut self,
+        upgrade: Option<crate::types::v1alpha1::status::upgrade::UpgradeStatus>,
+    ) {
+        self.next.upgrade = upgrade;
+    }
+
+    pub fn set_mirror_statuses(
+        &mut self,
+        mirrors: Vec<crate::types::v1alpha1::status::federation::MirrorStatus>,
+    ) {
+        self.next.mirrors = mirrors;
+    }
+
+    pub fn set_rebalance_status(
+        &mut self,
+        rebalance: Option<crate::types::v1alpha1::status::rebalance::RebalanceStatus>,
+    ) {
+        self.next.rebalance = rebalance;
+    }
+
     pub fn set_provisioning_status(
         &mut self,
         provisioning: crate::types::v1alpha1::status::provisioning::ProvisioningStatus,
@@ -258,6 +375,94 @@ impl StatusBuilder {
         self.next.provisioning = provisioning;
     }
 
+    /// Sets `ImagePullFailed` from the pod inspection. A healthy result only updates an existing
+    /// condition, so Tenants that never failed a pull do not carry it.
+    pub fn set_image_pull_failed(&mut self, message: Option<String>) {
+        self.set_pod_problem(
+            ConditionType::ImagePullFailed,
+            Reason::ImagePullBackOff,
+            message,
+            Reason::ImagesPulled,
+            "All Tenant pod images were pulled",
+        );
+    }
+
+    /// Sets `PodsCrashLooping` from the pod inspection, like [`Self::set_image_pull_failed`].
+    pub fn set_pods_crash_looping(&mut self, message: Option<String>) {
+        self.set_pod_problem(
+            ConditionType::PodsCrashLooping,
+            Reason::CrashLoopBackOff,
+            message,
+            Reason::ContainersRunning,
+            "No Tenant container is crash looping",
+        );
+    }
+
+    /// Sets `DeferredDueToDisruption` while StatefulSet updates wait for a drain or other
+    /// voluntary eviction, like [`Self::set_image_pull_failed`].
+    pub fn set_deferred_due_to_disruption(&mut self, message: Option<String>) {
+        self.set_pod_problem(
+            ConditionType::DeferredDueToDisruption,
+            Reason::DisruptionInProgress,
+            message,
+            Reason::NoDisruption,
+            "No StatefulSet update is waiting for a disruption",
+        );
+    }
+
+    fn set_pod_problem(
+        &mut self,
+        condition_type: ConditionType,
+        reason: Reason,
+        message: Option<String>,
+        cleared_reason: Reason,
+        cleared_message: &str,
+    ) {
+        match message {
+            Some(message) => self.set_condition(
+                condition_type,
+                ConditionStatus::True,
+                reason,
+                sanitize_message(&message),
+            ),
+            None if self.next.condition(condition_type).is_some() => self.set_condition(
+                condition_type,
+                ConditionStatus::False,
+                cleared_reason,
+                cleared_message.to_string(),
+            ),
+            None => {}
+        }
+    }
+
+    /// Records that maintenance mode holds back every change except status.
+    pub fn set_maintenance(&mut self, message: String) {
+        self.set_condition(
+            ConditionType::Reconciling,
+            ConditionStatus::False,
+            Reason::MaintenanceMode,
+            message,
+        );
+    }
+
+    /// Leaves `observedGeneration` at the last generation the operator actually reconciled.
+    pub fn keep_observed_generation(&mut self) {
+        self.generation = self.next.observed_generation;
+    }
+
+    pub fn set_bootstrapped(&mut self, status: ConditionStatus, reason: Reason, message: String) {
+        self.set_condition(ConditionType::Bootstrapped, status, reason, message);
+    }
+
+    pub fn set_verified(&mut self, status: ConditionStatus, reason: Reason, message: String) {
+        self.set_condition(
+            ConditionType::Verified,
+            status,
+            reason,
+            sanitize_message(&message),
+        );
+    }
+
     pub fn mark_started(&mut self) {
         self.set_condition(
             ConditionType::Ready,
@@ -515,8 +720,27 @@ impl StatusBuilder {
         self.next
             .remove_condition_by_type(LEGACY_PROGRESSING_CONDITION);
         self.next.observed_generation = self.generation;
+        self.next.operator_version = Some(crate::version::SHORT_VERSION.to_string());
+        self.next.last_reconcile_time = Some(self.now.clone());
         self.next.current_state = summarize_current_state(&self.next);
+        self.next.refresh_summary();
+        let signal = if self.deleting {
+            LifecycleSignal::Deleting
+        } else {
+            LifecycleSignal::from_status(&self.next)
+        };
+        self.next.lifecycle = Some(LifecycleStatus::advance(
+            self.next.lifecycle.as_ref(),
+            signal,
+            &self.now,
+        ));
         self.next.sort_conditions();
+        crate::types::v1alpha1::status::history::record_transitions(
+            &mut self.next.condition_history,
+            &self.previous_conditions,
+            &self.next.conditions,
+            &self.now,
+        );
         self.next
     }
 
@@ -584,6 +808,15 @@ impl StatusBuilder {
     }
 }
 
+/// RFC 3339 time `after` from now.
+pub(crate) fn next_reconcile_time(after: std::time::Duration) -> String {
+    let after = chrono::TimeDelta::from_std(after).unwrap_or(chrono::TimeDelta::MAX);
+    chrono::Utc::now()
+        .checked_add_signed(after)
+        .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC)
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
 fn sanitize_message(message: &str) -> String {
     redact_sensitive_pairs(message)
 }
@@ -901,6 +1134,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn image_pull_failed_is_added_on_failure_and_cleared_after() {
+        let summary = |status: &Status| {
+            status
+                .condition(ConditionType::ImagePullFailed)
+                .map(|condition| {
+                    (
+                        condition.status.clone(),
+                        condition.reason.clone(),
+                        condition.message.clone(),
+                    )
+                })
+        };
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.set_image_pull_failed(None);
+        assert_eq!(summary(&builder.build()), None);
+
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.set_image_pull_failed(Some("pull access denied".to_string()));
+        let status = builder.build();
+        assert_eq!(
+            summary(&status),
+            Some((
+                "True".to_string(),
+                "ImagePullBackOff".to_string(),
+                "pull access denied".to_string()
+            ))
+        );
+
+        tenant.status = Some(status);
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.set_image_pull_failed(None);
+        assert_eq!(
+            summary(&builder.build()),
+            Some((
+                "False".to_string(),
+                "ImagesPulled".to_string(),
+                "All Tenant pod images were pulled".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn set_requeue_records_next_reconcile_time_and_reason() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.set_requeue(
+            Some(std::time::Duration::from_secs(30)),
+            RequeueReason::RolloutInProgress,
+        );
+        let status = builder.build();
+        let next = status
+            .next_reconcile_time
+            .as_deref()
+            .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok())
+            .expect("next reconcile time");
+        let delay = next.signed_duration_since(chrono::Utc::now()).num_seconds();
+        assert!((28..=30).contains(&delay), "unexpected delay {delay}");
+        assert_eq!(status.requeue_reason.as_deref(), Some("RolloutInProgress"));
+
+        let mut tenant = tenant;
+        tenant.status = Some(status);
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.set_requeue(None, RequeueReason::WaitingForChange);
+        let status = builder.build();
+        assert_eq!(status.next_reconcile_time, None);
+        assert_eq!(status.requeue_reason.as_deref(), Some("WaitingForChange"));
+    }
+
     #[test]
     fn mark_started_sets_reconciling_condition() {
         let tenant = crate::tests::create_test_tenant(None, None);
@@ -952,6 +1255,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn status_builder_stamps_operator_version() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.status = Some(Status {
+            operator_version: Some("v0.0.1".to_string()),
+            ..Default::default()
+        });
+
+        let mut builder = StatusBuilder::from_tenant(&tenant);
+        builder.finish_success();
+        let status = builder.build();
+
+        assert_eq!(
+            status.operator_version.as_deref(),
+            Some(crate::version::SHORT_VERSION)
+        );
+    }
+
     #[test]
     fn transient_error_does_not_keep_previous_blocked_condition_current() {
         let mut tenant = crate::tests::create_test_tenant(None, None);
@@ -21,8 +21,8 @@ use std::collections::BTreeMap;
 use super::helpers::{body_mentions_not_found, build_query_pairs, extract_canned_policy_document};
 use super::{
     ADD_CANNED_POLICY_PATH, ADD_USER_PATH, ADMIN_SIGNING_SERVICE, INFO_CANNED_POLICY_PATH,
-    JSON_CONTENT_TYPE, LIST_CANNED_POLICIES_PATH, RustfsAdminClient, RustfsClientError,
-    RustfsServerInfo, SERVER_INFO_PATH, SET_POLICY_PATH, USER_INFO_PATH,
+    JSON_CONTENT_TYPE, LIST_CANNED_POLICIES_PATH, REMOVE_USER_PATH, RustfsAdminClient,
+    RustfsClientError, RustfsServerInfo, SERVER_INFO_PATH, SET_POLICY_PATH, USER_INFO_PATH,
 };
 use reqwest::StatusCode;
 use serde_json::Value;
@@ -154,7 +154,9 @@ impl RustfsAdminClient {
 
     pub async fn user_exists(&self, access_key: &str) -> Result<bool, RustfsClientError> {
         if access_key.trim().is_empty() {
-            return Err(RustfsClientError::InvalidCredentialValue { key: "accesskey" });
+            return Err(RustfsClientError::InvalidCredentialValue {
+                key: "accesskey".to_string(),
+            });
         }
 
         let query = build_query_pairs(&[("accessKey", access_key)]);
@@ -193,10 +195,14 @@ impl RustfsAdminClient {
         secret_key: &str,
     ) -> Result<(), RustfsClientError> {
         if access_key.trim().is_empty() {
-            return Err(RustfsClientError::InvalidCredentialValue { key: "accesskey" });
+            return Err(RustfsClientError::InvalidCredentialValue {
+                key: "accesskey".to_string(),
+            });
         }
         if secret_key.is_empty() {
-            return Err(RustfsClientError::EmptyCredentialValue { key: "secretkey" });
+            return Err(RustfsClientError::EmptyCredentialValue {
+                key: "secretkey".to_string(),
+            });
         }
 
         let body = serde_json::json!({
@@ -211,13 +217,32 @@ impl RustfsAdminClient {
             .map(|_| ())
     }
 
+    /// Remove a user and its policy mapping. A user that does not exist is not an error.
+    pub async fn remove_user(&self, access_key: &str) -> Result<(), RustfsClientError> {
+        if access_key.trim().is_empty() {
+            return Err(RustfsClientError::InvalidCredentialValue {
+                key: "accesskey".to_string(),
+            });
+        }
+        if !self.user_exists(access_key).await? {
+            return Ok(());
+        }
+
+        let query = build_query_pairs(&[("accessKey", access_key)]);
+        self.send_admin_request("DELETE", REMOVE_USER_PATH, &query, "", None)
+            .await
+            .map(|_| ())
+    }
+
     pub async fn set_user_policy(
         &self,
         access_key: &str,
         policies: &[String],
     ) -> Result<(), RustfsClientError> {
         if access_key.trim().is_empty() {
-            return Err(RustfsClientError::InvalidCredentialValue { key: "accesskey" });
+            return Err(RustfsClientError::InvalidCredentialValue {
+                key: "accesskey".to_string(),
+            });
         }
         if policies.is_empty() || policies.iter().any(|policy| policy.trim().is_empty()) {
             return Err(RustfsClientError::InvalidPolicyName);
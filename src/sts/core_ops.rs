@@ -49,6 +49,7 @@ impl RustfsAdminClient {
             "GET" => self.http_client.get(url),
             "POST" => self.http_client.post(url),
             "PUT" => self.http_client.put(url),
+            "DELETE" => self.http_client.delete(url),
             _ => return Err(RustfsClientError::RequestBuildFailed),
         }
         .header("x-amz-date", &signed.amz_date)
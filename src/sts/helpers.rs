@@ -24,17 +24,19 @@ use url::form_urlencoded;
 
 use crate::Tenant;
 use crate::sts::types::StsAssumeRoleCredentials;
+use crate::types::v1alpha1::credentials::CredsSecretReference;
 
-use super::{RustfsClientError, RustfsCredentials};
+use super::{BucketLifecycleRule, RustfsClientError, RustfsCredentials};
 
 pub(super) fn extract_credentials(
     data: Option<&BTreeMap<String, ByteString>>,
+    reference: &CredsSecretReference,
 ) -> Result<RustfsCredentials, RustfsClientError> {
     let secret_data = data.ok_or(RustfsClientError::TenantSecretLookupFailed)?;
 
     Ok(RustfsCredentials {
-        access_key: get_secret_value(secret_data, "accesskey")?,
-        secret_key: get_secret_value(secret_data, "secretkey")?,
+        access_key: get_secret_value(secret_data, reference.access_key())?,
+        secret_key: get_secret_value(secret_data, reference.secret_key())?,
     })
 }
 
@@ -53,17 +55,24 @@ pub(super) fn tenant_tls_client_certificate_required(tenant: &Tenant) -> bool {
 
 pub(super) fn get_secret_value(
     data: &BTreeMap<String, ByteString>,
-    field: &'static str,
+    field: &str,
 ) -> Result<String, RustfsClientError> {
     let raw = data
         .get(field)
-        .ok_or(RustfsClientError::MissingCredentialKey { key: field })?;
+        .ok_or_else(|| RustfsClientError::MissingCredentialKey {
+            key: field.to_string(),
+        })?;
 
-    let value = String::from_utf8(raw.0.clone())
-        .map_err(|_| RustfsClientError::InvalidCredentialValue { key: field })?;
+    let value = String::from_utf8(raw.0.clone()).map_err(|_| {
+        RustfsClientError::InvalidCredentialValue {
+            key: field.to_string(),
+        }
+    })?;
 
     if value.is_empty() {
-        return Err(RustfsClientError::EmptyCredentialValue { key: field });
+        return Err(RustfsClientError::EmptyCredentialValue {
+            key: field.to_string(),
+        });
     }
 
     Ok(value)
@@ -108,6 +117,76 @@ pub(super) fn escape_xml(value: &str) -> String {
         .replace('\'', "&apos;")
 }
 
+pub(super) fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// PutBucketLifecycleConfiguration body for `rules`.
+pub(super) fn lifecycle_configuration_body(rules: &[BucketLifecycleRule]) -> String {
+    let mut body =
+        String::from("<LifecycleConfiguration xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\">");
+    for rule in rules {
+        body.push_str("<Rule>");
+        if let Some(days) = rule.expiration_days {
+            body.push_str(&format!("<Expiration><Days>{days}</Days></Expiration>"));
+        }
+        body.push_str(&format!(
+            "<ID>{}</ID><Filter><Prefix>{}</Prefix></Filter><Status>{}</Status>",
+            escape_xml(&rule.id),
+            escape_xml(&rule.prefix),
+            if rule.enabled { "Enabled" } else { "Disabled" }
+        ));
+        if let Some((days, storage_class)) = &rule.transition {
+            body.push_str(&format!(
+                "<Transition><Days>{days}</Days><StorageClass>{}</StorageClass></Transition>",
+                escape_xml(storage_class)
+            ));
+        }
+        if let Some(days) = rule.noncurrent_expiration_days {
+            body.push_str(&format!(
+                "<NoncurrentVersionExpiration><NoncurrentDays>{days}</NoncurrentDays></NoncurrentVersionExpiration>"
+            ));
+        }
+        body.push_str("</Rule>");
+    }
+    body.push_str("</LifecycleConfiguration>");
+    body
+}
+
+/// Rules of a GetBucketLifecycleConfiguration response. Only the fields the operator manages
+/// are read, so rules using other actions compare as different and get rewritten.
+pub(super) fn parse_lifecycle_rules(body: &str) -> Vec<BucketLifecycleRule> {
+    body.split("<Rule>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</Rule>").map(|(rule, _)| rule))
+        .map(|rule| {
+            let days = |section: &str, tag: &str| {
+                extract_xml_tag(rule, section)
+                    .and_then(|section| extract_xml_tag(&section, tag))
+                    .and_then(|days| days.parse::<u32>().ok())
+            };
+            BucketLifecycleRule {
+                id: unescape_xml(&extract_xml_tag(rule, "ID").unwrap_or_default()),
+                prefix: unescape_xml(&extract_xml_tag(rule, "Prefix").unwrap_or_default()),
+                enabled: extract_xml_tag(rule, "Status").as_deref() == Some("Enabled"),
+                expiration_days: days("Expiration", "Days"),
+                noncurrent_expiration_days: days("NoncurrentVersionExpiration", "NoncurrentDays"),
+                transition: days("Transition", "Days").map(|days| {
+                    let storage_class = extract_xml_tag(rule, "Transition")
+                        .and_then(|section| extract_xml_tag(&section, "StorageClass"))
+                        .unwrap_or_default();
+                    (days, unescape_xml(&storage_class))
+                }),
+            }
+        })
+        .collect()
+}
+
 pub(super) fn body_mentions_not_found(body: &str) -> bool {
     let body = body.to_ascii_lowercase();
     body.contains("nosuchuser")
@@ -14,11 +14,13 @@
 
 //! Pool boundary:
 //!   - list/status and decommission lifecycle operations for tenant pools.
+//!   - starting and polling a rebalance across pools.
 
 use super::helpers::build_query_pairs;
 use super::{
     POOLS_CANCEL_PATH, POOLS_DECOMMISSION_PATH, POOLS_LIST_PATH, POOLS_STATUS_PATH,
-    RustfsAdminClient, RustfsClientError, RustfsPoolListItem, RustfsPoolStatus,
+    REBALANCE_START_PATH, REBALANCE_STATUS_PATH, RustfsAdminClient, RustfsClientError,
+    RustfsPoolListItem, RustfsPoolStatus, RustfsRebalanceStatus,
 };
 
 impl RustfsAdminClient {
@@ -65,4 +67,19 @@ impl RustfsAdminClient {
             .await?;
         Ok(())
     }
+
+    pub async fn start_rebalance(&self) -> Result<(), RustfsClientError> {
+        self.send_admin_request("POST", REBALANCE_START_PATH, "", "", None)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn rebalance_status(&self) -> Result<RustfsRebalanceStatus, RustfsClientError> {
+        let body = self
+            .send_admin_request("GET", REBALANCE_STATUS_PATH, "", "", None)
+            .await?;
+
+        serde_json::from_str::<RustfsRebalanceStatus>(&body)
+            .map_err(|_| RustfsClientError::ParseResponseFailed)
+    }
 }
@@ -16,9 +16,10 @@ use std::{collections::BTreeMap, time::Duration};
 
 use k8s_openapi::api::core::v1 as corev1;
 use kube::{Api, Client};
-use reqwest::{Certificate, Client as HttpClient, StatusCode};
+use reqwest::{Certificate, Client as HttpClient, ClientBuilder as HttpClientBuilder, StatusCode};
 
 use crate::Tenant;
+use crate::proxy::ProxyConfig;
 
 /// admin_ops: tenant admin operations (user/policy APIs).
 #[path = "admin_ops.rs"]
@@ -43,6 +44,7 @@ const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
 const JSON_CONTENT_TYPE: &str = "application/json";
 const ASSUME_ROLE_PATH: &str = "/";
 const ADD_USER_PATH: &str = "/rustfs/admin/v3/add-user";
+const REMOVE_USER_PATH: &str = "/rustfs/admin/v3/remove-user";
 const USER_INFO_PATH: &str = "/rustfs/admin/v3/user-info";
 const SET_POLICY_PATH: &str = "/rustfs/admin/v3/set-policy";
 const LIST_CANNED_POLICIES_PATH: &str = "/rustfs/admin/v3/list-canned-policies";
@@ -53,6 +55,8 @@ const POOLS_LIST_PATH: &str = "/rustfs/admin/v3/pools/list";
 const POOLS_STATUS_PATH: &str = "/rustfs/admin/v3/pools/status";
 const POOLS_DECOMMISSION_PATH: &str = "/rustfs/admin/v3/pools/decommission";
 const POOLS_CANCEL_PATH: &str = "/rustfs/admin/v3/pools/cancel";
+const REBALANCE_START_PATH: &str = "/rustfs/admin/v3/rebalance/start";
+const REBALANCE_STATUS_PATH: &str = "/rustfs/admin/v3/rebalance/status";
 const ADMIN_SIGNING_SERVICE: &str = "s3";
 const STS_SIGNING_SERVICE: &str = "sts";
 const ADMIN_HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
@@ -95,12 +99,47 @@ pub struct RustfsPoolStatus {
     pub decommission: Option<RustfsPoolDecommissionInfo>,
 }
 
+/// Response of `rebalance/status`.
+#[derive(Debug, Clone, serde::Deserialize, PartialEq)]
+pub struct RustfsRebalanceStatus {
+    #[serde(rename = "ID", default)]
+    pub id: String,
+    #[serde(default)]
+    pub pools: Vec<RustfsRebalancePoolStatus>,
+    #[serde(rename = "stoppedAt", default)]
+    pub stopped_at: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, PartialEq)]
+pub struct RustfsRebalancePoolStatus {
+    pub id: usize,
+    /// `Started`, `Completed`, `Stopped`, or `Failed`.
+    #[serde(default)]
+    pub status: String,
+    /// Fraction of the pool's capacity in use.
+    #[serde(default)]
+    pub used: f64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CreateBucketResult {
     Created,
     AlreadyExists,
 }
 
+/// One rule of a bucket lifecycle configuration, limited to the actions the operator manages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BucketLifecycleRule {
+    pub id: String,
+    /// Object key prefix; empty applies the rule to the whole bucket.
+    pub prefix: String,
+    pub enabled: bool,
+    pub expiration_days: Option<u32>,
+    pub noncurrent_expiration_days: Option<u32>,
+    /// Days and storage class of a transition.
+    pub transition: Option<(u32, String)>,
+}
+
 #[derive(Debug, Clone, Default, serde::Deserialize, PartialEq)]
 pub struct RustfsPoolDecommissionInfo {
     #[serde(rename = "startTime")]
@@ -132,6 +171,18 @@ pub struct RustfsServerInfo {
     pub backend: Option<RustfsErasureBackend>,
     #[serde(default)]
     pub pools: Option<BTreeMap<String, BTreeMap<String, RustfsErasureSetInfo>>>,
+    #[serde(default)]
+    pub servers: Vec<RustfsServerProperties>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, PartialEq)]
+pub struct RustfsServerProperties {
+    #[serde(default)]
+    pub endpoint: String,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub version: String,
 }
 
 #[derive(Debug, Clone, Default, serde::Deserialize, PartialEq)]
@@ -173,9 +224,9 @@ pub struct RustfsErasureSetInfo {
 pub enum RustfsClientError {
     MissingTenantNamespace,
     MissingCredsSecret,
-    MissingCredentialKey { key: &'static str },
-    EmptyCredentialValue { key: &'static str },
-    InvalidCredentialValue { key: &'static str },
+    MissingCredentialKey { key: String },
+    EmptyCredentialValue { key: String },
+    InvalidCredentialValue { key: String },
     TenantSecretLookupFailed,
     InvalidPolicyName,
     InvalidPolicyDocument,
@@ -248,11 +299,22 @@ pub struct RustfsAdminClient {
     http_client: HttpClient,
 }
 
-fn default_http_client() -> HttpClient {
-    HttpClient::builder()
+fn http_client_builder() -> Result<HttpClientBuilder, RustfsClientError> {
+    let builder = HttpClient::builder()
         .connect_timeout(ADMIN_HTTP_CONNECT_TIMEOUT)
-        .timeout(ADMIN_HTTP_REQUEST_TIMEOUT)
-        .build()
+        .timeout(ADMIN_HTTP_REQUEST_TIMEOUT);
+    ProxyConfig::from_env()
+        .apply_to_http_client(builder)
+        .map_err(|_| RustfsClientError::TlsClientBuildFailed)
+}
+
+fn default_http_client() -> HttpClient {
+    http_client_builder()
+        .and_then(|builder| {
+            builder
+                .build()
+                .map_err(|_| RustfsClientError::TlsClientBuildFailed)
+        })
         .unwrap_or_else(|_| HttpClient::new())
 }
 
@@ -281,9 +343,7 @@ impl RustfsAdminClient {
     ) -> Result<Self, RustfsClientError> {
         let certs = Certificate::from_pem_bundle(ca_pem)
             .map_err(|_| RustfsClientError::InvalidTenantTlsCa)?;
-        let mut builder = HttpClient::builder()
-            .connect_timeout(ADMIN_HTTP_CONNECT_TIMEOUT)
-            .timeout(ADMIN_HTTP_REQUEST_TIMEOUT);
+        let mut builder = http_client_builder()?;
         for cert in certs {
             builder = builder.add_root_certificate(cert);
         }
@@ -328,7 +388,7 @@ impl RustfsAdminClient {
             .unwrap_or_else(|| format!("{}-io", tenant.name()));
 
         Ok(Self::new_with_base_url(
-            format!("http://{service_name}.{namespace}.svc:9000"),
+            format!("http://{service_name}.{namespace}.svc:{}", tenant.s3_port()),
             credentials.access_key,
             credentials.secret_key,
         ))
@@ -354,7 +414,10 @@ impl RustfsAdminClient {
             .metadata
             .name
             .unwrap_or_else(|| format!("{}-io", tenant.name()));
-        let base_url = format!("https://{service_name}.{namespace}.svc:9000");
+        let base_url = format!(
+            "https://{service_name}.{namespace}.svc:{}",
+            tenant.s3_port()
+        );
 
         match Self::load_tenant_tls_ca(kube_client, tenant).await? {
             Some(ca_pem) => Self::new_with_base_url_and_ca_pem(
@@ -434,7 +497,7 @@ impl RustfsAdminClient {
             .await
             .map_err(|_| RustfsClientError::TenantSecretLookupFailed)?;
 
-        helpers::extract_credentials(secret.data.as_ref())
+        helpers::extract_credentials(secret.data.as_ref(), reference)
     }
 }
 
@@ -13,15 +13,23 @@
 // limitations under the License.
 
 //! S3 boundary:
-//!   - bucket lifecycle methods (create/lookup features)
+//!   - bucket lifecycle methods (create/lookup/delete features)
+//!   - object put/get/list/delete used by the Tenant verification and synthetic probes
+//!   - bucket listing and lifecycle configuration used by `spec.lifecyclePolicies`
 //!   - request semantics for S3-style object storage operations.
 
+use base64::Engine;
+use md5::{Digest, Md5};
 use reqwest::StatusCode;
 
 use super::helpers::{
     body_mentions_not_found, bucket_already_exists, build_query_pairs, create_bucket_body,
+    lifecycle_configuration_body, parse_lifecycle_rules,
+};
+use super::{
+    ADMIN_SIGNING_SERVICE, BucketLifecycleRule, CreateBucketResult, RustfsAdminClient,
+    RustfsClientError,
 };
-use super::{ADMIN_SIGNING_SERVICE, CreateBucketResult, RustfsAdminClient, RustfsClientError};
 
 impl RustfsAdminClient {
     // S3 duties: bucket operations exposed by the RustFS/S3-compatible endpoint.
@@ -132,4 +140,206 @@ impl RustfsAdminClient {
             .map_err(|_| RustfsClientError::RequestFailed)?;
         Ok(body.contains("<ObjectLockEnabled>Enabled</ObjectLockEnabled>"))
     }
+
+    pub async fn delete_bucket(&self, bucket: &str) -> Result<(), RustfsClientError> {
+        let response = self
+            .send_s3_request(reqwest::Method::DELETE, &format!("/{bucket}"), "", "")
+            .await?;
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        Err(RustfsClientError::UnexpectedStatus(response.status()))
+    }
+
+    pub async fn put_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        body: &str,
+    ) -> Result<(), RustfsClientError> {
+        let response = self
+            .send_s3_request(reqwest::Method::PUT, &format!("/{bucket}/{key}"), "", body)
+            .await?;
+        if response.status().is_success() {
+            return Ok(());
+        }
+        Err(RustfsClientError::UnexpectedStatus(response.status()))
+    }
+
+    pub async fn get_object(&self, bucket: &str, key: &str) -> Result<String, RustfsClientError> {
+        let response = self
+            .send_s3_request(reqwest::Method::GET, &format!("/{bucket}/{key}"), "", "")
+            .await?;
+        if !response.status().is_success() {
+            return Err(RustfsClientError::UnexpectedStatus(response.status()));
+        }
+        response
+            .text()
+            .await
+            .map_err(|_| RustfsClientError::RequestFailed)
+    }
+
+    /// Keys under `prefix`, from the first page of a ListObjectsV2 response.
+    pub async fn list_object_keys(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<Vec<String>, RustfsClientError> {
+        let query = build_query_pairs(&[("list-type", "2"), ("prefix", prefix)]);
+        let response = self
+            .send_s3_request(reqwest::Method::GET, &format!("/{bucket}"), &query, "")
+            .await?;
+        if !response.status().is_success() {
+            return Err(RustfsClientError::UnexpectedStatus(response.status()));
+        }
+        let body = response
+            .text()
+            .await
+            .map_err(|_| RustfsClientError::RequestFailed)?;
+        Ok(xml_element_values(&body, "Key"))
+    }
+
+    pub async fn delete_object(&self, bucket: &str, key: &str) -> Result<(), RustfsClientError> {
+        let response = self
+            .send_s3_request(reqwest::Method::DELETE, &format!("/{bucket}/{key}"), "", "")
+            .await?;
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        Err(RustfsClientError::UnexpectedStatus(response.status()))
+    }
+
+    /// Names of every bucket in the tenant (ListBuckets).
+    pub async fn list_buckets(&self) -> Result<Vec<String>, RustfsClientError> {
+        let response = self
+            .send_s3_request_with_headers(reqwest::Method::GET, "/", "", "", &[])
+            .await?;
+        if !response.status().is_success() {
+            return Err(RustfsClientError::UnexpectedStatus(response.status()));
+        }
+        let body = response
+            .text()
+            .await
+            .map_err(|_| RustfsClientError::RequestFailed)?;
+        Ok(xml_element_values(&body, "Name"))
+    }
+
+    /// Lifecycle rules of `bucket`; empty when it has no lifecycle configuration.
+    pub async fn get_bucket_lifecycle(
+        &self,
+        bucket: &str,
+    ) -> Result<Vec<BucketLifecycleRule>, RustfsClientError> {
+        let response = self
+            .send_s3_request(
+                reqwest::Method::GET,
+                &format!("/{bucket}"),
+                "lifecycle=",
+                "",
+            )
+            .await?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|_| RustfsClientError::RequestFailed)?;
+        if status.is_success() {
+            return Ok(parse_lifecycle_rules(&body));
+        }
+        if status == StatusCode::NOT_FOUND && body.contains("NoSuchLifecycleConfiguration") {
+            return Ok(Vec::new());
+        }
+        Err(RustfsClientError::UnexpectedStatus(status))
+    }
+
+    /// Replaces the lifecycle configuration of `bucket` with `rules`.
+    pub async fn put_bucket_lifecycle(
+        &self,
+        bucket: &str,
+        rules: &[BucketLifecycleRule],
+    ) -> Result<(), RustfsClientError> {
+        let body = lifecycle_configuration_body(rules);
+        let content_md5 =
+            base64::engine::general_purpose::STANDARD.encode(Md5::digest(body.as_bytes()));
+        let response = self
+            .send_s3_request_with_headers(
+                reqwest::Method::PUT,
+                &format!("/{bucket}"),
+                "lifecycle=",
+                &body,
+                &[
+                    ("content-md5", content_md5.as_str()),
+                    ("content-type", "application/xml"),
+                ],
+            )
+            .await?;
+        if response.status().is_success() {
+            return Ok(());
+        }
+        Err(RustfsClientError::UnexpectedStatus(response.status()))
+    }
+
+    async fn send_s3_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &str,
+        body: &str,
+    ) -> Result<reqwest::Response, RustfsClientError> {
+        if path.trim_matches('/').is_empty() {
+            return Err(RustfsClientError::RequestBuildFailed);
+        }
+        self.send_s3_request_with_headers(method, path, query, body, &[])
+            .await
+    }
+
+    async fn send_s3_request_with_headers(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &str,
+        body: &str,
+        extra_headers: &[(&str, &str)],
+    ) -> Result<reqwest::Response, RustfsClientError> {
+        let signed = self.sign_request_with_extra_headers(
+            method.as_str(),
+            path,
+            query,
+            body,
+            ADMIN_SIGNING_SERVICE,
+            extra_headers,
+        )?;
+        let host = self.host()?;
+        let mut url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        if !query.is_empty() {
+            url = format!("{url}?{query}");
+        }
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("x-amz-date", &signed.amz_date)
+            .header("x-amz-content-sha256", &signed.payload_hash)
+            .header("authorization", &signed.authorization)
+            .header("host", host);
+        for (name, value) in extra_headers {
+            request = request.header(*name, *value);
+        }
+        if !body.is_empty() {
+            request = request.body(body.to_string());
+        }
+        request
+            .send()
+            .await
+            .map_err(|_| RustfsClientError::RequestFailed)
+    }
+}
+
+/// Text of every `<name>` element; enough for the flat S3 list responses parsed here.
+fn xml_element_values(body: &str, name: &str) -> Vec<String> {
+    let open = format!("<{name}>");
+    let close = format!("</{name}>");
+    body.split(&open)
+        .skip(1)
+        .filter_map(|rest| rest.split_once(&close).map(|(value, _)| value.to_string()))
+        .collect()
 }
@@ -19,7 +19,7 @@ use axum::{
     body::Body,
     extract::State,
     http::{Request, StatusCode},
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
 };
 use k8s_openapi::{ByteString, api::core::v1 as corev1};
 use serde_json::Value;
@@ -27,12 +27,15 @@ use std::{collections::BTreeMap, sync::Arc};
 use tokio::sync::Mutex;
 
 use super::{
-    ADD_USER_PATH, CreateBucketResult, LIST_CANNED_POLICIES_PATH, POOLS_DECOMMISSION_PATH,
-    POOLS_LIST_PATH, POOLS_STATUS_PATH, RustfsAdminClient, RustfsClientError, SERVER_INFO_PATH,
-    SET_POLICY_PATH,
+    ADD_USER_PATH, BucketLifecycleRule, CreateBucketResult, LIST_CANNED_POLICIES_PATH,
+    POOLS_DECOMMISSION_PATH, POOLS_LIST_PATH, POOLS_STATUS_PATH, REBALANCE_STATUS_PATH,
+    REMOVE_USER_PATH, RustfsAdminClient, RustfsClientError, SERVER_INFO_PATH, SET_POLICY_PATH,
+    USER_INFO_PATH,
     helpers::{extract_canned_policy_document, extract_credentials, parse_assume_role_response},
 };
 
+use crate::types::v1alpha1::credentials::{CredentialKeys, CredsSecretReference};
+
 fn secret_with_fields(fields: Vec<(&str, &[u8])>) -> corev1::Secret {
     let mut data = BTreeMap::new();
     for (key, value) in fields {
@@ -49,10 +52,11 @@ fn secret_with_fields(fields: Vec<(&str, &[u8])>) -> corev1::Secret {
 fn extract_credentials_reports_missing_access_key() {
     let secret = secret_with_fields(vec![("secretkey", b"sekret")]);
 
-    let err = extract_credentials(secret.data.as_ref()).expect_err("expected missing access key");
+    let err = extract_credentials(secret.data.as_ref(), &CredsSecretReference::default())
+        .expect_err("expected missing access key");
     assert!(matches!(
         err,
-        RustfsClientError::MissingCredentialKey { key: "accesskey" }
+        RustfsClientError::MissingCredentialKey { ref key } if key == "accesskey"
     ));
 }
 
@@ -60,10 +64,11 @@ fn extract_credentials_reports_missing_access_key() {
 fn extract_credentials_reports_non_utf8_access_key() {
     let secret = secret_with_fields(vec![("accesskey", &[0xff, 0xfe]), ("secretkey", b"sekret")]);
 
-    let err = extract_credentials(secret.data.as_ref()).expect_err("expected invalid utf8");
+    let err = extract_credentials(secret.data.as_ref(), &CredsSecretReference::default())
+        .expect_err("expected invalid utf8");
     assert!(matches!(
         err,
-        RustfsClientError::InvalidCredentialValue { key: "accesskey" }
+        RustfsClientError::InvalidCredentialValue { ref key } if key == "accesskey"
     ));
 }
 
@@ -71,10 +76,11 @@ fn extract_credentials_reports_non_utf8_access_key() {
 fn extract_credentials_reports_missing_secret_key() {
     let secret = secret_with_fields(vec![("accesskey", b"access")]);
 
-    let err = extract_credentials(secret.data.as_ref()).expect_err("expected missing secret key");
+    let err = extract_credentials(secret.data.as_ref(), &CredsSecretReference::default())
+        .expect_err("expected missing secret key");
     assert!(matches!(
         err,
-        RustfsClientError::MissingCredentialKey { key: "secretkey" }
+        RustfsClientError::MissingCredentialKey { ref key } if key == "secretkey"
     ));
 }
 
@@ -82,10 +88,11 @@ fn extract_credentials_reports_missing_secret_key() {
 fn extract_credentials_reports_non_utf8_secret_key() {
     let secret = secret_with_fields(vec![("accesskey", b"access"), ("secretkey", &[0xff, 0xfe])]);
 
-    let err = extract_credentials(secret.data.as_ref()).expect_err("expected invalid utf8");
+    let err = extract_credentials(secret.data.as_ref(), &CredsSecretReference::default())
+        .expect_err("expected invalid utf8");
     assert!(matches!(
         err,
-        RustfsClientError::InvalidCredentialValue { key: "secretkey" }
+        RustfsClientError::InvalidCredentialValue { ref key } if key == "secretkey"
     ));
 }
 
@@ -93,13 +100,32 @@ fn extract_credentials_reports_non_utf8_secret_key() {
 fn extract_credentials_reports_empty_secret_key() {
     let secret = secret_with_fields(vec![("accesskey", b"abc"), ("secretkey", b"")]);
 
-    let err = extract_credentials(secret.data.as_ref()).expect_err("expected empty secret key");
+    let err = extract_credentials(secret.data.as_ref(), &CredsSecretReference::default())
+        .expect_err("expected empty secret key");
     assert!(matches!(
         err,
-        RustfsClientError::EmptyCredentialValue { key: "secretkey" }
+        RustfsClientError::EmptyCredentialValue { ref key } if key == "secretkey"
     ));
 }
 
+#[test]
+fn extract_credentials_reads_configured_key_names() {
+    let secret = secret_with_fields(vec![
+        ("AWS_ACCESS_KEY_ID", b"access"),
+        ("AWS_SECRET_ACCESS_KEY", b"sekret"),
+    ]);
+    let mut reference = CredsSecretReference::new("aws-creds");
+    reference.keys = Some(CredentialKeys {
+        access_key: Some("AWS_ACCESS_KEY_ID".to_string()),
+        secret_key: Some("AWS_SECRET_ACCESS_KEY".to_string()),
+    });
+
+    let credentials =
+        extract_credentials(secret.data.as_ref(), &reference).expect("configured keys");
+    assert_eq!(credentials.access_key, "access");
+    assert_eq!(credentials.secret_key, "sekret");
+}
+
 #[test]
 fn parse_assume_role_xml_success_and_failure() {
     let body_ok = "<AssumeRoleResponse xmlns=\"https://sts.amazonaws.com/doc/2011-06-15/\"><AssumeRoleResult><Credentials><AccessKeyId>AKI</AccessKeyId><SecretAccessKey>SEC</SecretAccessKey><SessionToken>TOKEN</SessionToken><Expiration>2026-01-01T00:00:00Z</Expiration></Credentials></AssumeRoleResult></AssumeRoleResponse>";
@@ -121,6 +147,7 @@ struct Capture {
     body: Arc<Mutex<String>>,
     authorization: Arc<Mutex<String>>,
     object_lock_header: Arc<Mutex<String>>,
+    content_md5: Arc<Mutex<String>>,
 }
 
 #[tokio::test]
@@ -496,6 +523,37 @@ async fn list_pools_parses_current_rustfs_pool_shape() {
     server.abort();
 }
 
+#[tokio::test]
+async fn rebalance_status_parses_pool_progress() {
+    let router = Router::new().route(
+        REBALANCE_STATUS_PATH,
+        get(|| async {
+            (
+                StatusCode::OK,
+                r#"{"ID":"a1b2","pools":[{"id":0,"status":"Started","used":0.62,"progress":{"objects":12,"bytes":4096}},{"id":1,"status":"Completed","used":0.21}]}"#,
+            )
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    let client = RustfsAdminClient::new_with_base_url(format!("http://{addr}"), "access", "secret");
+
+    let status = client.rebalance_status().await.unwrap();
+
+    assert_eq!(status.id, "a1b2");
+    assert_eq!(status.pools.len(), 2);
+    assert_eq!(status.pools[0].status, "Started");
+    assert_eq!(status.pools[1].used, 0.21);
+    assert_eq!(status.stopped_at, None);
+
+    server.abort();
+}
+
 #[tokio::test]
 async fn pool_decommission_start_uses_by_id_query_and_admin_signing() {
     let capture = Capture::default();
@@ -628,6 +686,51 @@ async fn add_user_uses_expected_path_query_and_body() {
     server.abort();
 }
 
+#[tokio::test]
+async fn remove_user_deletes_existing_users_only() {
+    let capture = Capture::default();
+    let route_capture = capture.clone();
+
+    let router = Router::new()
+        .route(
+            USER_INFO_PATH,
+            get(|req: Request<Body>| async move {
+                if req.uri().query() == Some("accessKey=app-user") {
+                    StatusCode::OK
+                } else {
+                    StatusCode::NOT_FOUND
+                }
+            }),
+        )
+        .route(
+            REMOVE_USER_PATH,
+            delete(
+                move |State(c): State<Capture>, req: Request<Body>| async move {
+                    *c.path.lock().await = req.uri().path().to_string();
+                    *c.query.lock().await = req.uri().query().unwrap_or("").to_string();
+                    StatusCode::OK
+                },
+            ),
+        )
+        .with_state(route_capture.clone());
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    let client = RustfsAdminClient::new_with_base_url(format!("http://{addr}"), "access", "secret");
+    client.remove_user("missing-user").await.unwrap();
+    assert_eq!(&*capture.path.lock().await, "");
+
+    client.remove_user("app-user").await.unwrap();
+    assert_eq!(&*capture.path.lock().await, REMOVE_USER_PATH);
+    assert_eq!(&*capture.query.lock().await, "accessKey=app-user");
+
+    server.abort();
+}
+
 #[tokio::test]
 async fn set_user_policy_uses_single_authoritative_mapping_call() {
     let capture = Capture::default();
@@ -761,6 +864,143 @@ async fn create_bucket_sends_object_lock_header_and_region_body() {
     server.abort();
 }
 
+#[tokio::test]
+async fn object_put_get_list_delete_round_trip() {
+    let capture = Capture::default();
+    let route_capture = capture.clone();
+
+    let router = Router::new()
+        .route(
+            "/verify",
+            get(|req: Request<Body>| async move {
+                assert_eq!(req.uri().query().unwrap_or(""), "list-type=2&prefix=probe-");
+                (
+                    StatusCode::OK,
+                    "<ListBucketResult><Contents><Key>probe-1</Key></Contents></ListBucketResult>",
+                )
+            }),
+        )
+        .route(
+            "/verify/probe-1",
+            put(
+                move |State(c): State<Capture>, req: Request<Body>| async move {
+                    let body_bytes = axum::body::to_bytes(req.into_body(), usize::MAX)
+                        .await
+                        .unwrap();
+                    *c.body.lock().await = String::from_utf8(body_bytes.to_vec()).unwrap();
+                    StatusCode::OK
+                },
+            )
+            .get(|State(c): State<Capture>| async move { c.body.lock().await.clone() })
+            .delete(|| async { StatusCode::NO_CONTENT }),
+        )
+        .with_state(route_capture.clone());
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    let client = RustfsAdminClient::new_with_base_url(format!("http://{addr}"), "access", "secret");
+    client.put_object("verify", "probe-1", "ok").await.unwrap();
+    assert_eq!(&*capture.body.lock().await, "ok");
+    assert_eq!(client.get_object("verify", "probe-1").await.unwrap(), "ok");
+    assert_eq!(
+        client.list_object_keys("verify", "probe-").await.unwrap(),
+        vec!["probe-1".to_string()]
+    );
+    client.delete_object("verify", "probe-1").await.unwrap();
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn bucket_lifecycle_put_get_round_trip() {
+    let capture = Capture::default();
+
+    let router = Router::new()
+        .route(
+            "/",
+            get(|| async {
+                "<ListAllMyBucketsResult><Owner><DisplayName>rustfs</DisplayName></Owner><Buckets><Bucket><Name>logs</Name></Bucket></Buckets></ListAllMyBucketsResult>"
+            }),
+        )
+        .route(
+            "/logs",
+            put(
+                |State(c): State<Capture>, req: Request<Body>| async move {
+                    *c.query.lock().await = req.uri().query().unwrap_or("").to_string();
+                    *c.content_md5.lock().await = req
+                        .headers()
+                        .get("content-md5")
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or("")
+                        .to_string();
+                    let body_bytes = axum::body::to_bytes(req.into_body(), usize::MAX)
+                        .await
+                        .unwrap();
+                    *c.body.lock().await = String::from_utf8(body_bytes.to_vec()).unwrap();
+                    StatusCode::OK
+                },
+            )
+            .get(|State(c): State<Capture>| async move {
+                let body = c.body.lock().await.clone();
+                if body.is_empty() {
+                    (
+                        StatusCode::NOT_FOUND,
+                        "<Error><Code>NoSuchLifecycleConfiguration</Code></Error>".to_string(),
+                    )
+                } else {
+                    (StatusCode::OK, body)
+                }
+            }),
+        )
+        .with_state(capture.clone());
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    let client = RustfsAdminClient::new_with_base_url(format!("http://{addr}"), "access", "secret");
+    assert_eq!(
+        client.list_buckets().await.unwrap(),
+        vec!["logs".to_string()]
+    );
+    assert!(
+        client
+            .get_bucket_lifecycle("logs")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let rules = vec![
+        BucketLifecycleRule {
+            id: "logs.archive".to_string(),
+            prefix: "a&b/".to_string(),
+            enabled: true,
+            transition: Some((30, "COLD".to_string())),
+            noncurrent_expiration_days: Some(7),
+            ..Default::default()
+        },
+        BucketLifecycleRule {
+            id: "logs.expire".to_string(),
+            enabled: false,
+            expiration_days: Some(365),
+            ..Default::default()
+        },
+    ];
+    client.put_bucket_lifecycle("logs", &rules).await.unwrap();
+    assert_eq!(&*capture.query.lock().await, "lifecycle=");
+    assert!(!capture.content_md5.lock().await.is_empty());
+    assert_eq!(client.get_bucket_lifecycle("logs").await.unwrap(), rules);
+
+    server.abort();
+}
+
 #[test]
 fn extract_canned_policy_document_accepts_raw_policy_document() {
     let raw_policy =
@@ -17,14 +17,12 @@ use std::io::Cursor;
 use std::net::Ipv4Addr;
 use std::time::Duration;
 
+use crate::utils::tls::{CertificateAuthority, service_dns_names};
 use k8s_openapi::ByteString;
 use k8s_openapi::api::core::v1 as corev1;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 use kube::api::PostParams;
 use kube::{Api, Client};
-use rcgen::{
-    BasicConstraints, CertificateParams, ExtendedKeyUsagePurpose, IsCa, KeyPair, KeyUsagePurpose,
-};
 use rustls::pki_types::CertificateDer;
 use snafu::{OptionExt, ResultExt, Snafu};
 use tokio::time::sleep;
@@ -78,7 +76,7 @@ pub enum Error {
     SecretMissingCa { namespace: String, secret: String },
 
     #[snafu(display("failed to generate operator STS TLS certificate: {source}"))]
-    GenerateCertificate { source: rcgen::Error },
+    GenerateCertificate { source: crate::utils::tls::Error },
 
     #[snafu(display("failed to parse STS TLS certificate: {source}"))]
     ParseCertificate { source: std::io::Error },
@@ -299,39 +297,20 @@ fn generate_sts_tls_material(
     namespace: &str,
     service_name: &str,
 ) -> TlsResult<OperatorStsTlsMaterial> {
-    let ca_key = KeyPair::generate().context(GenerateCertificateSnafu)?;
-    let mut ca_params = CertificateParams::default();
-    ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
-    ca_params.key_usages = vec![
-        KeyUsagePurpose::KeyCertSign,
-        KeyUsagePurpose::DigitalSignature,
-        KeyUsagePurpose::CrlSign,
-    ];
-    let ca_cert = ca_params
-        .self_signed(&ca_key)
+    let ca = CertificateAuthority::generate("rustfs-operator-sts-ca", None)
         .context(GenerateCertificateSnafu)?;
-
-    let server_key = KeyPair::generate().context(GenerateCertificateSnafu)?;
-    let mut server_names = service_dns_names(namespace, service_name);
+    let mut server_names = service_dns_names(service_name, namespace);
     server_names.push("localhost".to_string());
     server_names.push(Ipv4Addr::LOCALHOST.to_string());
-    let mut server_params =
-        CertificateParams::new(server_names).context(GenerateCertificateSnafu)?;
-    server_params.is_ca = IsCa::NoCa;
-    server_params.key_usages = vec![
-        KeyUsagePurpose::DigitalSignature,
-        KeyUsagePurpose::KeyEncipherment,
-    ];
-    server_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
-    let server_cert = server_params
-        .signed_by(&server_key, &ca_cert, &ca_key)
+    let server = ca
+        .issue_server_certificate(&server_names, None)
         .context(GenerateCertificateSnafu)?;
 
     Ok(OperatorStsTlsMaterial {
         secret_name: STS_TLS_SECRET_NAME.to_string(),
-        cert_pem: server_cert.pem().into_bytes(),
-        key_pem: server_key.serialize_pem().into_bytes(),
-        ca_pem: ca_cert.pem().into_bytes(),
+        cert_pem: server.cert_pem.into_bytes(),
+        key_pem: server.key_pem.into_bytes(),
+        ca_pem: ca.cert_pem().into_bytes(),
     })
 }
 
@@ -378,15 +357,6 @@ fn secret_data(
         })
 }
 
-fn service_dns_names(namespace: &str, service_name: &str) -> Vec<String> {
-    vec![
-        service_name.to_string(),
-        format!("{service_name}.{namespace}"),
-        format!("{service_name}.{namespace}.svc"),
-        format!("{service_name}.{namespace}.svc.cluster.local"),
-    ]
-}
-
 fn is_operator_managed(secret: &corev1::Secret) -> bool {
     secret
         .metadata
@@ -396,7 +366,7 @@ fn is_operator_managed(secret: &corev1::Secret) -> bool {
         .is_some_and(|value| value == "true")
 }
 
-fn operator_namespace() -> String {
+pub(crate) fn operator_namespace() -> String {
     if let Some(value) = std::env::var("OPERATOR_NAMESPACE")
         .ok()
         .map(|value| value.trim().to_string())
@@ -438,7 +408,7 @@ mod tests {
     #[test]
     fn service_dns_names_cover_short_and_cluster_forms() {
         assert_eq!(
-            service_dns_names("rustfs-system", "rustfs-operator-sts"),
+            service_dns_names("rustfs-operator-sts", "rustfs-system"),
             vec![
                 "rustfs-operator-sts",
                 "rustfs-operator-sts.rustfs-system",
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use crate::{
-    metrics::{self, TenantStorageMetrics},
+    metrics::{self, PoolStorageMetrics, TenantStorageMetrics},
     sts::rustfs_client::{RustfsAdminClient, RustfsServerInfo},
     types::v1alpha1::tenant::Tenant,
 };
 use futures::{StreamExt, stream};
 use kube::{Api, Client, api::ListParams};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
@@ -27,6 +29,15 @@ const DEFAULT_MONITOR_INTERVAL: Duration = Duration::from_secs(300);
 const MAX_CONCURRENT_TENANT_POLLS: usize = 4;
 const TENANT_LIST_PAGE_SIZE: u32 = 500;
 
+type StoragePoll = (Instant, Result<TenantStorageMetrics, String>);
+
+/// Last admin API poll of each Tenant, keyed by `(namespace, name)`. The monitor and the health
+/// check both read it, so a Tenant is polled once for the two of them.
+fn storage_polls() -> &'static Mutex<BTreeMap<(String, String), StoragePoll>> {
+    static POLLS: OnceLock<Mutex<BTreeMap<(String, String), StoragePoll>>> = OnceLock::new();
+    POLLS.get_or_init(Default::default)
+}
+
 pub fn is_enabled() -> bool {
     env_bool("OPERATOR_TENANT_MONITOR_ENABLED", true)
 }
@@ -87,6 +98,10 @@ async fn poll_all_tenants(client: Client) {
         })
         .collect::<Vec<_>>();
     metrics::prune_tenant_storage(&active_tenants);
+    storage_polls()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .retain(|key, _| active_tenants.contains(key));
 
     stream::iter(tenants)
         .for_each_concurrent(MAX_CONCURRENT_TENANT_POLLS, |tenant| {
@@ -120,7 +135,7 @@ async fn poll_tenant(client: Client, tenant: Tenant) {
         return;
     }
 
-    match poll_tenant_storage(&client, &tenant).await {
+    match shared_tenant_storage(&client, &tenant, interval() / 2).await {
         Ok(storage) => {
             metrics::record_tenant_storage(&namespace, &tenant_name, storage);
             metrics::record_tenant_monitor_poll("success", started.elapsed());
@@ -138,7 +153,7 @@ async fn poll_tenant(client: Client, tenant: Tenant) {
     }
 }
 
-async fn list_all_tenants(client: Client) -> Result<Vec<Tenant>, kube::Error> {
+pub(crate) async fn list_all_tenants(client: Client) -> Result<Vec<Tenant>, kube::Error> {
     let tenants_api = Api::<Tenant>::all(client);
     let mut tenants = Vec::new();
     let mut continue_token = None;
@@ -161,21 +176,61 @@ async fn list_all_tenants(client: Client) -> Result<Vec<Tenant>, kube::Error> {
     }
 }
 
+/// Storage metrics from a poll of `tenant` younger than `max_age`, polling the admin API when
+/// there is none.
+pub(crate) async fn shared_tenant_storage(
+    client: &Client,
+    tenant: &Tenant,
+    max_age: Duration,
+) -> Result<TenantStorageMetrics, String> {
+    let key = (
+        tenant.metadata.namespace.clone().unwrap_or_default(),
+        tenant.name(),
+    );
+    if let Some((polled_at, result)) = storage_polls()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(&key)
+        && polled_at.elapsed() < max_age
+    {
+        return result.clone();
+    }
+
+    let result = poll_tenant_storage(client, tenant)
+        .await
+        .map_err(|error| error.to_string());
+    storage_polls()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(key, (Instant::now(), result.clone()));
+    result
+}
+
 async fn poll_tenant_storage(
     client: &Client,
     tenant: &Tenant,
 ) -> Result<TenantStorageMetrics, Box<dyn std::error::Error + Send + Sync>> {
-    let credentials = RustfsAdminClient::load_tenant_credentials(client, tenant).await?;
-    let rustfs_client = if tenant.spec.tls.as_ref().is_some_and(|tls| tls.is_enabled()) {
-        RustfsAdminClient::from_tls_tenant_for_sts(client, tenant, credentials).await?
-    } else {
-        RustfsAdminClient::from_tenant(tenant, credentials)?
-    };
-    let info = rustfs_client.server_info().await?;
+    let info = tenant_admin_client(client, tenant)
+        .await?
+        .server_info()
+        .await?;
 
     Ok(storage_metrics_from_info(&info))
 }
 
+/// Client for the Tenant endpoint signed with the `credsSecret` credentials.
+pub(crate) async fn tenant_admin_client(
+    client: &Client,
+    tenant: &Tenant,
+) -> Result<RustfsAdminClient, Box<dyn std::error::Error + Send + Sync>> {
+    let credentials = RustfsAdminClient::load_tenant_credentials(client, tenant).await?;
+    if tenant.spec.tls.as_ref().is_some_and(|tls| tls.is_enabled()) {
+        Ok(RustfsAdminClient::from_tls_tenant_for_sts(client, tenant, credentials).await?)
+    } else {
+        Ok(RustfsAdminClient::from_tenant(tenant, credentials)?)
+    }
+}
+
 fn storage_metrics_from_info(info: &RustfsServerInfo) -> TenantStorageMetrics {
     let (online_drives, offline_drives, write_quorum_drives) = info
         .backend
@@ -194,13 +249,22 @@ fn storage_metrics_from_info(info: &RustfsServerInfo) -> TenantStorageMetrics {
     let mut raw_used_bytes = 0u64;
     let mut pool_usage_bytes = 0u64;
 
+    let mut pool_metrics: BTreeMap<u64, PoolStorageMetrics> = BTreeMap::new();
+
     if let Some(pools) = &info.pools {
-        for sets in pools.values() {
+        for (index, sets) in pools {
+            let mut pool = PoolStorageMetrics::default();
             for set in sets.values() {
                 healing_drives = healing_drives.saturating_add(set.heal_disks);
                 raw_capacity_bytes = raw_capacity_bytes.saturating_add(set.raw_capacity);
                 raw_used_bytes = raw_used_bytes.saturating_add(set.raw_usage);
                 pool_usage_bytes = pool_usage_bytes.saturating_add(set.usage);
+                pool.raw_capacity_bytes = pool.raw_capacity_bytes.saturating_add(set.raw_capacity);
+                pool.raw_used_bytes = pool.raw_used_bytes.saturating_add(set.raw_usage);
+            }
+            // Keys are pool indexes; sort them numerically so "10" follows "9".
+            if let Ok(index) = index.parse() {
+                pool_metrics.insert(index, pool);
             }
         }
     }
@@ -219,6 +283,7 @@ fn storage_metrics_from_info(info: &RustfsServerInfo) -> TenantStorageMetrics {
         raw_used_bytes,
         object_usage_bytes,
         write_quorum_drives,
+        pools: pool_metrics.into_values().collect(),
         healthy: online_drives > 0
             && offline_drives == 0
             && healing_drives == 0
@@ -236,7 +301,7 @@ fn write_quorum_from_backend(backend: &crate::sts::rustfs_client::RustfsErasureB
         .sum()
 }
 
-fn env_bool(name: &str, default: bool) -> bool {
+pub(crate) fn env_bool(name: &str, default: bool) -> bool {
     match std::env::var(name) {
         Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
             "1" | "true" | "yes" | "on" => true,
@@ -256,7 +321,6 @@ mod tests {
     use crate::sts::rustfs_client::{
         RustfsErasureBackend, RustfsErasureSetInfo, RustfsServerUsage,
     };
-    use std::collections::BTreeMap;
 
     #[test]
     fn storage_metrics_capture_capacity_healing_and_quorum() {
@@ -284,6 +348,7 @@ mod tests {
                 drives_per_set: vec![4],
             }),
             pools: Some(pools),
+            ..Default::default()
         };
 
         let metrics = storage_metrics_from_info(&info);
@@ -295,6 +360,13 @@ mod tests {
         assert_eq!(metrics.raw_used_bytes, 100);
         assert_eq!(metrics.object_usage_bytes, 80);
         assert_eq!(metrics.write_quorum_drives, 2);
+        assert_eq!(
+            metrics.pools,
+            vec![PoolStorageMetrics {
+                raw_capacity_bytes: 400,
+                raw_used_bytes: 100,
+            }]
+        );
         assert!(!metrics.healthy);
     }
 }
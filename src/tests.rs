@@ -38,6 +38,11 @@ pub fn create_test_tenant(
                     volumes_per_server: 4,
                     ..Default::default()
                 },
+                env: Vec::new(),
+                service_account_name: None,
+                pod_metadata: None,
+                pod_deletion_policy_when_node_is_down: None,
+                paused: None,
                 scheduling: Default::default(),
             }],
             service_account_name,
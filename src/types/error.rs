@@ -39,6 +39,25 @@ pub enum Error {
     #[snafu(display("invalid pool specification for tenant '{}': {}", name, message))]
     InvalidPoolSpec { name: String, message: String },
 
+    #[snafu(display("invalid ports for tenant '{}': {}", name, message))]
+    InvalidPortSpec { name: String, message: String },
+
+    #[snafu(display("invalid rbac for tenant '{}': {}", name, message))]
+    InvalidRbacSpec { name: String, message: String },
+
+    #[snafu(display("unsafe env override for tenant '{}': {}", name, message))]
+    ProtectedEnvOverride { name: String, message: String },
+
+    #[snafu(display("tenant '{}' violates the namespace policy: {}", name, message))]
+    NamespaceTenantConflict { name: String, message: String },
+
+    #[snafu(display(
+        "tenant '{}' is in a namespace the operator does not manage: {}",
+        name,
+        message
+    ))]
+    NamespaceNotAllowed { name: String, message: String },
+
     #[snafu(display("serde_json error: {}", source))]
     SerdeJson { source: serde_json::Error },
 }
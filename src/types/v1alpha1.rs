@@ -12,17 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod alerting;
+pub mod credentials;
+pub mod deletion;
+pub mod diagnostics;
 pub mod encryption;
+pub mod event_notifications;
+pub mod federation;
 pub mod k8s;
 pub mod logging;
+pub mod notifications;
 pub mod persistence;
 pub mod policy_binding;
 pub mod pool;
 pub mod pool_lifecycle;
+pub mod ports;
+pub mod profile;
 pub mod provisioning;
+pub mod rbac;
+pub mod rebalance;
 pub mod status;
+pub mod synthetics;
 pub mod tenant;
 pub mod tls;
+pub mod upgrade;
+pub mod verification;
 
 // Re-export commonly used types
 pub use pool::SchedulingConfig;
@@ -258,5 +272,11 @@ mod tenant_provisioning_crd_tests {
                 [0]["message"],
             json!("bucket name must be a valid RustFS/S3 bucket name")
         );
+        let creds_secret = &spec["properties"]["credsSecret"];
+        assert_eq!(creds_secret["required"], json!(["name"]));
+        assert_eq!(
+            creds_secret["properties"]["name"]["x-kubernetes-validations"][0]["rule"],
+            json!("self != ''")
+        );
     }
 }
@@ -0,0 +1,53 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub(crate) const DEFAULT_VOLUME_USAGE_THRESHOLD_PERCENT: u8 = 85;
+
+/// PrometheusRule the operator keeps for the Tenant while the Prometheus Operator is installed.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertingSpec {
+    /// Generate the `{tenant}-alerts` PrometheusRule. Defaults to true; `false` deletes it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Labels added to every alert, for Alertmanager routing (for example `team: storage`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+
+    /// Labels on the PrometheusRule object, to match the `ruleSelector` of a Prometheus.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rule_labels: BTreeMap<String, String>,
+
+    /// Used space of a data volume, in percent, at which `RustfsTenantVolumeNearlyFull` fires.
+    /// Defaults to 85.
+    #[schemars(range(min = 1, max = 99))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume_usage_threshold_percent: Option<u8>,
+}
+
+impl AlertingSpec {
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
+    pub fn volume_usage_threshold_percent(&self) -> u8 {
+        self.volume_usage_threshold_percent
+            .unwrap_or(DEFAULT_VOLUME_USAGE_THRESHOLD_PERCENT)
+    }
+}
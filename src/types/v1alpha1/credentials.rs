@@ -0,0 +1,195 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::KubeSchema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Reference to the Secret holding the RustFS `accesskey` and `secretkey`.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CredsSecretReference {
+    /// Name of the Secret in the Tenant namespace.
+    #[x_kube(validation = Rule::new("self != ''").message("credsSecret.name must not be empty"))]
+    pub name: String,
+
+    /// Who creates the Secret. Defaults to `kubernetes`.
+    #[serde(default, skip_serializing_if = "is_default_provider")]
+    pub provider: CredentialsProvider,
+
+    /// Secret keys holding the credentials, for Secrets created by other tooling
+    /// (for example `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<CredentialKeys>,
+
+    /// Minimum length of each credential value. Defaults to 8. RustFS itself needs an access key
+    /// of at least 3 and a secret key of at least 8 characters, so lower values only relax the
+    /// access key, down to 3.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[x_kube(
+        validation = Rule::new("self >= 3")
+            .message("credsSecret.minLength must be at least 3, the shortest access key RustFS accepts")
+    )]
+    pub min_length: Option<u32>,
+}
+
+/// Key names inside the credential Secret.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialKeys {
+    /// Key holding the access key. Defaults to `accesskey`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_key: Option<String>,
+
+    /// Key holding the secret key. Defaults to `secretkey`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_key: Option<String>,
+}
+
+impl CredsSecretReference {
+    pub const DEFAULT_ACCESS_KEY: &'static str = "accesskey";
+    pub const DEFAULT_SECRET_KEY: &'static str = "secretkey";
+    pub const DEFAULT_MIN_LENGTH: u32 = 8;
+    /// Shortest access key RustFS accepts.
+    pub const ACCESS_KEY_MIN_LENGTH_FLOOR: u32 = 3;
+    /// Shortest secret key RustFS accepts.
+    pub const SECRET_KEY_MIN_LENGTH_FLOOR: u32 = 8;
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn access_key(&self) -> &str {
+        self.keys
+            .as_ref()
+            .and_then(|keys| keys.access_key.as_deref())
+            .filter(|key| !key.is_empty())
+            .unwrap_or(Self::DEFAULT_ACCESS_KEY)
+    }
+
+    pub fn secret_key(&self) -> &str {
+        self.keys
+            .as_ref()
+            .and_then(|keys| keys.secret_key.as_deref())
+            .filter(|key| !key.is_empty())
+            .unwrap_or(Self::DEFAULT_SECRET_KEY)
+    }
+
+    /// Minimum length of the access key value.
+    pub fn access_key_min_length(&self) -> usize {
+        self.min_length
+            .unwrap_or(Self::DEFAULT_MIN_LENGTH)
+            .max(Self::ACCESS_KEY_MIN_LENGTH_FLOOR) as usize
+    }
+
+    /// Minimum length of the secret key value.
+    pub fn secret_key_min_length(&self) -> usize {
+        self.min_length
+            .unwrap_or(Self::DEFAULT_MIN_LENGTH)
+            .max(Self::SECRET_KEY_MIN_LENGTH_FLOOR) as usize
+    }
+
+    /// The Secret is materialized by an external store and may not exist yet.
+    pub fn is_external(&self) -> bool {
+        self.provider == CredentialsProvider::External
+    }
+}
+
+#[derive(
+    Default, Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename_all = "camelCase")]
+pub enum CredentialsProvider {
+    /// The Secret is created directly; a missing Secret blocks the Tenant.
+    #[strum(to_string = "kubernetes")]
+    #[default]
+    Kubernetes,
+
+    /// The Secret is materialized by an external store (for example an ExternalSecret or a
+    /// VaultStaticSecret); the Tenant waits for it with a `WaitingForCredentials` condition.
+    #[strum(to_string = "external")]
+    External,
+}
+
+fn is_default_provider(provider: &CredentialsProvider) -> bool {
+    *provider == CredentialsProvider::Kubernetes
+}
+
+/// Below this many bits of estimated entropy a secret key triggers a warning event.
+pub const LOW_ENTROPY_BITS: f64 = 48.0;
+
+/// Estimates the entropy of `value` in bits from its character frequencies.
+///
+/// This is a cheap heuristic for repeated or dictionary-like values, not a strength meter.
+pub fn estimated_entropy_bits(value: &str) -> f64 {
+    let mut counts = std::collections::HashMap::new();
+    let mut total = 0usize;
+    for ch in value.chars() {
+        *counts.entry(ch).or_insert(0usize) += 1;
+        total += 1;
+    }
+    if total == 0 {
+        return 0.0;
+    }
+    let per_char: f64 = counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total as f64;
+            -p * p.log2()
+        })
+        .sum();
+    per_char * total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_names_default_and_accept_overrides() {
+        let mut reference = CredsSecretReference::new("creds");
+        assert_eq!(reference.access_key(), "accesskey");
+        assert_eq!(reference.secret_key(), "secretkey");
+        assert_eq!(reference.access_key_min_length(), 8);
+        assert_eq!(reference.secret_key_min_length(), 8);
+
+        reference.keys = Some(CredentialKeys {
+            access_key: Some("AWS_ACCESS_KEY_ID".to_string()),
+            secret_key: Some(String::new()),
+        });
+        reference.min_length = Some(0);
+        assert_eq!(reference.access_key(), "AWS_ACCESS_KEY_ID");
+        assert_eq!(reference.secret_key(), "secretkey");
+        assert_eq!(reference.access_key_min_length(), 3);
+        assert_eq!(reference.secret_key_min_length(), 8);
+
+        reference.min_length = Some(16);
+        assert_eq!(reference.access_key_min_length(), 16);
+        assert_eq!(reference.secret_key_min_length(), 16);
+    }
+
+    #[test]
+    fn entropy_estimate_flags_repetitive_values() {
+        assert!(estimated_entropy_bits("rustfsadmin") < LOW_ENTROPY_BITS);
+        assert!(estimated_entropy_bits("aaaaaaaaaaaaaaaaaaaaaaaa") < LOW_ENTROPY_BITS);
+        assert!(
+            estimated_entropy_bits("wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY") > LOW_ENTROPY_BITS
+        );
+    }
+}
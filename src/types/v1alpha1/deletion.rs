@@ -0,0 +1,55 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::schemars::JsonSchema;
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Detection and remediation of a Tenant whose deletion is blocked by one of its children.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StuckDeletionConfig {
+    /// Seconds a deleting Tenant may wait on its children before a `TenantDeletionStuck` event
+    /// names the blocking objects and finalizers. Defaults to 600.
+    #[schemars(range(min = 60))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_seconds: Option<u32>,
+
+    /// Once the Tenant is stuck, force delete its terminating pods and remove the finalizers of
+    /// its terminating PersistentVolumeClaims and StatefulSets. Off by default; removing a
+    /// finalizer can leave the backing volume behind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_cleanup: Option<bool>,
+}
+
+/// How the operator removes the Tenant's children when the Tenant is deleted.
+///
+/// https://kubernetes.io/docs/concepts/architecture/garbage-collection/#cascading-deletion
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum DeletionPropagation {
+    /// Delete the children and keep the Tenant until they, and their pods, are gone.
+    #[strum(to_string = "Foreground")]
+    Foreground,
+
+    /// Delete the children and release the Tenant right away.
+    #[strum(to_string = "Background")]
+    Background,
+
+    /// Detach the children from the Tenant so they outlive it.
+    #[strum(to_string = "Orphan")]
+    Orphan,
+}
@@ -0,0 +1,37 @@
+//  Copyright 2025 RustFS Team
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Upper bound for `crashLogLines`, keeping the diagnostics ConfigMap well under 1 MiB.
+pub const MAX_CRASH_LOG_LINES: u32 = 500;
+
+/// Diagnostics collected when Tenant pods misbehave.
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsConfig {
+    /// Number of log lines to keep from the previous run of each crash-looping container.
+    /// The lines are stored in the `<tenant>-crash-diagnostics` ConfigMap. Unset or 0 disables
+    /// log capture; crash loops are still reported in the `PodsCrashLooping` condition.
+    #[schemars(range(max = MAX_CRASH_LOG_LINES))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crash_log_lines: Option<u32>,
+}
+
+impl DiagnosticsConfig {
+    pub fn crash_log_lines(&self) -> u32 {
+        self.crash_log_lines.unwrap_or(0).min(MAX_CRASH_LOG_LINES)
+    }
+}
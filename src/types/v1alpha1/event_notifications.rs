@@ -0,0 +1,138 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::api::core::v1 as corev1;
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+
+pub(crate) const MAX_EVENT_NOTIFICATION_TARGETS: u32 = 16;
+pub(crate) const MAX_EVENT_NOTIFICATION_TARGET_NAME_LENGTH: u32 = 32;
+
+/// Bucket event notification targets configured in RustFS.
+///
+/// RustFS provides webhook and MQTT targets; buckets publish to a target by its name once a
+/// bucket notification configuration refers to it.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotificationsSpec {
+    #[schemars(
+        length(max = MAX_EVENT_NOTIFICATION_TARGETS),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["name"])
+    )]
+    #[x_kube(validation = Rule::new("self.all(t, (has(t.webhook) ? 1 : 0) + (has(t.mqtt) ? 1 : 0) == 1)").message("each event notification target must set exactly one of webhook or mqtt"))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<EventNotificationTarget>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotificationTarget {
+    /// Target ID in RustFS. Upper-cased, it is the suffix of the target's `RUSTFS_NOTIFY_*`
+    /// environment variables.
+    #[schemars(
+        length(min = 1, max = MAX_EVENT_NOTIFICATION_TARGET_NAME_LENGTH),
+        regex(pattern = r"^[a-z0-9]([a-z0-9_]*[a-z0-9])?$")
+    )]
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookNotificationTarget>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<MqttNotificationTarget>,
+}
+
+impl EventNotificationTarget {
+    /// Suffix of the target's environment variables.
+    pub fn env_suffix(&self) -> String {
+        self.name.to_ascii_uppercase()
+    }
+
+    /// `host:port` the operator connects to when checking that the target is reachable.
+    pub fn address(&self) -> Option<String> {
+        let (endpoint, default_port) = if let Some(webhook) = &self.webhook {
+            (webhook.endpoint.as_str(), None)
+        } else {
+            (self.mqtt.as_ref()?.broker.as_str(), Some(1883))
+        };
+        let url = url::Url::parse(endpoint).ok()?;
+        let host = url.host_str()?;
+        let port = url.port_or_known_default().or(default_port)?;
+        Some(format!("{host}:{port}"))
+    }
+
+    /// Secrets the target reads, in a stable order.
+    pub fn secret_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        if let Some(webhook) = &self.webhook {
+            names.extend(webhook.auth_token_secret.as_ref().map(|r| r.name.as_str()));
+            names.extend(webhook.client_cert_secret.as_ref().map(|r| r.name.as_str()));
+        }
+        if let Some(mqtt) = &self.mqtt {
+            names.extend(mqtt.username_secret.as_ref().map(|r| r.name.as_str()));
+            names.extend(mqtt.password_secret.as_ref().map(|r| r.name.as_str()));
+        }
+        names
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookNotificationTarget {
+    /// URL RustFS POSTs bucket events to.
+    #[schemars(length(min = 1), regex(pattern = r"^https?://"))]
+    pub endpoint: String,
+
+    /// Secret key holding the token RustFS sends in the `Authorization` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_token_secret: Option<NotificationSecretKeyReference>,
+
+    /// `kubernetes.io/tls` Secret with the client certificate (`tls.crt`, `tls.key`) RustFS
+    /// presents to an endpoint that requires mutual TLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert_secret: Option<corev1::LocalObjectReference>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttNotificationTarget {
+    /// Broker URL, for example `tcp://mqtt.example.com:1883` or `ssl://mqtt.example.com:8883`.
+    #[schemars(length(min = 1), regex(pattern = r"^(tcp|ssl|ws|wss)://"))]
+    pub broker: String,
+
+    #[schemars(length(min = 1))]
+    pub topic: String,
+
+    /// MQTT quality of service level.
+    #[schemars(range(min = 0, max = 2))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qos: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username_secret: Option<NotificationSecretKeyReference>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_secret: Option<NotificationSecretKeyReference>,
+}
+
+/// A key of a Secret in the Tenant namespace.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSecretKeyReference {
+    #[schemars(length(min = 1))]
+    pub name: String,
+
+    #[schemars(length(min = 1))]
+    pub key: String,
+}
@@ -0,0 +1,96 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::{CustomResource, KubeSchema};
+use serde::{Deserialize, Serialize};
+
+pub(crate) const MAX_TENANT_MIRRORS: u32 = 16;
+
+/// A Kubernetes cluster the operator can reach with a kubeconfig stored in a Secret.
+///
+/// Tenants reference RemoteClusters from the same namespace in `spec.federation.mirrors`.
+#[derive(CustomResource, Deserialize, Serialize, Clone, Debug, KubeSchema)]
+#[kube(
+    group = "rustfs.com",
+    version = "v1alpha1",
+    kind = "RemoteCluster",
+    namespaced,
+    shortname = "remotecluster",
+    plural = "remoteclusters",
+    singular = "remotecluster",
+    printcolumn = r#"{"name":"Age", "type":"date", "jsonPath":".metadata.creationTimestamp"}"#,
+    crates(serde_json = "k8s_openapi::serde_json")
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteClusterSpec {
+    /// Secret in the RemoteCluster namespace holding the kubeconfig.
+    pub kubeconfig_secret: KubeconfigSecretReference,
+
+    /// Kubeconfig context to use. Defaults to the kubeconfig's current context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct KubeconfigSecretReference {
+    #[schemars(length(min = 1))]
+    pub name: String,
+
+    /// Secret key holding the kubeconfig. Defaults to `kubeconfig`.
+    #[serde(default = "default_kubeconfig_key")]
+    pub key: String,
+}
+
+fn default_kubeconfig_key() -> String {
+    "kubeconfig".to_string()
+}
+
+/// Copies of this Tenant maintained in remote clusters.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FederationSpec {
+    #[schemars(
+        length(max = MAX_TENANT_MIRRORS),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["cluster"])
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<TenantMirror>,
+}
+
+/// A remote cluster that runs its own copy of the Tenant.
+///
+/// The remote cluster needs the RustFS operator installed; it reconciles the mirrored Tenant
+/// into an independent RustFS cluster.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantMirror {
+    /// Name of a RemoteCluster in the Tenant namespace.
+    #[schemars(length(min = 1))]
+    pub cluster: String,
+
+    /// Namespace in the remote cluster. Defaults to the Tenant namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Also copy the Secrets the Tenant references (credentials, KMS, image pull, users, TLS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_secrets: Option<bool>,
+}
+
+impl TenantMirror {
+    pub fn sync_secrets(&self) -> bool {
+        self.sync_secrets.unwrap_or(true)
+    }
+}
@@ -38,6 +38,21 @@ pub enum PodManagementPolicy {
     Parallel,
 }
 
+/// How the scheduler handles a pod that would violate a topology spread constraint.
+///
+/// https://kubernetes.io/docs/concepts/scheduling-eviction/topology-spread-constraints/
+#[derive(Default, Deserialize, Serialize, Clone, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum WhenUnsatisfiable {
+    #[strum(to_string = "DoNotSchedule")]
+    DoNotSchedule,
+
+    #[strum(to_string = "ScheduleAnyway")]
+    #[default]
+    ScheduleAnyway,
+}
+
 /// Image pull policy for containers.
 /// - Always: Always pull the image
 /// - Never: Never pull the image
@@ -59,6 +74,23 @@ pub enum ImagePullPolicy {
     IfNotPresent,
 }
 
+/// Volume mode for pool data volumes.
+/// - Filesystem: volumes are formatted and mounted into the container (default)
+/// - Block: volumes are exposed to the container as raw block devices
+///
+/// https://kubernetes.io/docs/concepts/storage/persistent-volumes/#volume-mode
+#[derive(Default, Deserialize, Serialize, Clone, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum VolumeMode {
+    #[strum(to_string = "Filesystem")]
+    #[default]
+    Filesystem,
+
+    #[strum(to_string = "Block")]
+    Block,
+}
+
 /// Pod deletion policy when the node hosting the Pod is down (NotReady/Unknown).
 ///
 /// This is primarily intended to unblock StatefulSet pods stuck in terminating state
@@ -0,0 +1,134 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::KubeSchema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+pub(crate) const MAX_TENANT_WEBHOOKS: u32 = 8;
+pub(crate) const DEFAULT_NOTIFICATION_INTERVAL_SECONDS: u32 = 300;
+pub(crate) const MAX_NOTIFICATION_INTERVAL_SECONDS: u32 = 86_400;
+pub(crate) const MAX_NOTIFICATION_TEMPLATE_LENGTH: u32 = 2048;
+
+/// Webhooks the operator calls when the Tenant changes state.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsSpec {
+    #[schemars(
+        length(max = MAX_TENANT_WEBHOOKS),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["name"])
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<NotificationWebhook>,
+
+    /// Minimum seconds between two notifications of the same event to the same webhook.
+    /// Events inside the interval are dropped. Defaults to 300.
+    #[schemars(range(min = 0, max = MAX_NOTIFICATION_INTERVAL_SECONDS))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_interval_seconds: Option<u32>,
+}
+
+impl NotificationsSpec {
+    pub fn min_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(
+            self.min_interval_seconds
+                .unwrap_or(DEFAULT_NOTIFICATION_INTERVAL_SECONDS),
+        ))
+    }
+}
+
+/// An HTTP endpoint that receives a POST for each selected Tenant state transition.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationWebhook {
+    #[schemars(length(min = 1, max = 63))]
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "is_default_format")]
+    pub format: WebhookFormat,
+
+    /// Secret in the Tenant namespace holding the webhook URL. Slack incoming webhook URLs
+    /// grant posting rights, so they are never stored in the Tenant spec.
+    pub url_secret: WebhookUrlSecretReference,
+
+    /// Transitions that trigger this webhook. Defaults to all of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<NotificationEvent>,
+
+    /// Message text. `{tenant}`, `{namespace}`, `{event}`, `{state}`, and `{message}` are
+    /// replaced with the transition details.
+    /// Defaults to `RustFS Tenant {namespace}/{tenant} is {event}: {message}`.
+    #[schemars(length(min = 1, max = MAX_NOTIFICATION_TEMPLATE_LENGTH))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl NotificationWebhook {
+    pub fn wants(&self, event: NotificationEvent) -> bool {
+        self.events.is_empty() || self.events.contains(&event)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookUrlSecretReference {
+    #[schemars(length(min = 1))]
+    pub name: String,
+
+    /// Secret key holding the URL. Defaults to `url`.
+    #[serde(default = "default_url_key")]
+    pub key: String,
+}
+
+fn default_url_key() -> String {
+    "url".to_string()
+}
+
+#[derive(
+    Default, Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq,
+)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum WebhookFormat {
+    /// JSON object with the transition fields and the rendered `text`.
+    #[strum(to_string = "Generic")]
+    #[default]
+    Generic,
+
+    /// Slack incoming webhook payload (`{"text": ...}`).
+    #[strum(to_string = "Slack")]
+    Slack,
+}
+
+fn is_default_format(format: &WebhookFormat) -> bool {
+    format == &WebhookFormat::Generic
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq, Hash)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum NotificationEvent {
+    /// The Tenant state became `Degraded`.
+    #[strum(to_string = "Degraded")]
+    Degraded,
+
+    /// The Tenant state became `Ready` after being in any other state.
+    #[strum(to_string = "Ready")]
+    Ready,
+
+    /// A pool rollout failed or a canary upgrade was rolled back.
+    #[strum(to_string = "RolloutFailed")]
+    RolloutFailed,
+}
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use kube::KubeSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::types::v1alpha1::k8s;
+
 #[derive(Deserialize, Serialize, Clone, Debug, KubeSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PersistenceConfig {
@@ -26,15 +29,130 @@ pub struct PersistenceConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volume_claim_template: Option<corev1::PersistentVolumeClaimSpec>,
 
+    /// Per-volume claim settings, used instead of the uniform `volumeClaimTemplate`.
+    ///
+    /// Entries without `mountPath` are RustFS data volumes: there must be exactly
+    /// `volumesPerServer` of them, mounted in order at `{path}/rustfs{N}`. Entries with
+    /// `mountPath` are auxiliary volumes mounted at that path under their own claim template
+    /// `name`; they are not listed in `RUSTFS_VOLUMES`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[x_kube(validation = Rule::new("self == oldSelf").message("volumeTemplates is immutable"))]
+    pub volume_templates: Option<Vec<VolumeTemplate>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     #[x_kube(validation = Rule::new("self != ''").message("path must be not empty when specified"))]
     pub path: Option<String>,
 
+    /// Volume mode of the data volumes. Overrides `volumeClaimTemplate.volumeMode`. Defaults to
+    /// Filesystem. Block is rejected until RustFS can store data on raw block devices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[x_kube(validation = Rule::new("self == oldSelf").message("volumeMode is immutable"))]
+    pub volume_mode: Option<k8s::VolumeMode>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::BTreeMap<String, String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<std::collections::BTreeMap<String, String>>,
+
+    /// Pre-provisioned PersistentVolumeClaims to reuse instead of provisioning new volumes.
+    ///
+    /// Ordered server-major: entry `ordinal * volumesPerServer + volume` backs volume `vol-{volume}`
+    /// of server `{ordinal}`. Must contain exactly `servers * volumesPerServer` unique names.
+    /// Claims that do not already use the StatefulSet naming scheme are handed over to the
+    /// operator-created claim once the source claim is deleted and its volume is Released.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub existing_claims: Option<Vec<String>>,
+
+    /// Node-local disks for bare-metal clusters without a CSI driver.
+    ///
+    /// The operator creates one `local` PersistentVolume per disk, pinned to its node and
+    /// pre-bound to the matching StatefulSet claim. Mutually exclusive with
+    /// `volumeClaimTemplate` and `existingClaims`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[x_kube(validation = Rule::new("self == oldSelf").message("localVolumes is immutable"))]
+    pub local_volumes: Option<LocalVolumesConfig>,
+
+    /// VolumeSnapshots the pool's data volumes are restored from when their claims are first
+    /// created. Mutually exclusive with `existingClaims` and `localVolumes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[x_kube(validation = Rule::new("self == oldSelf").message("dataSource is immutable"))]
+    pub data_source: Option<SnapshotDataSource>,
+}
+
+/// Claim settings for a single volume of every server in a pool.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeTemplate {
+    /// Volume name. Used as the claim template name of auxiliary volumes.
+    #[schemars(
+        length(min = 1, max = 63),
+        regex(pattern = r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?$")
+    )]
+    pub name: String,
+
+    /// Requested capacity, e.g. `50Gi`.
+    pub size: Quantity,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_class_name: Option<String>,
+
+    /// Mount path for an auxiliary volume. Omit for RustFS data volumes. Must not overlap the
+    /// data path or the logs mount.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mount_path: Option<String>,
+}
+
+impl VolumeTemplate {
+    pub fn is_data_volume(&self) -> bool {
+        self.mount_path.is_none()
+    }
+}
+
+/// Snapshots to pre-populate a new pool's data volumes from.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDataSource {
+    /// `snapshot.storage.k8s.io/v1` VolumeSnapshots in the Tenant namespace, ordered
+    /// server-major: entry `ordinal * volumesPerServer + volume` restores volume `vol-{volume}` of
+    /// server `{ordinal}`. Must contain exactly `servers * volumesPerServer` unique names. Each
+    /// snapshot must be ready to use and no larger than the claim's requested storage.
+    pub volume_snapshots: Vec<String>,
+}
+
+/// Static local PersistentVolumes generated for a pool.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalVolumesConfig {
+    /// StorageClass name set on the generated PersistentVolumes and claims.
+    /// The StorageClass object does not need to exist. Defaults to `rustfs-local`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_class_name: Option<String>,
+
+    /// Capacity advertised by every generated PersistentVolume, e.g. `1Ti`.
+    pub capacity: Quantity,
+
+    /// One entry per server, in ordinal order.
+    /// Each entry must list exactly `volumesPerServer` disk paths.
+    pub nodes: Vec<LocalVolumeNode>,
+}
+
+/// Disks of a single server in a pool with local volumes.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalVolumeNode {
+    /// Node (`kubernetes.io/hostname`) the server and its volumes are pinned to.
+    #[x_kube(validation = Rule::new("self != ''").message("nodeName must be not empty"))]
+    pub node_name: String,
+
+    /// Absolute paths of pre-formatted, mounted disks on the node.
+    pub paths: Vec<String>,
+}
+
+impl PersistenceConfig {
+    pub fn is_block_mode(&self) -> bool {
+        self.volume_mode == Some(k8s::VolumeMode::Block)
+    }
 }
 
 impl Default for PersistenceConfig {
@@ -42,9 +160,14 @@ impl Default for PersistenceConfig {
         Self {
             volumes_per_server: 4, // Must be > 0 when serialized into a Tenant spec.
             volume_claim_template: None,
+            volume_templates: None,
             path: None,
+            volume_mode: None,
             labels: None,
             annotations: None,
+            existing_claims: None,
+            local_volumes: None,
+            data_source: None,
         }
     }
 }
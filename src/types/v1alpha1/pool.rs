@@ -15,9 +15,11 @@
 use k8s_openapi::api::core::v1 as corev1;
 use kube::KubeSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
+use crate::types::v1alpha1::k8s::{PodDeletionPolicyWhenNodeIsDown, WhenUnsatisfiable};
 use crate::types::v1alpha1::persistence::PersistenceConfig;
+use crate::types::v1alpha1::profile::MIN_PRODUCTION_POOL_DRIVES;
 
 /// Kubernetes scheduling and placement configuration for pools.
 /// Groups related scheduling fields for better code organization.
@@ -50,6 +52,46 @@ pub struct SchedulingConfig {
     pub priority_class_name: Option<String>,
 }
 
+/// Default spreading of each pool's pods across `topology.kubernetes.io/zone`, applied when the
+/// cluster nodes span more than one zone and the pool sets no `topologySpreadConstraints`.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneSpreadConfig {
+    /// Generate the default constraint. Defaults to true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// `whenUnsatisfiable` of the generated constraint. Defaults to `ScheduleAnyway`, so a full
+    /// zone never leaves pods Pending.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when_unsatisfiable: Option<WhenUnsatisfiable>,
+}
+
+/// Safety controls for the node-down pod cleanup driven by `podDeletionPolicyWhenNodeIsDown`.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeDownCleanupConfig {
+    /// Only emit `WouldDeletePodOnDownNode` events describing the pods that would be deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+
+    /// Minimum seconds between deletions of pods on the same node, so a flapping node does not
+    /// trigger repeated force-deletes. Defaults to 300.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_seconds: Option<u32>,
+}
+
+/// Extra metadata for the pods of a pool.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PodMetadata {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug, KubeSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Pool {
@@ -65,6 +107,32 @@ pub struct Pool {
 
     pub persistence: PersistenceConfig,
 
+    /// Freeze this pool: its StatefulSet is neither created, updated, nor scaled while other
+    /// pools keep reconciling. The pool reports the `Paused` state in status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+
+    /// Extra RustFS container env vars for this pool, applied after `spec.env`. An entry with the
+    /// same name as a Tenant-level or operator-set var replaces it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<corev1::EnvVar>,
+
+    /// ServiceAccount for this pool's pods, replacing `spec.serviceAccountName`. The operator
+    /// does not create it; when it manages the Tenant Role, it binds the Role to this
+    /// ServiceAccount with a `{tenant}-{pool}-role-binding` RoleBinding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_account_name: Option<String>,
+
+    /// Labels and annotations added to this pool's pod template, for example cost-allocation
+    /// labels or per-pool Istio settings. Keys the operator sets itself keep the operator value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pod_metadata: Option<PodMetadata>,
+
+    /// Node-down pod deletion policy for this pool's pods, replacing
+    /// `spec.podDeletionPolicyWhenNodeIsDown`. Set it on a single pool to opt only that pool in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pod_deletion_policy_when_node_is_down: Option<PodDeletionPolicyWhenNodeIsDown>,
+
     /// Kubernetes scheduling and placement configuration.
     /// Flattened to maintain backward compatibility with YAML structure.
     #[serde(flatten)]
@@ -72,9 +140,43 @@ pub struct Pool {
 }
 
 impl Pool {
+    pub fn is_paused(&self) -> bool {
+        self.paused.unwrap_or(false)
+    }
+
     pub fn is_single_node_single_disk(&self) -> bool {
         self.servers == 1 && self.persistence.volumes_per_server == 1
     }
+
+    /// Drives per erasure set RustFS forms from this pool: the largest size between 2 and 16
+    /// that divides the drive count and is symmetric across servers. `None` when no such size
+    /// exists, in which case RustFS refuses the layout.
+    pub fn erasure_set_drive_count(&self) -> Option<i32> {
+        let drives = self
+            .servers
+            .saturating_mul(self.persistence.volumes_per_server);
+        if drives == 1 {
+            return Some(1);
+        }
+        (MIN_ERASURE_SET_DRIVES..=MAX_ERASURE_SET_DRIVES)
+            .rev()
+            .filter(|size| drives > 0 && drives % size == 0)
+            .find(|size| size % self.servers == 0 || self.servers % size == 0)
+    }
+}
+
+const MIN_ERASURE_SET_DRIVES: i32 = 2;
+const MAX_ERASURE_SET_DRIVES: i32 = 16;
+
+/// Parity RustFS uses for an erasure set of `set_drives` drives when no storage class is set.
+pub fn default_erasure_parity(set_drives: i32) -> i32 {
+    match set_drives {
+        ..=1 => 0,
+        2..=3 => 1,
+        4..=5 => 2,
+        6..=7 => 3,
+        _ => 4,
+    }
 }
 
 /// Validate a pool name used in labels and RustFS peer DNS names.
@@ -124,11 +226,32 @@ pub fn validate_pool_collection(tenant_name: &str, pools: &[Pool]) -> Result<(),
             return Err(format!("pool names must be unique: '{}'", pool.name));
         }
         validate_rustfs_peer_dns_label(tenant_name, pool)?;
+        validate_existing_claims(pool)?;
+        validate_local_volumes(pool)?;
+        validate_data_source(pool)?;
+        validate_volume_templates(pool)?;
+        validate_volume_mode(pool)?;
     }
 
     Ok(())
 }
 
+/// Reject pools with fewer drives than the production profile allows.
+pub fn validate_production_drive_count(pools: &[Pool]) -> Result<(), String> {
+    for pool in pools {
+        let drives = pool
+            .servers
+            .saturating_mul(pool.persistence.volumes_per_server);
+        if drives < MIN_PRODUCTION_POOL_DRIVES {
+            return Err(format!(
+                "pool '{}' has {drives} drive(s) (servers * volumesPerServer); the production profile needs at least {MIN_PRODUCTION_POOL_DRIVES}, set spec.profile: standalone for single-node evaluation",
+                pool.name
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub fn validate_pool_shape_immutable(existing: &[Pool], desired: &[Pool]) -> Result<(), String> {
     for desired_pool in desired {
         let Some(existing_pool) = existing
@@ -160,6 +283,22 @@ pub fn validate_pool_shape_immutable(existing: &[Pool], desired: &[Pool]) -> Res
     Ok(())
 }
 
+/// Pools that `desired` renames, as `(existing, desired)` name pairs. A pool at the same position
+/// whose old name is gone from `desired` and whose new name is not in `existing` is a rename; the
+/// operator would create a fresh empty pool for the new name and orphan the old one.
+pub fn detect_pool_renames(existing: &[Pool], desired: &[Pool]) -> Vec<(String, String)> {
+    existing
+        .iter()
+        .zip(desired)
+        .filter(|(old, new)| {
+            old.name != new.name
+                && !desired.iter().any(|pool| pool.name == old.name)
+                && !existing.iter().any(|pool| pool.name == new.name)
+        })
+        .map(|(old, new)| (old.name.clone(), new.name.clone()))
+        .collect()
+}
+
 fn validate_rustfs_peer_dns_label(tenant_name: &str, pool: &Pool) -> Result<(), String> {
     let max_ordinal = pool.servers.saturating_sub(1).max(0);
     let dns_label_len = tenant_name.len() + 1 + pool.name.len() + 1 + ordinal_digits(max_ordinal);
@@ -174,15 +313,275 @@ fn validate_rustfs_peer_dns_label(tenant_name: &str, pool: &Pool) -> Result<(),
     Ok(())
 }
 
+fn validate_existing_claims(pool: &Pool) -> Result<(), String> {
+    let Some(claims) = &pool.persistence.existing_claims else {
+        return Ok(());
+    };
+
+    let expected = i64::from(pool.servers) * i64::from(pool.persistence.volumes_per_server);
+    if claims.len() as i64 != expected {
+        return Err(format!(
+            "pool '{}' persistence.existingClaims must list servers * volumesPerServer = {} claims, got {}",
+            pool.name,
+            expected,
+            claims.len()
+        ));
+    }
+
+    let mut names = HashSet::new();
+    for claim in claims {
+        if claim.is_empty() {
+            return Err(format!(
+                "pool '{}' persistence.existingClaims must not contain empty names",
+                pool.name
+            ));
+        }
+        if !names.insert(claim.as_str()) {
+            return Err(format!(
+                "pool '{}' persistence.existingClaims must be unique: '{}'",
+                pool.name, claim
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_data_source(pool: &Pool) -> Result<(), String> {
+    let Some(data_source) = &pool.persistence.data_source else {
+        return Ok(());
+    };
+
+    if pool.persistence.existing_claims.is_some() || pool.persistence.local_volumes.is_some() {
+        return Err(format!(
+            "pool '{}' persistence.dataSource cannot be combined with existingClaims or localVolumes",
+            pool.name
+        ));
+    }
+
+    let snapshots = &data_source.volume_snapshots;
+    let expected = i64::from(pool.servers) * i64::from(pool.persistence.volumes_per_server);
+    if snapshots.len() as i64 != expected {
+        return Err(format!(
+            "pool '{}' persistence.dataSource.volumeSnapshots must list servers * volumesPerServer = {} snapshots, got {}",
+            pool.name,
+            expected,
+            snapshots.len()
+        ));
+    }
+
+    let mut names = HashSet::new();
+    for snapshot in snapshots {
+        if snapshot.is_empty() {
+            return Err(format!(
+                "pool '{}' persistence.dataSource.volumeSnapshots must not contain empty names",
+                pool.name
+            ));
+        }
+        if !names.insert(snapshot.as_str()) {
+            return Err(format!(
+                "pool '{}' persistence.dataSource.volumeSnapshots must be unique: '{}'",
+                pool.name, snapshot
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// RustFS only stores data in directories, so it cannot use raw block devices yet.
+fn validate_volume_mode(pool: &Pool) -> Result<(), String> {
+    let template_block = pool
+        .persistence
+        .volume_claim_template
+        .as_ref()
+        .and_then(|template| template.volume_mode.as_deref())
+        == Some("Block");
+    if pool.persistence.is_block_mode() || template_block {
+        return Err(format!(
+            "pool '{}' persistence.volumeMode Block is not supported: RustFS needs a filesystem on each data volume",
+            pool.name
+        ));
+    }
+    Ok(())
+}
+
+fn validate_local_volumes(pool: &Pool) -> Result<(), String> {
+    let Some(local) = &pool.persistence.local_volumes else {
+        return Ok(());
+    };
+
+    if pool.persistence.volume_claim_template.is_some()
+        || pool.persistence.existing_claims.is_some()
+    {
+        return Err(format!(
+            "pool '{}' persistence.localVolumes cannot be combined with volumeClaimTemplate or existingClaims",
+            pool.name
+        ));
+    }
+
+    if local.nodes.len() as i64 != i64::from(pool.servers) {
+        return Err(format!(
+            "pool '{}' persistence.localVolumes.nodes must list one entry per server ({}), got {}",
+            pool.name,
+            pool.servers,
+            local.nodes.len()
+        ));
+    }
+
+    let mut disks = HashSet::new();
+    for node in &local.nodes {
+        if node.paths.len() as i64 != i64::from(pool.persistence.volumes_per_server) {
+            return Err(format!(
+                "pool '{}' persistence.localVolumes node '{}' must list volumesPerServer = {} paths, got {}",
+                pool.name,
+                node.node_name,
+                pool.persistence.volumes_per_server,
+                node.paths.len()
+            ));
+        }
+        for path in &node.paths {
+            if !path.starts_with('/') {
+                return Err(format!(
+                    "pool '{}' persistence.localVolumes path '{}' must be absolute",
+                    pool.name, path
+                ));
+            }
+            if !disks.insert((node.node_name.as_str(), path.as_str())) {
+                return Err(format!(
+                    "pool '{}' persistence.localVolumes lists '{}' on node '{}' more than once",
+                    pool.name, path, node.node_name
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_volume_templates(pool: &Pool) -> Result<(), String> {
+    let Some(templates) = &pool.persistence.volume_templates else {
+        return Ok(());
+    };
+
+    if pool.persistence.volume_claim_template.is_some() || pool.persistence.local_volumes.is_some()
+    {
+        return Err(format!(
+            "pool '{}' persistence.volumeTemplates cannot be combined with volumeClaimTemplate or localVolumes",
+            pool.name
+        ));
+    }
+
+    let data_volumes = templates.iter().filter(|t| t.is_data_volume()).count();
+    if data_volumes as i64 != i64::from(pool.persistence.volumes_per_server) {
+        return Err(format!(
+            "pool '{}' persistence.volumeTemplates must define volumesPerServer = {} data volumes (without mountPath), got {}",
+            pool.name, pool.persistence.volumes_per_server, data_volumes
+        ));
+    }
+
+    let mut names = HashSet::new();
+    let mut mount_paths = HashSet::new();
+    for template in templates {
+        if !names.insert(template.name.as_str()) {
+            return Err(format!(
+                "pool '{}' persistence.volumeTemplates names must be unique: '{}'",
+                pool.name, template.name
+            ));
+        }
+        let Some(mount_path) = &template.mount_path else {
+            continue;
+        };
+        if matches!(template.name.as_str(), "logs" | "operator-config")
+            || is_data_claim_template_name(&template.name)
+        {
+            return Err(format!(
+                "pool '{}' auxiliary volume name '{}' is reserved",
+                pool.name, template.name
+            ));
+        }
+        if !mount_path.starts_with('/') {
+            return Err(format!(
+                "pool '{}' auxiliary volume '{}' mountPath must be absolute",
+                pool.name, template.name
+            ));
+        }
+        if !mount_paths.insert(mount_path.trim_end_matches('/')) {
+            return Err(format!(
+                "pool '{}' auxiliary volume mountPath '{}' is used more than once",
+                pool.name, mount_path
+            ));
+        }
+        let data_path = pool
+            .persistence
+            .path
+            .as_deref()
+            .unwrap_or(crate::types::v1alpha1::tenant::DEFAULT_MOUNT_PATH);
+        if mount_paths_overlap(mount_path, data_path) {
+            return Err(format!(
+                "pool '{}' auxiliary volume mountPath '{}' overlaps the data volume path '{}'",
+                pool.name, mount_path, data_path
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Rejects auxiliary volumes mounted at or around the logs volume, which is mounted at
+/// `logs_mount_path` when logs are written to a volume.
+pub fn validate_auxiliary_mounts_against_logs(
+    pools: &[Pool],
+    logs_mount_path: &str,
+) -> Result<(), String> {
+    for pool in pools {
+        for template in pool.persistence.volume_templates.iter().flatten() {
+            let Some(mount_path) = &template.mount_path else {
+                continue;
+            };
+            if mount_paths_overlap(mount_path, logs_mount_path) {
+                return Err(format!(
+                    "pool '{}' auxiliary volume mountPath '{}' overlaps the logs mount path '{}'",
+                    pool.name, mount_path, logs_mount_path
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether two container mount paths are the same or one is nested inside the other.
+fn mount_paths_overlap(a: &str, b: &str) -> bool {
+    let a = a.trim_end_matches('/');
+    let b = b.trim_end_matches('/');
+    let nested = |inner: &str, outer: &str| {
+        inner
+            .strip_prefix(outer)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    };
+    nested(a, b) || nested(b, a)
+}
+
+fn is_data_claim_template_name(name: &str) -> bool {
+    name.strip_prefix("vol-")
+        .is_some_and(|index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+}
+
 fn ordinal_digits(value: i32) -> usize {
     value.to_string().len()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{validate_pool_collection, validate_pool_name};
-    use crate::types::v1alpha1::persistence::PersistenceConfig;
+    use super::{
+        default_erasure_parity, detect_pool_renames, validate_auxiliary_mounts_against_logs,
+        validate_pool_collection, validate_pool_name, validate_production_drive_count,
+    };
+    use crate::types::v1alpha1::persistence::{
+        LocalVolumeNode, LocalVolumesConfig, PersistenceConfig, VolumeTemplate,
+    };
     use crate::types::v1alpha1::pool::Pool;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 
     #[test]
     fn validates_pool_name_as_rfc1123_label() {
@@ -205,6 +604,47 @@ mod tests {
         assert!(validate_pool_collection("tenant", &pools).is_ok());
     }
 
+    #[test]
+    fn detects_positional_pool_renames() {
+        let existing = vec![test_pool("pool-0", 4, 2), test_pool("pool-1", 4, 2)];
+
+        let renamed = vec![test_pool("pool-0", 4, 2), test_pool("archive", 4, 2)];
+        assert_eq!(
+            detect_pool_renames(&existing, &renamed),
+            vec![("pool-1".to_string(), "archive".to_string())]
+        );
+
+        let expanded = vec![
+            test_pool("pool-0", 4, 2),
+            test_pool("pool-1", 4, 2),
+            test_pool("pool-2", 4, 2),
+        ];
+        assert!(detect_pool_renames(&existing, &expanded).is_empty());
+
+        let reordered = vec![test_pool("pool-1", 4, 2), test_pool("pool-0", 4, 2)];
+        assert!(detect_pool_renames(&existing, &reordered).is_empty());
+    }
+
+    #[test]
+    fn paused_defaults_to_false() {
+        let pool = serde_json::from_value::<Pool>(serde_json::json!({
+            "name": "pool-0",
+            "servers": 4,
+            "persistence": { "volumesPerServer": 4 }
+        }));
+        assert!(pool.is_ok_and(|pool| !pool.is_paused()));
+
+        let mut pool = test_pool("pool-0", 4, 4);
+        pool.paused = Some(true);
+        assert!(pool.is_paused());
+        assert_eq!(
+            serde_json::to_value(&pool)
+                .ok()
+                .map(|value| value["paused"].clone()),
+            Some(serde_json::Value::Bool(true))
+        );
+    }
+
     #[test]
     fn rejects_duplicate_pool_names() {
         let pools = vec![test_pool("pool-0", 1, 1), test_pool("pool-0", 1, 2)];
@@ -228,6 +668,231 @@ mod tests {
         assert!(err.contains("RustFS peer DNS label too long"));
     }
 
+    #[test]
+    fn production_profile_requires_four_drives_per_pool() {
+        assert!(validate_production_drive_count(&[test_pool("pool-0", 2, 2)]).is_ok());
+        let err =
+            validate_production_drive_count(&[test_pool("pool-0", 4, 4), test_pool("dev", 1, 1)])
+                .err()
+                .unwrap_or_default();
+        assert!(err.contains("pool 'dev' has 1 drive(s)"));
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.pools = vec![test_pool("dev", 1, 1)];
+        assert!(tenant.validate_pools().is_err());
+        tenant.spec.profile = Some(crate::types::v1alpha1::profile::TenantProfile::Standalone);
+        assert!(tenant.validate_pools().is_ok());
+    }
+
+    #[test]
+    fn existing_claims_must_cover_every_server_volume_once() {
+        let mut pool = test_pool("pool-0", 2, 2);
+        pool.persistence.existing_claims = Some(
+            ["data-a", "data-b", "data-c", "data-d"]
+                .map(String::from)
+                .to_vec(),
+        );
+        assert!(validate_pool_collection("tenant", std::slice::from_ref(&pool)).is_ok());
+
+        pool.persistence.existing_claims = Some(vec!["data-a".to_string(); 3]);
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("servers * volumesPerServer = 4 claims, got 3"));
+
+        pool.persistence.existing_claims = Some(vec!["data-a".to_string(); 4]);
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("existingClaims must be unique"));
+    }
+
+    #[test]
+    fn data_source_must_list_one_snapshot_per_volume() {
+        use crate::types::v1alpha1::persistence::SnapshotDataSource;
+
+        let mut pool = test_pool("pool-0", 2, 2);
+        pool.persistence.data_source = Some(SnapshotDataSource {
+            volume_snapshots: ["snap-a", "snap-b", "snap-c", "snap-d"]
+                .map(String::from)
+                .to_vec(),
+        });
+        assert!(validate_pool_collection("tenant", std::slice::from_ref(&pool)).is_ok());
+
+        pool.persistence.data_source = Some(SnapshotDataSource {
+            volume_snapshots: vec!["snap-a".to_string(); 4],
+        });
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("volumeSnapshots must be unique"));
+
+        pool.persistence.data_source = Some(SnapshotDataSource {
+            volume_snapshots: vec!["snap-a".to_string()],
+        });
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("servers * volumesPerServer = 4 snapshots, got 1"));
+
+        pool.persistence.data_source = Some(SnapshotDataSource {
+            volume_snapshots: ["snap-a", "snap-b", "snap-c", "snap-d"]
+                .map(String::from)
+                .to_vec(),
+        });
+        pool.persistence.existing_claims = Some(
+            ["data-a", "data-b", "data-c", "data-d"]
+                .map(String::from)
+                .to_vec(),
+        );
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("cannot be combined"));
+    }
+
+    #[test]
+    fn local_volumes_must_map_every_server_disk() {
+        let mut pool = test_pool("pool-0", 2, 1);
+        pool.persistence.local_volumes = Some(LocalVolumesConfig {
+            capacity: Quantity("1Ti".to_string()),
+            nodes: vec![local_node("node-a", &["/mnt/disk0"])],
+            ..Default::default()
+        });
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("one entry per server (2), got 1"));
+
+        if let Some(local) = pool.persistence.local_volumes.as_mut() {
+            local.nodes.push(local_node("node-b", &["mnt/disk0"]));
+        }
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("must be absolute"));
+
+        if let Some(local) = pool.persistence.local_volumes.as_mut() {
+            local.nodes[1] = local_node("node-b", &["/mnt/disk0"]);
+        }
+        assert!(validate_pool_collection("tenant", std::slice::from_ref(&pool)).is_ok());
+    }
+
+    #[test]
+    fn volume_templates_require_one_data_volume_per_slot() {
+        let mut pool = test_pool("pool-0", 4, 2);
+        pool.persistence.volume_templates = Some(vec![
+            volume_template("fast", None),
+            volume_template("capacity", None),
+            volume_template("cache", Some("/cache")),
+        ]);
+        assert!(validate_pool_collection("tenant", std::slice::from_ref(&pool)).is_ok());
+
+        pool.persistence.volumes_per_server = 3;
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("volumesPerServer = 3 data volumes"));
+
+        pool.persistence.volumes_per_server = 2;
+        pool.persistence.volume_templates = Some(vec![
+            volume_template("fast", None),
+            volume_template("capacity", None),
+            volume_template("vol-0", Some("/cache")),
+        ]);
+        let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+            .err()
+            .unwrap_or_default();
+        assert!(err.contains("is reserved"));
+
+        for mount_path in ["/data", "/data/rustfs0", "/data/cache", "/"] {
+            pool.persistence.volume_templates = Some(vec![
+                volume_template("fast", None),
+                volume_template("capacity", None),
+                volume_template("cache", Some(mount_path)),
+            ]);
+            let err = validate_pool_collection("tenant", std::slice::from_ref(&pool))
+                .err()
+                .unwrap_or_default();
+            assert!(
+                err.contains("overlaps the data volume path '/data'"),
+                "{mount_path} must be rejected"
+            );
+        }
+
+        pool.persistence.volume_templates = Some(vec![
+            volume_template("fast", None),
+            volume_template("capacity", None),
+            volume_template("cache", Some("/database")),
+        ]);
+        assert!(validate_pool_collection("tenant", std::slice::from_ref(&pool)).is_ok());
+        assert!(
+            validate_auxiliary_mounts_against_logs(std::slice::from_ref(&pool), "/logs").is_ok()
+        );
+
+        for mount_path in ["/logs", "/logs/archive"] {
+            pool.persistence.volume_templates = Some(vec![
+                volume_template("fast", None),
+                volume_template("capacity", None),
+                volume_template("cache", Some(mount_path)),
+            ]);
+            let err = validate_auxiliary_mounts_against_logs(std::slice::from_ref(&pool), "/logs")
+                .err()
+                .unwrap_or_default();
+            assert!(
+                err.contains("overlaps the logs mount path '/logs'"),
+                "{mount_path} must be rejected"
+            );
+        }
+    }
+
+    fn volume_template(name: &str, mount_path: Option<&str>) -> VolumeTemplate {
+        VolumeTemplate {
+            name: name.to_string(),
+            size: Quantity("10Gi".to_string()),
+            storage_class_name: None,
+            mount_path: mount_path.map(str::to_string),
+        }
+    }
+
+    fn local_node(node_name: &str, paths: &[&str]) -> LocalVolumeNode {
+        LocalVolumeNode {
+            node_name: node_name.to_string(),
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn block_volume_mode_is_rejected() {
+        let mut pool = test_pool("pool-0", 4, 2);
+        pool.persistence.volume_mode = Some(crate::types::v1alpha1::k8s::VolumeMode::Block);
+        assert!(
+            validate_pool_collection("tenant", std::slice::from_ref(&pool))
+                .is_err_and(|message| message.contains("volumeMode Block is not supported"))
+        );
+
+        pool.persistence.volume_mode = Some(crate::types::v1alpha1::k8s::VolumeMode::Filesystem);
+        assert!(validate_pool_collection("tenant", std::slice::from_ref(&pool)).is_ok());
+
+        pool.persistence.volume_claim_template =
+            Some(k8s_openapi::api::core::v1::PersistentVolumeClaimSpec {
+                volume_mode: Some("Block".to_string()),
+                ..Default::default()
+            });
+        assert!(validate_pool_collection("tenant", &[pool]).is_err());
+    }
+
+    #[test]
+    fn erasure_set_size_is_largest_symmetric_divisor() {
+        assert_eq!(test_pool("p", 1, 1).erasure_set_drive_count(), Some(1));
+        assert_eq!(test_pool("p", 4, 4).erasure_set_drive_count(), Some(16));
+        assert_eq!(test_pool("p", 6, 4).erasure_set_drive_count(), Some(12));
+        assert_eq!(test_pool("p", 3, 8).erasure_set_drive_count(), Some(12));
+        assert_eq!(test_pool("p", 17, 1).erasure_set_drive_count(), None);
+        assert_eq!(default_erasure_parity(16), 4);
+        assert_eq!(default_erasure_parity(4), 2);
+    }
+
     fn test_pool(name: &str, servers: i32, volumes_per_server: i32) -> Pool {
         Pool {
             name: name.to_string(),
@@ -236,6 +901,11 @@ mod tests {
                 volumes_per_server,
                 ..Default::default()
             },
+            env: Vec::new(),
+            service_account_name: None,
+            pod_metadata: None,
+            pod_deletion_policy_when_node_is_down: None,
+            paused: None,
             scheduling: Default::default(),
         }
     }
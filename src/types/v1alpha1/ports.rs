@@ -0,0 +1,105 @@
+//  Copyright 2025 RustFS Team
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_S3_PORT: i32 = 9000;
+pub const DEFAULT_CONSOLE_PORT: i32 = 9001;
+
+/// CEL rule on the Tenant spec keeping the effective S3 port unchanged. It sits on the spec
+/// rather than on `s3Port` because transition rules do not run when the field, or `ports`,
+/// was unset before.
+pub const S3_PORT_IMMUTABLE_RULE: &str = "(has(self.ports) && has(self.ports.s3Port) ? self.ports.s3Port : 9000) == (has(oldSelf.ports) && has(oldSelf.ports.s3Port) ? oldSelf.ports.s3Port : 9000)";
+
+/// Ports RustFS listens on. The same values are used for the container ports, the
+/// `RUSTFS_ADDRESS` and `RUSTFS_CONSOLE_ADDRESS` env vars, the probes, the Services, and the
+/// peer endpoints in `RUSTFS_VOLUMES`.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortsConfig {
+    /// S3 API and internode port. Defaults to 9000. Immutable, because every peer endpoint
+    /// embeds it and pods on the old and new port cannot form one cluster.
+    #[schemars(range(min = 1, max = 65535))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub s3_port: Option<i32>,
+
+    /// Console port. Defaults to 9001.
+    #[schemars(range(min = 1, max = 65535))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub console_port: Option<i32>,
+}
+
+impl PortsConfig {
+    pub fn s3_port(&self) -> i32 {
+        self.s3_port.unwrap_or(DEFAULT_S3_PORT)
+    }
+
+    pub fn console_port(&self) -> i32 {
+        self.console_port.unwrap_or(DEFAULT_CONSOLE_PORT)
+    }
+
+    /// Checks the effective ports, including defaults for unset fields.
+    pub fn validate(&self) -> Result<(), String> {
+        for (field, port) in [
+            ("s3Port", self.s3_port()),
+            ("consolePort", self.console_port()),
+        ] {
+            if !(1..=65535).contains(&port) {
+                return Err(format!("{field} must be between 1 and 65535, got {port}"));
+            }
+        }
+        if self.s3_port() == self.console_port() {
+            return Err(format!(
+                "s3Port and consolePort must differ, both are {}",
+                self.s3_port()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_checks_effective_ports() {
+        assert!(PortsConfig::default().validate().is_ok());
+        assert!(
+            PortsConfig {
+                s3_port: Some(7000),
+                console_port: Some(7001),
+            }
+            .validate()
+            .is_ok()
+        );
+        assert!(
+            PortsConfig {
+                s3_port: None,
+                console_port: Some(DEFAULT_S3_PORT),
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            PortsConfig {
+                s3_port: Some(70000),
+                console_port: None,
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
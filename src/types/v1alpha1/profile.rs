@@ -0,0 +1,122 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Minimum drives (`servers * volumesPerServer`) per pool under the production profile.
+pub const MIN_PRODUCTION_POOL_DRIVES: i32 = 4;
+
+/// Defaults and validation strictness applied to a Tenant.
+///
+/// - production: every pool needs at least four drives (default)
+/// - standalone: any layout, including one server with one volume, no default zone spread, no
+///   update deferral for PodDisruptionBudget disruptions, and small default resources
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename_all = "lowercase")]
+pub enum TenantProfile {
+    #[default]
+    Production,
+    Standalone,
+}
+
+impl std::fmt::Display for TenantProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TenantProfile::Production => write!(f, "production"),
+            TenantProfile::Standalone => write!(f, "standalone"),
+        }
+    }
+}
+
+/// Container resources for standalone pools that set none: enough for kind or minikube.
+pub(crate) fn standalone_resources() -> corev1::ResourceRequirements {
+    corev1::ResourceRequirements {
+        requests: Some(BTreeMap::from([
+            ("cpu".to_string(), Quantity("100m".to_string())),
+            ("memory".to_string(), Quantity("256Mi".to_string())),
+        ])),
+        limits: Some(BTreeMap::from([(
+            "memory".to_string(),
+            Quantity("1Gi".to_string()),
+        )])),
+        ..Default::default()
+    }
+}
+
+/// Curated RustFS runtime tuning applied through `RUSTFS_*` env vars. Explicit `env` entries on
+/// the Tenant or pool replace individual preset values.
+///
+/// - balanced: moderate object cache and disk read concurrency
+/// - throughput: large object cache and high read concurrency for large sequential workloads
+/// - low-memory: object cache off and few runtime threads for small nodes
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename_all = "kebab-case")]
+pub enum PerformanceProfile {
+    Balanced,
+    Throughput,
+    LowMemory,
+}
+
+impl std::fmt::Display for PerformanceProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PerformanceProfile::Balanced => write!(f, "balanced"),
+            PerformanceProfile::Throughput => write!(f, "throughput"),
+            PerformanceProfile::LowMemory => write!(f, "low-memory"),
+        }
+    }
+}
+
+impl PerformanceProfile {
+    /// The preset's `RUSTFS_*` variables, in a stable order.
+    pub fn env_presets(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            PerformanceProfile::Balanced => &[
+                ("RUSTFS_OBJECT_CACHE_ENABLE", "true"),
+                ("RUSTFS_OBJECT_CACHE_CAPACITY_MB", "512"),
+                ("RUSTFS_OBJECT_MAX_CONCURRENT_DISK_READS", "64"),
+            ],
+            PerformanceProfile::Throughput => &[
+                ("RUSTFS_OBJECT_CACHE_ENABLE", "true"),
+                ("RUSTFS_OBJECT_CACHE_CAPACITY_MB", "2048"),
+                ("RUSTFS_OBJECT_MAX_CONCURRENT_DISK_READS", "256"),
+                ("RUSTFS_RUNTIME_MAX_BLOCKING_THREADS", "1024"),
+            ],
+            PerformanceProfile::LowMemory => &[
+                ("RUSTFS_OBJECT_CACHE_ENABLE", "false"),
+                ("RUSTFS_OBJECT_MAX_CONCURRENT_DISK_READS", "16"),
+                ("RUSTFS_RUNTIME_WORKER_THREADS", "2"),
+                ("RUSTFS_RUNTIME_MAX_BLOCKING_THREADS", "64"),
+            ],
+        }
+    }
+
+    pub(crate) fn env(self) -> Vec<corev1::EnvVar> {
+        self.env_presets()
+            .iter()
+            .map(|(name, value)| corev1::EnvVar {
+                name: (*name).to_string(),
+                value: Some((*value).to_string()),
+                ..Default::default()
+            })
+            .collect()
+    }
+}
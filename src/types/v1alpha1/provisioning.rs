@@ -25,6 +25,10 @@ pub(crate) const MAX_POLICIES_PER_USER: u32 = 64;
 pub(crate) const MAX_USER_POLICY_NAME_LENGTH: u32 = 253;
 pub(crate) const MIN_BUCKET_NAME_LENGTH: u32 = 3;
 pub(crate) const MAX_BUCKET_NAME_LENGTH: u32 = 63;
+pub(crate) const MAX_LIFECYCLE_POLICY_NAME_LENGTH: u32 = 128;
+pub(crate) const MAX_LIFECYCLE_RULE_ID_LENGTH: u32 = 126;
+pub(crate) const MAX_LIFECYCLE_RULES_PER_POLICY: u32 = 100;
+pub(crate) const MAX_LIFECYCLE_SELECTOR_BUCKETS: u32 = 1024;
 
 #[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, ToSchema, Default, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
@@ -109,3 +113,144 @@ impl ProvisioningBucket {
         self.object_lock.unwrap_or(false)
     }
 }
+
+/// Lifecycle (ILM) rules applied to every bucket the selector matches.
+///
+/// Each rule is stored on the bucket with the ID `<policy name>.<rule id>`. The operator owns
+/// the rules whose ID starts with the name of a policy in the spec; other rules on the bucket are
+/// left in place.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecyclePolicy {
+    #[schemars(length(min = 1, max = MAX_LIFECYCLE_POLICY_NAME_LENGTH), regex(pattern = r"^\S+$"))]
+    pub name: String,
+
+    #[x_kube(validation = Rule::new("(has(self.names) && self.names.size() > 0) || has(self.prefix)").message("bucketSelector must set names or prefix"))]
+    pub bucket_selector: BucketSelector,
+
+    #[schemars(
+        length(min = 1, max = MAX_LIFECYCLE_RULES_PER_POLICY),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["id"])
+    )]
+    #[x_kube(validation = Rule::new("self.all(r, has(r.expiration) || has(r.noncurrentVersionExpiration) || has(r.transition))").message("each lifecycle rule needs expiration, noncurrentVersionExpiration, or transition"))]
+    pub rules: Vec<LifecycleRule>,
+
+    /// `Retain` leaves the rules on the buckets when the policy is removed from the spec.
+    #[serde(default, skip_serializing_if = "is_retain")]
+    pub deletion_policy: ProvisioningDeletionPolicy,
+}
+
+/// Buckets a lifecycle policy applies to: the listed names plus every existing bucket whose name
+/// starts with `prefix`.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketSelector {
+    /// Buckets that must exist; a missing one fails the policy.
+    #[schemars(
+        length(max = MAX_LIFECYCLE_SELECTOR_BUCKETS),
+        inner(length(min = MIN_BUCKET_NAME_LENGTH, max = MAX_BUCKET_NAME_LENGTH)),
+        extend("x-kubernetes-list-type" = "set")
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub names: Vec<String>,
+
+    /// Bucket name prefix. Matching buckets are looked up on every reconcile, so buckets created
+    /// later pick up the policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleRule {
+    #[schemars(length(min = 1, max = MAX_LIFECYCLE_RULE_ID_LENGTH), regex(pattern = r"^\S+$"))]
+    pub id: String,
+
+    /// Object key prefix the rule applies to. Unset applies it to every object in the bucket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// Defaults to true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<LifecycleExpiration>,
+
+    /// Expiration of noncurrent object versions in versioned buckets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub noncurrent_version_expiration: Option<NoncurrentVersionExpiration>,
+
+    /// Transition to a remote tier configured in RustFS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transition: Option<LifecycleTransition>,
+}
+
+impl LifecycleRule {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleExpiration {
+    /// Days after object creation.
+    #[schemars(range(min = 1))]
+    pub days: u32,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NoncurrentVersionExpiration {
+    /// Days after a version becomes noncurrent.
+    #[schemars(range(min = 1))]
+    pub noncurrent_days: u32,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleTransition {
+    /// Days after object creation.
+    pub days: u32,
+
+    /// Name of the RustFS tier objects move to.
+    #[schemars(length(min = 1))]
+    pub storage_class: String,
+}
+
+/// Resources created once, after the Tenant first becomes Ready.
+///
+/// Unlike `spec.policies`, `spec.users`, and `spec.buckets`, bootstrap items are not kept in
+/// sync: once the `Bootstrapped` condition is `True`, later edits are ignored.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, ToSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BootstrapSpec {
+    #[schemars(
+        length(max = crate::types::v1alpha1::tenant::MAX_TENANT_POLICIES),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["name"])
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policies: Vec<ProvisioningPolicy>,
+
+    #[schemars(
+        length(max = crate::types::v1alpha1::tenant::MAX_TENANT_USERS),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["name"])
+    )]
+    #[x_kube(validation = Rule::new("self.all(x, has(x.policies) && x.policies.size() > 0)").message("user policies must contain at least one policy"))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<ProvisioningUser>,
+
+    #[schemars(
+        length(max = crate::types::v1alpha1::tenant::MAX_TENANT_BUCKETS),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["name"])
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buckets: Vec<ProvisioningBucket>,
+}
+
+impl BootstrapSpec {
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty() && self.users.is_empty() && self.buckets.is_empty()
+    }
+}
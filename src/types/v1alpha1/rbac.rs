@@ -0,0 +1,154 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::api::rbac::v1 as rbacv1;
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+
+/// Role generated for the Tenant ServiceAccount.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantRbacConfig {
+    /// Create the `{tenant}-role` Role and its RoleBinding. Defaults to true.
+    /// Set to false where Roles are managed outside the operator; the ServiceAccount is still created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_role: Option<bool>,
+
+    /// Read-only rules appended to the generated Role. Only `get`, `list`, and `watch` are
+    /// allowed; wildcards, the `rbac.authorization.k8s.io` group, `serviceaccounts/token`, the
+    /// exec, attach, port-forward, and proxy subresources, and `secrets` without
+    /// `resourceNames` are rejected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_rules: Vec<rbacv1::PolicyRule>,
+}
+
+/// The only verbs `extraRules` may grant. Writes to pods, workloads, or ServiceAccounts would let
+/// the RustFS pods run code under any ServiceAccount in the namespace.
+const ALLOWED_EXTRA_RULE_VERBS: &[&str] = &["get", "list", "watch"];
+
+/// Subresources that hand out credentials or reach into another workload, even with `get`.
+const DENIED_EXTRA_RULE_RESOURCES: &[&str] = &[
+    "serviceaccounts/token",
+    "pods/exec",
+    "pods/attach",
+    "pods/portforward",
+    "pods/proxy",
+    "services/proxy",
+    "nodes/proxy",
+];
+
+impl TenantRbacConfig {
+    pub fn create_role(&self) -> bool {
+        self.create_role.unwrap_or(true)
+    }
+
+    /// Rejects `extraRules` that would let the RustFS pods escalate: anything but reads,
+    /// wildcards, RBAC objects, ServiceAccount tokens, access into other pods, and reading every
+    /// Secret. The operator grants these rules with its own ClusterRole, which is broader than
+    /// what a Tenant author usually holds.
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, rule) in self.extra_rules.iter().enumerate() {
+            let field = format!("rbac.extraRules[{index}]");
+            let groups = rule.api_groups.as_deref().unwrap_or_default();
+            let resources = rule.resources.as_deref().unwrap_or_default();
+            if groups.iter().any(|group| group == "*")
+                || resources.iter().any(|resource| resource.contains('*'))
+                || rule.verbs.iter().any(|verb| verb == "*")
+            {
+                return Err(format!("{field} must not use wildcards"));
+            }
+            if groups
+                .iter()
+                .any(|group| group == "rbac.authorization.k8s.io")
+            {
+                return Err(format!(
+                    "{field} must not grant access to rbac.authorization.k8s.io"
+                ));
+            }
+            if rule
+                .non_resource_urls
+                .as_ref()
+                .is_some_and(|urls| !urls.is_empty())
+            {
+                return Err(format!("{field} must not set nonResourceURLs"));
+            }
+            if let Some(verb) = rule
+                .verbs
+                .iter()
+                .find(|verb| !ALLOWED_EXTRA_RULE_VERBS.contains(&verb.as_str()))
+            {
+                return Err(format!(
+                    "{field} must only grant get, list, or watch, not {verb}"
+                ));
+            }
+            if let Some(resource) = resources
+                .iter()
+                .find(|resource| DENIED_EXTRA_RULE_RESOURCES.contains(&resource.as_str()))
+            {
+                return Err(format!("{field} must not grant access to {resource}"));
+            }
+            if groups.iter().any(String::is_empty)
+                && resources.iter().any(|resource| resource == "secrets")
+                && rule
+                    .resource_names
+                    .as_ref()
+                    .is_none_or(|names| names.is_empty())
+            {
+                return Err(format!(
+                    "{field} must name the secrets it grants in resourceNames"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Projected ServiceAccount token mounted into RustFS pods, for external IAM such as
+/// EKS IRSA or GKE workload identity.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceAccountTokenProjection {
+    /// Audience the token is issued for, e.g. `sts.amazonaws.com`.
+    #[schemars(length(min = 1))]
+    pub audience: String,
+
+    /// Requested token lifetime. The kubelet rotates the token before it expires.
+    /// Defaults to 3600; Kubernetes requires at least 600.
+    #[schemars(range(min = MIN_TOKEN_EXPIRATION_SECONDS))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration_seconds: Option<i64>,
+
+    /// Directory the token is mounted into; the token file is `token`.
+    /// Defaults to `/var/run/secrets/tokens`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mount_path: Option<String>,
+}
+
+pub const MIN_TOKEN_EXPIRATION_SECONDS: i64 = 600;
+const DEFAULT_TOKEN_EXPIRATION_SECONDS: i64 = 3600;
+const DEFAULT_TOKEN_MOUNT_PATH: &str = "/var/run/secrets/tokens";
+
+impl ServiceAccountTokenProjection {
+    pub fn expiration_seconds(&self) -> i64 {
+        self.expiration_seconds
+            .unwrap_or(DEFAULT_TOKEN_EXPIRATION_SECONDS)
+    }
+
+    pub fn mount_path(&self) -> &str {
+        self.mount_path
+            .as_deref()
+            .filter(|path| !path.is_empty())
+            .unwrap_or(DEFAULT_TOKEN_MOUNT_PATH)
+    }
+}
@@ -11,10 +11,16 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+pub mod additional_resource;
 pub mod certificate;
+pub mod federation;
+pub mod health;
+pub mod history;
 pub mod pool;
 pub mod provisioning;
+pub mod rebalance;
 pub mod state;
+pub mod upgrade;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -31,6 +37,15 @@ pub enum ConditionType {
     PoolsReady,
     WorkloadsReady,
     ProvisioningReady,
+    Bootstrapped,
+    /// True while a Tenant pod cannot pull its image.
+    ImagePullFailed,
+    /// True while a Tenant container is in CrashLoopBackOff.
+    PodsCrashLooping,
+    /// True once the S3 check of `spec.verification` passed for the current generation.
+    Verified,
+    /// True while StatefulSet updates wait for an ongoing voluntary disruption to finish.
+    DeferredDueToDisruption,
 }
 
 impl ConditionType {
@@ -46,6 +61,11 @@ impl ConditionType {
             Self::PoolsReady => "PoolsReady",
             Self::WorkloadsReady => "WorkloadsReady",
             Self::ProvisioningReady => "ProvisioningReady",
+            Self::Bootstrapped => "Bootstrapped",
+            Self::ImagePullFailed => "ImagePullFailed",
+            Self::PodsCrashLooping => "PodsCrashLooping",
+            Self::Verified => "Verified",
+            Self::DeferredDueToDisruption => "DeferredDueToDisruption",
         }
     }
 
@@ -61,6 +81,11 @@ impl ConditionType {
             Self::PoolsReady,
             Self::WorkloadsReady,
             Self::ProvisioningReady,
+            Self::Bootstrapped,
+            Self::ImagePullFailed,
+            Self::PodsCrashLooping,
+            Self::Verified,
+            Self::DeferredDueToDisruption,
         ]
         .iter()
         .position(|condition_type| condition_type.as_str() == type_)
@@ -107,20 +132,65 @@ impl CurrentState {
     }
 }
 
+/// Why the controller scheduled the next reconcile, surfaced as `status.requeueReason`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequeueReason {
+    PoolLifecycle,
+    ImagePullBackOff,
+    CrashLoopBackOff,
+    DisruptionInProgress,
+    RolloutInProgress,
+    VerificationRetry,
+    MirrorResync,
+    RebalanceInProgress,
+    PeriodicResync,
+    ErrorBackoff,
+    WaitingForChange,
+    MaintenanceMode,
+}
+
+impl RequeueReason {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PoolLifecycle => "PoolLifecycle",
+            Self::ImagePullBackOff => "ImagePullBackOff",
+            Self::CrashLoopBackOff => "CrashLoopBackOff",
+            Self::DisruptionInProgress => "DisruptionInProgress",
+            Self::RolloutInProgress => "RolloutInProgress",
+            Self::VerificationRetry => "VerificationRetry",
+            Self::MirrorResync => "MirrorResync",
+            Self::RebalanceInProgress => "RebalanceInProgress",
+            Self::PeriodicResync => "PeriodicResync",
+            Self::ErrorBackoff => "ErrorBackoff",
+            Self::WaitingForChange => "WaitingForChange",
+            Self::MaintenanceMode => "MaintenanceMode",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Reason {
     ReconcileStarted,
     ReconcileSucceeded,
     InvalidTenantName,
     InvalidPoolSpec,
+    InvalidPortSpec,
+    InvalidRbacSpec,
+    ProtectedEnvOverride,
+    NamespaceTenantConflict,
+    NamespaceNotAllowed,
     ImmutableFieldModified,
     CredentialSecretNotFound,
     CredentialSecretMissingKey,
     CredentialSecretInvalidEncoding,
     CredentialSecretTooShort,
+    WaitingForCredentials,
     KmsSecretNotFound,
     KmsSecretMissingKey,
     KmsConfigInvalid,
+    ExistingHeadlessServiceInvalid,
+    ExistingClaimUnavailable,
+    SnapshotSourceUnavailable,
     TlsDisabled,
     TlsConfigured,
     CertManagerCrdMissing,
@@ -146,8 +216,15 @@ pub enum Reason {
     PoolDecommissionFailed,
     StatefulSetApplyFailed,
     StatefulSetUpdateValidationFailed,
+    UpgradeBlocked,
     RolloutInProgress,
+    DisruptionInProgress,
+    NoDisruption,
     PodsNotReady,
+    ImagePullBackOff,
+    ImagesPulled,
+    CrashLoopBackOff,
+    ContainersRunning,
     PoolDegraded,
     ProvisioningConfigured,
     ProvisioningPending,
@@ -163,9 +240,13 @@ pub enum Reason {
     UserPolicySetFailed,
     BucketCreateFailed,
     BucketObjectLockConflict,
+    LifecyclePolicyApplyFailed,
+    VerificationSucceeded,
+    VerificationFailed,
     KubernetesApiError,
     StatusPatchFailed,
     ObservedGenerationStale,
+    MaintenanceMode,
 }
 
 impl Reason {
@@ -175,14 +256,23 @@ impl Reason {
             Self::ReconcileSucceeded => "ReconcileSucceeded",
             Self::InvalidTenantName => "InvalidTenantName",
             Self::InvalidPoolSpec => "InvalidPoolSpec",
+            Self::InvalidPortSpec => "InvalidPortSpec",
+            Self::InvalidRbacSpec => "InvalidRbacSpec",
+            Self::ProtectedEnvOverride => "ProtectedEnvOverride",
+            Self::NamespaceTenantConflict => "NamespaceTenantConflict",
+            Self::NamespaceNotAllowed => "NamespaceNotAllowed",
             Self::ImmutableFieldModified => "ImmutableFieldModified",
             Self::CredentialSecretNotFound => "CredentialSecretNotFound",
             Self::CredentialSecretMissingKey => "CredentialSecretMissingKey",
             Self::CredentialSecretInvalidEncoding => "CredentialSecretInvalidEncoding",
             Self::CredentialSecretTooShort => "CredentialSecretTooShort",
+            Self::WaitingForCredentials => "WaitingForCredentials",
             Self::KmsSecretNotFound => "KmsSecretNotFound",
             Self::KmsSecretMissingKey => "KmsSecretMissingKey",
             Self::KmsConfigInvalid => "KmsConfigInvalid",
+            Self::ExistingHeadlessServiceInvalid => "ExistingHeadlessServiceInvalid",
+            Self::ExistingClaimUnavailable => "ExistingClaimUnavailable",
+            Self::SnapshotSourceUnavailable => "SnapshotSourceUnavailable",
             Self::TlsDisabled => "TlsDisabled",
             Self::TlsConfigured => "TlsConfigured",
             Self::CertManagerCrdMissing => "CertManagerCrdMissing",
@@ -208,8 +298,15 @@ impl Reason {
             Self::PoolDecommissionFailed => "PoolDecommissionFailed",
             Self::StatefulSetApplyFailed => "StatefulSetApplyFailed",
             Self::StatefulSetUpdateValidationFailed => "StatefulSetUpdateValidationFailed",
+            Self::UpgradeBlocked => "UpgradeBlocked",
             Self::RolloutInProgress => "RolloutInProgress",
+            Self::DisruptionInProgress => "DisruptionInProgress",
+            Self::NoDisruption => "NoDisruption",
             Self::PodsNotReady => "PodsNotReady",
+            Self::ImagePullBackOff => "ImagePullBackOff",
+            Self::ImagesPulled => "ImagesPulled",
+            Self::CrashLoopBackOff => "CrashLoopBackOff",
+            Self::ContainersRunning => "ContainersRunning",
             Self::PoolDegraded => "PoolDegraded",
             Self::ProvisioningConfigured => "ProvisioningConfigured",
             Self::ProvisioningPending => "ProvisioningPending",
@@ -225,9 +322,13 @@ impl Reason {
             Self::UserPolicySetFailed => "UserPolicySetFailed",
             Self::BucketCreateFailed => "BucketCreateFailed",
             Self::BucketObjectLockConflict => "BucketObjectLockConflict",
+            Self::LifecyclePolicyApplyFailed => "LifecyclePolicyApplyFailed",
+            Self::VerificationSucceeded => "VerificationSucceeded",
+            Self::VerificationFailed => "VerificationFailed",
             Self::KubernetesApiError => "KubernetesApiError",
             Self::StatusPatchFailed => "StatusPatchFailed",
             Self::ObservedGenerationStale => "ObservedGenerationStale",
+            Self::MaintenanceMode => "MaintenanceMode",
         }
     }
 }
@@ -270,20 +371,73 @@ pub struct Condition {
 #[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Status {
+    /// Summary of the conditions written by the last reconcile.
     pub current_state: String,
 
+    /// Lifecycle phase derived from `currentState` and the previous phase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lifecycle: Option<state::LifecycleStatus>,
+
     pub available_replicas: i32,
 
+    /// Ready pods over all pods across all pools, e.g. `3/4`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready: Option<String>,
+
+    /// Pods across all pools, ready or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_replicas: Option<i32>,
+
+    /// Number of pools with a status entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_count: Option<i32>,
+
+    /// Raw capacity last reported by the RustFS admin API, e.g. `4Ti`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<String>,
+
     pub pools: Vec<pool::Pool>,
 
     /// The generation observed by the operator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
 
+    /// Version of the operator that last reconciled this Tenant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator_version: Option<String>,
+
+    /// SHA-256 of the Tenant spec last applied to child resources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub observed_spec_hash: Option<String>,
+
+    /// RFC 3339 time of the last reconcile that updated this status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reconcile_time: Option<String>,
+
+    /// Duration of the last successful reconcile, e.g. `1.250s`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reconcile_duration: Option<String>,
+
+    /// Failed reconciles since the operator process started
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconcile_error_count: Option<u64>,
+
+    /// RFC 3339 time of the next scheduled reconcile; unset while waiting for a change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_reconcile_time: Option<String>,
+
+    /// Why the next reconcile is scheduled, e.g. `RolloutInProgress` or `ErrorBackoff`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requeue_reason: Option<String>,
+
     /// Kubernetes standard conditions
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub conditions: Vec<Condition>,
 
+    /// Condition status transitions of the last 7 days, oldest first, capped at 50 entries.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub condition_history: Vec<history::ConditionTransition>,
+
     #[serde(default, skip_serializing_if = "certificate::Status::is_empty")]
     pub certificates: certificate::Status,
 
@@ -292,9 +446,43 @@ pub struct Status {
         skip_serializing_if = "provisioning::ProvisioningStatus::is_empty"
     )]
     pub provisioning: provisioning::ProvisioningStatus,
+
+    /// Canary image upgrade progress, when `spec.upgradeStrategy.mode` is `Canary`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upgrade: Option<upgrade::UpgradeStatus>,
+
+    /// Sync results for `spec.federation.mirrors`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<federation::MirrorStatus>,
+
+    /// Automatic rebalance after pool expansion, when `spec.rebalance.auto` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rebalance: Option<rebalance::RebalanceStatus>,
+
+    /// Pod readiness, admin API health, and capacity from the periodic health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<health::HealthStatus>,
+
+    /// Objects applied from `spec.additionalResources`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_resources: Vec<additional_resource::AdditionalResourceStatus>,
 }
 
 impl Status {
+    /// Recomputes `ready`, `totalReplicas`, `poolCount` and `capacity` for the printer columns.
+    pub fn refresh_summary(&mut self) {
+        let total: i32 = self.pools.iter().filter_map(|pool| pool.replicas).sum();
+        self.total_replicas = Some(total);
+        self.ready = Some(format!("{}/{}", self.available_replicas, total));
+        self.pool_count = Some(i32::try_from(self.pools.len()).unwrap_or(i32::MAX));
+        self.capacity = self
+            .health
+            .as_ref()
+            .and_then(|health| health.capacity.as_ref())
+            .filter(|capacity| capacity.raw_capacity_bytes > 0)
+            .map(|capacity| format_binary_bytes(capacity.raw_capacity_bytes));
+    }
+
     pub fn upsert_condition(&mut self, input: ConditionInput) {
         let type_ = input.type_.as_str();
         if let Some(condition) = self
@@ -453,6 +641,11 @@ pub fn is_blocked_reason(reason: &str) -> bool {
         reason,
         "InvalidTenantName"
             | "InvalidPoolSpec"
+            | "InvalidPortSpec"
+            | "InvalidRbacSpec"
+            | "ProtectedEnvOverride"
+            | "NamespaceTenantConflict"
+            | "NamespaceNotAllowed"
             | "ImmutableFieldModified"
             | "CredentialSecretNotFound"
             | "CredentialSecretMissingKey"
@@ -461,6 +654,9 @@ pub fn is_blocked_reason(reason: &str) -> bool {
             | "KmsSecretNotFound"
             | "KmsSecretMissingKey"
             | "KmsConfigInvalid"
+            | "ExistingHeadlessServiceInvalid"
+            | "ExistingClaimUnavailable"
+            | "SnapshotSourceUnavailable"
             | "CertManagerCrdMissing"
             | "CertManagerIssuerNotFound"
             | "CertManagerCertificateApplyFailed"
@@ -478,6 +674,7 @@ pub fn is_blocked_reason(reason: &str) -> bool {
             | "PoolDecommissionCanceled"
             | "PoolDecommissionFailed"
             | "StatefulSetUpdateValidationFailed"
+            | "UpgradeBlocked"
             | "ProvisioningUnsupported"
             | "PolicyDocumentConfigMapNotFound"
             | "PolicyDocumentKeyNotFound"
@@ -489,6 +686,7 @@ pub fn is_blocked_reason(reason: &str) -> bool {
             | "UserPolicySetFailed"
             | "BucketCreateFailed"
             | "BucketObjectLockConflict"
+            | "LifecyclePolicyApplyFailed"
     )
 }
 
@@ -509,9 +707,31 @@ fn condition_matches_observed_generation(status: &Status, condition: &Condition)
     }
 }
 
+/// Formats a byte count with the largest binary suffix that keeps it at least 1, e.g. `1.5Ti`.
+fn format_binary_bytes(bytes: u64) -> String {
+    const SUFFIXES: [&str; 7] = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei"];
+    let mut value = bytes as f64;
+    let mut suffix = 0;
+    while value >= 1024.0 && suffix < SUFFIXES.len() - 1 {
+        value /= 1024.0;
+        suffix += 1;
+    }
+    let formatted = format!("{value:.1}");
+    format!(
+        "{}{}",
+        formatted.strip_suffix(".0").unwrap_or(&formatted),
+        SUFFIXES[suffix]
+    )
+}
+
 pub fn next_actions_for_reason(reason: &str) -> Vec<&'static str> {
     match reason {
         "InvalidPoolSpec" => vec!["fixPoolSpec"],
+        "InvalidPortSpec" => vec!["fixPortSpec"],
+        "InvalidRbacSpec" => vec!["fixRbacExtraRules"],
+        "ProtectedEnvOverride" => vec!["removeProtectedEnvOverride"],
+        "NamespaceTenantConflict" => vec!["moveTenantToOwnNamespace"],
+        "NamespaceNotAllowed" => vec!["moveTenantToAllowedNamespace"],
         "CredentialSecretNotFound" => vec!["createCredentialSecret"],
         "CredentialSecretMissingKey" => vec!["addRequiredSecretKey"],
         "CredentialSecretInvalidEncoding" => vec!["replaceSecretValueWithUtf8"],
@@ -519,6 +739,11 @@ pub fn next_actions_for_reason(reason: &str) -> Vec<&'static str> {
         "KmsSecretNotFound" => vec!["createKmsSecret"],
         "KmsSecretMissingKey" => vec!["addRequiredKmsSecretKey"],
         "KmsConfigInvalid" => vec!["fixKmsConfig"],
+        "ExistingHeadlessServiceInvalid" => {
+            vec!["createHeadlessService", "fixExistingHeadlessService"]
+        }
+        "ExistingClaimUnavailable" => vec!["inspectExistingClaims", "releaseSourceClaim"],
+        "SnapshotSourceUnavailable" => vec!["inspectVolumeSnapshots", "fixDataSource"],
         "CertManagerCrdMissing" => vec!["installCertManager", "switchToExternalSecret"],
         "CertManagerIssuerNotFound" => vec!["createIssuer", "fixIssuerRef"],
         "CertManagerCertificateApplyFailed" => vec!["fixCertificateSpec", "inspectOperatorLogs"],
@@ -546,10 +771,16 @@ pub fn next_actions_for_reason(reason: &str) -> Vec<&'static str> {
         "PoolDecommissionCanceled" => vec!["startDecommission", "inspectPoolStatus"],
         "PoolDecommissionFailed" => vec!["inspectPoolStatus", "inspectOperatorLogs"],
         "DecommissionRequired" => vec!["startDecommission", "inspectPoolStatus"],
+        "PoolRenameNotAllowed" => vec!["restorePoolName", "addPoolThenDecommission"],
         "StatefulSetUpdateValidationFailed" => vec!["restoreImmutableField"],
         "StatefulSetApplyFailed" => vec!["retry", "inspectOperatorLogs"],
+        "UpgradeBlocked" => vec!["upgradeThroughIntermediateVersion", "setForceUpgrade"],
         "RolloutInProgress" => vec!["waitForRollout"],
+        "DisruptionInProgress" => vec!["waitForDisruption", "inspectPodDisruptionBudgets"],
+        "DisruptionBudgetExceeded" => vec!["waitForDisruption", "inspectPodDisruptionBudgets"],
         "PodsNotReady" => vec!["inspectPods", "inspectEvents"],
+        "ImagePullBackOff" => vec!["fixImageReference", "fixImagePullSecret"],
+        "CrashLoopBackOff" => vec!["inspectPodLogs", "inspectCrashDiagnostics", "inspectEvents"],
         "PoolDegraded" => vec![
             "inspectPools",
             "inspectPods",
@@ -568,6 +799,8 @@ pub fn next_actions_for_reason(reason: &str) -> Vec<&'static str> {
         "UserPolicySetFailed" => vec!["inspectUserPolicyMapping", "inspectOperatorLogs"],
         "BucketCreateFailed" => vec!["inspectBucket", "inspectOperatorLogs"],
         "BucketObjectLockConflict" => vec!["createObjectLockBucket", "fixBucketSpec"],
+        "LifecyclePolicyApplyFailed" => vec!["fixLifecyclePolicy", "inspectOperatorLogs"],
+        "VerificationFailed" => vec!["inspectPods", "inspectVolumes", "inspectOperatorLogs"],
         "KubernetesApiError" => vec!["retry", "inspectOperatorLogs"],
         "ObservedGenerationStale" => vec!["waitForReconcile"],
         _ => Vec::new(),
@@ -578,6 +811,49 @@ pub fn next_actions_for_reason(reason: &str) -> Vec<&'static str> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn summary_fields_back_the_printer_columns() {
+        let mut status = Status {
+            available_replicas: 3,
+            pools: ["pool-a", "pool-b"]
+                .into_iter()
+                .map(|name| {
+                    serde_json::from_value(serde_json::json!({
+                        "name": name,
+                        "ssName": format!("tenant-{name}"),
+                        "state": "RolloutComplete",
+                        "replicas": 2,
+                    }))
+                    .unwrap()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        status.refresh_summary();
+        assert_eq!(status.ready.as_deref(), Some("3/4"));
+        assert_eq!(status.total_replicas, Some(4));
+        assert_eq!(status.pool_count, Some(2));
+        assert_eq!(status.capacity, None);
+
+        status.health = Some(health::HealthStatus {
+            capacity: Some(health::CapacityStatus {
+                raw_capacity_bytes: 3 * 1024 * 1024 * 1024 * 1024 / 2,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        status.refresh_summary();
+        assert_eq!(status.capacity.as_deref(), Some("1.5Ti"));
+    }
+
+    #[test]
+    fn binary_bytes_use_the_largest_suffix() {
+        assert_eq!(format_binary_bytes(0), "0");
+        assert_eq!(format_binary_bytes(512), "512");
+        assert_eq!(format_binary_bytes(10 * 1024 * 1024 * 1024), "10Gi");
+        assert_eq!(format_binary_bytes(4 * 1024_u64.pow(4)), "4Ti");
+    }
+
     #[test]
     fn summary_ignores_conditions_from_older_observed_generation() {
         let status = Status {
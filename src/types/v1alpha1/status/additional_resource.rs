@@ -0,0 +1,42 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Apply result for one `spec.additionalResources` object.
+///
+/// The list doubles as the inventory used to prune objects removed from the spec.
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalResourceStatus {
+    pub api_version: String,
+
+    pub kind: String,
+
+    pub name: String,
+
+    /// Whether the last reconcile applied the object.
+    pub applied: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl AdditionalResourceStatus {
+    /// Whether both entries describe the same object.
+    pub fn same_object(&self, other: &Self) -> bool {
+        self.api_version == other.api_version && self.kind == other.kind && self.name == other.name
+    }
+}
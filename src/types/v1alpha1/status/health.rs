@@ -0,0 +1,104 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Result of the periodic health check, refreshed between reconciles.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthStatus {
+    /// RFC 3339 time of the last health check that wrote this status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_check_time: Option<String>,
+
+    pub ready_pods: i32,
+
+    pub total_pods: i32,
+
+    /// RustFS admin API probe result. Unset when the Tenant has no `credsSecret`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_api: Option<AdminApiHealth>,
+
+    /// Capacity reported by the RustFS admin API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<CapacityStatus>,
+
+    /// Result of the synthetic S3 probe. Unset when `spec.synthetics` is not enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synthetics: Option<SyntheticsStatus>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum AdminApiHealth {
+    /// Every drive is online and write quorum is met.
+    Healthy,
+    /// The API answered, but drives are offline or healing, or write quorum is lost.
+    Degraded,
+    /// The API could not be reached or rejected the request.
+    Unreachable,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityStatus {
+    pub raw_capacity_bytes: u64,
+    pub raw_used_bytes: u64,
+    pub online_drives: u64,
+    pub offline_drives: u64,
+
+    /// Raw capacity per pool, matched to `spec.pools` by position.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pools: Vec<PoolCapacityStatus>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolCapacityStatus {
+    pub name: String,
+    pub raw_capacity_bytes: u64,
+    pub raw_used_bytes: u64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyntheticsStatus {
+    /// RFC 3339 time of the last probe.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_probe_time: Option<String>,
+
+    /// Whether the last probe wrote and read back its object.
+    pub success: bool,
+
+    /// Milliseconds the last PUT took. Unset when the probe failed before the PUT finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub put_latency_ms: Option<u64>,
+
+    /// Milliseconds the last GET took. Unset when the probe failed before the GET finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub get_latency_ms: Option<u64>,
+
+    /// Failed probes since the last successful one.
+    pub consecutive_failures: u32,
+
+    /// Percentage of failed probes among the recent probes this operator process ran.
+    pub error_rate_percent: u32,
+
+    /// Error of the last probe, when it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
@@ -0,0 +1,157 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Condition;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Transitions kept in `status.conditionHistory`.
+pub const MAX_CONDITION_HISTORY: usize = 50;
+
+/// Transitions older than this are dropped from `status.conditionHistory`.
+pub const CONDITION_HISTORY_RETENTION: chrono::TimeDelta = chrono::TimeDelta::days(7);
+
+/// A condition changing status, e.g. `Ready` going from `True` to `False`.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionTransition {
+    /// Condition type, e.g. `Ready` or `Degraded`.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Status before the transition; unset when the condition first appeared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_status: Option<String>,
+
+    pub status: String,
+
+    pub reason: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+
+    /// RFC 3339 time of the transition.
+    pub time: String,
+}
+
+/// Appends the status changes from `previous` to `next` to `history`, then drops entries older
+/// than [`CONDITION_HISTORY_RETENTION`] and beyond [`MAX_CONDITION_HISTORY`], oldest first.
+pub fn record_transitions(
+    history: &mut Vec<ConditionTransition>,
+    previous: &[Condition],
+    next: &[Condition],
+    now: &str,
+) {
+    for condition in next {
+        let previous_status = previous
+            .iter()
+            .find(|previous| previous.type_ == condition.type_)
+            .map(|previous| previous.status.clone());
+        if previous_status.as_deref() == Some(condition.status.as_str()) {
+            continue;
+        }
+        history.push(ConditionTransition {
+            type_: condition.type_.clone(),
+            previous_status,
+            status: condition.status.clone(),
+            reason: condition.reason.clone(),
+            message: condition.message.clone(),
+            time: now.to_string(),
+        });
+    }
+
+    if let Ok(now) = chrono::DateTime::parse_from_rfc3339(now) {
+        history.retain(|transition| {
+            chrono::DateTime::parse_from_rfc3339(&transition.time)
+                .is_ok_and(|time| now - time <= CONDITION_HISTORY_RETENTION)
+        });
+    }
+    let excess = history.len().saturating_sub(MAX_CONDITION_HISTORY);
+    history.drain(..excess);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(type_: &str, status: &str) -> Condition {
+        Condition {
+            type_: type_.to_string(),
+            status: status.to_string(),
+            last_transition_time: None,
+            observed_generation: None,
+            reason: "Test".to_string(),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn records_status_changes_only() {
+        let mut history = Vec::new();
+        let now = "2025-01-08T00:00:00Z";
+        record_transitions(&mut history, &[], &[condition("Ready", "False")], now);
+        record_transitions(
+            &mut history,
+            &[condition("Ready", "False")],
+            &[condition("Ready", "False")],
+            now,
+        );
+        record_transitions(
+            &mut history,
+            &[condition("Ready", "False")],
+            &[condition("Ready", "True")],
+            now,
+        );
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].previous_status, None);
+        assert_eq!(history[1].previous_status.as_deref(), Some("False"));
+        assert_eq!(history[1].status, "True");
+    }
+
+    #[test]
+    fn drops_expired_and_excess_transitions() {
+        let mut history = vec![ConditionTransition {
+            type_: "Ready".to_string(),
+            status: "True".to_string(),
+            time: "2025-01-01T00:00:00Z".to_string(),
+            ..Default::default()
+        }];
+        for _ in 0..MAX_CONDITION_HISTORY {
+            history.push(ConditionTransition {
+                type_: "Degraded".to_string(),
+                status: "True".to_string(),
+                time: "2025-01-08T00:00:00Z".to_string(),
+                ..Default::default()
+            });
+        }
+        record_transitions(
+            &mut history,
+            &[condition("Ready", "True")],
+            &[condition("Ready", "False")],
+            "2025-01-08T00:00:01Z",
+        );
+
+        assert_eq!(history.len(), MAX_CONDITION_HISTORY);
+        assert!(
+            history
+                .iter()
+                .all(|transition| transition.time != "2025-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            history.last().map(|transition| transition.status.as_str()),
+            Some("False")
+        );
+    }
+}
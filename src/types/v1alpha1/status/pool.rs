@@ -70,6 +70,56 @@ pub struct Pool {
     /// Last time the pool status was updated
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_update_time: Option<String>,
+
+    /// When the operator first observed the StatefulSet rollout in progress; cleared once the
+    /// rollout completes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollout_started_at: Option<String>,
+
+    /// Stable network identity and readiness of each server in this pool, ordered by ordinal.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pods: Vec<PoolPodStatus>,
+
+    /// Data volumes whose claim or backing volume has failed, ordered by claim name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_volumes: Vec<FailedVolumeStatus>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, KubeSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedVolumeStatus {
+    /// PersistentVolumeClaim of the failed volume (`vol-{volume}-{tenant}-{pool}-{ordinal}`).
+    pub claim_name: String,
+
+    /// Pod that mounts the volume.
+    pub pod_name: String,
+
+    /// Why the volume is considered failed: ClaimLost, VolumeFailed, or NodeGone.
+    pub reason: String,
+
+    /// Whether the operator deleted the claim so the StatefulSet provisions a replacement.
+    #[serde(default)]
+    pub replacement_requested: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, KubeSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolPodStatus {
+    /// Pod name generated by the pool StatefulSet (`{tenant}-{pool}-{ordinal}`).
+    pub name: String,
+
+    /// StatefulSet ordinal of the pod.
+    pub ordinal: i32,
+
+    /// Fully qualified DNS name resolved through the tenant headless Service.
+    pub dns_name: String,
+
+    /// Whether the pod exists and reports the Ready condition as True.
+    pub ready: bool,
+
+    /// Node the pod is currently scheduled on, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_name: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Display, PartialEq, Eq)]
@@ -94,6 +144,9 @@ pub enum PoolState {
 
     #[strum(to_string = "PoolDegraded")]
     Degraded,
+
+    #[strum(to_string = "PoolPaused")]
+    Paused,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Display, PartialEq, Eq, JsonSchema)]
@@ -114,6 +167,10 @@ pub enum PoolLifecycleState {
 
     #[strum(to_string = "DecommissionFailed")]
     DecommissionFailed,
+
+    /// Removed from spec after decommission; kept until its StatefulSet and pods are deleted.
+    #[strum(to_string = "Terminating")]
+    Terminating,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, KubeSchema)]
@@ -196,6 +253,22 @@ pub struct PoolDecommissionCleanupStatus {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pvc_retention_policy: Option<String>,
+
+    /// RFC 3339 time the operator first saw the pool removed from spec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_from_spec_at: Option<String>,
+
+    /// Pods of the removed pool that still exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_pods: Option<i32>,
+
+    /// PVCs of the removed pool kept by the `Retain` policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retained_claims: Option<i32>,
+
+    /// PVCs of the removed pool that are being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminating_claims: Option<i32>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Display, PartialEq, Eq, JsonSchema)]
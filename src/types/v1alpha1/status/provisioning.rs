@@ -33,11 +33,17 @@ pub struct ProvisioningStatus {
 
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub buckets: Vec<ProvisioningItemStatus>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lifecycle_policies: Vec<ProvisioningItemStatus>,
 }
 
 impl ProvisioningStatus {
     pub fn is_empty(&self) -> bool {
-        self.policies.is_empty() && self.users.is_empty() && self.buckets.is_empty()
+        self.policies.is_empty()
+            && self.users.is_empty()
+            && self.buckets.is_empty()
+            && self.lifecycle_policies.is_empty()
     }
 }
 
@@ -104,6 +110,10 @@ pub struct ProvisioningItemStatus {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub object_lock: Option<bool>,
+
+    /// Buckets a lifecycle policy was applied to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buckets: Vec<String>,
 }
 
 impl ProvisioningItemStatus {
@@ -0,0 +1,62 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Automatic rebalance state, present while `spec.rebalance.auto` is enabled.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalanceStatus {
+    /// Active pools when the last rebalance started, or when `auto` was enabled. Adding a pool
+    /// beyond this count triggers the next rebalance.
+    pub pool_count: i32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<RebalancePhase>,
+
+    /// Progress toward equal pool usage, from 0 to 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_percent: Option<i32>,
+
+    /// Difference in used capacity fraction between the fullest and emptiest pool when the
+    /// rebalance started; progress is measured against it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_usage_spread: Option<f64>,
+
+    /// RFC 3339 time the rebalance was started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+
+    /// RFC 3339 time the rebalance was observed finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum RebalancePhase {
+    /// RustFS is moving data toward the new pools.
+    Running,
+    Completed,
+    /// The rebalance was stopped through the RustFS admin API.
+    Stopped,
+    /// The rebalance could not be started or RustFS reported a failure.
+    Failed,
+}
@@ -12,28 +12,174 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Typed Tenant lifecycle.
+//!
+//! `status.currentState` summarizes the conditions of the current reconcile and carries no
+//! history, so "creating for the first time" and "rolling out a change" both read `Reconciling`.
+//! The lifecycle phase is a state machine over those summaries: each status write feeds one
+//! [`LifecycleSignal`] into [`LifecyclePhase::next`], and the previous phase decides between
+//! `Provisioning` and `Updating`.
+
+use super::{CurrentState, Status};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
-#[derive(Display)]
-pub enum State {
-    #[strum(to_string = "Initialized")]
-    Initialized,
+/// `status.lifecycle`: the current lifecycle phase and its last transition.
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleStatus {
+    pub phase: LifecyclePhase,
+
+    /// Phase before the last transition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_phase: Option<LifecyclePhase>,
+
+    /// RFC 3339 time of the last phase transition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+
+    /// RFC 3339 time the Tenant first became Ready. Later reconciles are `Updating`, not
+    /// `Provisioning`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_ready_time: Option<String>,
+}
+
+#[derive(
+    Deserialize, Serialize, Clone, Copy, Debug, Default, JsonSchema, Display, PartialEq, Eq,
+)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum LifecyclePhase {
+    /// Creating workloads for a Tenant that has never been Ready.
+    #[default]
+    Provisioning,
+    /// Every pool is ready and nothing is in progress.
+    Ready,
+    /// Applying a change to a Tenant that has been Ready before.
+    Updating,
+    /// Running, but pods, volumes, or provisioning are failing.
+    Degraded,
+    /// Waiting for a user fix, such as a missing Secret or an invalid spec.
+    Blocked,
+    /// The Tenant has a deletion timestamp. Terminal.
+    Deleting,
+}
+
+/// What one status write observed, reduced to the inputs of the state machine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleSignal {
+    Progressing,
+    Ready,
+    Degraded,
+    Blocked,
+    Deleting,
+}
+
+impl LifecycleSignal {
+    /// Maps the condition summary in `status.currentState` to a signal.
+    pub fn from_status(status: &Status) -> Self {
+        match status.current_state.as_str() {
+            state if state == CurrentState::Ready.as_str() => Self::Ready,
+            state if state == CurrentState::Blocked.as_str() => Self::Blocked,
+            state if state == CurrentState::Degraded.as_str() => Self::Degraded,
+            _ => Self::Progressing,
+        }
+    }
+}
+
+impl LifecyclePhase {
+    /// The phase after `signal`. `provisioned` is whether the Tenant has been Ready before.
+    pub fn next(self, signal: LifecycleSignal, provisioned: bool) -> Self {
+        use LifecyclePhase as Phase;
+        use LifecycleSignal as Signal;
+
+        match (self, signal) {
+            (Phase::Deleting, _) | (_, Signal::Deleting) => Phase::Deleting,
+            (_, Signal::Blocked) => Phase::Blocked,
+            (_, Signal::Ready) => Phase::Ready,
+            (_, Signal::Degraded) => Phase::Degraded,
+            (Phase::Provisioning, Signal::Progressing) => Phase::Provisioning,
+            (Phase::Ready | Phase::Updating, Signal::Progressing) => Phase::Updating,
+            (Phase::Degraded | Phase::Blocked, Signal::Progressing) if provisioned => {
+                Phase::Updating
+            }
+            (Phase::Degraded | Phase::Blocked, Signal::Progressing) => Phase::Provisioning,
+        }
+    }
+}
+
+impl LifecycleStatus {
+    /// Applies `signal` to `previous`, keeping the transition time when the phase is unchanged.
+    pub fn advance(previous: Option<&Self>, signal: LifecycleSignal, now: &str) -> Self {
+        let first_ready_time = previous.and_then(|status| status.first_ready_time.clone());
+        let Some(previous) = previous else {
+            let phase = LifecyclePhase::default().next(signal, false);
+            return Self {
+                phase,
+                previous_phase: None,
+                last_transition_time: Some(now.to_string()),
+                first_ready_time: (phase == LifecyclePhase::Ready).then(|| now.to_string()),
+            };
+        };
+
+        let phase = previous.phase.next(signal, first_ready_time.is_some());
+        let first_ready_time =
+            first_ready_time.or_else(|| (phase == LifecyclePhase::Ready).then(|| now.to_string()));
+        if phase == previous.phase {
+            return Self {
+                first_ready_time,
+                ..previous.clone()
+            };
+        }
+        Self {
+            phase,
+            previous_phase: Some(previous.phase),
+            last_transition_time: Some(now.to_string()),
+            first_ready_time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: &str = "2025-01-01T00:00:00Z";
+    const T1: &str = "2025-01-01T00:05:00Z";
 
-    #[strum(to_string = "Statefulset not controlled by operator")]
-    NotOwned,
+    #[test]
+    fn progress_before_first_ready_is_provisioning_and_after_is_updating() {
+        let created = LifecycleStatus::advance(None, LifecycleSignal::Progressing, T0);
+        assert_eq!(created.phase, LifecyclePhase::Provisioning);
 
-    #[strum(to_string = "Pool Decommissioning Not Allowed")]
-    DecommissioningNotAllowed,
+        let blocked = LifecycleStatus::advance(Some(&created), LifecycleSignal::Blocked, T0);
+        let unblocked = LifecycleStatus::advance(Some(&blocked), LifecycleSignal::Progressing, T0);
+        assert_eq!(unblocked.phase, LifecyclePhase::Provisioning);
 
-    #[strum(to_string = "Provisioning IO Service")]
-    ProvisioningIOService,
+        let ready = LifecycleStatus::advance(Some(&unblocked), LifecycleSignal::Ready, T1);
+        assert_eq!(ready.phase, LifecyclePhase::Ready);
+        assert_eq!(ready.previous_phase, Some(LifecyclePhase::Provisioning));
+        assert_eq!(ready.first_ready_time.as_deref(), Some(T1));
 
-    #[strum(to_string = "Provisioning Console Service")]
-    ProvisioningConsoleService,
+        let degraded = LifecycleStatus::advance(Some(&ready), LifecycleSignal::Degraded, T1);
+        let recovering =
+            LifecycleStatus::advance(Some(&degraded), LifecycleSignal::Progressing, T1);
+        assert_eq!(recovering.phase, LifecyclePhase::Updating);
+        assert_eq!(recovering.first_ready_time.as_deref(), Some(T1));
+    }
 
-    #[strum(to_string = "Provisioning Headless Service")]
-    ProvisioningHeadlessService,
+    #[test]
+    fn unchanged_phase_keeps_transition_time_and_deleting_is_terminal() {
+        let ready = LifecycleStatus::advance(None, LifecycleSignal::Ready, T0);
+        let still_ready = LifecycleStatus::advance(Some(&ready), LifecycleSignal::Ready, T1);
+        assert_eq!(still_ready, ready);
 
-    #[strum(to_string = "Multiple tenants exist in the namespace")]
-    MultipleTenantsExist,
+        let deleting = LifecycleStatus::advance(Some(&ready), LifecycleSignal::Deleting, T1);
+        assert_eq!(deleting.phase, LifecyclePhase::Deleting);
+        assert_eq!(
+            LifecyclePhase::Deleting.next(LifecycleSignal::Ready, true),
+            LifecyclePhase::Deleting
+        );
+    }
 }
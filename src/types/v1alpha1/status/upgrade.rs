@@ -0,0 +1,55 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Progress of the last canary image upgrade.
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeStatus {
+    pub phase: UpgradePhase,
+
+    /// Image being rolled out.
+    pub target_image: String,
+
+    /// Image the pools ran before the upgrade; restored if the canary fails.
+    pub previous_image: String,
+
+    /// Pod updated first and checked during the soak period.
+    pub canary_pod: String,
+
+    /// RFC 3339 time the canary was started.
+    pub started_at: String,
+
+    /// RFC 3339 time the canary pod was first observed Ready on the target image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soak_started_at: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum UpgradePhase {
+    /// Only the canary pod runs the target image.
+    Soaking,
+    /// The canary passed; every pod is updated to the target image.
+    Promoted,
+    /// The canary failed; pools run the previous image until `spec.image` changes again.
+    RolledBack,
+}
@@ -0,0 +1,61 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const DEFAULT_SYNTHETICS_BUCKET: &str = "rustfs-operator-synthetics";
+const DEFAULT_SYNTHETICS_INTERVAL_SECONDS: u64 = 60;
+
+/// Periodic PUT/GET probe the health check runs against the Tenant S3 endpoint.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyntheticsSpec {
+    /// Run the probe. Defaults to true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Bucket holding the probe object. It is created on the first probe and kept, with the one
+    /// object overwritten by every probe. Defaults to `rustfs-operator-synthetics`.
+    #[schemars(length(min = 3, max = 63))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+
+    /// Seconds between probes. Probes run on health check ticks, so the effective interval is
+    /// rounded up to the operator health check interval. Defaults to 60.
+    #[schemars(range(min = 10))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_seconds: Option<u64>,
+}
+
+impl SyntheticsSpec {
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
+    pub fn bucket(&self) -> &str {
+        self.bucket
+            .as_deref()
+            .filter(|bucket| !bucket.is_empty())
+            .unwrap_or(DEFAULT_SYNTHETICS_BUCKET)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(
+            self.interval_seconds
+                .unwrap_or(DEFAULT_SYNTHETICS_INTERVAL_SECONDS),
+        )
+    }
+}
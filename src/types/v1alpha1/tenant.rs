@@ -12,32 +12,84 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::types::v1alpha1::alerting::AlertingSpec;
+use crate::types::v1alpha1::credentials::CredsSecretReference;
+use crate::types::v1alpha1::diagnostics::DiagnosticsConfig;
 use crate::types::v1alpha1::encryption::{EncryptionConfig, PodSecurityContextOverride};
+use crate::types::v1alpha1::event_notifications::EventNotificationsSpec;
+use crate::types::v1alpha1::federation::FederationSpec;
 use crate::types::v1alpha1::k8s;
 use crate::types::v1alpha1::logging::LoggingConfig;
-use crate::types::v1alpha1::pool::{Pool, validate_pool_collection};
+use crate::types::v1alpha1::notifications::NotificationsSpec;
+use crate::types::v1alpha1::pool::{
+    Pool, default_erasure_parity, validate_auxiliary_mounts_against_logs, validate_pool_collection,
+    validate_production_drive_count,
+};
 use crate::types::v1alpha1::pool_lifecycle::PoolLifecycleSpec;
+use crate::types::v1alpha1::ports::{PortsConfig, S3_PORT_IMMUTABLE_RULE};
 use crate::types::v1alpha1::provisioning::{
-    ProvisioningBucket, ProvisioningPolicy, ProvisioningUser,
+    BootstrapSpec, LifecyclePolicy, ProvisioningBucket, ProvisioningPolicy, ProvisioningUser,
 };
+use crate::types::v1alpha1::rbac::{ServiceAccountTokenProjection, TenantRbacConfig};
+use crate::types::v1alpha1::synthetics::SyntheticsSpec;
 use crate::types::v1alpha1::tls::TlsConfig;
+use crate::types::v1alpha1::upgrade::UpgradeStrategy;
+use crate::types::v1alpha1::verification::VerificationSpec;
 use crate::types::{self, error::NoNamespaceSnafu};
 use k8s_openapi::api::core::v1 as corev1;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
 use kube::{CustomResource, KubeSchema, Resource, ResourceExt};
 use serde::{Deserialize, Serialize};
 use snafu::OptionExt;
+use std::collections::BTreeMap;
+
+/// Label on generated children holding a hash of the object the operator rendered, so an
+/// unchanged child can be recognized without comparing it field by field.
+pub const SPEC_HASH_LABEL: &str = "rustfs.com/spec-hash";
+
+/// Value for [`SPEC_HASH_LABEL`]: the first 32 hex digits of the SHA-256 of the rendered object,
+/// which fits the 63 character limit of label values.
+pub(crate) fn spec_hash_label_value(object: &impl Serialize) -> String {
+    use sha2::{Digest, Sha256};
+
+    let rendered = serde_json::to_vec(object).unwrap_or_default();
+    let mut hash = hex::encode(Sha256::digest(rendered));
+    hash.truncate(32);
+    hash
+}
+
+pub use labels::{
+    COMPONENT_LABEL, Component, INSTANCE_LABEL, MANAGED_BY_LABEL, NAME_LABEL, POOL_LABEL,
+    TENANT_LABEL, pool_selector, tenant_selector,
+};
 
 // Submodules for resource factory methods
+mod config;
+mod event_notifications;
 mod helper;
+mod labels;
+mod normalize;
 mod rbac;
 mod services;
+mod volumes;
 mod workloads;
 
+pub use config::{CONFIG_CHECKSUM_ANNOTATION, ENV_FROM_CHECKSUM_ANNOTATION};
+pub use event_notifications::EVENT_NOTIFICATIONS_CHECKSUM_ANNOTATION;
+pub(crate) use helper::{DEFAULT_LOGS_MOUNT_PATH, DEFAULT_MOUNT_PATH};
+pub(crate) use normalize::contains as rendering_contained;
+pub use rbac::SERVICE_ACCOUNT_CHECKSUM_ANNOTATION;
+pub(crate) use volumes::{ExistingClaimSlot, VOLUME_SNAPSHOT_GROUP};
+pub(crate) use workloads::VOLUME_CLAIM_TEMPLATE_PREFIX;
+pub use workloads::{PROTECTED_OPERATOR_ENV_VARS, RESTARTED_AT_ANNOTATION, ZONE_TOPOLOGY_KEY};
+
 pub(crate) const MAX_TENANT_POOLS: u32 = 32;
 pub(crate) const MAX_TENANT_POLICIES: u32 = 256;
 pub(crate) const MAX_TENANT_USERS: u32 = 256;
 pub(crate) const MAX_TENANT_BUCKETS: u32 = 1024;
+pub(crate) const MAX_TENANT_LIFECYCLE_POLICIES: u32 = 256;
+pub(crate) const MAX_TENANT_ADDITIONAL_RESOURCES: u32 = 64;
 
 #[derive(CustomResource, Deserialize, Serialize, Clone, Debug, KubeSchema, Default)]
 #[kube(
@@ -50,10 +102,15 @@ pub(crate) const MAX_TENANT_BUCKETS: u32 = 1024;
     plural = "tenants",
     singular = "tenant",
     printcolumn = r#"{"name":"State", "type":"string", "jsonPath":".status.currentState"}"#,
+    printcolumn = r#"{"name":"Ready", "type":"string", "jsonPath":".status.ready"}"#,
+    printcolumn = r#"{"name":"Pools", "type":"integer", "jsonPath":".status.poolCount"}"#,
+    printcolumn = r#"{"name":"Capacity", "type":"string", "jsonPath":".status.capacity"}"#,
+    printcolumn = r#"{"name":"Phase", "type":"string", "jsonPath":".status.lifecycle.phase"}"#,
     printcolumn = r#"{"name":"Age", "type":"date", "jsonPath":".metadata.creationTimestamp"}"#,
     crates(serde_json = "k8s_openapi::serde_json")
 )]
 #[serde(rename_all = "camelCase")]
+#[x_kube(validation = Rule::new(S3_PORT_IMMUTABLE_RULE).message("ports.s3Port is immutable"))]
 pub struct TenantSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scheduler: Option<String>,
@@ -64,16 +121,43 @@ pub struct TenantSpec {
     )]
     pub pools: Vec<Pool>,
 
+    /// `production` (default) requires at least four drives per pool. `standalone` accepts any
+    /// layout, such as one server with one volume on kind or minikube, skips the default zone
+    /// spread and PodDisruptionBudget update deferral, and gives pools without `resources`
+    /// small requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<crate::types::v1alpha1::profile::TenantProfile>,
+
+    /// RustFS runtime tuning preset: `balanced`, `throughput`, or `low-memory`. Expands into
+    /// `RUSTFS_*` cache and concurrency env vars maintained by the operator; `env` and pool
+    /// `env` entries with the same name take precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performance_profile: Option<crate::types::v1alpha1::profile::PerformanceProfile>,
+
     /// Explicit lifecycle requests for pool decommissioning.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pool_lifecycle: Option<PoolLifecycleSpec>,
 
+    /// Rebalance data across pools after a pool is added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rebalance: Option<crate::types::v1alpha1::rebalance::RebalanceConfig>,
+
     #[serde(
         default = "helper::get_rustfs_image",
         skip_serializing_if = "Option::is_none"
     )]
     pub image: Option<String>,
 
+    /// Roll out an image change even when the upgrade pre-flight check would block it.
+    ///
+    /// The pre-flight check rejects upgrades that skip a major RustFS version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_upgrade: Option<bool>,
+
+    /// How image changes are rolled out. Defaults to a plain StatefulSet rolling update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upgrade_strategy: Option<UpgradeStrategy>,
+
     #[serde(
         default = "helper::get_rustfs_mount_path",
         skip_serializing_if = "Option::is_none"
@@ -86,6 +170,10 @@ pub struct TenantSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pod_management_policy: Option<k8s::PodManagementPolicy>,
 
+    /// Spread each pool's pods across zones when the cluster has more than one. On by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone_spread: Option<crate::types::v1alpha1::pool::ZoneSpreadConfig>,
+
     /// Controls how the operator handles Pods when the node hosting them is down (NotReady/Unknown).
     ///
     /// Typical use-case: a StatefulSet Pod gets stuck in Terminating when the node goes down.
@@ -96,18 +184,72 @@ pub struct TenantSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pod_deletion_policy_when_node_is_down: Option<k8s::PodDeletionPolicyWhenNodeIsDown>,
 
+    /// Dry-run mode and per-node cooldown for the node-down pod cleanup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_down_cleanup: Option<crate::types::v1alpha1::pool::NodeDownCleanupConfig>,
+
+    /// Reporting and opt-in forced cleanup when the Tenant's deletion is blocked by a child.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stuck_deletion: Option<crate::types::v1alpha1::deletion::StuckDeletionConfig>,
+
+    /// Operator-driven teardown of the StatefulSets, Services, ServiceAccounts, Roles,
+    /// RoleBindings, and ConfigMaps when the Tenant is deleted. When set, a finalizer holds the
+    /// Tenant until its children are handled; Foreground waits until they are gone. Unset leaves
+    /// the children to the garbage collector.
+    /// Values: Foreground | Background | Orphan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletion_propagation: Option<crate::types::v1alpha1::deletion::DeletionPropagation>,
+
+    /// Replace data volumes whose claim is Lost, whose PersistentVolume is Failed, or whose
+    /// node-local volume sits on a node that no longer exists.
+    ///
+    /// When enabled, the operator deletes one failed claim and its pod at a time so the
+    /// StatefulSet provisions a fresh volume, and only while RustFS reports no drive healing in
+    /// progress. Failed volumes are always reported in `status.pools[].failedVolumes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_replace_failed_drives: Option<bool>,
+
+    /// Name of a pre-existing headless Service to use for pool pod DNS instead of `{tenant}-hl`.
+    ///
+    /// Intended for migrations from other deployments. The operator does not create or modify
+    /// this Service; it must exist in the Tenant namespace, have `clusterIP: None`, and select
+    /// the Tenant pods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[x_kube(validation = Rule::new("self != ''").message("existingHeadlessService must be not empty when specified"))]
+    #[x_kube(validation = Rule::new("self == oldSelf").message("existingHeadlessService is immutable"))]
+    pub existing_headless_service: Option<String>,
+
+    /// S3 and console ports of the RustFS pods and Services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ports: Option<PortsConfig>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub env: Vec<corev1::EnvVar>,
 
+    /// ConfigMaps and Secrets whose keys all become env vars of the RustFS container. `env`,
+    /// pool `env`, and operator-set vars take precedence. Pods roll when a referenced object's
+    /// data changes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_from: Vec<corev1::EnvFromSource>,
+
+    /// Let tenant or pool `env` replace the variables that define the cluster topology
+    /// (`RUSTFS_VOLUMES`, `RUSTFS_ADDRESS`, `RUSTFS_CONSOLE_ADDRESS`). Without it such an override
+    /// blocks reconciliation, because a wrong value can split or break the cluster.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_unsafe_env_overrides: Option<bool>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tls: Option<TlsConfig>,
 
     // #[serde(default, skip_serializing_if = "Option::is_none")]
     // pub request_auto_cert: Option<bool>,
     //
-    // #[serde(default, skip_serializing_if = "Option::is_none")]
-    // pub cert_expiry_alert_threshold: Option<i32>,
-    //
+    /// Days before the TLS certificate expires at which the operator emits
+    /// `CertificateExpiringSoon` events and asks cert-manager to renew a managed certificate.
+    /// Defaults to 10; `0` disables the check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert_expiry_alert_threshold: Option<i32>,
+
     // #[serde(default, skip_serializing_if = "Option::is_none")]
     // pub liveness: Option<corev1::Probe>,
     //
@@ -139,6 +281,19 @@ pub struct TenantSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub create_service_account_rbac: Option<bool>,
 
+    /// Annotations on the generated ServiceAccount, e.g. `eks.amazonaws.com/role-arn`
+    /// or `iam.gke.io/gcp-service-account`. Ignored when `serviceAccountName` is set.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub service_account_annotations: BTreeMap<String, String>,
+
+    /// Projected ServiceAccount token mounted into every RustFS container.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_account_token_projection: Option<ServiceAccountTokenProjection>,
+
+    /// Scope of the Role bound to the Tenant ServiceAccount.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rbac: Option<TenantRbacConfig>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub priority_class_name: Option<String>,
 
@@ -152,15 +307,21 @@ pub struct TenantSpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logging: Option<LoggingConfig>,
 
+    /// Diagnostics captured for crash-looping pods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<DiagnosticsConfig>,
+
     // // #[serde(default, skip_serializing_if = "Option::is_none")]
     // // pub side_cars: Option<SideCars>,
     /// Optional reference to a Secret containing RustFS credentials.
-    /// The Secret must contain 'accesskey' and 'secretkey' keys (both required, minimum 8 characters each).
+    /// The Secret must contain 'accesskey' and 'secretkey' keys (both required, minimum 8 characters each);
+    /// `keys` and `minLength` adjust the key names and the length policy.
     /// If not specified, credentials can be provided via environment variables in 'env'.
     /// Priority: Secret credentials > Environment variables > RustFS built-in defaults.
     /// For production use, always configure credentials via Secret or environment variables.
+    /// Set `provider: external` when an external secret store creates the Secret.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub creds_secret: Option<corev1::LocalObjectReference>,
+    pub creds_secret: Option<CredsSecretReference>,
 
     /// Canned policies that should be applied to the RustFS tenant.
     #[schemars(
@@ -187,6 +348,19 @@ pub struct TenantSpec {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub buckets: Vec<ProvisioningBucket>,
 
+    /// Bucket lifecycle (ILM) rules the operator keeps applied in the RustFS tenant.
+    #[schemars(
+        length(max = MAX_TENANT_LIFECYCLE_POLICIES),
+        extend("x-kubernetes-list-type" = "map", "x-kubernetes-list-map-keys" = ["name"])
+    )]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lifecycle_policies: Vec<LifecyclePolicy>,
+
+    /// Policies, users, and buckets created once after the Tenant first becomes Ready.
+    /// Completion is recorded by the `Bootstrapped` condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bootstrap: Option<BootstrapSpec>,
+
     /// Encryption / KMS configuration for server-side encryption.
     /// When enabled, the operator injects KMS environment variables and mounts
     /// secrets into RustFS pods so the in-process `rustfs-kms` library is configured.
@@ -197,6 +371,56 @@ pub struct TenantSpec {
     /// Applies to all RustFS pods in this Tenant.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security_context: Option<PodSecurityContextOverride>,
+
+    /// Copies of this Tenant maintained in remote clusters.
+    /// Requires the operator to run with `OPERATOR_FEDERATION_ENABLED=true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub federation: Option<FederationSpec>,
+
+    /// Webhooks (Slack or generic JSON) called when the Tenant becomes Degraded or Ready, or a
+    /// rollout fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<NotificationsSpec>,
+
+    /// Bucket event notification targets (webhook or MQTT) configured in RustFS through
+    /// operator-managed environment variables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_notifications: Option<EventNotificationsSpec>,
+
+    /// Alerts for this Tenant, generated as a PrometheusRule when the Prometheus Operator is
+    /// installed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alerting: Option<AlertingSpec>,
+
+    /// S3 create/list/delete check of the Tenant endpoint after it becomes Ready,
+    /// reported by the `Verified` condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationSpec>,
+
+    /// Periodic S3 PUT/GET probe whose latency and error rate are exported as operator metrics
+    /// and reported in `status.health.synthetics`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synthetics: Option<SyntheticsSpec>,
+
+    /// Extra objects applied in the Tenant namespace with the Tenant as owner. Only ConfigMaps,
+    /// Secrets, Services, and ExternalSecrets are accepted. Objects removed from this list are
+    /// deleted.
+    #[schemars(schema_with = "additional_resources_schema")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_resources: Vec<RawExtension>,
+}
+
+/// `RawExtension` items must keep their fields, so the schema preserves unknown fields.
+fn additional_resources_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "array",
+        "maxItems": MAX_TENANT_ADDITIONAL_RESOURCES,
+        "items": {
+            "type": "object",
+            "x-kubernetes-preserve-unknown-fields": true,
+            "x-kubernetes-embedded-resource": true,
+        },
+    })
 }
 
 impl Tenant {
@@ -217,14 +441,188 @@ impl Tenant {
     }
 
     pub fn validate_pools(&self) -> Result<(), types::error::Error> {
-        validate_pool_collection(&self.name(), &self.spec.pools).map_err(|message| {
-            types::error::Error::InvalidPoolSpec {
+        validate_pool_collection(&self.name(), &self.spec.pools)
+            .and_then(|()| {
+                if self.is_standalone() {
+                    Ok(())
+                } else {
+                    validate_production_drive_count(&self.spec.pools)
+                }
+            })
+            .and_then(|()| match self.logs_mount_path() {
+                Some(path) => validate_auxiliary_mounts_against_logs(&self.spec.pools, path),
+                None => Ok(()),
+            })
+            .map_err(|message| types::error::Error::InvalidPoolSpec {
                 name: self.name(),
                 message,
-            }
+            })
+    }
+
+    /// Whether `spec.profile` is `standalone`.
+    pub fn is_standalone(&self) -> bool {
+        self.spec.profile == Some(crate::types::v1alpha1::profile::TenantProfile::Standalone)
+    }
+
+    pub fn validate_ports(&self) -> Result<(), types::error::Error> {
+        self.spec
+            .ports
+            .clone()
+            .unwrap_or_default()
+            .validate()
+            .map_err(|message| types::error::Error::InvalidPortSpec {
+                name: self.name(),
+                message,
+            })
+    }
+
+    pub fn validate_rbac(&self) -> Result<(), types::error::Error> {
+        self.spec
+            .rbac
+            .as_ref()
+            .map_or(Ok(()), |rbac| rbac.validate())
+            .map_err(|message| types::error::Error::InvalidRbacSpec {
+                name: self.name(),
+                message,
+            })
+    }
+
+    /// Rejects `env` entries, tenant or pool, that replace a protected operator variable unless
+    /// `spec.allowUnsafeEnvOverrides` is set.
+    pub fn validate_env_overrides(&self) -> Result<(), types::error::Error> {
+        if self.spec.allow_unsafe_env_overrides.unwrap_or(false) {
+            return Ok(());
+        }
+        let tenant_vars = self.spec.env.iter().map(|var| ("", var));
+        let pool_vars = self
+            .spec
+            .pools
+            .iter()
+            .flat_map(|pool| pool.env.iter().map(move |var| (pool.name.as_str(), var)));
+        let protected: Vec<String> = tenant_vars
+            .chain(pool_vars)
+            .filter(|(_, var)| PROTECTED_OPERATOR_ENV_VARS.contains(&var.name.as_str()))
+            .map(|(pool, var)| match pool {
+                "" => format!("env {}", var.name),
+                pool => format!("pool '{pool}' env {}", var.name),
+            })
+            .collect();
+        if protected.is_empty() {
+            return Ok(());
+        }
+        Err(types::error::Error::ProtectedEnvOverride {
+            name: self.name(),
+            message: format!(
+                "{} replaces an operator-managed variable; remove it or set spec.allowUnsafeEnvOverrides",
+                protected.join(", ")
+            ),
         })
     }
 
+    /// Checks that decommissioning `pool_name` leaves a cluster that can still hold the data:
+    /// at least one pool stays active, and every such pool has erasure sets wide enough for the
+    /// standard parity its pods run with (`RUSTFS_STORAGE_CLASS_STANDARD` in the pool's or the
+    /// Tenant's env, or the default for the first pool).
+    pub fn validate_pool_decommission(&self, pool_name: &str) -> Result<(), String> {
+        use crate::types::v1alpha1::pool_lifecycle::DecommissionAction;
+        use crate::types::v1alpha1::status::pool::PoolLifecycleState;
+
+        let leaving = |pool: &Pool| {
+            let requested = self
+                .spec
+                .pool_lifecycle
+                .as_ref()
+                .and_then(|lifecycle| lifecycle.request_for_pool(&pool.name))
+                .is_some_and(|request| request.action == DecommissionAction::Start);
+            let ss_name = format!("{}-{}", self.name(), pool.name);
+            let draining = self.status.as_ref().is_some_and(|status| {
+                status.pools.iter().any(|pool_status| {
+                    (pool_status.name.as_deref() == Some(pool.name.as_str())
+                        || pool_status.ss_name == ss_name)
+                        && matches!(
+                            pool_status.lifecycle_state,
+                            Some(
+                                PoolLifecycleState::Decommissioning
+                                    | PoolLifecycleState::Decommissioned
+                            )
+                        )
+                })
+            });
+            requested || draining
+        };
+        let remaining: Vec<&Pool> = self
+            .spec
+            .pools
+            .iter()
+            .filter(|pool| pool.name != pool_name && !leaving(pool))
+            .collect();
+        if remaining.is_empty() {
+            return Err(format!(
+                "cannot decommission pool '{pool_name}': no other active pool would remain to receive its data"
+            ));
+        }
+
+        let default_parity = default_erasure_parity(
+            self.spec
+                .pools
+                .first()
+                .and_then(Pool::erasure_set_drive_count)
+                .unwrap_or(1),
+        );
+        for pool in remaining {
+            let parity = self
+                .standard_parity_override(pool)
+                .unwrap_or(default_parity);
+            let set_drives = pool.erasure_set_drive_count().unwrap_or(0);
+            if parity * 2 > set_drives {
+                return Err(format!(
+                    "cannot decommission pool '{pool_name}': pool '{}' has erasure sets of {set_drives} drives, too few for parity EC:{parity} (needs at least {})",
+                    pool.name,
+                    parity * 2
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parity from an `EC:<n>` value of `RUSTFS_STORAGE_CLASS_STANDARD` for `pool`'s pods: the
+    /// pool's env, which replaces `spec.env`, and otherwise `spec.env`.
+    fn standard_parity_override(&self, pool: &Pool) -> Option<i32> {
+        pool.env
+            .iter()
+            .chain(&self.spec.env)
+            .find(|var| var.name == "RUSTFS_STORAGE_CLASS_STANDARD")
+            .and_then(|var| var.value.as_deref())
+            .and_then(|value| value.trim().strip_prefix("EC:"))
+            .and_then(|parity| parity.parse().ok())
+    }
+
+    /// Where the logs volume is mounted, or `None` when logs only go to stdout.
+    pub(crate) fn logs_mount_path(&self) -> Option<&str> {
+        use crate::types::v1alpha1::logging::LoggingMode;
+
+        let logging = self.spec.logging.as_ref()?;
+        if matches!(logging.mode, LoggingMode::Stdout) {
+            return None;
+        }
+        Some(
+            logging
+                .mount_path
+                .as_deref()
+                .unwrap_or(DEFAULT_LOGS_MOUNT_PATH),
+        )
+    }
+
+    /// S3 API and internode port, `spec.ports.s3Port` or 9000.
+    pub(crate) fn s3_port(&self) -> i32 {
+        self.spec.ports.clone().unwrap_or_default().s3_port()
+    }
+
+    /// Console port, `spec.ports.consolePort` or 9001.
+    pub(crate) fn console_port(&self) -> i32 {
+        self.spec.ports.clone().unwrap_or_default().console_port()
+    }
+
     /// a new owner reference for tenant
     pub fn new_owner_ref(&self) -> metav1::OwnerReference {
         metav1::OwnerReference {
@@ -238,7 +636,36 @@ impl Tenant {
     }
 
     pub(crate) fn headless_service_name(&self) -> String {
-        format!("{}-hl", self.name())
+        match &self.spec.existing_headless_service {
+            Some(name) => name.clone(),
+            None => format!("{}-hl", self.name()),
+        }
+    }
+
+    /// Pod name generated by a pool StatefulSet for the given ordinal.
+    pub(crate) fn pool_pod_name(&self, pool_name: &str, ordinal: i32) -> String {
+        format!("{}-{}-{}", self.name(), pool_name, ordinal)
+    }
+
+    /// Fully qualified DNS name of a pool pod, resolved through the headless Service.
+    /// Matches the peer endpoints rendered into `RUSTFS_VOLUMES`.
+    pub(crate) fn pool_pod_dns_name(
+        &self,
+        pool_name: &str,
+        ordinal: i32,
+        namespace: &str,
+    ) -> String {
+        format!(
+            "{}.{}.{}.svc.cluster.local",
+            self.pool_pod_name(pool_name, ordinal),
+            self.headless_service_name(),
+            namespace
+        )
+    }
+
+    /// RustFS image rendered into pool StatefulSets: `spec.image`, `TENANT_RUSTFS_IMAGE`, or the default.
+    pub fn rustfs_image(&self) -> String {
+        helper::get_rustfs_image_or_default(self.spec.image.as_ref())
     }
 
     pub fn service_account_name(&self) -> String {
@@ -248,50 +675,76 @@ impl Tenant {
             .unwrap_or_else(|| format!("{}-sa", self.name()))
     }
 
-    /// Returns common labels that should be applied to all Tenant-owned resources.
-    /// These labels follow Kubernetes recommended label conventions.
-    pub(crate) fn common_labels(&self) -> std::collections::BTreeMap<String, String> {
-        [
-            ("app.kubernetes.io/name".to_owned(), "rustfs".to_owned()),
-            ("app.kubernetes.io/instance".to_owned(), self.name()),
-            (
-                "app.kubernetes.io/managed-by".to_owned(),
-                "rustfs-operator".to_owned(),
-            ),
-            ("rustfs.tenant".to_owned(), self.name()),
-        ]
-        .into_iter()
-        .collect()
-    }
-
-    /// Returns labels for pool-specific resources (StatefulSets, PVCs).
-    /// Includes common labels plus pool-specific labels.
-    pub(crate) fn pool_labels(&self, pool: &Pool) -> std::collections::BTreeMap<String, String> {
-        let mut labels = self.common_labels();
-        labels.insert("rustfs.pool".to_owned(), pool.name.clone());
-        labels.insert(
-            "app.kubernetes.io/component".to_owned(),
-            "storage".to_owned(),
-        );
-        labels
+    /// ServiceAccount of a pool's pods: `spec.pools[].serviceAccountName`, or the Tenant's.
+    pub fn pool_service_account_name(&self, pool: &Pool) -> String {
+        pool.service_account_name
+            .clone()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| self.service_account_name())
     }
 
-    /// Returns selector labels for Services and StatefulSets.
-    /// These should be a stable subset of the full labels.
-    pub(crate) fn selector_labels(&self) -> std::collections::BTreeMap<String, String> {
-        [("rustfs.tenant".to_owned(), self.name())]
-            .into_iter()
-            .collect()
+    /// Names of Secrets a Tenant reads that are not re-issued by the operator or cert-manager.
+    pub fn referenced_secret_names(&self) -> std::collections::BTreeSet<String> {
+        let spec = &self.spec;
+        let mut names = std::collections::BTreeSet::new();
+        names.extend(spec.creds_secret.as_ref().map(|r| r.name.clone()));
+        let local_refs = [
+            spec.image_pull_secret.as_ref(),
+            spec.encryption
+                .as_ref()
+                .and_then(|encryption| encryption.kms_secret.as_ref()),
+        ];
+        names.extend(local_refs.into_iter().flatten().map(|r| r.name.clone()));
+
+        names.extend(spec.users.iter().map(|user| user.name.clone()));
+        if let Some(bootstrap) = &spec.bootstrap {
+            names.extend(bootstrap.users.iter().map(|user| user.name.clone()));
+        }
+
+        if let Some(cert_manager) = spec.tls.as_ref().and_then(|tls| tls.cert_manager.as_ref()) {
+            if !cert_manager.manage_certificate
+                && let Some(secret_name) = &cert_manager.secret_name
+            {
+                names.insert(secret_name.clone());
+            }
+            if let Some(ca_trust) = &cert_manager.ca_trust {
+                let ca_refs = [
+                    ca_trust.ca_secret_ref.as_ref(),
+                    ca_trust.client_ca_secret_ref.as_ref(),
+                ];
+                names.extend(ca_refs.into_iter().flatten().map(|r| r.name.clone()));
+            }
+        }
+
+        names.extend(
+            spec.env_from
+                .iter()
+                .filter_map(|source| source.secret_ref.as_ref())
+                .map(|secret| secret.name.clone()),
+        );
+        if let Some(event_notifications) = &spec.event_notifications {
+            names.extend(
+                event_notifications
+                    .targets
+                    .iter()
+                    .flat_map(|target| target.secret_names())
+                    .map(str::to_string),
+            );
+        }
+
+        names.retain(|name| !name.is_empty());
+        names
     }
 
-    /// Returns selector labels for pool-specific resources.
-    pub(crate) fn pool_selector_labels(
-        &self,
-        pool: &Pool,
-    ) -> std::collections::BTreeMap<String, String> {
-        let mut labels = self.selector_labels();
-        labels.insert("rustfs.pool".to_owned(), pool.name.clone());
-        labels
+    /// Names of the ConfigMaps listed in `spec.envFrom`.
+    pub fn env_from_config_map_names(&self) -> std::collections::BTreeSet<String> {
+        self.spec
+            .env_from
+            .iter()
+            .filter_map(|source| source.config_map_ref.as_ref())
+            .map(|config_map| config_map.name.clone())
+            .filter(|name| !name.is_empty())
+            .collect()
     }
 
     /// Build pool status from a StatefulSet.
@@ -379,8 +832,54 @@ impl Tenant {
             current_revision,
             update_revision,
             last_update_time,
+            rollout_started_at: None,
+            pods: Vec::new(),
+            failed_volumes: Vec::new(),
         }
     }
+
+    /// Build per-server status entries for a pool from the pods currently observed.
+    /// Every expected ordinal is listed, so missing pods show up as not ready.
+    pub(crate) fn build_pool_pod_statuses(
+        &self,
+        pool: &Pool,
+        namespace: &str,
+        pods: &[corev1::Pod],
+    ) -> Vec<crate::types::v1alpha1::status::pool::PoolPodStatus> {
+        (0..pool.servers.max(0))
+            .map(|ordinal| {
+                let name = self.pool_pod_name(&pool.name, ordinal);
+                let pod = pods
+                    .iter()
+                    .find(|pod| pod.metadata.name.as_deref() == Some(name.as_str()));
+
+                crate::types::v1alpha1::status::pool::PoolPodStatus {
+                    dns_name: self.pool_pod_dns_name(&pool.name, ordinal, namespace),
+                    ordinal,
+                    ready: pod.is_some_and(pod_is_ready),
+                    node_name: pod
+                        .and_then(|pod| pod.spec.as_ref())
+                        .and_then(|spec| spec.node_name.clone()),
+                    name,
+                }
+            })
+            .collect()
+    }
+}
+
+fn pod_is_ready(pod: &corev1::Pod) -> bool {
+    if pod.metadata.deletion_timestamp.is_some() {
+        return false;
+    }
+
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|condition| condition.type_ == "Ready" && condition.status == "True")
+        })
 }
 
 /// Validate a name conforms to DNS-1035 label rules:
@@ -437,9 +936,11 @@ pub fn validate_dns1035_label(name: &str) -> Result<(), types::error::Error> {
 
 #[cfg(test)]
 mod tests {
+    use super::{SPEC_HASH_LABEL, spec_hash_label_value};
     use crate::types::v1alpha1::status::pool::PoolState;
     use k8s_openapi::api::apps::v1::{StatefulSet, StatefulSetSpec, StatefulSetStatus};
     use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use kube::ResourceExt;
 
     fn statefulset_with_status(
         generation: i64,
@@ -494,6 +995,126 @@ mod tests {
         assert_eq!(pool_status.state, PoolState::Updating);
     }
 
+    #[test]
+    fn protected_env_override_requires_explicit_opt_in() {
+        use k8s_openapi::api::core::v1 as corev1;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.env = vec![corev1::EnvVar {
+            name: "RUSTFS_CACHE_SIZE".to_string(),
+            value: Some("1Gi".to_string()),
+            ..Default::default()
+        }];
+        tenant.spec.pools[0].env = vec![corev1::EnvVar {
+            name: "RUSTFS_VOLUMES".to_string(),
+            value: Some("http://other/data".to_string()),
+            ..Default::default()
+        }];
+
+        let err = tenant
+            .validate_env_overrides()
+            .expect_err("RUSTFS_VOLUMES override should be rejected");
+        assert!(err.to_string().contains("pool 'pool-0' env RUSTFS_VOLUMES"));
+
+        tenant.spec.allow_unsafe_env_overrides = Some(true);
+        assert!(tenant.validate_env_overrides().is_ok());
+    }
+
+    #[test]
+    fn decommission_requires_remaining_pool_with_room_for_parity() {
+        use k8s_openapi::api::core::v1 as corev1;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert!(
+            tenant
+                .validate_pool_decommission("pool-0")
+                .is_err_and(|message| message.contains("no other active pool"))
+        );
+
+        let mut small = tenant.spec.pools[0].clone();
+        small.name = "small".to_string();
+        small.servers = 1;
+        small.persistence.volumes_per_server = 2;
+        tenant.spec.pools.push(small);
+        assert!(
+            tenant
+                .validate_pool_decommission("pool-0")
+                .is_err_and(|message| message.contains("pool 'small' has erasure sets of 2 drives"))
+        );
+
+        tenant.spec.env = vec![corev1::EnvVar {
+            name: "RUSTFS_STORAGE_CLASS_STANDARD".to_string(),
+            value: Some("EC:1".to_string()),
+            ..Default::default()
+        }];
+        assert!(tenant.validate_pool_decommission("pool-0").is_ok());
+
+        tenant.spec.pools[1].env = vec![corev1::EnvVar {
+            name: "RUSTFS_STORAGE_CLASS_STANDARD".to_string(),
+            value: Some("EC:2".to_string()),
+            ..Default::default()
+        }];
+        assert!(
+            tenant
+                .validate_pool_decommission("pool-0")
+                .is_err_and(|message| message.contains("too few for parity EC:2"))
+        );
+    }
+
+    #[test]
+    fn existing_headless_service_replaces_generated_name() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert_eq!(tenant.headless_service_name(), "test-tenant-hl");
+
+        tenant.spec.existing_headless_service = Some("legacy-svc".to_string());
+
+        assert_eq!(tenant.headless_service_name(), "legacy-svc");
+        assert_eq!(
+            tenant.pool_pod_dns_name("pool-0", 0, "default"),
+            "test-tenant-pool-0-0.legacy-svc.default.svc.cluster.local"
+        );
+    }
+
+    #[test]
+    fn pool_pod_statuses_list_every_ordinal_with_dns_and_readiness() {
+        use k8s_openapi::api::core::v1 as corev1;
+
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+        let ready_pod = corev1::Pod {
+            metadata: ObjectMeta {
+                name: Some("test-tenant-pool-0-1".to_string()),
+                ..Default::default()
+            },
+            spec: Some(corev1::PodSpec {
+                node_name: Some("node-a".to_string()),
+                ..Default::default()
+            }),
+            status: Some(corev1::PodStatus {
+                conditions: Some(vec![corev1::PodCondition {
+                    type_: "Ready".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        };
+
+        let pods = tenant.build_pool_pod_statuses(pool, "default", &[ready_pod]);
+
+        assert_eq!(pods.len(), 4);
+        assert_eq!(pods[0].name, "test-tenant-pool-0-0");
+        assert_eq!(
+            pods[0].dns_name,
+            "test-tenant-pool-0-0.test-tenant-hl.default.svc.cluster.local"
+        );
+        assert!(!pods[0].ready);
+        assert_eq!(pods[0].node_name, None);
+        assert_eq!(pods[1].ordinal, 1);
+        assert!(pods[1].ready);
+        assert_eq!(pods[1].node_name.as_deref(), Some("node-a"));
+    }
+
     // Test 1: Default behavior - no custom SA
     #[test]
     fn test_service_account_name_default() {
@@ -593,6 +1214,77 @@ mod tests {
         );
     }
 
+    // Test: spec hash label tracks the rendering and stays off the pod template
+    #[test]
+    fn test_spec_hash_label() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let service = tenant.new_console_service();
+        let hash = spec_hash_label_value(&service);
+
+        assert_eq!(hash.len(), 32);
+        assert_eq!(hash, spec_hash_label_value(&tenant.new_console_service()));
+
+        let Ok(statefulset) = tenant.new_statefulset(&tenant.spec.pools[0]) else {
+            panic!("Should create StatefulSet");
+        };
+        // StatefulSets are tracked by the last-applied hash annotation instead.
+        assert!(!statefulset.labels().contains_key(SPEC_HASH_LABEL));
+        assert!(
+            statefulset
+                .annotations()
+                .contains_key(super::workloads::LAST_APPLIED_HASH_ANNOTATION)
+        );
+        let template_labels = statefulset
+            .spec
+            .and_then(|spec| spec.template.metadata)
+            .and_then(|metadata| metadata.labels)
+            .unwrap_or_default();
+        assert!(!template_labels.contains_key(SPEC_HASH_LABEL));
+
+        tenant.metadata.name = Some("renamed".to_string());
+        assert_ne!(hash, spec_hash_label_value(&tenant.new_console_service()));
+    }
+
+    // Test: the default apply comparator only matches the same spec hash label
+    #[test]
+    fn test_spec_hash_unchanged() {
+        use crate::context::spec_hash_unchanged;
+
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let mut desired = tenant.new_console_service();
+        let mut live = desired.clone();
+        assert!(!spec_hash_unchanged(&live, &desired));
+
+        let hash = spec_hash_label_value(&desired);
+        desired
+            .labels_mut()
+            .insert(SPEC_HASH_LABEL.to_string(), hash.clone());
+        assert!(!spec_hash_unchanged(&live, &desired));
+        live.labels_mut().insert(SPEC_HASH_LABEL.to_string(), hash);
+        assert!(spec_hash_unchanged(&live, &desired));
+    }
+
+    // Test: a hand edit of a rendered field defeats the current spec hash label
+    #[test]
+    fn test_rendering_unchanged_detects_hand_edits() {
+        use crate::context::rendering_unchanged;
+
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let mut desired = tenant.new_role();
+        desired.labels_mut().insert(
+            SPEC_HASH_LABEL.to_string(),
+            spec_hash_label_value(&tenant.new_role()),
+        );
+        let mut live = desired.clone();
+        live.metadata.resource_version = Some("7".to_string());
+        assert!(rendering_unchanged(&live, &desired));
+
+        if let Some(rule) = live.rules.as_mut().and_then(|rules| rules.first_mut()) {
+            rule.verbs.push("delete".to_string());
+        }
+        assert!(!rendering_unchanged(&live, &desired));
+    }
+
     // Test 6: Selector labels are stable subset
     #[test]
     fn test_selector_labels() {
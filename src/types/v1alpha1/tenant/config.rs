@@ -0,0 +1,197 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::services::{console_service_name, io_service_name};
+use super::{Component, Tenant};
+use crate::types;
+use crate::types::v1alpha1::tls::TlsPlan;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// Pod template annotation carrying the checksum of the rendered runtime ConfigMap.
+pub const CONFIG_CHECKSUM_ANNOTATION: &str = "operator.rustfs.com/config-checksum";
+
+/// Pod template annotation carrying the checksum of the objects listed in `spec.envFrom`.
+pub const ENV_FROM_CHECKSUM_ANNOTATION: &str = "operator.rustfs.com/env-from-checksum";
+pub(super) const CONFIG_VOLUME_NAME: &str = "operator-config";
+pub(super) const CONFIG_MOUNT_PATH: &str = "/var/run/rustfs/config";
+
+impl Tenant {
+    pub fn config_map_name(&self) -> String {
+        format!("{}-config", self.name())
+    }
+
+    /// Operator-computed runtime settings shared by RustFS and sidecars.
+    fn runtime_config_data(
+        &self,
+        tls_plan: &TlsPlan,
+    ) -> Result<BTreeMap<String, String>, types::error::Error> {
+        let namespace = self.namespace()?;
+        let layout = self
+            .spec
+            .pools
+            .iter()
+            .map(|pool| {
+                serde_json::json!({
+                    "name": pool.name,
+                    "servers": pool.servers,
+                    "volumesPerServer": pool.persistence.volumes_per_server,
+                    "drives": pool.servers * pool.persistence.volumes_per_server,
+                    "volumes": self.rustfs_pool_volume_spec(
+                        pool,
+                        tls_plan.internode_scheme,
+                        &namespace,
+                    ),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(BTreeMap::from([
+            (
+                "volumes".to_string(),
+                self.rustfs_volumes_env_value(tls_plan.internode_scheme)?,
+            ),
+            (
+                "s3-endpoint".to_string(),
+                format!(
+                    "{}://{}.{namespace}.svc.cluster.local:{}",
+                    tls_plan.internode_scheme,
+                    io_service_name(self),
+                    self.s3_port()
+                ),
+            ),
+            (
+                "console-url".to_string(),
+                format!(
+                    "http://{}.{namespace}.svc.cluster.local:{}",
+                    console_service_name(self),
+                    self.console_port()
+                ),
+            ),
+            (
+                "erasure-layout.json".to_string(),
+                serde_json::to_string_pretty(&layout)?,
+            ),
+        ]))
+    }
+
+    pub fn new_config_map(
+        &self,
+        tls_plan: &TlsPlan,
+    ) -> Result<corev1::ConfigMap, types::error::Error> {
+        let data = self.runtime_config_data(tls_plan)?;
+        Ok(corev1::ConfigMap {
+            metadata: metav1::ObjectMeta {
+                name: Some(self.config_map_name()),
+                namespace: self.namespace().ok(),
+                owner_references: Some(vec![self.new_owner_ref()]),
+                labels: Some(self.component_labels(Component::Config)),
+                annotations: Some(BTreeMap::from([(
+                    CONFIG_CHECKSUM_ANNOTATION.to_string(),
+                    config_checksum(&data),
+                )])),
+                ..Default::default()
+            },
+            data: Some(data),
+            ..Default::default()
+        })
+    }
+
+    /// Checksum stamped on pod templates so pods roll when the rendered configuration changes.
+    pub(super) fn runtime_config_checksum(
+        &self,
+        tls_plan: &TlsPlan,
+    ) -> Result<String, types::error::Error> {
+        Ok(config_checksum(&self.runtime_config_data(tls_plan)?))
+    }
+
+    pub(super) fn runtime_config_volume(&self) -> (corev1::Volume, corev1::VolumeMount) {
+        (
+            corev1::Volume {
+                name: CONFIG_VOLUME_NAME.to_string(),
+                config_map: Some(corev1::ConfigMapVolumeSource {
+                    name: self.config_map_name(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            corev1::VolumeMount {
+                name: CONFIG_VOLUME_NAME.to_string(),
+                mount_path: CONFIG_MOUNT_PATH.to_string(),
+                read_only: Some(true),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+pub(super) fn config_checksum(data: &BTreeMap<String, String>) -> String {
+    let mut hasher = Sha256::new();
+    for (key, value) in data {
+        hasher.update(key.as_bytes());
+        hasher.update([0]);
+        hasher.update(value.as_bytes());
+        hasher.update([0]);
+    }
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::CONFIG_CHECKSUM_ANNOTATION;
+    use crate::types::v1alpha1::tls::TlsPlan;
+
+    #[test]
+    fn config_map_renders_runtime_settings() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let config_map = tenant.new_config_map(&TlsPlan::disabled()).unwrap();
+
+        assert_eq!(
+            config_map.metadata.name.as_deref(),
+            Some("test-tenant-config")
+        );
+        let data = config_map.data.unwrap();
+        assert_eq!(
+            data.get("s3-endpoint").map(String::as_str),
+            Some("http://test-tenant-io.default.svc.cluster.local:9000")
+        );
+        assert_eq!(
+            data.get("console-url").map(String::as_str),
+            Some("http://test-tenant-console.default.svc.cluster.local:9001")
+        );
+        assert!(
+            data.get("volumes")
+                .is_some_and(|volumes| volumes.contains("test-tenant-pool-0-{0...3}"))
+        );
+        let layout: serde_json::Value =
+            serde_json::from_str(data.get("erasure-layout.json").unwrap()).unwrap();
+        assert_eq!(layout[0]["drives"], 16);
+
+        let checksum = config_map
+            .metadata
+            .annotations
+            .unwrap()
+            .remove(CONFIG_CHECKSUM_ANNOTATION)
+            .unwrap();
+        assert_eq!(
+            checksum,
+            tenant
+                .runtime_config_checksum(&TlsPlan::disabled())
+                .unwrap()
+        );
+    }
+}
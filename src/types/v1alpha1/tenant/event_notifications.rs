@@ -0,0 +1,203 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Tenant;
+use crate::types::v1alpha1::event_notifications::NotificationSecretKeyReference;
+use k8s_openapi::api::core::v1 as corev1;
+
+/// Pod template annotation carrying the checksum of the Secrets event notification targets read.
+pub const EVENT_NOTIFICATIONS_CHECKSUM_ANNOTATION: &str =
+    "operator.rustfs.com/event-notifications-checksum";
+
+const CLIENT_CERT_MOUNT_ROOT: &str = "/var/run/rustfs/notify";
+
+impl Tenant {
+    /// RustFS notification target env vars (`RUSTFS_NOTIFY_<TYPE>_<KEY>_<TARGET>`) and the
+    /// client certificate volumes of `spec.eventNotifications`.
+    ///
+    /// Returns `(env_vars, pod_volumes, volume_mounts)`.
+    pub(super) fn configure_event_notifications(
+        &self,
+    ) -> (
+        Vec<corev1::EnvVar>,
+        Vec<corev1::Volume>,
+        Vec<corev1::VolumeMount>,
+    ) {
+        let mut env = Vec::new();
+        let mut volumes = Vec::new();
+        let mut mounts = Vec::new();
+        let Some(event_notifications) = &self.spec.event_notifications else {
+            return (env, volumes, mounts);
+        };
+
+        for target in &event_notifications.targets {
+            let suffix = target.env_suffix();
+            if let Some(webhook) = &target.webhook {
+                let var = |key: &str| format!("RUSTFS_NOTIFY_WEBHOOK_{key}_{suffix}");
+                env.push(value_env(var("ENABLE"), "on"));
+                env.push(value_env(var("ENDPOINT"), &webhook.endpoint));
+                if let Some(secret) = &webhook.auth_token_secret {
+                    env.push(secret_env(var("AUTH_TOKEN"), secret));
+                }
+                if let Some(secret) = &webhook.client_cert_secret {
+                    let volume_name = format!("notify-{}", target.name.replace('_', "-"));
+                    let mount_path = format!("{CLIENT_CERT_MOUNT_ROOT}/{}", target.name);
+                    env.push(value_env(
+                        var("CLIENT_CERT"),
+                        &format!("{mount_path}/tls.crt"),
+                    ));
+                    env.push(value_env(
+                        var("CLIENT_KEY"),
+                        &format!("{mount_path}/tls.key"),
+                    ));
+                    volumes.push(corev1::Volume {
+                        name: volume_name.clone(),
+                        secret: Some(corev1::SecretVolumeSource {
+                            secret_name: Some(secret.name.clone()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    });
+                    mounts.push(corev1::VolumeMount {
+                        name: volume_name,
+                        mount_path,
+                        read_only: Some(true),
+                        ..Default::default()
+                    });
+                }
+            } else if let Some(mqtt) = &target.mqtt {
+                let var = |key: &str| format!("RUSTFS_NOTIFY_MQTT_{key}_{suffix}");
+                env.push(value_env(var("ENABLE"), "on"));
+                env.push(value_env(var("BROKER"), &mqtt.broker));
+                env.push(value_env(var("TOPIC"), &mqtt.topic));
+                if let Some(qos) = mqtt.qos {
+                    env.push(value_env(var("QOS"), &qos.to_string()));
+                }
+                if let Some(secret) = &mqtt.username_secret {
+                    env.push(secret_env(var("USERNAME"), secret));
+                }
+                if let Some(secret) = &mqtt.password_secret {
+                    env.push(secret_env(var("PASSWORD"), secret));
+                }
+            }
+        }
+
+        (env, volumes, mounts)
+    }
+}
+
+fn value_env(name: String, value: &str) -> corev1::EnvVar {
+    corev1::EnvVar {
+        name,
+        value: Some(value.to_string()),
+        ..Default::default()
+    }
+}
+
+fn secret_env(name: String, secret: &NotificationSecretKeyReference) -> corev1::EnvVar {
+    corev1::EnvVar {
+        name,
+        value_from: Some(corev1::EnvVarSource {
+            secret_key_ref: Some(corev1::SecretKeySelector {
+                name: secret.name.clone(),
+                key: secret.key.clone(),
+                optional: Some(false),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::v1alpha1::event_notifications::{
+        EventNotificationTarget, EventNotificationsSpec, MqttNotificationTarget,
+        NotificationSecretKeyReference, WebhookNotificationTarget,
+    };
+    use k8s_openapi::api::core::v1 as corev1;
+
+    #[test]
+    fn event_notification_targets_render_env_and_client_cert_mounts() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.event_notifications = Some(EventNotificationsSpec {
+            targets: vec![
+                EventNotificationTarget {
+                    name: "audit_hook".to_string(),
+                    webhook: Some(WebhookNotificationTarget {
+                        endpoint: "https://events.example.com/rustfs".to_string(),
+                        auth_token_secret: Some(NotificationSecretKeyReference {
+                            name: "hook-token".to_string(),
+                            key: "token".to_string(),
+                        }),
+                        client_cert_secret: Some(corev1::LocalObjectReference {
+                            name: "hook-client-tls".to_string(),
+                        }),
+                    }),
+                    mqtt: None,
+                },
+                EventNotificationTarget {
+                    name: "iot".to_string(),
+                    webhook: None,
+                    mqtt: Some(MqttNotificationTarget {
+                        broker: "tcp://mqtt.example.com".to_string(),
+                        topic: "rustfs/events".to_string(),
+                        qos: Some(1),
+                        ..Default::default()
+                    }),
+                },
+            ],
+        });
+
+        let (env, volumes, mounts) = tenant.configure_event_notifications();
+        let value = |name: &str| {
+            env.iter()
+                .find(|var| var.name == name)
+                .and_then(|var| var.value.as_deref())
+        };
+        assert_eq!(value("RUSTFS_NOTIFY_WEBHOOK_ENABLE_AUDIT_HOOK"), Some("on"));
+        assert_eq!(
+            value("RUSTFS_NOTIFY_WEBHOOK_ENDPOINT_AUDIT_HOOK"),
+            Some("https://events.example.com/rustfs")
+        );
+        assert_eq!(
+            value("RUSTFS_NOTIFY_WEBHOOK_CLIENT_KEY_AUDIT_HOOK"),
+            Some("/var/run/rustfs/notify/audit_hook/tls.key")
+        );
+        let token = env
+            .iter()
+            .find(|var| var.name == "RUSTFS_NOTIFY_WEBHOOK_AUTH_TOKEN_AUDIT_HOOK")
+            .and_then(|var| var.value_from.as_ref())
+            .and_then(|source| source.secret_key_ref.as_ref());
+        assert_eq!(token.map(|selector| selector.key.as_str()), Some("token"));
+        assert_eq!(value("RUSTFS_NOTIFY_MQTT_QOS_IOT"), Some("1"));
+        assert_eq!(volumes[0].name, "notify-audit-hook");
+        assert_eq!(mounts[0].mount_path, "/var/run/rustfs/notify/audit_hook");
+
+        let targets = &tenant.spec.event_notifications.as_ref().unwrap().targets;
+        assert_eq!(
+            targets[0].address().as_deref(),
+            Some("events.example.com:443")
+        );
+        assert_eq!(
+            targets[1].address().as_deref(),
+            Some("mqtt.example.com:1883")
+        );
+        assert!(
+            tenant
+                .referenced_secret_names()
+                .is_superset(&["hook-client-tls", "hook-token"].map(String::from).into())
+        );
+    }
+}
@@ -29,6 +29,12 @@ pub(crate) fn get_rustfs_image_or_default(explicit: Option<&String>) -> String {
         .unwrap_or_else(|| DEFAULT_RUSTFS_IMAGE.to_string())
 }
 
+/// Base directory of the RustFS data volumes when a pool sets no `persistence.path`.
+pub(crate) const DEFAULT_MOUNT_PATH: &str = "/data";
+
+/// Where the logs volume is mounted when `spec.logging.mountPath` is unset.
+pub(crate) const DEFAULT_LOGS_MOUNT_PATH: &str = "/logs";
+
 pub(crate) fn get_rustfs_mount_path() -> Option<String> {
-    Some("/data".to_owned())
+    Some(DEFAULT_MOUNT_PATH.to_owned())
 }
@@ -0,0 +1,163 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Labels on Tenant-owned resources.
+//!
+//! Every generated object carries the Kubernetes recommended `app.kubernetes.io/*` labels plus
+//! `rustfs.tenant`, and pool resources also `rustfs.pool`. `app.kubernetes.io/component` tells
+//! the role of the object apart.
+//!
+//! Selectors only use `rustfs.tenant` and `rustfs.pool`. Pods have carried those two labels since
+//! the first release and a StatefulSet selector cannot be changed, so existing Services and
+//! StatefulSets keep matching their pods; the recommended labels are added to metadata only.
+
+use super::Tenant;
+use crate::types::v1alpha1::pool::Pool;
+use std::collections::BTreeMap;
+
+pub const NAME_LABEL: &str = "app.kubernetes.io/name";
+pub const INSTANCE_LABEL: &str = "app.kubernetes.io/instance";
+pub const COMPONENT_LABEL: &str = "app.kubernetes.io/component";
+pub const MANAGED_BY_LABEL: &str = "app.kubernetes.io/managed-by";
+pub const TENANT_LABEL: &str = "rustfs.tenant";
+pub const POOL_LABEL: &str = "rustfs.pool";
+
+const APP_NAME: &str = "rustfs";
+const MANAGED_BY: &str = "rustfs-operator";
+
+/// Role of a Tenant-owned resource, the value of `app.kubernetes.io/component`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Component {
+    /// RustFS server pods, their StatefulSets, volumes, and the S3 and headless Services.
+    Storage,
+    /// The RustFS console Service.
+    Console,
+    /// Generated RustFS configuration.
+    Config,
+    /// ServiceAccount, Role, and RoleBinding of the RustFS pods.
+    Rbac,
+    /// Objects written for troubleshooting, such as captured crash logs.
+    Diagnostics,
+}
+
+impl Component {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Storage => "storage",
+            Self::Console => "console",
+            Self::Config => "config",
+            Self::Rbac => "rbac",
+            Self::Diagnostics => "diagnostics",
+        }
+    }
+}
+
+/// Label selector for every pod of a Tenant.
+pub fn tenant_selector(tenant: &str) -> String {
+    format!("{TENANT_LABEL}={tenant}")
+}
+
+/// Label selector for the pods of one pool.
+pub fn pool_selector(tenant: &str, pool: &str) -> String {
+    format!("{TENANT_LABEL}={tenant},{POOL_LABEL}={pool}")
+}
+
+impl Tenant {
+    /// Returns common labels that should be applied to all Tenant-owned resources.
+    /// These labels follow Kubernetes recommended label conventions.
+    pub(crate) fn common_labels(&self) -> BTreeMap<String, String> {
+        [
+            (NAME_LABEL.to_owned(), APP_NAME.to_owned()),
+            (INSTANCE_LABEL.to_owned(), self.name()),
+            (MANAGED_BY_LABEL.to_owned(), MANAGED_BY.to_owned()),
+            (TENANT_LABEL.to_owned(), self.name()),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Returns common labels plus the component of the resource.
+    pub(crate) fn component_labels(&self, component: Component) -> BTreeMap<String, String> {
+        let mut labels = self.common_labels();
+        labels.insert(COMPONENT_LABEL.to_owned(), component.as_str().to_owned());
+        labels
+    }
+
+    /// Returns labels for pool-specific resources (StatefulSets, PVCs).
+    /// Includes common labels plus pool-specific labels.
+    pub(crate) fn pool_labels(&self, pool: &Pool) -> BTreeMap<String, String> {
+        let mut labels = self.component_labels(Component::Storage);
+        labels.insert(POOL_LABEL.to_owned(), pool.name.clone());
+        labels
+    }
+
+    /// Returns selector labels for Services and StatefulSets.
+    /// These should be a stable subset of the full labels.
+    pub(crate) fn selector_labels(&self) -> BTreeMap<String, String> {
+        [(TENANT_LABEL.to_owned(), self.name())]
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns selector labels for pool-specific resources.
+    pub(crate) fn pool_selector_labels(&self, pool: &Pool) -> BTreeMap<String, String> {
+        let mut labels = self.selector_labels();
+        labels.insert(POOL_LABEL.to_owned(), pool.name.clone());
+        labels
+    }
+
+    /// Label selector for every pod of this Tenant.
+    pub(crate) fn pod_selector(&self) -> String {
+        tenant_selector(&self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_label_set_contains_its_selector() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+        let contains = |labels: &BTreeMap<String, String>, selector: &BTreeMap<String, String>| {
+            selector
+                .iter()
+                .all(|(key, value)| labels.get(key) == Some(value))
+        };
+
+        assert!(contains(
+            &tenant.pool_labels(pool),
+            &tenant.pool_selector_labels(pool)
+        ));
+        for component in [
+            Component::Storage,
+            Component::Console,
+            Component::Config,
+            Component::Rbac,
+            Component::Diagnostics,
+        ] {
+            let labels = tenant.component_labels(component);
+            assert!(contains(&labels, &tenant.selector_labels()));
+            assert_eq!(
+                labels.get(COMPONENT_LABEL).map(String::as_str),
+                Some(component.as_str())
+            );
+        }
+        assert_eq!(
+            pool_selector("t", "p"),
+            "rustfs.tenant=t,rustfs.pool=p".to_string()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Normalization of pod template fields before the StatefulSet diff.
+//!
+//! The StatefulSet read back from the API server carries defaults the operator never sets, env
+//! vars may come back in a different order, and resource quantities may come back in another
+//! spelling (`1000m` for `1`). Both sides of the comparison go through these functions so only
+//! changes the operator would actually apply trigger an update.
+
+use k8s_openapi::api::apps::v1;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// Mode the API server sets on Secret, ConfigMap, projected, and downward API volumes.
+const DEFAULT_VOLUME_MODE: i32 = 0o644;
+
+/// Env vars sorted by name, with empty values dropped and `fieldRef.apiVersion` defaulted.
+pub(super) fn env(vars: Option<&Vec<corev1::EnvVar>>) -> Vec<corev1::EnvVar> {
+    let mut vars: Vec<_> = vars
+        .into_iter()
+        .flatten()
+        .cloned()
+        .map(|mut var| {
+            var.value = var.value.filter(|value| !value.is_empty());
+            if let Some(field_ref) = var
+                .value_from
+                .as_mut()
+                .and_then(|source| source.field_ref.as_mut())
+            {
+                field_ref
+                    .api_version
+                    .get_or_insert_with(|| "v1".to_string());
+            }
+            var
+        })
+        .collect();
+    vars.sort_by(|left, right| left.name.cmp(&right.name));
+    vars
+}
+
+/// Pull policy the API server applies when none is set: `Always` for `latest` or untagged
+/// images, `IfNotPresent` otherwise.
+pub(super) fn image_pull_policy(container: &corev1::Container) -> Option<String> {
+    if let Some(policy) = container
+        .image_pull_policy
+        .as_deref()
+        .filter(|p| !p.is_empty())
+    {
+        return Some(policy.to_string());
+    }
+    let image = container.image.as_deref()?;
+    if image.contains('@') {
+        return Some("IfNotPresent".to_string());
+    }
+    let name = image.rsplit('/').next().unwrap_or(image);
+    let policy = match name.split_once(':') {
+        Some((_, tag)) if tag != "latest" => "IfNotPresent",
+        _ => "Always",
+    };
+    Some(policy.to_string())
+}
+
+/// Container ports with the default `TCP` protocol filled in.
+pub(super) fn ports(ports: Option<&Vec<corev1::ContainerPort>>) -> Vec<corev1::ContainerPort> {
+    ports
+        .into_iter()
+        .flatten()
+        .cloned()
+        .map(|mut port| {
+            port.protocol.get_or_insert_with(|| "TCP".to_string());
+            port
+        })
+        .collect()
+}
+
+/// Pod volumes with the default file mode filled in on Secret, ConfigMap, projected, and
+/// downward API sources.
+pub(super) fn volumes(volumes: Option<&Vec<corev1::Volume>>) -> Vec<corev1::Volume> {
+    volumes
+        .into_iter()
+        .flatten()
+        .cloned()
+        .map(|mut volume| {
+            if let Some(secret) = volume.secret.as_mut() {
+                secret.default_mode.get_or_insert(DEFAULT_VOLUME_MODE);
+            }
+            if let Some(config_map) = volume.config_map.as_mut() {
+                config_map.default_mode.get_or_insert(DEFAULT_VOLUME_MODE);
+            }
+            if let Some(projected) = volume.projected.as_mut() {
+                projected.default_mode.get_or_insert(DEFAULT_VOLUME_MODE);
+            }
+            if let Some(downward_api) = volume.downward_api.as_mut() {
+                downward_api.default_mode.get_or_insert(DEFAULT_VOLUME_MODE);
+            }
+            if let Some(size_limit) = volume
+                .empty_dir
+                .as_mut()
+                .and_then(|empty_dir| empty_dir.size_limit.as_mut())
+            {
+                *size_limit = quantity(size_limit);
+            }
+            volume
+        })
+        .collect()
+}
+
+/// `value` in a canonical spelling, so equal amounts compare equal: `1`, `1000m`, and `1e3m`
+/// all become `1000000000n`. Values that do not parse are kept as they are.
+pub(super) fn quantity(value: &Quantity) -> Quantity {
+    quantity_nanos(&value.0).map_or_else(|| value.clone(), |nanos| Quantity(format!("{nanos}n")))
+}
+
+/// Quantity map, such as resource requests, with every value in canonical spelling.
+fn quantities(values: Option<&BTreeMap<String, Quantity>>) -> Option<BTreeMap<String, Quantity>> {
+    values.map(|values| {
+        values
+            .iter()
+            .map(|(name, value)| (name.clone(), quantity(value)))
+            .collect()
+    })
+}
+
+/// Container resources with every quantity in canonical spelling.
+pub(super) fn resources(
+    resources: Option<&corev1::ResourceRequirements>,
+) -> Option<corev1::ResourceRequirements> {
+    resources.map(|resources| corev1::ResourceRequirements {
+        limits: quantities(resources.limits.as_ref()),
+        requests: quantities(resources.requests.as_ref()),
+        ..resources.clone()
+    })
+}
+
+/// Amount of a Kubernetes quantity in units of 10^-9, rounded up as the API server does.
+fn quantity_nanos(value: &str) -> Option<i128> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let number_end = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(number_end);
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    // The amount is numerator / denominator, both scaled by the suffix.
+    let mut numerator: i128 = format!("{whole}{fraction}").parse().ok()?;
+    let mut denominator: i128 = 10i128.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+    let (binary, decimal): (u32, i32) = match suffix {
+        "Ki" => (10, 0),
+        "Mi" => (20, 0),
+        "Gi" => (30, 0),
+        "Ti" => (40, 0),
+        "Pi" => (50, 0),
+        "Ei" => (60, 0),
+        "n" => (0, -9),
+        "u" => (0, -6),
+        "m" => (0, -3),
+        "" => (0, 0),
+        "k" => (0, 3),
+        "M" => (0, 6),
+        "G" => (0, 9),
+        "T" => (0, 12),
+        "P" => (0, 15),
+        "E" => (0, 18),
+        exponent => (0, exponent.strip_prefix(['e', 'E'])?.parse().ok()?),
+    };
+    numerator = numerator.checked_mul(1i128.checked_shl(binary)?)?;
+    let scale = 10i128.checked_pow((decimal + 9).unsigned_abs())?;
+    if decimal + 9 >= 0 {
+        numerator = numerator.checked_mul(scale)?;
+    } else {
+        denominator = denominator.checked_mul(scale)?;
+    }
+
+    let nanos = (numerator + denominator - 1) / denominator;
+    Some(if negative { -nanos } else { nanos })
+}
+
+/// SHA-256 of a StatefulSet spec as the operator renders it.
+pub(super) fn spec_hash(spec: &v1::StatefulSetSpec) -> Result<String, serde_json::Error> {
+    Ok(hex::encode(Sha256::digest(serde_json::to_vec(spec)?)))
+}
+
+/// StatefulSet spec with every pod template container and volume normalized.
+pub(super) fn statefulset_spec(spec: &v1::StatefulSetSpec) -> v1::StatefulSetSpec {
+    let mut spec = spec.clone();
+    if let Some(pod_spec) = spec.template.spec.as_mut() {
+        pod_spec.volumes = Some(volumes(pod_spec.volumes.as_ref())).filter(|v| !v.is_empty());
+        for container in pod_spec
+            .init_containers
+            .iter_mut()
+            .flatten()
+            .chain(pod_spec.containers.iter_mut())
+        {
+            container.image_pull_policy = image_pull_policy(container);
+            container.env = Some(env(container.env.as_ref())).filter(|env| !env.is_empty());
+            container.ports = Some(ports(container.ports.as_ref())).filter(|p| !p.is_empty());
+            container.resources = resources(container.resources.as_ref());
+        }
+    }
+    for claim in spec.volume_claim_templates.iter_mut().flatten() {
+        if let Some(claim_resources) = claim
+            .spec
+            .as_mut()
+            .and_then(|claim_spec| claim_spec.resources.as_mut())
+        {
+            claim_resources.limits = quantities(claim_resources.limits.as_ref());
+            claim_resources.requests = quantities(claim_resources.requests.as_ref());
+        }
+    }
+    spec
+}
+
+/// Whether `live` still holds every field of `desired` with the same value. Objects may carry
+/// extra keys, and arrays of objects extra elements, as added by defaulting or mutating
+/// webhooks; arrays of scalars, such as container args, must match exactly.
+pub(crate) fn contains(live: &Value, desired: &Value) -> bool {
+    match (live, desired) {
+        (Value::Object(live), Value::Object(desired)) => desired.iter().all(|(key, value)| {
+            live.get(key)
+                .is_some_and(|live_value| contains(live_value, value))
+        }),
+        (Value::Array(live), Value::Array(desired)) if desired.iter().all(Value::is_object) => {
+            desired
+                .iter()
+                .all(|item| live.iter().any(|live_item| contains(live_item, item)))
+        }
+        _ => live == desired,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(image: &str, policy: Option<&str>) -> corev1::Container {
+        corev1::Container {
+            image: Some(image.to_string()),
+            image_pull_policy: policy.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn image_pull_policy_follows_api_server_defaults() {
+        let policy = |image, set| image_pull_policy(&container(image, set));
+
+        assert_eq!(
+            policy("rustfs/rustfs:latest", None).as_deref(),
+            Some("Always")
+        );
+        assert_eq!(policy("rustfs/rustfs", None).as_deref(), Some("Always"));
+        assert_eq!(
+            policy("registry:5000/rustfs/rustfs", None).as_deref(),
+            Some("Always")
+        );
+        assert_eq!(
+            policy("rustfs/rustfs:1.0.0", None).as_deref(),
+            Some("IfNotPresent")
+        );
+        assert_eq!(
+            policy("rustfs/rustfs@sha256:abc", None).as_deref(),
+            Some("IfNotPresent")
+        );
+        assert_eq!(
+            policy("rustfs/rustfs:1.0.0", Some("Always")).as_deref(),
+            Some("Always")
+        );
+    }
+
+    #[test]
+    fn contains_tolerates_additions_but_not_changed_values() {
+        let desired = serde_json::json!({
+            "replicas": 4,
+            "containers": [{"name": "rustfs", "args": ["server"], "env": [{"name": "A", "value": "1"}]}],
+        });
+        let mutated = serde_json::json!({
+            "replicas": 4,
+            "containers": [
+                {"name": "istio-proxy", "image": "proxy"},
+                {"name": "rustfs", "args": ["server"], "env": [{"name": "INJECTED", "value": "x"}, {"name": "A", "value": "1"}]},
+            ],
+        });
+        let changed = serde_json::json!({
+            "replicas": 4,
+            "containers": [{"name": "rustfs", "args": ["server"], "env": [{"name": "A", "value": "2"}]}],
+        });
+        let extra_arg = serde_json::json!({
+            "replicas": 4,
+            "containers": [{"name": "rustfs", "args": ["server", "--debug"], "env": [{"name": "A", "value": "1"}]}],
+        });
+
+        assert!(contains(&mutated, &desired));
+        assert!(!contains(&changed, &desired));
+        assert!(!contains(&extra_arg, &desired));
+    }
+
+    #[test]
+    fn env_ignores_order_empty_values_and_field_ref_defaults() {
+        let field_ref = |api_version: Option<&str>| corev1::EnvVar {
+            name: "POD_NAME".to_string(),
+            value_from: Some(corev1::EnvVarSource {
+                field_ref: Some(corev1::ObjectFieldSelector {
+                    api_version: api_version.map(str::to_string),
+                    field_path: "metadata.name".to_string(),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let plain = |name: &str, value: &str| corev1::EnvVar {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+            ..Default::default()
+        };
+
+        let desired = vec![plain("B", "2"), field_ref(None), plain("A", "")];
+        let existing = vec![
+            corev1::EnvVar {
+                name: "A".to_string(),
+                ..Default::default()
+            },
+            field_ref(Some("v1")),
+            plain("B", "2"),
+        ];
+
+        assert_eq!(env(Some(&desired)), env(Some(&existing)));
+        assert_ne!(
+            env(Some(&desired)),
+            env(Some(&vec![
+                plain("B", "3"),
+                field_ref(None),
+                plain("A", "")
+            ]))
+        );
+    }
+
+    #[test]
+    fn quantities_compare_by_amount_not_spelling() {
+        let canonical = |value: &str| quantity(&Quantity(value.to_string())).0;
+
+        assert_eq!(canonical("1"), canonical("1000m"));
+        assert_eq!(canonical("0.5"), canonical("500m"));
+        assert_eq!(canonical("1Gi"), canonical("1024Mi"));
+        assert_eq!(canonical("1k"), canonical("1e3"));
+        assert_eq!(canonical("1500m"), canonical("1.5"));
+        assert_ne!(canonical("1Gi"), canonical("1G"));
+        assert_eq!(canonical("2Gi"), "2147483648000000000n");
+        assert_eq!(canonical("not-a-quantity"), "not-a-quantity");
+
+        let resources = |cpu: &str, memory: &str| {
+            serde_json::json!({
+                "template": {"spec": {"containers": [{
+                    "name": "rustfs",
+                    "resources": {"requests": {"cpu": cpu, "memory": memory}},
+                }]}},
+                "selector": {},
+                "serviceName": "t-hl",
+            })
+        };
+        let normalized = |value: Value| {
+            serde_json::from_value::<v1::StatefulSetSpec>(value)
+                .map(|spec| serde_json::to_value(statefulset_spec(&spec)).unwrap_or_default())
+                .unwrap_or_default()
+        };
+        assert!(contains(
+            &normalized(resources("1", "1Gi")),
+            &normalized(resources("1000m", "1024Mi"))
+        ));
+        assert!(!contains(
+            &normalized(resources("2", "1Gi")),
+            &normalized(resources("1000m", "1024Mi"))
+        ));
+    }
+}
@@ -12,29 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::Tenant;
+use super::config::config_checksum;
+use super::{Component, POOL_LABEL, Tenant};
+use crate::types::v1alpha1::pool::Pool;
 use k8s_openapi::Resource as _;
 use k8s_openapi::api::core::v1 as corev1;
 use k8s_openapi::api::rbac::v1 as rbacv1;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 use kube::{Resource, ResourceExt};
 
-fn role_binding_name(tenant: &Tenant) -> String {
-    format!("{}-role-binding", tenant.name())
-}
+/// Pod template annotation carrying the checksum of `spec.serviceAccountAnnotations`. Cloud IAM
+/// webhooks inject credentials only when a pod is created, so pods roll when the binding changes.
+pub const SERVICE_ACCOUNT_CHECKSUM_ANNOTATION: &str =
+    "operator.rustfs.com/service-account-checksum";
 
-fn role_name(tenant: &Tenant) -> String {
-    format!("{}-role", tenant.name())
-}
+const SA_TOKEN_VOLUME_NAME: &str = "sa-token";
+const SA_TOKEN_FILE: &str = "token";
 
 impl Tenant {
+    pub(crate) fn role_binding_name(&self) -> String {
+        format!("{}-role-binding", self.name())
+    }
+
+    pub(crate) fn pool_role_binding_name(&self, pool_name: &str) -> String {
+        format!("{}-{}-role-binding", self.name(), pool_name)
+    }
+
+    pub(crate) fn role_name(&self) -> String {
+        format!("{}-role", self.name())
+    }
+
     pub fn new_role_binding(&self, sa_name: &str, role: &rbacv1::Role) -> rbacv1::RoleBinding {
         rbacv1::RoleBinding {
             metadata: metav1::ObjectMeta {
-                name: Some(role_binding_name(self)),
+                name: Some(self.role_binding_name()),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(self.common_labels()),
+                labels: Some(self.component_labels(Component::Rbac)),
                 ..Default::default()
             },
             subjects: Some(vec![rbacv1::Subject {
@@ -51,35 +65,78 @@ impl Tenant {
         }
     }
 
+    /// RoleBinding of the Tenant Role for a pool with its own `serviceAccountName`. `None` when
+    /// the pool runs as the Tenant ServiceAccount, which the Tenant RoleBinding already covers.
+    pub fn new_pool_role_binding(
+        &self,
+        pool: &Pool,
+        role: &rbacv1::Role,
+    ) -> Option<rbacv1::RoleBinding> {
+        let sa_name = self.pool_service_account_name(pool);
+        if sa_name == self.service_account_name() {
+            return None;
+        }
+
+        let mut role_binding = self.new_role_binding(&sa_name, role);
+        role_binding.metadata.name = Some(self.pool_role_binding_name(&pool.name));
+        if let Some(labels) = role_binding.metadata.labels.as_mut() {
+            labels.insert(POOL_LABEL.to_owned(), pool.name.clone());
+        }
+        Some(role_binding)
+    }
+
+    /// Role bound to the Tenant ServiceAccount.
+    ///
+    /// Secret access is limited to the Secrets the Tenant references (credentials, user and KMS
+    /// Secrets, and the TLS certificate Secrets); `spec.rbac.extraRules` are appended as-is.
     pub fn new_role(&self) -> rbacv1::Role {
+        let mut secret_names = self.referenced_secret_names();
+        if let Some(secret_name) = self
+            .spec
+            .tls
+            .as_ref()
+            .and_then(|tls| tls.cert_manager.as_ref())
+            .and_then(|cert_manager| cert_manager.secret_name.as_ref())
+            .filter(|name| !name.is_empty())
+        {
+            secret_names.insert(secret_name.clone());
+        }
+
+        let mut rules = Vec::new();
+        if !secret_names.is_empty() {
+            rules.push(rbacv1::PolicyRule {
+                api_groups: Some(vec![String::new()]),
+                resources: Some(vec!["secrets".to_owned()]),
+                resource_names: Some(secret_names.into_iter().collect()),
+                verbs: vec!["get".to_owned(), "watch".to_owned()],
+                ..Default::default()
+            });
+        }
+        rules.push(rbacv1::PolicyRule {
+            api_groups: Some(vec![String::new()]),
+            resources: Some(vec!["services".to_owned()]),
+            verbs: vec!["create".to_owned(), "delete".to_owned(), "get".to_owned()],
+            ..Default::default()
+        });
+        rules.push(rbacv1::PolicyRule {
+            api_groups: Some(vec![Self::group(&()).to_string()]),
+            resources: Some(vec![Self::plural(&()).to_string()]),
+            verbs: vec!["get".to_owned(), "list".to_owned(), "watch".to_owned()],
+            ..Default::default()
+        });
+        if let Some(rbac) = &self.spec.rbac {
+            rules.extend(rbac.extra_rules.iter().cloned());
+        }
+
         rbacv1::Role {
             metadata: metav1::ObjectMeta {
-                name: Some(role_name(self)),
+                name: Some(self.role_name()),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(self.common_labels()),
+                labels: Some(self.component_labels(Component::Rbac)),
                 ..Default::default()
             },
-            rules: Some(vec![
-                rbacv1::PolicyRule {
-                    api_groups: Some(vec![String::new()]),
-                    resources: Some(vec!["secrets".to_owned()]),
-                    verbs: vec!["get".to_owned(), "list".to_owned(), "watch".to_owned()],
-                    ..Default::default()
-                },
-                rbacv1::PolicyRule {
-                    api_groups: Some(vec![String::new()]),
-                    resources: Some(vec!["services".to_owned()]),
-                    verbs: vec!["create".to_owned(), "delete".to_owned(), "get".to_owned()],
-                    ..Default::default()
-                },
-                rbacv1::PolicyRule {
-                    api_groups: Some(vec![Self::group(&()).to_string()]),
-                    resources: Some(vec![Self::plural(&()).to_string()]),
-                    verbs: vec!["get".to_owned(), "list".to_owned(), "watch".to_owned()],
-                    ..Default::default()
-                },
-            ]),
+            rules: Some(rules),
         }
     }
 
@@ -89,16 +146,70 @@ impl Tenant {
                 name: Some(self.service_account_name()),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(self.common_labels()),
+                labels: Some(self.component_labels(Component::Rbac)),
+                annotations: Some(self.spec.service_account_annotations.clone())
+                    .filter(|annotations| !annotations.is_empty()),
                 ..Default::default()
             },
             ..Default::default()
         }
     }
+
+    /// True when the live ServiceAccount is missing an annotation from
+    /// `spec.serviceAccountAnnotations` or carries a different value for it.
+    pub fn service_account_annotations_drifted(&self, existing: &corev1::ServiceAccount) -> bool {
+        let live = existing.annotations();
+        self.spec
+            .service_account_annotations
+            .iter()
+            .any(|(key, value)| live.get(key) != Some(value))
+    }
+
+    /// Checksum of `spec.serviceAccountAnnotations` for pools running as the generated
+    /// ServiceAccount.
+    pub(super) fn service_account_checksum(&self, pool: &Pool) -> Option<String> {
+        (self.spec.service_account_name.is_none()
+            && pool.service_account_name.is_none()
+            && !self.spec.service_account_annotations.is_empty())
+        .then(|| config_checksum(&self.spec.service_account_annotations))
+    }
+
+    /// Projected token volume and mount for `spec.serviceAccountTokenProjection`.
+    pub(super) fn service_account_token_volume(
+        &self,
+    ) -> Option<(corev1::Volume, corev1::VolumeMount)> {
+        let projection = self.spec.service_account_token_projection.as_ref()?;
+        Some((
+            corev1::Volume {
+                name: SA_TOKEN_VOLUME_NAME.to_string(),
+                projected: Some(corev1::ProjectedVolumeSource {
+                    sources: Some(vec![corev1::VolumeProjection {
+                        service_account_token: Some(corev1::ServiceAccountTokenProjection {
+                            audience: Some(projection.audience.clone()),
+                            expiration_seconds: Some(projection.expiration_seconds()),
+                            path: SA_TOKEN_FILE.to_string(),
+                        }),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            corev1::VolumeMount {
+                name: SA_TOKEN_VOLUME_NAME.to_string(),
+                mount_path: projection.mount_path().to_string(),
+                read_only: Some(true),
+                ..Default::default()
+            },
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::types::v1alpha1::credentials::CredsSecretReference;
+    use k8s_openapi::api::rbac::v1 as rbacv1;
+
     // Test: ServiceAccount resource creation
     #[test]
     fn test_new_service_account_structure() {
@@ -121,6 +232,75 @@ mod tests {
         }
     }
 
+    // Test: ServiceAccount annotations pass through for cloud IAM bindings
+    #[test]
+    fn test_new_service_account_annotations() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert!(tenant.new_service_account().metadata.annotations.is_none());
+
+        tenant.spec.service_account_annotations.insert(
+            "eks.amazonaws.com/role-arn".to_string(),
+            "arn:aws:iam::123456789012:role/rustfs".to_string(),
+        );
+        let sa = tenant.new_service_account();
+        assert_eq!(
+            sa.metadata
+                .annotations
+                .unwrap_or_default()
+                .get("eks.amazonaws.com/role-arn")
+                .map(String::as_str),
+            Some("arn:aws:iam::123456789012:role/rustfs")
+        );
+    }
+
+    // Test: annotation drift only concerns keys listed in spec.serviceAccountAnnotations
+    #[test]
+    fn test_service_account_annotations_drifted() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.service_account_annotations.insert(
+            "iam.gke.io/gcp-service-account".to_string(),
+            "rustfs@project.iam.gserviceaccount.com".to_string(),
+        );
+        let mut live = tenant.new_service_account();
+        assert!(!tenant.service_account_annotations_drifted(&live));
+
+        live.metadata
+            .annotations
+            .get_or_insert_default()
+            .insert("unrelated".to_string(), "kept".to_string());
+        assert!(!tenant.service_account_annotations_drifted(&live));
+
+        live.metadata.annotations.get_or_insert_default().insert(
+            "iam.gke.io/gcp-service-account".to_string(),
+            "edited@project.iam.gserviceaccount.com".to_string(),
+        );
+        assert!(tenant.service_account_annotations_drifted(&live));
+    }
+
+    // Test: pods running as the generated ServiceAccount roll when its annotations change
+    #[test]
+    fn test_service_account_checksum() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let pool = tenant.spec.pools[0].clone();
+        assert_eq!(tenant.service_account_checksum(&pool), None);
+
+        tenant.spec.service_account_annotations.insert(
+            "eks.amazonaws.com/role-arn".to_string(),
+            "arn:aws:iam::123456789012:role/a".to_string(),
+        );
+        let first = tenant.service_account_checksum(&pool);
+        assert!(first.is_some());
+        tenant.spec.service_account_annotations.insert(
+            "eks.amazonaws.com/role-arn".to_string(),
+            "arn:aws:iam::123456789012:role/b".to_string(),
+        );
+        assert_ne!(tenant.service_account_checksum(&pool), first);
+
+        let mut custom = pool.clone();
+        custom.service_account_name = Some("archive-sa".to_string());
+        assert_eq!(tenant.service_account_checksum(&custom), None);
+    }
+
     // Test: Role structure validation
     #[test]
     fn test_new_role_structure() {
@@ -134,24 +314,21 @@ mod tests {
 
         // Verify rules
         if let Some(rules) = &role.rules {
-            assert_eq!(rules.len(), 3, "Role should have 3 policy rules");
-
-            // Verify secrets rule
-            let secrets_rule = &rules[0];
-            assert_eq!(secrets_rule.resources, Some(vec!["secrets".to_string()]));
-            assert!(secrets_rule.verbs.contains(&"get".to_string()));
-            assert!(secrets_rule.verbs.contains(&"list".to_string()));
-            assert!(secrets_rule.verbs.contains(&"watch".to_string()));
+            assert_eq!(
+                rules.len(),
+                2,
+                "Role without referenced Secrets has no secrets rule"
+            );
 
             // Verify services rule
-            let services_rule = &rules[1];
+            let services_rule = &rules[0];
             assert_eq!(services_rule.resources, Some(vec!["services".to_string()]));
             assert!(services_rule.verbs.contains(&"create".to_string()));
             assert!(services_rule.verbs.contains(&"delete".to_string()));
             assert!(services_rule.verbs.contains(&"get".to_string()));
 
             // Verify tenants rule
-            let tenants_rule = &rules[2];
+            let tenants_rule = &rules[1];
             assert_eq!(tenants_rule.resources, Some(vec!["tenants".to_string()]));
             assert!(tenants_rule.verbs.contains(&"get".to_string()));
         } else {
@@ -159,6 +336,134 @@ mod tests {
         }
     }
 
+    // Test: secrets rule is scoped to referenced Secrets; extra rules are appended
+    #[test]
+    fn test_new_role_scopes_secrets_and_appends_extra_rules() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.creds_secret = Some(CredsSecretReference::new("tenant-creds"));
+        tenant.spec.rbac = Some(crate::types::v1alpha1::rbac::TenantRbacConfig {
+            create_role: None,
+            extra_rules: vec![rbacv1::PolicyRule {
+                api_groups: Some(vec![String::new()]),
+                resources: Some(vec!["configmaps".to_string()]),
+                verbs: vec!["get".to_string()],
+                ..Default::default()
+            }],
+        });
+
+        let role = tenant.new_role();
+        let rules = role.rules.unwrap_or_default();
+
+        assert_eq!(rules.len(), 4);
+        assert_eq!(rules[0].resources, Some(vec!["secrets".to_string()]));
+        assert_eq!(
+            rules[0].resource_names,
+            Some(vec!["tenant-creds".to_string()])
+        );
+        assert!(!rules[0].verbs.contains(&"list".to_string()));
+        assert_eq!(rules[3].resources, Some(vec!["configmaps".to_string()]));
+        assert!(tenant.validate_rbac().is_ok());
+    }
+
+    // Test: extra rules that would let the pods escalate are rejected
+    #[test]
+    fn test_validate_rbac_rejects_escalating_extra_rules() {
+        let rule = |group: &str, resource: &str, verb: &str| rbacv1::PolicyRule {
+            api_groups: Some(vec![group.to_string()]),
+            resources: Some(vec![resource.to_string()]),
+            verbs: vec![verb.to_string()],
+            ..Default::default()
+        };
+        for extra_rule in [
+            rule("*", "configmaps", "get"),
+            rule("", "*", "get"),
+            rule("", "configmaps", "*"),
+            rule("rbac.authorization.k8s.io", "rolebindings", "create"),
+            rule("", "serviceaccounts/token", "create"),
+            rule("", "pods/exec", "create"),
+            rule("", "pods/exec", "get"),
+            rule("", "pods", "create"),
+            rule("", "serviceaccounts", "create"),
+            rule("apps", "statefulsets", "create"),
+            rule("apps", "deployments", "patch"),
+            rule("", "secrets", "list"),
+        ] {
+            let mut tenant = crate::tests::create_test_tenant(None, None);
+            tenant.spec.rbac = Some(crate::types::v1alpha1::rbac::TenantRbacConfig {
+                create_role: None,
+                extra_rules: vec![extra_rule.clone()],
+            });
+            assert!(
+                matches!(
+                    tenant.validate_rbac(),
+                    Err(crate::types::error::Error::InvalidRbacSpec { .. })
+                ),
+                "{extra_rule:?} must be rejected"
+            );
+        }
+    }
+
+    // Test: read-only extra rules, and secrets rules limited by name, are accepted
+    #[test]
+    fn test_validate_rbac_accepts_read_only_extra_rules() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.rbac = Some(crate::types::v1alpha1::rbac::TenantRbacConfig {
+            create_role: None,
+            extra_rules: vec![
+                rbacv1::PolicyRule {
+                    api_groups: Some(vec!["apps".to_string()]),
+                    resources: Some(vec!["statefulsets".to_string()]),
+                    verbs: vec!["get".to_string(), "list".to_string(), "watch".to_string()],
+                    ..Default::default()
+                },
+                rbacv1::PolicyRule {
+                    api_groups: Some(vec![String::new()]),
+                    resources: Some(vec!["secrets".to_string()]),
+                    resource_names: Some(vec!["tenant-a-extra".to_string()]),
+                    verbs: vec!["get".to_string()],
+                    ..Default::default()
+                },
+            ],
+        });
+        assert!(tenant.validate_rbac().is_ok());
+    }
+
+    // Test: pools with their own ServiceAccount get a separate RoleBinding
+    #[test]
+    fn test_new_pool_role_binding_for_custom_pool_sa() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let role = tenant.new_role();
+        assert!(
+            tenant
+                .new_pool_role_binding(&tenant.spec.pools[0], &role)
+                .is_none()
+        );
+
+        tenant.spec.pools[0].service_account_name = Some("archive-sa".to_string());
+        let pool = &tenant.spec.pools[0];
+        let role_binding = tenant
+            .new_pool_role_binding(pool, &role)
+            .unwrap_or_else(|| panic!("pool with its own ServiceAccount needs a RoleBinding"));
+
+        assert_eq!(
+            role_binding.metadata.name.as_deref(),
+            Some("test-tenant-pool-0-role-binding")
+        );
+        assert_eq!(
+            role_binding
+                .metadata
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.get(super::POOL_LABEL))
+                .map(String::as_str),
+            Some("pool-0")
+        );
+        let subjects = role_binding.subjects.unwrap_or_default();
+        assert_eq!(subjects[0].name, "archive-sa");
+        assert_eq!(role_binding.role_ref.name, "test-tenant-role");
+        assert_eq!(tenant.pool_service_account_name(pool), "archive-sa");
+    }
+
     // Test: RoleBinding with default SA
     #[test]
     fn test_new_role_binding_default_sa() {
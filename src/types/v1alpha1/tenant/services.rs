@@ -12,17 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::Tenant;
+use super::{Component, Tenant};
 use crate::types::v1alpha1::tls::TlsPlan;
 use k8s_openapi::api::core::v1 as corev1;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 use k8s_openapi::apimachinery::pkg::util::intstr;
 
-fn io_service_name(tenant: &Tenant) -> String {
+pub(super) fn io_service_name(tenant: &Tenant) -> String {
     format!("{}-io", tenant.name())
 }
 
-fn console_service_name(tenant: &Tenant) -> String {
+pub(super) fn console_service_name(tenant: &Tenant) -> String {
     format!("{}-console", tenant.name())
 }
 
@@ -38,15 +38,15 @@ impl Tenant {
                 name: Some(io_service_name(self)),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(self.common_labels()),
+                labels: Some(self.component_labels(Component::Storage)),
                 ..Default::default()
             },
             spec: Some(corev1::ServiceSpec {
                 type_: Some("ClusterIP".to_owned()),
                 selector: Some(self.selector_labels()),
                 ports: Some(vec![corev1::ServicePort {
-                    port: 9000,
-                    target_port: Some(intstr::IntOrString::Int(9000)),
+                    port: self.s3_port(),
+                    target_port: Some(intstr::IntOrString::Int(self.s3_port())),
                     name: Some(rustfs_service_port_name(tls_plan).to_owned()),
                     ..Default::default()
                 }]),
@@ -63,15 +63,15 @@ impl Tenant {
                 name: Some(console_service_name(self)),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(self.common_labels()),
+                labels: Some(self.component_labels(Component::Console)),
                 ..Default::default()
             },
             spec: Some(corev1::ServiceSpec {
                 type_: Some("ClusterIP".to_owned()),
                 selector: Some(self.selector_labels()),
                 ports: Some(vec![corev1::ServicePort {
-                    port: 9001,
-                    target_port: Some(intstr::IntOrString::Int(9001)),
+                    port: self.console_port(),
+                    target_port: Some(intstr::IntOrString::Int(self.console_port())),
                     name: Some("http-console".to_owned()),
                     ..Default::default()
                 }]),
@@ -92,7 +92,7 @@ impl Tenant {
                 name: Some(self.headless_service_name()),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(self.common_labels()),
+                labels: Some(self.component_labels(Component::Storage)),
                 ..Default::default()
             },
             spec: Some(corev1::ServiceSpec {
@@ -101,7 +101,7 @@ impl Tenant {
                 publish_not_ready_addresses: Some(true),
                 selector: Some(self.selector_labels()),
                 ports: Some(vec![corev1::ServicePort {
-                    port: 9000,
+                    port: self.s3_port(),
                     name: Some(rustfs_service_port_name(tls_plan).to_owned()),
                     ..Default::default()
                 }]),
@@ -124,6 +124,7 @@ fn rustfs_service_port_name(tls_plan: &TlsPlan) -> &'static str {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use crate::types::v1alpha1::tls::TlsPlan;
+    use k8s_openapi::apimachinery::pkg::util::intstr;
 
     fn first_port_name(service: &k8s_openapi::api::core::v1::Service) -> Option<&str> {
         service
@@ -164,4 +165,33 @@ mod tests {
             Some("https-rustfs")
         );
     }
+
+    #[test]
+    fn custom_ports_are_used_by_services() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.ports = Some(crate::types::v1alpha1::ports::PortsConfig {
+            s3_port: Some(7000),
+            console_port: Some(7001),
+        });
+        let first_port = |service: k8s_openapi::api::core::v1::Service| {
+            service
+                .spec
+                .and_then(|spec| spec.ports)
+                .and_then(|ports| ports.into_iter().next())
+                .map(|port| (port.port, port.target_port))
+        };
+
+        assert_eq!(
+            first_port(tenant.new_io_service()),
+            Some((7000, Some(intstr::IntOrString::Int(7000))))
+        );
+        assert_eq!(
+            first_port(tenant.new_console_service()),
+            Some((7001, Some(intstr::IntOrString::Int(7001))))
+        );
+        assert_eq!(
+            first_port(tenant.new_headless_service()),
+            Some((7000, None))
+        );
+    }
 }
@@ -0,0 +1,452 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Tenant;
+use super::workloads::{stateful_name, volume_claim_template_name};
+use crate::types;
+use crate::types::v1alpha1::persistence::{LocalVolumesConfig, VolumeTemplate};
+use crate::types::v1alpha1::pool::Pool;
+use k8s_openapi::api::core::v1 as corev1;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+
+/// Annotation recording which pre-existing claim a handover claim reuses the volume of.
+pub(crate) const EXISTING_CLAIM_ANNOTATION: &str = "rustfs.com/existing-claim";
+
+/// API group of the CSI `VolumeSnapshot` kind.
+pub(crate) const VOLUME_SNAPSHOT_GROUP: &str = "snapshot.storage.k8s.io";
+
+const DEFAULT_LOCAL_STORAGE_CLASS: &str = "rustfs-local";
+const HOSTNAME_LABEL: &str = "kubernetes.io/hostname";
+
+fn local_storage_class_name(local: &LocalVolumesConfig) -> String {
+    local
+        .storage_class_name
+        .clone()
+        .unwrap_or_else(|| DEFAULT_LOCAL_STORAGE_CLASS.to_owned())
+}
+
+/// Claim template spec matching the PersistentVolumes generated for `persistence.localVolumes`.
+pub(super) fn local_volume_claim_spec(
+    local: &LocalVolumesConfig,
+) -> corev1::PersistentVolumeClaimSpec {
+    corev1::PersistentVolumeClaimSpec {
+        access_modes: Some(vec!["ReadWriteOnce".to_owned()]),
+        storage_class_name: Some(local_storage_class_name(local)),
+        resources: Some(corev1::VolumeResourceRequirements {
+            requests: Some(
+                [("storage".to_owned(), local.capacity.clone())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// A StatefulSet claim slot paired with the pre-existing claim configured for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ExistingClaimSlot {
+    /// Claim name the StatefulSet controller expects (`vol-{volume}-{tenant}-{pool}-{ordinal}`).
+    pub(crate) target: String,
+    /// Claim name listed in `persistence.existingClaims`.
+    pub(crate) source: String,
+}
+
+/// A StatefulSet claim slot paired with the VolumeSnapshot it is restored from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SnapshotRestoreSlot {
+    /// Claim name the StatefulSet controller expects (`vol-{volume}-{tenant}-{pool}-{ordinal}`).
+    pub(crate) target: String,
+    /// Data volume index, which selects the claim template the claim is built from.
+    pub(crate) volume: i32,
+    /// VolumeSnapshot listed in `persistence.dataSource.volumeSnapshots`.
+    pub(crate) snapshot: String,
+}
+
+/// Claim template spec for an entry of `persistence.volumeTemplates`.
+pub(super) fn volume_template_claim_spec(
+    template: &VolumeTemplate,
+    volume_mode: Option<String>,
+) -> corev1::PersistentVolumeClaimSpec {
+    corev1::PersistentVolumeClaimSpec {
+        access_modes: Some(vec!["ReadWriteOnce".to_owned()]),
+        storage_class_name: template.storage_class_name.clone(),
+        resources: Some(corev1::VolumeResourceRequirements {
+            requests: Some(
+                [("storage".to_owned(), template.size.clone())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        volume_mode,
+        ..Default::default()
+    }
+}
+
+impl ExistingClaimSlot {
+    /// The source claim already uses the StatefulSet naming scheme and is adopted as-is.
+    pub(crate) fn is_in_place(&self) -> bool {
+        self.target == self.source
+    }
+}
+
+impl Tenant {
+    /// PVC name the StatefulSet controller derives for a pool volume of a given server.
+    pub(crate) fn pool_claim_name(&self, pool: &Pool, ordinal: i32, volume: i32) -> String {
+        format!(
+            "{}-{}-{}",
+            volume_claim_template_name(volume),
+            stateful_name(self, pool),
+            ordinal
+        )
+    }
+
+    /// Maps `persistence.existingClaims` onto StatefulSet claim slots (server-major order).
+    /// Returns an empty list when the pool provisions its own volumes.
+    pub(crate) fn existing_claim_slots(&self, pool: &Pool) -> Vec<ExistingClaimSlot> {
+        let Some(claims) = &pool.persistence.existing_claims else {
+            return Vec::new();
+        };
+        let volumes_per_server = pool.persistence.volumes_per_server.max(1);
+
+        claims
+            .iter()
+            .enumerate()
+            .map(|(index, source)| {
+                let index = index as i32;
+                ExistingClaimSlot {
+                    target: self.pool_claim_name(
+                        pool,
+                        index / volumes_per_server,
+                        index % volumes_per_server,
+                    ),
+                    source: source.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Maps `persistence.dataSource.volumeSnapshots` onto StatefulSet claim slots (server-major
+    /// order). Returns an empty list when the pool provisions empty volumes.
+    pub(crate) fn snapshot_restore_slots(&self, pool: &Pool) -> Vec<SnapshotRestoreSlot> {
+        let Some(data_source) = &pool.persistence.data_source else {
+            return Vec::new();
+        };
+        let volumes_per_server = pool.persistence.volumes_per_server.max(1);
+
+        (0..)
+            .zip(&data_source.volume_snapshots)
+            .map(|(index, snapshot)| {
+                let (ordinal, volume) = (index / volumes_per_server, index % volumes_per_server);
+                SnapshotRestoreSlot {
+                    target: self.pool_claim_name(pool, ordinal, volume),
+                    volume,
+                    snapshot: snapshot.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Builds the claim of a snapshot restore slot: the pool's claim template for that volume
+    /// with `dataSource` pointing at the VolumeSnapshot. Created before the StatefulSet, which
+    /// then uses it instead of provisioning an empty volume.
+    pub(crate) fn new_snapshot_restore_claim(
+        &self,
+        pool: &Pool,
+        slot: &SnapshotRestoreSlot,
+    ) -> Result<corev1::PersistentVolumeClaim, types::error::Error> {
+        let template_name = volume_claim_template_name(slot.volume);
+        let template = self
+            .volume_claim_templates(pool)?
+            .into_iter()
+            .find(|template| template.metadata.name.as_deref() == Some(template_name.as_str()))
+            .unwrap_or_default();
+
+        let mut spec = template.spec.unwrap_or_default();
+        spec.data_source = Some(corev1::TypedLocalObjectReference {
+            api_group: Some(VOLUME_SNAPSHOT_GROUP.to_owned()),
+            kind: "VolumeSnapshot".to_owned(),
+            name: slot.snapshot.clone(),
+        });
+
+        Ok(corev1::PersistentVolumeClaim {
+            metadata: metav1::ObjectMeta {
+                name: Some(slot.target.clone()),
+                namespace: self.namespace().ok(),
+                labels: template.metadata.labels,
+                annotations: template.metadata.annotations,
+                ..Default::default()
+            },
+            spec: Some(spec),
+            ..Default::default()
+        })
+    }
+
+    /// Builds the claim that takes over the PersistentVolume of a pre-existing claim.
+    ///
+    /// The claim is pre-bound through `volumeName`, so the StatefulSet finds it under the
+    /// expected name and never provisions a new volume for that slot.
+    pub(crate) fn new_handover_claim(
+        &self,
+        pool: &Pool,
+        slot: &ExistingClaimSlot,
+        source: &corev1::PersistentVolumeClaim,
+        volume_name: &str,
+    ) -> corev1::PersistentVolumeClaim {
+        let mut labels = self.pool_labels(pool);
+        if let Some(user_labels) = &pool.persistence.labels {
+            labels.extend(user_labels.clone());
+        }
+
+        let mut annotations = pool.persistence.annotations.clone().unwrap_or_default();
+        annotations.insert(EXISTING_CLAIM_ANNOTATION.to_owned(), slot.source.clone());
+
+        let source_spec = source.spec.clone().unwrap_or_default();
+
+        corev1::PersistentVolumeClaim {
+            metadata: metav1::ObjectMeta {
+                name: Some(slot.target.clone()),
+                namespace: self.namespace().ok(),
+                labels: Some(labels),
+                annotations: Some(annotations),
+                ..Default::default()
+            },
+            spec: Some(corev1::PersistentVolumeClaimSpec {
+                access_modes: source_spec.access_modes,
+                resources: source_spec.resources,
+                storage_class_name: source_spec.storage_class_name,
+                volume_mode: source_spec.volume_mode,
+                volume_name: Some(volume_name.to_owned()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Static `local` PersistentVolumes for `persistence.localVolumes`, one per server disk.
+    ///
+    /// Each volume is pinned to its node and pre-bound to the StatefulSet claim of that slot,
+    /// so the scheduler places every server on the node holding its disks. Volumes are
+    /// cluster-scoped, carry no owner reference, and use the `Retain` reclaim policy.
+    pub(crate) fn new_local_persistent_volumes(
+        &self,
+        pool: &Pool,
+    ) -> Result<Vec<corev1::PersistentVolume>, types::error::Error> {
+        let Some(local) = &pool.persistence.local_volumes else {
+            return Ok(Vec::new());
+        };
+        let namespace = self.namespace()?;
+        let storage_class_name = local_storage_class_name(local);
+
+        let mut volumes = Vec::new();
+        for (ordinal, node) in (0..).zip(&local.nodes) {
+            for (volume, path) in (0..).zip(&node.paths) {
+                let claim_name = self.pool_claim_name(pool, ordinal, volume);
+                volumes.push(corev1::PersistentVolume {
+                    metadata: metav1::ObjectMeta {
+                        name: Some(format!("{}-{}", namespace, claim_name)),
+                        labels: Some(self.pool_labels(pool)),
+                        ..Default::default()
+                    },
+                    spec: Some(corev1::PersistentVolumeSpec {
+                        access_modes: Some(vec!["ReadWriteOnce".to_owned()]),
+                        capacity: Some(
+                            [("storage".to_owned(), local.capacity.clone())]
+                                .into_iter()
+                                .collect(),
+                        ),
+                        claim_ref: Some(corev1::ObjectReference {
+                            namespace: Some(namespace.clone()),
+                            name: Some(claim_name),
+                            ..Default::default()
+                        }),
+                        local: Some(corev1::LocalVolumeSource {
+                            path: path.clone(),
+                            fs_type: None,
+                        }),
+                        node_affinity: Some(corev1::VolumeNodeAffinity {
+                            required: Some(corev1::NodeSelector {
+                                node_selector_terms: vec![corev1::NodeSelectorTerm {
+                                    match_expressions: Some(vec![
+                                        corev1::NodeSelectorRequirement {
+                                            key: HOSTNAME_LABEL.to_owned(),
+                                            operator: "In".to_owned(),
+                                            values: Some(vec![node.node_name.clone()]),
+                                        },
+                                    ]),
+                                    ..Default::default()
+                                }],
+                            }),
+                        }),
+                        persistent_volume_reclaim_policy: Some("Retain".to_owned()),
+                        storage_class_name: Some(storage_class_name.clone()),
+                        volume_mode: Some(
+                            pool.persistence
+                                .volume_mode
+                                .clone()
+                                .unwrap_or_default()
+                                .to_string(),
+                        ),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                });
+            }
+        }
+
+        Ok(volumes)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::EXISTING_CLAIM_ANNOTATION;
+    use k8s_openapi::api::core::v1 as corev1;
+
+    #[test]
+    fn existing_claim_slots_follow_server_major_order() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &mut tenant.spec.pools[0];
+        pool.servers = 2;
+        pool.persistence.volumes_per_server = 2;
+        pool.persistence.existing_claims = Some(
+            [
+                "old-0-a",
+                "old-0-b",
+                "vol-0-test-tenant-pool-0-1",
+                "old-1-b",
+            ]
+            .map(String::from)
+            .to_vec(),
+        );
+        let pool = tenant.spec.pools[0].clone();
+
+        let slots = tenant.existing_claim_slots(&pool);
+
+        let targets: Vec<_> = slots.iter().map(|slot| slot.target.as_str()).collect();
+        assert_eq!(
+            targets,
+            vec![
+                "vol-0-test-tenant-pool-0-0",
+                "vol-1-test-tenant-pool-0-0",
+                "vol-0-test-tenant-pool-0-1",
+                "vol-1-test-tenant-pool-0-1",
+            ]
+        );
+        assert!(!slots[0].is_in_place());
+        assert!(slots[2].is_in_place());
+    }
+
+    #[test]
+    fn local_volumes_are_pinned_and_prebound_to_statefulset_claims() {
+        use crate::types::v1alpha1::persistence::{LocalVolumeNode, LocalVolumesConfig};
+        use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.pools[0].servers = 2;
+        tenant.spec.pools[0].persistence.volumes_per_server = 1;
+        tenant.spec.pools[0].persistence.local_volumes = Some(LocalVolumesConfig {
+            capacity: Quantity("1Ti".to_string()),
+            nodes: vec![
+                LocalVolumeNode {
+                    node_name: "node-a".to_string(),
+                    paths: vec!["/mnt/disk0".to_string()],
+                },
+                LocalVolumeNode {
+                    node_name: "node-b".to_string(),
+                    paths: vec!["/mnt/disk0".to_string()],
+                },
+            ],
+            ..Default::default()
+        });
+        let pool = tenant.spec.pools[0].clone();
+
+        let volumes = tenant.new_local_persistent_volumes(&pool).unwrap();
+
+        assert_eq!(volumes.len(), 2);
+        let second = volumes[1].spec.clone().unwrap_or_default();
+        assert_eq!(
+            volumes[1].metadata.name.as_deref(),
+            Some("default-vol-0-test-tenant-pool-0-1")
+        );
+        assert_eq!(
+            second.claim_ref.and_then(|claim_ref| claim_ref.name),
+            Some("vol-0-test-tenant-pool-0-1".to_string())
+        );
+        assert_eq!(
+            second
+                .node_affinity
+                .and_then(|affinity| affinity.required)
+                .and_then(|required| required.node_selector_terms.into_iter().next())
+                .and_then(|term| term.match_expressions)
+                .and_then(|expressions| expressions.into_iter().next())
+                .and_then(|expression| expression.values),
+            Some(vec!["node-b".to_string()])
+        );
+        assert_eq!(second.storage_class_name.as_deref(), Some("rustfs-local"));
+
+        let statefulset = tenant.new_statefulset(&pool).unwrap();
+        let claim_spec = statefulset
+            .spec
+            .and_then(|spec| spec.volume_claim_templates)
+            .and_then(|templates| templates.into_iter().next())
+            .and_then(|template| template.spec)
+            .unwrap_or_default();
+        assert_eq!(
+            claim_spec.storage_class_name.as_deref(),
+            Some("rustfs-local")
+        );
+    }
+
+    #[test]
+    fn handover_claim_is_prebound_to_source_volume() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+        let slot = super::ExistingClaimSlot {
+            target: tenant.pool_claim_name(pool, 0, 0),
+            source: "legacy-data-0".to_string(),
+        };
+        let source = corev1::PersistentVolumeClaim {
+            spec: Some(corev1::PersistentVolumeClaimSpec {
+                access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+                storage_class_name: Some("local-path".to_string()),
+                volume_name: Some("pv-legacy-0".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let claim = tenant.new_handover_claim(pool, &slot, &source, "pv-legacy-0");
+
+        assert_eq!(
+            claim.metadata.name.as_deref(),
+            Some("vol-0-test-tenant-pool-0-0")
+        );
+        assert_eq!(
+            claim
+                .metadata
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(EXISTING_CLAIM_ANNOTATION))
+                .map(String::as_str),
+            Some("legacy-data-0")
+        );
+        let spec = claim.spec.unwrap_or_default();
+        assert_eq!(spec.volume_name.as_deref(), Some("pv-legacy-0"));
+        assert_eq!(spec.storage_class_name.as_deref(), Some("local-path"));
+    }
+}
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use super::Tenant;
+use super::config::CONFIG_CHECKSUM_ANNOTATION;
+use super::normalize;
+use super::rbac::SERVICE_ACCOUNT_CHECKSUM_ANNOTATION;
+use super::volumes::{local_volume_claim_spec, volume_template_claim_spec};
 use crate::types;
 use crate::types::v1alpha1::encryption::KmsBackendType;
 use crate::types::v1alpha1::pool::Pool;
@@ -21,11 +25,21 @@ use k8s_openapi::api::apps::v1;
 use k8s_openapi::api::core::v1 as corev1;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 
-const VOLUME_CLAIM_TEMPLATE_PREFIX: &str = "vol";
+pub(crate) const VOLUME_CLAIM_TEMPLATE_PREFIX: &str = "vol";
 const DEFAULT_RUN_AS_USER: i64 = 10001;
 const DEFAULT_RUN_AS_GROUP: i64 = 10001;
 const DEFAULT_FS_GROUP: i64 = 10001;
 
+/// Well-known node label the default zone spread constraint uses as its topology key.
+pub const ZONE_TOPOLOGY_KEY: &str = "topology.kubernetes.io/zone";
+
+/// Hash of the StatefulSet spec the operator last applied, used for the three-way update check.
+pub const LAST_APPLIED_HASH_ANNOTATION: &str = "operator.rustfs.com/last-applied-hash";
+
+/// Pod template annotation a rollout restart sets, as `kubectl rollout restart` does. The
+/// operator never applies it, so the three-way update check leaves it in place.
+pub const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+
 const TLS_OPERATOR_MANAGED_ENV_VARS: &[&str] = &[
     "RUSTFS_VOLUMES",
     "RUSTFS_TLS_PATH",
@@ -34,18 +48,41 @@ const TLS_OPERATOR_MANAGED_ENV_VARS: &[&str] = &[
     "RUSTFS_SERVER_MTLS_ENABLE",
 ];
 
+/// Operator variables that `env` may only replace with `spec.allowUnsafeEnvOverrides`: they
+/// define the peer list and the ports that Services and probes point at.
+pub const PROTECTED_OPERATOR_ENV_VARS: &[&str] =
+    &["RUSTFS_VOLUMES", "RUSTFS_ADDRESS", "RUSTFS_CONSOLE_ADDRESS"];
+
 fn is_tls_operator_managed_env_var(name: &str) -> bool {
     TLS_OPERATOR_MANAGED_ENV_VARS.contains(&name)
 }
 
-fn volume_claim_template_name(shard: i32) -> String {
+fn last_applied_hash(statefulset: &v1::StatefulSet) -> Option<&str> {
+    statefulset
+        .metadata
+        .annotations
+        .as_ref()?
+        .get(LAST_APPLIED_HASH_ANNOTATION)
+        .map(String::as_str)
+}
+
+pub(super) fn volume_claim_template_name(shard: i32) -> String {
     format!("{VOLUME_CLAIM_TEMPLATE_PREFIX}-{shard}")
 }
 
-fn stateful_name(tenant: &Tenant, pool: &Pool) -> String {
+pub(super) fn stateful_name(tenant: &Tenant, pool: &Pool) -> String {
     format!("{}-{}", tenant.name(), pool.name)
 }
 
+/// Base directory of a pool's data volumes, shared by `RUSTFS_VOLUMES` and the volume mounts.
+fn pool_data_path(pool: &Pool) -> &str {
+    pool.persistence
+        .path
+        .as_deref()
+        .unwrap_or(super::helper::DEFAULT_MOUNT_PATH)
+        .trim_end_matches('/')
+}
+
 impl Tenant {
     pub(crate) fn rustfs_pool_volume_spec(
         &self,
@@ -55,17 +92,17 @@ impl Tenant {
     ) -> String {
         let tenant_name = self.name();
         let headless_service = self.headless_service_name();
-        let base_path = pool.persistence.path.as_deref().unwrap_or("/data");
-        let base_path = base_path.trim_end_matches('/');
+        let base_path = pool_data_path(pool);
 
         if self.spec.pools.len() == 1 && pool.is_single_node_single_disk() {
             return format!("{base_path}/rustfs0");
         }
 
         format!(
-            "{scheme}://{tenant_name}-{}-{{0...{}}}.{headless_service}.{namespace}.svc.cluster.local:9000{}/rustfs{{0...{}}}",
+            "{scheme}://{tenant_name}-{}-{{0...{}}}.{headless_service}.{namespace}.svc.cluster.local:{}{}/rustfs{{0...{}}}",
             pool.name,
             pool.servers - 1,
+            self.s3_port(),
             base_path,
             pool.persistence.volumes_per_server - 1
         )
@@ -74,7 +111,10 @@ impl Tenant {
     /// Constructs the RUSTFS_VOLUMES environment variable value
     /// Distributed and multi-pool tenants use peer DNS entries, while a single-pool
     /// single-node single-disk tenant uses its local data path.
-    fn rustfs_volumes_env_value(&self, scheme: &str) -> Result<String, types::error::Error> {
+    pub(super) fn rustfs_volumes_env_value(
+        &self,
+        scheme: &str,
+    ) -> Result<String, types::error::Error> {
         let namespace = self.namespace()?;
         let volume_specs = self
             .spec
@@ -95,7 +135,10 @@ impl Tenant {
 
         let default_logging = LoggingConfig::default();
         let logging = self.spec.logging.as_ref().unwrap_or(&default_logging);
-        let mount_path = logging.mount_path.as_deref().unwrap_or("/logs");
+        let mount_path = logging
+            .mount_path
+            .as_deref()
+            .unwrap_or(super::helper::DEFAULT_LOGS_MOUNT_PATH);
 
         match &logging.mode {
             LoggingMode::Stdout => {
@@ -132,31 +175,42 @@ impl Tenant {
 
     /// Creates volume claim templates for a pool
     /// Returns a vector of PersistentVolumeClaim templates for StatefulSet
-    fn volume_claim_templates(
+    pub(super) fn volume_claim_templates(
         &self,
         pool: &Pool,
     ) -> Result<Vec<corev1::PersistentVolumeClaim>, types::error::Error> {
         // Get PVC spec or create default (ReadWriteOnce, 10Gi)
-        let spec = pool
-            .persistence
-            .volume_claim_template
-            .clone()
-            .unwrap_or_else(|| {
-                let mut resources = std::collections::BTreeMap::new();
-                resources.insert(
-                    "storage".to_string(),
-                    k8s_openapi::apimachinery::pkg::api::resource::Quantity("10Gi".to_string()),
-                );
-
-                corev1::PersistentVolumeClaimSpec {
-                    access_modes: Some(vec!["ReadWriteOnce".to_string()]),
-                    resources: Some(corev1::VolumeResourceRequirements {
-                        requests: Some(resources),
+        let mut spec = match &pool.persistence.local_volumes {
+            Some(local) => local_volume_claim_spec(local),
+            None => pool
+                .persistence
+                .volume_claim_template
+                .clone()
+                .unwrap_or_else(|| {
+                    let mut resources = std::collections::BTreeMap::new();
+                    resources.insert(
+                        "storage".to_string(),
+                        k8s_openapi::apimachinery::pkg::api::resource::Quantity("10Gi".to_string()),
+                    );
+
+                    corev1::PersistentVolumeClaimSpec {
+                        access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+                        resources: Some(corev1::VolumeResourceRequirements {
+                            requests: Some(resources),
+                            ..Default::default()
+                        }),
                         ..Default::default()
-                    }),
-                    ..Default::default()
-                }
-            });
+                    }
+                }),
+        };
+
+        if pool.persistence.volume_mode.is_some() {
+            spec.volume_mode = pool
+                .persistence
+                .volume_mode
+                .as_ref()
+                .map(ToString::to_string);
+        }
 
         // Start with operator-managed labels (follows Kubernetes recommended labels)
         let mut labels = self.pool_labels(pool);
@@ -169,6 +223,20 @@ impl Tenant {
         // Get annotations from persistence config
         let annotations = pool.persistence.annotations.clone();
 
+        // Per-volume templates override the uniform spec: data volumes by position,
+        // auxiliary volumes (with a mountPath) as additional claim templates.
+        let (data_volume_templates, auxiliary_volume_templates): (Vec<_>, Vec<_>) = pool
+            .persistence
+            .volume_templates
+            .iter()
+            .flatten()
+            .partition(|template| template.is_data_volume());
+        let volume_mode = pool
+            .persistence
+            .volume_mode
+            .as_ref()
+            .map(ToString::to_string);
+
         // Generate volume claim templates for each volume
         let templates: Vec<_> = (0..pool.persistence.volumes_per_server)
             .map(|i| corev1::PersistentVolumeClaim {
@@ -178,13 +246,30 @@ impl Tenant {
                     annotations: annotations.clone(),
                     ..Default::default()
                 },
-                spec: Some(spec.clone()),
+                spec: Some(
+                    data_volume_templates
+                        .get(i as usize)
+                        .map(|template| volume_template_claim_spec(template, volume_mode.clone()))
+                        .unwrap_or_else(|| spec.clone()),
+                ),
                 ..Default::default()
             })
             .collect();
 
         // Add log PVC if persistent logging is enabled
         let mut all_templates = templates;
+        all_templates.extend(auxiliary_volume_templates.into_iter().map(|template| {
+            corev1::PersistentVolumeClaim {
+                metadata: metav1::ObjectMeta {
+                    name: Some(template.name.clone()),
+                    labels: Some(labels.clone()),
+                    annotations: annotations.clone(),
+                    ..Default::default()
+                },
+                spec: Some(volume_template_claim_spec(template, None)),
+                ..Default::default()
+            }
+        }));
         if let Some(logging) = &self.spec.logging {
             use crate::types::v1alpha1::logging::LoggingMode;
             if logging.mode == LoggingMode::Persistent {
@@ -343,35 +428,8 @@ impl Tenant {
         (env, volumes, mounts)
     }
 
-    pub fn new_statefulset(&self, pool: &Pool) -> Result<v1::StatefulSet, types::error::Error> {
-        self.new_statefulset_with_tls_plan(pool, &TlsPlan::disabled())
-    }
-
-    pub fn new_statefulset_with_tls_plan(
-        &self,
-        pool: &Pool,
-        tls_plan: &TlsPlan,
-    ) -> Result<v1::StatefulSet, types::error::Error> {
-        let labels = self.pool_labels(pool);
-        let selector_labels = self.pool_selector_labels(pool);
-
-        // Generate volume claim templates using helper function
-        let volume_claim_templates = self.volume_claim_templates(pool)?;
-
-        // Generate volume mounts for each volume
-        // Default path is /data if not specified
-        // Volume mount names must match the volume claim template names (vol-0, vol-1, etc.)
-        // Mount paths follow RustFS convention: /data/rustfs0, /data/rustfs1, etc.
-        let base_path = pool.persistence.path.as_deref().unwrap_or("/data");
-        let mut volume_mounts: Vec<corev1::VolumeMount> = (0..pool.persistence.volumes_per_server)
-            .map(|i| corev1::VolumeMount {
-                name: volume_claim_template_name(i),
-                mount_path: format!("{}/rustfs{}", base_path.trim_end_matches('/'), i),
-                ..Default::default()
-            })
-            .collect();
-
-        // Generate environment variables: operator-managed + user-provided
+    /// Env vars the operator sets on every RustFS container, before user overrides.
+    fn operator_env(&self, tls_plan: &TlsPlan) -> Result<Vec<corev1::EnvVar>, types::error::Error> {
         let mut env_vars = Vec::new();
 
         // Add RUSTFS_VOLUMES environment variable for the inferred storage layout.
@@ -386,13 +444,13 @@ impl Tenant {
         // Add required RustFS environment variables
         env_vars.push(corev1::EnvVar {
             name: "RUSTFS_ADDRESS".to_owned(),
-            value: Some("0.0.0.0:9000".to_owned()),
+            value: Some(format!("0.0.0.0:{}", self.s3_port())),
             ..Default::default()
         });
 
         env_vars.push(corev1::EnvVar {
             name: "RUSTFS_CONSOLE_ADDRESS".to_owned(),
-            value: Some("0.0.0.0:9001".to_owned()),
+            value: Some(format!("0.0.0.0:{}", self.console_port())),
             ..Default::default()
         });
 
@@ -411,7 +469,7 @@ impl Tenant {
                 value_from: Some(corev1::EnvVarSource {
                     secret_key_ref: Some(corev1::SecretKeySelector {
                         name: cfg.name.clone(),
-                        key: "accesskey".to_string(),
+                        key: cfg.access_key().to_string(),
                         optional: Some(false),
                     }),
                     ..Default::default()
@@ -424,7 +482,7 @@ impl Tenant {
                 value_from: Some(corev1::EnvVarSource {
                     secret_key_ref: Some(corev1::SecretKeySelector {
                         name: cfg.name.clone(),
-                        key: "secretkey".to_string(),
+                        key: cfg.secret_key().to_string(),
                         optional: Some(false),
                     }),
                     ..Default::default()
@@ -433,17 +491,114 @@ impl Tenant {
             });
         }
 
-        // Merge with user-provided environment variables.
-        // Preserve the legacy override behavior except for TLS runtime values that
-        // must stay aligned with the rendered TLS mounts, probes, status, and hash.
-        for user_env in &self.spec.env {
+        Ok(env_vars)
+    }
+
+    /// Container env of a pool: operator vars, then `spec.performanceProfile` presets, then
+    /// `spec.env`, then `spec.pools[].env`; later entries win. Also returns the operator vars that
+    /// user entries replaced. TLS runtime vars stay operator-managed, so they are never replaced.
+    pub(crate) fn pool_env(
+        &self,
+        pool: &Pool,
+        tls_plan: &TlsPlan,
+    ) -> Result<(Vec<corev1::EnvVar>, Vec<String>), types::error::Error> {
+        let mut env_vars = self.operator_env(tls_plan)?;
+        let operator_names: Vec<String> = env_vars.iter().map(|var| var.name.clone()).collect();
+        if let Some(profile) = self.spec.performance_profile {
+            env_vars.extend(
+                profile
+                    .env()
+                    .into_iter()
+                    .filter(|var| !operator_names.contains(&var.name)),
+            );
+        }
+        let mut overridden = Vec::new();
+        for user_env in self.spec.env.iter().chain(&pool.env) {
             if tls_plan.enabled && is_tls_operator_managed_env_var(&user_env.name) {
                 continue;
             }
+            if operator_names.contains(&user_env.name) && !overridden.contains(&user_env.name) {
+                overridden.push(user_env.name.clone());
+            }
             // Remove any existing var with the same name to allow non-reserved overrides.
             env_vars.retain(|e| e.name != user_env.name);
             env_vars.push(user_env.clone());
         }
+        Ok((env_vars, overridden))
+    }
+
+    /// The pool's own constraints, or a zone spread with `maxSkew: 1` over the pool's pods when
+    /// the cluster is multi-zone, the Tenant is not standalone, and `spec.zoneSpread` is not
+    /// disabled.
+    fn pool_topology_spread_constraints(
+        &self,
+        pool: &Pool,
+        multi_zone: bool,
+    ) -> Option<Vec<corev1::TopologySpreadConstraint>> {
+        if pool.scheduling.topology_spread_constraints.is_some() {
+            return pool.scheduling.topology_spread_constraints.clone();
+        }
+        let zone_spread = self.spec.zone_spread.clone().unwrap_or_default();
+        if !multi_zone || self.is_standalone() || !zone_spread.enabled.unwrap_or(true) {
+            return None;
+        }
+        Some(vec![corev1::TopologySpreadConstraint {
+            max_skew: 1,
+            topology_key: ZONE_TOPOLOGY_KEY.to_string(),
+            when_unsatisfiable: zone_spread
+                .when_unsatisfiable
+                .unwrap_or_default()
+                .to_string(),
+            label_selector: Some(metav1::LabelSelector {
+                match_labels: Some(self.pool_selector_labels(pool)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }])
+    }
+
+    pub fn new_statefulset(&self, pool: &Pool) -> Result<v1::StatefulSet, types::error::Error> {
+        self.new_statefulset_with_tls_plan(pool, &TlsPlan::disabled())
+    }
+
+    pub fn new_statefulset_with_tls_plan(
+        &self,
+        pool: &Pool,
+        tls_plan: &TlsPlan,
+    ) -> Result<v1::StatefulSet, types::error::Error> {
+        let labels = self.pool_labels(pool);
+        let selector_labels = self.pool_selector_labels(pool);
+
+        // Generate volume claim templates using helper function
+        let volume_claim_templates = self.volume_claim_templates(pool)?;
+
+        // Generate volume mounts for each volume
+        // Volume mount names must match the volume claim template names (vol-0, vol-1, etc.)
+        // Mount paths follow RustFS convention: /data/rustfs0, /data/rustfs1, etc.
+        let base_path = pool_data_path(pool);
+        let mut volume_mounts: Vec<corev1::VolumeMount> = (0..pool.persistence.volumes_per_server)
+            .map(|i| corev1::VolumeMount {
+                name: volume_claim_template_name(i),
+                mount_path: format!("{base_path}/rustfs{i}"),
+                ..Default::default()
+            })
+            .collect();
+
+        volume_mounts.extend(
+            pool.persistence
+                .volume_templates
+                .iter()
+                .flatten()
+                .filter_map(|template| {
+                    Some(corev1::VolumeMount {
+                        name: template.name.clone(),
+                        mount_path: template.mount_path.clone()?,
+                        ..Default::default()
+                    })
+                }),
+        );
+
+        let (mut env_vars, _) = self.pool_env(pool, tls_plan)?;
 
         // Configure logging based on tenant.spec.logging
         // Default: stdout (cloud-native best practice)
@@ -457,9 +612,36 @@ impl Tenant {
         env_vars.extend(kms_env);
         pod_volumes.append(&mut kms_volumes);
         volume_mounts.append(&mut kms_mounts);
+        let (notify_env, mut notify_volumes, mut notify_mounts) =
+            self.configure_event_notifications();
+        env_vars.extend(notify_env);
+        pod_volumes.append(&mut notify_volumes);
+        volume_mounts.append(&mut notify_mounts);
         pod_volumes.extend(tls_plan.volumes.clone());
         volume_mounts.extend(tls_plan.volume_mounts.clone());
 
+        if let Some((token_volume, token_mount)) = self.service_account_token_volume() {
+            pod_volumes.push(token_volume);
+            volume_mounts.push(token_mount);
+        }
+
+        let (config_volume, config_mount) = self.runtime_config_volume();
+        pod_volumes.push(config_volume);
+        volume_mounts.push(config_mount);
+        let pod_metadata = pool.pod_metadata.clone().unwrap_or_default();
+        let mut pod_labels = pod_metadata.labels;
+        pod_labels.extend(labels.clone());
+        let mut pod_template_annotations = pod_metadata.annotations;
+        pod_template_annotations.extend(tls_plan.pod_template_annotations.clone());
+        pod_template_annotations.insert(
+            CONFIG_CHECKSUM_ANNOTATION.to_string(),
+            self.runtime_config_checksum(tls_plan)?,
+        );
+        if let Some(checksum) = self.service_account_checksum(pool) {
+            pod_template_annotations
+                .insert(SERVICE_ACCOUNT_CHECKSUM_ANNOTATION.to_string(), checksum);
+        }
+
         // Enforce non-root execution and make mounted volumes writable by RustFS user.
         // If spec.securityContext overrides are set, use those values instead.
         let sc = self.spec.security_context.as_ref();
@@ -481,23 +663,22 @@ impl Tenant {
 
         let container = corev1::Container {
             name: "rustfs".to_owned(),
-            image: Some(super::helper::get_rustfs_image_or_default(
-                self.spec.image.as_ref(),
-            )),
+            image: Some(self.rustfs_image()),
             env: if env_vars.is_empty() {
                 None
             } else {
                 Some(env_vars)
             },
+            env_from: Some(self.spec.env_from.clone()).filter(|env_from| !env_from.is_empty()),
             ports: Some(vec![
                 corev1::ContainerPort {
-                    container_port: 9000,
+                    container_port: self.s3_port(),
                     name: Some("http".to_owned()),
                     protocol: Some("TCP".to_owned()),
                     ..Default::default()
                 },
                 corev1::ContainerPort {
-                    container_port: 9001,
+                    container_port: self.console_port(),
                     name: Some("console".to_owned()),
                     protocol: Some("TCP".to_owned()),
                     ..Default::default()
@@ -506,76 +687,90 @@ impl Tenant {
             volume_mounts: Some(volume_mounts),
             lifecycle: self.spec.lifecycle.clone(),
             // Apply pool-level resource requirements to container
-            resources: pool.scheduling.resources.clone(),
+            resources: pool.scheduling.resources.clone().or_else(|| {
+                self.is_standalone()
+                    .then(crate::types::v1alpha1::profile::standalone_resources)
+            }),
             image_pull_policy: self
                 .spec
                 .image_pull_policy
                 .as_ref()
                 .map(ToString::to_string),
-            liveness_probe: Some(http_probe("/health", tls_plan.probe_scheme)),
-            readiness_probe: Some(http_probe("/health/ready", tls_plan.probe_scheme)),
-            startup_probe: Some(http_probe("/health", tls_plan.probe_scheme)),
+            liveness_probe: Some(http_probe("/health", self.s3_port(), tls_plan.probe_scheme)),
+            readiness_probe: Some(http_probe(
+                "/health/ready",
+                self.s3_port(),
+                tls_plan.probe_scheme,
+            )),
+            startup_probe: Some(http_probe("/health", self.s3_port(), tls_plan.probe_scheme)),
             termination_message_policy: Some("FallbackToLogsOnError".to_string()),
             ..Default::default()
         };
 
+        let spec = v1::StatefulSetSpec {
+            replicas: Some(pool.servers),
+            service_name: Some(self.headless_service_name()),
+            pod_management_policy: Some(
+                self.spec
+                    .pod_management_policy
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            selector: metav1::LabelSelector {
+                match_labels: Some(selector_labels),
+                ..Default::default()
+            },
+            template: corev1::PodTemplateSpec {
+                metadata: Some(metav1::ObjectMeta {
+                    labels: Some(pod_labels),
+                    annotations: Some(pod_template_annotations),
+                    ..Default::default()
+                }),
+                spec: Some(corev1::PodSpec {
+                    service_account_name: Some(self.pool_service_account_name(pool)),
+                    containers: vec![container],
+                    security_context: pod_security_context,
+                    volumes: Some(pod_volumes),
+                    scheduler_name: self.spec.scheduler.clone(),
+                    // Pool-level priority class overrides tenant-level
+                    priority_class_name: pool
+                        .scheduling
+                        .priority_class_name
+                        .clone()
+                        .or_else(|| self.spec.priority_class_name.clone()),
+                    // Pool-level scheduling controls
+                    node_selector: pool.scheduling.node_selector.clone(),
+                    affinity: pool.scheduling.affinity.clone(),
+                    tolerations: pool.scheduling.tolerations.clone(),
+                    topology_spread_constraints: self
+                        .pool_topology_spread_constraints(pool, tls_plan.multi_zone),
+                    image_pull_secrets: self.spec.image_pull_secret.clone().map(|s| vec![s]),
+                    ..Default::default()
+                }),
+            },
+            volume_claim_templates: Some(volume_claim_templates),
+            ..Default::default()
+        };
+
         Ok(v1::StatefulSet {
             metadata: metav1::ObjectMeta {
                 name: Some(stateful_name(self, pool)),
                 namespace: self.namespace().ok(),
                 owner_references: Some(vec![self.new_owner_ref()]),
-                labels: Some(labels.clone()),
-                ..Default::default()
-            },
-            spec: Some(v1::StatefulSetSpec {
-                replicas: Some(pool.servers),
-                service_name: Some(self.headless_service_name()),
-                pod_management_policy: Some(
-                    self.spec
-                        .pod_management_policy
-                        .as_ref()
-                        .cloned()
-                        .unwrap_or_default()
-                        .to_string(),
+                labels: Some(labels),
+                annotations: Some(
+                    [(
+                        LAST_APPLIED_HASH_ANNOTATION.to_string(),
+                        normalize::spec_hash(&spec)?,
+                    )]
+                    .into_iter()
+                    .collect(),
                 ),
-                selector: metav1::LabelSelector {
-                    match_labels: Some(selector_labels),
-                    ..Default::default()
-                },
-                template: corev1::PodTemplateSpec {
-                    metadata: Some(metav1::ObjectMeta {
-                        labels: Some(labels),
-                        annotations: (!tls_plan.pod_template_annotations.is_empty())
-                            .then(|| tls_plan.pod_template_annotations.clone()),
-                        ..Default::default()
-                    }),
-                    spec: Some(corev1::PodSpec {
-                        service_account_name: Some(self.service_account_name()),
-                        containers: vec![container],
-                        security_context: pod_security_context,
-                        volumes: Some(pod_volumes),
-                        scheduler_name: self.spec.scheduler.clone(),
-                        // Pool-level priority class overrides tenant-level
-                        priority_class_name: pool
-                            .scheduling
-                            .priority_class_name
-                            .clone()
-                            .or_else(|| self.spec.priority_class_name.clone()),
-                        // Pool-level scheduling controls
-                        node_selector: pool.scheduling.node_selector.clone(),
-                        affinity: pool.scheduling.affinity.clone(),
-                        tolerations: pool.scheduling.tolerations.clone(),
-                        topology_spread_constraints: pool
-                            .scheduling
-                            .topology_spread_constraints
-                            .clone(),
-                        image_pull_secrets: self.spec.image_pull_secret.clone().map(|s| vec![s]),
-                        ..Default::default()
-                    }),
-                },
-                volume_claim_templates: Some(volume_claim_templates),
                 ..Default::default()
-            }),
+            },
+            spec: Some(spec),
             ..Default::default()
         })
     }
@@ -621,6 +816,19 @@ impl Tenant {
                 msg: "Desired StatefulSet missing spec".to_string(),
             })?;
 
+        // Three-way check: when the spec the operator last applied is still the desired one,
+        // anything else in the live object was added by someone else, such as a mutating
+        // webhook. Only the operator's own fields have to be present with the applied values.
+        if let Some(applied) = last_applied_hash(existing) {
+            if Some(applied) != last_applied_hash(&desired) {
+                return Ok(true);
+            }
+            return Ok(!normalize::contains(
+                &serde_json::to_value(normalize::statefulset_spec(existing_spec))?,
+                &serde_json::to_value(normalize::statefulset_spec(desired_spec))?,
+            ));
+        }
+
         // Check replicas (server count)
         if existing_spec.replicas != desired_spec.replicas {
             return Ok(true);
@@ -697,9 +905,9 @@ impl Tenant {
             return Ok(true);
         }
 
-        // Check pod volumes (TLS Secret/CA mounts live here).
-        if serde_json::to_value(&existing_pod_spec.volumes)?
-            != serde_json::to_value(&desired_pod_spec.volumes)?
+        // Check pod volumes (TLS Secret/CA mounts live here), ignoring defaulted file modes.
+        if serde_json::to_value(normalize::volumes(existing_pod_spec.volumes.as_ref()))?
+            != serde_json::to_value(normalize::volumes(desired_pod_spec.volumes.as_ref()))?
         {
             return Ok(true);
         }
@@ -752,21 +960,35 @@ impl Tenant {
             return Ok(true);
         }
 
-        // Check image pull policy
-        if existing_container.image_pull_policy != desired_container.image_pull_policy {
+        // Check image pull policy, resolving the default the API server fills in
+        if normalize::image_pull_policy(existing_container)
+            != normalize::image_pull_policy(desired_container)
+        {
+            return Ok(true);
+        }
+
+        // Check environment variables, ignoring order and server-side defaults
+        if serde_json::to_value(normalize::env(existing_container.env.as_ref()))?
+            != serde_json::to_value(normalize::env(desired_container.env.as_ref()))?
+        {
+            return Ok(true);
+        }
+
+        // Check bulk env sources
+        if existing_container.env_from != desired_container.env_from {
             return Ok(true);
         }
 
-        // Check environment variables (compare as JSON for deep equality)
-        if serde_json::to_value(&existing_container.env)?
-            != serde_json::to_value(&desired_container.env)?
+        // Check container ports, ignoring the defaulted protocol
+        if serde_json::to_value(normalize::ports(existing_container.ports.as_ref()))?
+            != serde_json::to_value(normalize::ports(desired_container.ports.as_ref()))?
         {
             return Ok(true);
         }
 
         // Check resources (compare as JSON for deep equality)
-        if serde_json::to_value(&existing_container.resources)?
-            != serde_json::to_value(&desired_container.resources)?
+        if serde_json::to_value(normalize::resources(existing_container.resources.as_ref()))?
+            != serde_json::to_value(normalize::resources(desired_container.resources.as_ref()))?
         {
             return Ok(true);
         }
@@ -859,6 +1081,21 @@ impl Tenant {
             });
         }
 
+        // The S3 port is embedded in every peer endpoint. The CRD rejects changing it, but a
+        // Tenant whose s3Port was edited before that rule existed is caught here.
+        if let (Some(existing_address), Some(desired_address)) =
+            (rustfs_address(existing_spec), rustfs_address(desired_spec))
+            && existing_address != desired_address
+        {
+            return Err(types::error::Error::ImmutableFieldModified {
+                name: ss_name,
+                field: "spec.ports.s3Port".to_string(),
+                message: format!(
+                    "S3 port cannot change: the pool listens on {existing_address}, the spec asks for {desired_address}."
+                ),
+            });
+        }
+
         // Validate serviceName is unchanged (immutable field)
         if existing_spec.service_name != desired_spec.service_name {
             return Err(types::error::Error::ImmutableFieldModified {
@@ -935,13 +1172,29 @@ impl Tenant {
     }
 }
 
+/// `RUSTFS_ADDRESS` of the RustFS container in a StatefulSet.
+fn rustfs_address(spec: &v1::StatefulSetSpec) -> Option<&str> {
+    spec.template
+        .spec
+        .as_ref()?
+        .containers
+        .iter()
+        .flat_map(|container| container.env.iter().flatten())
+        .find(|var| var.name == "RUSTFS_ADDRESS")?
+        .value
+        .as_deref()
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::{DEFAULT_FS_GROUP, DEFAULT_RUN_AS_GROUP, DEFAULT_RUN_AS_USER};
     use crate::types::v1alpha1::logging::{LoggingConfig, LoggingMode};
+    use crate::types::v1alpha1::pool::Pool;
+    use crate::types::v1alpha1::ports::PortsConfig;
     use crate::types::v1alpha1::tls::{SecretKeyReference, TlsPlan};
     use k8s_openapi::api::core::v1 as corev1;
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 
     fn image_pull_secret(name: &str) -> corev1::LocalObjectReference {
         corev1::LocalObjectReference {
@@ -1129,6 +1382,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn custom_pool_path_is_shared_by_rustfs_volumes_and_mounts() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.pools[0].servers = 1;
+        tenant.spec.pools[0].persistence.volumes_per_server = 1;
+        tenant.spec.pools[0].persistence.path = Some("/mnt/rustfs/".to_string());
+        let pool = &tenant.spec.pools[0];
+
+        let statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet with a custom path");
+
+        let pod_spec = statefulset.spec.unwrap().template.spec.unwrap();
+        let container = &pod_spec.containers[0];
+        assert_eq!(
+            env_value(container, "RUSTFS_VOLUMES"),
+            Some("/mnt/rustfs/rustfs0")
+        );
+        assert!(
+            container
+                .volume_mounts
+                .iter()
+                .flatten()
+                .any(|mount| mount.mount_path == "/mnt/rustfs/rustfs0")
+        );
+    }
+
+    #[test]
+    fn volume_templates_render_per_volume_claims_and_auxiliary_mounts() {
+        use crate::types::v1alpha1::persistence::VolumeTemplate;
+        use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.pools[0].persistence.volumes_per_server = 2;
+        tenant.spec.pools[0].persistence.volume_templates = Some(vec![
+            VolumeTemplate {
+                name: "fast".to_string(),
+                size: Quantity("100Gi".to_string()),
+                storage_class_name: Some("nvme".to_string()),
+                mount_path: None,
+            },
+            VolumeTemplate {
+                name: "capacity".to_string(),
+                size: Quantity("10Ti".to_string()),
+                storage_class_name: Some("hdd".to_string()),
+                mount_path: None,
+            },
+            VolumeTemplate {
+                name: "cache".to_string(),
+                size: Quantity("20Gi".to_string()),
+                storage_class_name: Some("nvme".to_string()),
+                mount_path: Some("/cache".to_string()),
+            },
+        ]);
+        let pool = &tenant.spec.pools[0];
+
+        let spec = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet with volume templates")
+            .spec
+            .unwrap();
+
+        let claims: Vec<_> = spec
+            .volume_claim_templates
+            .unwrap_or_default()
+            .into_iter()
+            .map(|claim| {
+                (
+                    claim.metadata.name.unwrap_or_default(),
+                    claim.spec.and_then(|spec| spec.storage_class_name),
+                )
+            })
+            .collect();
+        assert_eq!(
+            claims,
+            vec![
+                ("vol-0".to_string(), Some("nvme".to_string())),
+                ("vol-1".to_string(), Some("hdd".to_string())),
+                ("cache".to_string(), Some("nvme".to_string())),
+            ]
+        );
+
+        let container = &spec.template.spec.unwrap().containers[0];
+        let mounts = container.volume_mounts.as_ref().unwrap();
+        assert!(
+            mounts
+                .iter()
+                .any(|mount| mount.name == "cache" && mount.mount_path == "/cache")
+        );
+        assert!(
+            env_value(container, "RUSTFS_VOLUMES").is_some_and(|value| !value.contains("cache"))
+        );
+    }
+
     #[test]
     fn mixed_pool_single_node_single_disk_uses_peer_dns_volume() {
         let mut tenant = crate::tests::create_test_tenant(None, None);
@@ -1158,6 +1505,173 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn setting_s3_port_on_an_existing_pool_is_rejected() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let existing = tenant
+            .new_statefulset(&tenant.spec.pools[0])
+            .expect("Should create StatefulSet");
+
+        tenant.spec.ports = Some(PortsConfig {
+            s3_port: Some(crate::types::v1alpha1::ports::DEFAULT_S3_PORT),
+            console_port: None,
+        });
+        assert!(
+            tenant
+                .validate_statefulset_update(&existing, &tenant.spec.pools[0])
+                .is_ok()
+        );
+
+        tenant.spec.ports = Some(PortsConfig {
+            s3_port: Some(7000),
+            console_port: None,
+        });
+        let error = tenant
+            .validate_statefulset_update(&existing, &tenant.spec.pools[0])
+            .expect_err("unset to 7000 changes the S3 port");
+        assert!(matches!(
+            error,
+            crate::types::error::Error::ImmutableFieldModified { ref field, .. }
+                if field == "spec.ports.s3Port"
+        ));
+    }
+
+    #[test]
+    fn custom_ports_flow_into_env_container_ports_probes_and_peers() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.pools[0].servers = 2;
+        tenant.spec.ports = Some(PortsConfig {
+            s3_port: Some(7000),
+            console_port: Some(7001),
+        });
+        let pool = &tenant.spec.pools[0];
+
+        let statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet with custom ports");
+
+        let pod_spec = statefulset.spec.unwrap().template.spec.unwrap();
+        let container = &pod_spec.containers[0];
+        assert_eq!(env_value(container, "RUSTFS_ADDRESS"), Some("0.0.0.0:7000"));
+        assert_eq!(
+            env_value(container, "RUSTFS_CONSOLE_ADDRESS"),
+            Some("0.0.0.0:7001")
+        );
+        assert!(
+            env_value(container, "RUSTFS_VOLUMES")
+                .expect("RUSTFS_VOLUMES should be configured")
+                .contains(".svc.cluster.local:7000/data/")
+        );
+        let ports: Vec<_> = container
+            .ports
+            .iter()
+            .flatten()
+            .map(|port| port.container_port)
+            .collect();
+        assert_eq!(ports, vec![7000, 7001]);
+        for probe in [
+            &container.liveness_probe,
+            &container.readiness_probe,
+            &container.startup_probe,
+        ] {
+            let port = probe
+                .as_ref()
+                .and_then(|probe| probe.http_get.as_ref())
+                .map(|http_get| http_get.port.clone());
+            assert_eq!(port, Some(IntOrString::Int(7000)));
+        }
+    }
+
+    #[test]
+    fn pool_env_overrides_tenant_env_and_reports_operator_overrides() {
+        let plain = |name: &str, value: &str| corev1::EnvVar {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+            ..Default::default()
+        };
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.env = vec![plain("RUSTFS_CACHE_SIZE", "1Gi"), plain("TENANT_ONLY", "a")];
+        let mut archive = tenant.spec.pools[0].clone();
+        archive.name = "archive".to_string();
+        archive.env = vec![
+            plain("RUSTFS_CACHE_SIZE", "64Mi"),
+            plain("RUSTFS_CONSOLE_ENABLE", "false"),
+        ];
+        tenant.spec.pools.push(archive);
+
+        let container = |pool: &Pool| {
+            tenant
+                .new_statefulset(pool)
+                .expect("Should create StatefulSet")
+                .spec
+                .unwrap()
+                .template
+                .spec
+                .unwrap()
+                .containers
+                .remove(0)
+        };
+        let hot = container(&tenant.spec.pools[0]);
+        let archive = container(&tenant.spec.pools[1]);
+        assert_eq!(env_value(&hot, "RUSTFS_CACHE_SIZE"), Some("1Gi"));
+        assert_eq!(env_value(&archive, "RUSTFS_CACHE_SIZE"), Some("64Mi"));
+        assert_eq!(env_value(&archive, "TENANT_ONLY"), Some("a"));
+        assert_eq!(env_value(&archive, "RUSTFS_CONSOLE_ENABLE"), Some("false"));
+
+        let (_, overridden) = tenant
+            .pool_env(&tenant.spec.pools[1], &TlsPlan::disabled())
+            .expect("Should render env");
+        assert_eq!(overridden, vec!["RUSTFS_CONSOLE_ENABLE".to_string()]);
+        let (_, overridden) = tenant
+            .pool_env(&tenant.spec.pools[0], &TlsPlan::disabled())
+            .expect("Should render env");
+        assert!(overridden.is_empty());
+    }
+
+    #[test]
+    fn performance_profile_presets_yield_to_explicit_env() {
+        use crate::types::v1alpha1::profile::PerformanceProfile;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.performance_profile = Some(PerformanceProfile::Throughput);
+        tenant.spec.env = vec![corev1::EnvVar {
+            name: "RUSTFS_OBJECT_CACHE_CAPACITY_MB".to_string(),
+            value: Some("4096".to_string()),
+            ..Default::default()
+        }];
+
+        let (env, overridden) = tenant
+            .pool_env(&tenant.spec.pools[0], &TlsPlan::disabled())
+            .expect("Should render env");
+        let value = |name: &str| {
+            env.iter()
+                .find(|var| var.name == name)
+                .and_then(|var| var.value.as_deref())
+        };
+        assert_eq!(value("RUSTFS_OBJECT_CACHE_CAPACITY_MB"), Some("4096"));
+        assert_eq!(
+            value("RUSTFS_OBJECT_MAX_CONCURRENT_DISK_READS"),
+            Some("256")
+        );
+        assert_eq!(
+            env.iter()
+                .filter(|var| var.name == "RUSTFS_OBJECT_CACHE_CAPACITY_MB")
+                .count(),
+            1
+        );
+        assert!(overridden.is_empty(), "presets are not operator variables");
+
+        tenant.spec.performance_profile = Some(PerformanceProfile::LowMemory);
+        let (env, _) = tenant
+            .pool_env(&tenant.spec.pools[0], &TlsPlan::disabled())
+            .expect("Should render env");
+        assert!(
+            env.iter()
+                .any(|var| var.name == "RUSTFS_OBJECT_CACHE_ENABLE"
+                    && var.value.as_deref() == Some("false"))
+        );
+    }
+
     #[test]
     fn tls_statefulset_keeps_operator_managed_env_when_spec_env_conflicts() {
         let mut tenant = crate::tests::create_test_tenant(None, None);
@@ -1346,6 +1860,52 @@ mod tests {
         assert!(!has_log_mount, "Default should not have log volume mount");
     }
 
+    // Test: serviceAccountTokenProjection mounts a projected token volume
+    #[test]
+    fn test_service_account_token_projection_volume() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.service_account_token_projection = Some(
+            crate::types::v1alpha1::rbac::ServiceAccountTokenProjection {
+                audience: "sts.amazonaws.com".to_string(),
+                expiration_seconds: None,
+                mount_path: None,
+            },
+        );
+        let pool = &tenant.spec.pools[0];
+
+        let pod_spec = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet")
+            .spec
+            .expect("StatefulSet should have spec")
+            .template
+            .spec
+            .expect("Pod template should have spec");
+
+        let volumes = pod_spec.volumes.unwrap_or_default();
+        let token = volumes
+            .iter()
+            .find(|v| v.name == "sa-token")
+            .and_then(|v| v.projected.as_ref())
+            .and_then(|p| p.sources.as_ref())
+            .and_then(|sources| sources.first())
+            .and_then(|source| source.service_account_token.as_ref())
+            .expect("Should have projected token volume");
+        assert_eq!(token.audience.as_deref(), Some("sts.amazonaws.com"));
+        assert_eq!(token.expiration_seconds, Some(3600));
+        assert_eq!(token.path, "token");
+
+        let container = pod_spec.containers.first().expect("Should have container");
+        let mount = container
+            .volume_mounts
+            .iter()
+            .flatten()
+            .find(|m| m.name == "sa-token")
+            .expect("Should mount projected token");
+        assert_eq!(mount.mount_path, "/var/run/secrets/tokens");
+        assert_eq!(mount.read_only, Some(true));
+    }
+
     // Test: EmptyDir logging mode creates volume
     #[test]
     fn test_emptydir_logging_creates_volume() {
@@ -1670,6 +2230,26 @@ mod tests {
         );
     }
 
+    // Test: pod management policy defaults to Parallel and honors the spec
+    #[test]
+    fn test_statefulset_pod_management_policy_default() {
+        use crate::types::v1alpha1::k8s::PodManagementPolicy;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let policy = |tenant: &super::Tenant| {
+            tenant
+                .new_statefulset(&tenant.spec.pools[0])
+                .expect("Should create StatefulSet")
+                .spec
+                .and_then(|spec| spec.pod_management_policy)
+        };
+
+        assert_eq!(policy(&tenant).as_deref(), Some("Parallel"));
+
+        tenant.spec.pod_management_policy = Some(PodManagementPolicy::OrderedReady);
+        assert_eq!(policy(&tenant).as_deref(), Some("OrderedReady"));
+    }
+
     // Test: StatefulSet diff detection - no changes needed
     #[test]
     fn test_statefulset_no_update_needed() {
@@ -1691,6 +2271,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_statefulset_rollout_restart_annotation_is_not_drift() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+
+        let mut statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet");
+        statefulset
+            .spec
+            .as_mut()
+            .and_then(|spec| spec.template.metadata.as_mut())
+            .expect("template metadata")
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(
+                super::RESTARTED_AT_ANNOTATION.to_string(),
+                "2025-01-01T00:00:00Z".to_string(),
+            );
+
+        let needs_update = tenant
+            .statefulset_needs_update(&statefulset, pool)
+            .expect("Should check update need");
+
+        assert!(
+            !needs_update,
+            "A rollout restart annotation should not be reverted by the operator"
+        );
+    }
+
     // Test: StatefulSet diff detection - image change
     #[test]
     fn test_statefulset_image_change_detected() {
@@ -1715,6 +2325,46 @@ mod tests {
         );
     }
 
+    // Test: envFrom is passed through and its changes are detected
+    #[test]
+    fn test_statefulset_env_from_change_detected() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let env_from = |name: &str| corev1::EnvFromSource {
+            secret_ref: Some(corev1::SecretEnvSource {
+                name: name.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        tenant.spec.env_from = vec![env_from("rustfs-env")];
+        let pool = &tenant.spec.pools[0];
+
+        let statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet");
+        let container = &statefulset
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.template.spec.as_ref())
+            .expect("pod spec")
+            .containers[0];
+        assert_eq!(container.env_from, Some(vec![env_from("rustfs-env")]));
+        assert!(
+            tenant
+                .referenced_secret_names()
+                .iter()
+                .any(|name| name == "rustfs-env")
+        );
+
+        tenant.spec.env_from.push(env_from("rustfs-extra-env"));
+        assert!(
+            tenant
+                .statefulset_needs_update(&statefulset, pool)
+                .expect("Should check update need"),
+            "StatefulSet should need update when envFrom changes"
+        );
+    }
+
     // Test: StatefulSet diff detection - image pull secret add
     #[test]
     fn test_statefulset_image_pull_secret_add_detected() {
@@ -1737,6 +2387,164 @@ mod tests {
         );
     }
 
+    // Test: multi-zone clusters get a default zone spread unless the pool or Tenant opts out
+    #[test]
+    fn test_statefulset_default_zone_spread_on_multi_zone_clusters() {
+        use crate::types::v1alpha1::k8s::WhenUnsatisfiable;
+        use crate::types::v1alpha1::pool::ZoneSpreadConfig;
+
+        let constraints = |tenant: &super::Tenant, multi_zone: bool| {
+            let mut plan = TlsPlan::disabled();
+            plan.multi_zone = multi_zone;
+            tenant
+                .new_statefulset_with_tls_plan(&tenant.spec.pools[0], &plan)
+                .expect("Should create StatefulSet")
+                .spec
+                .and_then(|spec| spec.template.spec)
+                .and_then(|spec| spec.topology_spread_constraints)
+        };
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        assert!(constraints(&tenant, false).is_none());
+
+        let generated = constraints(&tenant, true).expect("default zone spread");
+        assert_eq!(generated.len(), 1);
+        assert_eq!(generated[0].max_skew, 1);
+        assert_eq!(generated[0].topology_key, super::ZONE_TOPOLOGY_KEY);
+        assert_eq!(generated[0].when_unsatisfiable, "ScheduleAnyway");
+        assert_eq!(
+            generated[0]
+                .label_selector
+                .as_ref()
+                .and_then(|selector| selector.match_labels.clone()),
+            Some(tenant.pool_selector_labels(&tenant.spec.pools[0]))
+        );
+
+        tenant.spec.zone_spread = Some(ZoneSpreadConfig {
+            enabled: None,
+            when_unsatisfiable: Some(WhenUnsatisfiable::DoNotSchedule),
+        });
+        assert_eq!(
+            constraints(&tenant, true).expect("default zone spread")[0].when_unsatisfiable,
+            "DoNotSchedule"
+        );
+
+        tenant.spec.pools[0].scheduling.topology_spread_constraints =
+            Some(vec![corev1::TopologySpreadConstraint {
+                max_skew: 2,
+                topology_key: "kubernetes.io/hostname".to_string(),
+                when_unsatisfiable: "DoNotSchedule".to_string(),
+                ..Default::default()
+            }]);
+        assert_eq!(
+            constraints(&tenant, true).expect("pool constraints")[0].topology_key,
+            "kubernetes.io/hostname"
+        );
+
+        tenant.spec.pools[0].scheduling.topology_spread_constraints = None;
+        tenant.spec.zone_spread = Some(ZoneSpreadConfig {
+            enabled: Some(false),
+            when_unsatisfiable: None,
+        });
+        assert!(constraints(&tenant, true).is_none());
+    }
+
+    // Test: standalone Tenants skip the zone spread and default container resources
+    #[test]
+    fn test_statefulset_standalone_profile_defaults() {
+        use crate::types::v1alpha1::profile::TenantProfile;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.profile = Some(TenantProfile::Standalone);
+        let mut plan = TlsPlan::disabled();
+        plan.multi_zone = true;
+        let pod_spec = |tenant: &super::Tenant| {
+            tenant
+                .new_statefulset_with_tls_plan(&tenant.spec.pools[0], &plan)
+                .expect("Should create StatefulSet")
+                .spec
+                .and_then(|spec| spec.template.spec)
+                .expect("pod spec")
+        };
+
+        let spec = pod_spec(&tenant);
+        assert!(spec.topology_spread_constraints.is_none());
+        let requests = spec.containers[0]
+            .resources
+            .as_ref()
+            .and_then(|resources| resources.requests.clone())
+            .unwrap_or_default();
+        assert_eq!(requests.get("memory").map(|q| q.0.as_str()), Some("256Mi"));
+
+        let pool_resources = corev1::ResourceRequirements {
+            requests: Some(std::collections::BTreeMap::from([(
+                "cpu".to_string(),
+                k8s_openapi::apimachinery::pkg::api::resource::Quantity("2".to_string()),
+            )])),
+            ..Default::default()
+        };
+        tenant.spec.pools[0].scheduling.resources = Some(pool_resources.clone());
+        assert_eq!(
+            pod_spec(&tenant).containers[0].resources,
+            Some(pool_resources)
+        );
+
+        tenant.spec.profile = None;
+        tenant.spec.pools[0].scheduling.resources = None;
+        assert!(pod_spec(&tenant).containers[0].resources.is_none());
+    }
+
+    // Test: pool podMetadata lands on the pod template without replacing operator keys
+    #[test]
+    fn test_statefulset_pool_pod_metadata_merged_and_detected() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        let statefulset = tenant
+            .new_statefulset(&tenant.spec.pools[0])
+            .expect("Should create StatefulSet");
+
+        tenant.spec.pools[0].pod_metadata = Some(crate::types::v1alpha1::pool::PodMetadata {
+            labels: [
+                ("cost-center", "storage"),
+                ("rustfs.tenant", "other-tenant"),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+            annotations: [("sidecar.istio.io/proxyCPU".to_string(), "500m".to_string())].into(),
+        });
+        let pool = &tenant.spec.pools[0];
+        let needs_update = tenant
+            .statefulset_needs_update(&statefulset, pool)
+            .expect("Should check update need");
+        assert!(
+            needs_update,
+            "StatefulSet should need update when pool podMetadata changes"
+        );
+
+        let desired = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet");
+        let metadata = desired.spec.unwrap().template.metadata.unwrap();
+        let labels = metadata.labels.unwrap();
+        assert_eq!(
+            labels.get("cost-center").map(String::as_str),
+            Some("storage")
+        );
+        assert_eq!(
+            labels.get("rustfs.tenant").map(String::as_str),
+            Some("test-tenant")
+        );
+        assert_eq!(
+            metadata
+                .annotations
+                .unwrap()
+                .get("sidecar.istio.io/proxyCPU")
+                .map(String::as_str),
+            Some("500m")
+        );
+        assert!(!desired.metadata.labels.unwrap().contains_key("cost-center"));
+    }
+
     // Test: StatefulSet diff detection - image pull secret change
     #[test]
     fn test_statefulset_image_pull_secret_change_detected() {
@@ -1837,6 +2645,135 @@ mod tests {
         );
     }
 
+    // Test: StatefulSet diff detection - API server defaults and env order are not changes
+    #[test]
+    fn test_statefulset_server_defaults_not_detected_as_change() {
+        use k8s_openapi::api::core::v1 as corev1;
+
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.spec.env = vec![
+            corev1::EnvVar {
+                name: "RUSTFS_B".to_string(),
+                value: Some("b".to_string()),
+                ..Default::default()
+            },
+            corev1::EnvVar {
+                name: "RUSTFS_A".to_string(),
+                value: Some("a".to_string()),
+                ..Default::default()
+            },
+        ];
+        let pool = &tenant.spec.pools[0];
+
+        let mut statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet");
+        let pod_spec = statefulset
+            .spec
+            .as_mut()
+            .and_then(|spec| spec.template.spec.as_mut())
+            .expect("Should have pod spec");
+        for volume in pod_spec.volumes.iter_mut().flatten() {
+            if let Some(config_map) = volume.config_map.as_mut() {
+                config_map.default_mode = Some(0o644);
+            }
+        }
+        let container = &mut pod_spec.containers[0];
+        container.env.iter_mut().for_each(|env| env.reverse());
+        container.image_pull_policy = Some("Always".to_string());
+        for port in container.ports.iter_mut().flatten() {
+            port.protocol = None;
+        }
+
+        let needs_update = tenant
+            .statefulset_needs_update(&statefulset, pool)
+            .expect("Should check update need");
+
+        assert!(
+            !needs_update,
+            "StatefulSet should not need update for server-defaulted fields"
+        );
+    }
+
+    // Test: StatefulSet diff detection - mutating webhook additions are tolerated
+    #[test]
+    fn test_statefulset_webhook_mutation_not_detected_as_change() {
+        use k8s_openapi::api::core::v1 as corev1;
+
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+
+        let mut statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet");
+        let pod_spec = statefulset
+            .spec
+            .as_mut()
+            .and_then(|spec| spec.template.spec.as_mut())
+            .expect("Should have pod spec");
+        pod_spec.containers.push(corev1::Container {
+            name: "istio-proxy".to_string(),
+            image: Some("istio/proxyv2:1.22.0".to_string()),
+            ..Default::default()
+        });
+        pod_spec.containers[0]
+            .env
+            .get_or_insert_with(Vec::new)
+            .push(corev1::EnvVar {
+                name: "INJECTED".to_string(),
+                value: Some("true".to_string()),
+                ..Default::default()
+            });
+
+        let needs_update = tenant
+            .statefulset_needs_update(&statefulset, pool)
+            .expect("Should check update need");
+        assert!(
+            !needs_update,
+            "StatefulSet should not need update for fields added by a webhook"
+        );
+
+        // Without the last-applied hash the live object is compared as a whole.
+        if let Some(annotations) = statefulset.metadata.annotations.as_mut() {
+            annotations.remove(super::LAST_APPLIED_HASH_ANNOTATION);
+        }
+        let needs_update = tenant
+            .statefulset_needs_update(&statefulset, pool)
+            .expect("Should check update need");
+        assert!(
+            needs_update,
+            "StatefulSet without a last-applied hash should be re-applied"
+        );
+    }
+
+    // Test: StatefulSet diff detection - drift of operator-owned fields is reverted
+    #[test]
+    fn test_statefulset_drift_of_applied_field_detected() {
+        let tenant = crate::tests::create_test_tenant(None, None);
+        let pool = &tenant.spec.pools[0];
+
+        let mut statefulset = tenant
+            .new_statefulset(pool)
+            .expect("Should create StatefulSet");
+        if let Some(container) = statefulset
+            .spec
+            .as_mut()
+            .and_then(|spec| spec.template.spec.as_mut())
+            .and_then(|pod_spec| pod_spec.containers.first_mut())
+        {
+            container.image = Some("rustfs/rustfs:edited".to_string());
+        }
+
+        let needs_update = tenant
+            .statefulset_needs_update(&statefulset, pool)
+            .expect("Should check update need");
+
+        assert!(
+            needs_update,
+            "StatefulSet should need update when an applied field was changed"
+        );
+    }
+
     // Test: StatefulSet diff detection - resources change
     #[test]
     fn test_statefulset_resources_change_detected() {
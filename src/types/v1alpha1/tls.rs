@@ -274,6 +274,9 @@ pub struct TlsPlan {
     pub volumes: Vec<corev1::Volume>,
     pub volume_mounts: Vec<corev1::VolumeMount>,
     pub status: Option<crate::types::v1alpha1::status::certificate::TlsCertificateStatus>,
+    /// Set by the reconciler when schedulable nodes span more than one zone, so pools without
+    /// their own topology spread constraints get the default zone spread.
+    pub multi_zone: bool,
 }
 
 impl TlsPlan {
@@ -288,6 +291,7 @@ impl TlsPlan {
             volumes: Vec::new(),
             volume_mounts: Vec::new(),
             status: None,
+            multi_zone: false,
         }
     }
 
@@ -402,6 +406,7 @@ impl TlsPlan {
             volumes,
             volume_mounts,
             status,
+            multi_zone: false,
         }
     }
 
@@ -484,11 +489,11 @@ fn directory_mount(volume_name: &str, mount_path: &str) -> corev1::VolumeMount {
     }
 }
 
-pub fn http_probe(path: &str, scheme: &'static str) -> corev1::Probe {
+pub fn http_probe(path: &str, port: i32, scheme: &'static str) -> corev1::Probe {
     corev1::Probe {
         http_get: Some(corev1::HTTPGetAction {
             path: Some(path.to_string()),
-            port: IntOrString::Int(9000),
+            port: IntOrString::Int(port),
             scheme: Some(scheme.to_string()),
             ..Default::default()
         }),
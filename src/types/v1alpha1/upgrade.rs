@@ -0,0 +1,67 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::KubeSchema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+pub(crate) const DEFAULT_CANARY_SOAK_SECONDS: u32 = 300;
+pub(crate) const MAX_CANARY_SOAK_SECONDS: u32 = 86_400;
+
+/// How image changes are rolled out to pool StatefulSets.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeStrategy {
+    #[serde(default, skip_serializing_if = "is_default_mode")]
+    pub mode: UpgradeMode,
+
+    /// Seconds the canary pod must stay Ready and healthy before the rollout continues.
+    /// Defaults to 300.
+    #[schemars(range(min = 0, max = MAX_CANARY_SOAK_SECONDS))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canary_soak_seconds: Option<u32>,
+}
+
+impl UpgradeStrategy {
+    pub fn is_canary(&self) -> bool {
+        self.mode == UpgradeMode::Canary
+    }
+
+    pub fn canary_soak(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(
+            self.canary_soak_seconds
+                .unwrap_or(DEFAULT_CANARY_SOAK_SECONDS),
+        ))
+    }
+}
+
+#[derive(Default, Deserialize, Serialize, Clone, Debug, JsonSchema, Display, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[schemars(rename_all = "PascalCase")]
+pub enum UpgradeMode {
+    /// Update every pod with the StatefulSet rolling update.
+    #[strum(to_string = "RollingUpdate")]
+    #[default]
+    RollingUpdate,
+
+    /// Update the highest-ordinal pod of the first pool, soak it, then update the rest.
+    /// A canary that fails its checks is reverted to the previous image.
+    #[strum(to_string = "Canary")]
+    Canary,
+}
+
+fn is_default_mode(mode: &UpgradeMode) -> bool {
+    mode == &UpgradeMode::RollingUpdate
+}
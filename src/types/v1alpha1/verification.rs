@@ -0,0 +1,46 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use kube::KubeSchema;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_VERIFICATION_BUCKET: &str = "rustfs-operator-verify";
+
+/// S3 check the operator runs against the Tenant once it is Ready.
+#[derive(Deserialize, Serialize, Clone, Debug, KubeSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationSpec {
+    /// Run the check. Defaults to true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Bucket the probe object is written to. It is created for the check and deleted after it.
+    /// Defaults to `rustfs-operator-verify`.
+    #[schemars(length(min = 3, max = 63))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+}
+
+impl VerificationSpec {
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
+    pub fn bucket(&self) -> &str {
+        self.bucket
+            .as_deref()
+            .filter(|bucket| !bucket.is_empty())
+            .unwrap_or(DEFAULT_VERIFICATION_BUCKET)
+    }
+}
@@ -0,0 +1,426 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodic per-Tenant usage records for platform billing pipelines.
+//!
+//! Off by default. With `OPERATOR_USAGE_METERING_ENABLED=true` the leader snapshots every Tenant
+//! once per `OPERATOR_USAGE_METERING_INTERVAL_SECONDS` and delivers the records of that period to
+//! the ConfigMap named by `OPERATOR_USAGE_METERING_CONFIGMAP` in the operator namespace (keys
+//! `usage.csv` and `usage.json`, replaced each period) and, when
+//! `OPERATOR_USAGE_METERING_WEBHOOK_URL` is set, POSTs them as JSON to that URL.
+//!
+//! Capacity comes from PersistentVolumeClaims and from the raw bytes the health check last read
+//! from the RustFS admin API. Pod-hours are the Running pods at the snapshot times the period
+//! length. Egress is not reported: RustFS exposes no per-Tenant transfer counters.
+
+use crate::console::handlers::cluster::parse_memory_to_bytes;
+use crate::tenant_monitor;
+use crate::types::v1alpha1::tenant::Tenant;
+use k8s_openapi::api::core::v1 as corev1;
+use kube::api::{ListParams, Patch, PatchParams};
+use kube::{Api, Client, ResourceExt};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+const DEFAULT_METERING_INTERVAL: Duration = Duration::from_secs(3600);
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+const CSV_KEY: &str = "usage.csv";
+const JSON_KEY: &str = "usage.json";
+
+/// Usage of one Tenant over one metering period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UsageRecord {
+    pub period_start: String,
+    pub period_end: String,
+    pub namespace: String,
+    pub tenant: String,
+    pub labels: BTreeMap<String, String>,
+    pub pools: usize,
+    pub running_pods: usize,
+    pub pod_hours: f64,
+    pub volume_capacity_bytes: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_capacity_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_used_bytes: Option<u64>,
+}
+
+/// Operator-level metering settings read from the environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct MeteringConfig {
+    pub interval: Duration,
+    pub config_map: Option<String>,
+    pub webhook_url: Option<String>,
+    pub webhook_token: Option<String>,
+    /// Tenant label keys copied into each record; empty copies every label.
+    pub label_keys: Vec<String>,
+}
+
+impl MeteringConfig {
+    pub(crate) fn from_env() -> Self {
+        let non_empty = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Self {
+            interval: interval(),
+            config_map: non_empty("OPERATOR_USAGE_METERING_CONFIGMAP"),
+            webhook_url: non_empty("OPERATOR_USAGE_METERING_WEBHOOK_URL"),
+            webhook_token: non_empty("OPERATOR_USAGE_METERING_WEBHOOK_TOKEN"),
+            label_keys: non_empty("OPERATOR_USAGE_METERING_LABEL_KEYS")
+                .map(|keys| {
+                    keys.split(',')
+                        .map(str::trim)
+                        .filter(|key| !key.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub fn is_enabled() -> bool {
+    tenant_monitor::env_bool("OPERATOR_USAGE_METERING_ENABLED", false)
+}
+
+pub fn interval() -> Duration {
+    match std::env::var("OPERATOR_USAGE_METERING_INTERVAL_SECONDS") {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(seconds) if seconds > 0 => Duration::from_secs(seconds),
+            Ok(_) | Err(_) => {
+                warn!(
+                    value,
+                    "invalid OPERATOR_USAGE_METERING_INTERVAL_SECONDS value, using default"
+                );
+                DEFAULT_METERING_INTERVAL
+            }
+        },
+        Err(_) => DEFAULT_METERING_INTERVAL,
+    }
+}
+
+pub async fn run(client: Client, cancel: CancellationToken) {
+    let config = MeteringConfig::from_env();
+    if config.config_map.is_none() && config.webhook_url.is_none() {
+        warn!(
+            "usage metering enabled without OPERATOR_USAGE_METERING_CONFIGMAP or OPERATOR_USAGE_METERING_WEBHOOK_URL; records are not delivered"
+        );
+    }
+    info!(
+        interval_seconds = config.interval.as_secs(),
+        config_map = ?config.config_map,
+        webhook = config.webhook_url.is_some(),
+        "usage metering started"
+    );
+    let http = crate::proxy::ProxyConfig::from_env()
+        .apply_to_http_client(reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT))
+        .and_then(|builder| builder.build())
+        .unwrap_or_else(|_| reqwest::Client::new());
+
+    // The first period ends one interval after startup, so its pod-hours cover a full interval.
+    let mut period_start = chrono::Utc::now();
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                info!("usage metering cancellation requested");
+                break;
+            }
+            _ = tokio::time::sleep(config.interval) => {}
+        }
+        let period_end = chrono::Utc::now();
+        meter_all_tenants(&client, &http, &config, period_start, period_end).await;
+        period_start = period_end;
+    }
+}
+
+async fn meter_all_tenants(
+    client: &Client,
+    http: &reqwest::Client,
+    config: &MeteringConfig,
+    period_start: chrono::DateTime<chrono::Utc>,
+    period_end: chrono::DateTime<chrono::Utc>,
+) {
+    let tenants = match tenant_monitor::list_all_tenants(client.clone()).await {
+        Ok(tenants) => tenants,
+        Err(error) => {
+            warn!(%error, "usage metering failed listing tenants");
+            return;
+        }
+    };
+
+    let mut records = Vec::with_capacity(tenants.len());
+    for tenant in &tenants {
+        let Ok(namespace) = tenant.namespace() else {
+            continue;
+        };
+        let params = ListParams::default().labels(&tenant.pod_selector());
+        let pods = Api::<corev1::Pod>::namespaced(client.clone(), &namespace)
+            .list(&params)
+            .await;
+        let claims = Api::<corev1::PersistentVolumeClaim>::namespaced(client.clone(), &namespace)
+            .list(&params)
+            .await;
+        match (pods, claims) {
+            (Ok(pods), Ok(claims)) => records.push(usage_record(
+                tenant,
+                &pods.items,
+                &claims.items,
+                config,
+                period_start,
+                period_end,
+            )),
+            (Err(error), _) | (_, Err(error)) => {
+                warn!(tenant = %tenant.name_any(), namespace, %error, "usage metering skipped tenant");
+            }
+        }
+    }
+
+    if let Some(name) = &config.config_map
+        && let Err(error) = write_config_map(client, name, &records, period_end).await
+    {
+        warn!(config_map = %name, %error, "usage metering failed writing ConfigMap");
+    }
+    if let Some(url) = &config.webhook_url
+        && let Err(error) = post_webhook(http, url, config.webhook_token.as_deref(), &records).await
+    {
+        warn!(%error, "usage metering webhook delivery failed");
+    }
+    info!(tenants = records.len(), "usage metering period recorded");
+}
+
+pub(crate) fn usage_record(
+    tenant: &Tenant,
+    pods: &[corev1::Pod],
+    claims: &[corev1::PersistentVolumeClaim],
+    config: &MeteringConfig,
+    period_start: chrono::DateTime<chrono::Utc>,
+    period_end: chrono::DateTime<chrono::Utc>,
+) -> UsageRecord {
+    let running_pods = pods
+        .iter()
+        .filter(|pod| {
+            pod.status
+                .as_ref()
+                .and_then(|status| status.phase.as_deref())
+                == Some("Running")
+        })
+        .count();
+    let hours = (period_end - period_start).num_seconds().max(0) as f64 / 3600.0;
+    let volume_capacity_bytes = claims
+        .iter()
+        .filter_map(|claim| claim.status.as_ref()?.capacity.as_ref()?.get("storage"))
+        .map(|quantity| parse_memory_to_bytes(&quantity.0))
+        .sum();
+    let capacity = tenant
+        .status
+        .as_ref()
+        .and_then(|status| status.health.as_ref())
+        .and_then(|health| health.capacity.as_ref());
+    let labels = tenant
+        .labels()
+        .iter()
+        .filter(|(key, _)| config.label_keys.is_empty() || config.label_keys.contains(key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+
+    UsageRecord {
+        period_start: period_start.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        period_end: period_end.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        namespace: tenant.namespace().unwrap_or_default(),
+        tenant: tenant.name_any(),
+        labels,
+        pools: tenant.spec.pools.len(),
+        running_pods,
+        pod_hours: running_pods as f64 * hours,
+        volume_capacity_bytes,
+        raw_capacity_bytes: capacity.map(|capacity| capacity.raw_capacity_bytes),
+        raw_used_bytes: capacity.map(|capacity| capacity.raw_used_bytes),
+    }
+}
+
+/// Records as CSV with a header row. Labels are one `key=value;key=value` column.
+pub(crate) fn render_csv(records: &[UsageRecord]) -> String {
+    let mut csv = String::from(
+        "periodStart,periodEnd,namespace,tenant,labels,pools,runningPods,podHours,volumeCapacityBytes,rawCapacityBytes,rawUsedBytes\n",
+    );
+    for record in records {
+        let labels = record
+            .labels
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(";");
+        let optional =
+            |value: Option<u64>| value.map(|value| value.to_string()).unwrap_or_default();
+        let fields = [
+            record.period_start.clone(),
+            record.period_end.clone(),
+            record.namespace.clone(),
+            record.tenant.clone(),
+            labels,
+            record.pools.to_string(),
+            record.running_pods.to_string(),
+            format!("{:.4}", record.pod_hours),
+            record.volume_capacity_bytes.to_string(),
+            optional(record.raw_capacity_bytes),
+            optional(record.raw_used_bytes),
+        ];
+        let line = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+async fn write_config_map(
+    client: &Client,
+    name: &str,
+    records: &[UsageRecord],
+    period_end: chrono::DateTime<chrono::Utc>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let namespace = crate::sts::tls::operator_namespace();
+    let config_map = corev1::ConfigMap {
+        metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.clone()),
+            annotations: Some(BTreeMap::from([(
+                "operator.rustfs.com/usage-period-end".to_string(),
+                period_end.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            )])),
+            ..Default::default()
+        },
+        data: Some(BTreeMap::from([
+            (CSV_KEY.to_string(), render_csv(records)),
+            (JSON_KEY.to_string(), serde_json::to_string(records)?),
+        ])),
+        ..Default::default()
+    };
+    Api::<corev1::ConfigMap>::namespaced(client.clone(), &namespace)
+        .patch(
+            name,
+            &PatchParams::apply("rustfs-operator").force(),
+            &Patch::Apply(&config_map),
+        )
+        .await?;
+    Ok(())
+}
+
+async fn post_webhook(
+    http: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+    records: &[UsageRecord],
+) -> Result<(), reqwest::Error> {
+    let mut request = http
+        .post(url)
+        .json(&serde_json::json!({ "records": records }));
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    request.send().await?.error_for_status()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::v1alpha1::status::Status;
+    use crate::types::v1alpha1::status::health::{CapacityStatus, HealthStatus};
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    #[test]
+    fn records_pod_hours_capacity_and_selected_labels() {
+        let mut tenant = crate::tests::create_test_tenant(None, None);
+        tenant.metadata.labels = Some(BTreeMap::from([
+            ("cost-center".to_string(), "storage, shared".to_string()),
+            ("team".to_string(), "data".to_string()),
+        ]));
+        tenant.status = Some(Status {
+            health: Some(HealthStatus {
+                capacity: Some(CapacityStatus {
+                    raw_capacity_bytes: 4000,
+                    raw_used_bytes: 1000,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let pod = |phase: &str| corev1::Pod {
+            status: Some(corev1::PodStatus {
+                phase: Some(phase.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let claim = corev1::PersistentVolumeClaim {
+            status: Some(corev1::PersistentVolumeClaimStatus {
+                capacity: Some(BTreeMap::from([(
+                    "storage".to_string(),
+                    Quantity("10Gi".to_string()),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let config = MeteringConfig {
+            label_keys: vec!["cost-center".to_string()],
+            ..Default::default()
+        };
+        let end = chrono::Utc::now();
+        let start = end - chrono::Duration::minutes(30);
+
+        let record = usage_record(
+            &tenant,
+            &[pod("Running"), pod("Running"), pod("Pending")],
+            &[claim.clone(), claim],
+            &config,
+            start,
+            end,
+        );
+
+        assert_eq!(record.running_pods, 2);
+        assert_eq!(record.pod_hours, 1.0);
+        assert_eq!(record.volume_capacity_bytes, 20 << 30);
+        assert_eq!(record.raw_used_bytes, Some(1000));
+        assert_eq!(
+            record.labels,
+            BTreeMap::from([("cost-center".to_string(), "storage, shared".to_string())])
+        );
+
+        let csv = render_csv(&[record]);
+        let row = csv.lines().nth(1).unwrap_or_default();
+        assert!(row.contains(",default,test-tenant,\"cost-center=storage, shared\",1,2,1.0000,"));
+        assert!(row.ends_with(",4000,1000"));
+    }
+}
@@ -15,8 +15,13 @@
 #![allow(unused)]
 #![allow(dead_code)]
 
-use rustls::crypto::ring::sign;
-use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeDelta, Utc};
+use rcgen::{
+    BasicConstraints, CertificateParams, DistinguishedName, DnType, ExtendedKeyUsagePurpose, IsCa,
+    KeyPair, KeyUsagePurpose,
+};
+use rustls::crypto::ring::{default_provider, sign};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
 use rustls::sign::{CertifiedKey, SigningKey};
 use rustls_pemfile::Item;
 use snafu::{ResultExt, Snafu};
@@ -44,6 +49,28 @@ pub enum Error {
 
     #[snafu(display("no supported pem type"))]
     NoSupportedPEMType,
+
+    #[snafu(display("certificate validity could not be parsed"))]
+    InvalidValidity,
+
+    #[snafu(display("generate certificate error: {source}"))]
+    GenerateCertificate { source: rcgen::Error },
+
+    #[snafu(display("invalid CA certificate: {source}"))]
+    InvalidCaCertificate { source: webpki::Error },
+
+    #[snafu(display("certificate chain validation failed: {source}"))]
+    ChainValidation { source: webpki::Error },
+
+    #[snafu(display("certificate does not cover name '{name}'"))]
+    SubjectNameMismatch { name: String },
+}
+
+/// Validity window of an X.509 certificate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CertificateValidity {
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
 }
 
 // load certificates from PEM file
@@ -84,7 +111,246 @@ pub fn x509_key_pair<T: AsRef<[u8]>>(cert_pem: T, key_pem: T) -> Result<(), Erro
     certified_key.keys_match().context(MatchFailedSnafu)
 }
 
+/// Reads the validity window of the first (leaf) certificate in a PEM bundle.
+pub fn certificate_validity<T: AsRef<[u8]>>(cert_pem: T) -> Result<CertificateValidity, Error> {
+    let certs = load_certs(cert_pem.as_ref())?;
+    let leaf = certs.first().ok_or(Error::NonCertificate)?;
+    parse_validity(leaf.as_ref()).ok_or(Error::InvalidValidity)
+}
+
+/// DNS names of a Service: the short name and its namespace, `svc` and cluster-local forms.
+pub fn service_dns_names(service_name: &str, namespace: &str) -> Vec<String> {
+    vec![
+        service_name.to_string(),
+        format!("{service_name}.{namespace}"),
+        format!("{service_name}.{namespace}.svc"),
+        format!("{service_name}.{namespace}.svc.cluster.local"),
+    ]
+}
+
+/// Stable DNS names of the pods of a StatefulSet behind its headless Service.
+pub fn headless_pod_dns_names(
+    statefulset_name: &str,
+    replicas: i32,
+    headless_service: &str,
+    namespace: &str,
+) -> Vec<String> {
+    (0..replicas.max(0))
+        .map(|ordinal| {
+            format!("{statefulset_name}-{ordinal}.{headless_service}.{namespace}.svc.cluster.local")
+        })
+        .collect()
+}
+
+/// PEM-encoded certificate and private key issued by [`CertificateAuthority`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuedCertificate {
+    pub cert_pem: String,
+    pub key_pem: String,
+}
+
+/// Self-signed certificate authority for issuing server certificates.
+pub struct CertificateAuthority {
+    certificate: rcgen::Certificate,
+    key: KeyPair,
+}
+
+impl CertificateAuthority {
+    /// Generates a new CA. Without a `lifetime` the certificate does not expire in practice.
+    pub fn generate(common_name: &str, lifetime: Option<TimeDelta>) -> Result<Self, Error> {
+        let key = KeyPair::generate().context(GenerateCertificateSnafu)?;
+        let mut params = CertificateParams::default();
+        let mut subject = DistinguishedName::new();
+        subject.push(DnType::CommonName, common_name);
+        params.distinguished_name = subject;
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params.key_usages = vec![
+            KeyUsagePurpose::KeyCertSign,
+            KeyUsagePurpose::DigitalSignature,
+            KeyUsagePurpose::CrlSign,
+        ];
+        set_lifetime(&mut params, lifetime);
+        let certificate = params.self_signed(&key).context(GenerateCertificateSnafu)?;
+        Ok(Self { certificate, key })
+    }
+
+    pub fn cert_pem(&self) -> String {
+        self.certificate.pem()
+    }
+
+    pub fn key_pem(&self) -> String {
+        self.key.serialize_pem()
+    }
+
+    /// Issues a server certificate for `subject_alt_names`; IP addresses become IP SANs.
+    pub fn issue_server_certificate(
+        &self,
+        subject_alt_names: &[String],
+        lifetime: Option<TimeDelta>,
+    ) -> Result<IssuedCertificate, Error> {
+        let key = KeyPair::generate().context(GenerateCertificateSnafu)?;
+        let mut params =
+            CertificateParams::new(subject_alt_names.to_vec()).context(GenerateCertificateSnafu)?;
+        params.is_ca = IsCa::NoCa;
+        params.key_usages = vec![
+            KeyUsagePurpose::DigitalSignature,
+            KeyUsagePurpose::KeyEncipherment,
+        ];
+        params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+        set_lifetime(&mut params, lifetime);
+        let certificate = params
+            .signed_by(&key, &self.certificate, &self.key)
+            .context(GenerateCertificateSnafu)?;
+        Ok(IssuedCertificate {
+            cert_pem: certificate.pem(),
+            key_pem: key.serialize_pem(),
+        })
+    }
+}
+
+// rcgen takes `time` dates; day precision is enough for certificate lifetimes. The window
+// starts a day early to tolerate clock skew between the operator and clients.
+fn set_lifetime(params: &mut CertificateParams, lifetime: Option<TimeDelta>) {
+    let Some(lifetime) = lifetime else {
+        return;
+    };
+    let now = Utc::now();
+    let day = |time: DateTime<Utc>| {
+        rcgen::date_time_ymd(time.year(), time.month() as u8, time.day() as u8)
+    };
+    params.not_before = day(now - TimeDelta::days(1));
+    params.not_after = day(now + lifetime);
+}
+
+/// Verifies that the leaf certificate of `cert_pem` chains to a certificate in `ca_pem` and is
+/// valid for server authentication at `now`. Extra certificates after the leaf are used as
+/// intermediates.
+pub fn verify_certificate_chain<T: AsRef<[u8]>>(
+    cert_pem: T,
+    ca_pem: T,
+    now: DateTime<Utc>,
+) -> Result<(), Error> {
+    let certs = load_certs(cert_pem.as_ref())?;
+    let (leaf, intermediates) = certs.split_first().ok_or(Error::NonCertificate)?;
+    let ca_certs = load_certs(ca_pem.as_ref())?;
+    let anchors = ca_certs
+        .iter()
+        .map(|ca| webpki::anchor_from_trusted_cert(ca).context(InvalidCaCertificateSnafu))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let leaf = webpki::EndEntityCert::try_from(leaf).context(ChainValidationSnafu)?;
+    let seconds = u64::try_from(now.timestamp()).unwrap_or_default();
+    leaf.verify_for_usage(
+        default_provider().signature_verification_algorithms.all,
+        &anchors,
+        intermediates,
+        UnixTime::since_unix_epoch(std::time::Duration::from_secs(seconds)),
+        webpki::KeyUsage::server_auth(),
+        None,
+        None,
+    )
+    .context(ChainValidationSnafu)?;
+    Ok(())
+}
+
+/// Returns the names from `names` that the leaf certificate of `cert_pem` does not cover.
+pub fn uncovered_subject_names<T: AsRef<[u8]>>(
+    cert_pem: T,
+    names: &[String],
+) -> Result<Vec<String>, Error> {
+    let certs = load_certs(cert_pem.as_ref())?;
+    let leaf = certs.first().ok_or(Error::NonCertificate)?;
+    let leaf = webpki::EndEntityCert::try_from(leaf).context(ChainValidationSnafu)?;
+    let mut missing = Vec::new();
+    for name in names {
+        let server_name = ServerName::try_from(name.as_str())
+            .map_err(|_| Error::SubjectNameMismatch { name: name.clone() })?;
+        if leaf.verify_is_valid_for_subject_name(&server_name).is_err() {
+            missing.push(name.clone());
+        }
+    }
+    Ok(missing)
+}
+
+const DER_SEQUENCE: u8 = 0x30;
+const DER_CONTEXT_VERSION: u8 = 0xa0;
+const DER_UTC_TIME: u8 = 0x17;
+const DER_GENERALIZED_TIME: u8 = 0x18;
+
+// Certificate ::= SEQUENCE { tbsCertificate SEQUENCE { [0] version OPTIONAL, serialNumber,
+// signature, issuer, validity SEQUENCE { notBefore, notAfter }, ... }, ... }
+fn parse_validity(der: &[u8]) -> Option<CertificateValidity> {
+    let (tag, certificate, _) = read_der(der)?;
+    if tag != DER_SEQUENCE {
+        return None;
+    }
+    let (tag, tbs, _) = read_der(certificate)?;
+    if tag != DER_SEQUENCE {
+        return None;
+    }
+
+    let (tag, _, mut rest) = read_der(tbs)?;
+    // The version field is optional; without it the first element is the serial number.
+    if tag == DER_CONTEXT_VERSION {
+        rest = read_der(rest)?.2;
+    }
+    // Skip signature and issuer.
+    rest = read_der(rest)?.2;
+    rest = read_der(rest)?.2;
+
+    let (tag, validity, _) = read_der(rest)?;
+    if tag != DER_SEQUENCE {
+        return None;
+    }
+    let (before_tag, not_before, rest) = read_der(validity)?;
+    let (after_tag, not_after, _) = read_der(rest)?;
+    Some(CertificateValidity {
+        not_before: parse_der_time(before_tag, not_before)?,
+        not_after: parse_der_time(after_tag, not_after)?,
+    })
+}
+
+/// Splits one DER element into its tag, contents, and the remaining input.
+fn read_der(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, input) = input.split_first()?;
+    let (&first, input) = input.split_first()?;
+    let (len, input) = if first < 0x80 {
+        (first as usize, input)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 || input.len() < count {
+            return None;
+        }
+        let len = input[..count]
+            .iter()
+            .fold(0usize, |len, &byte| (len << 8) | byte as usize);
+        (len, &input[count..])
+    };
+    if input.len() < len {
+        return None;
+    }
+    Some((tag, &input[..len], &input[len..]))
+}
+
+fn parse_der_time(tag: u8, value: &[u8]) -> Option<DateTime<Utc>> {
+    let value = std::str::from_utf8(value).ok()?;
+    let value = match tag {
+        // RFC 5280: two-digit years 50-99 are 19xx, 00-49 are 20xx.
+        DER_UTC_TIME => {
+            let year: u32 = value.get(..2)?.parse().ok()?;
+            let century = if year >= 50 { "19" } else { "20" };
+            format!("{century}{value}")
+        }
+        DER_GENERALIZED_TIME => value.to_string(),
+        _ => return None,
+    };
+    NaiveDateTime::parse_from_str(&value, "%Y%m%d%H%M%SZ")
+        .ok()
+        .map(|time| time.and_utc())
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -241,4 +507,105 @@ do0DpyMVNy4vlS2yIvg6NmbMcDq6ugLh3A==
 
         assert!(x509_key_pair(cert_pem, key_pem).is_ok());
     }
+
+    fn generated_cert_pem(not_after_year: i32) -> String {
+        let mut params = rcgen::CertificateParams::new(vec!["localhost".to_string()]).unwrap();
+        params.not_before = rcgen::date_time_ymd(2025, 1, 2);
+        params.not_after = rcgen::date_time_ymd(not_after_year, 3, 4);
+        let key = rcgen::KeyPair::generate().unwrap();
+        params.self_signed(&key).unwrap().pem()
+    }
+
+    #[test]
+    fn test_certificate_validity_reads_utc_and_generalized_time() {
+        let validity = certificate_validity(generated_cert_pem(2030)).unwrap();
+        assert_eq!(
+            validity.not_before.to_rfc3339(),
+            "2025-01-02T00:00:00+00:00"
+        );
+        assert_eq!(validity.not_after.to_rfc3339(), "2030-03-04T00:00:00+00:00");
+
+        // Dates from 2050 on are encoded as GeneralizedTime.
+        let validity = certificate_validity(generated_cert_pem(2051)).unwrap();
+        assert_eq!(validity.not_after.to_rfc3339(), "2051-03-04T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_certificate_validity_rejects_non_certificates() {
+        assert!(matches!(
+            certificate_validity(""),
+            Err(Error::NonCertificate)
+        ));
+        assert!(parse_validity(&[0x30, 0x03, 0x02, 0x01]).is_none());
+    }
+
+    #[test]
+    fn test_dns_name_helpers_cover_services_and_pods() {
+        assert_eq!(
+            service_dns_names("rustfs-io", "storage"),
+            vec![
+                "rustfs-io",
+                "rustfs-io.storage",
+                "rustfs-io.storage.svc",
+                "rustfs-io.storage.svc.cluster.local",
+            ]
+        );
+        assert_eq!(
+            headless_pod_dns_names("rustfs-pool-0", 2, "rustfs-hl", "storage"),
+            vec![
+                "rustfs-pool-0-0.rustfs-hl.storage.svc.cluster.local",
+                "rustfs-pool-0-1.rustfs-hl.storage.svc.cluster.local",
+            ]
+        );
+        assert!(headless_pod_dns_names("rustfs-pool-0", -1, "rustfs-hl", "storage").is_empty());
+    }
+
+    #[test]
+    fn test_certificate_authority_issues_verifiable_server_certificates() {
+        let ca = CertificateAuthority::generate("rustfs-ca", Some(TimeDelta::days(365))).unwrap();
+        let names = vec!["rustfs-io.storage.svc".to_string(), "127.0.0.1".to_string()];
+        let issued = ca
+            .issue_server_certificate(&names, Some(TimeDelta::days(30)))
+            .unwrap();
+
+        assert!(x509_key_pair(&issued.cert_pem, &issued.key_pem).is_ok());
+        verify_certificate_chain(issued.cert_pem.as_str(), &ca.cert_pem(), Utc::now()).unwrap();
+        assert!(
+            uncovered_subject_names(&issued.cert_pem, &names)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            uncovered_subject_names(&issued.cert_pem, &["other.storage.svc".to_string()]).unwrap(),
+            vec!["other.storage.svc"]
+        );
+
+        let validity = certificate_validity(&issued.cert_pem).unwrap();
+        let lifetime = validity.not_after - validity.not_before;
+        assert!(lifetime >= TimeDelta::days(30) && lifetime <= TimeDelta::days(32));
+
+        // Past notAfter the chain no longer verifies.
+        assert!(matches!(
+            verify_certificate_chain(
+                issued.cert_pem.as_str(),
+                &ca.cert_pem(),
+                Utc::now() + TimeDelta::days(40)
+            ),
+            Err(Error::ChainValidation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_certificate_chain_rejects_other_ca() {
+        let ca = CertificateAuthority::generate("rustfs-ca", None).unwrap();
+        let other = CertificateAuthority::generate("other-ca", None).unwrap();
+        let issued = ca
+            .issue_server_certificate(&["rustfs.storage.svc".to_string()], None)
+            .unwrap();
+
+        assert!(matches!(
+            verify_certificate_chain(issued.cert_pem.as_str(), &other.cert_pem(), Utc::now()),
+            Err(Error::ChainValidation { .. })
+        ));
+    }
 }
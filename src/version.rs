@@ -0,0 +1,96 @@
+// Copyright 2025 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Operator build information generated by `shadow-rs` in `build.rs`.
+
+use axum::Json;
+use axum::response::IntoResponse;
+use const_str::concat;
+use serde::Serialize;
+
+shadow_rs::shadow!(build);
+
+/// Release tag, `@<short commit>` for untagged builds, or the crate version as a fallback.
+#[allow(clippy::const_is_empty)]
+pub const SHORT_VERSION: &str = {
+    if !build::TAG.is_empty() {
+        build::TAG
+    } else if !build::SHORT_COMMIT.is_empty() {
+        concat!("@", build::SHORT_COMMIT)
+    } else {
+        build::PKG_VERSION
+    }
+};
+
+/// Multi-line build report printed by `--version`.
+pub const LONG_VERSION: &str = concat!(
+    concat!(SHORT_VERSION, "\n"),
+    concat!("build time   : ", build::BUILD_TIME, "\n"),
+    concat!("build profile: ", build::BUILD_RUST_CHANNEL, "\n"),
+    concat!("build os     : ", build::BUILD_OS, "\n"),
+    concat!("rust version : ", build::RUST_VERSION, "\n"),
+    concat!("rust channel : ", build::RUST_CHANNEL, "\n"),
+    concat!("git branch   : ", build::BRANCH, "\n"),
+    concat!("git commit   : ", build::COMMIT_HASH,),
+    #[allow(clippy::const_is_empty)]
+    if !build::TAG.is_empty() {
+        concat!("\n", "git tag      : ", build::TAG)
+    } else {
+        ""
+    },
+);
+
+/// Build information served at `/version` by the console and observability servers.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub package_version: &'static str,
+    pub git_commit: &'static str,
+    pub git_branch: &'static str,
+    pub git_tag: &'static str,
+    pub build_time: &'static str,
+    pub rust_version: &'static str,
+}
+
+impl BuildInfo {
+    pub const fn current() -> Self {
+        Self {
+            version: SHORT_VERSION,
+            package_version: build::PKG_VERSION,
+            git_commit: build::COMMIT_HASH,
+            git_branch: build::BRANCH,
+            git_tag: build::TAG,
+            build_time: build::BUILD_TIME,
+            rust_version: build::RUST_VERSION,
+        }
+    }
+}
+
+/// `GET /version`
+pub async fn handler() -> impl IntoResponse {
+    Json(BuildInfo::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildInfo, SHORT_VERSION};
+
+    #[test]
+    fn build_info_reports_short_version() {
+        let info = BuildInfo::current();
+        assert_eq!(info.version, SHORT_VERSION);
+        assert!(!info.package_version.is_empty());
+    }
+}